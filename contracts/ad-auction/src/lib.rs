@@ -0,0 +1,617 @@
+//! PulsarTrack - Ad Auction (Soroban)
+//! Placement-slot auctions between publishers and advertisers on Stellar.
+//!
+//! Publishers list a slot with its size, domain and floor price. Advertisers
+//! bid openly or through a sealed commit/reveal round, escrowing their bid in
+//! an accepted token (PULSAR or USDC). Settlement charges the winner the
+//! second-highest bid (or the fixed price), keeps that amount in escrow until
+//! the placement is released to the publisher, and notifies the orchestrator.
+//! Everything else is reclaimed by the bidders through `withdraw_bid`.
+//!
+//! Events are published as ("ad_auction", action, version) with a typed payload
+//! (see the Events section):
+//! - "slot_listed": `SlotListed`
+//! - "bid_placed": `BidPlaced`
+//! - "bid_committed": `BidCommitted`
+//! - "bid_revealed": `BidRevealed`
+//! - "payment_released": `PaymentReleased`
+//! - "bid_withdrawn": `BidWithdrawn`
+//! - "slot_settled": `SlotSettled`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error, symbol_short,
+    token, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
+};
+pub use pulsartrack_errors::AdAuctionError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum PricingMode {
+    SecondPrice, // winner pays the runner-up's bid, at least the floor
+    FixedPrice,  // first bid at the floor price takes the slot
+}
+
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum SlotStatus {
+    Open,
+    Settled,  // winning payment held in escrow
+    NoSale,   // closed without a valid bid
+    Released, // payment passed on to the publisher
+}
+
+/// Terms a publisher lists a slot with
+#[contracttype]
+#[derive(Clone)]
+pub struct SlotTerms {
+    pub size: String,   // e.g. "300x250"
+    pub domain: String,
+    pub floor_price: i128,
+    pub token: Address,
+    pub pricing: PricingMode,
+    pub sealed: bool,
+    pub bidding_ends: u64, // Unix timestamp
+    pub reveal_ends: u64,  // sealed auctions only
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Slot {
+    pub publisher: Address,
+    pub terms: SlotTerms,
+    pub status: SlotStatus,
+    pub best_bidder: Option<Address>,
+    pub best_campaign: u64,
+    pub best_amount: i128,
+    pub second_amount: i128,
+    pub price: i128, // charged to the winner on settlement
+    pub bid_count: u32,
+    pub created_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Bid {
+    pub campaign_id: u64,
+    pub escrowed: i128,
+    pub amount: i128, // 0 until a sealed bid is revealed
+    pub commitment: Option<BytesN<32>>,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SlotListed {
+    pub slot_id: u64,
+    pub publisher: Address,
+}
+contract_event!(SlotListed, "slot_listed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BidPlaced {
+    pub slot_id: u64,
+    pub bidder: Address,
+    pub amount: i128,
+}
+contract_event!(BidPlaced, "bid_placed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BidCommitted {
+    pub slot_id: u64,
+    pub bidder: Address,
+}
+contract_event!(BidCommitted, "bid_committed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BidRevealed {
+    pub slot_id: u64,
+    pub bidder: Address,
+    pub amount: i128,
+}
+contract_event!(BidRevealed, "bid_revealed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PaymentReleased {
+    pub slot_id: u64,
+    pub publisher: Address,
+    pub amount: i128,
+}
+contract_event!(PaymentReleased, "payment_released");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BidWithdrawn {
+    pub slot_id: u64,
+    pub bidder: Address,
+    pub amount: i128,
+}
+contract_event!(BidWithdrawn, "bid_withdrawn");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SlotSettled {
+    pub slot_id: u64,
+    pub winner: Option<Address>,
+    pub price: i128,
+}
+contract_event!(SlotSettled, "slot_settled");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Orchestrator,
+    Registry,
+    BidToken(Address),
+    SlotCounter,
+    Slot(u64),
+    Bid(u64, Address),
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const EVENT_TAG: &str = "ad_auction";
+
+#[contract]
+pub struct AdAuctionContract;
+
+#[contractimpl]
+impl AdAuctionContract {
+    pub fn initialize(env: Env, admin: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, AdAuctionError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::SlotCounter, &0u64);
+    }
+
+    /// Accept or stop accepting a token for bids (admin only)
+    pub fn set_bid_token(env: Env, admin: Address, token: Address, accepted: bool) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        if accepted {
+            env.storage().instance().set(&DataKey::BidToken(token), &true);
+        } else {
+            env.storage().instance().remove(&DataKey::BidToken(token));
+        }
+    }
+
+    /// Set the orchestrator notified of won placements (admin only)
+    pub fn set_orchestrator(env: Env, admin: Address, orchestrator: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Orchestrator, &orchestrator);
+    }
+
+    /// Resolve the orchestrator through the contract registry's "orchestr"
+    /// key, falling back to `set_orchestrator` (admin only)
+    pub fn set_registry(env: Env, admin: Address, registry: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Registry, &registry);
+    }
+
+    pub fn list_slot(env: Env, publisher: Address, terms: SlotTerms) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+
+        if !env.storage().instance().has(&DataKey::BidToken(terms.token.clone())) {
+            panic_with_error!(&env, AdAuctionError::TokenNotAccepted);
+        }
+        if terms.floor_price <= 0 {
+            panic_with_error!(&env, AdAuctionError::InvalidFloorPrice);
+        }
+        let now = env.ledger().timestamp();
+        if terms.bidding_ends <= now {
+            panic_with_error!(&env, AdAuctionError::InvalidBiddingWindow);
+        }
+        if terms.sealed {
+            if terms.pricing == PricingMode::FixedPrice {
+                panic_with_error!(&env, AdAuctionError::FixedPriceSlotsTakeOpenBids);
+            }
+            if terms.reveal_ends <= terms.bidding_ends {
+                panic_with_error!(&env, AdAuctionError::InvalidRevealWindow);
+            }
+        }
+
+        let counter: u64 = env.storage().instance().get(&DataKey::SlotCounter).unwrap_or(0);
+        let slot_id = counter + 1;
+
+        let slot = Slot {
+            publisher: publisher.clone(),
+            terms,
+            status: SlotStatus::Open,
+            best_bidder: None,
+            best_campaign: 0,
+            best_amount: 0,
+            second_amount: 0,
+            price: 0,
+            bid_count: 0,
+            created_at: now,
+        };
+        Self::_save_slot(&env, slot_id, &slot);
+        env.storage().instance().set(&DataKey::SlotCounter, &slot_id);
+
+        events::publish(&env, EVENT_TAG, SlotListed { slot_id, publisher });
+
+        slot_id
+    }
+
+    /// Place or raise an open bid, escrowing the difference. On a fixed-price
+    /// slot a bid at the floor price wins immediately.
+    pub fn place_bid(env: Env, bidder: Address, slot_id: u64, amount: i128, campaign_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        bidder.require_auth();
+
+        let mut slot = Self::_load_slot(&env, slot_id);
+        if slot.status != SlotStatus::Open {
+            panic_with_error!(&env, AdAuctionError::SlotNotOpen);
+        }
+        if slot.terms.sealed {
+            panic_with_error!(&env, AdAuctionError::SealedAuction);
+        }
+        if env.ledger().timestamp() >= slot.terms.bidding_ends {
+            panic_with_error!(&env, AdAuctionError::BiddingClosed);
+        }
+        if bidder == slot.publisher {
+            panic_with_error!(&env, AdAuctionError::PublisherCannotBid);
+        }
+        match slot.terms.pricing {
+            PricingMode::SecondPrice if amount < slot.terms.floor_price => panic_with_error!(&env, AdAuctionError::BidBelowFloorPrice),
+            PricingMode::FixedPrice if amount != slot.terms.floor_price => panic_with_error!(&env, AdAuctionError::BidMustMatchFixedPrice),
+            _ => {}
+        }
+
+        let mut bid = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Bid>(&DataKey::Bid(slot_id, bidder.clone()))
+            .unwrap_or(Bid {
+                campaign_id,
+                escrowed: 0,
+                amount: 0,
+                commitment: None,
+            });
+        if amount <= bid.amount {
+            panic_with_error!(&env, AdAuctionError::BidNotRaised);
+        }
+        if bid.escrowed == 0 {
+            slot.bid_count += 1;
+        }
+
+        token::Client::new(&env, &slot.terms.token).transfer(
+            &bidder,
+            &env.current_contract_address(),
+            &(amount - bid.escrowed),
+        );
+        bid.escrowed = amount;
+        bid.amount = amount;
+        bid.campaign_id = campaign_id;
+        Self::_save_bid(&env, slot_id, &bidder, &bid);
+        Self::_rank_bid(&mut slot, &bidder, campaign_id, amount);
+
+        events::publish(&env, EVENT_TAG, BidPlaced { slot_id, bidder, amount });
+
+        if slot.terms.pricing == PricingMode::FixedPrice {
+            Self::_settle(&env, slot_id, slot);
+        } else {
+            Self::_save_slot(&env, slot_id, &slot);
+        }
+    }
+
+    /// Commit to a sealed bid. `deposit` is escrowed now and must cover the
+    /// bid revealed later; overpaying hides the real amount.
+    pub fn commit_bid(
+        env: Env,
+        bidder: Address,
+        slot_id: u64,
+        commitment: BytesN<32>,
+        deposit: i128,
+        campaign_id: u64,
+    ) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        bidder.require_auth();
+
+        let mut slot = Self::_load_slot(&env, slot_id);
+        if slot.status != SlotStatus::Open || !slot.terms.sealed {
+            panic_with_error!(&env, AdAuctionError::NotASealedAuction);
+        }
+        if env.ledger().timestamp() >= slot.terms.bidding_ends {
+            panic_with_error!(&env, AdAuctionError::BiddingClosed);
+        }
+        if bidder == slot.publisher {
+            panic_with_error!(&env, AdAuctionError::PublisherCannotBid);
+        }
+        if deposit < slot.terms.floor_price {
+            panic_with_error!(&env, AdAuctionError::DepositBelowFloorPrice);
+        }
+        if env.storage().persistent().has(&DataKey::Bid(slot_id, bidder.clone())) {
+            panic_with_error!(&env, AdAuctionError::AlreadyCommitted);
+        }
+
+        token::Client::new(&env, &slot.terms.token).transfer(&bidder, &env.current_contract_address(), &deposit);
+        let bid = Bid {
+            campaign_id,
+            escrowed: deposit,
+            amount: 0,
+            commitment: Some(commitment),
+        };
+        Self::_save_bid(&env, slot_id, &bidder, &bid);
+        slot.bid_count += 1;
+        Self::_save_slot(&env, slot_id, &slot);
+
+        events::publish(&env, EVENT_TAG, BidCommitted { slot_id, bidder });
+    }
+
+    /// Reveal a sealed bid between the end of bidding and the end of the
+    /// reveal window. Unrevealed deposits can only be withdrawn.
+    pub fn reveal_bid(env: Env, bidder: Address, slot_id: u64, amount: i128, salt: BytesN<32>) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        bidder.require_auth();
+
+        let mut slot = Self::_load_slot(&env, slot_id);
+        if slot.status != SlotStatus::Open || !slot.terms.sealed {
+            panic_with_error!(&env, AdAuctionError::NotASealedAuction);
+        }
+        let now = env.ledger().timestamp();
+        if now < slot.terms.bidding_ends || now >= slot.terms.reveal_ends {
+            panic_with_error!(&env, AdAuctionError::NotInRevealWindow);
+        }
+
+        let mut bid: Bid = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Bid(slot_id, bidder.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, AdAuctionError::BidNotFound));
+        if bid.amount > 0 {
+            panic_with_error!(&env, AdAuctionError::AlreadyRevealed);
+        }
+        if bid.commitment != Some(Self::_commitment(&env, amount, &salt)) {
+            panic_with_error!(&env, AdAuctionError::CommitmentMismatch);
+        }
+        if amount < slot.terms.floor_price || amount > bid.escrowed {
+            panic_with_error!(&env, AdAuctionError::InvalidBidAmount);
+        }
+
+        bid.amount = amount;
+        Self::_save_bid(&env, slot_id, &bidder, &bid);
+        Self::_rank_bid(&mut slot, &bidder, bid.campaign_id, amount);
+        Self::_save_slot(&env, slot_id, &slot);
+
+        events::publish(&env, EVENT_TAG, BidRevealed { slot_id, bidder, amount });
+    }
+
+    /// Close bidding once its window has passed. Anyone may call this.
+    pub fn settle_slot(env: Env, slot_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let slot = Self::_load_slot(&env, slot_id);
+        if slot.status != SlotStatus::Open {
+            panic_with_error!(&env, AdAuctionError::SlotNotOpen);
+        }
+        let closes_at = if slot.terms.sealed {
+            slot.terms.reveal_ends
+        } else {
+            slot.terms.bidding_ends
+        };
+        if env.ledger().timestamp() < closes_at {
+            panic_with_error!(&env, AdAuctionError::AuctionStillRunning);
+        }
+        Self::_settle(&env, slot_id, slot);
+    }
+
+    /// Pay the escrowed winning price to the publisher once the placement
+    /// is delivered (orchestrator or admin)
+    pub fn release_payment(env: Env, caller: Address, slot_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        caller.require_auth();
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        let orchestrator = Self::_orchestrator(&env);
+        if caller != admin && Some(caller) != orchestrator {
+            panic_with_error!(&env, AdAuctionError::Unauthorized);
+        }
+
+        let mut slot = Self::_load_slot(&env, slot_id);
+        if slot.status != SlotStatus::Settled {
+            panic_with_error!(&env, AdAuctionError::SlotNotSettled);
+        }
+        slot.status = SlotStatus::Released;
+        Self::_save_slot(&env, slot_id, &slot);
+
+        token::Client::new(&env, &slot.terms.token).transfer(
+            &env.current_contract_address(),
+            &slot.publisher,
+            &slot.price,
+        );
+
+        events::publish(&env, EVENT_TAG, PaymentReleased { slot_id, publisher: slot.publisher, amount: slot.price });
+    }
+
+    /// Reclaim escrow after settlement: the whole deposit for losing or
+    /// unrevealed bids, and anything above the price for the winner
+    pub fn withdraw_bid(env: Env, bidder: Address, slot_id: u64) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        bidder.require_auth();
+
+        let slot = Self::_load_slot(&env, slot_id);
+        if slot.status == SlotStatus::Open {
+            panic_with_error!(&env, AdAuctionError::AuctionStillRunning);
+        }
+        let bid: Bid = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Bid(slot_id, bidder.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, AdAuctionError::BidNotFound));
+
+        let amount = if slot.best_bidder == Some(bidder.clone()) {
+            bid.escrowed - slot.price
+        } else {
+            bid.escrowed
+        };
+        env.storage().persistent().remove(&DataKey::Bid(slot_id, bidder.clone()));
+        if amount > 0 {
+            token::Client::new(&env, &slot.terms.token).transfer(&env.current_contract_address(), &bidder, &amount);
+        }
+
+        events::publish(&env, EVENT_TAG, BidWithdrawn { slot_id, bidder, amount });
+
+        amount
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn get_slot(env: Env, slot_id: u64) -> Option<Slot> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Slot(slot_id))
+    }
+
+    pub fn get_bid(env: Env, slot_id: u64, bidder: Address) -> Option<Bid> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Bid(slot_id, bidder))
+    }
+
+    pub fn is_bid_token(env: Env, token: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().has(&DataKey::BidToken(token))
+    }
+
+    /// Commitment a sealed bidder submits: sha256(amount XDR || salt)
+    pub fn bid_commitment(env: Env, amount: i128, salt: BytesN<32>) -> BytesN<32> {
+        Self::_commitment(&env, amount, &salt)
+    }
+
+    pub fn get_slot_count(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::SlotCounter).unwrap_or(0)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _require_admin(env: &Env, admin: &Address) {
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *admin != stored_admin {
+            panic_with_error!(&env, AdAuctionError::Unauthorized);
+        }
+    }
+
+    fn _load_slot(env: &Env, slot_id: u64) -> Slot {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Slot(slot_id))
+            .unwrap_or_else(|| panic_with_error!(&env, AdAuctionError::SlotNotFound))
+    }
+
+    fn _save_slot(env: &Env, slot_id: u64, slot: &Slot) {
+        let _ttl_key = DataKey::Slot(slot_id);
+        env.storage().persistent().set(&_ttl_key, slot);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _save_bid(env: &Env, slot_id: u64, bidder: &Address, bid: &Bid) {
+        let _ttl_key = DataKey::Bid(slot_id, bidder.clone());
+        env.storage().persistent().set(&_ttl_key, bid);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _commitment(env: &Env, amount: i128, salt: &BytesN<32>) -> BytesN<32> {
+        let mut preimage: Bytes = amount.to_xdr(env);
+        preimage.append(&Bytes::from(salt.clone()));
+        env.crypto().sha256(&preimage).into()
+    }
+
+    /// Keep track of the two highest bids
+    fn _rank_bid(slot: &mut Slot, bidder: &Address, campaign_id: u64, amount: i128) {
+        if slot.best_bidder.as_ref() == Some(bidder) {
+            slot.best_amount = amount;
+            slot.best_campaign = campaign_id;
+        } else if amount > slot.best_amount {
+            slot.second_amount = slot.best_amount;
+            slot.best_amount = amount;
+            slot.best_bidder = Some(bidder.clone());
+            slot.best_campaign = campaign_id;
+        } else if amount > slot.second_amount {
+            slot.second_amount = amount;
+        }
+    }
+
+    fn _orchestrator(env: &Env) -> Option<Address> {
+        if let Some(registry) = env.storage().instance().get::<DataKey, Address>(&DataKey::Registry) {
+            let resolved: Option<Address> = env.invoke_contract(
+                &registry,
+                &Symbol::new(env, "lookup"),
+                (symbol_short!("orchestr"),).into_val(env),
+            );
+            if resolved.is_some() {
+                return resolved;
+            }
+        }
+        env.storage().instance().get(&DataKey::Orchestrator)
+    }
+
+    /// Fix the winner's price, keep it in escrow and tell the orchestrator
+    fn _settle(env: &Env, slot_id: u64, mut slot: Slot) {
+        match slot.best_bidder.clone() {
+            Some(winner) => {
+                slot.price = match slot.terms.pricing {
+                    PricingMode::SecondPrice => slot.second_amount.max(slot.terms.floor_price),
+                    PricingMode::FixedPrice => slot.terms.floor_price,
+                };
+                slot.status = SlotStatus::Settled;
+
+                if let Some(orchestrator) = Self::_orchestrator(env) {
+                    // A failing hook must not block settlement
+                    let args: Vec<Val> = Vec::from_array(
+                        env,
+                        [
+                            slot_id.into_val(env),
+                            winner.into_val(env),
+                            slot.best_campaign.into_val(env),
+                            slot.price.into_val(env),
+                        ],
+                    );
+                    let _ = env.try_invoke_contract::<(), soroban_sdk::Error>(
+                        &orchestrator,
+                        &Symbol::new(env, "on_placement_won"),
+                        args,
+                    );
+                }
+            }
+            None => slot.status = SlotStatus::NoSale,
+        }
+        Self::_save_slot(env, slot_id, &slot);
+
+        events::publish(env, EVENT_TAG, SlotSettled { slot_id, winner: slot.best_bidder, price: slot.price });
+    }
+}
+
+mod test;
@@ -0,0 +1,225 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    symbol_short, token, Address, BytesN, Env, String, Symbol,
+};
+
+#[contract]
+pub struct MockOrchestrator;
+
+#[contractimpl]
+impl MockOrchestrator {
+    pub fn on_placement_won(env: Env, slot_id: u64, advertiser: Address, campaign_id: u64, price: i128) {
+        env.storage().instance().set(&slot_id, &(advertiser, campaign_id, price));
+    }
+
+    pub fn placement(env: Env, slot_id: u64) -> Option<(Address, u64, i128)> {
+        env.storage().instance().get(&slot_id)
+    }
+}
+
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_address(env: Env, key: Symbol, address: Address) {
+        env.storage().instance().set(&key, &address);
+    }
+
+    pub fn lookup(env: Env, key: Symbol) -> Option<Address> {
+        env.storage().instance().get(&key)
+    }
+}
+
+fn setup(env: &Env) -> (AdAuctionContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, AdAuctionContract);
+    let client = AdAuctionContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    client.set_bid_token(&admin, &token, &true);
+    (client, admin, token)
+}
+
+fn terms(env: &Env, token: &Address, pricing: PricingMode, sealed: bool) -> SlotTerms {
+    SlotTerms {
+        size: String::from_str(env, "300x250"),
+        domain: String::from_str(env, "news.example"),
+        floor_price: 100,
+        token: token.clone(),
+        pricing,
+        sealed,
+        bidding_ends: 1_000,
+        reveal_ends: 2_000,
+    }
+}
+
+fn funded(env: &Env, token: &Address, amount: i128) -> Address {
+    let account = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&account, &amount);
+    account
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AdAuctionContract);
+    let client = AdAuctionContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    assert_eq!(client.get_slot_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #701)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AdAuctionContract);
+    let client = AdAuctionContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.initialize(&admin);
+}
+
+#[test]
+fn test_open_second_price_auction() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token) = setup(&env);
+    let balance = token::Client::new(&env, &token);
+
+    let orchestrator_id = env.register_contract(None, MockOrchestrator);
+    let orchestrator = MockOrchestratorClient::new(&env, &orchestrator_id);
+    client.set_orchestrator(&admin, &orchestrator_id);
+
+    let publisher = Address::generate(&env);
+    let unlisted = Address::generate(&env);
+    assert!(client
+        .try_list_slot(&publisher, &terms(&env, &unlisted, PricingMode::SecondPrice, false))
+        .is_err());
+    let slot = client.list_slot(&publisher, &terms(&env, &token, PricingMode::SecondPrice, false));
+
+    let alice = funded(&env, &token, 1_000);
+    let bob = funded(&env, &token, 1_000);
+    assert!(client.try_place_bid(&alice, &slot, &50, &1).is_err());
+    client.place_bid(&alice, &slot, &150, &1);
+    client.place_bid(&bob, &slot, &300, &2);
+    client.place_bid(&alice, &slot, &250, &1);
+    assert_eq!(balance.balance(&alice), 750);
+
+    assert!(client.try_settle_slot(&slot).is_err());
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert!(client.try_place_bid(&alice, &slot, &400, &1).is_err());
+    client.settle_slot(&slot);
+
+    // Bob wins at Alice's bid and the orchestrator hears about it
+    let settled = client.get_slot(&slot).unwrap();
+    assert!(settled.status == SlotStatus::Settled);
+    assert_eq!(settled.price, 250);
+    assert_eq!(orchestrator.placement(&slot), Some((bob.clone(), 2, 250)));
+
+    assert_eq!(client.withdraw_bid(&alice, &slot), 250);
+    assert_eq!(client.withdraw_bid(&bob, &slot), 50);
+    assert_eq!(balance.balance(&bob), 750);
+
+    assert!(client.try_release_payment(&publisher, &slot).is_err());
+    client.release_payment(&orchestrator_id, &slot);
+    assert_eq!(balance.balance(&publisher), 250);
+    assert!(client.try_release_payment(&admin, &slot).is_err());
+}
+
+#[test]
+fn test_sealed_bids_commit_and_reveal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, token) = setup(&env);
+    let balance = token::Client::new(&env, &token);
+
+    let publisher = Address::generate(&env);
+    let slot = client.list_slot(&publisher, &terms(&env, &token, PricingMode::SecondPrice, true));
+
+    let alice = funded(&env, &token, 1_000);
+    let bob = funded(&env, &token, 1_000);
+    let carol = funded(&env, &token, 1_000);
+    let alice_salt = BytesN::from_array(&env, &[1u8; 32]);
+    let bob_salt = BytesN::from_array(&env, &[2u8; 32]);
+    client.commit_bid(&alice, &slot, &client.bid_commitment(&400, &alice_salt), &500, &1);
+    client.commit_bid(&bob, &slot, &client.bid_commitment(&180, &bob_salt), &500, &2);
+    client.commit_bid(&carol, &slot, &client.bid_commitment(&900, &bob_salt), &1_000, &3);
+    assert!(client.try_place_bid(&alice, &slot, &200, &1).is_err());
+
+    // Reveals only count inside the window and must match the commitment
+    assert!(client.try_reveal_bid(&alice, &slot, &400, &alice_salt).is_err());
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    assert!(client.try_reveal_bid(&alice, &slot, &450, &alice_salt).is_err());
+    client.reveal_bid(&alice, &slot, &400, &alice_salt);
+    client.reveal_bid(&bob, &slot, &180, &bob_salt);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.settle_slot(&slot);
+    let settled = client.get_slot(&slot).unwrap();
+    assert_eq!(settled.best_bidder, Some(alice.clone()));
+    assert_eq!(settled.price, 180);
+
+    // Carol never revealed and gets her whole deposit back
+    assert_eq!(client.withdraw_bid(&carol, &slot), 1_000);
+    assert_eq!(client.withdraw_bid(&alice, &slot), 320);
+    assert_eq!(balance.balance(&alice), 820);
+}
+
+#[test]
+fn test_fixed_price_slot_sold_to_first_taker() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token) = setup(&env);
+
+    let publisher = Address::generate(&env);
+    let slot = client.list_slot(&publisher, &terms(&env, &token, PricingMode::FixedPrice, false));
+
+    let alice = funded(&env, &token, 1_000);
+    let bob = funded(&env, &token, 1_000);
+    assert!(client.try_place_bid(&alice, &slot, &150, &1).is_err());
+    client.place_bid(&alice, &slot, &100, &1);
+    assert!(client.get_slot(&slot).unwrap().status == SlotStatus::Settled);
+    assert!(client.try_place_bid(&bob, &slot, &100, &2).is_err());
+
+    client.release_payment(&admin, &slot);
+    assert_eq!(token::Client::new(&env, &token).balance(&publisher), 100);
+}
+
+#[test]
+fn test_orchestrator_resolved_through_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token) = setup(&env);
+
+    let stale_id = env.register_contract(None, MockOrchestrator);
+    let current_id = env.register_contract(None, MockOrchestrator);
+    client.set_orchestrator(&admin, &stale_id);
+    let registry_id = env.register_contract(None, MockRegistry);
+    client.set_registry(&admin, &registry_id);
+    MockRegistryClient::new(&env, &registry_id).set_address(&symbol_short!("orchestr"), &current_id);
+
+    let publisher = Address::generate(&env);
+    let slot = client.list_slot(&publisher, &terms(&env, &token, PricingMode::FixedPrice, false));
+    let alice = funded(&env, &token, 1_000);
+    client.place_bid(&alice, &slot, &100, &1);
+
+    // The registry entry wins over the address set at deployment
+    assert_eq!(MockOrchestratorClient::new(&env, &current_id).placement(&slot), Some((alice, 1, 100)));
+    assert!(MockOrchestratorClient::new(&env, &stale_id).placement(&slot).is_none());
+    assert!(client.try_release_payment(&stale_id, &slot).is_err());
+    client.release_payment(&current_id, &slot);
+    assert_eq!(token::Client::new(&env, &token).balance(&publisher), 100);
+}
@@ -4,9 +4,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
 };
+pub use pulsartrack_errors::AdRegistryError;
+use pulsartrack_events::{self as events, contract_event};
 
 // ============================================================
 // Data Types
@@ -72,6 +74,18 @@ pub struct FlagRecord {
     pub verified: bool,
 }
 
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ContentRegistered {
+    pub content_id: u64,
+    pub campaign_id: u64,
+}
+contract_event!(ContentRegistered, "content_registered");
+
 // ============================================================
 // Storage Keys
 // ============================================================
@@ -113,6 +127,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "ad_registry";
+
 #[contract]
 pub struct AdRegistryContract;
 
@@ -122,7 +138,7 @@ impl AdRegistryContract {
     pub fn initialize(env: Env, admin: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, AdRegistryError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -163,7 +179,7 @@ impl AdRegistryContract {
             .unwrap_or(10_485_760);
 
         if size < min_size || size > max_size {
-            panic!("invalid content size");
+            panic_with_error!(&env, AdRegistryError::InvalidContentSize);
         }
 
         let nonce: u64 = env
@@ -225,10 +241,7 @@ impl AdRegistryContract {
             .instance()
             .set(&DataKey::ContentNonce, &content_id);
 
-        env.events().publish(
-            (symbol_short!("register"), symbol_short!("content")),
-            (content_id, campaign_id),
-        );
+        events::publish(&env, EVENT_TAG, ContentRegistered { content_id, campaign_id });
 
         content_id
     }
@@ -239,14 +252,14 @@ impl AdRegistryContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, AdRegistryError::Unauthorized);
         }
 
         let mut content: AdContent = env
             .storage()
             .persistent()
             .get(&DataKey::Content(content_id))
-            .expect("content not found");
+            .unwrap_or_else(|| panic_with_error!(&env, AdRegistryError::ContentNotFound));
         content.status = new_status;
         content.updated_at = env.ledger().timestamp();
         let _ttl_key = DataKey::Content(content_id);
@@ -267,10 +280,10 @@ impl AdRegistryContract {
             .storage()
             .persistent()
             .get(&DataKey::Content(content_id))
-            .expect("content not found");
+            .unwrap_or_else(|| panic_with_error!(&env, AdRegistryError::ContentNotFound));
 
         if content.owner == reporter {
-            panic!("cannot flag own content");
+            panic_with_error!(&env, AdRegistryError::CannotFlagOwnContent);
         }
 
         let flag = FlagRecord {
@@ -316,18 +329,18 @@ impl AdRegistryContract {
             .storage()
             .persistent()
             .get(&DataKey::Content(content_id))
-            .expect("content not found");
+            .unwrap_or_else(|| panic_with_error!(&env, AdRegistryError::ContentNotFound));
 
         match content.status {
             ContentStatus::Approved => {}
-            _ => panic!("content not approved"),
+            _ => panic_with_error!(&env, AdRegistryError::ContentNotApproved),
         }
 
         let mut perf: ContentPerformance = env
             .storage()
             .persistent()
             .get(&DataKey::Performance(content_id))
-            .expect("performance not found");
+            .unwrap_or_else(|| panic_with_error!(&env, AdRegistryError::PerformanceNotFound));
 
         perf.total_views += 1;
         perf.unique_viewers += 1;
@@ -353,7 +366,7 @@ impl AdRegistryContract {
             .storage()
             .persistent()
             .get(&DataKey::Performance(content_id))
-            .expect("performance not found");
+            .unwrap_or_else(|| panic_with_error!(&env, AdRegistryError::PerformanceNotFound));
 
         perf.total_clicks += 1;
 
@@ -379,10 +392,10 @@ impl AdRegistryContract {
             .storage()
             .persistent()
             .get(&DataKey::Content(content_id))
-            .expect("content not found");
+            .unwrap_or_else(|| panic_with_error!(&env, AdRegistryError::ContentNotFound));
 
         if content.owner != owner {
-            panic!("unauthorized");
+            panic_with_error!(&env, AdRegistryError::Unauthorized);
         }
 
         content.status = ContentStatus::Archived;
@@ -445,7 +458,7 @@ impl AdRegistryContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, AdRegistryError::Unauthorized);
         }
         env.storage()
             .instance()
@@ -3,9 +3,10 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error, symbol_short,
     Address, Env,
 };
+pub use pulsartrack_errors::AnalyticsAggregatorError;
 
 #[contracttype]
 #[derive(Clone)]
@@ -63,7 +64,7 @@ impl AnalyticsAggregatorContract {
     pub fn initialize(env: Env, admin: Address, oracle: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, AnalyticsAggregatorError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -151,7 +152,7 @@ impl AnalyticsAggregatorContract {
             .storage()
             .persistent()
             .get(&DataKey::CampaignAnalytics(campaign_id))
-            .expect("analytics not found");
+            .unwrap_or_else(|| panic_with_error!(&env, AnalyticsAggregatorError::AnalyticsNotFound));
 
         analytics.total_clicks += 1;
         if analytics.total_impressions > 0 {
@@ -0,0 +1,235 @@
+//! PulsarTrack - Analytics Rollups (Soroban)
+//! Verifiable per-epoch campaign and platform aggregates on Stellar.
+//!
+//! Approved reporters (the campaign orchestrator) push each impression,
+//! spend and refund as it happens. They are folded into a rollup for the
+//! current epoch, once for the campaign and once platform-wide, so indexers
+//! can read aggregates straight from the chain instead of piecing together
+//! counters kept separately by each contract.
+//!
+//! `active_publishers` counts distinct publishers seen in the epoch. Range
+//! totals sum everything else and report the busiest epoch's publisher
+//! count, since distinct publishers don't add up across epochs.
+//!
+//! Events are published as ("analytics", action, version) with a typed payload
+//! (see the Events section):
+//! - "reporter_set": `ReporterSet`
+
+
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contracttype, panic_with_error, Address, Env, Vec};
+pub use pulsartrack_errors::AnalyticsError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Scope {
+    Platform,
+    Campaign(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Rollup {
+    pub impressions: u64,
+    pub spend: i128,
+    pub refunds: i128,
+    pub active_publishers: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EpochRollup {
+    pub epoch: u64,
+    pub rollup: Rollup,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReporterSet {
+    pub reporter: Address,
+    pub allowed: bool,
+}
+contract_event!(ReporterSet, "reporter_set");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    EpochSecs,
+    Reporter(Address),
+    Rollup(Scope, u64),                 // scope, epoch
+    PublisherSeen(Scope, u64, Address), // scope, epoch, publisher
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const MAX_RANGE_EPOCHS: u64 = 100;
+
+const EVENT_TAG: &str = "analytics";
+
+#[contract]
+pub struct AnalyticsContract;
+
+#[contractimpl]
+impl AnalyticsContract {
+    pub fn initialize(env: Env, admin: Address, epoch_secs: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, AnalyticsError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        if epoch_secs == 0 {
+            panic_with_error!(&env, AnalyticsError::InvalidEpochLength);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::EpochSecs, &epoch_secs);
+    }
+
+    /// Allow or disallow a contract to push activity (admin only)
+    pub fn set_reporter(env: Env, admin: Address, reporter: Address, allowed: bool) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if admin != stored_admin {
+            panic_with_error!(&env, AnalyticsError::Unauthorized);
+        }
+        if allowed {
+            env.storage().instance().set(&DataKey::Reporter(reporter.clone()), &true);
+        } else {
+            env.storage().instance().remove(&DataKey::Reporter(reporter.clone()));
+        }
+
+        events::publish(&env, EVENT_TAG, ReporterSet { reporter, allowed });
+    }
+
+    /// Fold campaign activity into the current epoch's campaign and
+    /// platform rollups (reporters only)
+    pub fn record(
+        env: Env,
+        reporter: Address,
+        campaign_id: u64,
+        publisher: Option<Address>,
+        impressions: u64,
+        spend: i128,
+        refunds: i128,
+    ) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        reporter.require_auth();
+        if !env.storage().instance().has(&DataKey::Reporter(reporter)) {
+            panic_with_error!(&env, AnalyticsError::Unauthorized);
+        }
+        if spend < 0 || refunds < 0 {
+            panic_with_error!(&env, AnalyticsError::InvalidAmount);
+        }
+
+        let epoch = Self::_current_epoch(&env);
+        for scope in [Scope::Campaign(campaign_id), Scope::Platform] {
+            let mut rollup = Self::_rollup(&env, &scope, epoch);
+            rollup.impressions += impressions;
+            rollup.spend += spend;
+            rollup.refunds += refunds;
+            if let Some(publisher) = publisher.clone() {
+                let seen_key = DataKey::PublisherSeen(scope.clone(), epoch, publisher);
+                if !env.storage().persistent().has(&seen_key) {
+                    env.storage().persistent().set(&seen_key, &true);
+                    env.storage().persistent().extend_ttl(&seen_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+                    rollup.active_publishers += 1;
+                }
+            }
+            let _ttl_key = DataKey::Rollup(scope, epoch);
+            env.storage().persistent().set(&_ttl_key, &rollup);
+            env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn current_epoch(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_current_epoch(&env)
+    }
+
+    pub fn get_rollup(env: Env, scope: Scope, epoch: u64) -> Rollup {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_rollup(&env, &scope, epoch)
+    }
+
+    /// Rollups for epochs `from..=to` that saw any activity
+    pub fn get_range(env: Env, scope: Scope, from_epoch: u64, to_epoch: u64) -> Vec<EpochRollup> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_validate_range(&env, from_epoch, to_epoch);
+        let mut result = Vec::new(&env);
+        for epoch in from_epoch..=to_epoch {
+            if let Some(rollup) = env.storage().persistent().get(&DataKey::Rollup(scope.clone(), epoch)) {
+                result.push_back(EpochRollup { epoch, rollup });
+            }
+        }
+        result
+    }
+
+    /// Totals over epochs `from..=to`; `active_publishers` is the busiest
+    /// single epoch's count
+    pub fn get_range_total(env: Env, scope: Scope, from_epoch: u64, to_epoch: u64) -> Rollup {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_validate_range(&env, from_epoch, to_epoch);
+        let mut total = Rollup::default();
+        for epoch in from_epoch..=to_epoch {
+            let rollup = Self::_rollup(&env, &scope, epoch);
+            total.impressions += rollup.impressions;
+            total.spend += rollup.spend;
+            total.refunds += rollup.refunds;
+            total.active_publishers = total.active_publishers.max(rollup.active_publishers);
+        }
+        total
+    }
+
+    pub fn is_reporter(env: Env, reporter: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().has(&DataKey::Reporter(reporter))
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _current_epoch(env: &Env) -> u64 {
+        let epoch_secs: u64 = env.storage().instance().get(&DataKey::EpochSecs).unwrap();
+        env.ledger().timestamp() / epoch_secs
+    }
+
+    fn _rollup(env: &Env, scope: &Scope, epoch: u64) -> Rollup {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Rollup(scope.clone(), epoch))
+            .unwrap_or_default()
+    }
+
+    fn _validate_range(env: &Env, from_epoch: u64, to_epoch: u64) {
+        if from_epoch > to_epoch || to_epoch - from_epoch >= MAX_RANGE_EPOCHS {
+            panic_with_error!(&env, AnalyticsError::InvalidRange);
+        }
+    }
+}
+
+mod test;
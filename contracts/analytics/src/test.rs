@@ -0,0 +1,68 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
+
+const EPOCH: u64 = 3_600;
+
+fn setup(env: &Env) -> (AnalyticsContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, AnalyticsContract);
+    let client = AnalyticsContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let orchestrator = Address::generate(env);
+    client.initialize(&admin, &EPOCH);
+    client.set_reporter(&admin, &orchestrator, &true);
+    (client, admin, orchestrator)
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, orchestrator) = setup(&env);
+    assert!(client.is_reporter(&orchestrator));
+    assert_eq!(client.get_rollup(&Scope::Platform, &0), Rollup::default());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1001)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _orchestrator) = setup(&env);
+    client.initialize(&admin, &EPOCH);
+}
+
+#[test]
+fn test_rollups_per_campaign_and_platform() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, orchestrator) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let stranger = Address::generate(&env);
+    assert!(client.try_record(&stranger, &1, &None, &1, &10, &0).is_err());
+
+    client.record(&orchestrator, &1, &Some(alice.clone()), &1, &10, &0);
+    client.record(&orchestrator, &1, &Some(alice.clone()), &1, &10, &0);
+    client.record(&orchestrator, &2, &Some(bob.clone()), &1, &25, &0);
+    client.record(&orchestrator, &2, &None, &0, &0, &40);
+
+    let campaign = client.get_rollup(&Scope::Campaign(1), &0);
+    assert_eq!(campaign, Rollup { impressions: 2, spend: 20, refunds: 0, active_publishers: 1 });
+    let platform = client.get_rollup(&Scope::Platform, &0);
+    assert_eq!(platform, Rollup { impressions: 3, spend: 45, refunds: 40, active_publishers: 2 });
+
+    // Next epoch starts a fresh rollup and fresh publisher counts
+    env.ledger().with_mut(|li| li.timestamp += 2 * EPOCH);
+    client.record(&orchestrator, &1, &Some(alice.clone()), &4, &40, &0);
+    let range = client.get_range(&Scope::Campaign(1), &0, &2);
+    assert_eq!(range.len(), 2);
+    assert_eq!(range.get(1).unwrap().epoch, 2);
+    let total = client.get_range_total(&Scope::Campaign(1), &0, &2);
+    assert_eq!(total, Rollup { impressions: 6, spend: 60, refunds: 0, active_publishers: 1 });
+    assert!(client.try_get_range(&Scope::Platform, &0, &100).is_err());
+
+    client.set_reporter(&admin, &orchestrator, &false);
+    assert!(client.try_record(&orchestrator, &1, &None, &1, &10, &0).is_err());
+}
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Env, String,
 };
+pub use pulsartrack_errors::AnomalyDetectorError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone)]
@@ -53,6 +55,14 @@ pub struct TrafficBaseline {
     pub last_updated: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct AnomalyDetected {
+    pub report_id: u64,
+    pub campaign_id: u64,
+}
+contract_event!(AnomalyDetected, "anomaly_detected");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -69,6 +79,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 34_560;
 const PERSISTENT_BUMP_AMOUNT: u32 = 259_200;
 
+const EVENT_TAG: &str = "anomaly";
+
 #[contract]
 pub struct AnomalyDetectorContract;
 
@@ -77,7 +89,7 @@ impl AnomalyDetectorContract {
     pub fn initialize(env: Env, admin: Address, oracle: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, AnomalyDetectorError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -98,7 +110,7 @@ impl AnomalyDetectorContract {
         oracle.require_auth();
         let stored_oracle: Address = env.storage().instance().get(&DataKey::OracleAddress).unwrap();
         if oracle != stored_oracle {
-            panic!("unauthorized");
+            panic_with_error!(&env, AnomalyDetectorError::Unauthorized);
         }
 
         let baseline = TrafficBaseline {
@@ -129,7 +141,7 @@ impl AnomalyDetectorContract {
         oracle.require_auth();
         let stored_oracle: Address = env.storage().instance().get(&DataKey::OracleAddress).unwrap();
         if oracle != stored_oracle {
-            panic!("unauthorized");
+            panic_with_error!(&env, AnomalyDetectorError::Unauthorized);
         }
 
         let counter: u64 = env.storage().instance().get(&DataKey::ReportCounter).unwrap_or(0);
@@ -166,10 +178,7 @@ impl AnomalyDetectorContract {
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
         env.storage().instance().set(&DataKey::ReportCounter, &report_id);
 
-        env.events().publish(
-            (symbol_short!("anomaly"), symbol_short!("detected")),
-            (report_id, campaign_id),
-        );
+        events::publish(&env, EVENT_TAG, AnomalyDetected { report_id, campaign_id });
 
         report_id
     }
@@ -179,14 +188,14 @@ impl AnomalyDetectorContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, AnomalyDetectorError::Unauthorized);
         }
 
         let mut report: AnomalyReport = env
             .storage()
             .persistent()
             .get(&DataKey::Report(report_id))
-            .expect("report not found");
+            .unwrap_or_else(|| panic_with_error!(&env, AnomalyDetectorError::ReportNotFound));
 
         report.resolved = true;
         report.resolved_at = Some(env.ledger().timestamp());
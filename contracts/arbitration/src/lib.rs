@@ -0,0 +1,607 @@
+//! PulsarTrack - Arbitration Court (Soroban)
+//! Juror-staked adjudication of refund and review disputes on Stellar.
+//!
+//! PULSAR stakers register as jurors. Approved contracts (the refund
+//! processor, publisher reputation) escalate a case; a panel is drawn
+//! pseudo-randomly from the juror pool and votes with commit-reveal.
+//! Jurors in the majority share the case fee plus the stake slashed from
+//! jurors who voted against the majority or never revealed. The ruling is
+//! pushed back to the escalating contract's `on_ruling` hook; if the hook
+//! fails the case records it as undelivered and anyone may retry with
+//! `deliver_ruling`.
+//!
+//! A vote commitment is `sha256(xdr(claimant_wins: bool) || salt)`.
+//!
+//! Events are published as ("arbitration", action, version) with a typed
+//! payload (see the Events section):
+//! - "juror_staked": `JurorStaked`
+//! - "stake_withdrawn": `StakeWithdrawn`
+//! - "case_opened": `CaseOpened`
+//! - "vote_revealed": `VoteRevealed`
+//! - "juror_slashed": `JurorSlashed`
+//! - "case_ruled": `CaseRuled`
+//! - "ruling_delivered": `RulingDelivered`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    token, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Symbol, Vec,
+};
+pub use pulsartrack_errors::ArbitrationError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct CourtConfig {
+    pub min_stake: i128,
+    pub jurors_per_case: u32, // odd, so a full panel can't tie
+    pub commit_secs: u64,
+    pub reveal_secs: u64,
+    pub case_fee: i128,
+    pub penalty_bps: u32, // of stake, for voting against the majority or not revealing
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Juror {
+    pub stake: i128,
+    pub active_cases: u32,
+    pub cases_ruled: u32,
+    pub registered_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CaseStatus {
+    Voting,
+    Ruled,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Case {
+    pub escalator: Address, // contract notified of the ruling
+    pub subject: Address,   // party the case is about; never drawn as a juror
+    pub ref_id: u64,        // the escalator's own id for the dispute
+    pub jurors: Vec<Address>,
+    pub fee: i128,
+    pub commit_ends: u64,
+    pub reveal_ends: u64,
+    pub votes_for: u32,     // claimant wins
+    pub votes_against: u32,
+    pub status: CaseStatus,
+    pub claimant_wins: bool,
+    pub ruling_delivered: bool, // escalator's `on_ruling` accepted the ruling
+    pub opened_at: u64,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct JurorStaked {
+    pub juror: Address,
+    pub stake: i128,
+}
+contract_event!(JurorStaked, "juror_staked");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct StakeWithdrawn {
+    pub juror: Address,
+    pub amount: i128,
+}
+contract_event!(StakeWithdrawn, "stake_withdrawn");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct CaseOpened {
+    pub case_id: u64,
+    pub escalator: Address,
+    pub subject: Address,
+    pub ref_id: u64,
+}
+contract_event!(CaseOpened, "case_opened");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct VoteRevealed {
+    pub case_id: u64,
+    pub juror: Address,
+    pub claimant_wins: bool,
+}
+contract_event!(VoteRevealed, "vote_revealed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct JurorSlashed {
+    pub case_id: u64,
+    pub juror: Address,
+    pub amount: i128,
+}
+contract_event!(JurorSlashed, "juror_slashed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct CaseRuled {
+    pub case_id: u64,
+    pub claimant_wins: bool,
+}
+contract_event!(CaseRuled, "case_ruled");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RulingDelivered {
+    pub case_id: u64,
+}
+contract_event!(RulingDelivered, "ruling_delivered");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    StakeToken,
+    Config,
+    Escalator(Address),
+    Jurors, // Vec<Address>
+    Juror(Address),
+    CaseCounter,
+    Case(u64),
+    Commitment(u64, Address),
+    Vote(u64, Address),
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const MAX_JURORS: u32 = 200;
+const MAX_PANEL: u32 = 21;
+
+const EVENT_TAG: &str = "arbitration";
+
+#[contract]
+pub struct ArbitrationContract;
+
+#[contractimpl]
+impl ArbitrationContract {
+    pub fn initialize(env: Env, admin: Address, stake_token: Address, config: CourtConfig) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, ArbitrationError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        Self::_validate_config(&env, &config);
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::StakeToken, &stake_token);
+        env.storage().instance().set(&DataKey::Config, &config);
+        env.storage().instance().set(&DataKey::Jurors, &Vec::<Address>::new(&env));
+        env.storage().instance().set(&DataKey::CaseCounter, &0u64);
+    }
+
+    pub fn set_config(env: Env, admin: Address, config: CourtConfig) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        Self::_validate_config(&env, &config);
+        env.storage().instance().set(&DataKey::Config, &config);
+    }
+
+    /// Allow or disallow a contract to escalate cases (admin only)
+    pub fn set_escalator(env: Env, admin: Address, escalator: Address, allowed: bool) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        if allowed {
+            env.storage().instance().set(&DataKey::Escalator(escalator), &true);
+        } else {
+            env.storage().instance().remove(&DataKey::Escalator(escalator));
+        }
+    }
+
+    /// Stake PULSAR to join (or top up a place in) the juror pool
+    pub fn stake(env: Env, juror: Address, amount: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        juror.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&env, ArbitrationError::InvalidAmount);
+        }
+        let config = Self::_config(&env);
+        let mut info = Self::_juror(&env, &juror).unwrap_or(Juror {
+            stake: 0,
+            active_cases: 0,
+            cases_ruled: 0,
+            registered_at: env.ledger().timestamp(),
+        });
+        if info.stake + amount < config.min_stake {
+            panic_with_error!(&env, ArbitrationError::StakeBelowMinimum);
+        }
+
+        let mut jurors = Self::_jurors(&env);
+        if !jurors.contains(&juror) {
+            if jurors.len() >= MAX_JURORS {
+                panic_with_error!(&env, ArbitrationError::JurorPoolFull);
+            }
+            jurors.push_back(juror.clone());
+            env.storage().instance().set(&DataKey::Jurors, &jurors);
+        }
+
+        Self::_stake_token(&env).transfer(&juror, &env.current_contract_address(), &amount);
+        info.stake += amount;
+        Self::_save_juror(&env, &juror, &info);
+
+        events::publish(&env, EVENT_TAG, JurorStaked { juror, stake: info.stake });
+    }
+
+    /// Withdraw stake while not sitting on any case. Dropping below the
+    /// minimum means leaving the pool entirely.
+    pub fn withdraw_stake(env: Env, juror: Address, amount: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        juror.require_auth();
+        let mut info = Self::_juror(&env, &juror).unwrap_or_else(|| panic_with_error!(&env, ArbitrationError::JurorNotFound));
+        if info.active_cases > 0 {
+            panic_with_error!(&env, ArbitrationError::JurorHasActiveCases);
+        }
+        if amount <= 0 || amount > info.stake {
+            panic_with_error!(&env, ArbitrationError::InvalidAmount);
+        }
+        let remaining = info.stake - amount;
+        if remaining > 0 && remaining < Self::_config(&env).min_stake {
+            panic_with_error!(&env, ArbitrationError::StakeBelowMinimum);
+        }
+
+        info.stake = remaining;
+        if remaining == 0 {
+            env.storage().persistent().remove(&DataKey::Juror(juror.clone()));
+            Self::_leave_pool(&env, &juror);
+        } else {
+            Self::_save_juror(&env, &juror, &info);
+        }
+        Self::_stake_token(&env).transfer(&env.current_contract_address(), &juror, &amount);
+
+        events::publish(&env, EVENT_TAG, StakeWithdrawn { juror, amount });
+    }
+
+    /// Open a case and draw its panel. Called by an approved contract;
+    /// `payer` covers the case fee.
+    pub fn open_case(env: Env, escalator: Address, payer: Address, subject: Address, ref_id: u64) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        escalator.require_auth();
+        payer.require_auth();
+        if !env.storage().instance().has(&DataKey::Escalator(escalator.clone())) {
+            panic_with_error!(&env, ArbitrationError::Unauthorized);
+        }
+        let config = Self::_config(&env);
+
+        let mut candidates: Vec<Address> = Vec::new(&env);
+        for juror in Self::_jurors(&env).iter() {
+            if juror == subject {
+                continue;
+            }
+            if let Some(info) = Self::_juror(&env, &juror) {
+                if info.stake >= config.min_stake {
+                    candidates.push_back(juror);
+                }
+            }
+        }
+        if candidates.len() < config.jurors_per_case {
+            panic_with_error!(&env, ArbitrationError::NotEnoughJurors);
+        }
+        env.prng().shuffle(&mut candidates);
+        let panel = candidates.slice(0..config.jurors_per_case);
+        for juror in panel.iter() {
+            let mut info = Self::_juror(&env, &juror).unwrap();
+            info.active_cases += 1;
+            Self::_save_juror(&env, &juror, &info);
+        }
+
+        if config.case_fee > 0 {
+            Self::_stake_token(&env).transfer(&payer, &env.current_contract_address(), &config.case_fee);
+        }
+
+        let now = env.ledger().timestamp();
+        let counter: u64 = env.storage().instance().get(&DataKey::CaseCounter).unwrap_or(0);
+        let case_id = counter + 1;
+        let case = Case {
+            escalator: escalator.clone(),
+            subject: subject.clone(),
+            ref_id,
+            jurors: panel,
+            fee: config.case_fee,
+            commit_ends: now + config.commit_secs,
+            reveal_ends: now + config.commit_secs + config.reveal_secs,
+            votes_for: 0,
+            votes_against: 0,
+            status: CaseStatus::Voting,
+            claimant_wins: false,
+            ruling_delivered: false,
+            opened_at: now,
+        };
+        Self::_save_case(&env, case_id, &case);
+        env.storage().instance().set(&DataKey::CaseCounter, &case_id);
+
+        events::publish(&env, EVENT_TAG, CaseOpened { case_id, escalator, subject, ref_id });
+
+        case_id
+    }
+
+    pub fn commit_vote(env: Env, juror: Address, case_id: u64, commitment: BytesN<32>) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        juror.require_auth();
+        let case = Self::_load_case(&env, case_id);
+        if !case.jurors.contains(&juror) {
+            panic_with_error!(&env, ArbitrationError::NotOnPanel);
+        }
+        if env.ledger().timestamp() >= case.commit_ends {
+            panic_with_error!(&env, ArbitrationError::CommitPeriodEnded);
+        }
+        let _ttl_key = DataKey::Commitment(case_id, juror);
+        env.storage().persistent().set(&_ttl_key, &commitment);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    pub fn reveal_vote(env: Env, juror: Address, case_id: u64, claimant_wins: bool, salt: BytesN<32>) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        juror.require_auth();
+        let mut case = Self::_load_case(&env, case_id);
+        let now = env.ledger().timestamp();
+        if now < case.commit_ends || now >= case.reveal_ends {
+            panic_with_error!(&env, ArbitrationError::NotInRevealPeriod);
+        }
+        let vote_key = DataKey::Vote(case_id, juror.clone());
+        if env.storage().persistent().has(&vote_key) {
+            panic_with_error!(&env, ArbitrationError::AlreadyRevealed);
+        }
+        let commitment: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Commitment(case_id, juror.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, ArbitrationError::NoCommitment));
+        if Self::_commitment(&env, claimant_wins, &salt) != commitment {
+            panic_with_error!(&env, ArbitrationError::CommitmentMismatch);
+        }
+
+        env.storage().persistent().set(&vote_key, &claimant_wins);
+        env.storage().persistent().extend_ttl(&vote_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        if claimant_wins {
+            case.votes_for += 1;
+        } else {
+            case.votes_against += 1;
+        }
+        Self::_save_case(&env, case_id, &case);
+
+        events::publish(&env, EVENT_TAG, VoteRevealed { case_id, juror, claimant_wins });
+    }
+
+    /// Tally a case once the reveal period is over, settle juror rewards and
+    /// penalties and notify the escalator. A tie leaves the claimant
+    /// without a win. Anyone may call this.
+    pub fn finalize_case(env: Env, case_id: u64) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let mut case = Self::_load_case(&env, case_id);
+        if case.status != CaseStatus::Voting {
+            panic_with_error!(&env, ArbitrationError::CaseAlreadyRuled);
+        }
+        if env.ledger().timestamp() < case.reveal_ends {
+            panic_with_error!(&env, ArbitrationError::RevealPeriodActive);
+        }
+
+        let claimant_wins = case.votes_for > case.votes_against;
+        let config = Self::_config(&env);
+        let mut pot = case.fee;
+        let mut winners: Vec<Address> = Vec::new(&env);
+        for juror in case.jurors.iter() {
+            let mut info = match Self::_juror(&env, &juror) {
+                Some(info) => info,
+                None => continue,
+            };
+            info.active_cases = info.active_cases.saturating_sub(1);
+            info.cases_ruled += 1;
+
+            let vote: Option<bool> = env.storage().persistent().get(&DataKey::Vote(case_id, juror.clone()));
+            let with_majority = (case.votes_for != case.votes_against) && vote == Some(claimant_wins);
+            if with_majority {
+                winners.push_back(juror.clone());
+            } else if vote.is_none() || case.votes_for != case.votes_against {
+                let penalty = info.stake * config.penalty_bps as i128 / 10_000;
+                if penalty > 0 {
+                    info.stake -= penalty;
+                    pot += penalty;
+                    events::publish(&env, EVENT_TAG, JurorSlashed { case_id, juror: juror.clone(), amount: penalty });
+                }
+            }
+            Self::_save_juror(&env, &juror, &info);
+        }
+
+        // Majority jurors split the pot; with no majority it goes to the admin
+        let token = Self::_stake_token(&env);
+        if pot > 0 {
+            if winners.is_empty() {
+                let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+                token.transfer(&env.current_contract_address(), &admin, &pot);
+            } else {
+                let share = pot / winners.len() as i128;
+                let dust = pot - share * winners.len() as i128;
+                for (i, juror) in winners.iter().enumerate() {
+                    let amount = if i == 0 { share + dust } else { share };
+                    if amount > 0 {
+                        token.transfer(&env.current_contract_address(), &juror, &amount);
+                    }
+                }
+            }
+        }
+
+        case.status = CaseStatus::Ruled;
+        case.claimant_wins = claimant_wins;
+        Self::_save_case(&env, case_id, &case);
+
+        events::publish(&env, EVENT_TAG, CaseRuled { case_id, claimant_wins });
+
+        // The escalator rejecting the ruling must not undo the tally
+        Self::_deliver_ruling(&env, case_id, &mut case);
+
+        claimant_wins
+    }
+
+    /// Push a ruling the escalator failed to accept when the case was
+    /// finalized. Anyone may call this; returns whether it was accepted.
+    pub fn deliver_ruling(env: Env, case_id: u64) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let mut case = Self::_load_case(&env, case_id);
+        if case.status != CaseStatus::Ruled {
+            panic_with_error!(&env, ArbitrationError::CaseNotRuled);
+        }
+        if case.ruling_delivered {
+            panic_with_error!(&env, ArbitrationError::RulingAlreadyDelivered);
+        }
+        Self::_deliver_ruling(&env, case_id, &mut case)
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn get_case(env: Env, case_id: u64) -> Option<Case> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Case(case_id))
+    }
+
+    pub fn get_juror(env: Env, juror: Address) -> Option<Juror> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_juror(&env, &juror)
+    }
+
+    pub fn get_jurors(env: Env) -> Vec<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_jurors(&env)
+    }
+
+    pub fn get_config(env: Env) -> CourtConfig {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_config(&env)
+    }
+
+    /// Commitment a juror should submit for a vote, for client tooling
+    pub fn vote_commitment(env: Env, claimant_wins: bool, salt: BytesN<32>) -> BytesN<32> {
+        Self::_commitment(&env, claimant_wins, &salt)
+    }
+
+    pub fn get_case_count(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::CaseCounter).unwrap_or(0)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _require_admin(env: &Env, admin: &Address) {
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *admin != stored_admin {
+            panic_with_error!(&env, ArbitrationError::Unauthorized);
+        }
+    }
+
+    fn _validate_config(env: &Env, config: &CourtConfig) {
+        if config.min_stake <= 0 || config.case_fee < 0 {
+            panic_with_error!(&env, ArbitrationError::InvalidConfig);
+        }
+        if config.jurors_per_case == 0 || config.jurors_per_case > MAX_PANEL || config.jurors_per_case.is_multiple_of(2) {
+            panic_with_error!(&env, ArbitrationError::InvalidPanelSize);
+        }
+        if config.commit_secs == 0 || config.reveal_secs == 0 || config.penalty_bps > 10_000 {
+            panic_with_error!(&env, ArbitrationError::InvalidConfig);
+        }
+    }
+
+    fn _config(env: &Env) -> CourtConfig {
+        env.storage().instance().get(&DataKey::Config).unwrap()
+    }
+
+    fn _stake_token(env: &Env) -> token::Client<'_> {
+        let token: Address = env.storage().instance().get(&DataKey::StakeToken).unwrap();
+        token::Client::new(env, &token)
+    }
+
+    fn _jurors(env: &Env) -> Vec<Address> {
+        env.storage().instance().get(&DataKey::Jurors).unwrap_or(Vec::new(env))
+    }
+
+    fn _leave_pool(env: &Env, juror: &Address) {
+        let mut jurors = Self::_jurors(env);
+        if let Some(index) = jurors.first_index_of(juror) {
+            jurors.remove(index);
+            env.storage().instance().set(&DataKey::Jurors, &jurors);
+        }
+    }
+
+    fn _juror(env: &Env, juror: &Address) -> Option<Juror> {
+        env.storage().persistent().get(&DataKey::Juror(juror.clone()))
+    }
+
+    fn _save_juror(env: &Env, juror: &Address, info: &Juror) {
+        let _ttl_key = DataKey::Juror(juror.clone());
+        env.storage().persistent().set(&_ttl_key, info);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _load_case(env: &Env, case_id: u64) -> Case {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Case(case_id))
+            .unwrap_or_else(|| panic_with_error!(&env, ArbitrationError::CaseNotFound))
+    }
+
+    fn _save_case(env: &Env, case_id: u64, case: &Case) {
+        let _ttl_key = DataKey::Case(case_id);
+        env.storage().persistent().set(&_ttl_key, case);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    /// Call the escalator's `on_ruling` hook, recording on the case whether
+    /// it went through
+    fn _deliver_ruling(env: &Env, case_id: u64, case: &mut Case) -> bool {
+        let args = Vec::from_array(
+            env,
+            [
+                env.current_contract_address().into_val(env),
+                case_id.into_val(env),
+                case.subject.into_val(env),
+                case.ref_id.into_val(env),
+                case.claimant_wins.into_val(env),
+            ],
+        );
+        let delivered = matches!(
+            env.try_invoke_contract::<(), soroban_sdk::Error>(&case.escalator, &Symbol::new(env, "on_ruling"), args),
+            Ok(Ok(()))
+        );
+        if delivered {
+            case.ruling_delivered = true;
+            Self::_save_case(env, case_id, case);
+            events::publish(env, EVENT_TAG, RulingDelivered { case_id });
+        }
+        delivered
+    }
+
+    fn _commitment(env: &Env, claimant_wins: bool, salt: &BytesN<32>) -> BytesN<32> {
+        let mut preimage: Bytes = claimant_wins.to_xdr(env);
+        preimage.append(&Bytes::from(salt.clone()));
+        env.crypto().sha256(&preimage).into()
+    }
+}
+
+mod test;
@@ -0,0 +1,264 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl, testutils::{Address as _, Ledger}, token, Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+// Stand-in for an escalating contract (refund processor, reputation):
+// opens cases as itself and records the ruling it is sent
+#[contract]
+pub struct MockEscalator;
+
+#[contractimpl]
+impl MockEscalator {
+    pub fn escalate(env: Env, court: Address, payer: Address, subject: Address, ref_id: u64) -> u64 {
+        payer.require_auth();
+        env.invoke_contract(
+            &court,
+            &Symbol::new(&env, "open_case"),
+            Vec::from_array(
+                &env,
+                [
+                    env.current_contract_address().into_val(&env),
+                    payer.into_val(&env),
+                    subject.into_val(&env),
+                    ref_id.into_val(&env),
+                ],
+            ),
+        )
+    }
+
+    pub fn on_ruling(env: Env, court: Address, _case_id: u64, _subject: Address, ref_id: u64, claimant_wins: bool) {
+        court.require_auth();
+        if env.storage().instance().get(&Symbol::new(&env, "rejecting")).unwrap_or(false) {
+            panic!("ruling rejected");
+        }
+        env.storage().instance().set(&ref_id, &claimant_wins);
+    }
+
+    pub fn set_rejecting(env: Env, rejecting: bool) {
+        env.storage().instance().set(&Symbol::new(&env, "rejecting"), &rejecting);
+    }
+
+    pub fn ruling(env: Env, ref_id: u64) -> Option<bool> {
+        env.storage().instance().get(&ref_id)
+    }
+}
+
+struct Setup<'a> {
+    client: ArbitrationContractClient<'a>,
+    admin: Address,
+    token: Address,
+    escalator: MockEscalatorClient<'a>,
+}
+
+fn config() -> CourtConfig {
+    CourtConfig {
+        min_stake: 1_000,
+        jurors_per_case: 3,
+        commit_secs: 100,
+        reveal_secs: 100,
+        case_fee: 300,
+        penalty_bps: 1_000,
+    }
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let contract_id = env.register_contract(None, ArbitrationContract);
+    let client = ArbitrationContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    client.initialize(&admin, &token, &config());
+    let escalator_id = env.register_contract(None, MockEscalator);
+    client.set_escalator(&admin, &escalator_id, &true);
+    Setup { client, admin, token, escalator: MockEscalatorClient::new(env, &escalator_id) }
+}
+
+fn funded(env: &Env, token: &Address, amount: i128) -> Address {
+    let who = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&who, &amount);
+    who
+}
+
+fn salt(env: &Env, b: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[b; 32])
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    assert_eq!(s.client.get_config().jurors_per_case, 3);
+    assert_eq!(s.client.get_case_count(), 0);
+    assert_eq!(s.client.get_jurors().len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1201)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.initialize(&s.admin, &s.token, &config());
+}
+
+#[test]
+fn test_juror_staking_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let juror = funded(&env, &s.token, 5_000);
+
+    let mut even = config();
+    even.jurors_per_case = 4;
+    assert!(s.client.try_set_config(&s.admin, &even).is_err());
+
+    assert!(s.client.try_stake(&juror, &500).is_err());
+    s.client.stake(&juror, &1_500);
+    s.client.stake(&juror, &500);
+    assert_eq!(s.client.get_juror(&juror).unwrap().stake, 2_000);
+
+    // A partial withdrawal can't leave a stake below the minimum
+    assert!(s.client.try_withdraw_stake(&juror, &1_500).is_err());
+    s.client.withdraw_stake(&juror, &1_000);
+    s.client.withdraw_stake(&juror, &1_000);
+    assert!(s.client.get_juror(&juror).is_none());
+    assert_eq!(s.client.get_jurors().len(), 0);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&juror), 5_000);
+}
+
+#[test]
+fn test_commit_reveal_ruling_rewards_majority() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let balance = token::Client::new(&env, &s.token);
+    let claimant = funded(&env, &s.token, 1_000);
+
+    // The subject stakes too but must never sit on its own case
+    let subject = funded(&env, &s.token, 1_000);
+    s.client.stake(&subject, &1_000);
+    let jurors = [
+        funded(&env, &s.token, 1_000),
+        funded(&env, &s.token, 1_000),
+        funded(&env, &s.token, 1_000),
+    ];
+    for juror in jurors.iter() {
+        s.client.stake(juror, &1_000);
+    }
+
+    let unknown = Address::generate(&env);
+    assert!(s.client.try_open_case(&unknown, &claimant, &subject, &9).is_err());
+
+    let case_id = s.escalator.escalate(&s.client.address, &claimant, &subject, &42);
+    let case = s.client.get_case(&case_id).unwrap();
+    assert_eq!(case.jurors.len(), 3);
+    assert!(!case.jurors.contains(&subject));
+    assert_eq!(balance.balance(&claimant), 700);
+    assert!(s.client.try_withdraw_stake(&jurors[0], &1_000).is_err());
+
+    let votes = [true, true, false];
+    for (i, juror) in jurors.iter().enumerate() {
+        let commitment = s.client.vote_commitment(&votes[i], &salt(&env, i as u8));
+        s.client.commit_vote(juror, &case_id, &commitment);
+    }
+    assert!(s.client.try_commit_vote(&subject, &case_id, &salt(&env, 9)).is_err());
+    assert!(s.client.try_reveal_vote(&jurors[0], &case_id, &true, &salt(&env, 0)).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    // Revealing a different vote than committed fails
+    assert!(s.client.try_reveal_vote(&jurors[0], &case_id, &false, &salt(&env, 0)).is_err());
+    for (i, juror) in jurors.iter().enumerate() {
+        s.client.reveal_vote(juror, &case_id, &votes[i], &salt(&env, i as u8));
+    }
+    assert!(s.client.try_finalize_case(&case_id).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    assert!(s.client.finalize_case(&case_id));
+    assert_eq!(s.escalator.ruling(&42), Some(true));
+    assert!(s.client.try_finalize_case(&case_id).is_err());
+
+    // Fee 300 plus the dissenter's 100 penalty, split between two
+    assert_eq!(balance.balance(&jurors[0]), 200);
+    assert_eq!(balance.balance(&jurors[1]), 200);
+    assert_eq!(s.client.get_juror(&jurors[2]).unwrap().stake, 900);
+    let info = s.client.get_juror(&jurors[0]).unwrap();
+    assert_eq!(info.active_cases, 0);
+    assert_eq!(info.cases_ruled, 1);
+}
+
+#[test]
+fn test_non_revealers_slashed_and_tie_loses() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let balance = token::Client::new(&env, &s.token);
+    let claimant = funded(&env, &s.token, 1_000);
+    let subject = Address::generate(&env);
+    let jurors = [
+        funded(&env, &s.token, 1_000),
+        funded(&env, &s.token, 1_000),
+        funded(&env, &s.token, 1_000),
+    ];
+    for juror in jurors.iter() {
+        s.client.stake(juror, &1_000);
+    }
+
+    let case_id = s.escalator.escalate(&s.client.address, &claimant, &subject, &7);
+    s.client.commit_vote(&jurors[0], &case_id, &s.client.vote_commitment(&true, &salt(&env, 1)));
+    s.client.commit_vote(&jurors[1], &case_id, &s.client.vote_commitment(&false, &salt(&env, 2)));
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    s.client.reveal_vote(&jurors[0], &case_id, &true, &salt(&env, 1));
+    s.client.reveal_vote(&jurors[1], &case_id, &false, &salt(&env, 2));
+    env.ledger().with_mut(|li| li.timestamp += 100);
+
+    // One each way: the claimant loses, only the silent juror is slashed
+    assert!(!s.client.finalize_case(&case_id));
+    assert_eq!(s.escalator.ruling(&7), Some(false));
+    assert_eq!(s.client.get_juror(&jurors[0]).unwrap().stake, 1_000);
+    assert_eq!(s.client.get_juror(&jurors[1]).unwrap().stake, 1_000);
+    assert_eq!(s.client.get_juror(&jurors[2]).unwrap().stake, 900);
+    assert_eq!(balance.balance(&s.admin), 400);
+}
+
+#[test]
+fn test_failed_ruling_delivery_can_be_retried() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let claimant = funded(&env, &s.token, 1_000);
+    let subject = Address::generate(&env);
+    let juror = funded(&env, &s.token, 1_000);
+    s.client.set_config(&s.admin, &CourtConfig { jurors_per_case: 1, ..config() });
+    s.client.stake(&juror, &1_000);
+
+    let case_id = s.escalator.escalate(&s.client.address, &claimant, &subject, &7);
+    assert_eq!(
+        s.client.try_deliver_ruling(&case_id),
+        Err(Ok(soroban_sdk::Error::from_contract_error(ArbitrationError::CaseNotRuled as u32)))
+    );
+    s.client.commit_vote(&juror, &case_id, &s.client.vote_commitment(&true, &salt(&env, 1)));
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    s.client.reveal_vote(&juror, &case_id, &true, &salt(&env, 1));
+    env.ledger().with_mut(|li| li.timestamp += 100);
+
+    // The escalator turns the ruling down; the tally still stands
+    s.escalator.set_rejecting(&true);
+    assert!(s.client.finalize_case(&case_id));
+    let case = s.client.get_case(&case_id).unwrap();
+    assert_eq!(case.status, CaseStatus::Ruled);
+    assert!(!case.ruling_delivered);
+    assert_eq!(s.escalator.ruling(&7), None);
+    assert!(!s.client.deliver_ruling(&case_id));
+
+    s.escalator.set_rejecting(&false);
+    assert!(s.client.deliver_ruling(&case_id));
+    assert!(s.client.get_case(&case_id).unwrap().ruling_delivered);
+    assert_eq!(s.escalator.ruling(&7), Some(true));
+    assert_eq!(
+        s.client.try_deliver_ruling(&case_id),
+        Err(Ok(soroban_sdk::Error::from_contract_error(ArbitrationError::RulingAlreadyDelivered as u32)))
+    );
+}
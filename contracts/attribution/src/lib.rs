@@ -0,0 +1,373 @@
+//! PulsarTrack - Conversion Attribution (Soroban)
+//! Oracle-attested click and conversion records for campaigns on Stellar.
+//!
+//! Clicks and conversions are reported as hashes of the off-chain event,
+//! signed by a threshold of the configured oracle keys and relayed by
+//! anyone. Conversions may reference the click they came from, which must
+//! belong to the same campaign and publisher. Per-campaign and per-publisher
+//! counts feed performance-based payouts and refund eligibility.
+//!
+//! Events are published as ("attribution", action, version) with a typed
+//! payload (see the Events section):
+//! - "oracle_added": `OracleAdded`
+//! - "oracle_removed": `OracleRemoved`
+//! - "click_attested": `ClickAttested`
+//! - "conversion_attested": `ConversionAttested`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    xdr::ToXdr, Address, Bytes, BytesN, Env, Vec,
+};
+pub use pulsartrack_errors::AttributionError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum AttestationKind {
+    Click,
+    Conversion,
+}
+
+/// Event reported by the oracles
+#[contracttype]
+#[derive(Clone)]
+pub struct Attestation {
+    pub kind: AttestationKind,
+    pub event_hash: BytesN<32>, // hash of the off-chain click or conversion
+    pub campaign_id: u64,
+    pub publisher: Address,
+    pub click_hash: Option<BytesN<32>>, // click a conversion is attributed to
+    pub value: i128,                    // conversion value; 0 for clicks
+    pub occurred_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct OracleSignature {
+    pub public_key: BytesN<32>,
+    pub signature: BytesN<64>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AttestationRecord {
+    pub kind: AttestationKind,
+    pub campaign_id: u64,
+    pub publisher: Address,
+    pub value: i128,
+    pub occurred_at: u64,
+    pub recorded_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Default)]
+pub struct AttributionStats {
+    pub clicks: u64,
+    pub conversions: u64,
+    pub attributed_conversions: u64, // conversions linked to a recorded click
+    pub conversion_value: i128,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct OracleAdded {
+    pub public_key: BytesN<32>,
+}
+contract_event!(OracleAdded, "oracle_added");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct OracleRemoved {
+    pub public_key: BytesN<32>,
+}
+contract_event!(OracleRemoved, "oracle_removed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ClickAttested {
+    pub campaign_id: u64,
+    pub publisher: Address,
+    pub event_hash: BytesN<32>,
+}
+contract_event!(ClickAttested, "click_attested");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ConversionAttested {
+    pub campaign_id: u64,
+    pub publisher: Address,
+    pub event_hash: BytesN<32>,
+    pub value: i128,
+}
+contract_event!(ConversionAttested, "conversion_attested");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Oracles,
+    Threshold,
+    Event(BytesN<32>),
+    CampaignStats(u64),
+    PublisherStats(u64, Address),
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const MAX_ORACLES: u32 = 20;
+
+const EVENT_TAG: &str = "attribution";
+
+#[contract]
+pub struct AttributionContract;
+
+#[contractimpl]
+impl AttributionContract {
+    pub fn initialize(env: Env, admin: Address, threshold: u32) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, AttributionError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        if threshold == 0 {
+            panic_with_error!(&env, AttributionError::InvalidThreshold);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Threshold, &threshold);
+        env.storage().instance().set(&DataKey::Oracles, &Vec::<BytesN<32>>::new(&env));
+    }
+
+    pub fn add_oracle(env: Env, admin: Address, public_key: BytesN<32>) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+
+        let mut oracles = Self::get_oracles(env.clone());
+        if oracles.contains(&public_key) {
+            panic_with_error!(&env, AttributionError::AlreadyAnOracle);
+        }
+        if oracles.len() >= MAX_ORACLES {
+            panic_with_error!(&env, AttributionError::TooManyOracles);
+        }
+        oracles.push_back(public_key.clone());
+        env.storage().instance().set(&DataKey::Oracles, &oracles);
+
+        events::publish(&env, EVENT_TAG, OracleAdded { public_key });
+    }
+
+    pub fn remove_oracle(env: Env, admin: Address, public_key: BytesN<32>) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+
+        let mut oracles = Self::get_oracles(env.clone());
+        let index = oracles.first_index_of(&public_key).unwrap_or_else(|| panic_with_error!(&env, AttributionError::NotAnOracle));
+        oracles.remove(index);
+        env.storage().instance().set(&DataKey::Oracles, &oracles);
+
+        events::publish(&env, EVENT_TAG, OracleRemoved { public_key });
+    }
+
+    /// Number of distinct oracle signatures an attestation needs (admin only)
+    pub fn set_threshold(env: Env, admin: Address, threshold: u32) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        if threshold == 0 {
+            panic_with_error!(&env, AttributionError::InvalidThreshold);
+        }
+        env.storage().instance().set(&DataKey::Threshold, &threshold);
+    }
+
+    /// Record a click or conversion signed by enough oracles. Anyone may
+    /// relay it; each event hash is accepted once.
+    pub fn submit_attestation(env: Env, attestation: Attestation, signatures: Vec<OracleSignature>) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let event_key = DataKey::Event(attestation.event_hash.clone());
+        if env.storage().persistent().has(&event_key) {
+            panic_with_error!(&env, AttributionError::AlreadyRecorded);
+        }
+        Self::_verify_signatures(&env, &attestation, &signatures);
+
+        let is_click = attestation.kind == AttestationKind::Click;
+        if is_click && (attestation.click_hash.is_some() || attestation.value != 0) {
+            panic_with_error!(&env, AttributionError::InvalidClick);
+        }
+        if attestation.value < 0 {
+            panic_with_error!(&env, AttributionError::InvalidValue);
+        }
+        let attributed = match attestation.click_hash.clone() {
+            Some(click_hash) => {
+                let click: AttestationRecord = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Event(click_hash))
+                    .unwrap_or_else(|| panic_with_error!(&env, AttributionError::ClickNotFound));
+                if click.kind != AttestationKind::Click
+                    || click.campaign_id != attestation.campaign_id
+                    || click.publisher != attestation.publisher
+                {
+                    panic_with_error!(&env, AttributionError::ClickMismatch);
+                }
+                true
+            }
+            None => false,
+        };
+
+        let record = AttestationRecord {
+            kind: attestation.kind.clone(),
+            campaign_id: attestation.campaign_id,
+            publisher: attestation.publisher.clone(),
+            value: attestation.value,
+            occurred_at: attestation.occurred_at,
+            recorded_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&event_key, &record);
+        env.storage().persistent().extend_ttl(&event_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        for stats_key in [
+            DataKey::CampaignStats(attestation.campaign_id),
+            DataKey::PublisherStats(attestation.campaign_id, attestation.publisher.clone()),
+        ] {
+            let mut stats: AttributionStats = env.storage().persistent().get(&stats_key).unwrap_or_default();
+            if is_click {
+                stats.clicks += 1;
+            } else {
+                stats.conversions += 1;
+                stats.conversion_value += attestation.value;
+                if attributed {
+                    stats.attributed_conversions += 1;
+                }
+            }
+            env.storage().persistent().set(&stats_key, &stats);
+            env.storage().persistent().extend_ttl(&stats_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+
+        if is_click {
+            events::publish(&env, EVENT_TAG, ClickAttested { campaign_id: attestation.campaign_id, publisher: attestation.publisher, event_hash: attestation.event_hash });
+        } else {
+            events::publish(&env, EVENT_TAG, ConversionAttested { campaign_id: attestation.campaign_id, publisher: attestation.publisher, event_hash: attestation.event_hash, value: attestation.value });
+        }
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    /// Bytes the oracles sign for an attestation
+    pub fn attestation_payload(env: Env, attestation: Attestation) -> Bytes {
+        Self::_payload(&env, &attestation)
+    }
+
+    pub fn get_attestation(env: Env, event_hash: BytesN<32>) -> Option<AttestationRecord> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Event(event_hash))
+    }
+
+    pub fn get_campaign_stats(env: Env, campaign_id: u64) -> AttributionStats {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::CampaignStats(campaign_id))
+            .unwrap_or_default()
+    }
+
+    pub fn get_publisher_stats(env: Env, campaign_id: u64, publisher: Address) -> AttributionStats {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::PublisherStats(campaign_id, publisher))
+            .unwrap_or_default()
+    }
+
+    /// Conversions per click for a campaign, in basis points
+    pub fn conversion_rate_bps(env: Env, campaign_id: u64) -> u32 {
+        let stats = Self::get_campaign_stats(env, campaign_id);
+        if stats.clicks == 0 {
+            return 0;
+        }
+        (stats.conversions * 10_000 / stats.clicks).min(u32::MAX as u64) as u32
+    }
+
+    /// Whether a campaign has reached a conversion target, for payout and
+    /// refund-eligibility checks by other contracts
+    pub fn meets_conversion_target(env: Env, campaign_id: u64, target: u64) -> bool {
+        Self::get_campaign_stats(env, campaign_id).conversions >= target
+    }
+
+    pub fn get_oracles(env: Env) -> Vec<BytesN<32>> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .instance()
+            .get(&DataKey::Oracles)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_threshold(env: Env) -> u32 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::Threshold).unwrap_or(1)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _require_admin(env: &Env, admin: &Address) {
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *admin != stored_admin {
+            panic_with_error!(&env, AttributionError::Unauthorized);
+        }
+    }
+
+    fn _payload(env: &Env, attestation: &Attestation) -> Bytes {
+        (env.current_contract_address(), attestation.clone()).to_xdr(env)
+    }
+
+    /// Require `threshold` valid signatures from distinct oracles. An
+    /// invalid signature aborts the call.
+    fn _verify_signatures(env: &Env, attestation: &Attestation, signatures: &Vec<OracleSignature>) {
+        let oracles: Vec<BytesN<32>> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Oracles)
+            .unwrap_or(Vec::new(env));
+        let threshold: u32 = env.storage().instance().get(&DataKey::Threshold).unwrap_or(1);
+
+        let payload = Self::_payload(env, attestation);
+        let mut signers: Vec<BytesN<32>> = Vec::new(env);
+        for sig in signatures.iter() {
+            if !oracles.contains(&sig.public_key) {
+                panic_with_error!(&env, AttributionError::UnknownOracle);
+            }
+            if signers.contains(&sig.public_key) {
+                panic_with_error!(&env, AttributionError::DuplicateSigner);
+            }
+            env.crypto().ed25519_verify(&sig.public_key, &payload, &sig.signature);
+            signers.push_back(sig.public_key);
+        }
+        if signers.len() < threshold {
+            panic_with_error!(&env, AttributionError::NotEnoughSignatures);
+        }
+    }
+}
+
+mod test;
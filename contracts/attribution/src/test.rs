@@ -0,0 +1,143 @@
+#![cfg(test)]
+use super::*;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env};
+
+fn setup(env: &Env, threshold: u32) -> (AttributionContractClient<'_>, Address, [SigningKey; 3]) {
+    let contract_id = env.register_contract(None, AttributionContract);
+    let client = AttributionContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin, &threshold);
+
+    let keys = [1u8, 2, 3].map(|seed| SigningKey::from_bytes(&[seed; 32]));
+    for key in keys.iter() {
+        client.add_oracle(&admin, &BytesN::from_array(env, &key.verifying_key().to_bytes()));
+    }
+    (client, admin, keys)
+}
+
+fn sign(env: &Env, client: &AttributionContractClient, attestation: &Attestation, key: &SigningKey) -> OracleSignature {
+    let payload = client.attestation_payload(attestation);
+    let mut message = [0u8; 512];
+    let len = payload.len() as usize;
+    payload.copy_into_slice(&mut message[..len]);
+    OracleSignature {
+        public_key: BytesN::from_array(env, &key.verifying_key().to_bytes()),
+        signature: BytesN::from_array(env, &key.sign(&message[..len]).to_bytes()),
+    }
+}
+
+fn click(env: &Env, seed: u8, campaign_id: u64, publisher: &Address) -> Attestation {
+    Attestation {
+        kind: AttestationKind::Click,
+        event_hash: BytesN::from_array(env, &[seed; 32]),
+        campaign_id,
+        publisher: publisher.clone(),
+        click_hash: None,
+        value: 0,
+        occurred_at: 1_000,
+    }
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AttributionContract);
+    let client = AttributionContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &2);
+    assert_eq!(client.get_threshold(), 2);
+    assert_eq!(client.get_oracles().len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1301)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AttributionContract);
+    let client = AttributionContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &1);
+    client.initialize(&admin, &1);
+}
+
+#[test]
+fn test_threshold_signed_clicks_and_conversions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, [a, b, c]) = setup(&env, 2);
+
+    let publisher = Address::generate(&env);
+    let first = click(&env, 1, 7, &publisher);
+    let second = click(&env, 2, 7, &publisher);
+
+    // One oracle is not enough, and the same oracle can't sign twice
+    assert!(client.try_submit_attestation(&first, &vec![&env, sign(&env, &client, &first, &a)]).is_err());
+    assert!(client
+        .try_submit_attestation(&first, &vec![&env, sign(&env, &client, &first, &a), sign(&env, &client, &first, &a)])
+        .is_err());
+    // A signature over a different event doesn't carry over
+    assert!(client
+        .try_submit_attestation(&first, &vec![&env, sign(&env, &client, &first, &a), sign(&env, &client, &second, &b)])
+        .is_err());
+
+    client.submit_attestation(&first, &vec![&env, sign(&env, &client, &first, &a), sign(&env, &client, &first, &b)]);
+    client.submit_attestation(&second, &vec![&env, sign(&env, &client, &second, &b), sign(&env, &client, &second, &c)]);
+    assert!(client
+        .try_submit_attestation(&first, &vec![&env, sign(&env, &client, &first, &a), sign(&env, &client, &first, &c)])
+        .is_err());
+
+    let conversion = Attestation {
+        kind: AttestationKind::Conversion,
+        event_hash: BytesN::from_array(&env, &[9u8; 32]),
+        campaign_id: 7,
+        publisher: publisher.clone(),
+        click_hash: Some(first.event_hash.clone()),
+        value: 2_500,
+        occurred_at: 1_100,
+    };
+    client.submit_attestation(&conversion, &vec![&env, sign(&env, &client, &conversion, &a), sign(&env, &client, &conversion, &c)]);
+
+    // A conversion can't be pinned on another publisher's click
+    let other = Address::generate(&env);
+    let stolen = Attestation {
+        event_hash: BytesN::from_array(&env, &[10u8; 32]),
+        publisher: other.clone(),
+        click_hash: Some(second.event_hash.clone()),
+        ..conversion.clone()
+    };
+    assert!(client
+        .try_submit_attestation(&stolen, &vec![&env, sign(&env, &client, &stolen, &a), sign(&env, &client, &stolen, &b)])
+        .is_err());
+
+    let stats = client.get_campaign_stats(&7);
+    assert_eq!(stats.clicks, 2);
+    assert_eq!(stats.conversions, 1);
+    assert_eq!(stats.attributed_conversions, 1);
+    assert_eq!(stats.conversion_value, 2_500);
+    assert_eq!(client.get_publisher_stats(&7, &publisher).conversions, 1);
+    assert_eq!(client.get_publisher_stats(&7, &other).conversions, 0);
+    assert_eq!(client.conversion_rate_bps(&7), 5_000);
+    assert!(client.meets_conversion_target(&7, &1));
+    assert!(!client.meets_conversion_target(&7, &2));
+}
+
+#[test]
+fn test_removed_oracle_signatures_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, [a, _b, _c]) = setup(&env, 1);
+
+    let publisher = Address::generate(&env);
+    let event = click(&env, 1, 3, &publisher);
+    client.remove_oracle(&admin, &BytesN::from_array(&env, &a.verifying_key().to_bytes()));
+    assert_eq!(client.get_oracles().len(), 2);
+    assert!(client.try_submit_attestation(&event, &vec![&env, sign(&env, &client, &event, &a)]).is_err());
+    assert!(client.get_attestation(&event.event_hash).is_none());
+}
@@ -1,16 +1,18 @@
 //! PulsarTrack - Auction Engine (Soroban)
 //! Real-time bidding (RTB) auction system for ad impressions on Stellar.
 //!
-//! Events:
-//! - ("auction", "created"): [auction_id: u64, publisher: Address]
-//! - ("bid", "placed"): [auction_id: u64, bidder: Address, amount: i128]
-//! - ("auction", "settle"): [auction_id: u64, winner: Option<Address>, amount: Option<i128>]
+//! Events are published as ("auctions", action, version) with a typed payload:
+//! - "auction_created": `AuctionCreated`
+//! - "bid_placed": `BidPlaced`
+//! - "auction_settled": `AuctionSettled`
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     token, Address, Env, String,
 };
+pub use pulsartrack_errors::AuctionEngineError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -46,6 +48,32 @@ pub struct Bid {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct AuctionCreated {
+    pub auction_id: u64,
+    pub publisher: Address,
+}
+contract_event!(AuctionCreated, "auction_created");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BidPlaced {
+    pub auction_id: u64,
+    pub bidder: Address,
+    pub amount: i128,
+}
+contract_event!(BidPlaced, "bid_placed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AuctionSettled {
+    pub auction_id: u64,
+    pub winner: Option<Address>,
+    pub winning_bid: Option<i128>,
+}
+contract_event!(AuctionSettled, "auction_settled");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -63,6 +91,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 17_280;
 const PERSISTENT_BUMP_AMOUNT: u32 = 86_400;
 
+const EVENT_TAG: &str = "auctions";
+
 #[contract]
 pub struct AuctionEngineContract;
 
@@ -71,7 +101,7 @@ impl AuctionEngineContract {
     pub fn initialize(env: Env, admin: Address, token: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, AuctionEngineError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -113,10 +143,7 @@ impl AuctionEngineContract {
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
         env.storage().instance().set(&DataKey::AuctionCounter, &auction_id);
 
-        env.events().publish(
-            (symbol_short!("auction"), symbol_short!("created")),
-            (auction_id, publisher),
-        );
+        events::publish(&env, EVENT_TAG, AuctionCreated { auction_id, publisher });
 
         auction_id
     }
@@ -129,26 +156,26 @@ impl AuctionEngineContract {
             .storage()
             .persistent()
             .get(&DataKey::Auction(auction_id))
-            .expect("auction not found");
+            .unwrap_or_else(|| panic_with_error!(&env, AuctionEngineError::AuctionNotFound));
 
         if auction.status != AuctionStatus::Open {
-            panic!("auction not open");
+            panic_with_error!(&env, AuctionEngineError::AuctionNotOpen);
         }
 
         let now = env.ledger().timestamp();
         if now > auction.end_time {
-            panic!("auction ended");
+            panic_with_error!(&env, AuctionEngineError::AuctionEnded);
         }
 
         if amount < auction.floor_price {
-            panic!("bid below floor price");
+            panic_with_error!(&env, AuctionEngineError::BidBelowFloorPrice);
         }
 
         // Check if higher than current best
         let current_high: Option<i128> = env.storage().persistent().get(&DataKey::HighestBid(auction_id));
         if let Some(high) = current_high {
             if amount <= high {
-                panic!("bid too low");
+                panic_with_error!(&env, AuctionEngineError::BidTooLow);
             }
         }
 
@@ -180,10 +207,7 @@ impl AuctionEngineContract {
         env.storage().persistent().set(&_ttl_key, &auction);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("bid"), symbol_short!("placed")),
-            (auction_id, bidder, amount),
-        );
+        events::publish(&env, EVENT_TAG, BidPlaced { auction_id, bidder, amount });
     }
 
     pub fn settle_auction(env: Env, caller: Address, auction_id: u64) {
@@ -192,16 +216,16 @@ impl AuctionEngineContract {
             .storage()
             .persistent()
             .get(&DataKey::Auction(auction_id))
-            .expect("auction not found");
+            .unwrap_or_else(|| panic_with_error!(&env, AuctionEngineError::AuctionNotFound));
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if caller != auction.publisher && caller != admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, AuctionEngineError::Unauthorized);
         }
 
         let now = env.ledger().timestamp();
         if now < auction.end_time && caller != admin {
-            panic!("auction still running");
+            panic_with_error!(&env, AuctionEngineError::AuctionStillRunning);
         }
 
         auction.status = if auction.winning_bid.is_some() {
@@ -225,10 +249,7 @@ impl AuctionEngineContract {
         env.storage().persistent().set(&_ttl_key, &auction);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("auction"), symbol_short!("settle")),
-            (auction_id, auction.winner, auction.winning_bid),
-        );
+        events::publish(&env, EVENT_TAG, AuctionSettled { auction_id, winner: auction.winner, winning_bid: auction.winning_bid });
     }
 
     pub fn get_auction(env: Env, auction_id: u64) -> Option<Auction> {
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
-    Address, Env, String, Vec,
+    contract, contractimpl, contracttype, panic_with_error,
+    Address, Env, String,
 };
+pub use pulsartrack_errors::AudienceSegmentsError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone)]
@@ -30,6 +32,14 @@ pub struct SegmentMembership {
     pub score: u32,  // relevance score 0-1000
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct SegmentCreated {
+    pub segment_id: u64,
+    pub creator: Address,
+}
+contract_event!(SegmentCreated, "segment_created");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -44,6 +54,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 34_560;
 const PERSISTENT_BUMP_AMOUNT: u32 = 259_200;
 
+const EVENT_TAG: &str = "segments";
+
 #[contract]
 pub struct AudienceSegmentsContract;
 
@@ -52,7 +64,7 @@ impl AudienceSegmentsContract {
     pub fn initialize(env: Env, admin: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, AudienceSegmentsError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -90,10 +102,7 @@ impl AudienceSegmentsContract {
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
         env.storage().instance().set(&DataKey::SegmentCounter, &segment_id);
 
-        env.events().publish(
-            (symbol_short!("segment"), symbol_short!("created")),
-            (segment_id, creator),
-        );
+        events::publish(&env, EVENT_TAG, SegmentCreated { segment_id, creator });
 
         segment_id
     }
@@ -107,15 +116,15 @@ impl AudienceSegmentsContract {
             .storage()
             .persistent()
             .get(&DataKey::Segment(segment_id))
-            .expect("segment not found");
+            .unwrap_or_else(|| panic_with_error!(&env, AudienceSegmentsError::SegmentNotFound));
 
         // Either admin or segment creator can add members
         if admin != stored_admin && admin != segment.creator {
-            panic!("unauthorized");
+            panic_with_error!(&env, AudienceSegmentsError::Unauthorized);
         }
 
         if env.storage().persistent().has(&DataKey::Membership(segment_id, member.clone())) {
-            panic!("already a member");
+            panic_with_error!(&env, AudienceSegmentsError::AlreadyAMember);
         }
 
         let membership = SegmentMembership {
@@ -144,10 +153,10 @@ impl AudienceSegmentsContract {
             .storage()
             .persistent()
             .get(&DataKey::Segment(segment_id))
-            .expect("segment not found");
+            .unwrap_or_else(|| panic_with_error!(&env, AudienceSegmentsError::SegmentNotFound));
 
         if admin != stored_admin && admin != segment.creator {
-            panic!("unauthorized");
+            panic_with_error!(&env, AudienceSegmentsError::Unauthorized);
         }
 
         env.storage().persistent().remove(&DataKey::Membership(segment_id, member));
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Env,
 };
+pub use pulsartrack_errors::BudgetOptimizerError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone)]
@@ -44,6 +46,14 @@ pub struct OptimizationLog {
 
 use soroban_sdk::String;
 
+#[contracttype]
+#[derive(Clone)]
+pub struct BudgetOptimized {
+    pub campaign_id: u64,
+    pub daily_budget: i128,
+}
+contract_event!(BudgetOptimized, "budget_optimized");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -58,6 +68,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "budget";
+
 #[contract]
 pub struct BudgetOptimizerContract;
 
@@ -66,7 +78,7 @@ impl BudgetOptimizerContract {
     pub fn initialize(env: Env, admin: Address, oracle: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, BudgetOptimizerError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -87,7 +99,7 @@ impl BudgetOptimizerContract {
         advertiser.require_auth();
 
         if daily_budget > total_budget {
-            panic!("daily budget exceeds total");
+            panic_with_error!(&env, BudgetOptimizerError::DailyBudgetExceedsTotal);
         }
 
         let allocation = BudgetAllocation {
@@ -119,14 +131,14 @@ impl BudgetOptimizerContract {
         oracle.require_auth();
         let stored_oracle: Address = env.storage().instance().get(&DataKey::OracleAddress).unwrap();
         if oracle != stored_oracle {
-            panic!("unauthorized");
+            panic_with_error!(&env, BudgetOptimizerError::Unauthorized);
         }
 
         let mut allocation: BudgetAllocation = env
             .storage()
             .persistent()
             .get(&DataKey::Allocation(campaign_id))
-            .expect("allocation not found");
+            .unwrap_or_else(|| panic_with_error!(&env, BudgetOptimizerError::AllocationNotFound));
 
         let old_daily = allocation.daily_budget;
 
@@ -164,10 +176,7 @@ impl BudgetOptimizerContract {
         env.storage().persistent().set(&_ttl_key, &(count + 1));
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("budget"), symbol_short!("optimized")),
-            (campaign_id, capped_daily),
-        );
+        events::publish(&env, EVENT_TAG, BudgetOptimized { campaign_id, daily_budget: capped_daily });
     }
 
     pub fn record_spend(env: Env, admin: Address, campaign_id: u64, amount: i128) {
@@ -178,7 +187,7 @@ impl BudgetOptimizerContract {
             .storage()
             .persistent()
             .get(&DataKey::Allocation(campaign_id))
-            .expect("allocation not found");
+            .unwrap_or_else(|| panic_with_error!(&env, BudgetOptimizerError::AllocationNotFound));
 
         allocation.spent_today += amount;
         allocation.spent_total += amount;
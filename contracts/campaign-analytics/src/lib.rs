@@ -3,9 +3,10 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error, symbol_short,
     Address, Env,
 };
+pub use pulsartrack_errors::CampaignAnalyticsError;
 
 #[contracttype]
 #[derive(Clone)]
@@ -66,7 +67,7 @@ impl CampaignAnalyticsContract {
     pub fn initialize(env: Env, admin: Address, oracle: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, CampaignAnalyticsError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -87,7 +88,7 @@ impl CampaignAnalyticsContract {
         oracle.require_auth();
         let stored_oracle: Address = env.storage().instance().get(&DataKey::OracleAddress).unwrap();
         if oracle != stored_oracle {
-            panic!("unauthorized");
+            panic_with_error!(&env, CampaignAnalyticsError::Unauthorized);
         }
 
         let snapshot = CampaignSnapshot {
@@ -129,7 +130,7 @@ impl CampaignAnalyticsContract {
         oracle.require_auth();
         let stored_oracle: Address = env.storage().instance().get(&DataKey::OracleAddress).unwrap();
         if oracle != stored_oracle {
-            panic!("unauthorized");
+            panic_with_error!(&env, CampaignAnalyticsError::Unauthorized);
         }
 
         let funnel = ConversionFunnel {
@@ -161,7 +162,7 @@ impl CampaignAnalyticsContract {
         oracle.require_auth();
         let stored_oracle: Address = env.storage().instance().get(&DataKey::OracleAddress).unwrap();
         if oracle != stored_oracle {
-            panic!("unauthorized");
+            panic_with_error!(&env, CampaignAnalyticsError::Unauthorized);
         }
 
         let metrics = RetentionMetrics {
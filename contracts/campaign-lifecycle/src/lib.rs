@@ -1,16 +1,18 @@
 //! PulsarTrack - Campaign Lifecycle (Soroban)
 //! Manages ad campaign state transitions and lifecycle events on Stellar.
 //!
-//! Events:
-//! - ("lifecycle", "transit"): [campaign_id: u64]
-//! - ("campaign", "pause"): [campaign_id: u64, actor: Address]
-//! - ("campaign", "resume"): [campaign_id: u64, actor: Address]
+//! Events are published as ("lifecycle", action, version) with a typed payload:
+//! - "campaign_resumed": `CampaignResumed`
+//! - "campaign_paused": `CampaignPaused`
+//! - "campaign_transitioned": `CampaignTransitioned`
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Env, String,
 };
+pub use pulsartrack_errors::CampaignLifecycleError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -53,6 +55,29 @@ pub struct StateTransition {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct CampaignResumed {
+    pub campaign_id: u64,
+    pub actor: Address,
+}
+contract_event!(CampaignResumed, "campaign_resumed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct CampaignPaused {
+    pub campaign_id: u64,
+    pub actor: Address,
+}
+contract_event!(CampaignPaused, "campaign_paused");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct CampaignTransitioned {
+    pub campaign_id: u64,
+}
+contract_event!(CampaignTransitioned, "campaign_transitioned");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -67,6 +92,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "lifecycle";
+
 #[contract]
 pub struct CampaignLifecycleContract;
 
@@ -75,7 +102,7 @@ impl CampaignLifecycleContract {
     pub fn initialize(env: Env, admin: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, CampaignLifecycleError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -138,18 +165,18 @@ impl CampaignLifecycleContract {
             .storage()
             .persistent()
             .get(&DataKey::Lifecycle(campaign_id))
-            .expect("lifecycle not found");
+            .unwrap_or_else(|| panic_with_error!(&env, CampaignLifecycleError::LifecycleNotFound));
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
 
         // Only advertiser or admin can transition
         if actor != lifecycle.advertiser && actor != admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, CampaignLifecycleError::Unauthorized);
         }
 
         // Validate state transition
         let old_state = lifecycle.state.clone();
-        Self::_validate_transition(&old_state, &new_state);
+        Self::_validate_transition(&env, &old_state, &new_state);
 
         // Apply state
         let now = env.ledger().timestamp();
@@ -157,19 +184,13 @@ impl CampaignLifecycleContract {
             LifecycleState::Active => {
                 lifecycle.activated_at = Some(now);
                 if old_state == LifecycleState::Paused {
-                    env.events().publish(
-                        (symbol_short!("campaign"), symbol_short!("resume")),
-                        (campaign_id, actor.clone()),
-                    );
+                    events::publish(&env, EVENT_TAG, CampaignResumed { campaign_id, actor: actor.clone() });
                 }
             }
             LifecycleState::Paused => {
                 lifecycle.paused_at = Some(now);
                 lifecycle.pause_count += 1;
-                env.events().publish(
-                    (symbol_short!("campaign"), symbol_short!("pause")),
-                    (campaign_id, actor.clone()),
-                );
+                events::publish(&env, EVENT_TAG, CampaignPaused { campaign_id, actor: actor.clone() });
             }
             LifecycleState::Completed => {
                 lifecycle.completed_at = Some(now);
@@ -217,10 +238,7 @@ impl CampaignLifecycleContract {
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("lifecycle"), symbol_short!("transit")),
-            campaign_id,
-        );
+        events::publish(&env, EVENT_TAG, CampaignTransitioned { campaign_id });
     }
 
     pub fn extend_campaign(env: Env, advertiser: Address, campaign_id: u64, extra_ledgers: u32) {
@@ -231,10 +249,10 @@ impl CampaignLifecycleContract {
             .storage()
             .persistent()
             .get(&DataKey::Lifecycle(campaign_id))
-            .expect("lifecycle not found");
+            .unwrap_or_else(|| panic_with_error!(&env, CampaignLifecycleError::LifecycleNotFound));
 
         if lifecycle.advertiser != advertiser {
-            panic!("unauthorized");
+            panic_with_error!(&env, CampaignLifecycleError::Unauthorized);
         }
 
         lifecycle.current_end_ledger += extra_ledgers;
@@ -271,7 +289,7 @@ impl CampaignLifecycleContract {
             .unwrap_or(0)
     }
 
-    fn _validate_transition(from: &LifecycleState, to: &LifecycleState) {
+    fn _validate_transition(env: &Env, from: &LifecycleState, to: &LifecycleState) {
         let valid = match from {
             LifecycleState::Draft => matches!(to, LifecycleState::PendingReview | LifecycleState::Cancelled),
             LifecycleState::PendingReview => matches!(to, LifecycleState::Active | LifecycleState::Rejected | LifecycleState::Cancelled),
@@ -280,7 +298,7 @@ impl CampaignLifecycleContract {
             _ => false,
         };
         if !valid {
-            panic!("invalid state transition");
+            panic_with_error!(&env, CampaignLifecycleError::InvalidStateTransition);
         }
     }
 }
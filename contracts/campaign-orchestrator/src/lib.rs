@@ -4,9 +4,12 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
-    token, Address, Env, String,
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractimpl, contracttype, panic_with_error, symbol_short,
+    token, Address, Env, IntoVal, String, Symbol, Vec,
 };
+pub use pulsartrack_errors::CampaignOrchestratorError;
+use pulsartrack_events::{self as events, contract_event};
 
 // ============================================================
 // Data Types
@@ -82,6 +85,50 @@ pub struct CampaignMetrics {
     pub completion_rate: u32,
 }
 
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct CampaignCreated {
+    pub campaign_id: u64,
+    pub advertiser: Address,
+    pub budget: i128,
+}
+contract_event!(CampaignCreated, "campaign_created");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ViewRecorded {
+    pub campaign_id: u64,
+    pub publisher: Address,
+}
+contract_event!(ViewRecorded, "view_recorded");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct CampaignCancelled {
+    pub campaign_id: u64,
+    pub refund: i128,
+}
+contract_event!(CampaignCancelled, "campaign_cancelled");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherVerified {
+    pub publisher: Address,
+}
+contract_event!(PublisherVerified, "publisher_verified");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PlacementScheduled {
+    pub campaign_id: u64,
+    pub token_id: u64,
+}
+contract_event!(PlacementScheduled, "placement_scheduled");
+
 // ============================================================
 // Storage Keys
 // ============================================================
@@ -99,6 +146,12 @@ pub enum DataKey {
     Publisher(Address),
     AdvertiserStats(Address),
     DailyViews(u64, u64),
+    PlacementNft,
+    CampaignPlacement(u64), // campaign_id -> placement token_id
+    Pacing,
+    Analytics,
+    Verification,
+    Registry,
 }
 
 // ============================================================
@@ -110,6 +163,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "orchestrator";
+
 #[contract]
 pub struct CampaignOrchestratorContract;
 
@@ -119,7 +174,7 @@ impl CampaignOrchestratorContract {
     pub fn initialize(env: Env, admin: Address, token_address: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, CampaignOrchestratorError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -170,13 +225,13 @@ impl CampaignOrchestratorContract {
             .storage()
             .instance()
             .get(&DataKey::CampaignType(campaign_type))
-            .expect("campaign type not found");
+            .unwrap_or_else(|| panic_with_error!(&env, CampaignOrchestratorError::CampaignTypeNotFound));
 
         if budget < campaign_type_data.min_budget {
-            panic!("budget too low");
+            panic_with_error!(&env, CampaignOrchestratorError::BudgetTooLow);
         }
         if duration < campaign_type_data.min_duration || duration > campaign_type_data.max_duration {
-            panic!("invalid duration");
+            panic_with_error!(&env, CampaignOrchestratorError::InvalidDuration);
         }
 
         let counter: u64 = env
@@ -246,10 +301,7 @@ impl CampaignOrchestratorContract {
         // Update advertiser stats
         Self::_update_advertiser_stats(&env, &advertiser, campaign_id, budget);
 
-        env.events().publish(
-            (symbol_short!("campaign"), symbol_short!("created")),
-            (campaign_id, advertiser, budget),
-        );
+        events::publish(&env, EVENT_TAG, CampaignCreated { campaign_id, advertiser, budget });
 
         campaign_id
     }
@@ -263,31 +315,31 @@ impl CampaignOrchestratorContract {
             .storage()
             .persistent()
             .get(&DataKey::Campaign(campaign_id))
-            .expect("campaign not found");
+            .unwrap_or_else(|| panic_with_error!(&env, CampaignOrchestratorError::CampaignNotFound));
 
         // Verify publisher
         let publisher_data: VerifiedPublisher = env
             .storage()
             .persistent()
             .get(&DataKey::Publisher(publisher.clone()))
-            .expect("publisher not verified");
+            .unwrap_or_else(|| panic_with_error!(&env, CampaignOrchestratorError::PublisherNotVerified));
 
         if !publisher_data.verified {
-            panic!("publisher not verified");
+            panic_with_error!(&env, CampaignOrchestratorError::PublisherNotVerified);
         }
 
         // Check campaign is active
         match campaign.status {
             CampaignStatus::Active => {}
-            _ => panic!("campaign not active"),
+            _ => panic_with_error!(&env, CampaignOrchestratorError::CampaignNotActive),
         }
 
         if env.ledger().sequence() > campaign.end_ledger {
-            panic!("campaign expired");
+            panic_with_error!(&env, CampaignOrchestratorError::CampaignExpired);
         }
 
         if campaign.remaining_budget < campaign.cost_per_view {
-            panic!("insufficient budget");
+            panic_with_error!(&env, CampaignOrchestratorError::InsufficientBudget);
         }
 
         // Check daily view limit
@@ -296,17 +348,30 @@ impl CampaignOrchestratorContract {
         let daily_views: u64 = env.storage().temporary().get(&daily_key).unwrap_or(0);
 
         if daily_views >= campaign.daily_view_limit {
-            panic!("daily view limit reached");
+            panic_with_error!(&env, CampaignOrchestratorError::DailyViewLimitReached);
         }
 
-        // Transfer payment to publisher
-        let token_addr: Address = env.storage().instance().get(&DataKey::TokenAddress).unwrap();
-        let token_client = token::Client::new(&env, &token_addr);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &publisher,
-            &campaign.cost_per_view,
-        );
+        // Hold spend back when the pacing controller says the campaign is
+        // over its hourly or daily budget
+        if let Some(pacing) = Self::_pacing(&env) {
+            let allowed: bool = env.invoke_contract(
+                &pacing,
+                &Symbol::new(&env, "consume"),
+                Vec::from_array(
+                    &env,
+                    [
+                        env.current_contract_address().into_val(&env),
+                        campaign_id.into_val(&env),
+                        campaign.cost_per_view.into_val(&env),
+                    ],
+                ),
+            );
+            if !allowed {
+                panic_with_error!(&env, CampaignOrchestratorError::PacingLimitReached);
+            }
+        }
+
+        Self::_pay_publisher(&env, &publisher, campaign.cost_per_view);
 
         // Update campaign
         campaign.remaining_budget -= campaign.cost_per_view;
@@ -330,11 +395,9 @@ impl CampaignOrchestratorContract {
 
         // Update publisher earnings
         Self::_update_publisher_earnings(&env, &publisher, campaign.cost_per_view);
+        Self::_push_analytics(&env, campaign_id, Some(publisher.clone()), 1, campaign.cost_per_view, 0);
 
-        env.events().publish(
-            (symbol_short!("view"), symbol_short!("recorded")),
-            (campaign_id, publisher),
-        );
+        events::publish(&env, EVENT_TAG, ViewRecorded { campaign_id, publisher });
     }
 
     /// Pause a campaign (advertiser only)
@@ -346,10 +409,10 @@ impl CampaignOrchestratorContract {
             .storage()
             .persistent()
             .get(&DataKey::Campaign(campaign_id))
-            .expect("campaign not found");
+            .unwrap_or_else(|| panic_with_error!(&env, CampaignOrchestratorError::CampaignNotFound));
 
         if campaign.advertiser != advertiser {
-            panic!("unauthorized");
+            panic_with_error!(&env, CampaignOrchestratorError::Unauthorized);
         }
 
         campaign.status = CampaignStatus::Paused;
@@ -372,10 +435,10 @@ impl CampaignOrchestratorContract {
             .storage()
             .persistent()
             .get(&DataKey::Campaign(campaign_id))
-            .expect("campaign not found");
+            .unwrap_or_else(|| panic_with_error!(&env, CampaignOrchestratorError::CampaignNotFound));
 
         if campaign.advertiser != advertiser {
-            panic!("unauthorized");
+            panic_with_error!(&env, CampaignOrchestratorError::Unauthorized);
         }
 
         campaign.status = CampaignStatus::Active;
@@ -398,14 +461,14 @@ impl CampaignOrchestratorContract {
             .storage()
             .persistent()
             .get(&DataKey::Campaign(campaign_id))
-            .expect("campaign not found");
+            .unwrap_or_else(|| panic_with_error!(&env, CampaignOrchestratorError::CampaignNotFound));
 
         if campaign.advertiser != advertiser {
-            panic!("unauthorized");
+            panic_with_error!(&env, CampaignOrchestratorError::Unauthorized);
         }
 
         if !campaign.refundable {
-            panic!("campaign not refundable");
+            panic_with_error!(&env, CampaignOrchestratorError::CampaignNotRefundable);
         }
 
         let refund = campaign.remaining_budget;
@@ -426,12 +489,10 @@ impl CampaignOrchestratorContract {
                 env.storage().instance().get(&DataKey::TokenAddress).unwrap();
             let token_client = token::Client::new(&env, &token_addr);
             token_client.transfer(&env.current_contract_address(), &advertiser, &refund);
+            Self::_push_analytics(&env, campaign_id, None, 0, 0, refund);
         }
 
-        env.events().publish(
-            (symbol_short!("campaign"), symbol_short!("cancelled")),
-            (campaign_id, refund),
-        );
+        events::publish(&env, EVENT_TAG, CampaignCancelled { campaign_id, refund });
     }
 
     /// Admin: verify a publisher
@@ -440,7 +501,7 @@ impl CampaignOrchestratorContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, CampaignOrchestratorError::Unauthorized);
         }
 
         let publisher_data = VerifiedPublisher {
@@ -459,10 +520,7 @@ impl CampaignOrchestratorContract {
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("publisher"), symbol_short!("verified")),
-            publisher,
-        );
+        events::publish(&env, EVENT_TAG, PublisherVerified { publisher });
     }
 
     /// Admin: set platform fee
@@ -471,16 +529,162 @@ impl CampaignOrchestratorContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, CampaignOrchestratorError::Unauthorized);
         }
         if fee_pct > 10 {
-            panic!("fee too high");
+            panic_with_error!(&env, CampaignOrchestratorError::FeeTooHigh);
         }
         env.storage()
             .instance()
             .set(&DataKey::PlatformFeePct, &fee_pct);
     }
 
+    /// Admin: set the analytics contract views, spend and refunds are
+    /// reported to
+    pub fn set_analytics(env: Env, admin: Address, analytics: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if admin != stored_admin {
+            panic_with_error!(&env, CampaignOrchestratorError::Unauthorized);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::Analytics, &analytics);
+    }
+
+    /// Admin: set the pacing controller consulted before spend is released
+    pub fn set_pacing(env: Env, admin: Address, pacing: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if admin != stored_admin {
+            panic_with_error!(&env, CampaignOrchestratorError::Unauthorized);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::Pacing, &pacing);
+    }
+
+    /// Admin: set the publisher-verification contract view payments are
+    /// settled into. Publishers then withdraw from their accrued earnings
+    /// there instead of being paid per view; the orchestrator must be an
+    /// authorized caller of that contract.
+    pub fn set_verification(env: Env, admin: Address, verification: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if admin != stored_admin {
+            panic_with_error!(&env, CampaignOrchestratorError::Unauthorized);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::Verification, &verification);
+    }
+
+    /// Admin: resolve the pacing, analytics, verification and placement NFT
+    /// contracts through the contract registry's "pacing", "analytics",
+    /// "verifier" and "placement" keys, falling back to the addresses set
+    /// with `set_pacing` and friends
+    pub fn set_registry(env: Env, admin: Address, registry: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if admin != stored_admin {
+            panic_with_error!(&env, CampaignOrchestratorError::Unauthorized);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::Registry, &registry);
+    }
+
+    /// Set a campaign's hourly and daily spend caps (0 = unlimited)
+    pub fn set_campaign_pacing(env: Env, advertiser: Address, campaign_id: u64, hourly_cap: i128, daily_cap: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        advertiser.require_auth();
+
+        let campaign: Campaign = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Campaign(campaign_id))
+            .unwrap_or_else(|| panic_with_error!(&env, CampaignOrchestratorError::CampaignNotFound));
+
+        if campaign.advertiser != advertiser {
+            panic_with_error!(&env, CampaignOrchestratorError::Unauthorized);
+        }
+
+        let pacing = Self::_pacing(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, CampaignOrchestratorError::PacingNotSet));
+        env.invoke_contract::<()>(
+            &pacing,
+            &Symbol::new(&env, "set_caps"),
+            Vec::from_array(
+                &env,
+                [
+                    env.current_contract_address().into_val(&env),
+                    campaign_id.into_val(&env),
+                    hourly_cap.into_val(&env),
+                    daily_cap.into_val(&env),
+                ],
+            ),
+        );
+    }
+
+    /// Admin: set the placement NFT contract slots are checked against
+    pub fn set_placement_nft(env: Env, admin: Address, placement_nft: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if admin != stored_admin {
+            panic_with_error!(&env, CampaignOrchestratorError::Unauthorized);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::PlacementNft, &placement_nft);
+    }
+
+    /// Schedule a campaign into an exclusive placement slot. The advertiser
+    /// must hold the slot's NFT and its window must not have ended.
+    pub fn schedule_placement(env: Env, advertiser: Address, campaign_id: u64, token_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        advertiser.require_auth();
+
+        let campaign: Campaign = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Campaign(campaign_id))
+            .unwrap_or_else(|| panic_with_error!(&env, CampaignOrchestratorError::CampaignNotFound));
+
+        if campaign.advertiser != advertiser {
+            panic_with_error!(&env, CampaignOrchestratorError::Unauthorized);
+        }
+        match campaign.status {
+            CampaignStatus::Active | CampaignStatus::Paused => {}
+            _ => panic_with_error!(&env, CampaignOrchestratorError::CampaignNotActive),
+        }
+
+        let placement_nft = Self::_resolve(&env, symbol_short!("placement"), &DataKey::PlacementNft)
+            .unwrap_or_else(|| panic_with_error!(&env, CampaignOrchestratorError::PlacementNftNotSet));
+        let holds: bool = env.invoke_contract(
+            &placement_nft,
+            &Symbol::new(&env, "holds_slot"),
+            Vec::from_array(&env, [advertiser.into_val(&env), token_id.into_val(&env)]),
+        );
+        if !holds {
+            panic_with_error!(&env, CampaignOrchestratorError::PlacementSlotNotHeld);
+        }
+
+        let _ttl_key = DataKey::CampaignPlacement(campaign_id);
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &token_id);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, PlacementScheduled { campaign_id, token_id });
+    }
+
     // ============================================================
     // Read-Only Functions
     // ============================================================
@@ -527,6 +731,13 @@ impl CampaignOrchestratorContract {
             .get(&DataKey::AdvertiserStats(advertiser))
     }
 
+    pub fn get_campaign_placement(env: Env, campaign_id: u64) -> Option<u64> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::CampaignPlacement(campaign_id))
+    }
+
     pub fn get_campaign_count(env: Env) -> u64 {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         env.storage()
@@ -569,6 +780,91 @@ impl CampaignOrchestratorContract {
         env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
     }
 
+    fn _pacing(env: &Env) -> Option<Address> {
+        Self::_resolve(env, symbol_short!("pacing"), &DataKey::Pacing)
+    }
+
+    /// The registry's entry for `key` if there is one, else the address
+    /// stored under `fallback`
+    fn _resolve(env: &Env, key: Symbol, fallback: &DataKey) -> Option<Address> {
+        if let Some(registry) = env.storage().instance().get::<DataKey, Address>(&DataKey::Registry) {
+            let resolved: Option<Address> = env.invoke_contract(
+                &registry,
+                &Symbol::new(env, "lookup"),
+                (key,).into_val(env),
+            );
+            if resolved.is_some() {
+                return resolved;
+            }
+        }
+        env.storage().instance().get(fallback)
+    }
+
+    fn _push_analytics(
+        env: &Env,
+        campaign_id: u64,
+        publisher: Option<Address>,
+        impressions: u64,
+        spend: i128,
+        refunds: i128,
+    ) {
+        if let Some(analytics) = Self::_resolve(env, symbol_short!("analytics"), &DataKey::Analytics) {
+            env.invoke_contract::<()>(
+                &analytics,
+                &Symbol::new(env, "record"),
+                Vec::from_array(
+                    env,
+                    [
+                        env.current_contract_address().into_val(env),
+                        campaign_id.into_val(env),
+                        publisher.into_val(env),
+                        impressions.into_val(env),
+                        spend.into_val(env),
+                        refunds.into_val(env),
+                    ],
+                ),
+            );
+        }
+    }
+
+    /// Release one view's spend from the campaign budget held here. With a
+    /// verification contract configured it is settled into the publisher's
+    /// accrued earnings, otherwise paid to the publisher directly.
+    fn _pay_publisher(env: &Env, publisher: &Address, amount: i128) {
+        let token_addr: Address = env.storage().instance().get(&DataKey::TokenAddress).unwrap();
+        let this = env.current_contract_address();
+
+        let verification = match Self::_resolve(env, symbol_short!("verifier"), &DataKey::Verification) {
+            Some(verification) => verification,
+            None => {
+                token::Client::new(env, &token_addr).transfer(&this, publisher, &amount);
+                return;
+            }
+        };
+
+        // settle_earnings pulls the funds from this contract, so that nested
+        // transfer is pre-authorized
+        env.authorize_as_current_contract(Vec::from_array(
+            env,
+            [InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token_addr,
+                    fn_name: Symbol::new(env, "transfer"),
+                    args: (this.clone(), verification.clone(), amount).into_val(env),
+                },
+                sub_invocations: Vec::new(env),
+            })],
+        ));
+        env.invoke_contract::<()>(
+            &verification,
+            &Symbol::new(env, "settle_earnings"),
+            Vec::from_array(
+                env,
+                [this.into_val(env), publisher.into_val(env), amount.into_val(env)],
+            ),
+        );
+    }
+
     fn _update_publisher_earnings(env: &Env, publisher: &Address, earning: i128) {
         let key = DataKey::Publisher(publisher.clone());
         if let Some(mut pub_data) = env
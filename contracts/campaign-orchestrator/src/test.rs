@@ -1,6 +1,83 @@
 #![cfg(test)]
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, vec, String};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, token, Address, Env, vec, String, Symbol};
+
+// Stand-in for the pacing controller: allows spend up to a budget the
+// test sets through `set_caps` (taken as the daily cap)
+#[contract]
+pub struct MockPacing;
+
+#[contractimpl]
+impl MockPacing {
+    pub fn set_caps(env: Env, orchestrator: Address, campaign_id: u64, _hourly_cap: i128, daily_cap: i128) {
+        orchestrator.require_auth();
+        env.storage().instance().set(&campaign_id, &daily_cap);
+    }
+
+    pub fn consume(env: Env, orchestrator: Address, campaign_id: u64, amount: i128) -> bool {
+        orchestrator.require_auth();
+        let left: i128 = env.storage().instance().get(&campaign_id).unwrap_or(i128::MAX);
+        if amount > left {
+            return false;
+        }
+        env.storage().instance().set(&campaign_id, &(left - amount));
+        true
+    }
+}
+
+// Stand-in for the analytics contract: keeps running platform totals
+#[contract]
+pub struct MockAnalytics;
+
+#[contractimpl]
+impl MockAnalytics {
+    pub fn record(
+        env: Env,
+        reporter: Address,
+        _campaign_id: u64,
+        _publisher: Option<Address>,
+        impressions: u64,
+        spend: i128,
+        refunds: i128,
+    ) {
+        reporter.require_auth();
+        let (i, s, r): (u64, i128, i128) = env.storage().instance().get(&0u32).unwrap_or((0, 0, 0));
+        env.storage().instance().set(&0u32, &(i + impressions, s + spend, r + refunds));
+    }
+
+    pub fn totals(env: Env) -> (u64, i128, i128) {
+        env.storage().instance().get(&0u32).unwrap_or((0, 0, 0))
+    }
+}
+
+// Stand-in for the placement NFT: the test decides who holds which slot
+#[contract]
+pub struct MockPlacementNft;
+
+#[contractimpl]
+impl MockPlacementNft {
+    pub fn set_holder(env: Env, token_id: u64, holder: Address) {
+        env.storage().instance().set(&token_id, &holder);
+    }
+
+    pub fn holds_slot(env: Env, holder: Address, token_id: u64) -> bool {
+        env.storage().instance().get::<u64, Address>(&token_id) == Some(holder)
+    }
+}
+
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_address(env: Env, key: Symbol, address: Address) {
+        env.storage().instance().set(&key, &address);
+    }
+
+    pub fn lookup(env: Env, key: Symbol) -> Option<Address> {
+        env.storage().instance().get(&key)
+    }
+}
 
 #[test]
 fn test_initialize() {
@@ -17,7 +94,7 @@ fn test_initialize() {
 }
 
 #[test]
-#[should_panic(expected = "already initialized")]
+#[should_panic(expected = "Error(Contract, #1901)")]
 fn test_initialize_twice() {
     let env = Env::default();
     env.mock_all_auths();
@@ -46,3 +123,129 @@ fn test_initialize_non_admin_fails() {
     // This should panic because admin didn't authorize it and we haven't mocked it
     client.initialize(&admin, &token_address);
 }
+
+#[test]
+fn test_schedule_placement_requires_holding_slot() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, CampaignOrchestratorContract);
+    let client = CampaignOrchestratorContractClient::new(&env, &contract_id);
+    let nft_id = env.register_contract(None, MockPlacementNft);
+    let nft = MockPlacementNftClient::new(&env, &nft_id);
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(token_admin).address();
+    let advertiser = Address::generate(&env);
+    let rival = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&advertiser, &10_000_000);
+    client.initialize(&admin, &token_address);
+
+    let campaign_id = client.create_campaign(&advertiser, &1, &2_000_000, &1_000, &500, &1_000, &100, &true);
+    assert!(client.try_schedule_placement(&advertiser, &campaign_id, &7).is_err());
+
+    client.set_placement_nft(&admin, &nft_id);
+    nft.set_holder(&7, &rival);
+    assert!(client.try_schedule_placement(&advertiser, &campaign_id, &7).is_err());
+    assert!(client.try_schedule_placement(&rival, &campaign_id, &7).is_err());
+
+    nft.set_holder(&7, &advertiser);
+    client.schedule_placement(&advertiser, &campaign_id, &7);
+    assert_eq!(client.get_campaign_placement(&campaign_id), Some(7));
+}
+
+#[test]
+fn test_record_view_respects_pacing() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, CampaignOrchestratorContract);
+    let client = CampaignOrchestratorContractClient::new(&env, &contract_id);
+    let pacing_id = env.register_contract(None, MockPacing);
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(token_admin).address();
+    let advertiser = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&advertiser, &10_000_000);
+    client.initialize(&admin, &token_address);
+    client.verify_publisher(&admin, &publisher, &80);
+
+    let campaign_id = client.create_campaign(&advertiser, &1, &2_000_000, &1_000, &500, &1_000, &100, &true);
+    assert!(client.try_set_campaign_pacing(&advertiser, &campaign_id, &0, &2_000).is_err());
+
+    client.set_pacing(&admin, &pacing_id);
+    assert!(client.try_set_campaign_pacing(&publisher, &campaign_id, &0, &2_000).is_err());
+    client.set_campaign_pacing(&advertiser, &campaign_id, &0, &2_000);
+
+    client.record_view(&campaign_id, &publisher);
+    client.record_view(&campaign_id, &publisher);
+    assert!(client.try_record_view(&campaign_id, &publisher).is_err());
+    assert_eq!(client.get_campaign(&campaign_id).unwrap().current_views, 2);
+    assert_eq!(token::Client::new(&env, &token_address).balance(&publisher), 2_000);
+}
+
+#[test]
+fn test_activity_reported_to_analytics() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, CampaignOrchestratorContract);
+    let client = CampaignOrchestratorContractClient::new(&env, &contract_id);
+    let analytics_id = env.register_contract(None, MockAnalytics);
+    let analytics = MockAnalyticsClient::new(&env, &analytics_id);
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(token_admin).address();
+    let advertiser = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&advertiser, &10_000_000);
+    client.initialize(&admin, &token_address);
+    client.verify_publisher(&admin, &publisher, &80);
+    client.set_analytics(&admin, &analytics_id);
+
+    let campaign_id = client.create_campaign(&advertiser, &1, &2_000_000, &1_000, &500, &1_000, &100, &true);
+    client.record_view(&campaign_id, &publisher);
+    client.record_view(&campaign_id, &publisher);
+    client.cancel_campaign(&advertiser, &campaign_id);
+
+    assert_eq!(analytics.totals(), (2, 2_000, 1_998_000));
+}
+
+#[test]
+fn test_dependencies_resolved_through_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, CampaignOrchestratorContract);
+    let client = CampaignOrchestratorContractClient::new(&env, &contract_id);
+    let registry_id = env.register_contract(None, MockRegistry);
+    let registry = MockRegistryClient::new(&env, &registry_id);
+    let pacing_id = env.register_contract(None, MockPacing);
+    let analytics_id = env.register_contract(None, MockAnalytics);
+    let analytics = MockAnalyticsClient::new(&env, &analytics_id);
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(token_admin).address();
+    let advertiser = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&advertiser, &10_000_000);
+    client.initialize(&admin, &token_address);
+    client.verify_publisher(&admin, &publisher, &80);
+    client.set_registry(&admin, &registry_id);
+    assert!(client.try_set_registry(&publisher, &registry_id).is_err());
+
+    // Nothing is set directly; every dependency comes from the registry
+    registry.set_address(&symbol_short!("pacing"), &pacing_id);
+    registry.set_address(&symbol_short!("analytics"), &analytics_id);
+    let campaign_id = client.create_campaign(&advertiser, &1, &2_000_000, &1_000, &500, &1_000, &100, &true);
+    client.set_campaign_pacing(&advertiser, &campaign_id, &0, &1_000);
+
+    client.record_view(&campaign_id, &publisher);
+    assert!(client.try_record_view(&campaign_id, &publisher).is_err());
+    assert_eq!(analytics.totals(), (1, 1_000, 0));
+}
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     token, Address, Env, String,
 };
+pub use pulsartrack_errors::CreativeMarketplaceError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -52,6 +54,22 @@ pub struct License {
     pub expires_at: Option<u64>,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct ListingCreated {
+    pub listing_id: u64,
+    pub creator: Address,
+}
+contract_event!(ListingCreated, "listing_created");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct LicensePurchased {
+    pub listing_id: u64,
+    pub price: i128,
+}
+contract_event!(LicensePurchased, "license_purchased");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -67,6 +85,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "creatives";
+
 #[contract]
 pub struct CreativeMarketplaceContract;
 
@@ -75,7 +95,7 @@ impl CreativeMarketplaceContract {
     pub fn initialize(env: Env, admin: Address, token: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, CreativeMarketplaceError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -97,7 +117,7 @@ impl CreativeMarketplaceContract {
         creator.require_auth();
 
         if price <= 0 {
-            panic!("invalid price");
+            panic_with_error!(&env, CreativeMarketplaceError::InvalidPrice);
         }
 
         let counter: u64 = env.storage().instance().get(&DataKey::ListingCounter).unwrap_or(0);
@@ -122,10 +142,7 @@ impl CreativeMarketplaceContract {
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
         env.storage().instance().set(&DataKey::ListingCounter, &listing_id);
 
-        env.events().publish(
-            (symbol_short!("listing"), symbol_short!("created")),
-            (listing_id, creator),
-        );
+        events::publish(&env, EVENT_TAG, ListingCreated { listing_id, creator });
 
         listing_id
     }
@@ -143,15 +160,15 @@ impl CreativeMarketplaceContract {
             .storage()
             .persistent()
             .get(&DataKey::Listing(listing_id))
-            .expect("listing not found");
+            .unwrap_or_else(|| panic_with_error!(&env, CreativeMarketplaceError::ListingNotFound));
 
         if listing.status != ListingStatus::Active {
-            panic!("listing not active");
+            panic_with_error!(&env, CreativeMarketplaceError::ListingNotActive);
         }
 
         // Check not already licensed
         if env.storage().persistent().has(&DataKey::License(listing_id, buyer.clone())) {
-            panic!("already licensed");
+            panic_with_error!(&env, CreativeMarketplaceError::AlreadyLicensed);
         }
 
         // Calculate fee
@@ -198,10 +215,7 @@ impl CreativeMarketplaceContract {
         env.storage().persistent().set(&_ttl_key, &listing);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("license"), symbol_short!("purchased")),
-            (listing_id, listing.price),
-        );
+        events::publish(&env, EVENT_TAG, LicensePurchased { listing_id, price: listing.price });
     }
 
     pub fn remove_listing(env: Env, creator: Address, listing_id: u64) {
@@ -212,10 +226,10 @@ impl CreativeMarketplaceContract {
             .storage()
             .persistent()
             .get(&DataKey::Listing(listing_id))
-            .expect("listing not found");
+            .unwrap_or_else(|| panic_with_error!(&env, CreativeMarketplaceError::ListingNotFound));
 
         if listing.creator != creator {
-            panic!("unauthorized");
+            panic_with_error!(&env, CreativeMarketplaceError::Unauthorized);
         }
 
         listing.status = ListingStatus::Removed;
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     token, Address, Env, String,
 };
+pub use pulsartrack_errors::DisputeResolutionError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -19,9 +21,12 @@ pub enum DisputeStatus {
     Closed,
 }
 
+/// `Pending` until an arbitrator rules. A plain variant rather than an
+/// `Option` field, which the SDK's XDR conversions cannot encode for enums.
 #[contracttype]
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub enum DisputeOutcome {
+    Pending,
     Claimant,
     Respondent,
     Split,
@@ -40,13 +45,28 @@ pub struct Dispute {
     pub description: String,
     pub evidence_hash: String,  // IPFS hash of evidence
     pub status: DisputeStatus,
-    pub outcome: Option<DisputeOutcome>,
+    pub outcome: DisputeOutcome,
     pub resolution_notes: String,
     pub filed_at: u64,
     pub resolved_at: Option<u64>,
     pub arbitrator: Option<Address>,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct DisputeFiled {
+    pub dispute_id: u64,
+    pub claimant: Address,
+}
+contract_event!(DisputeFiled, "dispute_filed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct DisputeResolved {
+    pub dispute_id: u64,
+}
+contract_event!(DisputeResolved, "dispute_resolved");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -63,6 +83,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 34_560;
 const PERSISTENT_BUMP_AMOUNT: u32 = 259_200;
 
+const EVENT_TAG: &str = "disputes";
+
 #[contract]
 pub struct DisputeResolutionContract;
 
@@ -71,7 +93,7 @@ impl DisputeResolutionContract {
     pub fn initialize(env: Env, admin: Address, token: Address, filing_fee: i128) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, DisputeResolutionError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -85,7 +107,7 @@ impl DisputeResolutionContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, DisputeResolutionError::Unauthorized);
         }
         let _ttl_key = DataKey::ArbitratorApproved(arbitrator);
         env.storage().persistent().set(&_ttl_key, &true);
@@ -127,7 +149,7 @@ impl DisputeResolutionContract {
             description,
             evidence_hash,
             status: DisputeStatus::Filed,
-            outcome: None,
+            outcome: DisputeOutcome::Pending,
             resolution_notes: String::from_str(&env, ""),
             filed_at: env.ledger().timestamp(),
             resolved_at: None,
@@ -139,10 +161,7 @@ impl DisputeResolutionContract {
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
         env.storage().instance().set(&DataKey::DisputeCounter, &dispute_id);
 
-        env.events().publish(
-            (symbol_short!("dispute"), symbol_short!("filed")),
-            (dispute_id, claimant),
-        );
+        events::publish(&env, EVENT_TAG, DisputeFiled { dispute_id, claimant });
 
         dispute_id
     }
@@ -152,7 +171,7 @@ impl DisputeResolutionContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, DisputeResolutionError::Unauthorized);
         }
 
         let is_authorized: bool = env
@@ -162,14 +181,14 @@ impl DisputeResolutionContract {
             .unwrap_or(false);
 
         if !is_authorized {
-            panic!("arbitrator not authorized");
+            panic_with_error!(&env, DisputeResolutionError::ArbitratorNotAuthorized);
         }
 
         let mut dispute: Dispute = env
             .storage()
             .persistent()
             .get(&DataKey::Dispute(dispute_id))
-            .expect("dispute not found");
+            .unwrap_or_else(|| panic_with_error!(&env, DisputeResolutionError::DisputeNotFound));
 
         dispute.arbitrator = Some(arbitrator);
         dispute.status = DisputeStatus::UnderReview;
@@ -192,13 +211,17 @@ impl DisputeResolutionContract {
             .storage()
             .persistent()
             .get(&DataKey::Dispute(dispute_id))
-            .expect("dispute not found");
+            .unwrap_or_else(|| panic_with_error!(&env, DisputeResolutionError::DisputeNotFound));
 
         if dispute.arbitrator != Some(arbitrator) {
-            panic!("not assigned arbitrator");
+            panic_with_error!(&env, DisputeResolutionError::NotAssignedArbitrator);
+        }
+
+        if outcome == DisputeOutcome::Pending {
+            panic_with_error!(&env, DisputeResolutionError::InvalidOutcome);
         }
 
-        dispute.outcome = Some(outcome);
+        dispute.outcome = outcome;
         dispute.resolution_notes = notes;
         dispute.status = DisputeStatus::Resolved;
         dispute.resolved_at = Some(env.ledger().timestamp());
@@ -207,10 +230,7 @@ impl DisputeResolutionContract {
         env.storage().persistent().set(&_ttl_key, &dispute);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("dispute"), symbol_short!("resolved")),
-            dispute_id,
-        );
+        events::publish(&env, EVENT_TAG, DisputeResolved { dispute_id });
     }
 
     pub fn get_dispute(env: Env, dispute_id: u64) -> Option<Dispute> {
@@ -1,18 +1,21 @@
 //! PulsarTrack - Escrow Vault (Soroban)
 //! Advanced escrow with time-locked funds, performance triggers, and multi-party approval.
 //!
-//! Events:
-//! - ("escrow", "created"): [escrow_id: u64, campaign_id: u64, amount: i128]
-//! - ("escrow", "release"): [escrow_id: u64, amount: i128]
-//! - ("escrow", "release_partial"): [escrow_id: u64, amount: i128]
-//! - ("escrow", "refund"): [escrow_id: u64, amount: i128]
+//! Events are published as ("vault", action, version) with a typed payload
+//! (see the Events section):
+//! - "escrow_created": `EscrowCreated`
+//! - "escrow_released": `EscrowReleased`
+//! - "escrow_partially_released": `EscrowPartiallyReleased`
+//! - "escrow_refunded": `EscrowRefunded`
 
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     token, Address, Env, Vec,
 };
+pub use pulsartrack_errors::EscrowVaultError;
+use pulsartrack_events::{self as events, contract_event};
 
 // ============================================================
 // Data Types
@@ -63,6 +66,43 @@ pub struct PerformanceMetrics {
     pub last_updated: u64,
 }
 
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EscrowCreated {
+    pub escrow_id: u64,
+    pub campaign_id: u64,
+    pub amount: i128,
+}
+contract_event!(EscrowCreated, "escrow_created");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EscrowReleased {
+    pub escrow_id: u64,
+    pub amount: i128,
+}
+contract_event!(EscrowReleased, "escrow_released");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EscrowPartiallyReleased {
+    pub escrow_id: u64,
+    pub amount: i128,
+}
+contract_event!(EscrowPartiallyReleased, "escrow_partially_released");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EscrowRefunded {
+    pub escrow_id: u64,
+    pub amount: i128,
+}
+contract_event!(EscrowRefunded, "escrow_refunded");
+
 // ============================================================
 // Storage Keys
 // ============================================================
@@ -90,6 +130,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "vault";
+
 #[contract]
 pub struct EscrowVaultContract;
 
@@ -99,7 +141,7 @@ impl EscrowVaultContract {
     pub fn initialize(env: Env, admin: Address, token_address: Address, oracle: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, EscrowVaultError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -133,10 +175,10 @@ impl EscrowVaultContract {
         depositor.require_auth();
 
         if amount <= 0 {
-            panic!("invalid amount");
+            panic_with_error!(&env, EscrowVaultError::InvalidAmount);
         }
         if performance_threshold > 100 {
-            panic!("invalid performance threshold");
+            panic_with_error!(&env, EscrowVaultError::InvalidPerformanceThreshold);
         }
 
         // Transfer funds to escrow contract
@@ -199,10 +241,7 @@ impl EscrowVaultContract {
             .instance()
             .set(&DataKey::EscrowNonce, &escrow_id);
 
-        env.events().publish(
-            (symbol_short!("escrow"), symbol_short!("created")),
-            (escrow_id, campaign_id, amount),
-        );
+        events::publish(&env, EVENT_TAG, EscrowCreated { escrow_id, campaign_id, amount });
 
         escrow_id
     }
@@ -219,17 +258,17 @@ impl EscrowVaultContract {
             .unwrap_or(false);
 
         if !is_required {
-            panic!("not a required approver");
+            panic_with_error!(&env, EscrowVaultError::NotARequiredApprover);
         }
 
         let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(escrow_id))
-            .expect("escrow not found");
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowVaultError::EscrowNotFound));
 
         if escrow.state == EscrowState::Released {
-            panic!("already released");
+            panic_with_error!(&env, EscrowVaultError::AlreadyReleased);
         }
 
         let approval = EscrowApproval {
@@ -268,19 +307,19 @@ impl EscrowVaultContract {
             .storage()
             .persistent()
             .get(&DataKey::Escrow(escrow_id))
-            .expect("escrow not found");
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowVaultError::EscrowNotFound));
 
         // Must be depositor or admin
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if caller != escrow.depositor && caller != admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, EscrowVaultError::Unauthorized);
         }
 
         Self::_check_can_release(&env, &escrow, escrow_id);
 
         let locked = escrow.locked_amount;
         if locked <= 0 {
-            panic!("nothing to release");
+            panic_with_error!(&env, EscrowVaultError::NothingToRelease);
         }
 
         let token_addr: Address = env.storage().instance().get(&DataKey::TokenAddress).unwrap();
@@ -304,10 +343,7 @@ impl EscrowVaultContract {
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("escrow"), symbol_short!("release")),
-            (escrow_id, locked),
-        );
+        events::publish(&env, EVENT_TAG, EscrowReleased { escrow_id, amount: locked });
     }
 
     /// Partial release
@@ -319,17 +355,17 @@ impl EscrowVaultContract {
             .storage()
             .persistent()
             .get(&DataKey::Escrow(escrow_id))
-            .expect("escrow not found");
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowVaultError::EscrowNotFound));
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if caller != escrow.depositor && caller != admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, EscrowVaultError::Unauthorized);
         }
 
         Self::_check_can_release(&env, &escrow, escrow_id);
 
         if amount <= 0 || amount > escrow.locked_amount {
-            panic!("invalid amount");
+            panic_with_error!(&env, EscrowVaultError::InvalidAmount);
         }
 
         let token_addr: Address = env.storage().instance().get(&DataKey::TokenAddress).unwrap();
@@ -352,10 +388,7 @@ impl EscrowVaultContract {
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("escrow"), symbol_short!("release_p")), // "release_partial" is too long for symbol_short
-            (escrow_id, amount),
-        );
+        events::publish(&env, EVENT_TAG, EscrowPartiallyReleased { escrow_id, amount });
     }
 
     /// Refund escrow if expired
@@ -367,15 +400,15 @@ impl EscrowVaultContract {
             .storage()
             .persistent()
             .get(&DataKey::Escrow(escrow_id))
-            .expect("escrow not found");
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowVaultError::EscrowNotFound));
 
         let now = env.ledger().timestamp();
         if now < escrow.expires_at {
-            panic!("escrow not yet expired");
+            panic_with_error!(&env, EscrowVaultError::EscrowNotYetExpired);
         }
 
         if escrow.locked_amount <= 0 {
-            panic!("nothing to refund");
+            panic_with_error!(&env, EscrowVaultError::NothingToRefund);
         }
 
         let refund = escrow.locked_amount;
@@ -399,10 +432,7 @@ impl EscrowVaultContract {
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("escrow"), symbol_short!("refund")),
-            (escrow_id, refund),
-        );
+        events::publish(&env, EVENT_TAG, EscrowRefunded { escrow_id, amount: refund });
     }
 
     /// Update performance metrics (oracle only)
@@ -422,11 +452,11 @@ impl EscrowVaultContract {
             .get(&DataKey::OracleAddress)
             .unwrap();
         if oracle != stored_oracle {
-            panic!("unauthorized");
+            panic_with_error!(&env, EscrowVaultError::Unauthorized);
         }
 
         if performance > 100 {
-            panic!("invalid performance");
+            panic_with_error!(&env, EscrowVaultError::InvalidPerformance);
         }
 
         let metrics = PerformanceMetrics {
@@ -515,7 +545,7 @@ impl EscrowVaultContract {
     fn _check_can_release(env: &Env, escrow: &Escrow, escrow_id: u64) {
         let now = env.ledger().timestamp();
         if now < escrow.time_lock_until {
-            panic!("time lock active");
+            panic_with_error!(&env, EscrowVaultError::TimeLockActive);
         }
 
         let min_threshold: u32 = env
@@ -529,7 +559,7 @@ impl EscrowVaultContract {
             .get(&DataKey::ApprovalCount(escrow_id))
             .unwrap_or(0);
         if approvals < min_threshold {
-            panic!("approval required");
+            panic_with_error!(&env, EscrowVaultError::ApprovalRequired);
         }
 
         if let Some(perf) = env
@@ -538,7 +568,7 @@ impl EscrowVaultContract {
             .get::<DataKey, PerformanceMetrics>(&DataKey::Performance(escrow_id))
         {
             if perf.current_performance < escrow.performance_threshold {
-                panic!("performance threshold not met");
+                panic_with_error!(&env, EscrowVaultError::PerformanceThresholdNotMet);
             }
         }
     }
@@ -18,7 +18,7 @@ fn test_initialize() {
 }
 
 #[test]
-#[should_panic(expected = "already initialized")]
+#[should_panic(expected = "Error(Contract, #2201)")]
 fn test_initialize_twice() {
     let env = Env::default();
     env.mock_all_auths();
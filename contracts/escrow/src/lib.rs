@@ -0,0 +1,582 @@
+//! PulsarTrack - Escrow (Soroban)
+//! General-purpose milestone escrow for campaigns on Stellar.
+//!
+//! A depositor locks tokens for a beneficiary split across milestones. Each
+//! milestone is released by the arbiter or by mutual consent of both parties,
+//! falls back to its timeout action once its deadline passes, and can be
+//! disputed, in which case it is filed with the refund processor for review.
+//!
+//! Events are published as ("escrow", action, version) with a typed payload
+//! (see the Events section):
+//! - "escrow_created": `EscrowCreated`
+//! - "milestone_approved": `MilestoneApproved`
+//! - "milestone_disputed": `MilestoneDisputed`
+//! - "milestone_released": `MilestoneReleased`
+//! - "milestone_refunded": `MilestoneRefunded`
+//! - "escrow_closed": `EscrowClosed`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error, symbol_short,
+    token, Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
+};
+pub use pulsartrack_errors::EscrowError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum EscrowStatus {
+    Active,
+    Closed, // every milestone settled
+}
+
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum MilestoneStatus {
+    Pending,
+    Disputed,
+    Released,
+    Refunded,
+    Split, // dispute settled with a partial refund
+}
+
+/// What happens to a milestone nobody acted on by its deadline
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum TimeoutAction {
+    Release,
+    Refund,
+}
+
+/// Milestone terms supplied when the escrow is created
+#[contracttype]
+#[derive(Clone)]
+pub struct MilestoneSpec {
+    pub amount: i128,
+    pub description: String,
+    pub deadline: u64, // Unix timestamp
+    pub timeout_action: TimeoutAction,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Milestone {
+    pub amount: i128,
+    pub description: String,
+    pub deadline: u64,
+    pub timeout_action: TimeoutAction,
+    pub status: MilestoneStatus,
+    pub depositor_approved: bool,
+    pub beneficiary_approved: bool,
+    pub refund_id: Option<u64>, // request filed with the refund processor
+    pub settled_at: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Escrow {
+    pub depositor: Address,
+    pub beneficiary: Address,
+    pub arbiter: Address,
+    pub token: Address,
+    pub campaign_id: u64,
+    pub total: i128,
+    pub released: i128,
+    pub refunded: i128,
+    pub milestone_count: u32,
+    pub settled_count: u32,
+    pub status: EscrowStatus,
+    pub created_at: u64,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EscrowCreated {
+    pub escrow_id: u64,
+    pub depositor: Address,
+    pub beneficiary: Address,
+    pub total: i128,
+}
+contract_event!(EscrowCreated, "escrow_created");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct MilestoneApproved {
+    pub escrow_id: u64,
+    pub index: u32,
+    pub party: Address,
+}
+contract_event!(MilestoneApproved, "milestone_approved");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct MilestoneDisputed {
+    pub escrow_id: u64,
+    pub index: u32,
+    pub refund_id: Option<u64>,
+}
+contract_event!(MilestoneDisputed, "milestone_disputed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct MilestoneReleased {
+    pub escrow_id: u64,
+    pub index: u32,
+    pub amount: i128,
+}
+contract_event!(MilestoneReleased, "milestone_released");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct MilestoneRefunded {
+    pub escrow_id: u64,
+    pub index: u32,
+    pub amount: i128,
+}
+contract_event!(MilestoneRefunded, "milestone_refunded");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EscrowClosed {
+    pub escrow_id: u64,
+}
+contract_event!(EscrowClosed, "escrow_closed");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    RefundProcessor,
+    Registry,
+    EscrowCounter,
+    Escrow(u64),
+    Milestone(u64, u32),
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const MAX_MILESTONES: u32 = 20;
+
+const EVENT_TAG: &str = "escrow";
+
+#[contract]
+pub struct EscrowContract;
+
+#[contractimpl]
+impl EscrowContract {
+    pub fn initialize(env: Env, admin: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, EscrowError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::EscrowCounter, &0u64);
+    }
+
+    /// Set the refund processor that reviews disputed milestones (admin only)
+    pub fn set_refund_processor(env: Env, admin: Address, processor: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if admin != stored_admin {
+            panic_with_error!(&env, EscrowError::Unauthorized);
+        }
+        env.storage().instance().set(&DataKey::RefundProcessor, &processor);
+    }
+
+    /// Resolve the refund processor through the contract registry's
+    /// "refund" key, falling back to `set_refund_processor` (admin only)
+    pub fn set_registry(env: Env, admin: Address, registry: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if admin != stored_admin {
+            panic_with_error!(&env, EscrowError::Unauthorized);
+        }
+        env.storage().instance().set(&DataKey::Registry, &registry);
+    }
+
+    /// Lock the sum of all milestone amounts from the depositor
+    pub fn create_escrow(
+        env: Env,
+        depositor: Address,
+        beneficiary: Address,
+        arbiter: Address,
+        token: Address,
+        campaign_id: u64,
+        milestones: Vec<MilestoneSpec>,
+    ) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        depositor.require_auth();
+
+        if milestones.is_empty() || milestones.len() > MAX_MILESTONES {
+            panic_with_error!(&env, EscrowError::InvalidMilestoneCount);
+        }
+        if depositor == beneficiary {
+            panic_with_error!(&env, EscrowError::DepositorIsBeneficiary);
+        }
+
+        let counter: u64 = env.storage().instance().get(&DataKey::EscrowCounter).unwrap_or(0);
+        let escrow_id = counter + 1;
+        let now = env.ledger().timestamp();
+
+        let mut total: i128 = 0;
+        for (index, spec) in milestones.iter().enumerate() {
+            if spec.amount <= 0 {
+                panic_with_error!(&env, EscrowError::InvalidAmount);
+            }
+            if spec.deadline <= now {
+                panic_with_error!(&env, EscrowError::InvalidDeadline);
+            }
+            total = total.checked_add(spec.amount).unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+
+            let milestone = Milestone {
+                amount: spec.amount,
+                description: spec.description,
+                deadline: spec.deadline,
+                timeout_action: spec.timeout_action,
+                status: MilestoneStatus::Pending,
+                depositor_approved: false,
+                beneficiary_approved: false,
+                refund_id: None,
+                settled_at: None,
+            };
+            let _ttl_key = DataKey::Milestone(escrow_id, index as u32);
+            env.storage().persistent().set(&_ttl_key, &milestone);
+            env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+
+        token::Client::new(&env, &token).transfer(&depositor, &env.current_contract_address(), &total);
+
+        let escrow = Escrow {
+            depositor: depositor.clone(),
+            beneficiary: beneficiary.clone(),
+            arbiter,
+            token,
+            campaign_id,
+            total,
+            released: 0,
+            refunded: 0,
+            milestone_count: milestones.len(),
+            settled_count: 0,
+            status: EscrowStatus::Active,
+            created_at: now,
+        };
+        let _ttl_key = DataKey::Escrow(escrow_id);
+        env.storage().persistent().set(&_ttl_key, &escrow);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        env.storage().instance().set(&DataKey::EscrowCounter, &escrow_id);
+
+        events::publish(&env, EVENT_TAG, EscrowCreated { escrow_id, depositor, beneficiary, total });
+
+        escrow_id
+    }
+
+    /// Approve releasing a milestone; it pays out once both the depositor
+    /// and the beneficiary have approved
+    pub fn approve_milestone(env: Env, party: Address, escrow_id: u64, index: u32) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        party.require_auth();
+
+        let escrow = Self::_load_escrow(&env, escrow_id);
+        let mut milestone = Self::_load_milestone(&env, escrow_id, index);
+        if milestone.status != MilestoneStatus::Pending {
+            panic_with_error!(&env, EscrowError::MilestoneNotPending);
+        }
+
+        if party == escrow.depositor {
+            milestone.depositor_approved = true;
+        } else if party == escrow.beneficiary {
+            milestone.beneficiary_approved = true;
+        } else {
+            panic_with_error!(&env, EscrowError::Unauthorized);
+        }
+
+        events::publish(&env, EVENT_TAG, MilestoneApproved { escrow_id, index, party });
+
+        if milestone.depositor_approved && milestone.beneficiary_approved {
+            let amount = milestone.amount;
+            Self::_settle(&env, escrow_id, escrow, index, milestone, amount, MilestoneStatus::Released);
+        } else {
+            Self::_save_milestone(&env, escrow_id, index, &milestone);
+        }
+    }
+
+    /// Release a pending or disputed milestone to the beneficiary (arbiter only)
+    pub fn arbiter_release(env: Env, arbiter: Address, escrow_id: u64, index: u32) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        arbiter.require_auth();
+
+        let escrow = Self::_load_escrow(&env, escrow_id);
+        if arbiter != escrow.arbiter {
+            panic_with_error!(&env, EscrowError::Unauthorized);
+        }
+        let milestone = Self::_load_open_milestone(&env, escrow_id, index);
+        let amount = milestone.amount;
+        Self::_settle(&env, escrow_id, escrow, index, milestone, amount, MilestoneStatus::Released);
+    }
+
+    /// Return a pending or disputed milestone to the depositor (arbiter only)
+    pub fn arbiter_refund(env: Env, arbiter: Address, escrow_id: u64, index: u32) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        arbiter.require_auth();
+
+        let escrow = Self::_load_escrow(&env, escrow_id);
+        if arbiter != escrow.arbiter {
+            panic_with_error!(&env, EscrowError::Unauthorized);
+        }
+        let milestone = Self::_load_open_milestone(&env, escrow_id, index);
+        Self::_settle(&env, escrow_id, escrow, index, milestone, 0, MilestoneStatus::Refunded);
+    }
+
+    /// Apply a milestone's timeout action once its deadline has passed.
+    /// Anyone may call this; disputed milestones wait for their resolution.
+    pub fn claim_timeout(env: Env, escrow_id: u64, index: u32) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let escrow = Self::_load_escrow(&env, escrow_id);
+        let milestone = Self::_load_milestone(&env, escrow_id, index);
+        if milestone.status != MilestoneStatus::Pending {
+            panic_with_error!(&env, EscrowError::MilestoneNotPending);
+        }
+        if env.ledger().timestamp() < milestone.deadline {
+            panic_with_error!(&env, EscrowError::DeadlineNotReached);
+        }
+
+        match milestone.timeout_action {
+            TimeoutAction::Release => {
+                let amount = milestone.amount;
+                Self::_settle(&env, escrow_id, escrow, index, milestone, amount, MilestoneStatus::Released);
+            }
+            TimeoutAction::Refund => {
+                Self::_settle(&env, escrow_id, escrow, index, milestone, 0, MilestoneStatus::Refunded);
+            }
+        }
+    }
+
+    /// Freeze a pending milestone until it is resolved. When a refund
+    /// processor is configured the milestone amount is filed with it as a
+    /// refund request on the depositor's behalf, which needs this contract
+    /// listed there as a filer.
+    pub fn raise_dispute(env: Env, party: Address, escrow_id: u64, index: u32, reason: String) -> Option<u64> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        party.require_auth();
+
+        let escrow = Self::_load_escrow(&env, escrow_id);
+        if party != escrow.depositor && party != escrow.beneficiary {
+            panic_with_error!(&env, EscrowError::Unauthorized);
+        }
+        let mut milestone = Self::_load_milestone(&env, escrow_id, index);
+        if milestone.status != MilestoneStatus::Pending {
+            panic_with_error!(&env, EscrowError::MilestoneNotPending);
+        }
+
+        if let Some(processor) = Self::_refund_processor(&env) {
+            let refund_id: u64 = env.invoke_contract(
+                &processor,
+                &Symbol::new(&env, "request_refund_for"),
+                Vec::from_array(
+                    &env,
+                    [
+                        env.current_contract_address().into_val(&env),
+                        escrow.depositor.into_val(&env),
+                        escrow.campaign_id.into_val(&env),
+                        milestone.amount.into_val(&env),
+                        reason.into_val(&env),
+                    ],
+                ),
+            );
+            milestone.refund_id = Some(refund_id);
+        }
+        milestone.status = MilestoneStatus::Disputed;
+        Self::_save_milestone(&env, escrow_id, index, &milestone);
+
+        events::publish(&env, EVENT_TAG, MilestoneDisputed { escrow_id, index, refund_id: milestone.refund_id });
+
+        milestone.refund_id
+    }
+
+    /// Settle a disputed milestone from the refund processor's decision:
+    /// the approved amount goes back to the depositor and the rest to the
+    /// beneficiary. Anyone may call this once the request is decided.
+    pub fn resolve_dispute(env: Env, escrow_id: u64, index: u32) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let escrow = Self::_load_escrow(&env, escrow_id);
+        let milestone = Self::_load_milestone(&env, escrow_id, index);
+        if milestone.status != MilestoneStatus::Disputed {
+            panic_with_error!(&env, EscrowError::MilestoneNotDisputed);
+        }
+        let refund_id = milestone.refund_id.unwrap_or_else(|| panic_with_error!(&env, EscrowError::NoRefundRequest));
+        let processor = Self::_refund_processor(&env).unwrap_or_else(|| panic_with_error!(&env, EscrowError::RefundProcessorNotSet));
+
+        let refund_val: Val = env.invoke_contract(
+            &processor,
+            &Symbol::new(&env, "get_refund"),
+            (refund_id,).into_val(&env),
+        );
+        if refund_val.is_void() {
+            panic_with_error!(&env, EscrowError::RefundNotFound);
+        }
+        let refund = Map::<Symbol, Val>::try_from_val(&env, &refund_val)
+            .unwrap_or_else(|_| panic_with_error!(&env, EscrowError::InvalidRefund));
+        let field = |name: &str| {
+            refund
+                .get(Symbol::new(&env, name))
+                .unwrap_or_else(|| panic_with_error!(&env, EscrowError::InvalidRefund))
+        };
+        let status = Vec::<Val>::try_from_val(&env, &field("status"))
+            .ok()
+            .and_then(|status| status.get(0))
+            .and_then(|status| Symbol::try_from_val(&env, &status).ok())
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::InvalidRefund));
+
+        let refund_amount = if status == Symbol::new(&env, "Approved") || status == Symbol::new(&env, "Processed") {
+            i128::try_from_val(&env, &field("amount_approved"))
+                .unwrap_or_else(|_| panic_with_error!(&env, EscrowError::InvalidRefund))
+                .clamp(0, milestone.amount)
+        } else if status == Symbol::new(&env, "Rejected") {
+            0
+        } else {
+            panic_with_error!(&env, EscrowError::DisputePending);
+        };
+
+        let release_amount = milestone.amount - refund_amount;
+        let status = if refund_amount == 0 {
+            MilestoneStatus::Released
+        } else if release_amount == 0 {
+            MilestoneStatus::Refunded
+        } else {
+            MilestoneStatus::Split
+        };
+        Self::_settle(&env, escrow_id, escrow, index, milestone, release_amount, status);
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn get_escrow(env: Env, escrow_id: u64) -> Option<Escrow> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Escrow(escrow_id))
+    }
+
+    pub fn get_milestone(env: Env, escrow_id: u64, index: u32) -> Option<Milestone> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Milestone(escrow_id, index))
+    }
+
+    pub fn get_refund_processor(env: Env) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_refund_processor(&env)
+    }
+
+    pub fn get_escrow_count(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::EscrowCounter).unwrap_or(0)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _refund_processor(env: &Env) -> Option<Address> {
+        if let Some(registry) = env.storage().instance().get::<DataKey, Address>(&DataKey::Registry) {
+            let resolved: Option<Address> = env.invoke_contract(
+                &registry,
+                &Symbol::new(env, "lookup"),
+                (symbol_short!("refund"),).into_val(env),
+            );
+            if resolved.is_some() {
+                return resolved;
+            }
+        }
+        env.storage().instance().get(&DataKey::RefundProcessor)
+    }
+
+    fn _load_escrow(env: &Env, escrow_id: u64) -> Escrow {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Escrow(escrow_id))
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::EscrowNotFound))
+    }
+
+    fn _load_milestone(env: &Env, escrow_id: u64, index: u32) -> Milestone {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Milestone(escrow_id, index))
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::MilestoneNotFound))
+    }
+
+    fn _load_open_milestone(env: &Env, escrow_id: u64, index: u32) -> Milestone {
+        let milestone = Self::_load_milestone(env, escrow_id, index);
+        if milestone.status != MilestoneStatus::Pending && milestone.status != MilestoneStatus::Disputed {
+            panic_with_error!(&env, EscrowError::MilestoneAlreadySettled);
+        }
+        milestone
+    }
+
+    fn _save_milestone(env: &Env, escrow_id: u64, index: u32, milestone: &Milestone) {
+        let _ttl_key = DataKey::Milestone(escrow_id, index);
+        env.storage().persistent().set(&_ttl_key, milestone);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    /// Pay `release_amount` of a milestone to the beneficiary and the rest
+    /// back to the depositor, closing the escrow after its last milestone
+    fn _settle(
+        env: &Env,
+        escrow_id: u64,
+        mut escrow: Escrow,
+        index: u32,
+        mut milestone: Milestone,
+        release_amount: i128,
+        status: MilestoneStatus,
+    ) {
+        let refund_amount = milestone.amount - release_amount;
+        let token_client = token::Client::new(env, &escrow.token);
+        if release_amount > 0 {
+            token_client.transfer(&env.current_contract_address(), &escrow.beneficiary, &release_amount);
+            events::publish(env, EVENT_TAG, MilestoneReleased { escrow_id, index, amount: release_amount });
+        }
+        if refund_amount > 0 {
+            token_client.transfer(&env.current_contract_address(), &escrow.depositor, &refund_amount);
+            events::publish(env, EVENT_TAG, MilestoneRefunded { escrow_id, index, amount: refund_amount });
+        }
+
+        milestone.status = status;
+        milestone.settled_at = Some(env.ledger().timestamp());
+        Self::_save_milestone(env, escrow_id, index, &milestone);
+
+        escrow.released += release_amount;
+        escrow.refunded += refund_amount;
+        escrow.settled_count += 1;
+        if escrow.settled_count == escrow.milestone_count {
+            escrow.status = EscrowStatus::Closed;
+            events::publish(env, EVENT_TAG, EscrowClosed { escrow_id });
+        }
+        let _ttl_key = DataKey::Escrow(escrow_id);
+        env.storage().persistent().set(&_ttl_key, &escrow);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+}
+
+mod test;
@@ -0,0 +1,229 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    testutils::{Address as _, Ledger},
+    symbol_short, token, vec, Address, Env, String, Symbol,
+};
+
+// Stand-in for the refund processor: same entry points and field names,
+// with the decision set directly by the test
+#[contracttype]
+#[derive(Clone)]
+pub enum MockRefundStatus {
+    Requested,
+    Approved,
+    Rejected,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct MockRefund {
+    pub requester: Address,
+    pub on_behalf_of: Option<Address>,
+    pub amount_requested: i128,
+    pub amount_approved: i128,
+    pub status: MockRefundStatus,
+}
+
+#[contract]
+pub struct MockRefundProcessor;
+
+#[contractimpl]
+impl MockRefundProcessor {
+    pub fn request_refund_for(
+        env: Env,
+        filer: Address,
+        advertiser: Address,
+        _campaign_id: u64,
+        amount: i128,
+        _reason: String,
+    ) -> u64 {
+        filer.require_auth();
+        let refund = MockRefund {
+            requester: filer,
+            on_behalf_of: Some(advertiser),
+            amount_requested: amount,
+            amount_approved: 0,
+            status: MockRefundStatus::Requested,
+        };
+        env.storage().instance().set(&1u64, &refund);
+        1
+    }
+
+    pub fn decide(env: Env, refund_id: u64, status: MockRefundStatus, amount_approved: i128) {
+        let mut refund: MockRefund = env.storage().instance().get(&refund_id).unwrap();
+        refund.status = status;
+        refund.amount_approved = amount_approved;
+        env.storage().instance().set(&refund_id, &refund);
+    }
+
+    pub fn get_refund(env: Env, refund_id: u64) -> Option<MockRefund> {
+        env.storage().instance().get(&refund_id)
+    }
+}
+
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_address(env: Env, key: Symbol, address: Address) {
+        env.storage().instance().set(&key, &address);
+    }
+
+    pub fn lookup(env: Env, key: Symbol) -> Option<Address> {
+        env.storage().instance().get(&key)
+    }
+}
+
+fn setup(env: &Env) -> (EscrowContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, EscrowContract);
+    let client = EscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    (client, admin, token)
+}
+
+fn spec(env: &Env, amount: i128, deadline: u64, timeout_action: TimeoutAction) -> MilestoneSpec {
+    MilestoneSpec {
+        amount,
+        description: String::from_str(env, "deliverable"),
+        deadline,
+        timeout_action,
+    }
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, EscrowContract);
+    let client = EscrowContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    assert_eq!(client.get_escrow_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2301)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, EscrowContract);
+    let client = EscrowContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.initialize(&admin);
+}
+
+#[test]
+fn test_milestones_released_by_consent_arbiter_and_timeout() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, token) = setup(&env);
+    let balance = token::Client::new(&env, &token);
+
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&depositor, &1_000);
+
+    let milestones = vec![
+        &env,
+        spec(&env, 100, 1_000, TimeoutAction::Release),
+        spec(&env, 200, 1_000, TimeoutAction::Release),
+        spec(&env, 300, 2_000, TimeoutAction::Release),
+        spec(&env, 400, 2_000, TimeoutAction::Refund),
+    ];
+    let id = client.create_escrow(&depositor, &beneficiary, &arbiter, &token, &7, &milestones);
+    assert_eq!(balance.balance(&depositor), 0);
+    assert_eq!(client.get_escrow(&id).unwrap().total, 1_000);
+
+    // Mutual consent needs both parties
+    client.approve_milestone(&depositor, &id, &0);
+    assert_eq!(balance.balance(&beneficiary), 0);
+    client.approve_milestone(&beneficiary, &id, &0);
+    assert_eq!(balance.balance(&beneficiary), 100);
+    assert!(client.try_approve_milestone(&arbiter, &id, &1).is_err());
+
+    client.arbiter_release(&arbiter, &id, &1);
+    assert_eq!(balance.balance(&beneficiary), 300);
+    assert!(client.try_arbiter_refund(&arbiter, &id, &1).is_err());
+
+    assert!(client.try_claim_timeout(&id, &2).is_err());
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.claim_timeout(&id, &2);
+    client.claim_timeout(&id, &3);
+    assert_eq!(balance.balance(&beneficiary), 600);
+    assert_eq!(balance.balance(&depositor), 400);
+
+    let escrow = client.get_escrow(&id).unwrap();
+    assert!(escrow.status == EscrowStatus::Closed);
+    assert_eq!(escrow.released, 600);
+    assert_eq!(escrow.refunded, 400);
+}
+
+#[test]
+fn test_dispute_filed_with_refund_processor() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token) = setup(&env);
+    let balance = token::Client::new(&env, &token);
+
+    let processor_id = env.register_contract(None, MockRefundProcessor);
+    let processor = MockRefundProcessorClient::new(&env, &processor_id);
+    client.set_refund_processor(&admin, &processor_id);
+
+    let depositor = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&depositor, &500);
+    let milestones = vec![&env, spec(&env, 500, 1_000, TimeoutAction::Release)];
+    let id = client.create_escrow(&depositor, &beneficiary, &arbiter, &token, &7, &milestones);
+
+    let refund_id = client
+        .raise_dispute(&depositor, &id, &0, &String::from_str(&env, "impressions were bots"))
+        .unwrap();
+    let refund = processor.get_refund(&refund_id).unwrap();
+    assert_eq!(refund.requester, client.address);
+    assert_eq!(refund.on_behalf_of, Some(depositor.clone()));
+    assert_eq!(refund.amount_requested, 500);
+
+    // A disputed milestone doesn't time out and can't be resolved before review
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert!(client.try_claim_timeout(&id, &0).is_err());
+    assert!(client.try_resolve_dispute(&id, &0).is_err());
+
+    processor.decide(&refund_id, &MockRefundStatus::Approved, &200);
+    client.resolve_dispute(&id, &0);
+    assert_eq!(balance.balance(&depositor), 200);
+    assert_eq!(balance.balance(&beneficiary), 300);
+    assert!(client.get_milestone(&id, &0).unwrap().status == MilestoneStatus::Split);
+    assert!(client.get_escrow(&id).unwrap().status == EscrowStatus::Closed);
+}
+
+#[test]
+fn test_refund_processor_resolved_through_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _token) = setup(&env);
+
+    let fallback = Address::generate(&env);
+    client.set_refund_processor(&admin, &fallback);
+    let registry_id = env.register_contract(None, MockRegistry);
+    client.set_registry(&admin, &registry_id);
+    assert_eq!(client.get_refund_processor(), Some(fallback));
+
+    // Re-pointing the registry key moves the escrow without touching it
+    let processor_id = env.register_contract(None, MockRefundProcessor);
+    MockRegistryClient::new(&env, &registry_id).set_address(&symbol_short!("refund"), &processor_id);
+    assert_eq!(client.get_refund_processor(), Some(processor_id));
+}
@@ -0,0 +1,325 @@
+//! PulsarTrack - Fee Manager (Soroban)
+//! Single source of platform fees for PulsarTrack on Stellar.
+//!
+//! Fee schedules are keyed by operation type (e.g. `refund`, `campaign`,
+//! `auction`) and publisher tier, and are set by the DAO. A tier without
+//! its own schedule falls back to the operation's tier-0 schedule; an
+//! operation without any schedule is free. Collected fees go straight to
+//! the treasury, less the insurance pool's share if one is set. Contracts
+//! that earn platform revenue outright (e.g. subscription plans) forward it
+//! whole to the treasury through here so it is counted alongside fees.
+//!
+//! Events are published as ("fees", action, version) with a typed payload
+//! (see the Events section):
+//! - "insurance_set": `InsuranceSet`
+//! - "fee_schedule_set": `FeeScheduleSet`
+//! - "fee_schedule_removed": `FeeScheduleRemoved`
+//! - "fee_collected": `FeeCollected`
+//! - "revenue_collected": `RevenueCollected`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    token, Address, Env, Symbol,
+};
+pub use pulsartrack_errors::FeeManagerError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct FeeSchedule {
+    pub bps: u32,       // percentage fee on the amount
+    pub flat_fee: i128, // added on top of the percentage
+    pub min_fee: i128,
+    pub max_fee: i128, // 0 = uncapped
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct InsuranceSet {
+    pub pool: Address,
+    pub share_bps: u32,
+}
+contract_event!(InsuranceSet, "insurance_set");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct FeeScheduleSet {
+    pub op: Symbol,
+    pub tier: u32,
+    pub bps: u32,
+    pub flat_fee: i128,
+}
+contract_event!(FeeScheduleSet, "fee_schedule_set");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct FeeScheduleRemoved {
+    pub op: Symbol,
+    pub tier: u32,
+}
+contract_event!(FeeScheduleRemoved, "fee_schedule_removed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct FeeCollected {
+    pub op: Symbol,
+    pub payer: Address,
+    pub token: Address,
+    pub fee: i128,
+}
+contract_event!(FeeCollected, "fee_collected");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RevenueCollected {
+    pub op: Symbol,
+    pub payer: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+contract_event!(RevenueCollected, "revenue_collected");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Dao,
+    Treasury,
+    InsurancePool,
+    InsuranceShareBps,
+    Schedule(Symbol, u32),
+    Collected(Symbol, Address), // op, token
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const MAX_FEE_BPS: u32 = 2_000; // 20%
+const DEFAULT_TIER: u32 = 0;
+
+const EVENT_TAG: &str = "fees";
+
+#[contract]
+pub struct FeeManagerContract;
+
+#[contractimpl]
+impl FeeManagerContract {
+    pub fn initialize(env: Env, admin: Address, dao: Address, treasury: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, FeeManagerError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Dao, &dao);
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+    }
+
+    pub fn set_dao(env: Env, admin: Address, dao: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Dao, &dao);
+    }
+
+    pub fn set_treasury(env: Env, dao: Address, treasury: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_dao(&env, &dao);
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+    }
+
+    /// Send `share_bps` of every collected fee to the insurance pool
+    /// (DAO only)
+    pub fn set_insurance(env: Env, dao: Address, pool: Address, share_bps: u32) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_dao(&env, &dao);
+        if share_bps > 10_000 {
+            panic_with_error!(&env, FeeManagerError::InvalidShare);
+        }
+        env.storage().instance().set(&DataKey::InsurancePool, &pool);
+        env.storage().instance().set(&DataKey::InsuranceShareBps, &share_bps);
+
+        events::publish(&env, EVENT_TAG, InsuranceSet { pool, share_bps });
+    }
+
+    /// Set the fee schedule for an operation and publisher tier (DAO only)
+    pub fn set_fee_schedule(env: Env, dao: Address, op: Symbol, tier: u32, schedule: FeeSchedule) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_dao(&env, &dao);
+        if schedule.bps > MAX_FEE_BPS {
+            panic_with_error!(&env, FeeManagerError::FeeTooHigh);
+        }
+        if schedule.flat_fee < 0 || schedule.min_fee < 0 || schedule.max_fee < 0 {
+            panic_with_error!(&env, FeeManagerError::InvalidFee);
+        }
+        if schedule.max_fee > 0 && schedule.min_fee > schedule.max_fee {
+            panic_with_error!(&env, FeeManagerError::InvalidFeeBounds);
+        }
+
+        let _ttl_key = DataKey::Schedule(op.clone(), tier);
+        env.storage().persistent().set(&_ttl_key, &schedule);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, FeeScheduleSet { op, tier, bps: schedule.bps, flat_fee: schedule.flat_fee });
+    }
+
+    pub fn remove_fee_schedule(env: Env, dao: Address, op: Symbol, tier: u32) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_dao(&env, &dao);
+        env.storage().persistent().remove(&DataKey::Schedule(op.clone(), tier));
+
+        events::publish(&env, EVENT_TAG, FeeScheduleRemoved { op, tier });
+    }
+
+    /// Fee owed on `amount` for an operation at a publisher tier
+    pub fn quote_fee(env: Env, op: Symbol, tier: u32, amount: i128) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_quote(&env, &op, tier, amount)
+    }
+
+    /// Quote and charge the fee, paying it from `payer` into the treasury
+    /// and insurance pool.
+    /// Returns the fee charged.
+    pub fn collect_fee(env: Env, payer: Address, token: Address, op: Symbol, tier: u32, amount: i128) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        payer.require_auth();
+        if amount < 0 {
+            panic_with_error!(&env, FeeManagerError::InvalidAmount);
+        }
+
+        let fee = Self::_quote(&env, &op, tier, amount);
+        if fee == 0 {
+            return 0;
+        }
+
+        let token_client = token::Client::new(&env, &token);
+        let mut to_treasury = fee;
+        if let Some(pool) = env.storage().instance().get::<DataKey, Address>(&DataKey::InsurancePool) {
+            let share_bps: u32 = env.storage().instance().get(&DataKey::InsuranceShareBps).unwrap_or(0);
+            let insured = fee * share_bps as i128 / 10_000;
+            if insured > 0 {
+                token_client.transfer(&payer, &pool, &insured);
+                to_treasury -= insured;
+            }
+        }
+        if to_treasury > 0 {
+            let treasury: Address = env.storage().instance().get(&DataKey::Treasury).unwrap();
+            token_client.transfer(&payer, &treasury, &to_treasury);
+        }
+
+        Self::_record_collected(&env, &op, &token, fee);
+
+        events::publish(&env, EVENT_TAG, FeeCollected { op, payer, token, fee });
+
+        fee
+    }
+
+    /// Forward platform revenue from `payer` to the treasury in full and
+    /// count it under `op`
+    pub fn collect_revenue(env: Env, payer: Address, token: Address, op: Symbol, amount: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        payer.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&env, FeeManagerError::InvalidAmount);
+        }
+
+        let treasury: Address = env.storage().instance().get(&DataKey::Treasury).unwrap();
+        token::Client::new(&env, &token).transfer(&payer, &treasury, &amount);
+        Self::_record_collected(&env, &op, &token, amount);
+
+        events::publish(&env, EVENT_TAG, RevenueCollected { op, payer, token, amount });
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    /// The schedule a quote would use, after the tier-0 fallback
+    pub fn get_fee_schedule(env: Env, op: Symbol, tier: u32) -> Option<FeeSchedule> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_schedule(&env, &op, tier)
+    }
+
+    pub fn get_collected(env: Env, op: Symbol, token: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::Collected(op, token))
+            .unwrap_or(0)
+    }
+
+    pub fn get_treasury(env: Env) -> Address {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::Treasury).unwrap()
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _require_admin(env: &Env, admin: &Address) {
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *admin != stored_admin {
+            panic_with_error!(&env, FeeManagerError::Unauthorized);
+        }
+    }
+
+    fn _require_dao(env: &Env, dao: &Address) {
+        dao.require_auth();
+        let stored_dao: Address = env.storage().instance().get(&DataKey::Dao).unwrap();
+        if *dao != stored_dao {
+            panic_with_error!(&env, FeeManagerError::Unauthorized);
+        }
+    }
+
+    fn _record_collected(env: &Env, op: &Symbol, token: &Address, amount: i128) {
+        let _ttl_key = DataKey::Collected(op.clone(), token.clone());
+        let collected: i128 = env.storage().persistent().get(&_ttl_key).unwrap_or(0);
+        env.storage().persistent().set(&_ttl_key, &(collected + amount));
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _schedule(env: &Env, op: &Symbol, tier: u32) -> Option<FeeSchedule> {
+        let storage = env.storage().persistent();
+        storage
+            .get(&DataKey::Schedule(op.clone(), tier))
+            .or_else(|| storage.get(&DataKey::Schedule(op.clone(), DEFAULT_TIER)))
+    }
+
+    fn _quote(env: &Env, op: &Symbol, tier: u32, amount: i128) -> i128 {
+        let schedule = match Self::_schedule(env, op, tier) {
+            Some(s) => s,
+            None => return 0,
+        };
+        let mut fee = amount * schedule.bps as i128 / 10_000 + schedule.flat_fee;
+        if fee < schedule.min_fee {
+            fee = schedule.min_fee;
+        }
+        if schedule.max_fee > 0 && fee > schedule.max_fee {
+            fee = schedule.max_fee;
+        }
+        // Never charge more than the operation moves
+        fee.min(amount)
+    }
+}
+
+mod test;
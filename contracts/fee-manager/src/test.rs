@@ -0,0 +1,104 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, Symbol};
+
+fn setup(env: &Env) -> (FeeManagerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, FeeManagerContract);
+    let client = FeeManagerContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let dao = Address::generate(env);
+    let treasury = Address::generate(env);
+    client.initialize(&admin, &dao, &treasury);
+    (client, admin, dao, treasury)
+}
+
+fn schedule(bps: u32, flat_fee: i128, min_fee: i128, max_fee: i128) -> FeeSchedule {
+    FeeSchedule { bps, flat_fee, min_fee, max_fee }
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _dao, treasury) = setup(&env);
+    assert_eq!(client.get_treasury(), treasury);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2401)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, dao, treasury) = setup(&env);
+    client.initialize(&admin, &dao, &treasury);
+}
+
+#[test]
+fn test_quote_per_operation_and_tier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, dao, _treasury) = setup(&env);
+    let refund = Symbol::new(&env, "refund");
+    let auction = Symbol::new(&env, "auction");
+
+    assert!(client.try_set_fee_schedule(&admin, &refund, &0, &schedule(100, 0, 0, 0)).is_err());
+    assert!(client.try_set_fee_schedule(&dao, &refund, &0, &schedule(5_000, 0, 0, 0)).is_err());
+    assert!(client.try_set_fee_schedule(&dao, &refund, &0, &schedule(100, 0, 50, 10)).is_err());
+
+    client.set_fee_schedule(&dao, &refund, &0, &schedule(250, 5, 20, 500));
+    client.set_fee_schedule(&dao, &refund, &2, &schedule(100, 0, 0, 0));
+
+    assert_eq!(client.quote_fee(&refund, &0, &10_000), 255);
+    assert_eq!(client.quote_fee(&refund, &0, &100), 20); // min fee
+    assert_eq!(client.quote_fee(&refund, &0, &100_000), 500); // capped
+    assert_eq!(client.quote_fee(&refund, &0, &10), 10); // never above the amount
+    assert_eq!(client.quote_fee(&refund, &1, &10_000), 255); // falls back to tier 0
+    assert_eq!(client.quote_fee(&refund, &2, &10_000), 100);
+    assert_eq!(client.quote_fee(&auction, &2, &10_000), 0);
+
+    client.remove_fee_schedule(&dao, &refund, &2);
+    assert_eq!(client.quote_fee(&refund, &2, &10_000), 255);
+}
+
+#[test]
+fn test_collect_fee_pays_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, dao, treasury) = setup(&env);
+    let campaign = Symbol::new(&env, "campaign");
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &10_000);
+
+    assert_eq!(client.collect_fee(&payer, &token, &campaign, &0, &5_000), 0);
+
+    client.set_fee_schedule(&dao, &campaign, &0, &schedule(300, 0, 0, 0));
+    assert_eq!(client.collect_fee(&payer, &token, &campaign, &1, &5_000), 150);
+    assert_eq!(client.collect_fee(&payer, &token, &campaign, &0, &1_000), 30);
+
+    let balance = token::Client::new(&env, &token);
+    assert_eq!(balance.balance(&treasury), 180);
+    assert_eq!(balance.balance(&payer), 9_820);
+    assert_eq!(client.get_collected(&campaign, &token), 180);
+
+    let new_treasury = Address::generate(&env);
+    client.set_treasury(&dao, &new_treasury);
+    client.collect_fee(&payer, &token, &campaign, &0, &1_000);
+    assert_eq!(balance.balance(&new_treasury), 30);
+
+    // A slice of every fee funds the insurance pool
+    let pool = Address::generate(&env);
+    assert!(client.try_set_insurance(&dao, &pool, &10_001).is_err());
+    client.set_insurance(&dao, &pool, &2_000);
+    assert_eq!(client.collect_fee(&payer, &token, &campaign, &0, &5_000), 150);
+    assert_eq!(balance.balance(&pool), 30);
+    assert_eq!(balance.balance(&new_treasury), 150);
+
+    // Outright revenue goes to the treasury whole, without the pool's share
+    let subscription = Symbol::new(&env, "subscr");
+    client.collect_revenue(&payer, &token, &subscription, &1_000);
+    assert_eq!(balance.balance(&new_treasury), 1_150);
+    assert_eq!(balance.balance(&pool), 30);
+    assert_eq!(client.get_collected(&subscription, &token), 1_000);
+}
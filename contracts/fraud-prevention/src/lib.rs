@@ -4,9 +4,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Bytes, BytesN, Env, String,
 };
+pub use pulsartrack_errors::FraudPreventionError;
+use pulsartrack_events::{self as events, contract_event};
 
 // ============================================================
 // Data Types
@@ -41,6 +43,26 @@ pub struct VerificationCache {
     pub average_score: u32,
 }
 
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ViewVerified {
+    pub campaign_id: u64,
+    pub publisher: Address,
+    pub verified: bool,
+}
+contract_event!(ViewVerified, "view_verified");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherFlagged {
+    pub publisher: Address,
+}
+contract_event!(PublisherFlagged, "publisher_flagged");
+
 // ============================================================
 // Storage Keys
 // ============================================================
@@ -67,6 +89,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "fraud_prevention";
+
 #[contract]
 pub struct FraudPreventionContract;
 
@@ -76,7 +100,7 @@ impl FraudPreventionContract {
     pub fn initialize(env: Env, admin: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, FraudPreventionError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -119,7 +143,7 @@ impl FraudPreventionContract {
             .unwrap_or(10);
 
         if view_count >= max_views {
-            panic!("rate limit exceeded");
+            panic_with_error!(&env, FraudPreventionError::RateLimitExceeded);
         }
 
         // Generate view ID from campaign + publisher + viewer + timestamp
@@ -131,7 +155,7 @@ impl FraudPreventionContract {
             .persistent()
             .has(&DataKey::ViewRecord(view_id.clone()))
         {
-            panic!("duplicate view");
+            panic_with_error!(&env, FraudPreventionError::DuplicateView);
         }
 
         // Calculate verification score
@@ -202,13 +226,10 @@ impl FraudPreventionContract {
                 .set(&DataKey::VerifyCounter, &(counter + 1));
         }
 
-        env.events().publish(
-            (symbol_short!("view"), symbol_short!("verified")),
-            (campaign_id, publisher, verified),
-        );
+        events::publish(&env, EVENT_TAG, ViewVerified { campaign_id, publisher, verified });
 
         if !verified {
-            panic!("verification failed");
+            panic_with_error!(&env, FraudPreventionError::VerificationFailed);
         }
         true
     }
@@ -245,10 +266,7 @@ impl FraudPreventionContract {
         env.storage().persistent().set(&key, &activity);
         env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("publisher"), symbol_short!("flagged")),
-            publisher,
-        );
+        events::publish(&env, EVENT_TAG, PublisherFlagged { publisher });
     }
 
     /// Admin: clear suspicious flag
@@ -257,7 +275,7 @@ impl FraudPreventionContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, FraudPreventionError::Unauthorized);
         }
 
         env.storage()
@@ -271,10 +289,10 @@ impl FraudPreventionContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, FraudPreventionError::Unauthorized);
         }
         if threshold < 50 || threshold > 100 {
-            panic!("invalid threshold");
+            panic_with_error!(&env, FraudPreventionError::InvalidThreshold);
         }
         env.storage()
             .instance()
@@ -0,0 +1,489 @@
+//! PulsarTrack - Fraud Registry (Soroban)
+//! Bonded whistleblower reports against publishers and advertisers on Stellar.
+//!
+//! A reporter stakes a bond behind an evidence hash. The accused may contest
+//! by posting a matching bond. Adjudicators then confirm or reject:
+//! - Confirmed: the reporter's bond is returned, a contesting bond is slashed
+//!   (the reporter's share to the reporter, the rest to the treasury), and a
+//!   reported publisher is slashed in the reputation contract and suspended in
+//!   the verification contract.
+//! - Rejected: the reporter's bond goes to the treasury and any contesting
+//!   bond is returned.
+//!
+//! The registry must be registered as an oracle in publisher-reputation and
+//! as a verifier in publisher-verification for the cross-contract penalties
+//! to apply; a failing hook is reported in the confirmation event rather than
+//! blocking the decision.
+//!
+//! Events are published as ("fraud", action, version) with a typed payload
+//! (see the Events section):
+//! - "report_submitted": `ReportSubmitted`
+//! - "report_contested": `ReportContested`
+//! - "report_confirmed": `ReportConfirmed`
+//! - "report_rejected": `ReportRejected`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    token, Address, BytesN, Env, IntoVal, Symbol, Val, Vec,
+};
+pub use pulsartrack_errors::FraudRegistryError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum TargetRole {
+    Publisher,
+    Advertiser,
+}
+
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum FraudCategory {
+    ClickFraud,
+    BotTraffic,
+    AdStacking,
+    DomainSpoofing,
+    PaymentFraud,
+    Other,
+}
+
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum ReportStatus {
+    Open,
+    Contested,
+    Confirmed,
+    Rejected,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct FraudConfig {
+    pub min_bond: i128,
+    pub reporter_share_bps: u32, // of a slashed contesting bond
+    pub slash_penalty: u32,      // reputation points removed from a publisher
+    pub suspension_secs: u64,    // 0 suspends indefinitely
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct FraudReport {
+    pub reporter: Address,
+    pub target: Address,
+    pub role: TargetRole,
+    pub category: FraudCategory,
+    pub evidence_hash: BytesN<32>,
+    pub bond: i128,
+    pub contest_bond: i128,
+    pub status: ReportStatus,
+    pub submitted_at: u64,
+    pub resolved_at: Option<u64>,
+    pub resolved_by: Option<Address>,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReportSubmitted {
+    pub report_id: u64,
+    pub reporter: Address,
+    pub target: Address,
+    pub category: FraudCategory,
+}
+contract_event!(ReportSubmitted, "report_submitted");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReportContested {
+    pub report_id: u64,
+    pub accused: Address,
+}
+contract_event!(ReportContested, "report_contested");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReportConfirmed {
+    pub report_id: u64,
+    pub target: Address,
+    pub slashed: bool,
+    pub suspended: bool,
+}
+contract_event!(ReportConfirmed, "report_confirmed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReportRejected {
+    pub report_id: u64,
+    pub target: Address,
+}
+contract_event!(ReportRejected, "report_rejected");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    BondToken,
+    Treasury,
+    Config,
+    ReputationContract,
+    VerificationContract,
+    Adjudicators,
+    ReportCounter,
+    Report(u64),
+    ConfirmedCount(Address),
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const EVENT_TAG: &str = "fraud";
+
+#[contract]
+pub struct FraudRegistryContract;
+
+#[contractimpl]
+impl FraudRegistryContract {
+    pub fn initialize(env: Env, admin: Address, bond_token: Address, treasury: Address, config: FraudConfig) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, FraudRegistryError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        Self::_validate_config(&env, &config);
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::BondToken, &bond_token);
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+        env.storage().instance().set(&DataKey::Config, &config);
+        env.storage().instance().set(&DataKey::ReportCounter, &0u64);
+    }
+
+    pub fn set_config(env: Env, admin: Address, config: FraudConfig) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        Self::_validate_config(&env, &config);
+        env.storage().instance().set(&DataKey::Config, &config);
+    }
+
+    /// Point the registry at the contracts it penalizes publishers in (admin only)
+    pub fn set_penalty_contracts(env: Env, admin: Address, reputation: Address, verification: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::ReputationContract, &reputation);
+        env.storage().instance().set(&DataKey::VerificationContract, &verification);
+    }
+
+    pub fn add_adjudicator(env: Env, admin: Address, adjudicator: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        let mut adjudicators = Self::get_adjudicators(env.clone());
+        if adjudicators.contains(&adjudicator) {
+            panic_with_error!(&env, FraudRegistryError::AlreadyAnAdjudicator);
+        }
+        adjudicators.push_back(adjudicator);
+        env.storage().instance().set(&DataKey::Adjudicators, &adjudicators);
+    }
+
+    pub fn remove_adjudicator(env: Env, admin: Address, adjudicator: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        let mut adjudicators = Self::get_adjudicators(env.clone());
+        let index = adjudicators.first_index_of(&adjudicator).unwrap_or_else(|| panic_with_error!(&env, FraudRegistryError::NotAnAdjudicator));
+        adjudicators.remove(index);
+        env.storage().instance().set(&DataKey::Adjudicators, &adjudicators);
+    }
+
+    /// Report a publisher or advertiser, staking `bond` behind the evidence
+    pub fn submit_report(
+        env: Env,
+        reporter: Address,
+        target: Address,
+        role: TargetRole,
+        category: FraudCategory,
+        evidence_hash: BytesN<32>,
+        bond: i128,
+    ) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        reporter.require_auth();
+
+        if reporter == target {
+            panic_with_error!(&env, FraudRegistryError::CannotReportSelf);
+        }
+        let config: FraudConfig = env.storage().instance().get(&DataKey::Config).unwrap();
+        if bond < config.min_bond {
+            panic_with_error!(&env, FraudRegistryError::BondBelowMinimum);
+        }
+
+        let bond_token: Address = env.storage().instance().get(&DataKey::BondToken).unwrap();
+        token::Client::new(&env, &bond_token).transfer(&reporter, &env.current_contract_address(), &bond);
+
+        let counter: u64 = env.storage().instance().get(&DataKey::ReportCounter).unwrap_or(0);
+        let report_id = counter + 1;
+
+        let report = FraudReport {
+            reporter: reporter.clone(),
+            target: target.clone(),
+            role,
+            category: category.clone(),
+            evidence_hash,
+            bond,
+            contest_bond: 0,
+            status: ReportStatus::Open,
+            submitted_at: env.ledger().timestamp(),
+            resolved_at: None,
+            resolved_by: None,
+        };
+        Self::_save_report(&env, report_id, &report);
+        env.storage().instance().set(&DataKey::ReportCounter, &report_id);
+
+        events::publish(&env, EVENT_TAG, ReportSubmitted { report_id, reporter, target, category });
+
+        report_id
+    }
+
+    /// Contest an open report by matching the reporter's bond (accused only)
+    pub fn contest_report(env: Env, accused: Address, report_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        accused.require_auth();
+
+        let mut report = Self::_load_report(&env, report_id);
+        if accused != report.target {
+            panic_with_error!(&env, FraudRegistryError::Unauthorized);
+        }
+        if report.status != ReportStatus::Open {
+            panic_with_error!(&env, FraudRegistryError::ReportNotOpen);
+        }
+
+        let bond_token: Address = env.storage().instance().get(&DataKey::BondToken).unwrap();
+        token::Client::new(&env, &bond_token).transfer(&accused, &env.current_contract_address(), &report.bond);
+        report.contest_bond = report.bond;
+        report.status = ReportStatus::Contested;
+        Self::_save_report(&env, report_id, &report);
+
+        events::publish(&env, EVENT_TAG, ReportContested { report_id, accused });
+    }
+
+    /// Uphold a report and penalize its target (adjudicator only)
+    pub fn confirm_report(env: Env, adjudicator: Address, report_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_adjudicator(&env, &adjudicator);
+
+        let mut report = Self::_load_pending_report(&env, report_id);
+        let config: FraudConfig = env.storage().instance().get(&DataKey::Config).unwrap();
+        let bond_token: Address = env.storage().instance().get(&DataKey::BondToken).unwrap();
+        let treasury: Address = env.storage().instance().get(&DataKey::Treasury).unwrap();
+        let token_client = token::Client::new(&env, &bond_token);
+
+        let reporter_share = report.contest_bond * config.reporter_share_bps as i128 / 10_000;
+        token_client.transfer(&env.current_contract_address(), &report.reporter, &(report.bond + reporter_share));
+        if report.contest_bond > reporter_share {
+            token_client.transfer(&env.current_contract_address(), &treasury, &(report.contest_bond - reporter_share));
+        }
+
+        let (slashed, suspended) = if report.role == TargetRole::Publisher {
+            Self::_penalize_publisher(&env, &report, &config)
+        } else {
+            (false, false)
+        };
+
+        report.status = ReportStatus::Confirmed;
+        report.resolved_at = Some(env.ledger().timestamp());
+        report.resolved_by = Some(adjudicator);
+        Self::_save_report(&env, report_id, &report);
+
+        let count_key = DataKey::ConfirmedCount(report.target.clone());
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage().persistent().set(&count_key, &(count + 1));
+        env.storage().persistent().extend_ttl(&count_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, ReportConfirmed { report_id, target: report.target, slashed, suspended });
+    }
+
+    /// Dismiss a report, forfeiting the reporter's bond (adjudicator only)
+    pub fn reject_report(env: Env, adjudicator: Address, report_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_adjudicator(&env, &adjudicator);
+
+        let mut report = Self::_load_pending_report(&env, report_id);
+        let bond_token: Address = env.storage().instance().get(&DataKey::BondToken).unwrap();
+        let treasury: Address = env.storage().instance().get(&DataKey::Treasury).unwrap();
+        let token_client = token::Client::new(&env, &bond_token);
+
+        token_client.transfer(&env.current_contract_address(), &treasury, &report.bond);
+        if report.contest_bond > 0 {
+            token_client.transfer(&env.current_contract_address(), &report.target, &report.contest_bond);
+        }
+
+        report.status = ReportStatus::Rejected;
+        report.resolved_at = Some(env.ledger().timestamp());
+        report.resolved_by = Some(adjudicator);
+        Self::_save_report(&env, report_id, &report);
+
+        events::publish(&env, EVENT_TAG, ReportRejected { report_id, target: report.target });
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn get_report(env: Env, report_id: u64) -> Option<FraudReport> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Report(report_id))
+    }
+
+    pub fn get_report_count(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::ReportCounter).unwrap_or(0)
+    }
+
+    /// Number of confirmed reports against an address
+    pub fn get_confirmed_count(env: Env, target: Address) -> u32 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::ConfirmedCount(target)).unwrap_or(0)
+    }
+
+    pub fn get_config(env: Env) -> FraudConfig {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::Config).unwrap()
+    }
+
+    pub fn get_adjudicators(env: Env) -> Vec<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .instance()
+            .get(&DataKey::Adjudicators)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _require_admin(env: &Env, admin: &Address) {
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *admin != stored_admin {
+            panic_with_error!(&env, FraudRegistryError::Unauthorized);
+        }
+    }
+
+    fn _require_adjudicator(env: &Env, adjudicator: &Address) {
+        adjudicator.require_auth();
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        let adjudicators: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Adjudicators)
+            .unwrap_or(Vec::new(env));
+        if *adjudicator != admin && !adjudicators.contains(adjudicator) {
+            panic_with_error!(&env, FraudRegistryError::Unauthorized);
+        }
+    }
+
+    fn _validate_config(env: &Env, config: &FraudConfig) {
+        if config.min_bond <= 0 || config.reporter_share_bps > 10_000 {
+            panic_with_error!(&env, FraudRegistryError::InvalidConfig);
+        }
+    }
+
+    fn _load_report(env: &Env, report_id: u64) -> FraudReport {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Report(report_id))
+            .unwrap_or_else(|| panic_with_error!(&env, FraudRegistryError::ReportNotFound))
+    }
+
+    fn _load_pending_report(env: &Env, report_id: u64) -> FraudReport {
+        let report = Self::_load_report(env, report_id);
+        if report.status != ReportStatus::Open && report.status != ReportStatus::Contested {
+            panic_with_error!(&env, FraudRegistryError::ReportAlreadyResolved);
+        }
+        report
+    }
+
+    fn _save_report(env: &Env, report_id: u64, report: &FraudReport) {
+        let _ttl_key = DataKey::Report(report_id);
+        env.storage().persistent().set(&_ttl_key, report);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    /// Slash the publisher's reputation and suspend its verification,
+    /// returning which of the two calls succeeded
+    fn _penalize_publisher(env: &Env, report: &FraudReport, config: &FraudConfig) -> (bool, bool) {
+        let registry = env.current_contract_address();
+
+        let slashed = match env
+            .storage()
+            .instance()
+            .get::<DataKey, Address>(&DataKey::ReputationContract)
+        {
+            Some(reputation) if config.slash_penalty > 0 => {
+                let args: Vec<Val> = Vec::from_array(
+                    env,
+                    [
+                        registry.into_val(env),
+                        report.target.into_val(env),
+                        config.slash_penalty.into_val(env),
+                        report.evidence_hash.into_val(env),
+                    ],
+                );
+                matches!(
+                    env.try_invoke_contract::<u64, soroban_sdk::Error>(
+                        &reputation,
+                        &Symbol::new(env, "propose_slash"),
+                        args,
+                    ),
+                    Ok(Ok(_))
+                )
+            }
+            _ => false,
+        };
+
+        let suspended = match env
+            .storage()
+            .instance()
+            .get::<DataKey, Address>(&DataKey::VerificationContract)
+        {
+            Some(verification) => {
+                let args: Vec<Val> = Vec::from_array(
+                    env,
+                    [
+                        registry.into_val(env),
+                        report.target.into_val(env),
+                        config.suspension_secs.into_val(env),
+                    ],
+                );
+                matches!(
+                    env.try_invoke_contract::<(), soroban_sdk::Error>(
+                        &verification,
+                        &Symbol::new(env, "suspend_publisher"),
+                        args,
+                    ),
+                    Ok(Ok(()))
+                )
+            }
+            None => false,
+        };
+
+        (slashed, suspended)
+    }
+}
+
+mod test;
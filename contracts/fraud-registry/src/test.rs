@@ -0,0 +1,162 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::Address as _,
+    token, Address, BytesN, Env,
+};
+
+#[contract]
+pub struct MockReputation;
+
+#[contractimpl]
+impl MockReputation {
+    pub fn propose_slash(env: Env, oracle: Address, publisher: Address, penalty: u32, _evidence_hash: BytesN<32>) -> u64 {
+        oracle.require_auth();
+        env.storage().instance().set(&publisher, &penalty);
+        1
+    }
+
+    pub fn penalty(env: Env, publisher: Address) -> u32 {
+        env.storage().instance().get(&publisher).unwrap_or(0)
+    }
+}
+
+// Only knows publishers it was told about, like the real registry
+#[contract]
+pub struct MockVerification;
+
+#[contractimpl]
+impl MockVerification {
+    pub fn register(env: Env, publisher: Address) {
+        env.storage().instance().set(&publisher, &0u64);
+    }
+
+    pub fn suspend_publisher(env: Env, verifier: Address, publisher: Address, duration_secs: u64) {
+        verifier.require_auth();
+        if !env.storage().instance().has(&publisher) {
+            panic!("publisher not found");
+        }
+        env.storage().instance().set(&publisher, &duration_secs);
+    }
+
+    pub fn suspension(env: Env, publisher: Address) -> u64 {
+        env.storage().instance().get(&publisher).unwrap_or(0)
+    }
+}
+
+fn config() -> FraudConfig {
+    FraudConfig {
+        min_bond: 100,
+        reporter_share_bps: 5_000,
+        slash_penalty: 25,
+        suspension_secs: 86_400,
+    }
+}
+
+fn setup(env: &Env) -> (FraudRegistryContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, FraudRegistryContract);
+    let client = FraudRegistryContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let treasury = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    client.initialize(&admin, &token, &treasury, &config());
+    (client, admin, token, treasury)
+}
+
+fn funded(env: &Env, token: &Address, amount: i128) -> Address {
+    let account = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&account, &amount);
+    account
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _token, _treasury) = setup(&env);
+    assert_eq!(client.get_report_count(), 0);
+    assert_eq!(client.get_config().min_bond, 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2601)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token, treasury) = setup(&env);
+    client.initialize(&admin, &token, &treasury, &config());
+}
+
+#[test]
+fn test_confirmed_report_penalizes_publisher_and_pays_reporter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token, treasury) = setup(&env);
+    let balance = token::Client::new(&env, &token);
+
+    let reputation_id = env.register_contract(None, MockReputation);
+    let verification_id = env.register_contract(None, MockVerification);
+    let reputation = MockReputationClient::new(&env, &reputation_id);
+    let verification = MockVerificationClient::new(&env, &verification_id);
+    client.set_penalty_contracts(&admin, &reputation_id, &verification_id);
+    let adjudicator = Address::generate(&env);
+    client.add_adjudicator(&admin, &adjudicator);
+
+    let reporter = funded(&env, &token, 1_000);
+    let publisher = funded(&env, &token, 1_000);
+    verification.register(&publisher);
+    let evidence = BytesN::from_array(&env, &[4u8; 32]);
+
+    assert!(client
+        .try_submit_report(&reporter, &publisher, &TargetRole::Publisher, &FraudCategory::BotTraffic, &evidence, &50)
+        .is_err());
+    let id = client.submit_report(&reporter, &publisher, &TargetRole::Publisher, &FraudCategory::BotTraffic, &evidence, &200);
+    client.contest_report(&publisher, &id);
+    assert!(client.get_report(&id).unwrap().status == ReportStatus::Contested);
+
+    assert!(client.try_confirm_report(&reporter, &id).is_err());
+    client.confirm_report(&adjudicator, &id);
+
+    // Reporter gets the bond back plus half the slashed contesting bond
+    assert_eq!(balance.balance(&reporter), 1_100);
+    assert_eq!(balance.balance(&publisher), 800);
+    assert_eq!(balance.balance(&treasury), 100);
+    assert_eq!(reputation.penalty(&publisher), 25);
+    assert_eq!(verification.suspension(&publisher), 86_400);
+    assert_eq!(client.get_confirmed_count(&publisher), 1);
+    assert!(client.try_reject_report(&adjudicator, &id).is_err());
+}
+
+#[test]
+fn test_rejected_report_forfeits_bond_and_failed_hooks_do_not_block() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token, treasury) = setup(&env);
+    let balance = token::Client::new(&env, &token);
+
+    let reputation_id = env.register_contract(None, MockReputation);
+    let verification_id = env.register_contract(None, MockVerification);
+    client.set_penalty_contracts(&admin, &reputation_id, &verification_id);
+
+    let reporter = funded(&env, &token, 1_000);
+    let advertiser = funded(&env, &token, 1_000);
+    let evidence = BytesN::from_array(&env, &[5u8; 32]);
+
+    let false_report = client.submit_report(&reporter, &advertiser, &TargetRole::Advertiser, &FraudCategory::PaymentFraud, &evidence, &300);
+    client.contest_report(&advertiser, &false_report);
+    client.reject_report(&admin, &false_report);
+    assert_eq!(balance.balance(&treasury), 300);
+    assert_eq!(balance.balance(&advertiser), 1_000);
+    assert_eq!(balance.balance(&reporter), 700);
+
+    // The verification contract doesn't know this publisher; the report is
+    // still confirmed and the reputation slash still lands
+    let publisher = Address::generate(&env);
+    let id = client.submit_report(&reporter, &publisher, &TargetRole::Publisher, &FraudCategory::ClickFraud, &evidence, &100);
+    client.confirm_report(&admin, &id);
+    assert!(client.get_report(&id).unwrap().status == ReportStatus::Confirmed);
+    assert_eq!(MockReputationClient::new(&env, &reputation_id).penalty(&publisher), 25);
+    assert_eq!(balance.balance(&reporter), 700);
+}
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Env, String, Vec,
 };
+pub use pulsartrack_errors::GovernanceCoreError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -36,6 +38,13 @@ pub struct GovernanceParams {
     pub max_active_proposals: u32,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct RoleGranted {
+    pub account: Address,
+}
+contract_event!(RoleGranted, "role_granted");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -50,6 +59,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 34_560;
 const PERSISTENT_BUMP_AMOUNT: u32 = 259_200;
 
+const EVENT_TAG: &str = "governance";
+
 #[contract]
 pub struct GovernanceCoreContract;
 
@@ -58,7 +69,7 @@ impl GovernanceCoreContract {
     pub fn initialize(env: Env, admin: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, GovernanceCoreError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -80,7 +91,7 @@ impl GovernanceCoreContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, GovernanceCoreError::Unauthorized);
         }
 
         let grant = RoleGrant {
@@ -107,10 +118,7 @@ impl GovernanceCoreContract {
             .instance()
             .set(&DataKey::RoleCount(role), &(count + 1));
 
-        env.events().publish(
-            (symbol_short!("role"), symbol_short!("granted")),
-            account,
-        );
+        events::publish(&env, EVENT_TAG, RoleGranted { account });
     }
 
     pub fn revoke_role(env: Env, admin: Address, account: Address, role: Role) {
@@ -118,7 +126,7 @@ impl GovernanceCoreContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, GovernanceCoreError::Unauthorized);
         }
 
         env.storage()
@@ -159,7 +167,7 @@ impl GovernanceCoreContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, GovernanceCoreError::Unauthorized);
         }
         env.storage().instance().set(&DataKey::GovernanceParams, &params);
     }
@@ -169,7 +177,7 @@ impl GovernanceCoreContract {
         env.storage()
             .instance()
             .get(&DataKey::GovernanceParams)
-            .expect("not initialized")
+            .unwrap_or_else(|| panic_with_error!(&env, GovernanceCoreError::NotInitialized))
     }
 
     pub fn get_role_grant(env: Env, account: Address, role: Role) -> Option<RoleGrant> {
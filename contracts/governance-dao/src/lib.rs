@@ -1,17 +1,38 @@
 //! PulsarTrack - Governance DAO (Soroban)
 //! On-chain DAO governance with proposals and voting on Stellar.
 //!
-//! Events:
-//! - ("proposal", "created"): [proposal_id: u64, proposer: Address]
-//! - ("gov", "voted"): [proposal_id: u64, voter: Address, power: i128]
-//! - ("proposal", "finalized"): [proposal_id: u64, status: ProposalStatus]
+//! Events are published as ("dao", action, version) with a typed payload
+//! (see the Events section):
+//! - "proposal_created": `ProposalCreated`
+//! - "vote_cast": `VoteCast`
+//! - "vote_cast_by_sig": `VoteCastBySig`
+//! - "vote_withdrawn": `VoteWithdrawn`
+//! - "metadata_attached": `MetadataAttached`
+//! - "proposal_finalized": `ProposalFinalized`
+//! - "proposal_queued": `ProposalQueued`
+//! - "proposal_vetoed": `ProposalVetoed`
+//! - "veto_revoked": `VetoRevoked`
+//! - "finalize_bounty_paid": `FinalizeBountyPaid`
+//! - "kind_config_set": `KindConfigSet`
+//! - "param_set": `ParamSet`
+//! - "deposit_refunded": `DepositRefunded`
+//! - "deposit_forfeited": `DepositForfeited`
+//! - "proposal_executed": `ProposalExecuted`
+//! - "proposal_expired": `ProposalExpired`
+//! - "upgraded", "migrated", "upgrade_authority": shared payloads from
+//!   `pulsartrack-events`
 
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
-    Address, Env, String,
+    contract, contractimpl, contracttype, panic_with_error, symbol_short, token, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
 };
+pub use pulsartrack_errors::DaoError;
+use pulsartrack_events::{self as events, contract_event};
+use pulsartrack_access::Ownable;
+use pulsartrack_upgrade::{ContractVersion, Upgradeable};
+use pulsartrack_storage::{self as storage, Persistent, TtlPolicy};
 
 // ============================================================
 // Data Types
@@ -23,45 +44,293 @@ pub enum ProposalStatus {
     Active,
     Passed,
     Rejected,
+    Queued,
+    Vetoed,
     Executed,
     Cancelled,
     Expired,
 }
 
 #[contracttype]
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub enum VoteChoice {
     For,
     Against,
     Abstain,
 }
 
+/// Category of a proposal; each can carry its own voting rules
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum ProposalKind {
+    General,
+    TreasurySpend,
+    ParameterChange,
+    ContractUpgrade,
+    VetoOverride, // created only through `propose_veto_override`
+    Emergency,    // created only through `create_emergency_proposal`
+}
+
+/// Voting rules for one proposal kind
+#[contracttype]
+#[derive(Clone)]
+pub struct KindConfig {
+    pub quorum_bps: u32,    // of governance token supply
+    pub threshold_bps: u32, // of For + Against votes
+    pub voting_period: u32, // in ledgers
+    pub timelock_secs: u64,
+}
+
+/// Who may open emergency proposals and how many may run at once
+#[contracttype]
+#[derive(Clone)]
+pub struct EmergencyConfig {
+    pub stake_threshold: i128, // snapshot votes needed by non-guardian proposers
+    pub max_active: u32,
+}
+
+/// Call made by the DAO itself when a passed proposal is executed. Treasury
+/// grants call the multisig treasury's `execute_dao_payment(dao, recipient,
+/// token, amount)`, which only pays out when this DAO is the caller.
+#[contracttype]
+#[derive(Clone)]
+pub struct ProposalAction {
+    pub target: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct Proposal {
     pub proposer: Address,
     pub title: String,
     pub description: String,
-    pub target_contract: Option<Address>,
+    pub kind: ProposalKind,
+    pub has_action: bool, // payload stored under `DataKey::Action`
     pub status: ProposalStatus,
     pub votes_for: i128,
     pub votes_against: i128,
     pub votes_abstain: i128,
     pub quorum_required: i128,
-    pub threshold_pct: u32, // percentage to pass
+    pub threshold_bps: u32, // share of decisive votes needed to pass
+    pub timelock_secs: u64,
     pub start_ledger: u32,
     pub end_ledger: u32,
+    pub snapshot_ledger: u32, // voting power is read as of this ledger, the one before start
+    pub voting_mode: VotingMode,
     pub created_at: u64,
+    pub eta: Option<u64>,  // earliest execution time once queued
     pub executed_at: Option<u64>,
+    pub execution_result: Option<Bytes>, // XDR of the action's return value
+}
+
+/// A voter's current vote on one proposal, as returned by their history
+#[contracttype]
+#[derive(Clone)]
+pub struct VoteRecord {
+    pub proposal_id: u64,
+    pub vote: Vote,
+}
+
+/// Off-chain document anchored to a proposal, e.g. the full text or forum thread
+#[contracttype]
+#[derive(Clone)]
+pub struct ProposalMetadata {
+    pub content_hash: BytesN<32>,
+    pub uri: String,
+    pub attached_at: u64,
+}
+
+/// Guardian veto recorded against a queued proposal
+#[contracttype]
+#[derive(Clone)]
+pub struct Veto {
+    pub guardian: Address,
+    pub reason_hash: BytesN<32>,
+    pub vetoed_at: u64,
+}
+
+/// Deposit required to open a proposal
+#[contracttype]
+#[derive(Clone)]
+pub struct DepositConfig {
+    pub token: Address,
+    pub amount: i128,
+    pub treasury: Address,
+}
+
+/// Reward paid to whoever finalizes an ended proposal
+#[contracttype]
+#[derive(Clone)]
+pub struct FinalizeBounty {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Deposit held against a single proposal until it is settled
+#[contracttype]
+#[derive(Clone)]
+pub struct ProposalDeposit {
+    pub token: Address,
+    pub amount: i128,
+    pub treasury: Address,
+}
+
+/// How snapshot votes translate into tallied weight
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum VotingMode {
+    Linear,
+    Quadratic,    // integer square root of the snapshot votes
+    Capped(i128), // snapshot votes, up to a per-address maximum
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub struct Vote {
     pub choice: VoteChoice,
-    pub power: i128,
+    pub power: i128,     // weight counted in the tallies
+    pub raw_power: i128, // snapshot votes before the voting mode is applied
     pub voted_at: u64,
+    pub overrides: Option<Address>, // delegate whose representation this vote replaced
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ProposalCreated {
+    pub proposal_id: u64,
+    pub proposer: Address,
+}
+contract_event!(ProposalCreated, "proposal_created");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct VoteCast {
+    pub proposal_id: u64,
+    pub voter: Address,
+    pub choice: VoteChoice,
+    pub power: i128,
+}
+contract_event!(VoteCast, "vote_cast");
+
+/// Follows the `vote_cast` event for a relayed, signed vote
+#[contracttype]
+#[derive(Clone)]
+pub struct VoteCastBySig {
+    pub proposal_id: u64,
+    pub voter: Address,
+}
+contract_event!(VoteCastBySig, "vote_cast_by_sig");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct VoteWithdrawn {
+    pub proposal_id: u64,
+    pub voter: Address,
+}
+contract_event!(VoteWithdrawn, "vote_withdrawn");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct MetadataAttached {
+    pub proposal_id: u64,
+    pub content_hash: BytesN<32>,
+}
+contract_event!(MetadataAttached, "metadata_attached");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ProposalFinalized {
+    pub proposal_id: u64,
+    pub status: ProposalStatus,
+}
+contract_event!(ProposalFinalized, "proposal_finalized");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ProposalQueued {
+    pub proposal_id: u64,
+    pub eta: u64,
+}
+contract_event!(ProposalQueued, "proposal_queued");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ProposalVetoed {
+    pub proposal_id: u64,
+    pub guardian: Address,
+    pub reason_hash: BytesN<32>,
+}
+contract_event!(ProposalVetoed, "proposal_vetoed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct VetoRevoked {
+    pub proposal_id: u64,
+}
+contract_event!(VetoRevoked, "veto_revoked");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct FinalizeBountyPaid {
+    pub proposal_id: u64,
+    pub finalizer: Address,
+    pub amount: i128,
+}
+contract_event!(FinalizeBountyPaid, "finalize_bounty_paid");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct KindConfigSet {
+    pub kind: ProposalKind,
+    pub config: KindConfig,
+}
+contract_event!(KindConfigSet, "kind_config_set");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ParamSet {
+    pub name: Symbol,
+    pub value: i128,
+}
+contract_event!(ParamSet, "param_set");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct DepositRefunded {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub amount: i128,
+}
+contract_event!(DepositRefunded, "deposit_refunded");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct DepositForfeited {
+    pub proposal_id: u64,
+    pub treasury: Address,
+    pub amount: i128,
+}
+contract_event!(DepositForfeited, "deposit_forfeited");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ProposalExecuted {
+    pub proposal_id: u64,
+}
+contract_event!(ProposalExecuted, "proposal_executed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ProposalExpired {
+    pub proposal_id: u64,
 }
+contract_event!(ProposalExpired, "proposal_expired");
 
 // ============================================================
 // Storage Keys
@@ -71,14 +340,39 @@ pub struct Vote {
 pub enum DataKey {
     Admin,
     GovernanceToken,
+    Registry,
     ProposalCounter,
     VotingPeriod,
     QuorumRequired,
     PassThreshold,
     ProposerMinTokens,
+    TimelockDelay,
+    GracePeriod,
+    DepositConfig,
+    Deposit(u64),
+    KindConfig(ProposalKind),
+    Overridden(u64, Address),
+    Guardian,
+    Veto(u64),
+    EmergencyConfig,
+    ActiveEmergencies,
+    VoteKey(Address),
+    SigNonce(Address),
+    VotingMode,
+    FinalizeBounty,
+    BountyPool,
+    VoterVoteCount(Address),
+    VoterVote(Address, u32), // (voter, index) -> proposal id, in first-vote order
+    VoteIndexed(u64, Address), // power delegators took back from a delegate
     Proposal(u64),
+    StatusProposals(ProposalStatus), // Vec<u64>, in the order proposals reached the status
+    Action(u64),
+    Metadata(u64),
+    MetadataFrozen(u64), // set by the first vote
     Vote(u64, Address),
     HasVoted(u64, Address),
+    UpgradeAuthority,
+    ContractVersion,
 }
 
 // ============================================================
@@ -89,6 +383,27 @@ const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
 const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 34_560;
 const PERSISTENT_BUMP_AMOUNT: u32 = 259_200;
+// TTL per kind of persistent entry, bumped on every access
+const PROPOSAL_TTL: TtlPolicy = storage::STANDARD;
+
+const EVENT_TAG: &str = "dao";
+const CONTRACT_VERSION: u32 = 1; // deployments from before versioning count as 1
+
+const DEFAULT_TIMELOCK_DELAY: u64 = 172_800; // 2 days between queueing and execution
+const VETO_OVERRIDE_THRESHOLD_BPS: u32 = 6_667; // supermajority needed to lift a veto
+const EMERGENCY_VOTING_PERIOD: u32 = 720; // ~1 hour of ledgers
+const EMERGENCY_THRESHOLD_BPS: u32 = 7_500;
+const EMERGENCY_QUORUM_BPS: u32 = 1_000;
+const EMERGENCY_TIMELOCK: u64 = 3_600;
+
+// Bounds on parameters the DAO may amend through its own proposals
+const MIN_VOTING_PERIOD: u32 = 720; // ~1 hour of ledgers
+const MAX_VOTING_PERIOD: u32 = 535_680; // ~31 days of ledgers
+const MIN_PASS_THRESHOLD: i128 = 50;
+const MAX_TIMELOCK_DELAY: i128 = 2_592_000; // 30 days
+const MIN_GRACE_PERIOD: i128 = 86_400; // 1 day; 0 disables expiry
+const MAX_METADATA_ENTRIES: u32 = 10;
+const MAX_PAGE_SIZE: u32 = 50;
 
 #[contract]
 pub struct GovernanceDaoContract;
@@ -106,11 +421,9 @@ impl GovernanceDaoContract {
         proposer_min: i128,     // min tokens to create proposal
     ) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+        if Ownable::init(&env, &DataKey::Admin, &admin).is_err() {
+            panic_with_error!(&env, DaoError::AlreadyInitialized);
         }
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage()
             .instance()
             .set(&DataKey::GovernanceToken, &governance_token);
@@ -129,138 +442,247 @@ impl GovernanceDaoContract {
         env.storage()
             .instance()
             .set(&DataKey::ProposerMinTokens, &proposer_min);
+        Upgradeable::init(&env, &DataKey::ContractVersion, CONTRACT_VERSION);
+    }
+
+    /// See `Upgradeable`. A passed proposal can also upgrade or migrate the
+    /// DAO through a self-call, standing in for the upgrade authority.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), DaoError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::require_authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)?;
+        Upgradeable::upgrade(&env, EVENT_TAG, &DataKey::ContractVersion, new_wasm_hash);
+        Ok(())
+    }
+
+    pub fn migrate(env: Env, from_version: u32) -> Result<u32, DaoError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::require_authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)?;
+        Self::_migrate(&env, from_version)
     }
 
-    /// Create a new governance proposal
+    pub fn set_upgrade_authority(env: Env, caller: Address, authority: Address) -> Result<(), DaoError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::set_authority(&env, EVENT_TAG, &DataKey::UpgradeAuthority, &DataKey::Admin, &caller, &authority)?;
+        Ok(())
+    }
+
+    pub fn get_upgrade_authority(env: Env) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)
+    }
+
+    pub fn get_version(env: Env) -> ContractVersion {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::version(&env, &DataKey::ContractVersion)
+    }
+
+    /// Create a new governance proposal. The proposer needs at least the
+    /// `proposer_min` parameter in votes at the new proposal's snapshot.
     pub fn create_proposal(
         env: Env,
         proposer: Address,
         title: String,
         description: String,
-        target_contract: Option<Address>,
+        kind: ProposalKind,
+        action: Option<ProposalAction>,
     ) -> u64 {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         proposer.require_auth();
 
-        let counter: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::ProposalCounter)
-            .unwrap_or(0);
-        let proposal_id = counter + 1;
+        if kind == ProposalKind::VetoOverride || kind == ProposalKind::Emergency {
+            panic_with_error!(&env, DaoError::ReservedKind);
+        }
 
-        let voting_period: u32 = env
+        Self::_create_proposal(&env, proposer, title, description, kind, action)
+    }
+
+    /// Open a fast-tracked proposal with a short voting period and a higher
+    /// bar to pass. Only the guardian or large stakeholders may do so, and
+    /// only a limited number may be open at once.
+    pub fn create_emergency_proposal(
+        env: Env,
+        proposer: Address,
+        title: String,
+        description: String,
+        action: Option<ProposalAction>,
+    ) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        proposer.require_auth();
+
+        let config: EmergencyConfig = env
             .storage()
             .instance()
-            .get(&DataKey::VotingPeriod)
-            .unwrap_or(1_000);
-        let quorum: i128 = env
+            .get(&DataKey::EmergencyConfig)
+            .unwrap_or_else(|| panic_with_error!(&env, DaoError::EmergencyProposalsDisabled));
+        let guardian: Option<Address> = env.storage().instance().get(&DataKey::Guardian);
+        if guardian.as_ref() != Some(&proposer)
+            && Self::_past_votes(&env, &proposer, env.ledger().sequence().saturating_sub(1)) < config.stake_threshold
+        {
+            panic_with_error!(&env, DaoError::InsufficientStake);
+        }
+
+        let active: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::QuorumRequired)
+            .get(&DataKey::ActiveEmergencies)
             .unwrap_or(0);
-        let threshold: u32 = env
-            .storage()
+        if active >= config.max_active {
+            panic_with_error!(&env, DaoError::TooManyEmergencyProposals);
+        }
+        env.storage()
             .instance()
-            .get(&DataKey::PassThreshold)
-            .unwrap_or(51);
+            .set(&DataKey::ActiveEmergencies, &(active + 1));
 
-        let start = env.ledger().sequence();
-        let proposal = Proposal {
-            proposer: proposer.clone(),
-            title,
-            description,
-            target_contract,
-            status: ProposalStatus::Active,
-            votes_for: 0,
-            votes_against: 0,
-            votes_abstain: 0,
-            quorum_required: quorum,
-            threshold_pct: threshold,
-            start_ledger: start,
-            end_ledger: start + voting_period,
-            created_at: env.ledger().timestamp(),
-            executed_at: None,
-        };
+        Self::_create_proposal(&env, proposer, title, description, ProposalKind::Emergency, action)
+    }
 
-        let _ttl_key = DataKey::Proposal(proposal_id);
+    /// Cast a vote on a proposal, weighted by the voter's governance token
+    /// votes at the proposal's snapshot ledger, including power delegated to
+    /// them. A delegator voting directly takes their own balance back from
+    /// their delegate. Voting again before the end of the voting period
+    /// replaces the earlier choice.
+    pub fn cast_vote(env: Env, voter: Address, proposal_id: u64, choice: VoteChoice) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        voter.require_auth();
+
+        Self::_record_vote(&env, voter, proposal_id, choice);
+    }
+
+    /// Register the ed25519 key whose signatures may cast votes for `voter`
+    pub fn set_vote_key(env: Env, voter: Address, public_key: BytesN<32>) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        voter.require_auth();
+        let _ttl_key = DataKey::VoteKey(voter);
         env.storage()
             .persistent()
-            .set(&_ttl_key, &proposal);
+            .set(&_ttl_key, &public_key);
         env.storage()
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    /// Relay a vote signed off-chain by the voter's registered key. The
+    /// signature covers `vote_payload` and is single-use via the voter's nonce.
+    pub fn cast_vote_by_sig(
+        env: Env,
+        voter: Address,
+        proposal_id: u64,
+        choice: VoteChoice,
+        expiration: u64,
+        signature: BytesN<64>,
+    ) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.ledger().timestamp() > expiration {
+            panic_with_error!(&env, DaoError::SignatureExpired);
+        }
+        let public_key: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VoteKey(voter.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, DaoError::NoVoteKey));
+        let nonce = Self::get_vote_nonce(env.clone(), voter.clone());
+        let payload = Self::_vote_payload(&env, &voter, proposal_id, &choice, expiration, nonce);
+        env.crypto().ed25519_verify(&public_key, &payload, &signature);
+
+        let _ttl_key = DataKey::SigNonce(voter.clone());
         env.storage()
-            .instance()
-            .set(&DataKey::ProposalCounter, &proposal_id);
+            .persistent()
+            .set(&_ttl_key, &(nonce + 1));
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("proposal"), symbol_short!("created")),
-            (proposal_id, proposer),
-        );
+        Self::_record_vote(&env, voter.clone(), proposal_id, choice);
 
-        proposal_id
+        events::publish(&env, EVENT_TAG, VoteCastBySig { proposal_id, voter });
+    }
+
+    /// The exact bytes a voter signs for `cast_vote_by_sig`
+    pub fn vote_payload(env: Env, voter: Address, proposal_id: u64, choice: VoteChoice, expiration: u64) -> Bytes {
+        let nonce = Self::get_vote_nonce(env.clone(), voter.clone());
+        Self::_vote_payload(&env, &voter, proposal_id, &choice, expiration, nonce)
     }
 
-    /// Cast a vote on a proposal
-    pub fn cast_vote(env: Env, voter: Address, proposal_id: u64, choice: VoteChoice, power: i128) {
+    pub fn get_vote_nonce(env: Env, voter: Address) -> u64 {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        voter.require_auth();
+        env.storage()
+            .persistent()
+            .get(&DataKey::SigNonce(voter))
+            .unwrap_or(0)
+    }
 
-        // Check not already voted
+    /// Anchor a document to a proposal (proposer only). Allowed until the first
+    /// vote is cast, after which the list is frozen so voters can verify the
+    /// exact text they voted on.
+    pub fn attach_metadata(
+        env: Env,
+        proposer: Address,
+        proposal_id: u64,
+        content_hash: BytesN<32>,
+        uri: String,
+    ) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        proposer.require_auth();
+        let proposal = Self::_open_proposal(&env, proposal_id);
+        if proposal.proposer != proposer {
+            panic_with_error!(&env, DaoError::Unauthorized);
+        }
         if env
             .storage()
             .persistent()
-            .has(&DataKey::HasVoted(proposal_id, voter.clone()))
+            .has(&DataKey::MetadataFrozen(proposal_id))
         {
-            panic!("already voted");
+            panic_with_error!(&env, DaoError::MetadataFrozen);
         }
 
-        let mut proposal: Proposal = env
+        let mut entries: Vec<ProposalMetadata> = env
             .storage()
             .persistent()
-            .get(&DataKey::Proposal(proposal_id))
-            .expect("proposal not found");
-
-        if proposal.status != ProposalStatus::Active {
-            panic!("proposal not active");
-        }
-
-        if env.ledger().sequence() > proposal.end_ledger {
-            panic!("voting period ended");
-        }
-
-        if power <= 0 {
-            panic!("invalid voting power");
+            .get(&DataKey::Metadata(proposal_id))
+            .unwrap_or(Vec::new(&env));
+        if entries.len() >= MAX_METADATA_ENTRIES {
+            panic_with_error!(&env, DaoError::TooManyMetadataEntries);
         }
+        entries.push_back(ProposalMetadata {
+            content_hash: content_hash.clone(),
+            uri,
+            attached_at: env.ledger().timestamp(),
+        });
 
-        // Record vote
-        match choice {
-            VoteChoice::For => proposal.votes_for += power,
-            VoteChoice::Against => proposal.votes_against += power,
-            VoteChoice::Abstain => proposal.votes_abstain += power,
-        }
-
-        let vote = Vote {
-            choice,
-            power,
-            voted_at: env.ledger().timestamp(),
-        };
-
-        let _ttl_key = DataKey::Vote(proposal_id, voter.clone());
+        let _ttl_key = DataKey::Metadata(proposal_id);
         env.storage()
             .persistent()
-            .set(&_ttl_key, &vote);
+            .set(&_ttl_key, &entries);
         env.storage()
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
-        let _ttl_key = DataKey::HasVoted(proposal_id, voter.clone());
+
+        events::publish(&env, EVENT_TAG, MetadataAttached { proposal_id, content_hash });
+    }
+
+    /// Remove the voter's weight from a proposal before the voting period ends
+    pub fn withdraw_vote(env: Env, voter: Address, proposal_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        voter.require_auth();
+
+        let mut proposal = Self::_open_proposal(&env, proposal_id);
+        let vote: Vote = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Vote(proposal_id, voter.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, DaoError::NotVoted));
+        Self::_remove_tally(&mut proposal, &vote);
+        if let Some(delegate) = vote.overrides.clone() {
+            // Hand the represented power back to the delegate
+            Self::_shift_override(&env, &mut proposal, proposal_id, &delegate, -vote.raw_power);
+        }
+
         env.storage()
             .persistent()
-            .set(&_ttl_key, &true);
+            .remove(&DataKey::Vote(proposal_id, voter.clone()));
         env.storage()
             .persistent()
-            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+            .remove(&DataKey::HasVoted(proposal_id, voter.clone()));
         let _ttl_key = DataKey::Proposal(proposal_id);
         env.storage()
             .persistent()
@@ -269,45 +691,141 @@ impl GovernanceDaoContract {
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("gov"), symbol_short!("voted")),
-            (proposal_id, voter, power),
-        );
+        events::publish(&env, EVENT_TAG, VoteWithdrawn { proposal_id, voter });
     }
 
-    /// Finalize a proposal after voting period
-    pub fn finalize_proposal(env: Env, proposal_id: u64) {
+    /// Finalize a proposal after voting period, paying the finalizer the
+    /// configured bounty when funds are available
+    pub fn finalize_proposal(env: Env, finalizer: Address, proposal_id: u64) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        let mut proposal: Proposal = env
+        finalizer.require_auth();
+        let proposal: Proposal = env
             .storage()
             .persistent()
             .get(&DataKey::Proposal(proposal_id))
-            .expect("proposal not found");
+            .unwrap_or_else(|| panic_with_error!(&env, DaoError::ProposalNotFound));
 
         if proposal.status != ProposalStatus::Active {
-            panic!("proposal not active");
+            panic_with_error!(&env, DaoError::ProposalNotActive);
         }
 
         if env.ledger().sequence() <= proposal.end_ledger {
-            panic!("voting period not ended");
+            panic_with_error!(&env, DaoError::VotingPeriodNotEnded);
         }
 
-        let total_votes = proposal.votes_for + proposal.votes_against;
-        let quorum_met = total_votes >= proposal.quorum_required;
-        let for_pct = if total_votes > 0 {
-            (proposal.votes_for * 100) / total_votes
-        } else {
-            0
-        };
+        Self::_finalize(&env, &finalizer, proposal_id, proposal);
+    }
 
-        proposal.status = if quorum_met && for_pct as u32 >= proposal.threshold_pct {
-            ProposalStatus::Passed
-        } else if !quorum_met {
-            ProposalStatus::Rejected
-        } else {
-            ProposalStatus::Rejected
+    /// Finalize every ended, still-active proposal in `proposal_ids`, skipping
+    /// the rest. Returns how many were finalized.
+    pub fn finalize_batch(env: Env, finalizer: Address, proposal_ids: Vec<u64>) -> u32 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        finalizer.require_auth();
+
+        let mut finalized: u32 = 0;
+        for proposal_id in proposal_ids.iter() {
+            if let Some(proposal) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Proposal>(&DataKey::Proposal(proposal_id))
+            {
+                if proposal.status == ProposalStatus::Active && env.ledger().sequence() > proposal.end_ledger {
+                    Self::_finalize(&env, &finalizer, proposal_id, proposal);
+                    finalized += 1;
+                }
+            }
+        }
+        finalized
+    }
+
+    /// Set the bounty paid per finalized proposal; an amount of 0 disables it.
+    /// Callable by the admin or by the DAO itself through an executed proposal.
+    pub fn set_finalize_bounty(env: Env, caller: Address, token: Address, amount: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin_or_self(&env, &caller);
+        if amount < 0 {
+            panic_with_error!(&env, DaoError::InvalidBounty);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::FinalizeBounty, &FinalizeBounty { token, amount });
+    }
+
+    /// Top up the pool that pays finalization bounties
+    pub fn fund_bounty_pool(env: Env, funder: Address, amount: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        funder.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&env, DaoError::InvalidAmount);
+        }
+        let bounty: FinalizeBounty = env
+            .storage()
+            .instance()
+            .get(&DataKey::FinalizeBounty)
+            .unwrap_or_else(|| panic_with_error!(&env, DaoError::BountyNotConfigured));
+
+        token::Client::new(&env, &bounty.token).transfer(&funder, &env.current_contract_address(), &amount);
+        let pool: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::BountyPool)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::BountyPool, &(pool + amount));
+    }
+
+    /// Set the guardian allowed to veto queued proposals. Callable by the
+    /// admin or by the DAO itself through an executed proposal.
+    pub fn set_guardian(env: Env, caller: Address, guardian: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin_or_self(&env, &caller);
+        env.storage().instance().set(&DataKey::Guardian, &guardian);
+    }
+
+    /// Emergency brake: the guardian can veto a queued proposal while its
+    /// timelock is still running
+    pub fn veto_proposal(env: Env, guardian: Address, proposal_id: u64, reason_hash: BytesN<32>) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        guardian.require_auth();
+        let stored_guardian: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Guardian)
+            .unwrap_or_else(|| panic_with_error!(&env, DaoError::NoGuardian));
+        if guardian != stored_guardian {
+            panic_with_error!(&env, DaoError::Unauthorized);
+        }
+
+        let mut proposal: Proposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+            .unwrap_or_else(|| panic_with_error!(&env, DaoError::ProposalNotFound));
+        if proposal.status != ProposalStatus::Queued {
+            panic_with_error!(&env, DaoError::ProposalNotQueued);
+        }
+        if proposal.kind == ProposalKind::VetoOverride {
+            panic_with_error!(&env, DaoError::CannotVetoOverride);
+        }
+        if env.ledger().timestamp() >= proposal.eta.unwrap_or(0) {
+            panic_with_error!(&env, DaoError::TimelockElapsed);
+        }
+
+        Self::_set_status(&env, proposal_id, &mut proposal, ProposalStatus::Vetoed);
+        let veto = Veto {
+            guardian: guardian.clone(),
+            reason_hash: reason_hash.clone(),
+            vetoed_at: env.ledger().timestamp(),
         };
 
+        let _ttl_key = DataKey::Veto(proposal_id);
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &veto);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
         let _ttl_key = DataKey::Proposal(proposal_id);
         env.storage()
             .persistent()
@@ -316,32 +834,231 @@ impl GovernanceDaoContract {
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("proposal"), symbol_short!("finalized")),
-            (proposal_id, proposal.status),
-        );
+        events::publish(&env, EVENT_TAG, ProposalVetoed { proposal_id, guardian, reason_hash });
+    }
+
+    /// Put a vetoed proposal to a token vote. If it passes with a
+    /// supermajority, executing it lifts the veto.
+    pub fn propose_veto_override(env: Env, proposer: Address, proposal_id: u64) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        proposer.require_auth();
+
+        let proposal: Proposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+            .unwrap_or_else(|| panic_with_error!(&env, DaoError::ProposalNotFound));
+        if proposal.status != ProposalStatus::Vetoed {
+            panic_with_error!(&env, DaoError::ProposalNotVetoed);
+        }
+
+        let dao = env.current_contract_address();
+        let action = ProposalAction {
+            target: dao.clone(),
+            function: Symbol::new(&env, "revoke_veto"),
+            args: Vec::from_array(&env, [dao.into_val(&env), proposal_id.into_val(&env)]),
+        };
+        Self::_create_proposal(
+            &env,
+            proposer,
+            proposal.title,
+            String::from_str(&env, "Override guardian veto"),
+            ProposalKind::VetoOverride,
+            Some(action),
+        )
+    }
+
+    /// Lift a veto and return the proposal to the queue. Only the DAO itself,
+    /// through a passed veto override, may call this.
+    pub fn revoke_veto(env: Env, caller: Address, proposal_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        caller.require_auth();
+        if caller != env.current_contract_address() {
+            panic_with_error!(&env, DaoError::Unauthorized);
+        }
+        Self::_revoke_veto(&env, proposal_id);
+    }
+
+    /// Set the voting mode applied to proposals created from now on. Callable
+    /// by the admin or by the DAO itself through an executed proposal.
+    pub fn set_voting_mode(env: Env, caller: Address, mode: VotingMode) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin_or_self(&env, &caller);
+        Self::_set_voting_mode(&env, mode);
+    }
+
+    pub fn get_voting_mode(env: Env) -> VotingMode {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .instance()
+            .get(&DataKey::VotingMode)
+            .unwrap_or(VotingMode::Linear)
     }
 
-    /// Mark proposal as executed (admin only)
-    pub fn execute_proposal(env: Env, admin: Address, proposal_id: u64) {
+    /// Configure the emergency path. Callable by the admin or by the DAO
+    /// itself through an executed proposal.
+    pub fn set_emergency_config(env: Env, caller: Address, config: EmergencyConfig) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        admin.require_auth();
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if admin != stored_admin {
-            panic!("unauthorized");
+        Self::_require_admin_or_self(&env, &caller);
+        if config.stake_threshold <= 0 {
+            panic_with_error!(&env, DaoError::InvalidEmergencyConfig);
         }
+        env.storage().instance().set(&DataKey::EmergencyConfig, &config);
+    }
+
+    /// Set the voting rules for a proposal kind. Callable by the admin or by
+    /// the DAO itself through an executed proposal.
+    pub fn set_kind_config(env: Env, caller: Address, kind: ProposalKind, config: KindConfig) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin_or_self(&env, &caller);
+        Self::_set_kind_config(&env, kind, config);
+    }
 
+    /// Amend one of the DAO-wide parameters fixed at initialize: `voting_period`,
+    /// `quorum`, `threshold`, `proposer_min`, `timelock` or `grace_period`.
+    /// Callable by the admin or by the DAO itself through an executed proposal.
+    pub fn set_param(env: Env, caller: Address, name: Symbol, value: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin_or_self(&env, &caller);
+        Self::_set_param(&env, name, value);
+    }
+
+    /// Current value of a parameter accepted by `set_param`
+    pub fn get_param(env: Env, name: Symbol) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let storage = env.storage().instance();
+        if name == Symbol::new(&env, "voting_period") {
+            storage.get::<DataKey, u32>(&DataKey::VotingPeriod).unwrap_or(1_000) as i128
+        } else if name == Symbol::new(&env, "quorum") {
+            storage.get(&DataKey::QuorumRequired).unwrap_or(0)
+        } else if name == Symbol::new(&env, "threshold") {
+            storage.get::<DataKey, u32>(&DataKey::PassThreshold).unwrap_or(51) as i128
+        } else if name == Symbol::new(&env, "proposer_min") {
+            storage.get(&DataKey::ProposerMinTokens).unwrap_or(0)
+        } else if name == Symbol::new(&env, "timelock") {
+            storage
+                .get::<DataKey, u64>(&DataKey::TimelockDelay)
+                .unwrap_or(DEFAULT_TIMELOCK_DELAY) as i128
+        } else if name == Symbol::new(&env, "grace_period") {
+            storage.get::<DataKey, u64>(&DataKey::GracePeriod).unwrap_or(0) as i128
+        } else {
+            panic_with_error!(&env, DaoError::UnknownParam);
+        }
+    }
+
+    /// Require a deposit to open proposals; an amount of 0 disables it (admin only)
+    pub fn set_proposal_deposit(env: Env, admin: Address, token: Address, amount: i128, treasury: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        if amount < 0 {
+            panic_with_error!(&env, DaoError::InvalidDeposit);
+        }
+        env.storage().instance().set(
+            &DataKey::DepositConfig,
+            &DepositConfig { token, amount, treasury },
+        );
+    }
+
+    /// Resolve the governance token through the contract registry's "token"
+    /// key, falling back to the one passed to `initialize` (admin only)
+    pub fn set_registry(env: Env, admin: Address, registry: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Registry, &registry);
+    }
+
+    /// Set the delay between queueing a passed proposal and executing it (admin only)
+    pub fn set_timelock_delay(env: Env, admin: Address, delay_secs: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::TimelockDelay, &delay_secs);
+    }
+
+    /// Start the timelock on a passed proposal
+    pub fn queue_proposal(env: Env, proposal_id: u64) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         let mut proposal: Proposal = env
             .storage()
             .persistent()
             .get(&DataKey::Proposal(proposal_id))
-            .expect("proposal not found");
+            .unwrap_or_else(|| panic_with_error!(&env, DaoError::ProposalNotFound));
 
         if proposal.status != ProposalStatus::Passed {
-            panic!("proposal not passed");
+            panic_with_error!(&env, DaoError::ProposalNotPassed);
+        }
+
+        let eta = env.ledger().timestamp() + proposal.timelock_secs;
+        Self::_set_status(&env, proposal_id, &mut proposal, ProposalStatus::Queued);
+        proposal.eta = Some(eta);
+
+        let _ttl_key = DataKey::Proposal(proposal_id);
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &proposal);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, ProposalQueued { proposal_id, eta });
+
+        eta
+    }
+
+    /// Execute a queued proposal once its timelock has elapsed, invoking its
+    /// action (if any) as the DAO. A failing call reverts and leaves the
+    /// proposal queued. With a grace period set, a proposal left unexecuted
+    /// past it is marked expired instead.
+    pub fn execute_proposal(env: Env, proposal_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let mut proposal: Proposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+            .unwrap_or_else(|| panic_with_error!(&env, DaoError::ProposalNotFound));
+
+        if proposal.status != ProposalStatus::Queued {
+            panic_with_error!(&env, DaoError::ProposalNotQueued);
+        }
+        let eta = proposal.eta.unwrap_or(u64::MAX);
+        if env.ledger().timestamp() < eta {
+            panic_with_error!(&env, DaoError::TimelockNotExpired);
         }
 
-        proposal.status = ProposalStatus::Executed;
+        let grace_period: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::GracePeriod)
+            .unwrap_or(0);
+        if grace_period > 0 && env.ledger().timestamp() > eta.saturating_add(grace_period) {
+            Self::_set_status(&env, proposal_id, &mut proposal, ProposalStatus::Expired);
+            let _ttl_key = DataKey::Proposal(proposal_id);
+            env.storage()
+                .persistent()
+                .set(&_ttl_key, &proposal);
+            env.storage()
+                .persistent()
+                .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+            events::publish(&env, EVENT_TAG, ProposalExpired { proposal_id });
+            return;
+        }
+
+        if let Some(action) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, ProposalAction>(&DataKey::Action(proposal_id))
+        {
+            // Soroban forbids re-entry, so calls back into the DAO run in place
+            let result: Val = if action.target == env.current_contract_address() {
+                Self::_execute_self_action(&env, &action)
+            } else {
+                env.invoke_contract(&action.target, &action.function, action.args)
+            };
+            proposal.execution_result = Some(result.to_xdr(&env));
+        }
+
+        Self::_set_status(&env, proposal_id, &mut proposal, ProposalStatus::Executed);
         proposal.executed_at = Some(env.ledger().timestamp());
 
         let _ttl_key = DataKey::Proposal(proposal_id);
@@ -351,6 +1068,8 @@ impl GovernanceDaoContract {
         env.storage()
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, ProposalExecuted { proposal_id });
     }
 
     /// Cancel a proposal (proposer or admin)
@@ -358,18 +1077,24 @@ impl GovernanceDaoContract {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         caller.require_auth();
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         let mut proposal: Proposal = env
             .storage()
             .persistent()
             .get(&DataKey::Proposal(proposal_id))
-            .expect("proposal not found");
+            .unwrap_or_else(|| panic_with_error!(&env, DaoError::ProposalNotFound));
+
+        if caller != proposal.proposer && !Ownable::is_owner(&env, &DataKey::Admin, &caller) {
+            panic_with_error!(&env, DaoError::Unauthorized);
+        }
 
-        if caller != proposal.proposer && caller != admin {
-            panic!("unauthorized");
+        if proposal.status != ProposalStatus::Active {
+            panic_with_error!(&env, DaoError::ProposalNotActive);
         }
 
-        proposal.status = ProposalStatus::Cancelled;
+        // A withdrawn proposal never reached quorum, so its deposit is forfeited
+        Self::_set_status(&env, proposal_id, &mut proposal, ProposalStatus::Cancelled);
+        Self::_settle_deposit(&env, proposal_id, &proposal.proposer, false);
+        Self::_release_emergency_slot(&env, &proposal);
         let _ttl_key = DataKey::Proposal(proposal_id);
         env.storage()
             .persistent()
@@ -384,10 +1109,86 @@ impl GovernanceDaoContract {
     // ============================================================
 
     pub fn get_proposal(env: Env, proposal_id: u64) -> Option<Proposal> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Persistent::get(&env, &DataKey::Proposal(proposal_id), PROPOSAL_TTL)
+    }
+
+    pub fn get_proposal_action(env: Env, proposal_id: u64) -> Option<ProposalAction> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         env.storage()
             .persistent()
-            .get(&DataKey::Proposal(proposal_id))
+            .get(&DataKey::Action(proposal_id))
+    }
+
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::Guardian)
+    }
+
+    pub fn get_proposal_metadata(env: Env, proposal_id: u64) -> Vec<ProposalMetadata> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::Metadata(proposal_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn is_metadata_frozen(env: Env, proposal_id: u64) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .has(&DataKey::MetadataFrozen(proposal_id))
+    }
+
+    pub fn get_veto(env: Env, proposal_id: u64) -> Option<Veto> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::Veto(proposal_id))
+    }
+
+    pub fn get_emergency_config(env: Env) -> Option<EmergencyConfig> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::EmergencyConfig)
+    }
+
+    pub fn get_active_emergencies(env: Env) -> u32 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .instance()
+            .get(&DataKey::ActiveEmergencies)
+            .unwrap_or(0)
+    }
+
+    pub fn get_kind_config(env: Env, kind: ProposalKind) -> Option<KindConfig> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::KindConfig(kind))
+    }
+
+    pub fn get_finalize_bounty(env: Env) -> Option<FinalizeBounty> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::FinalizeBounty)
+    }
+
+    pub fn get_bounty_pool(env: Env) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .instance()
+            .get(&DataKey::BountyPool)
+            .unwrap_or(0)
+    }
+
+    pub fn get_deposit_config(env: Env) -> Option<DepositConfig> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::DepositConfig)
+    }
+
+    /// Deposit still held for a proposal; `None` once it has been settled
+    pub fn get_deposit(env: Env, proposal_id: u64) -> Option<ProposalDeposit> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::Deposit(proposal_id))
     }
 
     pub fn get_vote(env: Env, proposal_id: u64, voter: Address) -> Option<Vote> {
@@ -405,6 +1206,84 @@ impl GovernanceDaoContract {
             .unwrap_or(false)
     }
 
+    /// Page through proposals by id, or only those with `status_filter` in the
+    /// order they reached it. Proposals whose records were archived are left
+    /// out of the page. At most MAX_PAGE_SIZE are returned.
+    pub fn list_proposals(
+        env: Env,
+        offset: u64,
+        limit: u32,
+        status_filter: Option<ProposalStatus>,
+    ) -> Vec<(u64, Proposal)> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let limit = limit.min(MAX_PAGE_SIZE) as u64;
+        let mut ids = Vec::new(&env);
+        match status_filter {
+            Some(status) => {
+                let listed: Vec<u64> = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::StatusProposals(status))
+                    .unwrap_or(Vec::new(&env));
+                let end = (listed.len() as u64).min(offset.saturating_add(limit));
+                let mut idx = offset;
+                while idx < end {
+                    ids.push_back(listed.get(idx as u32).unwrap());
+                    idx += 1;
+                }
+            }
+            None => {
+                let count: u64 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::ProposalCounter)
+                    .unwrap_or(0);
+                let end = count.min(offset.saturating_add(limit));
+                let mut id = offset + 1;
+                while id <= end {
+                    ids.push_back(id);
+                    id += 1;
+                }
+            }
+        }
+        Self::_load_proposals(&env, ids)
+    }
+
+    /// Page through the proposals a voter has voted on, oldest first.
+    /// Withdrawn votes are left out.
+    pub fn list_votes_by_voter(env: Env, voter: Address, offset: u32, limit: u32) -> Vec<VoteRecord> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VoterVoteCount(voter.clone()))
+            .unwrap_or(0);
+
+        let mut result = Vec::new(&env);
+        let mut skipped: u32 = 0;
+        let mut idx: u32 = 0;
+        while idx < count && result.len() < limit {
+            let proposal_id: u64 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::VoterVote(voter.clone(), idx))
+                .unwrap();
+            if let Some(vote) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Vote>(&DataKey::Vote(proposal_id, voter.clone()))
+            {
+                if skipped < offset {
+                    skipped += 1;
+                } else {
+                    result.push_back(VoteRecord { proposal_id, vote });
+                }
+            }
+            idx += 1;
+        }
+        result
+    }
+
     pub fn get_proposal_count(env: Env) -> u64 {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         env.storage()
@@ -412,6 +1291,816 @@ impl GovernanceDaoContract {
             .get(&DataKey::ProposalCounter)
             .unwrap_or(0)
     }
+
+    // ============================================================
+    // TTL Maintenance
+    // ============================================================
+
+    /// Keep a proposal and its action, metadata, deposit and veto from being
+    /// archived. Anyone may call; returns false if there is no such proposal.
+    pub fn extend_ttl_proposal(env: Env, proposal_id: u64) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Persistent::extend(&env, &DataKey::Action(proposal_id), PROPOSAL_TTL);
+        Persistent::extend(&env, &DataKey::Metadata(proposal_id), PROPOSAL_TTL);
+        Persistent::extend(&env, &DataKey::Deposit(proposal_id), PROPOSAL_TTL);
+        Persistent::extend(&env, &DataKey::Veto(proposal_id), PROPOSAL_TTL);
+        Persistent::extend(&env, &DataKey::Proposal(proposal_id), PROPOSAL_TTL)
+    }
+
+    // ============================================================
+    // Internal Helpers
+    // ============================================================
+
+    fn _require_admin(env: &Env, admin: &Address) {
+        if let Err(e) = Ownable::require_owner(env, &DataKey::Admin, admin) {
+            panic_with_error!(env, DaoError::from(e));
+        }
+    }
+
+    fn _migrate(env: &Env, from_version: u32) -> Result<u32, DaoError> {
+        Ok(Upgradeable::migrate(env, EVENT_TAG, &DataKey::ContractVersion, from_version, CONTRACT_VERSION, |_| {})?)
+    }
+
+    /// The admin, or the DAO itself acting on a passed proposal
+    fn _require_admin_or_self(env: &Env, caller: &Address) {
+        caller.require_auth();
+        if *caller != env.current_contract_address() && !Ownable::is_owner(env, &DataKey::Admin, caller) {
+            panic_with_error!(env, DaoError::Unauthorized);
+        }
+    }
+
+    fn _create_proposal(
+        env: &Env,
+        proposer: Address,
+        title: String,
+        description: String,
+        kind: ProposalKind,
+        action: Option<ProposalAction>,
+    ) -> u64 {
+        let counter: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ProposalCounter)
+            .unwrap_or(0);
+        let proposal_id = counter + 1;
+
+        // Power is read as of the ledger before creation: balances can still
+        // move within the current one, so tokens borrowed and returned in the
+        // same ledger never count. Emergencies are gated by their own stake
+        // threshold instead of `proposer_min`.
+        let start = env.ledger().sequence();
+        let snapshot = start.saturating_sub(1);
+        let proposer_min: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ProposerMinTokens)
+            .unwrap_or(0);
+        if kind != ProposalKind::Emergency
+            && proposer_min > 0
+            && Self::_past_votes(env, &proposer, snapshot) < proposer_min
+        {
+            panic_with_error!(env, DaoError::InsufficientStake);
+        }
+
+        // Kinds without their own rules use the DAO-wide defaults, except
+        // emergencies which always run on a fast track
+        let kind_config = env
+            .storage()
+            .instance()
+            .get::<DataKey, KindConfig>(&DataKey::KindConfig(kind.clone()))
+            .or(if kind == ProposalKind::Emergency {
+                Some(KindConfig {
+                    quorum_bps: EMERGENCY_QUORUM_BPS,
+                    threshold_bps: EMERGENCY_THRESHOLD_BPS,
+                    voting_period: EMERGENCY_VOTING_PERIOD,
+                    timelock_secs: EMERGENCY_TIMELOCK,
+                })
+            } else {
+                None
+            });
+        let (voting_period, quorum, threshold_bps, timelock_secs) = match kind_config {
+            Some(config) => (
+                config.voting_period,
+                Self::_total_supply(env) * config.quorum_bps as i128 / 10_000,
+                config.threshold_bps,
+                config.timelock_secs,
+            ),
+            None => {
+                let voting_period: u32 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::VotingPeriod)
+                    .unwrap_or(1_000);
+                let quorum: i128 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::QuorumRequired)
+                    .unwrap_or(0);
+                let threshold_pct: u32 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::PassThreshold)
+                    .unwrap_or(51);
+                let timelock_secs: u64 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::TimelockDelay)
+                    .unwrap_or(DEFAULT_TIMELOCK_DELAY);
+                (voting_period, quorum, threshold_pct * 100, timelock_secs)
+            }
+        };
+        let threshold_bps = if kind == ProposalKind::VetoOverride {
+            threshold_bps.max(VETO_OVERRIDE_THRESHOLD_BPS)
+        } else {
+            threshold_bps
+        };
+
+        let proposal = Proposal {
+            proposer: proposer.clone(),
+            title,
+            description,
+            kind,
+            has_action: action.is_some(),
+            status: ProposalStatus::Active,
+            votes_for: 0,
+            votes_against: 0,
+            votes_abstain: 0,
+            quorum_required: quorum,
+            threshold_bps,
+            timelock_secs,
+            start_ledger: start,
+            end_ledger: start + voting_period,
+            snapshot_ledger: snapshot,
+            voting_mode: env
+                .storage()
+                .instance()
+                .get(&DataKey::VotingMode)
+                .unwrap_or(VotingMode::Linear),
+            created_at: env.ledger().timestamp(),
+            eta: None,
+            executed_at: None,
+            execution_result: None,
+        };
+
+        let _ttl_key = DataKey::Proposal(proposal_id);
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &proposal);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_list(env, &ProposalStatus::Active, proposal_id);
+        if let Some(config) = env
+            .storage()
+            .instance()
+            .get::<DataKey, DepositConfig>(&DataKey::DepositConfig)
+        {
+            if config.amount > 0 {
+                token::Client::new(env, &config.token).transfer(
+                    &proposer,
+                    &env.current_contract_address(),
+                    &config.amount,
+                );
+                let deposit = ProposalDeposit {
+                    token: config.token,
+                    amount: config.amount,
+                    treasury: config.treasury,
+                };
+                let _ttl_key = DataKey::Deposit(proposal_id);
+                env.storage()
+                    .persistent()
+                    .set(&_ttl_key, &deposit);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+            }
+        }
+        if let Some(action) = action {
+            let _ttl_key = DataKey::Action(proposal_id);
+            env.storage()
+                .persistent()
+                .set(&_ttl_key, &action);
+            env.storage()
+                .persistent()
+                .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ProposalCounter, &proposal_id);
+
+        events::publish(env, EVENT_TAG, ProposalCreated { proposal_id, proposer });
+
+        proposal_id
+    }
+
+    /// Tally a vote for an already-authorized voter
+    fn _record_vote(env: &Env, voter: Address, proposal_id: u64, choice: VoteChoice) {
+        let mut proposal = Self::_open_proposal(env, proposal_id);
+        let snapshot = proposal.snapshot_ledger;
+
+        let (raw_power, overrides) = match env
+            .storage()
+            .persistent()
+            .get::<DataKey, Vote>(&DataKey::Vote(proposal_id, voter.clone()))
+        {
+            // Take back a previous vote before recording the new one
+            Some(previous) => {
+                Self::_remove_tally(&mut proposal, &previous);
+                (previous.raw_power, previous.overrides)
+            }
+            None => {
+                // Weight already claimed by delegators who voted themselves
+                let overridden: i128 = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Overridden(proposal_id, voter.clone()))
+                    .unwrap_or(0);
+                match Self::_past_delegate(env, &voter, snapshot) {
+                    Some(delegate) => {
+                        // Everything the voter would otherwise pass on,
+                        // including weight delegated to them
+                        let own = Self::_past_represented(env, &voter, snapshot) - overridden;
+                        Self::_shift_override(env, &mut proposal, proposal_id, &delegate, own);
+                        (own, Some(delegate))
+                    }
+                    None => (Self::_past_votes(env, &voter, snapshot) - overridden, None),
+                }
+            }
+        };
+        if raw_power <= 0 {
+            panic_with_error!(env, DaoError::NoVotingPower);
+        }
+
+        // Record vote
+        let power = Self::_weigh(&proposal.voting_mode, raw_power);
+        Self::_adjust_tally(&mut proposal, &choice, power);
+
+        let vote = Vote {
+            choice: choice.clone(),
+            power,
+            raw_power,
+            voted_at: env.ledger().timestamp(),
+            overrides,
+        };
+
+        let _ttl_key = DataKey::Vote(proposal_id, voter.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &vote);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        let _ttl_key = DataKey::HasVoted(proposal_id, voter.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::VoteIndexed(proposal_id, voter.clone()))
+        {
+            Self::_index_vote(env, &voter, proposal_id);
+        }
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::MetadataFrozen(proposal_id))
+        {
+            let _ttl_key = DataKey::MetadataFrozen(proposal_id);
+            env.storage()
+                .persistent()
+                .set(&_ttl_key, &true);
+            env.storage()
+                .persistent()
+                .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+        let _ttl_key = DataKey::Proposal(proposal_id);
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &proposal);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(env, EVENT_TAG, VoteCast { proposal_id, voter, choice, power });
+    }
+
+    /// XDR of (dao, voter, proposal_id, choice, expiration, nonce); binding the
+    /// DAO address keeps signatures from replaying against another deployment
+    fn _vote_payload(
+        env: &Env,
+        voter: &Address,
+        proposal_id: u64,
+        choice: &VoteChoice,
+        expiration: u64,
+        nonce: u64,
+    ) -> Bytes {
+        (
+            env.current_contract_address(),
+            voter.clone(),
+            proposal_id,
+            choice.clone(),
+            expiration,
+            nonce,
+        )
+            .to_xdr(env)
+    }
+
+    /// Append a proposal to the voter's history the first time they vote on it
+    fn _index_vote(env: &Env, voter: &Address, proposal_id: u64) {
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VoterVoteCount(voter.clone()))
+            .unwrap_or(0);
+
+        let _ttl_key = DataKey::VoterVote(voter.clone(), count);
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &proposal_id);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        let _ttl_key = DataKey::VoterVoteCount(voter.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &(count + 1));
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        let _ttl_key = DataKey::VoteIndexed(proposal_id, voter.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    /// Load a proposal that is still accepting votes
+    fn _open_proposal(env: &Env, proposal_id: u64) -> Proposal {
+        let proposal: Proposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+            .unwrap_or_else(|| panic_with_error!(env, DaoError::ProposalNotFound));
+
+        if proposal.status != ProposalStatus::Active {
+            panic_with_error!(env, DaoError::ProposalNotActive);
+        }
+
+        if env.ledger().sequence() > proposal.end_ledger {
+            panic_with_error!(env, DaoError::VotingPeriodEnded);
+        }
+
+        proposal
+    }
+
+    fn _adjust_tally(proposal: &mut Proposal, choice: &VoteChoice, delta: i128) {
+        match choice {
+            VoteChoice::For => proposal.votes_for += delta,
+            VoteChoice::Against => proposal.votes_against += delta,
+            VoteChoice::Abstain => proposal.votes_abstain += delta,
+        }
+    }
+
+    fn _remove_tally(proposal: &mut Proposal, vote: &Vote) {
+        Self::_adjust_tally(proposal, &vote.choice, -vote.power);
+    }
+
+    /// Tallied weight for `raw_power` snapshot votes under `mode`
+    fn _weigh(mode: &VotingMode, raw_power: i128) -> i128 {
+        if raw_power <= 0 {
+            return 0;
+        }
+        match mode {
+            VotingMode::Linear => raw_power,
+            VotingMode::Quadratic => Self::_isqrt(raw_power),
+            VotingMode::Capped(max) => raw_power.min(*max),
+        }
+    }
+
+    fn _isqrt(n: i128) -> i128 {
+        let mut x = n;
+        let mut y = (x + 1) / 2;
+        while y < x {
+            x = y;
+            y = (x + n / x) / 2;
+        }
+        x
+    }
+
+    /// Move `amount` of represented weight out of (or back into) the
+    /// delegation chain starting at `delegate`, as of the proposal snapshot.
+    /// Every hop passes the weight on, so each is adjusted up to and including
+    /// the first one that has voted; that vote already settled the rest of
+    /// the chain for its own weight.
+    fn _shift_override(env: &Env, proposal: &mut Proposal, proposal_id: u64, delegate: &Address, amount: i128) {
+        let mut current = delegate.clone();
+        loop {
+            let key = DataKey::Overridden(proposal_id, current.clone());
+            let overridden: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+            env.storage().persistent().set(&key, &(overridden + amount));
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+            let vote_key = DataKey::Vote(proposal_id, current.clone());
+            if let Some(mut vote) = env.storage().persistent().get::<DataKey, Vote>(&vote_key) {
+                Self::_remove_tally(proposal, &vote);
+                vote.raw_power -= amount;
+                vote.power = Self::_weigh(&proposal.voting_mode, vote.raw_power);
+                Self::_adjust_tally(proposal, &vote.choice, vote.power);
+                env.storage().persistent().set(&vote_key, &vote);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&vote_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+                return;
+            }
+
+            // Delegations at any one ledger never form a cycle
+            match Self::_past_delegate(env, &current, proposal.snapshot_ledger) {
+                Some(next) => current = next,
+                None => return,
+            }
+        }
+    }
+
+    fn _finalize(env: &Env, finalizer: &Address, proposal_id: u64, mut proposal: Proposal) {
+        // Abstentions count toward quorum but not toward the pass threshold
+        let participation = proposal.votes_for + proposal.votes_against + proposal.votes_abstain;
+        let quorum_met = participation >= proposal.quorum_required;
+        let decisive_votes = proposal.votes_for + proposal.votes_against;
+        let for_bps = if decisive_votes > 0 {
+            (proposal.votes_for * 10_000) / decisive_votes
+        } else {
+            0
+        };
+
+        let status = if quorum_met && for_bps as u32 >= proposal.threshold_bps {
+            ProposalStatus::Passed
+        } else {
+            ProposalStatus::Rejected
+        };
+        Self::_set_status(env, proposal_id, &mut proposal, status);
+
+        Self::_pay_finalize_bounty(env, proposal_id, finalizer, !quorum_met);
+        // Reaching quorum earns the deposit back whatever the outcome
+        Self::_settle_deposit(env, proposal_id, &proposal.proposer, quorum_met);
+        Self::_release_emergency_slot(env, &proposal);
+
+        let _ttl_key = DataKey::Proposal(proposal_id);
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &proposal);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(env, EVENT_TAG, ProposalFinalized { proposal_id, status: proposal.status });
+    }
+
+    /// Pay the finalization bounty out of a deposit that is about to be
+    /// forfeited, else out of the bounty pool. Skipped when neither can cover it.
+    fn _pay_finalize_bounty(env: &Env, proposal_id: u64, finalizer: &Address, forfeiting: bool) {
+        let bounty: FinalizeBounty = match env.storage().instance().get(&DataKey::FinalizeBounty) {
+            Some(bounty) => bounty,
+            None => return,
+        };
+        if bounty.amount <= 0 {
+            return;
+        }
+
+        let deposit_key = DataKey::Deposit(proposal_id);
+        let deposit: Option<ProposalDeposit> = env.storage().persistent().get(&deposit_key);
+        match deposit {
+            Some(mut deposit) if forfeiting && deposit.token == bounty.token && deposit.amount >= bounty.amount => {
+                deposit.amount -= bounty.amount;
+                env.storage().persistent().set(&deposit_key, &deposit);
+            }
+            _ => {
+                let pool: i128 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::BountyPool)
+                    .unwrap_or(0);
+                if pool < bounty.amount {
+                    return;
+                }
+                env.storage()
+                    .instance()
+                    .set(&DataKey::BountyPool, &(pool - bounty.amount));
+            }
+        }
+
+        token::Client::new(env, &bounty.token).transfer(&env.current_contract_address(), finalizer, &bounty.amount);
+        events::publish(
+            env,
+            EVENT_TAG,
+            FinalizeBountyPaid { proposal_id, finalizer: finalizer.clone(), amount: bounty.amount },
+        );
+    }
+
+    fn _set_voting_mode(env: &Env, mode: VotingMode) {
+        if let VotingMode::Capped(max) = mode {
+            if max <= 0 {
+                panic_with_error!(env, DaoError::InvalidVotingMode);
+            }
+        }
+        env.storage().instance().set(&DataKey::VotingMode, &mode);
+    }
+
+    fn _set_kind_config(env: &Env, kind: ProposalKind, config: KindConfig) {
+        if config.quorum_bps > 10_000
+            || config.threshold_bps == 0
+            || config.threshold_bps > 10_000
+            || config.voting_period == 0
+        {
+            panic_with_error!(env, DaoError::InvalidKindConfig);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::KindConfig(kind.clone()), &config);
+        events::publish(env, EVENT_TAG, KindConfigSet { kind, config });
+    }
+
+    /// Bounds-check and store a DAO-wide parameter. Values that could leave
+    /// governance unable to pass anything (e.g. a quorum above the token
+    /// supply) are rejected.
+    fn _set_param(env: &Env, name: Symbol, value: i128) {
+        let storage = env.storage().instance();
+        if name == Symbol::new(env, "voting_period") {
+            if !(MIN_VOTING_PERIOD as i128..=MAX_VOTING_PERIOD as i128).contains(&value) {
+                panic_with_error!(env, DaoError::ParamOutOfBounds);
+            }
+            storage.set(&DataKey::VotingPeriod, &(value as u32));
+        } else if name == Symbol::new(env, "quorum") {
+            if value <= 0 || value > Self::_total_supply(env) {
+                panic_with_error!(env, DaoError::ParamOutOfBounds);
+            }
+            storage.set(&DataKey::QuorumRequired, &value);
+        } else if name == Symbol::new(env, "threshold") {
+            if !(MIN_PASS_THRESHOLD..=100).contains(&value) {
+                panic_with_error!(env, DaoError::ParamOutOfBounds);
+            }
+            storage.set(&DataKey::PassThreshold, &(value as u32));
+        } else if name == Symbol::new(env, "proposer_min") {
+            if value < 0 || value > Self::_total_supply(env) {
+                panic_with_error!(env, DaoError::ParamOutOfBounds);
+            }
+            storage.set(&DataKey::ProposerMinTokens, &value);
+        } else if name == Symbol::new(env, "timelock") {
+            if !(0..=MAX_TIMELOCK_DELAY).contains(&value) {
+                panic_with_error!(env, DaoError::ParamOutOfBounds);
+            }
+            storage.set(&DataKey::TimelockDelay, &(value as u64));
+        } else if name == Symbol::new(env, "grace_period") {
+            if value != 0 && !(MIN_GRACE_PERIOD..=u64::MAX as i128).contains(&value) {
+                panic_with_error!(env, DaoError::ParamOutOfBounds);
+            }
+            storage.set(&DataKey::GracePeriod, &(value as u64));
+        } else {
+            panic_with_error!(env, DaoError::UnknownParam);
+        }
+        events::publish(env, EVENT_TAG, ParamSet { name, value });
+    }
+
+    /// Apply a proposal action that targets the DAO itself
+    fn _execute_self_action(env: &Env, action: &ProposalAction) -> Val {
+        // Upgrades take no caller: the passed proposal stands in for the
+        // upgrade authority
+        if action.function == Symbol::new(env, "upgrade") && action.args.len() == 1 {
+            let new_wasm_hash = BytesN::<32>::try_from_val(env, &action.args.get(0).unwrap())
+                .unwrap_or_else(|_| panic_with_error!(env, DaoError::InvalidActionArgs));
+            Upgradeable::upgrade(env, EVENT_TAG, &DataKey::ContractVersion, new_wasm_hash);
+            return ().into_val(env);
+        }
+        if action.function == Symbol::new(env, "migrate") && action.args.len() == 1 {
+            let from_version = u32::try_from_val(env, &action.args.get(0).unwrap())
+                .unwrap_or_else(|_| panic_with_error!(env, DaoError::InvalidActionArgs));
+            return Self::_migrate(env, from_version)
+                .unwrap_or_else(|e| panic_with_error!(env, e))
+                .into_val(env);
+        }
+
+        let caller = action
+            .args
+            .get(0)
+            .and_then(|arg| Address::try_from_val(env, &arg).ok())
+            .unwrap_or_else(|| panic_with_error!(env, DaoError::InvalidActionArgs));
+        if caller != env.current_contract_address() {
+            panic_with_error!(env, DaoError::Unauthorized);
+        }
+
+        if action.function == Symbol::new(env, "set_kind_config") && action.args.len() == 3 {
+            let kind = ProposalKind::try_from_val(env, &action.args.get(1).unwrap())
+                .unwrap_or_else(|_| panic_with_error!(env, DaoError::InvalidActionArgs));
+            let config = KindConfig::try_from_val(env, &action.args.get(2).unwrap())
+                .unwrap_or_else(|_| panic_with_error!(env, DaoError::InvalidActionArgs));
+            Self::_set_kind_config(env, kind, config);
+        } else if action.function == Symbol::new(env, "set_voting_mode") && action.args.len() == 2 {
+            let mode = VotingMode::try_from_val(env, &action.args.get(1).unwrap())
+                .unwrap_or_else(|_| panic_with_error!(env, DaoError::InvalidActionArgs));
+            Self::_set_voting_mode(env, mode);
+        } else if action.function == Symbol::new(env, "set_guardian") && action.args.len() == 2 {
+            let guardian = Address::try_from_val(env, &action.args.get(1).unwrap())
+                .unwrap_or_else(|_| panic_with_error!(env, DaoError::InvalidActionArgs));
+            env.storage().instance().set(&DataKey::Guardian, &guardian);
+        } else if action.function == Symbol::new(env, "set_param") && action.args.len() == 3 {
+            let name = Symbol::try_from_val(env, &action.args.get(1).unwrap())
+                .unwrap_or_else(|_| panic_with_error!(env, DaoError::InvalidActionArgs));
+            let value = i128::try_from_val(env, &action.args.get(2).unwrap())
+                .unwrap_or_else(|_| panic_with_error!(env, DaoError::InvalidActionArgs));
+            Self::_set_param(env, name, value);
+        } else if action.function == Symbol::new(env, "revoke_veto") && action.args.len() == 2 {
+            let proposal_id = u64::try_from_val(env, &action.args.get(1).unwrap())
+                .unwrap_or_else(|_| panic_with_error!(env, DaoError::InvalidActionArgs));
+            Self::_revoke_veto(env, proposal_id);
+        } else {
+            panic_with_error!(env, DaoError::UnsupportedSelfCall);
+        }
+        ().into_val(env)
+    }
+
+    fn _revoke_veto(env: &Env, proposal_id: u64) {
+        let mut proposal: Proposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+            .unwrap_or_else(|| panic_with_error!(env, DaoError::ProposalNotFound));
+        if proposal.status != ProposalStatus::Vetoed {
+            panic_with_error!(env, DaoError::ProposalNotVetoed);
+        }
+
+        Self::_set_status(env, proposal_id, &mut proposal, ProposalStatus::Queued);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Veto(proposal_id));
+        let _ttl_key = DataKey::Proposal(proposal_id);
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &proposal);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(env, EVENT_TAG, VetoRevoked { proposal_id });
+    }
+
+    /// The registry's "token" entry, falling back to the one set at `initialize`
+    fn _governance_token(env: &Env) -> Address {
+        if let Some(registry) = env.storage().instance().get::<DataKey, Address>(&DataKey::Registry) {
+            let resolved: Option<Address> = env.invoke_contract(
+                &registry,
+                &Symbol::new(env, "lookup"),
+                (symbol_short!("token"),).into_val(env),
+            );
+            if let Some(token) = resolved {
+                return token;
+            }
+        }
+        env.storage()
+            .instance()
+            .get(&DataKey::GovernanceToken)
+            .unwrap_or_else(|| panic_with_error!(env, DaoError::NotInitialized))
+    }
+
+    fn _total_supply(env: &Env) -> i128 {
+        let token = Self::_governance_token(env);
+        env.invoke_contract(&token, &Symbol::new(env, "total_supply"), Vec::new(env))
+    }
+
+    /// Move `proposal` to `status`, keeping the per-status id lists in step
+    fn _set_status(env: &Env, proposal_id: u64, proposal: &mut Proposal, status: ProposalStatus) {
+        let key = DataKey::StatusProposals(proposal.status.clone());
+        let mut ids: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        if let Some(idx) = ids.first_index_of(proposal_id) {
+            ids.remove(idx);
+            env.storage().persistent().set(&key, &ids);
+        }
+        Self::_list(env, &status, proposal_id);
+        proposal.status = status;
+    }
+
+    fn _list(env: &Env, status: &ProposalStatus, proposal_id: u64) {
+        let key = DataKey::StatusProposals(status.clone());
+        let mut ids: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        ids.push_back(proposal_id);
+        env.storage().persistent().set(&key, &ids);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _load_proposals(env: &Env, ids: Vec<u64>) -> Vec<(u64, Proposal)> {
+        let mut result = Vec::new(env);
+        for id in ids.iter() {
+            if let Some(proposal) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Proposal>(&DataKey::Proposal(id))
+            {
+                result.push_back((id, proposal));
+            }
+        }
+        result
+    }
+
+    /// Free an emergency slot once the proposal stops accepting votes
+    fn _release_emergency_slot(env: &Env, proposal: &Proposal) {
+        if proposal.kind != ProposalKind::Emergency {
+            return;
+        }
+        let active: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ActiveEmergencies)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::ActiveEmergencies, &active.saturating_sub(1));
+    }
+
+    /// Return a proposal's deposit to the proposer, or send it to the treasury
+    fn _settle_deposit(env: &Env, proposal_id: u64, proposer: &Address, refund: bool) {
+        let deposit: ProposalDeposit = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::Deposit(proposal_id))
+        {
+            Some(deposit) => deposit,
+            None => return,
+        };
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Deposit(proposal_id));
+
+        let token_client = token::Client::new(env, &deposit.token);
+        if refund {
+            token_client.transfer(&env.current_contract_address(), proposer, &deposit.amount);
+            events::publish(
+                env,
+                EVENT_TAG,
+                DepositRefunded { proposal_id, proposer: proposer.clone(), amount: deposit.amount },
+            );
+        } else {
+            token_client.transfer(&env.current_contract_address(), &deposit.treasury, &deposit.amount);
+            events::publish(
+                env,
+                EVENT_TAG,
+                DepositForfeited { proposal_id, treasury: deposit.treasury, amount: deposit.amount },
+            );
+        }
+    }
+
+    /// Voting power from the governance token's checkpoints. Panics cleanly
+    /// when the token can't serve the snapshot rather than falling back to
+    /// the live balance.
+    fn _past_votes(env: &Env, voter: &Address, ledger: u32) -> i128 {
+        let token = Self::_governance_token(env);
+        let args: Vec<Val> = Vec::from_array(env, [voter.into_val(env), ledger.into_val(env)]);
+        match env.try_invoke_contract::<i128, soroban_sdk::Error>(
+            &token,
+            &Symbol::new(env, "get_past_votes"),
+            args,
+        ) {
+            Ok(Ok(power)) => power,
+            _ => panic_with_error!(env, DaoError::SnapshotUnavailable),
+        }
+    }
+
+    /// Who the account had delegated to at the snapshot. Tokens without
+    /// delegation history are treated as having no delegations.
+    fn _past_delegate(env: &Env, account: &Address, ledger: u32) -> Option<Address> {
+        let token = Self::_governance_token(env);
+        let args: Vec<Val> = Vec::from_array(env, [account.into_val(env), ledger.into_val(env)]);
+        match env.try_invoke_contract::<Option<Address>, soroban_sdk::Error>(
+            &token,
+            &Symbol::new(env, "get_past_delegate"),
+            args,
+        ) {
+            Ok(Ok(delegate)) => delegate,
+            _ => None,
+        }
+    }
+
+    /// Weight the account held at the snapshot, its own and delegated to it,
+    /// whether or not it had passed that on
+    fn _past_represented(env: &Env, account: &Address, ledger: u32) -> i128 {
+        let token = Self::_governance_token(env);
+        let args: Vec<Val> = Vec::from_array(env, [account.into_val(env), ledger.into_val(env)]);
+        match env.try_invoke_contract::<i128, soroban_sdk::Error>(
+            &token,
+            &Symbol::new(env, "get_past_represented"),
+            args,
+        ) {
+            Ok(Ok(represented)) => represented,
+            _ => panic_with_error!(env, DaoError::SnapshotUnavailable),
+        }
+    }
 }
 
 mod test;
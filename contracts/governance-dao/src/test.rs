@@ -1,6 +1,11 @@
 #![cfg(test)]
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, IntoVal};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    token, Address, BytesN, Env, IntoVal, String, Symbol, Vec,
+    xdr::FromXdr,
+};
 
 #[test]
 fn test_initialize() {
@@ -17,7 +22,7 @@ fn test_initialize() {
 }
 
 #[test]
-#[should_panic(expected = "already initialized")]
+#[should_panic(expected = "Error(Contract, #601)")]
 fn test_initialize_twice() {
     let env = Env::default();
     env.mock_all_auths();
@@ -46,3 +51,1042 @@ fn test_initialize_non_admin_fails() {
     // This should panic because admin didn't authorize it and we haven't mocked it
     client.initialize(&admin, &token, &3600u32, &1000i128, &5000u32, &100i128);
 }
+
+#[contract]
+pub struct MockVotesToken;
+
+#[contractimpl]
+impl MockVotesToken {
+    pub fn set_votes(env: Env, account: Address, ledger: u32, votes: i128) {
+        env.storage().persistent().set(&(account, ledger), &votes);
+    }
+
+    pub fn get_past_votes(env: Env, account: Address, ledger: u32) -> i128 {
+        env.storage().persistent().get(&(account, ledger)).unwrap_or(0)
+    }
+
+    pub fn set_delegate(env: Env, account: Address, ledger: u32, delegate: Address) {
+        env.storage().persistent().set(&(symbol_short!("delegate"), account, ledger), &delegate);
+    }
+
+    pub fn get_past_delegate(env: Env, account: Address, ledger: u32) -> Option<Address> {
+        env.storage().persistent().get(&(symbol_short!("delegate"), account, ledger))
+    }
+
+    pub fn set_represented(env: Env, account: Address, ledger: u32, represented: i128) {
+        env.storage().persistent().set(&(symbol_short!("repr"), account, ledger), &represented);
+    }
+
+    pub fn get_past_represented(env: Env, account: Address, ledger: u32) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("repr"), account, ledger))
+            .unwrap_or(0)
+    }
+
+    pub fn total_supply(_env: Env) -> i128 {
+        1_000
+    }
+}
+
+fn setup_with_token(env: &Env, token: &Address) -> (GovernanceDaoContractClient<'static>, Address) {
+    let contract_id = env.register_contract(None, GovernanceDaoContract);
+    let client = GovernanceDaoContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin, token, &100u32, &100i128, &51u32, &0i128);
+    (client, admin)
+}
+
+#[test]
+fn test_votes_weighted_at_snapshot() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, _) = setup_with_token(&env, &token_id);
+
+    let proposer = Address::generate(&env);
+    let voter = Address::generate(&env);
+    let snapshot = env.ledger().sequence();
+    token.set_votes(&voter, &snapshot, &150);
+
+    let id = client.create_proposal(
+        &proposer,
+        &String::from_str(&env, "Lower fees"),
+        &String::from_str(&env, "Cut the platform fee"),
+        &ProposalKind::General,
+        &None,
+    );
+    assert_eq!(client.get_proposal(&id).unwrap().snapshot_ledger, snapshot);
+
+    // Tokens acquired after the proposal was created don't count
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    token.set_votes(&voter, &env.ledger().sequence(), &10_000);
+
+    client.cast_vote(&voter, &id, &VoteChoice::For);
+    assert_eq!(client.get_vote(&id, &voter).unwrap().power, 150);
+    assert_eq!(client.get_proposal(&id).unwrap().votes_for, 150);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #629)")]
+fn test_vote_without_snapshot_power_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let (client, _) = setup_with_token(&env, &token_id);
+
+    let proposer = Address::generate(&env);
+    let id = client.create_proposal(
+        &proposer,
+        &String::from_str(&env, "Lower fees"),
+        &String::from_str(&env, "Cut the platform fee"),
+        &ProposalKind::General,
+        &None,
+    );
+    client.cast_vote(&Address::generate(&env), &id, &VoteChoice::For);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #636)")]
+fn test_vote_fails_when_token_has_no_snapshots() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // A token contract without `get_past_votes`
+    let token_admin = Address::generate(&env);
+    let token_id = env.register_stellar_asset_contract_v2(token_admin).address();
+    let (client, _) = setup_with_token(&env, &token_id);
+
+    let proposer = Address::generate(&env);
+    let id = client.create_proposal(
+        &proposer,
+        &String::from_str(&env, "Lower fees"),
+        &String::from_str(&env, "Cut the platform fee"),
+        &ProposalKind::General,
+        &None,
+    );
+    client.cast_vote(&Address::generate(&env), &id, &VoteChoice::For);
+}
+
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_address(env: Env, key: Symbol, address: Address) {
+        env.storage().instance().set(&key, &address);
+    }
+
+    pub fn lookup(env: Env, key: Symbol) -> Option<Address> {
+        env.storage().instance().get(&key)
+    }
+}
+
+#[test]
+fn test_governance_token_resolved_through_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let stale_id = env.register_contract(None, MockVotesToken);
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, admin) = setup_with_token(&env, &stale_id);
+
+    let registry_id = env.register_contract(None, MockRegistry);
+    let registry = MockRegistryClient::new(&env, &registry_id);
+    client.set_registry(&admin, &registry_id);
+
+    let proposer = Address::generate(&env);
+    let voter = Address::generate(&env);
+    let snapshot = env.ledger().sequence();
+    token.set_votes(&voter, &snapshot, &150);
+
+    // No "token" entry yet: the token from `initialize` is used
+    let id = client.create_proposal(
+        &proposer,
+        &String::from_str(&env, "Lower fees"),
+        &String::from_str(&env, "Cut the platform fee"),
+        &ProposalKind::General,
+        &None,
+    );
+    assert!(client.try_cast_vote(&voter, &id, &VoteChoice::For).is_err());
+
+    registry.set_address(&symbol_short!("token"), &token_id);
+    client.cast_vote(&voter, &id, &VoteChoice::For);
+    assert_eq!(client.get_proposal(&id).unwrap().votes_for, 150);
+}
+
+#[test]
+fn test_proposer_min_checked_at_snapshot() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, admin) = setup_with_token(&env, &token_id);
+    client.set_param(&admin, &Symbol::new(&env, "proposer_min"), &100);
+
+    let proposer = Address::generate(&env);
+    let title = String::from_str(&env, "Lower fees");
+    let description = String::from_str(&env, "Cut the platform fee");
+    token.set_votes(&proposer, &env.ledger().sequence(), &99);
+    assert_eq!(
+        client.try_create_proposal(&proposer, &title, &description, &ProposalKind::General, &None),
+        Err(Ok(soroban_sdk::Error::from_contract_error(DaoError::InsufficientStake as u32)))
+    );
+
+    token.set_votes(&proposer, &env.ledger().sequence(), &100);
+    client.create_proposal(&proposer, &title, &description, &ProposalKind::General, &None);
+}
+
+#[test]
+fn test_abstain_counts_toward_quorum_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, _) = setup_with_token(&env, &token_id);
+
+    let snapshot = env.ledger().sequence();
+    let yes = Address::generate(&env);
+    let no = Address::generate(&env);
+    let abstainer = Address::generate(&env);
+    token.set_votes(&yes, &snapshot, &30);
+    token.set_votes(&no, &snapshot, &20);
+    token.set_votes(&abstainer, &snapshot, &60);
+
+    let proposer = Address::generate(&env);
+    let title = String::from_str(&env, "Lower fees");
+    let description = String::from_str(&env, "Cut the platform fee");
+
+    // Without the abstention, 50 votes miss the 100 quorum
+    let short = client.create_proposal(&proposer, &title, &description, &ProposalKind::General, &None);
+    client.cast_vote(&yes, &short, &VoteChoice::For);
+    client.cast_vote(&no, &short, &VoteChoice::Against);
+
+    let id = client.create_proposal(&proposer, &title, &description, &ProposalKind::General, &None);
+    client.cast_vote(&yes, &id, &VoteChoice::For);
+    client.cast_vote(&no, &id, &VoteChoice::Against);
+    client.cast_vote(&abstainer, &id, &VoteChoice::Abstain);
+
+    let proposal = client.get_proposal(&id).unwrap();
+    assert_eq!(proposal.votes_for, 30);
+    assert_eq!(proposal.votes_against, 20);
+    assert_eq!(proposal.votes_abstain, 60);
+
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    client.finalize_proposal(&Address::generate(&env), &short);
+    client.finalize_proposal(&Address::generate(&env), &id);
+
+    // 30 / 50 decisive votes = 60% clears the 51% threshold
+    assert!(client.get_proposal(&short).unwrap().status == ProposalStatus::Rejected);
+    assert!(client.get_proposal(&id).unwrap().status == ProposalStatus::Passed);
+}
+
+#[contract]
+pub struct MockFeeTarget;
+
+#[contractimpl]
+impl MockFeeTarget {
+    pub fn set_fee(env: Env, fee_bps: u32) -> u32 {
+        let previous: u32 = env.storage().instance().get(&0u32).unwrap_or(0);
+        env.storage().instance().set(&0u32, &fee_bps);
+        previous
+    }
+
+    pub fn fee(env: Env) -> u32 {
+        env.storage().instance().get(&0u32).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_queue_and_execute_proposal_action() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, admin) = setup_with_token(&env, &token_id);
+    client.set_timelock_delay(&admin, &3_600);
+
+    let target_id = env.register_contract(None, MockFeeTarget);
+    let target = MockFeeTargetClient::new(&env, &target_id);
+    target.set_fee(&500);
+
+    let voter = Address::generate(&env);
+    token.set_votes(&voter, &env.ledger().sequence(), &200);
+
+    let action = ProposalAction {
+        target: target_id.clone(),
+        function: Symbol::new(&env, "set_fee"),
+        args: Vec::from_array(&env, [250u32.into_val(&env)]),
+    };
+    let id = client.create_proposal(
+        &Address::generate(&env),
+        &String::from_str(&env, "Lower fees"),
+        &String::from_str(&env, "Cut the platform fee"),
+        &ProposalKind::ParameterChange,
+        &Some(action),
+    );
+    assert!(client.get_proposal(&id).unwrap().has_action);
+    assert!(client.get_proposal_action(&id).is_some());
+    client.cast_vote(&voter, &id, &VoteChoice::For);
+
+    // Only passed proposals can be queued
+    assert!(client.try_queue_proposal(&id).is_err());
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    client.finalize_proposal(&Address::generate(&env), &id);
+
+    let eta = client.queue_proposal(&id);
+    assert_eq!(eta, env.ledger().timestamp() + 3_600);
+    assert!(client.try_execute_proposal(&id).is_err());
+    assert_eq!(target.fee(), 500);
+
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    client.execute_proposal(&id);
+    assert_eq!(target.fee(), 250);
+
+    let proposal = client.get_proposal(&id).unwrap();
+    assert!(proposal.status == ProposalStatus::Executed);
+    let previous = u32::from_xdr(&env, &proposal.execution_result.unwrap()).unwrap();
+    assert_eq!(previous, 500);
+    assert!(client.try_execute_proposal(&id).is_err());
+}
+
+#[test]
+fn test_proposal_deposit_refunded_or_forfeited() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let votes_id = env.register_contract(None, MockVotesToken);
+    let votes = MockVotesTokenClient::new(&env, &votes_id);
+    let (client, admin) = setup_with_token(&env, &votes_id);
+
+    let token_admin = Address::generate(&env);
+    let pulsar = env.register_stellar_asset_contract_v2(token_admin).address();
+    let pulsar_balance = token::Client::new(&env, &pulsar);
+    let treasury = Address::generate(&env);
+    client.set_proposal_deposit(&admin, &pulsar, &1_000, &treasury);
+
+    let proposer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &pulsar).mint(&proposer, &2_000);
+    let voter = Address::generate(&env);
+    votes.set_votes(&voter, &env.ledger().sequence(), &150);
+
+    let title = String::from_str(&env, "Lower fees");
+    let description = String::from_str(&env, "Cut the platform fee");
+    let popular = client.create_proposal(&proposer, &title, &description, &ProposalKind::General, &None);
+    let ignored = client.create_proposal(&proposer, &title, &description, &ProposalKind::General, &None);
+    assert_eq!(pulsar_balance.balance(&proposer), 0);
+    assert_eq!(client.get_deposit(&popular).unwrap().amount, 1_000);
+
+    // Quorum is met even though the vote goes against the proposal
+    client.cast_vote(&voter, &popular, &VoteChoice::Against);
+
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    client.finalize_proposal(&Address::generate(&env), &popular);
+    client.finalize_proposal(&Address::generate(&env), &ignored);
+
+    assert!(client.get_proposal(&popular).unwrap().status == ProposalStatus::Rejected);
+    assert_eq!(pulsar_balance.balance(&proposer), 1_000);
+    assert_eq!(pulsar_balance.balance(&treasury), 1_000);
+    assert!(client.get_deposit(&popular).is_none());
+    assert!(client.get_deposit(&ignored).is_none());
+}
+
+#[test]
+fn test_kind_config_applied_at_creation_and_settable_by_governance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, admin) = setup_with_token(&env, &token_id);
+
+    let title = String::from_str(&env, "Treasury spend");
+    let description = String::from_str(&env, "Fund the grants program");
+
+    // Treasury spends need 20% of supply to turn out and a 2/3 majority
+    let strict = KindConfig {
+        quorum_bps: 2_000,
+        threshold_bps: 6_667,
+        voting_period: 50,
+        timelock_secs: 7_200,
+    };
+    client.set_kind_config(&admin, &ProposalKind::TreasurySpend, &strict);
+
+    let spend = client.create_proposal(&admin, &title, &description, &ProposalKind::TreasurySpend, &None);
+    let proposal = client.get_proposal(&spend).unwrap();
+    assert_eq!(proposal.quorum_required, 200);
+    assert_eq!(proposal.threshold_bps, 6_667);
+    assert_eq!(proposal.end_ledger, proposal.start_ledger + 50);
+    assert_eq!(proposal.timelock_secs, 7_200);
+
+    // Other kinds keep the DAO-wide defaults
+    let general = client.create_proposal(&admin, &title, &description, &ProposalKind::General, &None);
+    assert_eq!(client.get_proposal(&general).unwrap().threshold_bps, 5_100);
+
+    // 60% For clears the default bar but not the treasury one
+    let snapshot = env.ledger().sequence();
+    let yes = Address::generate(&env);
+    let no = Address::generate(&env);
+    token.set_votes(&yes, &snapshot, &150);
+    token.set_votes(&no, &snapshot, &100);
+    for id in [spend, general] {
+        client.cast_vote(&yes, &id, &VoteChoice::For);
+        client.cast_vote(&no, &id, &VoteChoice::Against);
+    }
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    client.finalize_proposal(&Address::generate(&env), &spend);
+    client.finalize_proposal(&Address::generate(&env), &general);
+    assert!(client.get_proposal(&spend).unwrap().status == ProposalStatus::Rejected);
+    assert!(client.get_proposal(&general).unwrap().status == ProposalStatus::Passed);
+
+    // The DAO can retune a kind through one of its own proposals
+    let relaxed = KindConfig {
+        quorum_bps: 1_000,
+        threshold_bps: 5_500,
+        voting_period: 50,
+        timelock_secs: 3_600,
+    };
+    let dao = client.address.clone();
+    let action = ProposalAction {
+        target: dao.clone(),
+        function: Symbol::new(&env, "set_kind_config"),
+        args: Vec::from_array(
+            &env,
+            [
+                dao.into_val(&env),
+                ProposalKind::TreasurySpend.into_val(&env),
+                relaxed.into_val(&env),
+            ],
+        ),
+    };
+    let snapshot = env.ledger().sequence() - 1;
+    token.set_votes(&yes, &snapshot, &150);
+    let retune = client.create_proposal(&admin, &title, &description, &ProposalKind::ParameterChange, &Some(action));
+    client.cast_vote(&yes, &retune, &VoteChoice::For);
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    client.finalize_proposal(&Address::generate(&env), &retune);
+    client.queue_proposal(&retune);
+    env.ledger().with_mut(|li| li.timestamp += DEFAULT_TIMELOCK_DELAY);
+    client.execute_proposal(&retune);
+
+    let config = client.get_kind_config(&ProposalKind::TreasurySpend).unwrap();
+    assert_eq!(config.threshold_bps, 5_500);
+    assert_eq!(config.timelock_secs, 3_600);
+    assert!(client.try_set_kind_config(&Address::generate(&env), &ProposalKind::General, &relaxed).is_err());
+}
+
+#[test]
+fn test_change_and_withdraw_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, _) = setup_with_token(&env, &token_id);
+
+    let voter = Address::generate(&env);
+    token.set_votes(&voter, &env.ledger().sequence(), &80);
+    let id = client.create_proposal(
+        &Address::generate(&env),
+        &String::from_str(&env, "Lower fees"),
+        &String::from_str(&env, "Cut the platform fee"),
+        &ProposalKind::General,
+        &None,
+    );
+
+    client.cast_vote(&voter, &id, &VoteChoice::For);
+    client.cast_vote(&voter, &id, &VoteChoice::Against);
+    let proposal = client.get_proposal(&id).unwrap();
+    assert_eq!(proposal.votes_for, 0);
+    assert_eq!(proposal.votes_against, 80);
+
+    client.withdraw_vote(&voter, &id);
+    let proposal = client.get_proposal(&id).unwrap();
+    assert_eq!(proposal.votes_against, 0);
+    assert!(!client.has_voted(&id, &voter));
+    assert!(client.try_withdraw_vote(&voter, &id).is_err());
+
+    // Once voting has closed the choice is final
+    client.cast_vote(&voter, &id, &VoteChoice::Abstain);
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    assert!(client.try_cast_vote(&voter, &id, &VoteChoice::For).is_err());
+    assert!(client.try_withdraw_vote(&voter, &id).is_err());
+    assert_eq!(client.get_proposal(&id).unwrap().votes_abstain, 80);
+}
+
+#[test]
+fn test_delegators_can_override_their_delegate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, _) = setup_with_token(&env, &token_id);
+
+    // Alice (100) and Carol (30) delegate to Bob, who holds 50 himself
+    let snapshot = env.ledger().sequence();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    token.set_represented(&alice, &snapshot, &100);
+    token.set_represented(&carol, &snapshot, &30);
+    token.set_delegate(&alice, &snapshot, &bob);
+    token.set_delegate(&carol, &snapshot, &bob);
+    token.set_votes(&bob, &snapshot, &180);
+
+    let title = String::from_str(&env, "Lower fees");
+    let description = String::from_str(&env, "Cut the platform fee");
+    let id = client.create_proposal(&bob, &title, &description, &ProposalKind::General, &None);
+
+    // Bob votes with everything delegated to him
+    client.cast_vote(&bob, &id, &VoteChoice::For);
+    assert_eq!(client.get_proposal(&id).unwrap().votes_for, 180);
+
+    // Alice disagrees and takes her own weight back
+    client.cast_vote(&alice, &id, &VoteChoice::Against);
+    let proposal = client.get_proposal(&id).unwrap();
+    assert_eq!(proposal.votes_for, 80);
+    assert_eq!(proposal.votes_against, 100);
+    assert_eq!(client.get_vote(&id, &bob).unwrap().power, 80);
+    assert_eq!(client.get_vote(&id, &alice).unwrap().overrides, Some(bob.clone()));
+
+    // Withdrawing hands the weight back to Bob
+    client.withdraw_vote(&alice, &id);
+    let proposal = client.get_proposal(&id).unwrap();
+    assert_eq!(proposal.votes_for, 180);
+    assert_eq!(proposal.votes_against, 0);
+
+    // A delegator voting before the delegate is excluded from the delegate's weight
+    let second = client.create_proposal(&bob, &title, &description, &ProposalKind::General, &None);
+    client.cast_vote(&carol, &second, &VoteChoice::Abstain);
+    client.cast_vote(&bob, &second, &VoteChoice::For);
+    let proposal = client.get_proposal(&second).unwrap();
+    assert_eq!(proposal.votes_abstain, 30);
+    assert_eq!(proposal.votes_for, 150);
+}
+
+#[test]
+fn test_guardian_veto_and_supermajority_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, admin) = setup_with_token(&env, &token_id);
+    let guardian = Address::generate(&env);
+    client.set_guardian(&admin, &guardian);
+
+    let yes = Address::generate(&env);
+    let no = Address::generate(&env);
+    let title = String::from_str(&env, "Drain treasury");
+    let description = String::from_str(&env, "Suspicious spend");
+    let reason = BytesN::from_array(&env, &[9u8; 32]);
+
+    let snapshot = env.ledger().sequence();
+    token.set_votes(&yes, &snapshot, &150);
+    let id = client.create_proposal(&yes, &title, &description, &ProposalKind::General, &None);
+    client.cast_vote(&yes, &id, &VoteChoice::For);
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    client.finalize_proposal(&Address::generate(&env), &id);
+
+    // Vetoes are only possible while the proposal sits in the timelock
+    assert!(client.try_veto_proposal(&guardian, &id, &reason).is_err());
+    client.queue_proposal(&id);
+    assert!(client.try_veto_proposal(&yes, &id, &reason).is_err());
+    client.veto_proposal(&guardian, &id, &reason);
+    assert!(client.get_proposal(&id).unwrap().status == ProposalStatus::Vetoed);
+    assert_eq!(client.get_veto(&id).unwrap().reason_hash, reason);
+    env.ledger().with_mut(|li| li.timestamp += DEFAULT_TIMELOCK_DELAY);
+    assert!(client.try_execute_proposal(&id).is_err());
+
+    // Override proposals can't be opened through create_proposal
+    assert!(client
+        .try_create_proposal(&yes, &title, &description, &ProposalKind::VetoOverride, &None)
+        .is_err());
+
+    // 60% in favour is not enough to lift a veto
+    let snapshot = env.ledger().sequence() - 1;
+    token.set_votes(&yes, &snapshot, &120);
+    token.set_votes(&no, &snapshot, &80);
+    let weak = client.propose_veto_override(&yes, &id);
+    assert_eq!(client.get_proposal(&weak).unwrap().threshold_bps, 6_667);
+    client.cast_vote(&yes, &weak, &VoteChoice::For);
+    client.cast_vote(&no, &weak, &VoteChoice::Against);
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    client.finalize_proposal(&Address::generate(&env), &weak);
+    assert!(client.get_proposal(&weak).unwrap().status == ProposalStatus::Rejected);
+
+    // 75% lifts it, and the override itself can't be vetoed
+    let snapshot = env.ledger().sequence() - 1;
+    token.set_votes(&yes, &snapshot, &150);
+    token.set_votes(&no, &snapshot, &50);
+    let strong = client.propose_veto_override(&yes, &id);
+    client.cast_vote(&yes, &strong, &VoteChoice::For);
+    client.cast_vote(&no, &strong, &VoteChoice::Against);
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    client.finalize_proposal(&Address::generate(&env), &strong);
+    client.queue_proposal(&strong);
+    assert!(client.try_veto_proposal(&guardian, &strong, &reason).is_err());
+    env.ledger().with_mut(|li| li.timestamp += DEFAULT_TIMELOCK_DELAY);
+    client.execute_proposal(&strong);
+
+    assert!(client.get_proposal(&id).unwrap().status == ProposalStatus::Queued);
+    assert!(client.get_veto(&id).is_none());
+    client.execute_proposal(&id);
+    assert!(client.get_proposal(&id).unwrap().status == ProposalStatus::Executed);
+}
+
+#[test]
+fn test_emergency_proposals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, admin) = setup_with_token(&env, &token_id);
+    let guardian = Address::generate(&env);
+    client.set_guardian(&admin, &guardian);
+
+    let title = String::from_str(&env, "Pause payouts");
+    let description = String::from_str(&env, "Exploit in progress");
+    assert!(client.try_create_emergency_proposal(&guardian, &title, &description, &None).is_err());
+
+    client.set_emergency_config(
+        &admin,
+        &EmergencyConfig { stake_threshold: 500, max_active: 1 },
+    );
+
+    let whale = Address::generate(&env);
+    let minnow = Address::generate(&env);
+    let snapshot = env.ledger().sequence();
+    token.set_votes(&whale, &snapshot, &500);
+    token.set_votes(&minnow, &snapshot, &10);
+    assert!(client.try_create_emergency_proposal(&minnow, &title, &description, &None).is_err());
+    assert!(client
+        .try_create_proposal(&whale, &title, &description, &ProposalKind::Emergency, &None)
+        .is_err());
+
+    let id = client.create_emergency_proposal(&whale, &title, &description, &None);
+    let proposal = client.get_proposal(&id).unwrap();
+    assert_eq!(proposal.end_ledger, proposal.start_ledger + 720);
+    assert_eq!(proposal.threshold_bps, 7_500);
+    assert_eq!(proposal.quorum_required, 100);
+    assert_eq!(client.get_active_emergencies(), 1);
+
+    // The concurrency cap applies even to the guardian
+    assert!(client.try_create_emergency_proposal(&guardian, &title, &description, &None).is_err());
+
+    client.cast_vote(&whale, &id, &VoteChoice::For);
+    env.ledger().with_mut(|li| li.sequence_number += 721);
+    client.finalize_proposal(&Address::generate(&env), &id);
+    assert!(client.get_proposal(&id).unwrap().status == ProposalStatus::Passed);
+    assert_eq!(client.get_active_emergencies(), 0);
+
+    let next = client.create_emergency_proposal(&guardian, &title, &description, &None);
+    client.cancel_proposal(&guardian, &next);
+    assert_eq!(client.get_active_emergencies(), 0);
+}
+
+#[test]
+fn test_cast_vote_by_signature() {
+    use ed25519_dalek::{Signer, SigningKey};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, _) = setup_with_token(&env, &token_id);
+
+    let voter = Address::generate(&env);
+    token.set_votes(&voter, &env.ledger().sequence(), &40);
+    let id = client.create_proposal(
+        &Address::generate(&env),
+        &String::from_str(&env, "Lower fees"),
+        &String::from_str(&env, "Cut the platform fee"),
+        &ProposalKind::General,
+        &None,
+    );
+
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = BytesN::from_array(&env, &signing_key.verifying_key().to_bytes());
+    client.set_vote_key(&voter, &public_key);
+
+    let sign = |choice: &VoteChoice, expiration: u64| {
+        let payload = client.vote_payload(&voter, &id, choice, &expiration);
+        let mut message = [0u8; 256];
+        let len = payload.len() as usize;
+        payload.copy_into_slice(&mut message[..len]);
+        BytesN::from_array(&env, &signing_key.sign(&message[..len]).to_bytes())
+    };
+
+    let expiration = env.ledger().timestamp() + 600;
+    let signature = sign(&VoteChoice::For, expiration);
+
+    // A signature for one choice can't be replayed for another
+    assert!(client
+        .try_cast_vote_by_sig(&voter, &id, &VoteChoice::Against, &expiration, &signature)
+        .is_err());
+
+    client.cast_vote_by_sig(&voter, &id, &VoteChoice::For, &expiration, &signature);
+    assert_eq!(client.get_proposal(&id).unwrap().votes_for, 40);
+    assert_eq!(client.get_vote_nonce(&voter), 1);
+
+    // Nor reused once consumed
+    assert!(client
+        .try_cast_vote_by_sig(&voter, &id, &VoteChoice::For, &expiration, &signature)
+        .is_err());
+
+    // Expired signatures are refused
+    let stale = sign(&VoteChoice::Abstain, expiration);
+    env.ledger().with_mut(|li| li.timestamp += 601);
+    assert!(client
+        .try_cast_vote_by_sig(&voter, &id, &VoteChoice::Abstain, &expiration, &stale)
+        .is_err());
+}
+
+#[test]
+fn test_list_proposals_and_voter_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, admin) = setup_with_token(&env, &token_id);
+
+    let voter = Address::generate(&env);
+    token.set_votes(&voter, &env.ledger().sequence(), &10);
+    let title = String::from_str(&env, "Proposal");
+    let description = String::from_str(&env, "Details");
+    for _ in 0..5 {
+        client.create_proposal(&admin, &title, &description, &ProposalKind::General, &None);
+    }
+    client.cancel_proposal(&admin, &2);
+    client.cancel_proposal(&admin, &4);
+
+    let page = client.list_proposals(&1, &2, &None);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().0, 2);
+    assert_eq!(page.get(1).unwrap().0, 3);
+
+    let active = client.list_proposals(&0, &10, &Some(ProposalStatus::Active));
+    assert_eq!(active.len(), 3);
+    assert_eq!(active.get(2).unwrap().0, 5);
+    let cancelled = client.list_proposals(&1, &10, &Some(ProposalStatus::Cancelled));
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(cancelled.get(0).unwrap().0, 4);
+    // A status list follows the proposal out of its old status
+    assert_eq!(client.list_proposals(&0, &10, &Some(ProposalStatus::Cancelled)).len(), 2);
+
+    client.cast_vote(&voter, &5, &VoteChoice::For);
+    client.cast_vote(&voter, &1, &VoteChoice::Against);
+    client.cast_vote(&voter, &3, &VoteChoice::Abstain);
+    // Changing a vote doesn't duplicate it; withdrawing hides it
+    client.cast_vote(&voter, &5, &VoteChoice::Against);
+    client.withdraw_vote(&voter, &1);
+
+    let history = client.list_votes_by_voter(&voter, &0, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().proposal_id, 5);
+    assert!(history.get(0).unwrap().vote.choice == VoteChoice::Against);
+    assert_eq!(history.get(1).unwrap().proposal_id, 3);
+    assert_eq!(client.list_votes_by_voter(&voter, &1, &10).len(), 1);
+
+    // Pages are capped however large a limit is asked for
+    for _ in 0..50 {
+        client.create_proposal(&admin, &title, &description, &ProposalKind::General, &None);
+    }
+    assert_eq!(client.list_proposals(&0, &u32::MAX, &None).len(), 50);
+    let active = client.list_proposals(&0, &u32::MAX, &Some(ProposalStatus::Active));
+    assert_eq!(active.len(), 50);
+    assert_eq!(active.get(3).unwrap().0, 6);
+    assert_eq!(client.list_proposals(&50, &u32::MAX, &Some(ProposalStatus::Active)).len(), 3);
+}
+
+#[test]
+fn test_quadratic_and_capped_voting_modes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, admin) = setup_with_token(&env, &token_id);
+
+    let whale = Address::generate(&env);
+    let small = Address::generate(&env);
+    let snapshot = env.ledger().sequence();
+    token.set_votes(&whale, &snapshot, &10_000);
+    token.set_votes(&small, &snapshot, &50);
+
+    let title = String::from_str(&env, "Community sentiment");
+    let description = String::from_str(&env, "Should we sponsor the meetup?");
+
+    client.set_voting_mode(&admin, &VotingMode::Quadratic);
+    let quadratic = client.create_proposal(&admin, &title, &description, &ProposalKind::General, &None);
+    client.set_voting_mode(&admin, &VotingMode::Capped(500));
+    let capped = client.create_proposal(&admin, &title, &description, &ProposalKind::General, &None);
+    assert!(client.get_voting_mode() == VotingMode::Capped(500));
+
+    for id in [quadratic, capped] {
+        client.cast_vote(&whale, &id, &VoteChoice::For);
+        client.cast_vote(&small, &id, &VoteChoice::Against);
+    }
+
+    // Each proposal keeps the mode it was created under
+    let proposal = client.get_proposal(&quadratic).unwrap();
+    assert_eq!(proposal.votes_for, 100);
+    assert_eq!(proposal.votes_against, 7);
+    assert_eq!(client.get_vote(&quadratic, &whale).unwrap().raw_power, 10_000);
+
+    let proposal = client.get_proposal(&capped).unwrap();
+    assert_eq!(proposal.votes_for, 500);
+    assert_eq!(proposal.votes_against, 50);
+
+    assert!(client.try_set_voting_mode(&admin, &VotingMode::Capped(0)).is_err());
+}
+
+#[test]
+fn test_finalize_bounty_and_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let votes_id = env.register_contract(None, MockVotesToken);
+    let votes = MockVotesTokenClient::new(&env, &votes_id);
+    let (client, admin) = setup_with_token(&env, &votes_id);
+
+    let token_admin = Address::generate(&env);
+    let pulsar = env.register_stellar_asset_contract_v2(token_admin).address();
+    let pulsar_balance = token::Client::new(&env, &pulsar);
+    let pulsar_mint = token::StellarAssetClient::new(&env, &pulsar);
+    let treasury = Address::generate(&env);
+    client.set_proposal_deposit(&admin, &pulsar, &1_000, &treasury);
+    client.set_finalize_bounty(&admin, &pulsar, &50);
+
+    let funder = Address::generate(&env);
+    pulsar_mint.mint(&funder, &60);
+    client.fund_bounty_pool(&funder, &60);
+    assert_eq!(client.get_bounty_pool(), 60);
+
+    let proposer = Address::generate(&env);
+    pulsar_mint.mint(&proposer, &4_000);
+    let voter = Address::generate(&env);
+    votes.set_votes(&voter, &env.ledger().sequence(), &150);
+
+    let title = String::from_str(&env, "Lower fees");
+    let description = String::from_str(&env, "Cut the platform fee");
+    let popular = client.create_proposal(&proposer, &title, &description, &ProposalKind::General, &None);
+    let ignored = client.create_proposal(&proposer, &title, &description, &ProposalKind::General, &None);
+    let still_open = client.create_proposal(&proposer, &title, &description, &ProposalKind::General, &None);
+    client.cast_vote(&voter, &popular, &VoteChoice::For);
+
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    let late = client.create_proposal(&proposer, &title, &description, &ProposalKind::General, &None);
+    client.cancel_proposal(&proposer, &still_open);
+
+    let keeper = Address::generate(&env);
+    let ids = Vec::from_array(&env, [popular, ignored, still_open, late, 99]);
+    assert_eq!(client.finalize_batch(&keeper, &ids), 2);
+
+    // The spam proposal's forfeited deposit pays its own bounty; the
+    // successful one is paid from the pool so its proposer is made whole
+    assert_eq!(pulsar_balance.balance(&keeper), 100);
+    assert_eq!(client.get_bounty_pool(), 10);
+    assert_eq!(pulsar_balance.balance(&proposer), 1_000);
+    assert_eq!(pulsar_balance.balance(&treasury), 1_000 + 950);
+    assert!(client.get_proposal(&late).unwrap().status == ProposalStatus::Active);
+
+    // An empty pool doesn't matter when the forfeited deposit can cover it
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    client.finalize_proposal(&keeper, &late);
+    assert_eq!(pulsar_balance.balance(&keeper), 150);
+    assert_eq!(client.get_bounty_pool(), 10);
+}
+
+#[test]
+fn test_set_param_through_proposal_with_bounds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, admin) = setup_with_token(&env, &token_id);
+    let dao = client.address.clone();
+
+    let title = String::from_str(&env, "Tune governance");
+    let description = String::from_str(&env, "Raise the pass threshold");
+    let voter = Address::generate(&env);
+    let set_param = |name: &str, value: i128| ProposalAction {
+        target: dao.clone(),
+        function: Symbol::new(&env, "set_param"),
+        args: Vec::from_array(
+            &env,
+            [
+                dao.into_val(&env),
+                Symbol::new(&env, name).into_val(&env),
+                value.into_val(&env),
+            ],
+        ),
+    };
+
+    token.set_votes(&voter, &env.ledger().sequence(), &150);
+    let raise = client.create_proposal(&admin, &title, &description, &ProposalKind::ParameterChange, &Some(set_param("threshold", 66)));
+    // A quorum above the token supply could never be reached
+    let brick = client.create_proposal(&admin, &title, &description, &ProposalKind::ParameterChange, &Some(set_param("quorum", 1_001)));
+    for id in [raise, brick] {
+        client.cast_vote(&voter, &id, &VoteChoice::For);
+    }
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    for id in [raise, brick] {
+        client.finalize_proposal(&Address::generate(&env), &id);
+        client.queue_proposal(&id);
+    }
+    env.ledger().with_mut(|li| li.timestamp += DEFAULT_TIMELOCK_DELAY);
+
+    client.execute_proposal(&raise);
+    assert_eq!(client.get_param(&Symbol::new(&env, "threshold")), 66);
+    let general = client.create_proposal(&admin, &title, &description, &ProposalKind::General, &None);
+    assert_eq!(client.get_proposal(&general).unwrap().threshold_bps, 6_600);
+
+    assert!(client.try_execute_proposal(&brick).is_err());
+    assert!(client.get_proposal(&brick).unwrap().status == ProposalStatus::Queued);
+    assert_eq!(client.get_param(&Symbol::new(&env, "quorum")), 100);
+
+    assert!(client.try_set_param(&admin, &Symbol::new(&env, "voting_period"), &10).is_err());
+    assert!(client.try_set_param(&admin, &Symbol::new(&env, "threshold"), &101).is_err());
+    assert!(client.try_set_param(&admin, &Symbol::new(&env, "grace_period"), &60).is_err());
+    assert!(client.try_set_param(&admin, &Symbol::new(&env, "unknown"), &1).is_err());
+    assert!(client.try_set_param(&voter, &Symbol::new(&env, "timelock"), &0).is_err());
+
+    // Once a grace period is set, stale queued proposals expire rather than run
+    client.set_param(&admin, &Symbol::new(&env, "grace_period"), &86_400);
+    env.ledger().with_mut(|li| li.timestamp += 86_401);
+    client.execute_proposal(&brick);
+    assert!(client.get_proposal(&brick).unwrap().status == ProposalStatus::Expired);
+}
+
+#[test]
+fn test_metadata_frozen_once_voting_starts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, _admin) = setup_with_token(&env, &token_id);
+
+    let proposer = Address::generate(&env);
+    let id = client.create_proposal(
+        &proposer,
+        &String::from_str(&env, "Grants round"),
+        &String::from_str(&env, "See the attached spec"),
+        &ProposalKind::General,
+        &None,
+    );
+
+    let spec = BytesN::from_array(&env, &[1u8; 32]);
+    let thread = BytesN::from_array(&env, &[2u8; 32]);
+    client.attach_metadata(&proposer, &id, &spec, &String::from_str(&env, "ipfs://spec"));
+    client.attach_metadata(&proposer, &id, &thread, &String::from_str(&env, "https://forum/t/42"));
+    assert!(client
+        .try_attach_metadata(&Address::generate(&env), &id, &spec, &String::from_str(&env, "ipfs://x"))
+        .is_err());
+    assert!(!client.is_metadata_frozen(&id));
+
+    let voter = Address::generate(&env);
+    token.set_votes(&voter, &env.ledger().sequence(), &150);
+    client.cast_vote(&voter, &id, &VoteChoice::For);
+    assert!(client.is_metadata_frozen(&id));
+    assert!(client
+        .try_attach_metadata(&proposer, &id, &spec, &String::from_str(&env, "ipfs://edited"))
+        .is_err());
+
+    // Withdrawing the only vote doesn't reopen the list
+    client.withdraw_vote(&voter, &id);
+    assert!(client
+        .try_attach_metadata(&proposer, &id, &spec, &String::from_str(&env, "ipfs://edited"))
+        .is_err());
+
+    let metadata = client.get_proposal_metadata(&id);
+    assert_eq!(metadata.len(), 2);
+    assert_eq!(metadata.get(0).unwrap().content_hash, spec);
+    assert_eq!(metadata.get(1).unwrap().uri, String::from_str(&env, "https://forum/t/42"));
+}
+
+#[test]
+fn test_upgrade_authority_and_self_migration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let token = MockVotesTokenClient::new(&env, &token_id);
+    let (client, admin) = setup_with_token(&env, &token_id);
+    let dao = client.address.clone();
+    let timelock = Address::generate(&env);
+
+    assert_eq!(client.get_version().version, 1);
+    assert_eq!(client.get_upgrade_authority(), Some(admin.clone()));
+    assert_eq!(client.try_migrate(&1), Err(Ok(DaoError::AlreadyMigrated)));
+
+    client.set_upgrade_authority(&admin, &timelock);
+    assert_eq!(client.get_upgrade_authority(), Some(timelock.clone()));
+    assert_eq!(client.try_set_upgrade_authority(&admin, &admin), Err(Ok(DaoError::Unauthorized)));
+
+    // A passed proposal reaches `migrate` through a self-call with no caller argument
+    let voter = Address::generate(&env);
+    token.set_votes(&voter, &env.ledger().sequence(), &150);
+    let action = ProposalAction {
+        target: dao.clone(),
+        function: Symbol::new(&env, "migrate"),
+        args: Vec::from_array(&env, [1u32.into_val(&env)]),
+    };
+    let id = client.create_proposal(
+        &admin,
+        &String::from_str(&env, "Migrate storage"),
+        &String::from_str(&env, "Run pending migrations"),
+        &ProposalKind::ParameterChange,
+        &Some(action),
+    );
+    client.cast_vote(&voter, &id, &VoteChoice::For);
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    client.finalize_proposal(&Address::generate(&env), &id);
+    client.queue_proposal(&id);
+    env.ledger().with_mut(|li| li.timestamp += DEFAULT_TIMELOCK_DELAY);
+
+    assert_eq!(client.try_execute_proposal(&id), Err(Ok(soroban_sdk::Error::from_contract_error(DaoError::AlreadyMigrated as u32))));
+}
+
+#[test]
+fn test_extend_ttl_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register_contract(None, MockVotesToken);
+    let (client, _) = setup_with_token(&env, &token_id);
+    let proposer = Address::generate(&env);
+    let id = client.create_proposal(
+        &proposer,
+        &String::from_str(&env, "Lower fees"),
+        &String::from_str(&env, "Cut the platform fee"),
+        &ProposalKind::General,
+        &None,
+    );
+
+    let ttl = || env.as_contract(&client.address, || env.storage().persistent().get_ttl(&DataKey::Proposal(id)));
+    assert_eq!(ttl(), PROPOSAL_TTL.extend_to);
+
+    for _ in 0..3 {
+        env.ledger().with_mut(|li| li.sequence_number += 80_000);
+        client.get_version();
+    }
+    assert!(ttl() < PROPOSAL_TTL.threshold);
+
+    assert!(client.extend_ttl_proposal(&id));
+    assert_eq!(ttl(), PROPOSAL_TTL.extend_to);
+    assert!(!client.extend_ttl_proposal(&(id + 1)));
+}
@@ -4,9 +4,14 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
-    Address, Env, String,
+    contract, contractimpl, contracttype,
+    Address, BytesN, Env, String, Vec,
 };
+pub use pulsartrack_errors::TokenError;
+use pulsartrack_events::{self as events, contract_event};
+use pulsartrack_access::Ownable;
+use pulsartrack_upgrade::{ContractVersion, Upgradeable};
+use pulsartrack_storage::{self as storage, Persistent, TtlPolicy};
 
 // ============================================================
 // Data Types
@@ -19,6 +24,30 @@ pub struct Delegation {
     pub delegated_at: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct PartialDelegation {
+    pub delegate: Address,
+    pub bps: u32, // share of the delegator's balance, in basis points
+    pub delegated_at: u64,
+}
+
+/// A value as of the end of `ledger`
+#[contracttype]
+#[derive(Clone)]
+pub struct Checkpoint {
+    pub ledger: u32,
+    pub value: i128,
+}
+
+/// Who an account had fully delegated to as of the end of `ledger`
+#[contracttype]
+#[derive(Clone)]
+pub struct DelegateCheckpoint {
+    pub ledger: u32,
+    pub delegate: Option<Address>,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct TokenMetadata {
@@ -27,6 +56,72 @@ pub struct TokenMetadata {
     pub decimals: u32,
 }
 
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct MetadataUpdated {
+    pub admin: Address,
+    pub name: String,
+    pub symbol: String,
+}
+contract_event!(MetadataUpdated, "metadata_updated");
+
+/// Who spent the allowance; the movement itself is the standard transfer
+/// event published just before it
+#[contracttype]
+#[derive(Clone)]
+pub struct TransferFrom {
+    pub spender: Address,
+    pub from: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+contract_event!(TransferFrom, "transfer_from");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AccountFrozen {
+    pub admin: Address,
+    pub account: Address,
+}
+contract_event!(AccountFrozen, "frozen");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AccountUnfrozen {
+    pub admin: Address,
+    pub account: Address,
+}
+contract_event!(AccountUnfrozen, "unfrozen");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Delegated {
+    pub delegator: Address,
+    pub delegate: Address,
+}
+contract_event!(Delegated, "delegated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PartialDelegated {
+    pub delegator: Address,
+    pub delegate: Address,
+    pub bps: u32,
+}
+contract_event!(PartialDelegated, "partial_delegated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PartialUndelegated {
+    pub delegator: Address,
+    pub delegate: Address,
+}
+contract_event!(PartialUndelegated, "partial_undelegated");
+
 // ============================================================
 // Storage Keys
 // ============================================================
@@ -40,10 +135,22 @@ pub enum DataKey {
     Balance(Address),
     Allowance(Address, Address),
     Delegation(Address),
-    VotingSnapshot(Address, u32), // Address, ledger_sequence
+    PartialDelegations(Address), // delegator -> Vec<PartialDelegation>
+    Delegators(Address),         // delegate -> Vec<Address> of partial delegators
+    Frozen(Address),
+    Represented(Address),        // kept own share + everything delegated in
+    BalanceHistory(Address),     // Vec<Checkpoint>
+    RepresentedHistory(Address), // Vec<Checkpoint>
+    DelegateHistory(Address),    // Vec<DelegateCheckpoint>
+    IncomingChains(Address),     // Vec<u32>: full-delegation chains ending here, counted by length
+    UpgradeAuthority,
+    ContractVersion,
 }
 
 pub const MAX_SUPPLY: i128 = 1_000_000_000_000; // 1M tokens with 6 decimals
+pub const MAX_BPS: u32 = 10_000;
+pub const MAX_PARTIAL_DELEGATES: u32 = 10;
+pub const MAX_DELEGATION_HOPS: u32 = 8; // longest allowed chain of full delegations
 
 // ============================================================
 // Contract
@@ -53,6 +160,13 @@ const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
 const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 34_560;
 const PERSISTENT_BUMP_AMOUNT: u32 = 259_200;
+// TTL per kind of persistent entry, bumped on every access
+const BALANCE_TTL: TtlPolicy = storage::STANDARD;
+const HISTORY_TTL: TtlPolicy = storage::LONG_LIVED;
+
+const EVENT_TAG: &str = "token";
+// v2 adds voting weight and checkpoints; v1 accounts get theirs lazily (see `_represented`)
+const CONTRACT_VERSION: u32 = 2;
 
 #[contract]
 pub struct GovernanceTokenContract;
@@ -60,13 +174,9 @@ pub struct GovernanceTokenContract;
 #[contractimpl]
 impl GovernanceTokenContract {
     /// Initialize the PULSAR governance token
-    pub fn initialize(env: Env, admin: Address) {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), TokenError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
-        }
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &admin);
+        Ownable::init(&env, &DataKey::Admin, &admin)?;
         env.storage()
             .instance()
             .set(&DataKey::TotalSupply, &0i128);
@@ -80,6 +190,41 @@ impl GovernanceTokenContract {
             decimals: 7,
         };
         env.storage().instance().set(&DataKey::Metadata, &metadata);
+        Upgradeable::init(&env, &DataKey::ContractVersion, CONTRACT_VERSION);
+        Ok(())
+    }
+
+    /// See `Upgradeable`
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), TokenError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::require_authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)?;
+        Upgradeable::upgrade(&env, EVENT_TAG, &DataKey::ContractVersion, new_wasm_hash);
+        Ok(())
+    }
+
+    pub fn migrate(env: Env, from_version: u32) -> Result<u32, TokenError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::require_authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)?;
+        // v1 -> v2 has no eager step: v1 kept no list of holders, so each
+        // account's weight and checkpoints are derived from its v1 balance on
+        // first use and v1 delegations are carried over by `sync_delegation`
+        Ok(Upgradeable::migrate(&env, EVENT_TAG, &DataKey::ContractVersion, from_version, CONTRACT_VERSION, |_| {})?)
+    }
+
+    pub fn set_upgrade_authority(env: Env, caller: Address, authority: Address) -> Result<(), TokenError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::set_authority(&env, EVENT_TAG, &DataKey::UpgradeAuthority, &DataKey::Admin, &caller, &authority)?;
+        Ok(())
+    }
+
+    pub fn get_upgrade_authority(env: Env) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)
+    }
+
+    pub fn get_version(env: Env) -> ContractVersion {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::version(&env, &DataKey::ContractVersion)
     }
 
     /// Get token name
@@ -103,13 +248,37 @@ impl GovernanceTokenContract {
         meta.decimals
     }
 
+    /// Update token name and symbol (admin only). Decimals are immutable.
+    pub fn update_metadata(
+        env: Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+    ) -> Result<(), TokenError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+
+        if name.is_empty() || symbol.is_empty() {
+            return Err(TokenError::InvalidMetadata);
+        }
+
+        let mut meta: TokenMetadata = env
+            .storage()
+            .instance()
+            .get(&DataKey::Metadata)
+            .ok_or(TokenError::NotInitialized)?;
+        meta.name = name.clone();
+        meta.symbol = symbol.clone();
+        env.storage().instance().set(&DataKey::Metadata, &meta);
+
+        events::publish(&env, EVENT_TAG, MetadataUpdated { admin, name, symbol });
+        Ok(())
+    }
+
     /// Get balance of an address
     pub fn balance(env: Env, account: Address) -> i128 {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        env.storage()
-            .persistent()
-            .get(&DataKey::Balance(account))
-            .unwrap_or(0)
+        Persistent::get(&env, &DataKey::Balance(account), BALANCE_TTL).unwrap_or(0)
     }
 
     /// Get total supply
@@ -122,116 +291,87 @@ impl GovernanceTokenContract {
     }
 
     /// Transfer tokens
-    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) -> Result<(), TokenError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         from.require_auth();
 
         if amount <= 0 {
-            panic!("invalid amount");
-        }
-
-        let from_balance: i128 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Balance(from.clone()))
-            .unwrap_or(0);
-
-        if from_balance < amount {
-            panic!("insufficient balance");
+            return Err(TokenError::InvalidAmount);
         }
+        Self::_require_not_frozen(&env, &from)?;
 
-        let _ttl_key = DataKey::Balance(from.clone());
-        env.storage()
-            .persistent()
-            .set(&_ttl_key, &(from_balance - amount));
-        env.storage()
-            .persistent()
-            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
-
-        let to_balance: i128 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Balance(to.clone()))
-            .unwrap_or(0);
-        let _ttl_key = DataKey::Balance(to.clone());
-        env.storage()
-            .persistent()
-            .set(&_ttl_key, &(to_balance + amount));
-        env.storage()
-            .persistent()
-            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_move_balance(&env, &from, &to, amount)?;
 
-        env.events().publish(
-            (symbol_short!("transfer"),),
-            (from, to, amount),
-        );
+        events::token::transfer(&env, from, to, amount);
+        Ok(())
     }
 
     /// Transfer from (requires prior approval)
-    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), TokenError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         spender.require_auth();
 
-        let allowance: i128 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Allowance(from.clone(), spender.clone()))
-            .unwrap_or(0);
-
-        if allowance < amount {
-            panic!("insufficient allowance");
+        if amount <= 0 {
+            return Err(TokenError::InvalidAmount);
         }
+        Self::_require_not_frozen(&env, &from)?;
 
-        let from_balance: i128 = env
+        let allowance: i128 = env
             .storage()
             .persistent()
-            .get(&DataKey::Balance(from.clone()))
+            .get(&DataKey::Allowance(from.clone(), spender.clone()))
             .unwrap_or(0);
+        let new_allowance = allowance
+            .checked_sub(amount)
+            .filter(|a| *a >= 0)
+            .ok_or(TokenError::InsufficientAllowance)?;
 
-        if from_balance < amount {
-            panic!("insufficient balance");
-        }
+        Self::_move_balance(&env, &from, &to, amount)?;
 
-        let _ttl_key = DataKey::Allowance(from.clone(), spender);
+        let _ttl_key = DataKey::Allowance(from.clone(), spender.clone());
         env.storage()
             .persistent()
-            .set(&_ttl_key, &(allowance - amount));
-        env.storage()
-            .persistent()
-            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
-        let _ttl_key = DataKey::Balance(from.clone());
-        env.storage()
-            .persistent()
-            .set(&_ttl_key, &(from_balance - amount));
+            .set(&_ttl_key, &new_allowance);
         env.storage()
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        let to_balance: i128 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Balance(to.clone()))
-            .unwrap_or(0);
-        let _ttl_key = DataKey::Balance(to.clone());
-        env.storage()
-            .persistent()
-            .set(&_ttl_key, &(to_balance + amount));
-        env.storage()
-            .persistent()
-            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        events::token::transfer(&env, from.clone(), to.clone(), amount);
+        events::publish(&env, EVENT_TAG, TransferFrom { spender, from, to, amount });
+        Ok(())
     }
 
     /// Approve token spending
-    pub fn approve(env: Env, owner: Address, spender: Address, amount: i128, expiry: u32) {
+    pub fn approve(
+        env: Env,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+        expiry: u32,
+    ) -> Result<(), TokenError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         owner.require_auth();
-        let _ttl_key = DataKey::Allowance(owner, spender);
+
+        if amount < 0 {
+            return Err(TokenError::InvalidAmount);
+        }
+
+        let _ttl_key = DataKey::Allowance(owner.clone(), spender.clone());
         env.storage()
             .persistent()
             .set(&_ttl_key, &amount);
         env.storage()
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::token::approve(&env, owner, spender, amount, expiry);
+        Ok(())
     }
 
     /// Get allowance
@@ -244,12 +384,12 @@ impl GovernanceTokenContract {
     }
 
     /// Mint new tokens (admin only)
-    pub fn mint(env: Env, admin: Address, recipient: Address, amount: i128) {
+    pub fn mint(env: Env, admin: Address, recipient: Address, amount: i128) -> Result<(), TokenError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        admin.require_auth();
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if admin != stored_admin {
-            panic!("unauthorized");
+        Self::_require_admin(&env, &admin)?;
+
+        if amount <= 0 {
+            return Err(TokenError::InvalidAmount);
         }
 
         let current_supply: i128 = env
@@ -257,111 +397,341 @@ impl GovernanceTokenContract {
             .instance()
             .get(&DataKey::TotalSupply)
             .unwrap_or(0);
+        let new_supply = current_supply
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
 
-        if current_supply + amount > MAX_SUPPLY {
-            panic!("exceeds max supply");
+        if new_supply > MAX_SUPPLY {
+            return Err(TokenError::ExceedsMaxSupply);
         }
 
-        let balance: i128 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Balance(recipient.clone()))
-            .unwrap_or(0);
-        let _ttl_key = DataKey::Balance(recipient);
-        env.storage()
-            .persistent()
-            .set(&_ttl_key, &(balance + amount));
-        env.storage()
-            .persistent()
-            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        let balance = Self::_balance(&env, &recipient);
+        let new_balance = balance.checked_add(amount).ok_or(TokenError::Overflow)?;
+        Self::_set_balance(&env, &recipient, new_balance)?;
         env.storage()
             .instance()
-            .set(&DataKey::TotalSupply, &(current_supply + amount));
+            .set(&DataKey::TotalSupply, &new_supply);
+
+        events::token::mint(&env, admin, recipient, amount);
+        Ok(())
     }
 
     /// Burn tokens
-    pub fn burn(env: Env, from: Address, amount: i128) {
+    pub fn burn(env: Env, from: Address, amount: i128) -> Result<(), TokenError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         from.require_auth();
 
-        let balance: i128 = env
-            .storage()
+        if amount <= 0 {
+            return Err(TokenError::InvalidAmount);
+        }
+        Self::_require_not_frozen(&env, &from)?;
+
+        Self::_burn_balance(&env, &from, amount)?;
+
+        events::token::burn(&env, from, amount);
+        Ok(())
+    }
+
+    /// Freeze an account, blocking outgoing transfers (admin only)
+    pub fn freeze(env: Env, admin: Address, account: Address) -> Result<(), TokenError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+
+        let _ttl_key = DataKey::Frozen(account.clone());
+        env.storage().persistent().set(&_ttl_key, &true);
+        env.storage()
             .persistent()
-            .get(&DataKey::Balance(from.clone()))
-            .unwrap_or(0);
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        if balance < amount {
-            panic!("insufficient balance");
-        }
+        events::publish(&env, EVENT_TAG, AccountFrozen { admin, account });
+        Ok(())
+    }
+
+    /// Unfreeze an account (admin only)
+    pub fn unfreeze(env: Env, admin: Address, account: Address) -> Result<(), TokenError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
 
-        let _ttl_key = DataKey::Balance(from);
         env.storage()
             .persistent()
-            .set(&_ttl_key, &(balance - amount));
+            .remove(&DataKey::Frozen(account.clone()));
+
+        events::publish(&env, EVENT_TAG, AccountUnfrozen { admin, account });
+        Ok(())
+    }
+
+    /// Check whether an account is frozen
+    pub fn is_frozen(env: Env, account: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         env.storage()
             .persistent()
-            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+            .get(&DataKey::Frozen(account))
+            .unwrap_or(false)
+    }
 
-        let supply: i128 = env
+    /// Claw back tokens from a frozen account, burning them (admin only)
+    pub fn clawback(env: Env, admin: Address, from: Address, amount: i128) -> Result<(), TokenError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+
+        if amount <= 0 {
+            return Err(TokenError::InvalidAmount);
+        }
+        if !env
             .storage()
-            .instance()
-            .get(&DataKey::TotalSupply)
-            .unwrap_or(0);
-        env.storage()
-            .instance()
-            .set(&DataKey::TotalSupply, &(supply - amount));
+            .persistent()
+            .get(&DataKey::Frozen(from.clone()))
+            .unwrap_or(false)
+        {
+            return Err(TokenError::AccountNotFrozen);
+        }
+
+        Self::_burn_balance(&env, &from, amount)?;
+
+        events::token::clawback(&env, admin, from, amount);
+        Ok(())
+    }
+
+    /// Delegate voting power. Rejected if it would delegate to oneself, close
+    /// a loop (`delegate_to`'s own chain of delegations leads back to
+    /// `delegator`) or make a chain longer than `MAX_DELEGATION_HOPS`, counting
+    /// both the chains already delegating into `delegator` and the one
+    /// leaving `delegate_to`.
+    pub fn delegate(env: Env, delegator: Address, delegate_to: Address) -> Result<(), TokenError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        delegator.require_auth();
+
+        if delegate_to == delegator {
+            return Err(TokenError::SelfDelegation);
+        }
+        if Self::_delegated_bps(&env, &delegator) > 0 {
+            return Err(TokenError::PartialDelegationsActive);
+        }
+        // The new link counts as one hop of the resulting chain, on top of
+        // the longest chain already ending at `delegator`
+        let max_hops = (MAX_DELEGATION_HOPS - 1)
+            .checked_sub(Self::_incoming_depth(&env, &delegator))
+            .ok_or(TokenError::DelegationChainTooLong)?;
+        Self::_resolve_delegate(&env, &delegate_to, &delegator, max_hops)?;
+
+        Self::_set_delegate(&env, &delegator, Some(delegate_to.clone()))?;
+
+        events::publish(&env, EVENT_TAG, Delegated { delegator, delegate: delegate_to });
+        Ok(())
+    }
+
+    /// Revoke delegation
+    pub fn revoke_delegation(env: Env, delegator: Address) -> Result<(), TokenError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        delegator.require_auth();
+        Self::_set_delegate(&env, &delegator, None)
     }
 
-    /// Delegate voting power
-    pub fn delegate(env: Env, delegator: Address, delegate_to: Address) {
+    /// Delegate a share of voting power (in basis points) to `delegate_to`.
+    /// Calling again for the same delegate replaces its share.
+    pub fn delegate_partial(
+        env: Env,
+        delegator: Address,
+        delegate_to: Address,
+        bps: u32,
+    ) -> Result<(), TokenError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         delegator.require_auth();
 
-        let delegation = Delegation {
+        if delegate_to == delegator {
+            return Err(TokenError::SelfDelegation);
+        }
+        if bps == 0 || bps > MAX_BPS {
+            return Err(TokenError::InvalidBps);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Delegation(delegator.clone()))
+        {
+            return Err(TokenError::AlreadyDelegated);
+        }
+
+        let key = DataKey::PartialDelegations(delegator.clone());
+        let current: Vec<PartialDelegation> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(&env));
+
+        let mut updated: Vec<PartialDelegation> = Vec::new(&env);
+        let mut total: u32 = bps;
+        let mut previous_bps: u32 = 0;
+        for d in current.iter() {
+            if d.delegate != delegate_to {
+                total = total.checked_add(d.bps).ok_or(TokenError::Overflow)?;
+                updated.push_back(d);
+            } else {
+                previous_bps = d.bps;
+            }
+        }
+        if total > MAX_BPS {
+            return Err(TokenError::DelegationExceedsLimit);
+        }
+        if updated.len() >= MAX_PARTIAL_DELEGATES {
+            return Err(TokenError::TooManyDelegates);
+        }
+        updated.push_back(PartialDelegation {
             delegate: delegate_to.clone(),
+            bps,
             delegated_at: env.ledger().timestamp(),
-        };
+        });
+
+        let balance = Self::_balance(&env, &delegator);
+        let kept_before = Self::_bps_share(balance, MAX_BPS - (total - bps + previous_bps))?;
+        let kept_after = Self::_bps_share(balance, MAX_BPS - total)?;
+        Self::_shift_represented(&env, &delegator, kept_after - kept_before)?;
+        let share_delta = Self::_bps_share(balance, bps)? - Self::_bps_share(balance, previous_bps)?;
+        Self::_shift_represented(&env, &delegate_to, share_delta)?;
 
-        let _ttl_key = DataKey::Delegation(delegator.clone());
+        env.storage().persistent().set(&key, &updated);
         env.storage()
             .persistent()
-            .set(&_ttl_key, &delegation);
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        let delegators_key = DataKey::Delegators(delegate_to.clone());
+        let mut delegators: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&delegators_key)
+            .unwrap_or(Vec::new(&env));
+        if !delegators.contains(&delegator) {
+            delegators.push_back(delegator.clone());
+            env.storage().persistent().set(&delegators_key, &delegators);
+        }
         env.storage()
             .persistent()
-            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+            .extend_ttl(&delegators_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("delegate"),),
-            (delegator, delegate_to),
-        );
+        events::publish(&env, EVENT_TAG, PartialDelegated { delegator, delegate: delegate_to, bps });
+        Ok(())
     }
 
-    /// Revoke delegation
-    pub fn revoke_delegation(env: Env, delegator: Address) {
+    /// Revoke a partial delegation to `delegate_to`
+    pub fn revoke_partial_delegation(
+        env: Env,
+        delegator: Address,
+        delegate_to: Address,
+    ) -> Result<(), TokenError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         delegator.require_auth();
-        env.storage()
-            .persistent()
-            .remove(&DataKey::Delegation(delegator));
-    }
 
-    /// Get voting power (0 if delegated)
-    pub fn voting_power(env: Env, voter: Address) -> i128 {
-        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        let delegation = env
+        let key = DataKey::PartialDelegations(delegator.clone());
+        let current: Vec<PartialDelegation> = env
             .storage()
             .persistent()
-            .get::<DataKey, Delegation>(&DataKey::Delegation(voter.clone()));
+            .get(&key)
+            .unwrap_or(Vec::new(&env));
+
+        let mut updated: Vec<PartialDelegation> = Vec::new(&env);
+        let mut removed_bps: Option<u32> = None;
+        for d in current.iter() {
+            if d.delegate != delegate_to {
+                updated.push_back(d);
+            } else {
+                removed_bps = Some(d.bps);
+            }
+        }
+        let removed_bps = removed_bps.ok_or(TokenError::DelegationNotFound)?;
 
-        if delegation.is_some() {
-            // Delegated - no direct voting power
-            0
+        // Hand the share back to the delegator
+        let balance = Self::_balance(&env, &delegator);
+        let delegated_before = Self::_delegated_bps(&env, &delegator);
+        let kept_before = Self::_bps_share(balance, MAX_BPS - delegated_before)?;
+        let kept_after = Self::_bps_share(balance, MAX_BPS - (delegated_before - removed_bps))?;
+        Self::_shift_represented(&env, &delegator, kept_after - kept_before)?;
+        Self::_shift_represented(&env, &delegate_to, -Self::_bps_share(balance, removed_bps)?)?;
+
+        if updated.is_empty() {
+            env.storage().persistent().remove(&key);
         } else {
-            env.storage()
-                .persistent()
-                .get(&DataKey::Balance(voter))
-                .unwrap_or(0)
+            env.storage().persistent().set(&key, &updated);
+        }
+
+        let delegators_key = DataKey::Delegators(delegate_to.clone());
+        let delegators: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&delegators_key)
+            .unwrap_or(Vec::new(&env));
+        let mut remaining: Vec<Address> = Vec::new(&env);
+        for d in delegators.iter() {
+            if d != delegator {
+                remaining.push_back(d);
+            }
         }
+        if remaining.is_empty() {
+            env.storage().persistent().remove(&delegators_key);
+        } else {
+            env.storage().persistent().set(&delegators_key, &remaining);
+        }
+
+        events::publish(&env, EVENT_TAG, PartialUndelegated { delegator, delegate: delegate_to });
+        Ok(())
+    }
+
+    /// Get voting power: the undelegated share of the voter's own balance
+    /// plus everything delegated to them, partially or through a chain of
+    /// full delegations (0 if they have fully delegated themselves)
+    pub fn voting_power(env: Env, voter: Address) -> Result<i128, TokenError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if Self::_delegation(&env, &voter).is_some() {
+            return Ok(0);
+        }
+        Ok(Self::_represented(&env, &voter))
+    }
+
+    /// Voting power as of the end of `ledger`, on the same terms as
+    /// `voting_power`
+    pub fn get_past_votes(env: Env, account: Address, ledger: u32) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if Self::_past_delegate(&env, &account, ledger).is_some() {
+            return 0;
+        }
+        let current = Self::_represented(&env, &account);
+        Self::_past_value(&env, &DataKey::RepresentedHistory(account), ledger, current)
+    }
+
+    /// Weight flowing through the account as of the end of `ledger`: the
+    /// undelegated share of its own balance plus everything delegated to it,
+    /// counted whether or not it passed the total on to a delegate of its own
+    pub fn get_past_represented(env: Env, account: Address, ledger: u32) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let current = Self::_represented(&env, &account);
+        Self::_past_value(&env, &DataKey::RepresentedHistory(account), ledger, current)
+    }
+
+    /// Balance as of the end of `ledger`
+    pub fn get_past_balance(env: Env, account: Address, ledger: u32) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let current = Self::_balance(&env, &account);
+        Self::_past_value(&env, &DataKey::BalanceHistory(account), ledger, current)
+    }
+
+    /// Full delegate as of the end of `ledger`, if the account had one
+    pub fn get_past_delegate(env: Env, account: Address, ledger: u32) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_past_delegate(&env, &account, ledger)
+    }
+
+    /// Get partial delegations made by a delegator
+    pub fn get_partial_delegations(env: Env, delegator: Address) -> Vec<PartialDelegation> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::PartialDelegations(delegator))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get total basis points a delegator has partially delegated
+    pub fn get_delegated_bps(env: Env, delegator: Address) -> u32 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_delegated_bps(&env, &delegator)
     }
 
     /// Get delegation info
@@ -371,6 +741,385 @@ impl GovernanceTokenContract {
             .persistent()
             .get(&DataKey::Delegation(delegator))
     }
+
+    /// Follow full delegations from `account` to the address that ends up
+    /// holding its vote (`account` itself if it hasn't delegated)
+    pub fn resolve_delegate(env: Env, account: Address) -> Result<Address, TokenError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_resolve_delegate(&env, &account, &account, MAX_DELEGATION_HOPS)
+    }
+
+    /// Carry a full delegation made before v2 over to the delegate's votes.
+    /// Until then it counts as not delegated. Anyone may call; returns false
+    /// if the account has no such delegation. An account's next balance
+    /// change carries it over as well.
+    pub fn sync_delegation(env: Env, account: Address) -> Result<bool, TokenError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_sync_delegation(&env, &account)
+    }
+
+    // ============================================================
+    // TTL Maintenance
+    // ============================================================
+
+    /// Keep an account's balance and delegation entries from being archived.
+    /// Anyone may call; returns false if the account holds no balance entry.
+    pub fn extend_ttl_account(env: Env, account: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Persistent::extend(&env, &DataKey::Delegation(account.clone()), BALANCE_TTL);
+        Persistent::extend(&env, &DataKey::PartialDelegations(account.clone()), BALANCE_TTL);
+        Persistent::extend(&env, &DataKey::Delegators(account.clone()), BALANCE_TTL);
+        Persistent::extend(&env, &DataKey::Represented(account.clone()), BALANCE_TTL);
+        Persistent::extend(&env, &DataKey::BalanceHistory(account.clone()), HISTORY_TTL);
+        Persistent::extend(&env, &DataKey::RepresentedHistory(account.clone()), HISTORY_TTL);
+        Persistent::extend(&env, &DataKey::DelegateHistory(account.clone()), HISTORY_TTL);
+        Persistent::extend(&env, &DataKey::Balance(account), BALANCE_TTL)
+    }
+
+    // ============================================================
+    // Internal Helpers
+    // ============================================================
+
+    fn _require_admin(env: &Env, admin: &Address) -> Result<(), TokenError> {
+        Ok(Ownable::require_owner(env, &DataKey::Admin, admin)?)
+    }
+
+    fn _require_not_frozen(env: &Env, account: &Address) -> Result<(), TokenError> {
+        if env
+            .storage()
+            .persistent()
+            .get(&DataKey::Frozen(account.clone()))
+            .unwrap_or(false)
+        {
+            return Err(TokenError::AccountFrozen);
+        }
+        Ok(())
+    }
+
+    fn _balance(env: &Env, account: &Address) -> i128 {
+        Persistent::get(env, &DataKey::Balance(account.clone()), BALANCE_TTL).unwrap_or(0)
+    }
+
+    /// Write a balance, checkpoint it and move the change in voting weight
+    /// to the account and its partial delegates in proportion to their shares
+    fn _set_balance(env: &Env, account: &Address, amount: i128) -> Result<(), TokenError> {
+        Self::_sync_delegation(env, account)?;
+        let previous = Self::_balance(env, account);
+
+        let partials: Vec<PartialDelegation> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PartialDelegations(account.clone()))
+            .unwrap_or(Vec::new(env));
+        let mut kept_bps = MAX_BPS;
+        for d in partials.iter() {
+            kept_bps = kept_bps.saturating_sub(d.bps);
+            let delta = Self::_bps_share(amount, d.bps)? - Self::_bps_share(previous, d.bps)?;
+            Self::_shift_represented(env, &d.delegate, delta)?;
+        }
+        // Shift before storing the balance: an account's weight is derived
+        // from its balance until first written
+        let delta = Self::_bps_share(amount, kept_bps)? - Self::_bps_share(previous, kept_bps)?;
+        Self::_shift_represented(env, account, delta)?;
+
+        let history_key = DataKey::BalanceHistory(account.clone());
+        Self::_seed_history(env, &history_key, previous);
+        Persistent::set(env, &DataKey::Balance(account.clone()), &amount, BALANCE_TTL);
+        Self::_checkpoint(env, &history_key, amount);
+        Ok(())
+    }
+
+    fn _move_balance(env: &Env, from: &Address, to: &Address, amount: i128) -> Result<(), TokenError> {
+        let from_balance = Self::_balance(env, from)
+            .checked_sub(amount)
+            .filter(|b| *b >= 0)
+            .ok_or(TokenError::InsufficientBalance)?;
+        Self::_set_balance(env, from, from_balance)?;
+
+        let to_balance = Self::_balance(env, to)
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+        Self::_set_balance(env, to, to_balance)
+    }
+
+    fn _burn_balance(env: &Env, from: &Address, amount: i128) -> Result<(), TokenError> {
+        let balance = Self::_balance(env, from)
+            .checked_sub(amount)
+            .filter(|b| *b >= 0)
+            .ok_or(TokenError::InsufficientBalance)?;
+
+        let supply: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .unwrap_or(0);
+        let new_supply = supply.checked_sub(amount).ok_or(TokenError::Overflow)?;
+
+        Self::_set_balance(env, from, balance)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalSupply, &new_supply);
+        Ok(())
+    }
+
+    /// Stored weight, or for an account never written since v2 (every v1
+    /// holder) the undelegated share of its balance
+    fn _represented(env: &Env, account: &Address) -> i128 {
+        Persistent::get(env, &DataKey::Represented(account.clone()), BALANCE_TTL).unwrap_or_else(|| {
+            let kept_bps = MAX_BPS.saturating_sub(Self::_delegated_bps(env, account));
+            Self::_bps_share(Self::_balance(env, account), kept_bps).unwrap_or(0)
+        })
+    }
+
+    /// The account's full delegation, unless it dates from v1 and hasn't been
+    /// synced: every delegation made since v2 has a delegate history
+    fn _delegation(env: &Env, account: &Address) -> Option<Delegation> {
+        if !env.storage().persistent().has(&DataKey::DelegateHistory(account.clone())) {
+            return None;
+        }
+        env.storage()
+            .persistent()
+            .get(&DataKey::Delegation(account.clone()))
+    }
+
+    /// Move the weight of a v1 delegation onto its delegate's chain
+    fn _sync_delegation(env: &Env, account: &Address) -> Result<bool, TokenError> {
+        if env.storage().persistent().has(&DataKey::DelegateHistory(account.clone())) {
+            return Ok(false);
+        }
+        let Some(delegation) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Delegation>(&DataKey::Delegation(account.clone()))
+        else {
+            return Ok(false);
+        };
+        Self::_shift_represented(env, &delegation.delegate, Self::_represented(env, account))?;
+        Self::_shift_incoming(env, account, &delegation.delegate, true);
+        let mut history: Vec<DelegateCheckpoint> = Vec::new(env);
+        history.push_back(DelegateCheckpoint { ledger: env.ledger().sequence(), delegate: Some(delegation.delegate) });
+        Persistent::set(env, &DataKey::DelegateHistory(account.clone()), &history, HISTORY_TTL);
+        Ok(true)
+    }
+
+    /// Add `delta` to the weight represented by `start` and by every account
+    /// down its chain of full delegations, all of which pass it on
+    fn _shift_represented(env: &Env, start: &Address, delta: i128) -> Result<(), TokenError> {
+        if delta == 0 {
+            return Ok(());
+        }
+        let mut current = start.clone();
+        loop {
+            let previous = Self::_represented(env, &current);
+            let represented = previous.checked_add(delta).ok_or(TokenError::Overflow)?;
+            let history_key = DataKey::RepresentedHistory(current.clone());
+            Self::_seed_history(env, &history_key, previous);
+            Persistent::set(env, &DataKey::Represented(current.clone()), &represented, BALANCE_TTL);
+            Self::_checkpoint(env, &history_key, represented);
+
+            // Delegation links never form a cycle, so the walk ends
+            match Self::_delegation(env, &current) {
+                Some(d) => current = d.delegate,
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Point `delegator`'s full delegation at `delegate` (or clear it), moving
+    /// the weight it represents off the old chain and onto the new one
+    fn _set_delegate(env: &Env, delegator: &Address, delegate: Option<Address>) -> Result<(), TokenError> {
+        let key = DataKey::Delegation(delegator.clone());
+        let weight = Self::_represented(env, delegator);
+        if let Some(previous) = Self::_delegation(env, delegator) {
+            Self::_shift_represented(env, &previous.delegate, -weight)?;
+            Self::_shift_incoming(env, delegator, &previous.delegate, false);
+        }
+
+        match delegate.clone() {
+            Some(to) => {
+                let delegation = Delegation {
+                    delegate: to.clone(),
+                    delegated_at: env.ledger().timestamp(),
+                };
+                env.storage().persistent().set(&key, &delegation);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+                Self::_shift_represented(env, &to, weight)?;
+                Self::_shift_incoming(env, delegator, &to, true);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+
+        let history_key = DataKey::DelegateHistory(delegator.clone());
+        let mut history: Vec<DelegateCheckpoint> =
+            Persistent::get(env, &history_key, HISTORY_TTL).unwrap_or(Vec::new(env));
+        let ledger = env.ledger().sequence();
+        if history.last().map(|c| c.ledger) == Some(ledger) {
+            history.pop_back();
+        }
+        history.push_back(DelegateCheckpoint { ledger, delegate });
+        Persistent::set(env, &history_key, &history, HISTORY_TTL);
+        Ok(())
+    }
+
+    /// Chains of full delegations ending at `account`; entry `i` counts
+    /// those `i + 1` hops long
+    fn _incoming(env: &Env, account: &Address) -> Vec<u32> {
+        Persistent::get(env, &DataKey::IncomingChains(account.clone()), BALANCE_TTL)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Length of the longest chain of full delegations ending at `account`
+    fn _incoming_depth(env: &Env, account: &Address) -> u32 {
+        Self::_incoming(env, account).len()
+    }
+
+    /// Add (or take away) the chains running over `from`'s delegation to `to`
+    /// at `to` and at every account down its chain, one hop longer each step
+    fn _shift_incoming(env: &Env, from: &Address, to: &Address, added: bool) {
+        // `from` on its own, then every chain into `from` extended by the link
+        let mut arriving: Vec<u32> = Vec::new(env);
+        arriving.push_back(1);
+        arriving.append(&Self::_incoming(env, from));
+
+        let mut current = to.clone();
+        let mut offset = 0;
+        while offset < MAX_DELEGATION_HOPS {
+            let mut counts = Self::_incoming(env, &current);
+            for (i, count) in arriving.iter().enumerate() {
+                let index = i as u32 + offset;
+                if index >= MAX_DELEGATION_HOPS {
+                    break;
+                }
+                while counts.len() <= index {
+                    counts.push_back(0);
+                }
+                let existing = counts.get_unchecked(index);
+                let updated = if added {
+                    existing.saturating_add(count)
+                } else {
+                    existing.saturating_sub(count)
+                };
+                counts.set(index, updated);
+            }
+            // Trailing zeros are dropped so the length is the deepest chain
+            while counts.last() == Some(0) {
+                counts.pop_back();
+            }
+            let key = DataKey::IncomingChains(current.clone());
+            if counts.is_empty() {
+                env.storage().persistent().remove(&key);
+            } else {
+                Persistent::set(env, &key, &counts, BALANCE_TTL);
+            }
+
+            match Self::_delegation(env, &current) {
+                Some(d) => current = d.delegate,
+                None => return,
+            }
+            offset += 1;
+        }
+    }
+
+    /// Record `value` as of the current ledger, replacing an earlier
+    /// checkpoint from the same ledger
+    fn _checkpoint(env: &Env, key: &DataKey, value: i128) {
+        let mut history: Vec<Checkpoint> =
+            Persistent::get(env, key, HISTORY_TTL).unwrap_or(Vec::new(env));
+        let ledger = env.ledger().sequence();
+        if history.last().map(|c| c.ledger) == Some(ledger) {
+            history.pop_back();
+        }
+        history.push_back(Checkpoint { ledger, value });
+        Persistent::set(env, key, &history, HISTORY_TTL);
+    }
+
+    /// Give a history that was never written (a v1 account) a first
+    /// checkpoint holding `value` from before any ledger of interest
+    fn _seed_history(env: &Env, key: &DataKey, value: i128) {
+        if value != 0 && !env.storage().persistent().has(key) {
+            let mut history: Vec<Checkpoint> = Vec::new(env);
+            history.push_back(Checkpoint { ledger: 0, value });
+            Persistent::set(env, key, &history, HISTORY_TTL);
+        }
+    }
+
+    /// Index of the last checkpoint at or before `ledger`, given the
+    /// ledgers of a history in ascending order
+    fn _checkpoint_index(len: u32, ledger: u32, ledger_at: impl Fn(u32) -> u32) -> Option<u32> {
+        let (mut low, mut high) = (0u32, len);
+        while low < high {
+            let mid = (low + high) / 2;
+            if ledger_at(mid) <= ledger {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low.checked_sub(1)
+    }
+
+    /// The checkpointed value as of `ledger`; an account with no history
+    /// hasn't changed since v1, so its `current` value applies
+    fn _past_value(env: &Env, key: &DataKey, ledger: u32, current: i128) -> i128 {
+        let Some(history) = Persistent::get::<DataKey, Vec<Checkpoint>>(env, key, HISTORY_TTL) else {
+            return current;
+        };
+        Self::_checkpoint_index(history.len(), ledger, |i| history.get_unchecked(i).ledger)
+            .map(|i| history.get_unchecked(i).value)
+            .unwrap_or(0)
+    }
+
+    fn _past_delegate(env: &Env, account: &Address, ledger: u32) -> Option<Address> {
+        let history: Vec<DelegateCheckpoint> =
+            Persistent::get(env, &DataKey::DelegateHistory(account.clone()), HISTORY_TTL)
+                .unwrap_or(Vec::new(env));
+        Self::_checkpoint_index(history.len(), ledger, |i| history.get_unchecked(i).ledger)
+            .and_then(|i| history.get_unchecked(i).delegate)
+    }
+
+    /// Walk full delegations from `start` and return where the chain ends.
+    /// Fails if the chain reaches `origin` or takes more than `max_hops` hops.
+    fn _resolve_delegate(env: &Env, start: &Address, origin: &Address, max_hops: u32) -> Result<Address, TokenError> {
+        let mut current = start.clone();
+        let mut hops = 0;
+        while let Some(d) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Delegation>(&DataKey::Delegation(current.clone()))
+        {
+            if d.delegate == *origin {
+                return Err(TokenError::DelegationCycle);
+            }
+            hops += 1;
+            if hops > max_hops {
+                return Err(TokenError::DelegationChainTooLong);
+            }
+            current = d.delegate;
+        }
+        Ok(current)
+    }
+
+    fn _bps_share(amount: i128, bps: u32) -> Result<i128, TokenError> {
+        amount
+            .checked_mul(bps as i128)
+            .map(|v| v / MAX_BPS as i128)
+            .ok_or(TokenError::Overflow)
+    }
+
+    fn _delegated_bps(env: &Env, delegator: &Address) -> u32 {
+        let partials: Vec<PartialDelegation> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PartialDelegations(delegator.clone()))
+            .unwrap_or(Vec::new(env));
+        let mut total: u32 = 0;
+        for d in partials.iter() {
+            total = total.saturating_add(d.bps);
+        }
+        total
+    }
 }
 
 mod test;
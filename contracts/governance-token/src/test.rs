@@ -1,6 +1,6 @@
 #![cfg(test)]
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, vec, String};
+use soroban_sdk::{testutils::{storage::Persistent as _, Address as _, Events, Ledger}, Address, Env, IntoVal, Symbol, vec, String};
 
 #[test]
 fn test_initialize() {
@@ -16,7 +16,7 @@ fn test_initialize() {
 }
 
 #[test]
-#[should_panic(expected = "already initialized")]
+#[should_panic(expected = "Error(Contract, #101)")]
 fn test_initialize_twice() {
     let env = Env::default();
     env.mock_all_auths();
@@ -43,3 +43,435 @@ fn test_initialize_non_admin_fails() {
     // This should panic because admin didn't authorize it and we haven't mocked it
     client.initialize(&admin);
 }
+
+#[test]
+fn test_partial_delegation_splits_voting_power() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.mint(&admin, &holder, &10_000);
+
+    client.delegate_partial(&holder, &alice, &2_500);
+    client.delegate_partial(&holder, &bob, &5_000);
+
+    assert_eq!(client.get_delegated_bps(&holder), 7_500);
+    assert_eq!(client.voting_power(&holder), 2_500);
+    assert_eq!(client.voting_power(&alice), 2_500);
+    assert_eq!(client.voting_power(&bob), 5_000);
+
+    client.revoke_partial_delegation(&holder, &bob);
+    assert_eq!(client.voting_power(&holder), 7_500);
+    assert_eq!(client.voting_power(&bob), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_partial_delegation_over_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.delegate_partial(&holder, &Address::generate(&env), &6_000);
+    client.delegate_partial(&holder, &Address::generate(&env), &5_000);
+}
+
+#[test]
+fn test_clawback_frozen_account() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.mint(&admin, &holder, &1_000);
+    client.freeze(&admin, &holder);
+    assert!(client.is_frozen(&holder));
+
+    client.clawback(&admin, &holder, &400);
+    assert_eq!(client.balance(&holder), 600);
+    assert_eq!(client.total_supply(), 600);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #110)")]
+fn test_clawback_requires_freeze() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.mint(&admin, &holder, &1_000);
+    client.clawback(&admin, &holder, &400);
+}
+
+#[test]
+fn test_invalid_amounts_return_typed_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.mint(&admin, &holder, &1_000);
+
+    assert_eq!(client.try_burn(&holder, &-5), Err(Ok(TokenError::InvalidAmount)));
+    assert_eq!(
+        client.try_transfer_from(&spender, &holder, &spender, &-5),
+        Err(Ok(TokenError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_mint(&admin, &holder, &i128::MAX),
+        Err(Ok(TokenError::Overflow))
+    );
+    assert_eq!(
+        client.try_transfer(&holder, &spender, &1_001),
+        Err(Ok(TokenError::InsufficientBalance))
+    );
+    assert_eq!(client.balance(&holder), 1_000);
+}
+
+#[test]
+fn test_supply_and_allowance_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let topics = |action: &str| (Symbol::new(&env, "token"), Symbol::new(&env, action), 1u32).into_val(&env);
+
+    // SEP-41 movements keep the standard (action, addresses...) topics
+    client.mint(&admin, &holder, &1_000);
+    let (_, ev_topics, data) = env.events().all().last().unwrap();
+    assert_eq!(ev_topics, (Symbol::new(&env, "mint"), admin.clone(), holder.clone()).into_val(&env));
+    let amount: i128 = data.into_val(&env);
+    assert_eq!(amount, 1_000);
+
+    client.approve(&holder, &spender, &300, &1_000);
+    let (_, ev_topics, data) = env.events().all().last().unwrap();
+    assert_eq!(ev_topics, (Symbol::new(&env, "approve"), holder.clone(), spender.clone()).into_val(&env));
+    let (amount, expiry): (i128, u32) = data.into_val(&env);
+    assert_eq!((amount, expiry), (300, 1_000));
+
+    client.transfer_from(&spender, &holder, &spender, &200);
+    let events = env.events().all();
+    let (_, ev_topics, data) = events.get(events.len() - 2).unwrap();
+    assert_eq!(ev_topics, (Symbol::new(&env, "transfer"), holder.clone(), spender.clone()).into_val(&env));
+    let amount: i128 = data.into_val(&env);
+    assert_eq!(amount, 200);
+    let (_, ev_topics, data) = events.last().unwrap();
+    assert_eq!(ev_topics, topics("transfer_from"));
+    let ev: TransferFrom = data.into_val(&env);
+    assert_eq!((ev.spender, ev.amount), (spender.clone(), 200));
+
+    client.burn(&holder, &100);
+    let (_, ev_topics, _) = env.events().all().last().unwrap();
+    assert_eq!(ev_topics, (Symbol::new(&env, "burn"), holder.clone()).into_val(&env));
+}
+
+#[test]
+fn test_update_metadata() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let name = String::from_str(&env, "Pulsar Governance");
+    let symbol = String::from_str(&env, "PLSR");
+    assert_eq!(
+        client.try_update_metadata(&stranger, &name, &symbol),
+        Err(Ok(TokenError::Unauthorized))
+    );
+
+    client.update_metadata(&admin, &name, &symbol);
+    assert_eq!(client.name(), name);
+    assert_eq!(client.symbol(), symbol);
+    assert_eq!(client.decimals(), 7);
+}
+
+#[test]
+fn test_versioning() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.initialize(&admin);
+
+    assert_eq!(client.get_version().version, 2);
+    assert_eq!(client.try_migrate(&2), Err(Ok(TokenError::AlreadyMigrated)));
+    assert_eq!(client.try_set_upgrade_authority(&stranger, &stranger), Err(Ok(TokenError::Unauthorized)));
+
+    // Deployments from before versioning count as v1
+    env.as_contract(&contract_id, || {
+        env.storage().instance().remove(&DataKey::ContractVersion);
+    });
+    assert_eq!(client.get_version().version, 1);
+    assert_eq!(client.try_migrate(&0), Err(Ok(TokenError::VersionMismatch)));
+    assert_eq!(client.migrate(&1), 2);
+}
+
+#[test]
+fn test_upgraded_v1_balances_keep_their_votes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    client.initialize(&admin);
+
+    // v1 kept only balances and bare delegations: Carol had delegated to Bob
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Balance(alice.clone()), &100i128);
+        storage.set(&DataKey::Balance(bob.clone()), &50i128);
+        storage.set(&DataKey::Balance(carol.clone()), &30i128);
+        storage.set(&DataKey::Delegation(carol.clone()), &Delegation { delegate: bob.clone(), delegated_at: 0 });
+        env.storage().instance().set(&DataKey::TotalSupply, &180i128);
+        env.storage().instance().remove(&DataKey::ContractVersion);
+    });
+    assert_eq!(client.migrate(&1), 2);
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    assert_eq!(client.voting_power(&alice), 100);
+    assert_eq!(client.get_past_votes(&alice, &99), 100);
+    assert_eq!(client.get_past_balance(&alice, &99), 100);
+
+    // The first transfer after the upgrade starts from the v1 balance
+    client.transfer(&alice, &bob, &10);
+    assert_eq!(client.voting_power(&alice), 90);
+    assert_eq!(client.voting_power(&bob), 60);
+    assert_eq!(client.get_past_votes(&alice, &99), 100);
+    assert_eq!(client.get_past_balance(&alice, &99), 100);
+    assert_eq!(client.get_past_votes(&alice, &100), 90);
+
+    // Carol's v1 delegation counts once it is synced
+    assert_eq!(client.voting_power(&carol), 30);
+    assert!(client.sync_delegation(&carol));
+    assert!(!client.sync_delegation(&carol));
+    assert_eq!(client.voting_power(&carol), 0);
+    assert_eq!(client.voting_power(&bob), 90);
+    client.transfer(&carol, &alice, &5);
+    assert_eq!(client.voting_power(&bob), 85);
+    client.revoke_delegation(&carol);
+    assert_eq!(client.voting_power(&carol), 25);
+    assert_eq!(client.voting_power(&bob), 60);
+}
+
+#[test]
+fn test_extend_ttl_account() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+    client.initialize(&admin);
+    client.mint(&admin, &holder, &1_000);
+
+    let ttl = || env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&DataKey::Balance(holder.clone())));
+    assert_eq!(ttl(), BALANCE_TTL.extend_to);
+
+    // A dormant balance ages below its threshold while the instance stays live
+    for _ in 0..3 {
+        env.ledger().with_mut(|li| li.sequence_number += 80_000);
+        client.decimals();
+    }
+    assert!(ttl() < BALANCE_TTL.threshold);
+
+    assert!(client.extend_ttl_account(&holder));
+    assert_eq!(ttl(), BALANCE_TTL.extend_to);
+    assert!(!client.extend_ttl_account(&Address::generate(&env)));
+}
+
+#[test]
+fn test_delegation_loops_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    client.initialize(&admin);
+
+    assert_eq!(client.try_delegate(&a, &a), Err(Ok(TokenError::SelfDelegation)));
+    assert_eq!(client.try_delegate_partial(&a, &a, &5_000), Err(Ok(TokenError::SelfDelegation)));
+
+    // A -> B -> C resolves to C; closing the loop from either end fails
+    client.delegate(&a, &b);
+    client.delegate(&b, &c);
+    assert_eq!(client.resolve_delegate(&a), c);
+    assert_eq!(client.resolve_delegate(&c), c);
+    assert_eq!(client.try_delegate(&b, &a), Err(Ok(TokenError::DelegationCycle)));
+    assert_eq!(client.try_delegate(&c, &a), Err(Ok(TokenError::DelegationCycle)));
+
+    // Redelegating elsewhere is fine once the loop is gone
+    client.revoke_delegation(&a);
+    client.delegate(&c, &a);
+    assert_eq!(client.resolve_delegate(&b), a);
+}
+
+#[test]
+fn test_delegation_chain_bounded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    // A chain of MAX_DELEGATION_HOPS delegations ending at `head` is the longest allowed
+    let head = Address::generate(&env);
+    let mut next = head.clone();
+    for _ in 0..MAX_DELEGATION_HOPS {
+        let account = Address::generate(&env);
+        client.delegate(&account, &next);
+        next = account;
+    }
+    assert_eq!(client.resolve_delegate(&next), head);
+    assert_eq!(
+        client.try_delegate(&Address::generate(&env), &next),
+        Err(Ok(TokenError::DelegationChainTooLong))
+    );
+}
+
+#[test]
+fn test_joining_two_chains_counts_both() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    // Two separate chains of 7 delegations; returns (first, last) account
+    let chain = || {
+        let first = Address::generate(&env);
+        let mut last = first.clone();
+        for _ in 0..7 {
+            let next = Address::generate(&env);
+            client.delegate(&last, &next);
+            last = next;
+        }
+        (first, last)
+    };
+    let (a_first, a_last) = chain();
+    let (b_first, b_last) = chain();
+
+    // Linking the end of one to the start of the other would make 15 hops
+    assert_eq!(
+        client.try_delegate(&a_last, &b_first),
+        Err(Ok(TokenError::DelegationChainTooLong))
+    );
+    assert_eq!(client.resolve_delegate(&a_first), a_last);
+    assert_eq!(client.resolve_delegate(&b_first), b_last);
+
+    // One more hop still fits onto a chain of 7, from either end
+    client.delegate(&a_last, &Address::generate(&env));
+    let tail = Address::generate(&env);
+    client.delegate(&tail, &b_first);
+
+    // Shortening a chain frees room at its end again
+    assert_eq!(
+        client.try_delegate(&b_last, &Address::generate(&env)),
+        Err(Ok(TokenError::DelegationChainTooLong))
+    );
+    client.revoke_delegation(&tail);
+    client.delegate(&b_last, &Address::generate(&env));
+}
+
+#[test]
+fn test_past_votes_follow_checkpoints() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, GovernanceTokenContract);
+    let client = GovernanceTokenContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.initialize(&admin);
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    let start = env.ledger().sequence();
+    client.mint(&admin, &alice, &1_000);
+    client.mint(&admin, &bob, &200);
+
+    // Moves within one ledger collapse into a single checkpoint
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    client.transfer(&alice, &bob, &300);
+    client.delegate(&alice, &bob);
+    let delegated = env.ledger().sequence();
+
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    client.burn(&bob, &100);
+
+    assert_eq!(client.get_past_balance(&alice, &(start - 1)), 0);
+    assert_eq!(client.get_past_balance(&alice, &start), 1_000);
+    assert_eq!(client.get_past_balance(&alice, &(delegated - 1)), 1_000);
+    assert_eq!(client.get_past_balance(&alice, &delegated), 700);
+
+    assert_eq!(client.get_past_delegate(&alice, &start), None);
+    assert_eq!(client.get_past_delegate(&alice, &delegated), Some(bob.clone()));
+
+    // Bob votes with Alice's balance once she has delegated to him
+    assert_eq!(client.get_past_votes(&alice, &start), 1_000);
+    assert_eq!(client.get_past_votes(&bob, &start), 200);
+    assert_eq!(client.get_past_votes(&alice, &delegated), 0);
+    assert_eq!(client.get_past_votes(&bob, &delegated), 1_200);
+    assert_eq!(client.get_past_votes(&bob, &env.ledger().sequence()), 1_100);
+    assert_eq!(client.voting_power(&bob), 1_100);
+}
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Env, String,
 };
+pub use pulsartrack_errors::IdentityRegistryError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -40,6 +42,20 @@ pub struct Identity {
     pub last_activity: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct IdentityRegistered {
+    pub account: Address,
+}
+contract_event!(IdentityRegistered, "identity_registered");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct IdentityVerified {
+    pub account: Address,
+}
+contract_event!(IdentityVerified, "identity_verified");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -53,6 +69,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "identity";
+
 #[contract]
 pub struct IdentityRegistryContract;
 
@@ -61,7 +79,7 @@ impl IdentityRegistryContract {
     pub fn initialize(env: Env, admin: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, IdentityRegistryError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -79,11 +97,11 @@ impl IdentityRegistryContract {
         account.require_auth();
 
         if env.storage().persistent().has(&DataKey::Identity(account.clone())) {
-            panic!("already registered");
+            panic_with_error!(&env, IdentityRegistryError::AlreadyRegistered);
         }
 
         if env.storage().persistent().has(&DataKey::NameOwner(display_name.clone())) {
-            panic!("name taken");
+            panic_with_error!(&env, IdentityRegistryError::NameTaken);
         }
 
         let identity = Identity {
@@ -108,10 +126,7 @@ impl IdentityRegistryContract {
         let count: u64 = env.storage().instance().get(&DataKey::IdentityCount).unwrap_or(0);
         env.storage().instance().set(&DataKey::IdentityCount, &(count + 1));
 
-        env.events().publish(
-            (symbol_short!("identity"), symbol_short!("register")),
-            account,
-        );
+        events::publish(&env, EVENT_TAG, IdentityRegistered { account });
     }
 
     pub fn verify_identity(
@@ -124,14 +139,14 @@ impl IdentityRegistryContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, IdentityRegistryError::Unauthorized);
         }
 
         let mut identity: Identity = env
             .storage()
             .persistent()
             .get(&DataKey::Identity(account.clone()))
-            .expect("identity not found");
+            .unwrap_or_else(|| panic_with_error!(&env, IdentityRegistryError::IdentityNotFound));
 
         identity.status = IdentityStatus::Verified;
         identity.credentials_hash = credentials_hash;
@@ -141,10 +156,7 @@ impl IdentityRegistryContract {
         env.storage().persistent().set(&_ttl_key, &identity);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("identity"), symbol_short!("verified")),
-            account,
-        );
+        events::publish(&env, EVENT_TAG, IdentityVerified { account });
     }
 
     pub fn update_metadata(env: Env, account: Address, metadata_hash: String) {
@@ -155,7 +167,7 @@ impl IdentityRegistryContract {
             .storage()
             .persistent()
             .get(&DataKey::Identity(account.clone()))
-            .expect("identity not found");
+            .unwrap_or_else(|| panic_with_error!(&env, IdentityRegistryError::IdentityNotFound));
 
         identity.metadata_hash = metadata_hash;
         identity.last_activity = env.ledger().timestamp();
@@ -170,14 +182,14 @@ impl IdentityRegistryContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, IdentityRegistryError::Unauthorized);
         }
 
         let mut identity: Identity = env
             .storage()
             .persistent()
             .get(&DataKey::Identity(account.clone()))
-            .expect("identity not found");
+            .unwrap_or_else(|| panic_with_error!(&env, IdentityRegistryError::IdentityNotFound));
 
         identity.status = IdentityStatus::Suspended;
         let _ttl_key = DataKey::Identity(account);
@@ -0,0 +1,414 @@
+//! PulsarTrack - Insurance Pool (Soroban)
+//! Advertiser protection against proven publisher fraud on Stellar.
+//!
+//! The pool is funded by a slice of platform fees (the fee manager sends
+//! its insurance share straight to this contract) plus any direct deposits.
+//! An advertiser harmed by a publisher whose fraud report was confirmed in
+//! the fraud registry files a claim, capped per campaign. Each claim opens
+//! a dispute against the publisher in the dispute-resolution contract, with
+//! the advertiser as claimant paying any filing fee, and the arbitrator's
+//! outcome decides the payout: in full for the claimant, half on a split,
+//! nothing otherwise.
+//!
+//! Events are published as ("insurance", action, version) with a typed payload
+//! (see the Events section):
+//! - "pool_funded": `PoolFunded`
+//! - "claim_filed": `ClaimFiled`
+//! - "claim_paid": `ClaimPaid`
+//! - "claim_denied": `ClaimDenied`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    token, Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
+};
+pub use pulsartrack_errors::InsurancePoolError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClaimStatus {
+    Pending,
+    Paid,
+    Denied,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct InsuranceClaim {
+    pub advertiser: Address,
+    pub publisher: Address,
+    pub campaign_id: u64,
+    pub fraud_report_id: u64,
+    pub dispute_id: u64,
+    pub amount: i128,
+    pub paid: i128,
+    pub status: ClaimStatus,
+    pub filed_at: u64,
+    pub settled_at: Option<u64>,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PoolFunded {
+    pub funder: Address,
+    pub amount: i128,
+}
+contract_event!(PoolFunded, "pool_funded");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ClaimFiled {
+    pub claim_id: u64,
+    pub advertiser: Address,
+    pub campaign_id: u64,
+    pub amount: i128,
+    pub dispute_id: u64,
+}
+contract_event!(ClaimFiled, "claim_filed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ClaimPaid {
+    pub claim_id: u64,
+    pub advertiser: Address,
+    pub amount: i128,
+}
+contract_event!(ClaimPaid, "claim_paid");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ClaimDenied {
+    pub claim_id: u64,
+}
+contract_event!(ClaimDenied, "claim_denied");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Token,
+    FraudRegistry,
+    DisputeResolution,
+    CampaignCap,
+    ClaimCounter,
+    Claim(u64),
+    ReportClaim(u64, Address),  // fraud report, advertiser
+    CampaignCommitted(u64),     // pending + paid claims on a campaign
+    Reserved,                   // pending claims across the pool
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const EVENT_TAG: &str = "insurance";
+
+#[contract]
+pub struct InsurancePoolContract;
+
+#[contractimpl]
+impl InsurancePoolContract {
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        token: Address,
+        fraud_registry: Address,
+        dispute_resolution: Address,
+        campaign_cap: i128,
+    ) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, InsurancePoolError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        if campaign_cap <= 0 {
+            panic_with_error!(&env, InsurancePoolError::InvalidCap);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Token, &token);
+        env.storage().instance().set(&DataKey::FraudRegistry, &fraud_registry);
+        env.storage().instance().set(&DataKey::DisputeResolution, &dispute_resolution);
+        env.storage().instance().set(&DataKey::CampaignCap, &campaign_cap);
+        env.storage().instance().set(&DataKey::ClaimCounter, &0u64);
+        env.storage().instance().set(&DataKey::Reserved, &0i128);
+    }
+
+    pub fn set_campaign_cap(env: Env, admin: Address, campaign_cap: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        if campaign_cap <= 0 {
+            panic_with_error!(&env, InsurancePoolError::InvalidCap);
+        }
+        env.storage().instance().set(&DataKey::CampaignCap, &campaign_cap);
+    }
+
+    /// Deposit into the pool. Fee-manager payouts arrive as plain transfers.
+    pub fn fund(env: Env, funder: Address, amount: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        funder.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&env, InsurancePoolError::InvalidAmount);
+        }
+        Self::_token(&env).transfer(&funder, &env.current_contract_address(), &amount);
+
+        events::publish(&env, EVENT_TAG, PoolFunded { funder, amount });
+    }
+
+    /// Claim compensation for a campaign hit by a publisher whose fraud
+    /// report has been confirmed. Opens a dispute against the publisher
+    /// for arbitration with the advertiser as claimant, so any filing fee
+    /// comes out of the advertiser's balance rather than the pool's.
+    pub fn file_claim(
+        env: Env,
+        advertiser: Address,
+        campaign_id: u64,
+        fraud_report_id: u64,
+        amount: i128,
+        description: String,
+        evidence_hash: String,
+    ) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        advertiser.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&env, InsurancePoolError::InvalidAmount);
+        }
+
+        let report_key = DataKey::ReportClaim(fraud_report_id, advertiser.clone());
+        if env.storage().persistent().has(&report_key) {
+            panic_with_error!(&env, InsurancePoolError::AlreadyClaimed);
+        }
+        let publisher = Self::_confirmed_publisher(&env, fraud_report_id);
+
+        let cap: i128 = env.storage().instance().get(&DataKey::CampaignCap).unwrap();
+        let committed = Self::_campaign_committed(&env, campaign_id);
+        if committed + amount > cap {
+            panic_with_error!(&env, InsurancePoolError::ExceedsCampaignCap);
+        }
+
+        let dispute_resolution: Address = env.storage().instance().get(&DataKey::DisputeResolution).unwrap();
+        let dispute_id: u64 = env.invoke_contract(
+            &dispute_resolution,
+            &Symbol::new(&env, "file_dispute"),
+            Vec::from_array(
+                &env,
+                [
+                    advertiser.into_val(&env),
+                    publisher.into_val(&env),
+                    campaign_id.into_val(&env),
+                    amount.into_val(&env),
+                    description.into_val(&env),
+                    evidence_hash.into_val(&env),
+                ],
+            ),
+        );
+
+        let counter: u64 = env.storage().instance().get(&DataKey::ClaimCounter).unwrap_or(0);
+        let claim_id = counter + 1;
+        let claim = InsuranceClaim {
+            advertiser: advertiser.clone(),
+            publisher,
+            campaign_id,
+            fraud_report_id,
+            dispute_id,
+            amount,
+            paid: 0,
+            status: ClaimStatus::Pending,
+            filed_at: env.ledger().timestamp(),
+            settled_at: None,
+        };
+        Self::_save_claim(&env, claim_id, &claim);
+        env.storage().instance().set(&DataKey::ClaimCounter, &claim_id);
+
+        env.storage().persistent().set(&report_key, &claim_id);
+        env.storage().persistent().extend_ttl(&report_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_set_campaign_committed(&env, campaign_id, committed + amount);
+        let reserved = Self::_reserved(&env);
+        env.storage().instance().set(&DataKey::Reserved, &(reserved + amount));
+
+        events::publish(&env, EVENT_TAG, ClaimFiled { claim_id, advertiser, campaign_id, amount, dispute_id });
+
+        claim_id
+    }
+
+    /// Pay or deny a claim once its dispute has been resolved. Anyone may
+    /// call this.
+    pub fn settle_claim(env: Env, claim_id: u64) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let mut claim = Self::_load_claim(&env, claim_id);
+        if claim.status != ClaimStatus::Pending {
+            panic_with_error!(&env, InsurancePoolError::ClaimNotPending);
+        }
+
+        let payout = match Self::_dispute_outcome(&env, claim.dispute_id) {
+            Some(outcome) if outcome == Symbol::new(&env, "Claimant") => claim.amount,
+            Some(outcome) if outcome == Symbol::new(&env, "Split") => claim.amount / 2,
+            Some(_) => 0,
+            None => panic_with_error!(&env, InsurancePoolError::DisputePending),
+        };
+
+        let reserved = Self::_reserved(&env);
+        if payout > Self::_token(&env).balance(&env.current_contract_address()) {
+            panic_with_error!(&env, InsurancePoolError::InsufficientPoolFunds);
+        }
+        env.storage().instance().set(&DataKey::Reserved, &(reserved - claim.amount));
+        // Only what is actually paid counts against the campaign cap
+        let committed = Self::_campaign_committed(&env, claim.campaign_id);
+        Self::_set_campaign_committed(&env, claim.campaign_id, committed - (claim.amount - payout));
+
+        claim.paid = payout;
+        claim.status = if payout > 0 { ClaimStatus::Paid } else { ClaimStatus::Denied };
+        claim.settled_at = Some(env.ledger().timestamp());
+        Self::_save_claim(&env, claim_id, &claim);
+
+        if payout > 0 {
+            Self::_token(&env).transfer(&env.current_contract_address(), &claim.advertiser, &payout);
+            events::publish(&env, EVENT_TAG, ClaimPaid { claim_id, advertiser: claim.advertiser, amount: payout });
+        } else {
+            events::publish(&env, EVENT_TAG, ClaimDenied { claim_id });
+        }
+
+        payout
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn get_claim(env: Env, claim_id: u64) -> Option<InsuranceClaim> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Claim(claim_id))
+    }
+
+    /// Cap left for new claims on a campaign
+    pub fn get_campaign_remaining(env: Env, campaign_id: u64) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let cap: i128 = env.storage().instance().get(&DataKey::CampaignCap).unwrap();
+        (cap - Self::_campaign_committed(&env, campaign_id)).max(0)
+    }
+
+    /// Pool balance not held back for pending claims
+    pub fn get_available(env: Env) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_token(&env).balance(&env.current_contract_address()) - Self::_reserved(&env)
+    }
+
+    pub fn get_claim_count(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::ClaimCounter).unwrap_or(0)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _require_admin(env: &Env, admin: &Address) {
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *admin != stored_admin {
+            panic_with_error!(&env, InsurancePoolError::Unauthorized);
+        }
+    }
+
+    fn _token(env: &Env) -> token::Client<'_> {
+        let token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        token::Client::new(env, &token)
+    }
+
+    fn _reserved(env: &Env) -> i128 {
+        env.storage().instance().get(&DataKey::Reserved).unwrap_or(0)
+    }
+
+    fn _campaign_committed(env: &Env, campaign_id: u64) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CampaignCommitted(campaign_id))
+            .unwrap_or(0)
+    }
+
+    fn _set_campaign_committed(env: &Env, campaign_id: u64, committed: i128) {
+        let _ttl_key = DataKey::CampaignCommitted(campaign_id);
+        env.storage().persistent().set(&_ttl_key, &committed);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _load_claim(env: &Env, claim_id: u64) -> InsuranceClaim {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Claim(claim_id))
+            .unwrap_or_else(|| panic_with_error!(&env, InsurancePoolError::ClaimNotFound))
+    }
+
+    fn _save_claim(env: &Env, claim_id: u64, claim: &InsuranceClaim) {
+        let _ttl_key = DataKey::Claim(claim_id);
+        env.storage().persistent().set(&_ttl_key, claim);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    /// Fetch a record from another contract as a field map
+    fn _fetch(env: &Env, contract: &Address, function: &str, id: u64) -> Option<Map<Symbol, Val>> {
+        let val: Val = env.invoke_contract(contract, &Symbol::new(env, function), (id,).into_val(env));
+        if val.is_void() {
+            return None;
+        }
+        Some(Map::<Symbol, Val>::try_from_val(env, &val).unwrap_or_else(|_| panic_with_error!(&env, InsurancePoolError::InvalidRecord)))
+    }
+
+    fn _field(env: &Env, record: &Map<Symbol, Val>, name: &str) -> Val {
+        record
+            .get(Symbol::new(env, name))
+            .unwrap_or_else(|| panic_with_error!(&env, InsurancePoolError::InvalidRecord))
+    }
+
+    /// Variant name of a unit enum value
+    fn _variant(env: &Env, val: &Val) -> Option<Symbol> {
+        Vec::<Val>::try_from_val(env, val)
+            .ok()
+            .and_then(|v| v.get(0))
+            .and_then(|tag| Symbol::try_from_val(env, &tag).ok())
+    }
+
+    /// The publisher named by a confirmed fraud report
+    fn _confirmed_publisher(env: &Env, report_id: u64) -> Address {
+        let registry: Address = env.storage().instance().get(&DataKey::FraudRegistry).unwrap();
+        let report = Self::_fetch(env, &registry, "get_report", report_id).unwrap_or_else(|| panic_with_error!(&env, InsurancePoolError::ReportNotFound));
+        if Self::_variant(env, &Self::_field(env, &report, "status")) != Some(Symbol::new(env, "Confirmed")) {
+            panic_with_error!(&env, InsurancePoolError::FraudNotConfirmed);
+        }
+        if Self::_variant(env, &Self::_field(env, &report, "role")) != Some(Symbol::new(env, "Publisher")) {
+            panic_with_error!(&env, InsurancePoolError::NotAPublisherReport);
+        }
+        Address::try_from_val(env, &Self::_field(env, &report, "target")).unwrap_or_else(|_| panic_with_error!(&env, InsurancePoolError::InvalidRecord))
+    }
+
+    /// Outcome of a resolved dispute, or None while it is still open
+    fn _dispute_outcome(env: &Env, dispute_id: u64) -> Option<Symbol> {
+        let dispute_resolution: Address = env.storage().instance().get(&DataKey::DisputeResolution).unwrap();
+        let dispute = Self::_fetch(env, &dispute_resolution, "get_dispute", dispute_id).unwrap_or_else(|| panic_with_error!(&env, InsurancePoolError::DisputeNotFound));
+        let status = Self::_variant(env, &Self::_field(env, &dispute, "status"));
+        if status != Some(Symbol::new(env, "Resolved")) && status != Some(Symbol::new(env, "Closed")) {
+            return None;
+        }
+        // Closed before a ruling counts as no action
+        Some(Self::_variant(env, &Self::_field(env, &dispute, "outcome")).unwrap_or(Symbol::new(env, "NoAction")))
+    }
+}
+
+mod test;
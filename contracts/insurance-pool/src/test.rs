@@ -0,0 +1,264 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{contract, contractimpl, contracttype, testutils::Address as _, token, Address, Env, String};
+
+// Stand-ins for the fraud registry and dispute resolution contracts: same
+// entry points and field names, with outcomes set directly by the test
+#[contracttype]
+#[derive(Clone)]
+pub enum ReportStatus {
+    Open,
+    Confirmed,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum TargetRole {
+    Publisher,
+    Advertiser,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct MockReport {
+    pub target: Address,
+    pub role: TargetRole,
+    pub status: ReportStatus,
+}
+
+#[contract]
+pub struct MockFraudRegistry;
+
+#[contractimpl]
+impl MockFraudRegistry {
+    pub fn add_report(env: Env, report_id: u64, target: Address, role: TargetRole, status: ReportStatus) {
+        env.storage().instance().set(&report_id, &MockReport { target, role, status });
+    }
+
+    pub fn get_report(env: Env, report_id: u64) -> Option<MockReport> {
+        env.storage().instance().get(&report_id)
+    }
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DisputeStatus {
+    Filed,
+    Resolved,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DisputeOutcome {
+    Claimant,
+    Respondent,
+    Split,
+    NoAction,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct MockDispute {
+    pub claimant: Address,
+    pub respondent: Address,
+    pub claim_amount: i128,
+    pub status: DisputeStatus,
+    pub outcome: DisputeOutcome, // only read once resolved
+}
+
+#[contract]
+pub struct MockDisputes;
+
+#[contractimpl]
+impl MockDisputes {
+    pub fn file_dispute(
+        env: Env,
+        claimant: Address,
+        respondent: Address,
+        _campaign_id: u64,
+        claim_amount: i128,
+        _description: String,
+        _evidence_hash: String,
+    ) -> u64 {
+        claimant.require_auth();
+        let id: u64 = env.storage().instance().get(&0u64).unwrap_or(0) + 1;
+        let dispute = MockDispute { claimant, respondent, claim_amount, status: DisputeStatus::Filed, outcome: DisputeOutcome::NoAction };
+        env.storage().instance().set(&id, &dispute);
+        env.storage().instance().set(&0u64, &id);
+        id
+    }
+
+    pub fn resolve(env: Env, dispute_id: u64, outcome: DisputeOutcome) {
+        let mut dispute: MockDispute = env.storage().instance().get(&dispute_id).unwrap();
+        dispute.status = DisputeStatus::Resolved;
+        dispute.outcome = outcome;
+        env.storage().instance().set(&dispute_id, &dispute);
+    }
+
+    pub fn get_dispute(env: Env, dispute_id: u64) -> Option<MockDispute> {
+        env.storage().instance().get(&dispute_id)
+    }
+}
+
+struct Setup<'a> {
+    client: InsurancePoolContractClient<'a>,
+    admin: Address,
+    token: Address,
+    registry: MockFraudRegistryClient<'a>,
+    disputes: MockDisputesClient<'a>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let contract_id = env.register_contract(None, InsurancePoolContract);
+    let client = InsurancePoolContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    let registry_id = env.register_contract(None, MockFraudRegistry);
+    let disputes_id = env.register_contract(None, MockDisputes);
+    client.initialize(&admin, &token, &registry_id, &disputes_id, &1_000);
+    Setup {
+        client,
+        admin,
+        token,
+        registry: MockFraudRegistryClient::new(env, &registry_id),
+        disputes: MockDisputesClient::new(env, &disputes_id),
+    }
+}
+
+fn text(env: &Env, s: &str) -> String {
+    String::from_str(env, s)
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    assert_eq!(s.client.get_claim_count(), 0);
+    assert_eq!(s.client.get_campaign_remaining(&1), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2901)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.initialize(&s.admin, &s.token, &s.registry.address, &s.disputes.address, &1_000);
+}
+
+#[test]
+fn test_claims_need_confirmed_publisher_fraud_and_respect_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let advertiser = Address::generate(&env);
+    let publisher = Address::generate(&env);
+
+    s.registry.add_report(&1, &publisher, &TargetRole::Publisher, &ReportStatus::Open);
+    s.registry.add_report(&2, &advertiser, &TargetRole::Advertiser, &ReportStatus::Confirmed);
+    s.registry.add_report(&3, &publisher, &TargetRole::Publisher, &ReportStatus::Confirmed);
+    s.registry.add_report(&4, &publisher, &TargetRole::Publisher, &ReportStatus::Confirmed);
+
+    assert!(s.client.try_file_claim(&advertiser, &7, &1, &500, &text(&env, "bots"), &text(&env, "Qm1")).is_err());
+    assert!(s.client.try_file_claim(&advertiser, &7, &2, &500, &text(&env, "bots"), &text(&env, "Qm1")).is_err());
+    assert!(s.client.try_file_claim(&advertiser, &7, &9, &500, &text(&env, "bots"), &text(&env, "Qm1")).is_err());
+    assert!(s.client.try_file_claim(&advertiser, &7, &3, &1_500, &text(&env, "bots"), &text(&env, "Qm1")).is_err());
+
+    let claim_id = s.client.file_claim(&advertiser, &7, &3, &800, &text(&env, "bots"), &text(&env, "Qm1"));
+    let claim = s.client.get_claim(&claim_id).unwrap();
+    assert_eq!(claim.publisher, publisher);
+    assert_eq!(s.disputes.get_dispute(&claim.dispute_id).unwrap().respondent, publisher);
+    assert_eq!(s.client.get_campaign_remaining(&7), 200);
+
+    assert!(s.client.try_file_claim(&advertiser, &7, &3, &100, &text(&env, "again"), &text(&env, "Qm2")).is_err());
+    assert!(s.client.try_file_claim(&advertiser, &7, &4, &300, &text(&env, "more"), &text(&env, "Qm2")).is_err());
+    assert!(s.client.try_settle_claim(&claim_id).is_err());
+}
+
+#[test]
+fn test_arbitration_outcome_decides_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let balance = token::Client::new(&env, &s.token);
+    let advertiser = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    let fee_manager = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &s.token).mint(&fee_manager, &2_000);
+    s.registry.add_report(&1, &publisher, &TargetRole::Publisher, &ReportStatus::Confirmed);
+    s.registry.add_report(&2, &publisher, &TargetRole::Publisher, &ReportStatus::Confirmed);
+    s.registry.add_report(&3, &publisher, &TargetRole::Publisher, &ReportStatus::Confirmed);
+
+    let full = s.client.file_claim(&advertiser, &1, &1, &600, &text(&env, "a"), &text(&env, "Qm"));
+    let split = s.client.file_claim(&advertiser, &2, &2, &400, &text(&env, "b"), &text(&env, "Qm"));
+    let denied = s.client.file_claim(&advertiser, &3, &3, &300, &text(&env, "c"), &text(&env, "Qm"));
+
+    let dispute = |claim_id: u64| s.client.get_claim(&claim_id).unwrap().dispute_id;
+    s.disputes.resolve(&dispute(full), &DisputeOutcome::Claimant);
+    s.disputes.resolve(&dispute(split), &DisputeOutcome::Split);
+    s.disputes.resolve(&dispute(denied), &DisputeOutcome::Respondent);
+
+    // The pool has to hold the funds before it pays
+    assert!(s.client.try_settle_claim(&full).is_err());
+    s.client.fund(&fee_manager, &1_000);
+    assert_eq!(s.client.get_available(), -300);
+
+    assert_eq!(s.client.settle_claim(&full), 600);
+    assert_eq!(s.client.settle_claim(&split), 200);
+    assert_eq!(s.client.settle_claim(&denied), 0);
+    assert_eq!(balance.balance(&advertiser), 800);
+    assert_eq!(s.client.get_available(), 200);
+    assert!(s.client.get_claim(&denied).unwrap().status == ClaimStatus::Denied);
+    assert!(s.client.try_settle_claim(&full).is_err());
+
+    // Unpaid parts of the split claim go back to the campaign's cap
+    assert_eq!(s.client.get_campaign_remaining(&2), 800);
+    assert_eq!(s.client.get_campaign_remaining(&3), 1_000);
+}
+
+#[test]
+fn test_claim_filing_fee_is_paid_by_advertiser() {
+    use pulsar_dispute_resolution::{
+        DisputeOutcome as Outcome, DisputeResolutionContract, DisputeResolutionContractClient,
+    };
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, InsurancePoolContract);
+    let client = InsurancePoolContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let balance = token::Client::new(&env, &token);
+    let registry = MockFraudRegistryClient::new(&env, &env.register_contract(None, MockFraudRegistry));
+    let disputes = DisputeResolutionContractClient::new(&env, &env.register_contract(None, DisputeResolutionContract));
+    disputes.initialize(&admin, &token, &50);
+    client.initialize(&admin, &token, &registry.address, &disputes.address, &1_000);
+
+    let advertiser = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let fee_manager = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&advertiser, &50);
+    token::StellarAssetClient::new(&env, &token).mint(&fee_manager, &1_000);
+    client.fund(&fee_manager, &1_000);
+    registry.add_report(&1, &publisher, &TargetRole::Publisher, &ReportStatus::Confirmed);
+
+    let claim_id = client.file_claim(&advertiser, &1, &1, &600, &text(&env, "bots"), &text(&env, "Qm"));
+    let dispute_id = client.get_claim(&claim_id).unwrap().dispute_id;
+    let dispute = disputes.get_dispute(&dispute_id).unwrap();
+    assert_eq!(dispute.claimant, advertiser);
+    assert_eq!(dispute.respondent, publisher);
+    assert_eq!(balance.balance(&advertiser), 0);
+    assert_eq!(balance.balance(&admin), 50);
+    assert_eq!(balance.balance(&contract_id), 1_000);
+    assert!(client.try_settle_claim(&claim_id).is_err());
+
+    disputes.authorize_arbitrator(&admin, &arbitrator);
+    disputes.assign_arbitrator(&admin, &dispute_id, &arbitrator);
+    disputes.resolve_dispute(&arbitrator, &dispute_id, &Outcome::Claimant, &text(&env, "fraud shown"));
+    assert_eq!(client.settle_claim(&claim_id), 600);
+    assert_eq!(balance.balance(&advertiser), 600);
+    assert_eq!(client.get_available(), 400);
+}
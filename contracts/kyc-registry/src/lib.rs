@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Env, String,
 };
+pub use pulsartrack_errors::KycRegistryError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -40,6 +42,21 @@ pub struct KycProvider {
     pub total_verifications: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct KycSubmitted {
+    pub account: Address,
+    pub level: KycLevel,
+}
+contract_event!(KycSubmitted, "kyc_submitted");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct KycVerified {
+    pub account: Address,
+}
+contract_event!(KycVerified, "kyc_verified");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -53,6 +70,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "kyc";
+
 #[contract]
 pub struct KycRegistryContract;
 
@@ -61,7 +80,7 @@ impl KycRegistryContract {
     pub fn initialize(env: Env, admin: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, KycRegistryError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -72,7 +91,7 @@ impl KycRegistryContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, KycRegistryError::Unauthorized);
         }
 
         let kyc_provider = KycProvider {
@@ -102,10 +121,10 @@ impl KycRegistryContract {
             .storage()
             .persistent()
             .get(&DataKey::Provider(provider.clone()))
-            .expect("provider not registered");
+            .unwrap_or_else(|| panic_with_error!(&env, KycRegistryError::ProviderNotRegistered));
 
         if !kyc_provider.is_active {
-            panic!("provider not active");
+            panic_with_error!(&env, KycRegistryError::ProviderNotActive);
         }
 
         let record = KycRecord {
@@ -125,10 +144,7 @@ impl KycRegistryContract {
         env.storage().persistent().set(&_ttl_key, &record);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("kyc"), symbol_short!("submitted")),
-            (account, level),
-        );
+        events::publish(&env, EVENT_TAG, KycSubmitted { account, level });
     }
 
     pub fn verify_kyc(
@@ -144,17 +160,17 @@ impl KycRegistryContract {
             .storage()
             .persistent()
             .get(&DataKey::Provider(provider.clone()))
-            .expect("provider not found");
+            .unwrap_or_else(|| panic_with_error!(&env, KycRegistryError::ProviderNotFound));
 
         if !provider_data.is_active {
-            panic!("provider not active");
+            panic_with_error!(&env, KycRegistryError::ProviderNotActive);
         }
 
         let mut record: KycRecord = env
             .storage()
             .persistent()
             .get(&DataKey::KycRecord(account.clone()))
-            .expect("kyc not submitted");
+            .unwrap_or_else(|| panic_with_error!(&env, KycRegistryError::KycNotSubmitted));
 
         let now = env.ledger().timestamp();
         record.verified = true;
@@ -171,10 +187,7 @@ impl KycRegistryContract {
         env.storage().persistent().set(&_ttl_key, &provider_data);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("kyc"), symbol_short!("verified")),
-            account,
-        );
+        events::publish(&env, EVENT_TAG, KycVerified { account });
     }
 
     pub fn revoke_kyc(env: Env, admin: Address, account: Address) {
@@ -182,14 +195,14 @@ impl KycRegistryContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, KycRegistryError::Unauthorized);
         }
 
         let mut record: KycRecord = env
             .storage()
             .persistent()
             .get(&DataKey::KycRecord(account.clone()))
-            .expect("kyc not found");
+            .unwrap_or_else(|| panic_with_error!(&env, KycRegistryError::KycNotFound));
 
         record.verified = false;
         let _ttl_key = DataKey::KycRecord(account);
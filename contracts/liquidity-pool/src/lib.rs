@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     token, Address, Env,
 };
+pub use pulsartrack_errors::LiquidityPoolError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone)]
@@ -39,6 +41,15 @@ pub struct BorrowPosition {
     pub due_at: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct Deposited {
+    pub provider: Address,
+    pub amount: i128,
+    pub shares: i128,
+}
+contract_event!(Deposited, "deposited");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -55,6 +66,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "liquidity";
+
 #[contract]
 pub struct LiquidityPoolContract;
 
@@ -63,7 +76,7 @@ impl LiquidityPoolContract {
     pub fn initialize(env: Env, admin: Address, token: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, LiquidityPoolError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -84,7 +97,7 @@ impl LiquidityPoolContract {
         provider.require_auth();
 
         if amount <= 0 {
-            panic!("invalid amount");
+            panic_with_error!(&env, LiquidityPoolError::InvalidAmount);
         }
 
         let token_addr: Address = env.storage().instance().get(&DataKey::TokenAddress).unwrap();
@@ -124,10 +137,7 @@ impl LiquidityPoolContract {
         env.storage().persistent().set(&_ttl_key, &position);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("pool"), symbol_short!("deposit")),
-            (provider, amount, shares),
-        );
+        events::publish(&env, EVENT_TAG, Deposited { provider, amount, shares });
 
         shares
     }
@@ -140,10 +150,10 @@ impl LiquidityPoolContract {
             .storage()
             .persistent()
             .get(&DataKey::Provider(provider.clone()))
-            .expect("no position");
+            .unwrap_or_else(|| panic_with_error!(&env, LiquidityPoolError::NoPosition));
 
         if position.shares < shares {
-            panic!("insufficient shares");
+            panic_with_error!(&env, LiquidityPoolError::InsufficientShares);
         }
 
         let mut pool: PoolState = env.storage().instance().get(&DataKey::PoolState).unwrap();
@@ -153,7 +163,7 @@ impl LiquidityPoolContract {
         let available = pool.total_liquidity - pool.total_borrowed;
 
         if amount > available {
-            panic!("insufficient liquidity");
+            panic_with_error!(&env, LiquidityPoolError::InsufficientLiquidity);
         }
 
         pool.total_liquidity -= amount;
@@ -182,11 +192,11 @@ impl LiquidityPoolContract {
         let available = pool.total_liquidity - pool.total_borrowed;
 
         if amount > available {
-            panic!("insufficient liquidity");
+            panic_with_error!(&env, LiquidityPoolError::InsufficientLiquidity);
         }
 
         if env.storage().persistent().has(&DataKey::Borrow(campaign_id)) {
-            panic!("already has borrow");
+            panic_with_error!(&env, LiquidityPoolError::AlreadyHasBorrow);
         }
 
         pool.total_borrowed += amount;
@@ -221,10 +231,10 @@ impl LiquidityPoolContract {
             .storage()
             .persistent()
             .get(&DataKey::Borrow(campaign_id))
-            .expect("borrow not found");
+            .unwrap_or_else(|| panic_with_error!(&env, LiquidityPoolError::BorrowNotFound));
 
         if borrow.borrower != borrower {
-            panic!("unauthorized");
+            panic_with_error!(&env, LiquidityPoolError::Unauthorized);
         }
 
         let token_addr: Address = env.storage().instance().get(&DataKey::TokenAddress).unwrap();
@@ -245,7 +255,7 @@ impl LiquidityPoolContract {
 
     pub fn get_pool_state(env: Env) -> PoolState {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        env.storage().instance().get(&DataKey::PoolState).expect("not initialized")
+        env.storage().instance().get(&DataKey::PoolState).unwrap_or_else(|| panic_with_error!(&env, LiquidityPoolError::NotInitialized))
     }
 
     pub fn get_provider_position(env: Env, provider: Address) -> Option<ProviderPosition> {
@@ -0,0 +1,291 @@
+//! PulsarTrack - Merkle Distributor (Soroban)
+//! Airdrops of governance tokens to large recipient sets on Stellar.
+//!
+//! The admin funds a distribution and commits the merkle root of its
+//! (address, amount) pairs. Recipients claim their own leaf with a proof;
+//! whatever is left after the deadline can be swept back to the treasury.
+//!
+//! Leaves are `sha256(xdr((address, amount)))` and each parent hashes the
+//! concatenation of its two children in ascending byte order, so proofs
+//! don't need left/right flags.
+//!
+//! Events are published as ("airdrop", action, version) with a typed payload
+//! (see the Events section):
+//! - "distribution_created": `DistributionCreated`
+//! - "claimed": `Claimed`
+//! - "swept": `Swept`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    token, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec,
+};
+pub use pulsartrack_errors::MerkleDistributorError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Distribution {
+    pub token: Address,
+    pub root: BytesN<32>,
+    pub total: i128,
+    pub claimed: i128,
+    pub deadline: u64, // claims close and the remainder can be swept
+    pub swept: bool,
+    pub created_at: u64,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct DistributionCreated {
+    pub distribution_id: u64,
+    pub root: BytesN<32>,
+    pub total: i128,
+    pub deadline: u64,
+}
+contract_event!(DistributionCreated, "distribution_created");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Claimed {
+    pub distribution_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+}
+contract_event!(Claimed, "claimed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Swept {
+    pub distribution_id: u64,
+    pub treasury: Address,
+    pub amount: i128,
+}
+contract_event!(Swept, "swept");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Treasury,
+    DistributionCounter,
+    Distribution(u64),
+    Claimed(u64, Address),
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const MAX_PROOF_LEN: u32 = 32;
+
+const EVENT_TAG: &str = "airdrop";
+
+#[contract]
+pub struct MerkleDistributorContract;
+
+#[contractimpl]
+impl MerkleDistributorContract {
+    pub fn initialize(env: Env, admin: Address, treasury: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, MerkleDistributorError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+        env.storage().instance().set(&DataKey::DistributionCounter, &0u64);
+    }
+
+    pub fn set_treasury(env: Env, admin: Address, treasury: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+    }
+
+    /// Fund a distribution of `total` tokens and commit its merkle root
+    /// (admin only)
+    pub fn create_distribution(
+        env: Env,
+        admin: Address,
+        token: Address,
+        root: BytesN<32>,
+        total: i128,
+        deadline: u64,
+    ) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        if total <= 0 {
+            panic_with_error!(&env, MerkleDistributorError::InvalidTotal);
+        }
+        if deadline <= env.ledger().timestamp() {
+            panic_with_error!(&env, MerkleDistributorError::InvalidDeadline);
+        }
+
+        token::Client::new(&env, &token).transfer(&admin, &env.current_contract_address(), &total);
+
+        let counter: u64 = env.storage().instance().get(&DataKey::DistributionCounter).unwrap_or(0);
+        let distribution_id = counter + 1;
+        let distribution = Distribution {
+            token,
+            root: root.clone(),
+            total,
+            claimed: 0,
+            deadline,
+            swept: false,
+            created_at: env.ledger().timestamp(),
+        };
+        Self::_save_distribution(&env, distribution_id, &distribution);
+        env.storage().instance().set(&DataKey::DistributionCounter, &distribution_id);
+
+        events::publish(&env, EVENT_TAG, DistributionCreated { distribution_id, root, total, deadline });
+
+        distribution_id
+    }
+
+    /// Claim the recipient's allocation with a proof of its leaf
+    pub fn claim(env: Env, recipient: Address, distribution_id: u64, amount: i128, proof: Vec<BytesN<32>>) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        recipient.require_auth();
+
+        let mut distribution = Self::_load_distribution(&env, distribution_id);
+        if env.ledger().timestamp() >= distribution.deadline {
+            panic_with_error!(&env, MerkleDistributorError::ClaimPeriodEnded);
+        }
+        let claimed_key = DataKey::Claimed(distribution_id, recipient.clone());
+        if env.storage().persistent().has(&claimed_key) {
+            panic_with_error!(&env, MerkleDistributorError::AlreadyClaimed);
+        }
+        if proof.len() > MAX_PROOF_LEN {
+            panic_with_error!(&env, MerkleDistributorError::ProofTooLong);
+        }
+        if Self::_root_from_proof(&env, Self::_leaf(&env, &recipient, amount), &proof) != distribution.root {
+            panic_with_error!(&env, MerkleDistributorError::InvalidProof);
+        }
+        if amount <= 0 || distribution.claimed + amount > distribution.total {
+            panic_with_error!(&env, MerkleDistributorError::InvalidAmount);
+        }
+
+        env.storage().persistent().set(&claimed_key, &true);
+        env.storage().persistent().extend_ttl(&claimed_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        distribution.claimed += amount;
+        Self::_save_distribution(&env, distribution_id, &distribution);
+
+        token::Client::new(&env, &distribution.token).transfer(&env.current_contract_address(), &recipient, &amount);
+
+        events::publish(&env, EVENT_TAG, Claimed { distribution_id, recipient, amount });
+    }
+
+    /// Return unclaimed tokens to the treasury once the deadline has passed.
+    /// Anyone may call this.
+    pub fn sweep(env: Env, distribution_id: u64) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let mut distribution = Self::_load_distribution(&env, distribution_id);
+        if env.ledger().timestamp() < distribution.deadline {
+            panic_with_error!(&env, MerkleDistributorError::ClaimPeriodActive);
+        }
+        if distribution.swept {
+            panic_with_error!(&env, MerkleDistributorError::AlreadySwept);
+        }
+
+        let remaining = distribution.total - distribution.claimed;
+        distribution.swept = true;
+        Self::_save_distribution(&env, distribution_id, &distribution);
+
+        let treasury: Address = env.storage().instance().get(&DataKey::Treasury).unwrap();
+        if remaining > 0 {
+            token::Client::new(&env, &distribution.token).transfer(&env.current_contract_address(), &treasury, &remaining);
+        }
+
+        events::publish(&env, EVENT_TAG, Swept { distribution_id, treasury, amount: remaining });
+
+        remaining
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn get_distribution(env: Env, distribution_id: u64) -> Option<Distribution> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Distribution(distribution_id))
+    }
+
+    pub fn is_claimed(env: Env, distribution_id: u64, recipient: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().has(&DataKey::Claimed(distribution_id, recipient))
+    }
+
+    /// Leaf hash for a recipient, for off-chain tree builders
+    pub fn leaf_hash(env: Env, recipient: Address, amount: i128) -> BytesN<32> {
+        Self::_leaf(&env, &recipient, amount)
+    }
+
+    pub fn get_distribution_count(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::DistributionCounter).unwrap_or(0)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _require_admin(env: &Env, admin: &Address) {
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *admin != stored_admin {
+            panic_with_error!(&env, MerkleDistributorError::Unauthorized);
+        }
+    }
+
+    fn _load_distribution(env: &Env, distribution_id: u64) -> Distribution {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Distribution(distribution_id))
+            .unwrap_or_else(|| panic_with_error!(&env, MerkleDistributorError::DistributionNotFound))
+    }
+
+    fn _save_distribution(env: &Env, distribution_id: u64, distribution: &Distribution) {
+        let _ttl_key = DataKey::Distribution(distribution_id);
+        env.storage().persistent().set(&_ttl_key, distribution);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _leaf(env: &Env, recipient: &Address, amount: i128) -> BytesN<32> {
+        env.crypto().sha256(&(recipient.clone(), amount).to_xdr(env)).into()
+    }
+
+    fn _root_from_proof(env: &Env, leaf: BytesN<32>, proof: &Vec<BytesN<32>>) -> BytesN<32> {
+        let mut node = leaf;
+        for sibling in proof.iter() {
+            let (first, second) = if node.to_array() <= sibling.to_array() {
+                (node, sibling)
+            } else {
+                (sibling, node)
+            };
+            let mut preimage = Bytes::from(first);
+            preimage.append(&Bytes::from(second));
+            node = env.crypto().sha256(&preimage).into();
+        }
+        node
+    }
+}
+
+mod test;
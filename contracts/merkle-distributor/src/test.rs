@@ -0,0 +1,87 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Bytes, BytesN, Env,
+};
+
+fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a.to_array() <= b.to_array() { (a, b) } else { (b, a) };
+    let mut preimage = Bytes::from(first.clone());
+    preimage.append(&Bytes::from(second.clone()));
+    env.crypto().sha256(&preimage).into()
+}
+
+fn setup(env: &Env) -> (MerkleDistributorContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, MerkleDistributorContract);
+    let client = MerkleDistributorContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let treasury = Address::generate(env);
+    client.initialize(&admin, &treasury);
+
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    token::StellarAssetClient::new(env, &token).mint(&admin, &10_000);
+    (client, admin, treasury, token)
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _treasury, _token) = setup(&env);
+    assert_eq!(client.get_distribution_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3201)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, treasury, _token) = setup(&env);
+    client.initialize(&admin, &treasury);
+}
+
+#[test]
+fn test_claim_with_proof_and_sweep_remainder() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, treasury, token) = setup(&env);
+    let balance = token::Client::new(&env, &token);
+
+    let recipients = [0; 4].map(|_| Address::generate(&env));
+    let amounts = [100i128, 200, 300, 400];
+    let leaves: [BytesN<32>; 4] = [0, 1, 2, 3].map(|i| client.leaf_hash(&recipients[i], &amounts[i]));
+    let left = hash_pair(&env, &leaves[0], &leaves[1]);
+    let right = hash_pair(&env, &leaves[2], &leaves[3]);
+    let root = hash_pair(&env, &left, &right);
+
+    let id = client.create_distribution(&admin, &token, &root, &1_000, &5_000);
+    assert_eq!(balance.balance(&admin), 9_000);
+
+    client.claim(&recipients[0], &id, &100, &vec![&env, leaves[1].clone(), right.clone()]);
+    client.claim(&recipients[3], &id, &400, &vec![&env, leaves[2].clone(), left.clone()]);
+    assert_eq!(balance.balance(&recipients[0]), 100);
+    assert_eq!(balance.balance(&recipients[3]), 400);
+    assert!(client.is_claimed(&id, &recipients[0]));
+
+    // No double claims, no inflated amounts, no borrowing someone else's proof
+    assert!(client
+        .try_claim(&recipients[0], &id, &100, &vec![&env, leaves[1].clone(), right.clone()])
+        .is_err());
+    assert!(client
+        .try_claim(&recipients[1], &id, &250, &vec![&env, leaves[0].clone(), right.clone()])
+        .is_err());
+    assert!(client
+        .try_claim(&recipients[2], &id, &100, &vec![&env, leaves[1].clone(), right.clone()])
+        .is_err());
+
+    assert!(client.try_sweep(&id).is_err());
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    assert!(client
+        .try_claim(&recipients[1], &id, &200, &vec![&env, leaves[0].clone(), right.clone()])
+        .is_err());
+    assert_eq!(client.sweep(&id), 500);
+    assert_eq!(balance.balance(&treasury), 500);
+    assert!(client.try_sweep(&id).is_err());
+}
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Env, String,
 };
+pub use pulsartrack_errors::MilestoneTrackerError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -33,6 +35,14 @@ pub struct Milestone {
     pub created_at: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct MilestoneAchieved {
+    pub milestone_id: u64,
+    pub campaign_id: u64,
+}
+contract_event!(MilestoneAchieved, "milestone_achieved");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -47,6 +57,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "milestones";
+
 #[contract]
 pub struct MilestoneTrackerContract;
 
@@ -55,7 +67,7 @@ impl MilestoneTrackerContract {
     pub fn initialize(env: Env, admin: Address, oracle: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, MilestoneTrackerError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -124,14 +136,14 @@ impl MilestoneTrackerContract {
         oracle.require_auth();
         let stored_oracle: Address = env.storage().instance().get(&DataKey::OracleAddress).unwrap();
         if oracle != stored_oracle {
-            panic!("unauthorized");
+            panic_with_error!(&env, MilestoneTrackerError::Unauthorized);
         }
 
         let mut milestone: Milestone = env
             .storage()
             .persistent()
             .get(&DataKey::Milestone(milestone_id))
-            .expect("milestone not found");
+            .unwrap_or_else(|| panic_with_error!(&env, MilestoneTrackerError::MilestoneNotFound));
 
         if milestone.status == MilestoneStatus::Achieved {
             return; // Already achieved, no update needed
@@ -143,10 +155,7 @@ impl MilestoneTrackerContract {
             milestone.status = MilestoneStatus::Achieved;
             milestone.achieved_at = Some(env.ledger().timestamp());
 
-            env.events().publish(
-                (symbol_short!("milestone"), symbol_short!("achieved")),
-                (milestone_id, milestone.campaign_id),
-            );
+            events::publish(&env, EVENT_TAG, MilestoneAchieved { milestone_id, campaign_id: milestone.campaign_id });
         } else if env.ledger().sequence() > milestone.deadline_ledger {
             milestone.status = MilestoneStatus::Missed;
         } else {
@@ -166,7 +175,7 @@ impl MilestoneTrackerContract {
             .storage()
             .persistent()
             .get(&DataKey::Milestone(milestone_id))
-            .expect("milestone not found");
+            .unwrap_or_else(|| panic_with_error!(&env, MilestoneTrackerError::MilestoneNotFound));
 
         milestone.status = MilestoneStatus::Disputed;
         let _ttl_key = DataKey::Milestone(milestone_id);
@@ -179,14 +188,14 @@ impl MilestoneTrackerContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, MilestoneTrackerError::Unauthorized);
         }
 
         let mut milestone: Milestone = env
             .storage()
             .persistent()
             .get(&DataKey::Milestone(milestone_id))
-            .expect("milestone not found");
+            .unwrap_or_else(|| panic_with_error!(&env, MilestoneTrackerError::MilestoneNotFound));
 
         milestone.status = if achieved {
             MilestoneStatus::Achieved
@@ -0,0 +1,99 @@
+//! PulsarTrack - Multicall (Soroban)
+//! Batched read-only invocations across the PulsarTrack suite.
+//!
+//! Frontends simulate `aggregate` once instead of issuing one RPC simulation
+//! per getter. Each call is `(contract, function, args)` and results come
+//! back in the same order. The contract keeps no state and never authorizes
+//! anything on its own behalf, so it is only meant to be simulated: calls
+//! that need a user's auth fail exactly as they would if made directly.
+//!
+//! - `aggregate` fails the whole batch if any call fails.
+//! - `aggregate_partial` returns `(success, value)` per call and keeps
+//!   going past failures; a failed call's value is the error it raised.
+//! - `aggregate_at` also returns the ledger the batch was read at, so callers
+//!   can tell when two batches come from the same snapshot.
+
+
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contracttype, panic_with_error, Address, Env, Symbol, Val, Vec};
+pub use pulsartrack_errors::MulticallError;
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Batch {
+    pub ledger: u32,
+    pub timestamp: u64,
+    pub results: Vec<Val>,
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+/// Upper bound on calls per batch, well inside a simulation's CPU budget for
+/// the suite's getters
+pub const MAX_CALLS: u32 = 64;
+
+#[contract]
+pub struct MulticallContract;
+
+#[contractimpl]
+impl MulticallContract {
+    /// Invoke every call in order and return the results; panics if any call fails
+    pub fn aggregate(env: Env, calls: Vec<(Address, Symbol, Vec<Val>)>) -> Vec<Val> {
+        Self::_check_batch(&calls);
+        let mut results = Vec::new(&env);
+        for (contract, func, args) in calls.iter() {
+            results.push_back(env.invoke_contract::<Val>(&contract, &func, args));
+        }
+        results
+    }
+
+    /// Like `aggregate`, but also returns the ledger sequence and timestamp
+    /// the batch was read at
+    pub fn aggregate_at(env: Env, calls: Vec<(Address, Symbol, Vec<Val>)>) -> Batch {
+        Batch {
+            ledger: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+            results: Self::aggregate(env.clone(), calls),
+        }
+    }
+
+    /// Invoke every call in order, recording failures instead of aborting
+    pub fn aggregate_partial(env: Env, calls: Vec<(Address, Symbol, Vec<Val>)>) -> Vec<(bool, Val)> {
+        Self::_check_batch(&calls);
+        let mut results = Vec::new(&env);
+        for (contract, func, args) in calls.iter() {
+            let result = match env.try_invoke_contract::<Val, soroban_sdk::Error>(&contract, &func, args) {
+                Ok(Ok(value)) => (true, value),
+                Err(Ok(err)) => (false, err.to_val()),
+                _ => (false, Val::VOID.to_val()),
+            };
+            results.push_back(result);
+        }
+        results
+    }
+
+    pub fn max_calls(_env: Env) -> u32 {
+        MAX_CALLS
+    }
+
+    // ============================================================
+    // Internal Helpers
+    // ============================================================
+
+    fn _check_batch(calls: &Vec<(Address, Symbol, Vec<Val>)>) {
+        if calls.is_empty() {
+            panic_with_error!(calls.env(), MulticallError::NoCalls);
+        }
+        if calls.len() > MAX_CALLS {
+            panic_with_error!(calls.env(), MulticallError::TooManyCalls);
+        }
+    }
+}
+
+mod test;
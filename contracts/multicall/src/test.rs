@@ -0,0 +1,121 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, panic_with_error, testutils::{Address as _, Ledger},
+    vec, Address, Env, IntoVal, Symbol, TryFromVal,
+};
+
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReaderError {
+    NotFound = 7,
+}
+
+// Stand-in for a suite contract exposing a few getters
+#[contract]
+pub struct MockReader;
+
+#[contractimpl]
+impl MockReader {
+    pub fn set(env: Env, key: u32, value: i128) {
+        env.storage().instance().set(&key, &value);
+    }
+
+    pub fn get(env: Env, key: u32) -> i128 {
+        env.storage()
+            .instance()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, ReaderError::NotFound))
+    }
+
+    pub fn owner(_env: Env, who: Address) -> Address {
+        who
+    }
+}
+
+fn setup(env: &Env) -> (MulticallContractClient<'static>, Address) {
+    let multicall = MulticallContractClient::new(env, &env.register(MulticallContract, ()));
+    let reader_id = env.register(MockReader, ());
+    let reader = MockReaderClient::new(env, &reader_id);
+    reader.set(&1, &100);
+    reader.set(&2, &250);
+    (multicall, reader_id)
+}
+
+#[test]
+fn test_aggregate_returns_results_in_order() {
+    let env = Env::default();
+    let (multicall, reader) = setup(&env);
+    let who = Address::generate(&env);
+
+    let calls = vec![
+        &env,
+        (reader.clone(), Symbol::new(&env, "get"), vec![&env, 2u32.into_val(&env)]),
+        (reader.clone(), Symbol::new(&env, "owner"), vec![&env, who.into_val(&env)]),
+        (reader.clone(), Symbol::new(&env, "get"), vec![&env, 1u32.into_val(&env)]),
+    ];
+    let results = multicall.aggregate(&calls);
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(i128::try_from_val(&env, &results.get(0).unwrap()).unwrap(), 250);
+    assert_eq!(Address::try_from_val(&env, &results.get(1).unwrap()).unwrap(), who);
+    assert_eq!(i128::try_from_val(&env, &results.get(2).unwrap()).unwrap(), 100);
+
+    env.ledger().with_mut(|li| li.sequence_number = 42);
+    let batch = multicall.aggregate_at(&calls);
+    assert_eq!(batch.ledger, 42);
+    assert_eq!(batch.results.len(), 3);
+}
+
+#[test]
+fn test_aggregate_fails_whole_batch() {
+    let env = Env::default();
+    let (multicall, reader) = setup(&env);
+
+    let calls = vec![
+        &env,
+        (reader.clone(), Symbol::new(&env, "get"), vec![&env, 1u32.into_val(&env)]),
+        (reader.clone(), Symbol::new(&env, "get"), vec![&env, 9u32.into_val(&env)]),
+    ];
+    assert!(multicall.try_aggregate(&calls).is_err());
+}
+
+#[test]
+fn test_aggregate_partial_records_failures() {
+    let env = Env::default();
+    let (multicall, reader) = setup(&env);
+
+    let calls = vec![
+        &env,
+        (reader.clone(), Symbol::new(&env, "get"), vec![&env, 9u32.into_val(&env)]),
+        (reader.clone(), Symbol::new(&env, "get"), vec![&env, 1u32.into_val(&env)]),
+    ];
+    let results = multicall.aggregate_partial(&calls);
+
+    let (success, value) = results.get(0).unwrap();
+    assert!(!success);
+    assert_eq!(
+        soroban_sdk::Error::try_from_val(&env, &value).unwrap(),
+        soroban_sdk::Error::from_contract_error(ReaderError::NotFound as u32)
+    );
+
+    let (success, value) = results.get(1).unwrap();
+    assert!(success);
+    assert_eq!(i128::try_from_val(&env, &value).unwrap(), 100);
+}
+
+#[test]
+fn test_batch_size_limits() {
+    let env = Env::default();
+    let (multicall, reader) = setup(&env);
+
+    assert!(multicall.try_aggregate(&vec![&env]).is_err());
+
+    let mut calls = vec![&env];
+    for _ in 0..=MAX_CALLS {
+        calls.push_back((reader.clone(), Symbol::new(&env, "get"), vec![&env, 1u32.into_val(&env)]));
+    }
+    assert!(multicall.try_aggregate_partial(&calls).is_err());
+    calls.pop_back();
+    assert_eq!(multicall.aggregate_partial(&calls).len(), MAX_CALLS);
+}
@@ -4,8 +4,13 @@
 #![no_std]
 use soroban_sdk::{
     contract, contractimpl, contracttype, symbol_short,
-    token, Address, Env, String, Vec,
+    token, Address, BytesN, Env, IntoVal, Map, String, Symbol, Val, Vec,
 };
+pub use pulsartrack_errors::TreasuryError;
+use pulsartrack_events::{self as events, contract_event, RoleGranted, RoleRevoked};
+use pulsartrack_access::Ownable;
+use pulsartrack_upgrade::{ContractVersion, Upgradeable};
+use pulsartrack_storage::{self as storage, Persistent, TtlPolicy};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -15,61 +20,302 @@ pub enum TxStatus {
     Executed,
     Rejected,
     Expired,
+    Cancelled,
+}
+
+/// What a treasury transaction does once executed. For non-payment kinds the
+/// `recipient`, `token` and `amount` fields of `TreasuryTx` are unused.
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum TxKind {
+    Payment,
+    AddSigner(Address),
+    RemoveSigner(Address),
+    ChangeThreshold(u32),
+    SetSignerWeight(Address, u32),
+    /// Invoke `function` on `contract` with `args`, authorized by the treasury
+    Invoke(Address, Symbol, Vec<Val>),
+    /// Pay each (recipient, token, amount) entry atomically
+    Batch(Vec<(Address, Address, i128)>),
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub struct TreasuryTx {
     pub tx_id: u64,
+    pub kind: TxKind,
     pub proposer: Address,
     pub recipient: Address,
     pub token: Address,
     pub amount: i128,
     pub description: String,
     pub status: TxStatus,
-    pub approvals: u32,
-    pub rejections: u32,
+    pub approvals: u32,  // summed weight of approving signers
+    pub rejections: u32, // summed weight of rejecting signers
     pub required_approvals: u32,
     pub created_at: u64,
     pub expires_at: u64,
+    pub approved_at: Option<u64>,
     pub executed_at: Option<u64>,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct SpendingLimit {
+    pub amount: i128,
+    pub window_seconds: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SpendingWindow {
+    pub window_start: u64,
+    pub spent: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct DepositReceipt {
+    pub deposit_id: u64,
+    pub depositor: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub memo: String,
+    pub deposited_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TreasuryReport {
+    pub token: Address,
+    pub total_inflow: i128,
+    pub total_outflow: i128,
+    pub deposit_count: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TxProposed {
+    pub tx_id: u64,
+    pub proposer: Address,
+}
+contract_event!(TxProposed, "tx_proposed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TxApproved {
+    pub tx_id: u64,
+    pub signer: Address,
+    pub approvals: u32,
+    pub status: TxStatus,
+}
+contract_event!(TxApproved, "tx_approved");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ApprovalRevoked {
+    pub tx_id: u64,
+    pub signer: Address,
+}
+contract_event!(ApprovalRevoked, "approval_revoked");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TxRejected {
+    pub tx_id: u64,
+    pub signer: Address,
+    pub rejections: u32,
+    pub status: TxStatus,
+}
+contract_event!(TxRejected, "tx_rejected");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct DaoPayment {
+    pub dao: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+contract_event!(DaoPayment, "dao_payment");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TreasuryFrozen {
+    pub signer: Address,
+    pub frozen_until: u64,
+}
+contract_event!(TreasuryFrozen, "frozen");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TreasuryUnfrozen {
+    pub approvers: Vec<Address>,
+}
+contract_event!(TreasuryUnfrozen, "unfrozen");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TxCancelled {
+    pub tx_id: u64,
+    pub proposer: Address,
+}
+contract_event!(TxCancelled, "tx_cancelled");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Deposited {
+    pub deposit_id: u64,
+    pub depositor: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+contract_event!(Deposited, "deposited");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SpendingLimitSet {
+    pub token: Address,
+    pub amount: i128,
+    pub window_seconds: u64,
+}
+contract_event!(SpendingLimitSet, "spending_limit_set");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TxExpired {
+    pub tx_id: u64,
+}
+contract_event!(TxExpired, "tx_expired");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BatchPayment {
+    pub tx_id: u64,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+contract_event!(BatchPayment, "batch_payment");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TxExecuted {
+    pub tx_id: u64,
+    pub amount: i128,
+}
+contract_event!(TxExecuted, "tx_executed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SignerWeightSet {
+    pub signer: Address,
+    pub weight: u32,
+}
+contract_event!(SignerWeightSet, "signer_weight_set");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ThresholdChanged {
+    pub old_required: u32,
+    pub new_required: u32,
+}
+contract_event!(ThresholdChanged, "threshold_changed");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
     Signers,
-    RequiredSigners,
+    SignerWeights, // Map<Address, u32>; signers without an entry weigh 1
+    RequiredSigners, // approval threshold as a weight sum
     TxCounter,
     Tx(u64),
     TxApproval(u64, Address),
+    TxRejection(u64, Address), // weight the signer rejected with
+    SpendingLimit(Address),
+    SpendingWindow(Address),
+    ExecutionDelay,
+    DepositCounter,
+    Deposit(u64),
+    Report(Address),
+    OpenTxs, // Vec<u64> of Pending/Approved tx ids
+    StatusTxs(TxStatus), // Vec<u64> of tx ids that ended in a status, in the order they did
+    FrozenUntil,
+    FreezeCooldown,
+    DaoContract,
+    Registry,
+    HeldTokens, // Vec<Address> of every token deposited or paid out
+    UpgradeAuthority,
+    ContractVersion,
 }
 
+const MAX_BATCH_SIZE: u32 = 50;
+const MAX_PAGE_SIZE: u32 = 50;
+const DEFAULT_FREEZE_COOLDOWN: u64 = 172_800; // 48h
+
 const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
 const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+// TTL per kind of persistent entry, bumped on every access
+const TX_TTL: TtlPolicy = storage::LONG_LIVED;
+
+const EVENT_TAG: &str = "treasury";
+const CONTRACT_VERSION: u32 = 1; // deployments from before versioning count as 1
 
 #[contract]
 pub struct MultisigTreasuryContract;
 
 #[contractimpl]
 impl MultisigTreasuryContract {
-    pub fn initialize(env: Env, admin: Address, initial_signers: Vec<Address>, required: u32) {
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        initial_signers: Vec<Address>,
+        required: u32,
+    ) -> Result<(), TreasuryError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
-        }
-        admin.require_auth();
+        Ownable::init(&env, &DataKey::Admin, &admin)?;
 
-        if required == 0 || required > initial_signers.len() as u32 {
-            panic!("invalid required signers");
+        if required == 0 || required > initial_signers.len() {
+            return Err(TreasuryError::InvalidRequiredSigners);
         }
 
-        env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Signers, &initial_signers);
         env.storage().instance().set(&DataKey::RequiredSigners, &required);
         env.storage().instance().set(&DataKey::TxCounter, &0u64);
+        Upgradeable::init(&env, &DataKey::ContractVersion, CONTRACT_VERSION);
+        Ok(())
+    }
+
+    /// See `Upgradeable`
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::require_authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)?;
+        Upgradeable::upgrade(&env, EVENT_TAG, &DataKey::ContractVersion, new_wasm_hash);
+        Ok(())
+    }
+
+    pub fn migrate(env: Env, from_version: u32) -> Result<u32, TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::require_authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)?;
+        Ok(Upgradeable::migrate(&env, EVENT_TAG, &DataKey::ContractVersion, from_version, CONTRACT_VERSION, |_| {})?)
+    }
+
+    pub fn set_upgrade_authority(env: Env, caller: Address, authority: Address) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::set_authority(&env, EVENT_TAG, &DataKey::UpgradeAuthority, &DataKey::Admin, &caller, &authority)?;
+        Ok(())
+    }
+
+    pub fn get_upgrade_authority(env: Env) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)
+    }
+
+    pub fn get_version(env: Env) -> ContractVersion {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::version(&env, &DataKey::ContractVersion)
     }
 
     pub fn propose_transaction(
@@ -80,19 +326,13 @@ impl MultisigTreasuryContract {
         amount: i128,
         description: String,
         expires_in: u64,
-    ) -> u64 {
+    ) -> Result<u64, TreasuryError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         proposer.require_auth();
-
-        let signers: Vec<Address> = env.storage().instance().get(&DataKey::Signers).unwrap();
-        let is_signer = signers.contains(&proposer);
-
-        if !is_signer {
-            panic!("not a signer");
-        }
+        Self::_require_signer(&env, &proposer)?;
 
         if amount <= 0 {
-            panic!("invalid amount");
+            return Err(TreasuryError::InvalidAmount);
         }
 
         let counter: u64 = env.storage().instance().get(&DataKey::TxCounter).unwrap_or(0);
@@ -101,6 +341,7 @@ impl MultisigTreasuryContract {
 
         let tx = TreasuryTx {
             tx_id,
+            kind: TxKind::Payment,
             proposer: proposer.clone(),
             recipient,
             token,
@@ -112,6 +353,7 @@ impl MultisigTreasuryContract {
             required_approvals: required,
             created_at: env.ledger().timestamp(),
             expires_at: env.ledger().timestamp() + expires_in,
+            approved_at: None,
             executed_at: None,
         };
 
@@ -119,141 +361,1038 @@ impl MultisigTreasuryContract {
         env.storage().persistent().set(&_ttl_key, &tx);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
         env.storage().instance().set(&DataKey::TxCounter, &tx_id);
+        Self::_add_open(&env, tx_id);
 
-        env.events().publish(
-            (symbol_short!("treasury"), symbol_short!("proposed")),
-            (tx_id, proposer),
-        );
+        events::publish(&env, EVENT_TAG, TxProposed { tx_id, proposer });
 
-        tx_id
+        Ok(tx_id)
     }
 
-    pub fn approve_transaction(env: Env, signer: Address, tx_id: u64) {
+    /// Propose a change to the signer set, signer weights or approval threshold. Goes through
+    /// the same approval flow as payments and is applied on execution.
+    pub fn propose_signer_change(
+        env: Env,
+        proposer: Address,
+        kind: TxKind,
+        description: String,
+        expires_in: u64,
+    ) -> Result<u64, TreasuryError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        signer.require_auth();
+        proposer.require_auth();
+        let signers = Self::_require_signer(&env, &proposer)?;
 
-        let signers: Vec<Address> = env.storage().instance().get(&DataKey::Signers).unwrap();
-        if !signers.contains(&signer) {
-            panic!("not a signer");
+        let required: u32 = env.storage().instance().get(&DataKey::RequiredSigners).unwrap();
+        Self::_validate_signer_change(&env, &signers, required, &kind)?;
+
+        Ok(Self::_store_new_tx(&env, kind, proposer, description, expires_in))
+    }
+
+    /// Propose an arbitrary cross-contract call made by the treasury, e.g. to
+    /// upgrade another PulsarTrack contract or change its configuration. Tokens
+    /// under a spending limit can't be the target; pay those out with
+    /// `propose_transaction` so the limit applies.
+    pub fn propose_call(
+        env: Env,
+        proposer: Address,
+        contract: Address,
+        function: Symbol,
+        args: Vec<Val>,
+        description: String,
+        expires_in: u64,
+    ) -> Result<u64, TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        proposer.require_auth();
+        Self::_require_signer(&env, &proposer)?;
+
+        if contract == env.current_contract_address() {
+            return Err(TreasuryError::CannotCallSelf);
         }
+        Self::_require_unlimited_target(&env, &contract)?;
+
+        Ok(Self::_store_new_tx(
+            &env,
+            TxKind::Invoke(contract, function, args),
+            proposer,
+            description,
+            expires_in,
+        ))
+    }
 
-        if env.storage().persistent().has(&DataKey::TxApproval(tx_id, signer.clone())) {
-            panic!("already voted");
+    /// Propose several payments that are approved once and executed atomically
+    pub fn propose_batch(
+        env: Env,
+        proposer: Address,
+        payments: Vec<(Address, Address, i128)>,
+        description: String,
+        expires_in: u64,
+    ) -> Result<u64, TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        proposer.require_auth();
+        Self::_require_signer(&env, &proposer)?;
+
+        if payments.is_empty() || payments.len() > MAX_BATCH_SIZE {
+            return Err(TreasuryError::InvalidBatchSize);
+        }
+        for (_, _, amount) in payments.iter() {
+            if amount <= 0 {
+                return Err(TreasuryError::InvalidAmount);
+            }
         }
 
-        let mut tx: TreasuryTx = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Tx(tx_id))
-            .expect("tx not found");
+        Ok(Self::_store_new_tx(&env, TxKind::Batch(payments), proposer, description, expires_in))
+    }
+
+    pub fn approve_transaction(env: Env, signer: Address, tx_id: u64) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        signer.require_auth();
+        Self::_require_signer(&env, &signer)?;
+
+        if Self::_has_voted(&env, tx_id, &signer) {
+            return Err(TreasuryError::AlreadyVoted);
+        }
+
+        let mut tx = Self::_load_tx(&env, tx_id)?;
 
         if tx.status != TxStatus::Pending {
-            panic!("tx not pending");
+            return Err(TreasuryError::TxNotPending);
         }
 
+        // An overdue tx is left for expire_transactions to sweep; any write
+        // here would be rolled back with the error anyway.
         if env.ledger().timestamp() > tx.expires_at {
-            tx.status = TxStatus::Expired;
-            let _ttl_key = DataKey::Tx(tx_id);
-            env.storage().persistent().set(&_ttl_key, &tx);
-            env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
-            panic!("tx expired");
+            return Err(TreasuryError::TxExpired);
         }
 
-        tx.approvals += 1;
-        let _ttl_key = DataKey::TxApproval(tx_id, signer);
-        env.storage().persistent().set(&_ttl_key, &true);
+        let weight = Self::_weight(&env, &signer);
+        tx.approvals += weight;
+        let _ttl_key = DataKey::TxApproval(tx_id, signer.clone());
+        env.storage().persistent().set(&_ttl_key, &weight);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
         if tx.approvals >= tx.required_approvals {
             tx.status = TxStatus::Approved;
+            tx.approved_at = Some(env.ledger().timestamp());
+        }
+
+        let _ttl_key = DataKey::Tx(tx_id);
+        env.storage().persistent().set(&_ttl_key, &tx);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(
+            &env,
+            EVENT_TAG,
+            TxApproved { tx_id, signer, approvals: tx.approvals, status: tx.status },
+        );
+        Ok(())
+    }
+
+    /// Withdraw a previous approval. An approved tx that drops below its
+    /// threshold returns to Pending.
+    pub fn revoke_approval(env: Env, signer: Address, tx_id: u64) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        signer.require_auth();
+
+        let approval_key = DataKey::TxApproval(tx_id, signer.clone());
+        if !env.storage().persistent().has(&approval_key) {
+            return Err(TreasuryError::NotApproved);
+        }
+
+        let mut tx = Self::_load_tx(&env, tx_id)?;
+
+        if tx.status != TxStatus::Pending && tx.status != TxStatus::Approved {
+            return Err(TreasuryError::TxNotOpen);
+        }
+
+        let weight: u32 = env.storage().persistent().get(&approval_key).unwrap_or(1);
+        env.storage().persistent().remove(&approval_key);
+        tx.approvals = tx.approvals.saturating_sub(weight);
+
+        if tx.status == TxStatus::Approved && tx.approvals < tx.required_approvals {
+            tx.status = TxStatus::Pending;
+            tx.approved_at = None;
         }
 
         let _ttl_key = DataKey::Tx(tx_id);
         env.storage().persistent().set(&_ttl_key, &tx);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, ApprovalRevoked { tx_id, signer });
+        Ok(())
     }
 
-    pub fn execute_transaction(env: Env, caller: Address, tx_id: u64) {
+    pub fn execute_transaction(env: Env, caller: Address, tx_id: u64) -> Result<(), TreasuryError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         caller.require_auth();
+        Self::_require_signer(&env, &caller)?;
 
-        let signers: Vec<Address> = env.storage().instance().get(&DataKey::Signers).unwrap();
-        if !signers.contains(&caller) {
-            panic!("not a signer");
+        let tx = Self::_load_tx(&env, tx_id)?;
+
+        if tx.status != TxStatus::Approved {
+            return Err(TreasuryError::TxNotApproved);
         }
 
-        let mut tx: TreasuryTx = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Tx(tx_id))
-            .expect("tx not found");
+        let delay: u64 = env.storage().instance().get(&DataKey::ExecutionDelay).unwrap_or(0);
+        if env.ledger().timestamp() < tx.approved_at.unwrap_or(0) + delay {
+            return Err(TreasuryError::TimelockActive);
+        }
+
+        Self::_execute(&env, tx)
+    }
+
+    /// Execute an approved tx immediately, bypassing the execution delay.
+    /// Requires authorization from every current signer.
+    pub fn emergency_execute(env: Env, tx_id: u64) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let signers = Self::_signers(&env)?;
+        for signer in signers.iter() {
+            signer.require_auth();
+        }
+
+        let tx = Self::_load_tx(&env, tx_id)?;
 
         if tx.status != TxStatus::Approved {
-            panic!("tx not approved");
+            return Err(TreasuryError::TxNotApproved);
         }
 
-        let token_client = token::Client::new(&env, &tx.token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &tx.recipient,
-            &tx.amount,
-        );
+        Self::_execute(&env, tx)
+    }
+
+    /// Set the delay in seconds between a tx reaching approval and becoming executable (admin only)
+    pub fn set_execution_delay(env: Env, admin: Address, delay: u64) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::ExecutionDelay, &delay);
+        Ok(())
+    }
+
+    pub fn get_execution_delay(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::ExecutionDelay).unwrap_or(0)
+    }
+
+    /// Vote against a pending tx. A signer votes once, either way; the tx is
+    /// rejected once the remaining weight can no longer reach its threshold.
+    pub fn reject_transaction(env: Env, signer: Address, tx_id: u64) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        signer.require_auth();
+        let signers = Self::_require_signer(&env, &signer)?;
+
+        if Self::_has_voted(&env, tx_id, &signer) {
+            return Err(TreasuryError::AlreadyVoted);
+        }
+
+        let mut tx = Self::_load_tx(&env, tx_id)?;
+
+        if tx.status != TxStatus::Pending {
+            return Err(TreasuryError::TxNotPending);
+        }
+
+        let weight = Self::_weight(&env, &signer);
+        tx.rejections += weight;
+        let _ttl_key = DataKey::TxRejection(tx_id, signer.clone());
+        env.storage().persistent().set(&_ttl_key, &weight);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        let total_weight = Self::_total_weight(&env, &signers);
+        let max_possible_approvals = total_weight.saturating_sub(tx.rejections);
+
+        if max_possible_approvals < tx.required_approvals {
+            tx.status = TxStatus::Rejected;
+            Self::_close(&env, tx_id, &tx.status);
+        }
 
-        tx.status = TxStatus::Executed;
-        tx.executed_at = Some(env.ledger().timestamp());
         let _ttl_key = DataKey::Tx(tx_id);
         env.storage().persistent().set(&_ttl_key, &tx);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("treasury"), symbol_short!("executed")),
-            (tx_id, tx.amount),
+        events::publish(
+            &env,
+            EVENT_TAG,
+            TxRejected { tx_id, signer, rejections: tx.rejections, status: tx.status },
         );
+        Ok(())
     }
 
-    pub fn reject_transaction(env: Env, signer: Address, tx_id: u64) {
+    /// Trust a governance DAO contract to direct treasury payments (admin only)
+    pub fn set_dao_contract(env: Env, admin: Address, dao_contract: Address) -> Result<(), TreasuryError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        signer.require_auth();
+        Self::_require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::DaoContract, &dao_contract);
+        Ok(())
+    }
 
-        let signers: Vec<Address> = env.storage().instance().get(&DataKey::Signers).unwrap();
-        if !signers.contains(&signer) {
-            panic!("not a signer");
+    /// Resolve the trusted DAO through the contract registry's "dao" key,
+    /// falling back to `set_dao_contract` (admin only)
+    pub fn set_registry(env: Env, admin: Address, registry: Address) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::Registry, &registry);
+        Ok(())
+    }
+
+    pub fn get_dao_contract(env: Env) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_dao_contract(&env)
+    }
+
+    /// Pay out a grant approved by token holders, bypassing signer approval.
+    /// Only the trusted DAO may call this, which it does when executing a
+    /// passed proposal whose action targets the treasury. Recipient, token
+    /// and amount are therefore the ones stored in the proposal's action, and
+    /// the DAO's timelock and one-time execution apply.
+    pub fn execute_dao_payment(
+        env: Env,
+        dao_contract: Address,
+        recipient: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let trusted = Self::_dao_contract(&env).ok_or(TreasuryError::DaoNotConfigured)?;
+        if dao_contract != trusted {
+            return Err(TreasuryError::UntrustedDao);
+        }
+        dao_contract.require_auth();
+
+        if amount <= 0 {
+            return Err(TreasuryError::InvalidAmount);
+        }
+        if Self::_is_frozen(&env) {
+            return Err(TreasuryError::TreasuryFrozen);
         }
+        Self::_require_balance(&env, &token, amount)?;
+
+        Self::_record_outflow(&env, &token, amount)?;
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &recipient, &amount);
+
+        events::publish(&env, EVENT_TAG, DaoPayment { dao: dao_contract, recipient, token, amount });
+        Ok(())
+    }
+
+    /// Block all executions for the freeze cool-down. Any single signer may
+    /// trigger this when they suspect a compromised key.
+    pub fn freeze(env: Env, signer: Address) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        signer.require_auth();
+        Self::_require_signer(&env, &signer)?;
 
-        let mut tx: TreasuryTx = env
+        let cooldown: u64 = env
             .storage()
-            .persistent()
-            .get(&DataKey::Tx(tx_id))
-            .expect("tx not found");
+            .instance()
+            .get(&DataKey::FreezeCooldown)
+            .unwrap_or(DEFAULT_FREEZE_COOLDOWN);
+        let frozen_until = env.ledger().timestamp() + cooldown;
+        env.storage().instance().set(&DataKey::FrozenUntil, &frozen_until);
 
-        if tx.status != TxStatus::Pending {
-            panic!("tx not pending");
-        }
+        events::publish(&env, EVENT_TAG, TreasuryFrozen { signer, frozen_until });
+        Ok(())
+    }
+
+    /// Lift a freeze early. `approvers` must be signers whose combined weight
+    /// meets the approval threshold, and each must authorize the call.
+    pub fn unfreeze(env: Env, approvers: Vec<Address>) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
-        tx.rejections += 1;
+        let signers = Self::_signers(&env)?;
+        let mut counted: Vec<Address> = Vec::new(&env);
+        let mut weight: u32 = 0;
+        for approver in approvers.iter() {
+            if !signers.contains(&approver) {
+                return Err(TreasuryError::NotSigner);
+            }
+            if counted.contains(&approver) {
+                continue;
+            }
+            approver.require_auth();
+            weight += Self::_weight(&env, &approver);
+            counted.push_back(approver);
+        }
 
-        let total_signers = signers.len() as u32;
         let required: u32 = env.storage().instance().get(&DataKey::RequiredSigners).unwrap();
-        let max_possible_approvals = total_signers - tx.rejections;
+        if weight < required {
+            return Err(TreasuryError::InsufficientApprovals);
+        }
 
-        if max_possible_approvals < required {
-            tx.status = TxStatus::Rejected;
+        env.storage().instance().remove(&DataKey::FrozenUntil);
+
+        events::publish(&env, EVENT_TAG, TreasuryUnfrozen { approvers: counted });
+        Ok(())
+    }
+
+    pub fn is_frozen(env: Env) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_is_frozen(&env)
+    }
+
+    /// Set how long a signer-triggered freeze lasts, in seconds (admin only)
+    pub fn set_freeze_cooldown(env: Env, admin: Address, cooldown: u64) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::FreezeCooldown, &cooldown);
+        Ok(())
+    }
+
+    /// Withdraw a still-pending tx (original proposer only)
+    pub fn cancel_transaction(env: Env, proposer: Address, tx_id: u64) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        proposer.require_auth();
+
+        let mut tx = Self::_load_tx(&env, tx_id)?;
+
+        if tx.proposer != proposer {
+            return Err(TreasuryError::NotProposer);
+        }
+        if tx.status != TxStatus::Pending {
+            return Err(TreasuryError::TxNotPending);
         }
 
+        tx.status = TxStatus::Cancelled;
+        Self::_close(&env, tx_id, &tx.status);
+
         let _ttl_key = DataKey::Tx(tx_id);
         env.storage().persistent().set(&_ttl_key, &tx);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, TxCancelled { tx_id, proposer });
+        Ok(())
     }
 
+    /// Deposit tokens into the treasury and record a receipt
+    pub fn deposit(
+        env: Env,
+        depositor: Address,
+        token: Address,
+        amount: i128,
+        memo: String,
+    ) -> Result<u64, TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        depositor.require_auth();
+
+        if amount <= 0 {
+            return Err(TreasuryError::InvalidAmount);
+        }
+
+        token::Client::new(&env, &token).transfer(
+            &depositor,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        let counter: u64 = env.storage().instance().get(&DataKey::DepositCounter).unwrap_or(0);
+        let deposit_id = counter + 1;
+
+        let receipt = DepositReceipt {
+            deposit_id,
+            depositor: depositor.clone(),
+            token: token.clone(),
+            amount,
+            memo,
+            deposited_at: env.ledger().timestamp(),
+        };
+        let _ttl_key = DataKey::Deposit(deposit_id);
+        env.storage().persistent().set(&_ttl_key, &receipt);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        env.storage().instance().set(&DataKey::DepositCounter, &deposit_id);
+
+        Self::_track_token(&env, &token);
+        let mut report = Self::_load_report(&env, &token);
+        report.total_inflow += amount;
+        report.deposit_count += 1;
+        Self::_save_report(&env, &report);
+
+        events::publish(&env, EVENT_TAG, Deposited { deposit_id, depositor, token, amount });
+
+        Ok(deposit_id)
+    }
+
+    pub fn get_deposit(env: Env, deposit_id: u64) -> Option<DepositReceipt> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Deposit(deposit_id))
+    }
+
+    /// Recorded inflow (deposits) and executed outflow for a token
+    pub fn get_treasury_report(env: Env, token: Address) -> TreasuryReport {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_load_report(&env, &token)
+    }
+
+    /// Current balance of `token` held by the treasury
+    pub fn get_balance(env: Env, token: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        token::Client::new(&env, &token).balance(&env.current_contract_address())
+    }
+
+    /// Every token the treasury has received through `deposit` or paid out,
+    /// in the order first seen. Balances may since have dropped to zero.
+    pub fn list_held_tokens(env: Env) -> Vec<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::HeldTokens).unwrap_or(Vec::new(&env))
+    }
+
+    /// Cap executed outflow of `token` to `amount` per `window_seconds` (admin only)
+    pub fn set_spending_limit(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+        window_seconds: u64,
+    ) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+        if amount <= 0 || window_seconds == 0 {
+            return Err(TreasuryError::InvalidLimit);
+        }
+
+        let _ttl_key = DataKey::SpendingLimit(token.clone());
+        env.storage().persistent().set(&_ttl_key, &SpendingLimit { amount, window_seconds });
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, SpendingLimitSet { token, amount, window_seconds });
+        Ok(())
+    }
+
+    pub fn remove_spending_limit(env: Env, admin: Address, token: Address) -> Result<(), TreasuryError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+
+        env.storage().persistent().remove(&DataKey::SpendingLimit(token.clone()));
+        env.storage().persistent().remove(&DataKey::SpendingWindow(token));
+        Ok(())
+    }
+
+    pub fn get_spending_limit(env: Env, token: Address) -> Option<SpendingLimit> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::SpendingLimit(token))
+    }
+
+    pub fn get_spending_window(env: Env, token: Address) -> Option<SpendingWindow> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::SpendingWindow(token))
+    }
+
+    /// Mark overdue pending transactions as Expired. Permissionless; ids that
+    /// are not pending or not yet overdue are skipped. Returns how many expired.
+    pub fn expire_transactions(env: Env, tx_ids: Vec<u64>) -> u32 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let now = env.ledger().timestamp();
+        let mut expired: u32 = 0;
+
+        for tx_id in tx_ids.iter() {
+            let key = DataKey::Tx(tx_id);
+            let tx: Option<TreasuryTx> = env.storage().persistent().get(&key);
+            if let Some(mut tx) = tx {
+                if tx.status != TxStatus::Pending || now <= tx.expires_at {
+                    continue;
+                }
+                tx.status = TxStatus::Expired;
+                env.storage().persistent().set(&key, &tx);
+                env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+                Self::_close(&env, tx_id, &tx.status);
+                expired += 1;
+
+                events::publish(&env, EVENT_TAG, TxExpired { tx_id });
+            }
+        }
+
+        expired
+    }
+
+    /// Get a transaction with its live status: an overdue Pending tx is
+    /// reported as Expired even before it has been swept.
     pub fn get_transaction(env: Env, tx_id: u64) -> Option<TreasuryTx> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        env.storage().persistent().get(&DataKey::Tx(tx_id))
+        let tx: Option<TreasuryTx> = Persistent::get(&env, &DataKey::Tx(tx_id), TX_TTL);
+        tx.map(|tx| Self::_with_live_status(&env, tx))
+    }
+
+    /// Page through open transactions still awaiting approval. At most
+    /// MAX_PAGE_SIZE are returned.
+    pub fn list_pending(env: Env, offset: u32, limit: u32) -> Vec<TreasuryTx> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let open: Vec<u64> = env.storage().persistent().get(&DataKey::OpenTxs).unwrap_or(Vec::new(&env));
+        Self::_page_by_status(&env, open.iter(), &TxStatus::Pending, offset, limit.min(MAX_PAGE_SIZE))
+    }
+
+    /// Page through transactions with the given status: open ones in creation
+    /// order, the rest in the order they reached it. Overdue transactions not
+    /// swept yet come after the swept ones under Expired. At most
+    /// MAX_PAGE_SIZE are returned.
+    pub fn list_by_status(env: Env, status: TxStatus, offset: u32, limit: u32) -> Vec<TreasuryTx> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let open: Vec<u64> = env.storage().persistent().get(&DataKey::OpenTxs).unwrap_or(Vec::new(&env));
+        if status == TxStatus::Pending || status == TxStatus::Approved {
+            return Self::_page_by_status(&env, open.iter(), &status, offset, limit);
+        }
+
+        // A closed transaction keeps its status, so its list pages by position
+        let listed: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::StatusTxs(status.clone()))
+            .unwrap_or(Vec::new(&env));
+        let start = offset.min(listed.len());
+        let end = offset.saturating_add(limit).min(listed.len());
+        let mut result = Vec::new(&env);
+        for tx_id in listed.slice(start..end).iter() {
+            if let Some(tx) = env.storage().persistent().get::<DataKey, TreasuryTx>(&DataKey::Tx(tx_id)) {
+                result.push_back(tx);
+            }
+        }
+        if status == TxStatus::Expired {
+            let skip = offset.saturating_sub(listed.len());
+            let remaining = limit - (end - start);
+            result.append(&Self::_page_by_status(&env, open.iter(), &status, skip, remaining));
+        }
+        result
     }
 
     pub fn get_signers(env: Env) -> Vec<Address> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         env.storage().instance().get(&DataKey::Signers).unwrap()
     }
+
+    /// Signers paired with their approval weight
+    pub fn get_signer_weights(env: Env) -> Vec<(Address, u32)> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let signers: Vec<Address> = env.storage().instance().get(&DataKey::Signers).unwrap();
+        let weights = Self::_weights(&env);
+        let mut result = Vec::new(&env);
+        for signer in signers.iter() {
+            let weight = weights.get(signer.clone()).unwrap_or(1);
+            result.push_back((signer, weight));
+        }
+        result
+    }
+
+    pub fn get_required_signers(env: Env) -> u32 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::RequiredSigners).unwrap()
+    }
+
+    // ============================================================
+    // TTL Maintenance
+    // ============================================================
+
+    /// Keep a transaction record from being archived. Anyone may call;
+    /// returns false if there is no such transaction.
+    pub fn extend_ttl_transaction(env: Env, tx_id: u64) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Persistent::extend(&env, &DataKey::Tx(tx_id), TX_TTL)
+    }
+
+    // ============================================================
+    // Internal Helpers
+    // ============================================================
+
+    fn _require_admin(env: &Env, admin: &Address) -> Result<(), TreasuryError> {
+        Ok(Ownable::require_owner(env, &DataKey::Admin, admin)?)
+    }
+
+    /// The registry's "dao" entry, falling back to the one set directly
+    fn _dao_contract(env: &Env) -> Option<Address> {
+        if let Some(registry) = env.storage().instance().get::<DataKey, Address>(&DataKey::Registry) {
+            let resolved: Option<Address> = env.invoke_contract(
+                &registry,
+                &Symbol::new(env, "lookup"),
+                (symbol_short!("dao"),).into_val(env),
+            );
+            if resolved.is_some() {
+                return resolved;
+            }
+        }
+        env.storage().instance().get(&DataKey::DaoContract)
+    }
+
+    fn _has_voted(env: &Env, tx_id: u64, signer: &Address) -> bool {
+        env.storage().persistent().has(&DataKey::TxApproval(tx_id, signer.clone()))
+            || env.storage().persistent().has(&DataKey::TxRejection(tx_id, signer.clone()))
+    }
+
+    fn _signers(env: &Env) -> Result<Vec<Address>, TreasuryError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Signers)
+            .ok_or(TreasuryError::NotInitialized)
+    }
+
+    /// Returns the current signer set so callers need not load it again
+    fn _require_signer(env: &Env, signer: &Address) -> Result<Vec<Address>, TreasuryError> {
+        let signers = Self::_signers(env)?;
+        if !signers.contains(signer) {
+            return Err(TreasuryError::NotSigner);
+        }
+        Ok(signers)
+    }
+
+    fn _load_tx(env: &Env, tx_id: u64) -> Result<TreasuryTx, TreasuryError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Tx(tx_id))
+            .ok_or(TreasuryError::TxNotFound)
+    }
+
+    /// Run an approved tx. It is marked executed before any token transfer or
+    /// contract call, so a re-entrant call finds it no longer approved.
+    fn _execute(env: &Env, mut tx: TreasuryTx) -> Result<(), TreasuryError> {
+        if Self::_is_frozen(env) {
+            return Err(TreasuryError::TreasuryFrozen);
+        }
+
+        // Fail on a shortfall up front rather than partway through the transfers
+        match tx.kind.clone() {
+            TxKind::Payment => Self::_require_balance(env, &tx.token, tx.amount)?,
+            TxKind::Invoke(contract, _, _) => Self::_require_unlimited_target(env, &contract)?,
+            TxKind::Batch(payments) => {
+                let mut totals: Map<Address, i128> = Map::new(env);
+                for (_, token_addr, amount) in payments.iter() {
+                    let total = totals.get(token_addr.clone()).unwrap_or(0) + amount;
+                    totals.set(token_addr, total);
+                }
+                for (token_addr, total) in totals.iter() {
+                    Self::_require_balance(env, &token_addr, total)?;
+                }
+            }
+            _ => {}
+        }
+
+        tx.status = TxStatus::Executed;
+        tx.executed_at = Some(env.ledger().timestamp());
+        Self::_close(env, tx.tx_id, &tx.status);
+        let _ttl_key = DataKey::Tx(tx.tx_id);
+        env.storage().persistent().set(&_ttl_key, &tx);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        match tx.kind.clone() {
+            TxKind::Payment => {
+                Self::_record_outflow(env, &tx.token, tx.amount)?;
+                let token_client = token::Client::new(env, &tx.token);
+                token_client.transfer(
+                    &env.current_contract_address(),
+                    &tx.recipient,
+                    &tx.amount,
+                );
+            }
+            TxKind::Invoke(contract, function, args) => {
+                env.invoke_contract::<Val>(&contract, &function, args);
+            }
+            TxKind::Batch(payments) => {
+                for (recipient, token_addr, amount) in payments.iter() {
+                    Self::_record_outflow(env, &token_addr, amount)?;
+                    token::Client::new(env, &token_addr).transfer(
+                        &env.current_contract_address(),
+                        &recipient,
+                        &amount,
+                    );
+                    events::publish(
+                        env,
+                        EVENT_TAG,
+                        BatchPayment { tx_id: tx.tx_id, recipient, token: token_addr, amount },
+                    );
+                }
+            }
+            kind => Self::_apply_signer_change(env, &kind)?,
+        }
+
+        events::publish(env, EVENT_TAG, TxExecuted { tx_id: tx.tx_id, amount: tx.amount });
+        Ok(())
+    }
+
+    /// Arbitrary calls can't be metered, so a token under a spending limit may
+    /// only be moved through payments. Checked again at execution in case the
+    /// limit was set after the call was proposed.
+    fn _require_unlimited_target(env: &Env, contract: &Address) -> Result<(), TreasuryError> {
+        if env.storage().persistent().has(&DataKey::SpendingLimit(contract.clone())) {
+            return Err(TreasuryError::LimitedTokenCall);
+        }
+        Ok(())
+    }
+
+    fn _require_balance(env: &Env, token: &Address, amount: i128) -> Result<(), TreasuryError> {
+        if token::Client::new(env, token).balance(&env.current_contract_address()) < amount {
+            return Err(TreasuryError::InsufficientTreasuryBalance);
+        }
+        Ok(())
+    }
+
+    fn _load_report(env: &Env, token: &Address) -> TreasuryReport {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Report(token.clone()))
+            .unwrap_or(TreasuryReport {
+                token: token.clone(),
+                total_inflow: 0,
+                total_outflow: 0,
+                deposit_count: 0,
+            })
+    }
+
+    fn _save_report(env: &Env, report: &TreasuryReport) {
+        let _ttl_key = DataKey::Report(report.token.clone());
+        env.storage().persistent().set(&_ttl_key, report);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _record_outflow(env: &Env, token: &Address, amount: i128) -> Result<(), TreasuryError> {
+        Self::_track_token(env, token);
+        let mut report = Self::_load_report(env, token);
+        report.total_outflow += amount;
+        Self::_save_report(env, &report);
+
+        let limit: SpendingLimit = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::SpendingLimit(token.clone()))
+        {
+            Some(l) => l,
+            None => return Ok(()),
+        };
+
+        let now = env.ledger().timestamp();
+        let key = DataKey::SpendingWindow(token.clone());
+        let mut window: SpendingWindow = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(SpendingWindow { window_start: now, spent: 0 });
+
+        if now >= window.window_start + limit.window_seconds {
+            window = SpendingWindow { window_start: now, spent: 0 };
+        }
+
+        window.spent += amount;
+        if window.spent > limit.amount {
+            return Err(TreasuryError::SpendingLimitExceeded);
+        }
+
+        env.storage().persistent().set(&key, &window);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Ok(())
+    }
+
+    fn _track_token(env: &Env, token: &Address) {
+        let mut held: Vec<Address> = env.storage().persistent().get(&DataKey::HeldTokens).unwrap_or(Vec::new(env));
+        if !held.contains(token) {
+            held.push_back(token.clone());
+            env.storage().persistent().set(&DataKey::HeldTokens, &held);
+        }
+        env.storage().persistent().extend_ttl(&DataKey::HeldTokens, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _with_live_status(env: &Env, mut tx: TreasuryTx) -> TreasuryTx {
+        if tx.status == TxStatus::Pending && env.ledger().timestamp() > tx.expires_at {
+            tx.status = TxStatus::Expired;
+        }
+        tx
+    }
+
+    fn _is_frozen(env: &Env) -> bool {
+        let frozen_until: u64 = env.storage().instance().get(&DataKey::FrozenUntil).unwrap_or(0);
+        env.ledger().timestamp() < frozen_until
+    }
+
+    fn _weights(env: &Env) -> Map<Address, u32> {
+        env.storage().instance().get(&DataKey::SignerWeights).unwrap_or(Map::new(env))
+    }
+
+    fn _weight(env: &Env, signer: &Address) -> u32 {
+        Self::_weights(env).get(signer.clone()).unwrap_or(1)
+    }
+
+    fn _total_weight(env: &Env, signers: &Vec<Address>) -> u32 {
+        let weights = Self::_weights(env);
+        let mut total: u32 = 0;
+        for signer in signers.iter() {
+            total += weights.get(signer).unwrap_or(1);
+        }
+        total
+    }
+
+    fn _add_open(env: &Env, tx_id: u64) {
+        let mut open: Vec<u64> = env.storage().persistent().get(&DataKey::OpenTxs).unwrap_or(Vec::new(env));
+        open.push_back(tx_id);
+        env.storage().persistent().set(&DataKey::OpenTxs, &open);
+        env.storage().persistent().extend_ttl(&DataKey::OpenTxs, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    /// Move `tx_id` off the open list onto the list for the status it ended in
+    fn _close(env: &Env, tx_id: u64, status: &TxStatus) {
+        let mut open: Vec<u64> = env.storage().persistent().get(&DataKey::OpenTxs).unwrap_or(Vec::new(env));
+        if let Some(idx) = open.first_index_of(tx_id) {
+            open.remove(idx);
+            env.storage().persistent().set(&DataKey::OpenTxs, &open);
+        }
+
+        let key = DataKey::StatusTxs(status.clone());
+        let mut closed: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        closed.push_back(tx_id);
+        env.storage().persistent().set(&key, &closed);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _page_by_status(
+        env: &Env,
+        tx_ids: impl Iterator<Item = u64>,
+        status: &TxStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<TreasuryTx> {
+        let mut result = Vec::new(env);
+        let mut skipped: u32 = 0;
+        for tx_id in tx_ids {
+            if result.len() >= limit {
+                break;
+            }
+            let tx: Option<TreasuryTx> = env.storage().persistent().get(&DataKey::Tx(tx_id));
+            if let Some(tx) = tx {
+                let tx = Self::_with_live_status(env, tx);
+                if tx.status != *status {
+                    continue;
+                }
+                if skipped < offset {
+                    skipped += 1;
+                    continue;
+                }
+                result.push_back(tx);
+            }
+        }
+        result
+    }
+
+    fn _store_new_tx(
+        env: &Env,
+        kind: TxKind,
+        proposer: Address,
+        description: String,
+        expires_in: u64,
+    ) -> u64 {
+        let counter: u64 = env.storage().instance().get(&DataKey::TxCounter).unwrap_or(0);
+        let tx_id = counter + 1;
+        let required: u32 = env.storage().instance().get(&DataKey::RequiredSigners).unwrap();
+
+        let tx = TreasuryTx {
+            tx_id,
+            kind,
+            proposer: proposer.clone(),
+            recipient: env.current_contract_address(),
+            token: env.current_contract_address(),
+            amount: 0,
+            description,
+            status: TxStatus::Pending,
+            approvals: 0,
+            rejections: 0,
+            required_approvals: required,
+            created_at: env.ledger().timestamp(),
+            expires_at: env.ledger().timestamp() + expires_in,
+            approved_at: None,
+            executed_at: None,
+        };
+
+        let _ttl_key = DataKey::Tx(tx_id);
+        env.storage().persistent().set(&_ttl_key, &tx);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        env.storage().instance().set(&DataKey::TxCounter, &tx_id);
+        Self::_add_open(env, tx_id);
+
+        events::publish(env, EVENT_TAG, TxProposed { tx_id, proposer });
+
+        tx_id
+    }
+
+    fn _validate_signer_change(
+        env: &Env,
+        signers: &Vec<Address>,
+        required: u32,
+        kind: &TxKind,
+    ) -> Result<(), TreasuryError> {
+        let total_weight = Self::_total_weight(env, signers);
+        match kind {
+            TxKind::Payment | TxKind::Invoke(..) | TxKind::Batch(..) => {
+                return Err(TreasuryError::NotSignerChange);
+            }
+            TxKind::AddSigner(signer) => {
+                if signers.contains(signer) {
+                    return Err(TreasuryError::AlreadySigner);
+                }
+            }
+            TxKind::RemoveSigner(signer) => {
+                if !signers.contains(signer) {
+                    return Err(TreasuryError::NotSigner);
+                }
+                if total_weight - Self::_weight(env, signer) < required {
+                    return Err(TreasuryError::WouldDropBelowThreshold);
+                }
+            }
+            TxKind::ChangeThreshold(new_required) => {
+                if *new_required == 0 || *new_required > total_weight {
+                    return Err(TreasuryError::InvalidRequiredSigners);
+                }
+            }
+            TxKind::SetSignerWeight(signer, weight) => {
+                if !signers.contains(signer) {
+                    return Err(TreasuryError::NotSigner);
+                }
+                if *weight == 0 {
+                    return Err(TreasuryError::InvalidWeight);
+                }
+                if total_weight - Self::_weight(env, signer) + weight < required {
+                    return Err(TreasuryError::WouldDropBelowThreshold);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn _apply_signer_change(env: &Env, kind: &TxKind) -> Result<(), TreasuryError> {
+        let mut signers = Self::_signers(env)?;
+        let required: u32 = env.storage().instance().get(&DataKey::RequiredSigners).unwrap();
+        // The signer set may have changed since the proposal was made
+        Self::_validate_signer_change(env, &signers, required, kind)?;
+
+        match kind {
+            TxKind::AddSigner(signer) => {
+                signers.push_back(signer.clone());
+                env.storage().instance().set(&DataKey::Signers, &signers);
+                events::publish(
+                    env,
+                    EVENT_TAG,
+                    RoleGranted { role: symbol_short!("signer"), account: signer.clone() },
+                );
+            }
+            TxKind::RemoveSigner(signer) => {
+                if let Some(idx) = signers.first_index_of(signer) {
+                    signers.remove(idx);
+                }
+                env.storage().instance().set(&DataKey::Signers, &signers);
+                let mut weights = Self::_weights(env);
+                weights.remove(signer.clone());
+                env.storage().instance().set(&DataKey::SignerWeights, &weights);
+                events::publish(
+                    env,
+                    EVENT_TAG,
+                    RoleRevoked { role: symbol_short!("signer"), account: signer.clone() },
+                );
+            }
+            TxKind::SetSignerWeight(signer, weight) => {
+                let mut weights = Self::_weights(env);
+                weights.set(signer.clone(), *weight);
+                env.storage().instance().set(&DataKey::SignerWeights, &weights);
+                events::publish(env, EVENT_TAG, SignerWeightSet { signer: signer.clone(), weight: *weight });
+            }
+            TxKind::ChangeThreshold(new_required) => {
+                env.storage().instance().set(&DataKey::RequiredSigners, new_required);
+                events::publish(
+                    env,
+                    EVENT_TAG,
+                    ThresholdChanged { old_required: required, new_required: *new_required },
+                );
+            }
+            TxKind::Payment | TxKind::Invoke(..) | TxKind::Batch(..) => {}
+        }
+        Ok(())
+    }
 }
 
 mod test;
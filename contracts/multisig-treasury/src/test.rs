@@ -1,6 +1,6 @@
 #![cfg(test)]
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, vec, String};
+use soroban_sdk::{contract, contractimpl, testutils::{storage::Persistent as _, Address as _, Events, Ledger}, Address, Env, IntoVal, Symbol, TryFromVal, vec, String};
 
 #[test]
 fn test_initialize() {
@@ -18,7 +18,7 @@ fn test_initialize() {
 }
 
 #[test]
-#[should_panic(expected = "already initialized")]
+#[should_panic(expected = "Error(Contract, #201)")]
 fn test_initialize_twice() {
     let env = Env::default();
     env.mock_all_auths();
@@ -49,3 +49,877 @@ fn test_initialize_non_admin_fails() {
     // This should panic because admin didn't authorize it and we haven't mocked it
     client.initialize(&admin, &initial_signers, &required);
 }
+
+#[test]
+fn test_signer_management_proposals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let s3 = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone(), s2.clone()], &2);
+
+    let desc = String::from_str(&env, "add s3");
+    let tx_id = client.propose_signer_change(&s1, &TxKind::AddSigner(s3.clone()), &desc, &3600);
+    client.approve_transaction(&s1, &tx_id);
+    client.approve_transaction(&s2, &tx_id);
+    client.execute_transaction(&s1, &tx_id);
+    assert_eq!(client.get_signers().len(), 3);
+
+    let desc = String::from_str(&env, "raise threshold");
+    let tx_id = client.propose_signer_change(&s3, &TxKind::ChangeThreshold(3), &desc, &3600);
+    client.approve_transaction(&s1, &tx_id);
+    client.approve_transaction(&s3, &tx_id);
+    client.execute_transaction(&s3, &tx_id);
+    assert_eq!(client.get_required_signers(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #219)")]
+fn test_remove_signer_below_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone(), s2.clone()], &2);
+
+    let desc = String::from_str(&env, "remove s2");
+    client.propose_signer_change(&s1, &TxKind::RemoveSigner(s2), &desc, &3600);
+}
+
+#[contract]
+pub struct CallTarget;
+
+#[contractimpl]
+impl CallTarget {
+    pub fn set_fee(env: Env, fee: u32) {
+        env.storage().instance().set(&symbol_short!("fee"), &fee);
+    }
+
+    pub fn fee(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("fee")).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_cross_contract_call_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+    let target_id = env.register_contract(None, CallTarget);
+    let target = CallTargetClient::new(&env, &target_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone(), s2.clone()], &2);
+
+    let args: Vec<Val> = vec![&env, 250u32.into_val(&env)];
+    let desc = String::from_str(&env, "set fee");
+    let tx_id = client.propose_call(
+        &s1,
+        &target_id,
+        &Symbol::new(&env, "set_fee"),
+        &args,
+        &desc,
+        &3600,
+    );
+    client.approve_transaction(&s1, &tx_id);
+    client.approve_transaction(&s2, &tx_id);
+    client.execute_transaction(&s2, &tx_id);
+
+    assert_eq!(target.fee(), 250);
+    assert!(client.get_transaction(&tx_id).unwrap().status == TxStatus::Executed);
+}
+
+fn event_topics(env: &Env, action: &str) -> soroban_sdk::Vec<soroban_sdk::Val> {
+    (Symbol::new(env, "treasury"), Symbol::new(env, action), 1u32).into_val(env)
+}
+
+fn create_funded_token(env: &Env, holder: &Address, amount: i128) -> Address {
+    let token_admin = Address::generate(env);
+    let token_id = env.register_stellar_asset_contract_v2(token_admin).address();
+    token::StellarAssetClient::new(env, &token_id).mint(holder, &amount);
+    token_id
+}
+
+#[test]
+fn test_spending_limit_enforced_per_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone()], &1);
+
+    let token_id = create_funded_token(&env, &contract_id, 10_000);
+    client.set_spending_limit(&admin, &token_id, &1_000, &86_400);
+
+    let desc = String::from_str(&env, "payout");
+    let tx1 = client.propose_transaction(&s1, &recipient, &token_id, &800, &desc, &3600);
+    client.approve_transaction(&s1, &tx1);
+    client.execute_transaction(&s1, &tx1);
+
+    let tx2 = client.propose_transaction(&s1, &recipient, &token_id, &300, &desc, &172_800);
+    client.approve_transaction(&s1, &tx2);
+    assert!(client.try_execute_transaction(&s1, &tx2).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    client.execute_transaction(&s1, &tx2);
+    assert_eq!(token::Client::new(&env, &token_id).balance(&recipient), 1_100);
+}
+
+#[test]
+fn test_execution_delay_and_emergency_execute() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone(), s2.clone()], &2);
+    client.set_execution_delay(&admin, &3_600);
+
+    let token_id = create_funded_token(&env, &contract_id, 10_000);
+    let desc = String::from_str(&env, "grant");
+
+    let tx1 = client.propose_transaction(&s1, &recipient, &token_id, &500, &desc, &86_400);
+    client.approve_transaction(&s1, &tx1);
+    client.approve_transaction(&s2, &tx1);
+    assert!(client.try_execute_transaction(&s1, &tx1).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    client.execute_transaction(&s1, &tx1);
+
+    let tx2 = client.propose_transaction(&s1, &recipient, &token_id, &500, &desc, &86_400);
+    client.approve_transaction(&s1, &tx2);
+    client.approve_transaction(&s2, &tx2);
+    client.emergency_execute(&tx2);
+
+    assert_eq!(token::Client::new(&env, &token_id).balance(&recipient), 1_000);
+}
+
+#[test]
+fn test_revoke_approval_returns_to_pending() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone(), s2.clone()], &2);
+
+    let token_id = create_funded_token(&env, &contract_id, 10_000);
+    let desc = String::from_str(&env, "payout");
+    let tx_id = client.propose_transaction(&s1, &recipient, &token_id, &500, &desc, &3600);
+    client.approve_transaction(&s1, &tx_id);
+    client.approve_transaction(&s2, &tx_id);
+    assert!(client.get_transaction(&tx_id).unwrap().status == TxStatus::Approved);
+
+    client.revoke_approval(&s2, &tx_id);
+    let tx = client.get_transaction(&tx_id).unwrap();
+    assert!(tx.status == TxStatus::Pending);
+    assert_eq!(tx.approvals, 1);
+    assert!(client.try_execute_transaction(&s1, &tx_id).is_err());
+}
+
+#[test]
+fn test_batch_payment_executes_all() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone()], &1);
+
+    let token_id = create_funded_token(&env, &contract_id, 10_000);
+    let payments = vec![
+        &env,
+        (alice.clone(), token_id.clone(), 300i128),
+        (bob.clone(), token_id.clone(), 700i128),
+    ];
+    let desc = String::from_str(&env, "payroll");
+    let tx_id = client.propose_batch(&s1, &payments, &desc, &3600);
+    client.approve_transaction(&s1, &tx_id);
+    client.execute_transaction(&s1, &tx_id);
+
+    let token_client = token::Client::new(&env, &token_id);
+    assert_eq!(token_client.balance(&alice), 300);
+    assert_eq!(token_client.balance(&bob), 700);
+    assert_eq!(token_client.balance(&contract_id), 9_000);
+}
+
+#[test]
+fn test_deposit_and_treasury_report() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone()], &1);
+
+    let token_id = create_funded_token(&env, &donor, 5_000);
+    let deposit_id = client.deposit(&donor, &token_id, &2_000, &String::from_str(&env, "q3 fees"));
+    assert_eq!(client.get_deposit(&deposit_id).unwrap().amount, 2_000);
+
+    let desc = String::from_str(&env, "payout");
+    let tx_id = client.propose_transaction(&s1, &recipient, &token_id, &500, &desc, &3600);
+    client.approve_transaction(&s1, &tx_id);
+    client.execute_transaction(&s1, &tx_id);
+
+    let report = client.get_treasury_report(&token_id);
+    assert_eq!(report.total_inflow, 2_000);
+    assert_eq!(report.total_outflow, 500);
+    assert_eq!(report.deposit_count, 1);
+}
+
+#[test]
+fn test_balance_and_held_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone()], &1);
+    assert_eq!(client.list_held_tokens().len(), 0);
+
+    let usdc = create_funded_token(&env, &donor, 5_000);
+    let xlm = create_funded_token(&env, &contract_id, 800);
+    client.deposit(&donor, &usdc, &2_000, &String::from_str(&env, "fees"));
+    client.deposit(&donor, &usdc, &1_000, &String::from_str(&env, "more fees"));
+    assert_eq!(client.get_balance(&usdc), 3_000);
+    assert_eq!(client.get_balance(&xlm), 800);
+    assert_eq!(client.list_held_tokens(), vec![&env, usdc.clone()]);
+
+    let desc = String::from_str(&env, "payout");
+    let tx_id = client.propose_transaction(&s1, &recipient, &xlm, &800, &desc, &3600);
+    client.approve_transaction(&s1, &tx_id);
+    client.execute_transaction(&s1, &tx_id);
+
+    assert_eq!(client.get_balance(&xlm), 0);
+    assert_eq!(client.list_held_tokens(), vec![&env, usdc, xlm]);
+}
+
+#[test]
+fn test_list_pending_and_by_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone(), s2.clone()], &2);
+
+    let token_id = create_funded_token(&env, &contract_id, 10_000);
+    let desc = String::from_str(&env, "payout");
+    let tx1 = client.propose_transaction(&s1, &recipient, &token_id, &100, &desc, &3600);
+    let tx2 = client.propose_transaction(&s1, &recipient, &token_id, &200, &desc, &3600);
+    let tx3 = client.propose_transaction(&s1, &recipient, &token_id, &300, &desc, &3600);
+
+    client.approve_transaction(&s1, &tx1);
+    client.approve_transaction(&s2, &tx1);
+    client.execute_transaction(&s1, &tx1);
+
+    let pending = client.list_pending(&0, &10);
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending.get(0).unwrap().tx_id, tx2);
+
+    let page = client.list_pending(&1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().tx_id, tx3);
+
+    let executed = client.list_by_status(&TxStatus::Executed, &0, &10);
+    assert_eq!(executed.len(), 1);
+    assert_eq!(executed.get(0).unwrap().tx_id, tx1);
+
+    // Closed transactions are listed in the order they closed
+    client.cancel_transaction(&s1, &tx3);
+    client.cancel_transaction(&s1, &tx2);
+    let cancelled = client.list_by_status(&TxStatus::Cancelled, &0, &10);
+    assert_eq!(cancelled.len(), 2);
+    assert_eq!(cancelled.get(0).unwrap().tx_id, tx3);
+    assert_eq!(client.list_by_status(&TxStatus::Cancelled, &1, &10).get(0).unwrap().tx_id, tx2);
+    assert_eq!(client.list_pending(&0, &10).len(), 0);
+
+    // Pages are capped however large a limit is asked for
+    for _ in 0..51 {
+        client.propose_transaction(&s1, &recipient, &token_id, &1, &desc, &3600);
+    }
+    assert_eq!(client.list_pending(&0, &u32::MAX).len(), 50);
+    assert_eq!(client.list_by_status(&TxStatus::Pending, &0, &u32::MAX).len(), 50);
+    assert_eq!(client.list_pending(&50, &u32::MAX).len(), 1);
+}
+
+#[test]
+fn test_weighted_signers_reach_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let founder = Address::generate(&env);
+    let ops1 = Address::generate(&env);
+    let ops2 = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, founder.clone(), ops1.clone(), ops2.clone()], &2);
+
+    // Existing equal-weight signers default to weight 1
+    let desc = String::from_str(&env, "founder weight");
+    let tx_id = client.propose_signer_change(&founder, &TxKind::SetSignerWeight(founder.clone(), 2), &desc, &3600);
+    client.approve_transaction(&ops1, &tx_id);
+    client.approve_transaction(&ops2, &tx_id);
+    client.execute_transaction(&ops1, &tx_id);
+
+    let weights = client.get_signer_weights();
+    assert_eq!(weights.get(0).unwrap(), (founder.clone(), 2));
+
+    // The founder alone now meets the threshold of 2
+    let token_id = create_funded_token(&env, &contract_id, 1_000);
+    let tx_id = client.propose_transaction(&ops1, &recipient, &token_id, &100, &desc, &3600);
+    client.approve_transaction(&founder, &tx_id);
+    assert!(client.get_transaction(&tx_id).unwrap().status == TxStatus::Approved);
+
+    // A single weight-1 approval does not
+    let tx_id = client.propose_transaction(&ops1, &recipient, &token_id, &100, &desc, &3600);
+    client.approve_transaction(&ops1, &tx_id);
+    assert!(client.get_transaction(&tx_id).unwrap().status == TxStatus::Pending);
+}
+
+#[test]
+fn test_cancel_transaction_by_proposer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone(), s2.clone()], &2);
+
+    let token_id = create_funded_token(&env, &contract_id, 1_000);
+    let desc = String::from_str(&env, "obsolete");
+    let tx_id = client.propose_transaction(&s1, &recipient, &token_id, &100, &desc, &3600);
+
+    assert_eq!(client.try_cancel_transaction(&s2, &tx_id), Err(Ok(TreasuryError::NotProposer)));
+    client.cancel_transaction(&s1, &tx_id);
+
+    assert!(client.get_transaction(&tx_id).unwrap().status == TxStatus::Cancelled);
+    assert_eq!(client.list_pending(&0, &10).len(), 0);
+    assert_eq!(client.try_approve_transaction(&s2, &tx_id), Err(Ok(TreasuryError::TxNotPending)));
+}
+
+#[test]
+fn test_freeze_blocks_execution_until_unfrozen() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone(), s2.clone()], &2);
+
+    let token_id = create_funded_token(&env, &contract_id, 1_000);
+    let desc = String::from_str(&env, "payout");
+    let tx_id = client.propose_transaction(&s1, &recipient, &token_id, &100, &desc, &3600);
+    client.approve_transaction(&s1, &tx_id);
+    client.approve_transaction(&s2, &tx_id);
+
+    client.freeze(&s2);
+    assert!(client.is_frozen());
+    assert!(client.try_execute_transaction(&s1, &tx_id).is_err());
+
+    assert!(client.try_unfreeze(&vec![&env, s1.clone()]).is_err());
+    client.unfreeze(&vec![&env, s1.clone(), s2.clone()]);
+    assert!(!client.is_frozen());
+    client.execute_transaction(&s1, &tx_id);
+}
+
+#[test]
+fn test_expire_transactions_sweep() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone(), s2.clone()], &2);
+
+    let token_id = create_funded_token(&env, &contract_id, 1_000);
+    let desc = String::from_str(&env, "payout");
+    let short = client.propose_transaction(&s1, &recipient, &token_id, &100, &desc, &60);
+    let long = client.propose_transaction(&s1, &recipient, &token_id, &100, &desc, &86_400);
+
+    env.ledger().with_mut(|li| li.timestamp += 120);
+
+    // Live status is reported before the sweep
+    assert!(client.get_transaction(&short).unwrap().status == TxStatus::Expired);
+    assert_eq!(client.list_pending(&0, &10).len(), 1);
+
+    assert_eq!(client.list_by_status(&TxStatus::Expired, &0, &10).get(0).unwrap().tx_id, short);
+
+    assert_eq!(client.expire_transactions(&vec![&env, short, long]), 1);
+    assert!(client.get_transaction(&long).unwrap().status == TxStatus::Pending);
+    assert_eq!(client.list_by_status(&TxStatus::Expired, &0, &10).len(), 1);
+
+    // Swept transactions come first, then overdue ones not swept yet
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    let expired = client.list_by_status(&TxStatus::Expired, &0, &10);
+    assert_eq!(expired.len(), 2);
+    assert_eq!(expired.get(1).unwrap().tx_id, long);
+    assert_eq!(client.list_by_status(&TxStatus::Expired, &1, &10).get(0).unwrap().tx_id, long);
+    assert_eq!(client.list_by_status(&TxStatus::Expired, &0, &1).len(), 1);
+}
+
+#[test]
+fn test_dao_directed_payment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let dao = Address::generate(&env);
+    let impostor = Address::generate(&env);
+    let grantee = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone()], &1);
+
+    let token_id = create_funded_token(&env, &contract_id, 10_000);
+    assert_eq!(
+        client.try_execute_dao_payment(&dao, &grantee, &token_id, &5_000),
+        Err(Ok(TreasuryError::DaoNotConfigured))
+    );
+    client.set_dao_contract(&admin, &dao);
+
+    // Only the trusted DAO can direct a payment, and only from funds on hand
+    assert_eq!(
+        client.try_execute_dao_payment(&impostor, &grantee, &token_id, &5_000),
+        Err(Ok(TreasuryError::UntrustedDao))
+    );
+    assert_eq!(
+        client.try_execute_dao_payment(&dao, &grantee, &token_id, &20_000),
+        Err(Ok(TreasuryError::InsufficientTreasuryBalance))
+    );
+    client.execute_dao_payment(&dao, &grantee, &token_id, &5_000);
+    assert_eq!(token::Client::new(&env, &token_id).balance(&grantee), 5_000);
+    assert_eq!(client.get_treasury_report(&token_id).total_outflow, 5_000);
+}
+
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_address(env: Env, key: Symbol, address: Address) {
+        env.storage().instance().set(&key, &address);
+    }
+
+    pub fn lookup(env: Env, key: Symbol) -> Option<Address> {
+        env.storage().instance().get(&key)
+    }
+}
+
+#[test]
+fn test_dao_resolved_through_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let old_dao = Address::generate(&env);
+    let new_dao = Address::generate(&env);
+    let grantee = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, Address::generate(&env)], &1);
+    client.set_dao_contract(&admin, &old_dao);
+    let token_id = create_funded_token(&env, &contract_id, 10_000);
+
+    let registry_id = env.register_contract(None, MockRegistry);
+    let registry = MockRegistryClient::new(&env, &registry_id);
+    client.set_registry(&admin, &registry_id);
+
+    // No "dao" entry yet: the DAO set directly is still trusted
+    assert_eq!(client.get_dao_contract(), Some(old_dao.clone()));
+    client.execute_dao_payment(&old_dao, &grantee, &token_id, &1_000);
+
+    registry.set_address(&Symbol::new(&env, "dao"), &new_dao);
+    assert_eq!(client.get_dao_contract(), Some(new_dao.clone()));
+    assert_eq!(
+        client.try_execute_dao_payment(&old_dao, &grantee, &token_id, &1_000),
+        Err(Ok(TreasuryError::UntrustedDao))
+    );
+    client.execute_dao_payment(&new_dao, &grantee, &token_id, &1_000);
+    assert_eq!(token::Client::new(&env, &token_id).balance(&grantee), 2_000);
+}
+
+// Voting power source for the real governance DAO
+#[contract]
+pub struct MockVotesToken;
+
+#[contractimpl]
+impl MockVotesToken {
+    pub fn set_votes(env: Env, account: Address, ledger: u32, votes: i128) {
+        env.storage().persistent().set(&(account, ledger), &votes);
+    }
+
+    pub fn get_past_votes(env: Env, account: Address, ledger: u32) -> i128 {
+        env.storage().persistent().get(&(account, ledger)).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_dao_grant_paid_by_executing_proposal() {
+    use pulsar_governance_dao::{
+        GovernanceDaoContract, GovernanceDaoContractClient, ProposalAction, ProposalKind, VoteChoice,
+    };
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+    let votes_id = env.register_contract(None, MockVotesToken);
+    let dao_id = env.register_contract(None, GovernanceDaoContract);
+    let dao = GovernanceDaoContractClient::new(&env, &dao_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let voter = Address::generate(&env);
+    let grantee = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone()], &1);
+    client.set_dao_contract(&admin, &dao_id);
+    dao.initialize(&admin, &votes_id, &100u32, &100i128, &51u32, &0i128);
+    dao.set_timelock_delay(&admin, &3_600);
+    MockVotesTokenClient::new(&env, &votes_id).set_votes(&voter, &env.ledger().sequence(), &200);
+
+    let token_id = create_funded_token(&env, &contract_id, 10_000);
+    let action = ProposalAction {
+        target: contract_id.clone(),
+        function: Symbol::new(&env, "execute_dao_payment"),
+        args: (dao_id.clone(), grantee.clone(), token_id.clone(), 4_000i128).into_val(&env),
+    };
+    let id = dao.create_proposal(
+        &Address::generate(&env),
+        &String::from_str(&env, "Grant"),
+        &String::from_str(&env, "Fund the indexer"),
+        &ProposalKind::TreasurySpend,
+        &Some(action),
+    );
+    dao.cast_vote(&voter, &id, &VoteChoice::For);
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    dao.finalize_proposal(&Address::generate(&env), &id);
+    dao.queue_proposal(&id);
+
+    // Nothing moves until the DAO executes the proposal after its timelock
+    assert_eq!(token::Client::new(&env, &token_id).balance(&grantee), 0);
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    dao.execute_proposal(&id);
+    assert_eq!(token::Client::new(&env, &token_id).balance(&grantee), 4_000);
+    assert_eq!(client.get_treasury_report(&token_id).total_outflow, 4_000);
+
+    // The executed proposal can't pay out twice
+    assert!(dao.try_execute_proposal(&id).is_err());
+    assert_eq!(token::Client::new(&env, &token_id).balance(&grantee), 4_000);
+}
+
+#[test]
+fn test_lifecycle_events_and_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let s3 = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone(), s2.clone()], &2);
+
+    let token_id = create_funded_token(&env, &contract_id, 1_000);
+    let desc = String::from_str(&env, "payout");
+    assert_eq!(
+        client.try_propose_transaction(&outsider, &recipient, &token_id, &100, &desc, &3600),
+        Err(Ok(TreasuryError::NotSigner))
+    );
+    let tx_id = client.propose_transaction(&s1, &recipient, &token_id, &100, &desc, &3600);
+
+    client.approve_transaction(&s1, &tx_id);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, event_topics(&env, "tx_approved"));
+    let ev: TxApproved = data.into_val(&env);
+    assert_eq!((ev.tx_id, ev.signer, ev.approvals), (tx_id, s1.clone(), 1));
+    assert!(ev.status == TxStatus::Pending);
+    assert_eq!(client.try_approve_transaction(&s1, &tx_id), Err(Ok(TreasuryError::AlreadyVoted)));
+
+    client.reject_transaction(&s2, &tx_id);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, event_topics(&env, "tx_rejected"));
+    let ev: TxRejected = data.into_val(&env);
+    assert_eq!(ev.rejections, 1);
+    assert!(ev.status == TxStatus::Rejected);
+    assert_eq!(client.try_execute_transaction(&s1, &tx_id), Err(Ok(TreasuryError::TxNotApproved)));
+
+    let desc = String::from_str(&env, "add s3");
+    let tx_id = client.propose_signer_change(&s1, &TxKind::AddSigner(s3.clone()), &desc, &3600);
+    client.approve_transaction(&s1, &tx_id);
+    client.approve_transaction(&s2, &tx_id);
+    client.execute_transaction(&s1, &tx_id);
+    let signer_added = env.events().all().iter().any(|(_, topics, data)| {
+        topics == event_topics(&env, "role_granted")
+            && RoleGranted::try_from_val(&env, &data).ok()
+                == Some(RoleGranted { role: symbol_short!("signer"), account: s3.clone() })
+    });
+    assert!(signer_added);
+
+    let desc = String::from_str(&env, "stale");
+    let tx_id = client.propose_transaction(&s1, &recipient, &token_id, &100, &desc, &60);
+    env.ledger().with_mut(|li| li.timestamp += 61);
+    assert_eq!(client.try_approve_transaction(&s2, &tx_id), Err(Ok(TreasuryError::TxExpired)));
+    client.expire_transactions(&vec![&env, tx_id]);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, event_topics(&env, "tx_expired"));
+    let ev: TxExpired = data.into_val(&env);
+    assert_eq!(ev.tx_id, tx_id);
+}
+
+#[test]
+fn test_rejections_recorded_per_signer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let s3 = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone(), s2.clone(), s3.clone()], &2);
+
+    let token_id = create_funded_token(&env, &contract_id, 1_000);
+    let desc = String::from_str(&env, "payout");
+    let tx_id = client.propose_transaction(&s1, &recipient, &token_id, &100, &desc, &3600);
+
+    // Lowering the threshold afterwards doesn't change what this tx needs
+    let desc = String::from_str(&env, "threshold 1");
+    let change = client.propose_signer_change(&s1, &TxKind::ChangeThreshold(1), &desc, &3600);
+    client.approve_transaction(&s1, &change);
+    client.approve_transaction(&s2, &change);
+    client.execute_transaction(&s1, &change);
+    assert_eq!(client.get_required_signers(), 1);
+
+    // A signer votes once, either way
+    client.reject_transaction(&s1, &tx_id);
+    assert_eq!(client.try_reject_transaction(&s1, &tx_id), Err(Ok(TreasuryError::AlreadyVoted)));
+    assert_eq!(client.try_approve_transaction(&s1, &tx_id), Err(Ok(TreasuryError::AlreadyVoted)));
+    client.approve_transaction(&s2, &tx_id);
+    assert_eq!(client.try_reject_transaction(&s2, &tx_id), Err(Ok(TreasuryError::AlreadyVoted)));
+    let tx = client.get_transaction(&tx_id).unwrap();
+    assert_eq!((tx.approvals, tx.rejections), (1, 1));
+    assert!(tx.status == TxStatus::Pending);
+
+    // Two of three rejecting leaves too little weight for the tx's threshold of 2
+    client.reject_transaction(&s3, &tx_id);
+    assert!(client.get_transaction(&tx_id).unwrap().status == TxStatus::Rejected);
+}
+
+#[test]
+fn test_upgrade_authority_hand_off_to_dao() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let dao = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, admin.clone()], &1);
+
+    let version = client.get_version();
+    assert_eq!(version.version, 1);
+    assert!(version.wasm_hash.is_none());
+    assert_eq!(client.get_upgrade_authority(), Some(admin.clone()));
+
+    client.set_upgrade_authority(&admin, &dao);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "treasury"), Symbol::new(&env, "upgrade_authority"), 1u32).into_val(&env));
+    let ev: pulsartrack_events::UpgradeAuthoritySet = data.into_val(&env);
+    assert_eq!((ev.previous, ev.authority), (admin.clone(), dao.clone()));
+
+    // Only the DAO can move it from here
+    assert_eq!(client.try_set_upgrade_authority(&admin, &admin), Err(Ok(TreasuryError::Unauthorized)));
+    client.set_upgrade_authority(&dao, &admin);
+    assert_eq!(client.get_upgrade_authority(), Some(admin));
+
+    assert_eq!(client.try_migrate(&1), Err(Ok(TreasuryError::AlreadyMigrated)));
+}
+
+#[test]
+fn test_extend_ttl_transaction() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone()], &1);
+
+    let token_id = create_funded_token(&env, &contract_id, 1_000);
+    let tx_id = client.propose_transaction(&s1, &admin, &token_id, &100, &String::from_str(&env, "payout"), &3600);
+
+    let ttl = || env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&DataKey::Tx(tx_id)));
+    assert_eq!(ttl(), TX_TTL.extend_to);
+
+    for _ in 0..12 {
+        env.ledger().with_mut(|li| li.sequence_number += 80_000);
+        client.get_version();
+    }
+    assert!(ttl() < TX_TTL.threshold);
+
+    assert!(client.extend_ttl_transaction(&tx_id));
+    assert_eq!(ttl(), TX_TTL.extend_to);
+    assert!(!client.extend_ttl_transaction(&(tx_id + 1)));
+}
+
+#[test]
+fn test_execute_checks_balance_and_runs_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone()], &1);
+
+    let token_id = create_funded_token(&env, &contract_id, 500);
+    let desc = String::from_str(&env, "payout");
+
+    // A shortfall leaves the tx approved so it can run once topped up
+    let tx_id = client.propose_transaction(&s1, &r1, &token_id, &800, &desc, &3600);
+    client.approve_transaction(&s1, &tx_id);
+    assert_eq!(
+        client.try_execute_transaction(&s1, &tx_id),
+        Err(Ok(TreasuryError::InsufficientTreasuryBalance))
+    );
+    assert!(client.get_transaction(&tx_id).unwrap().status == TxStatus::Approved);
+
+    token::StellarAssetClient::new(&env, &token_id).mint(&contract_id, &500);
+    client.execute_transaction(&s1, &tx_id);
+    assert!(client.get_transaction(&tx_id).unwrap().status == TxStatus::Executed);
+    assert_eq!(
+        client.try_execute_transaction(&s1, &tx_id),
+        Err(Ok(TreasuryError::TxNotApproved))
+    );
+    assert_eq!(token::Client::new(&env, &token_id).balance(&r1), 800);
+
+    // Batch entries in the same token are checked against the balance together
+    let payments = vec![&env, (r1.clone(), token_id.clone(), 150), (r2.clone(), token_id.clone(), 100)];
+    let batch_id = client.propose_batch(&s1, &payments, &desc, &3600);
+    client.approve_transaction(&s1, &batch_id);
+    assert_eq!(
+        client.try_execute_transaction(&s1, &batch_id),
+        Err(Ok(TreasuryError::InsufficientTreasuryBalance))
+    );
+    assert_eq!(token::Client::new(&env, &token_id).balance(&r2), 0);
+}
+
+#[test]
+fn test_call_to_limited_token_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, MultisigTreasuryContract);
+    let client = MultisigTreasuryContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.initialize(&admin, &vec![&env, s1.clone()], &1);
+
+    let token_id = create_funded_token(&env, &contract_id, 10_000);
+    let args: Vec<Val> = (contract_id.clone(), recipient.clone(), 5_000i128).into_val(&env);
+    let desc = String::from_str(&env, "raw transfer");
+
+    // Proposed before the limit exists, so it must be caught at execution
+    let tx_id = client.propose_call(&s1, &token_id, &Symbol::new(&env, "transfer"), &args, &desc, &3600);
+    client.approve_transaction(&s1, &tx_id);
+
+    client.set_spending_limit(&admin, &token_id, &1_000, &86_400);
+    assert_eq!(
+        client.try_propose_call(&s1, &token_id, &Symbol::new(&env, "transfer"), &args, &desc, &3600),
+        Err(Ok(TreasuryError::LimitedTokenCall))
+    );
+    assert_eq!(
+        client.try_execute_transaction(&s1, &tx_id),
+        Err(Ok(TreasuryError::LimitedTokenCall))
+    );
+    assert_eq!(token::Client::new(&env, &token_id).balance(&recipient), 0);
+
+    client.remove_spending_limit(&admin, &token_id);
+    client.execute_transaction(&s1, &tx_id);
+    assert_eq!(token::Client::new(&env, &token_id).balance(&recipient), 5_000);
+}
@@ -0,0 +1,424 @@
+//! PulsarTrack - Oracle Aggregator (Soroban)
+//! Multi-reporter impression and uptime measurements for PulsarTrack on Stellar.
+//!
+//! Registered reporters each submit a signed value for a (metric, subject,
+//! round). Once a quorum has reported, the round is finalized at the median
+//! and any reporter too far from it takes an outlier strike; enough strikes
+//! and the reporter is dropped.
+//!
+//! Consumers such as payment streams or reputation can point their oracle
+//! address at this contract: each metric can have a consumer contract and
+//! function that receive `(aggregator, subject, value)` when a newer round
+//! is finalized. Other contracts can also read `get_latest` directly.
+//!
+//! Events are published as ("aggregator", action, version) with a typed payload
+//! (see the Events section):
+//! - "value_submitted": `ValueSubmitted`
+//! - "round_finalized": `RoundFinalized`
+//! - "outlier_flagged": `OutlierFlagged`
+//! - "forward_failed": `ForwardFailed`
+//! - "reporter_ejected": `ReporterEjected`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    Address, Env, IntoVal, Symbol, Vec,
+};
+pub use pulsartrack_errors::OracleAggregatorError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AggregatorConfig {
+    pub quorum: u32,      // submissions needed to finalize a round
+    pub outlier_bps: u32, // max distance from the median, in bps of the median
+    pub max_strikes: u32, // outlier strikes before a reporter is dropped (0 = never)
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Submission {
+    pub reporter: Address,
+    pub value: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Aggregate {
+    pub round: u64,
+    pub median: u64,
+    pub reporters: u32,
+    pub finalized_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Consumer {
+    pub contract: Address,
+    pub function: Symbol,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ValueSubmitted {
+    pub metric: Symbol,
+    pub subject: u64,
+    pub round: u64,
+    pub reporter: Address,
+    pub value: u64,
+}
+contract_event!(ValueSubmitted, "value_submitted");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RoundFinalized {
+    pub metric: Symbol,
+    pub subject: u64,
+    pub round: u64,
+    pub median: u64,
+}
+contract_event!(RoundFinalized, "round_finalized");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct OutlierFlagged {
+    pub reporter: Address,
+    pub metric: Symbol,
+    pub round: u64,
+    pub value: u64,
+}
+contract_event!(OutlierFlagged, "outlier_flagged");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ForwardFailed {
+    pub metric: Symbol,
+    pub subject: u64,
+    pub round: u64,
+}
+contract_event!(ForwardFailed, "forward_failed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReporterEjected {
+    pub reporter: Address,
+    pub strikes: u32,
+}
+contract_event!(ReporterEjected, "reporter_ejected");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Config,
+    Reporters,
+    Strikes(Address),
+    Submissions(Symbol, u64, u64), // metric, subject, round
+    Round(Symbol, u64, u64),
+    Latest(Symbol, u64),
+    Consumer(Symbol),
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const MAX_REPORTERS: u32 = 20;
+
+const EVENT_TAG: &str = "aggregator";
+
+#[contract]
+pub struct OracleAggregatorContract;
+
+#[contractimpl]
+impl OracleAggregatorContract {
+    pub fn initialize(env: Env, admin: Address, config: AggregatorConfig) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, OracleAggregatorError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        Self::_validate_config(&env, &config);
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Config, &config);
+        env.storage().instance().set(&DataKey::Reporters, &Vec::<Address>::new(&env));
+    }
+
+    pub fn set_config(env: Env, admin: Address, config: AggregatorConfig) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        Self::_validate_config(&env, &config);
+        env.storage().instance().set(&DataKey::Config, &config);
+    }
+
+    pub fn add_reporter(env: Env, admin: Address, reporter: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        let mut reporters = Self::_reporters(&env);
+        if reporters.contains(&reporter) {
+            panic_with_error!(&env, OracleAggregatorError::ReporterExists);
+        }
+        if reporters.len() >= MAX_REPORTERS {
+            panic_with_error!(&env, OracleAggregatorError::TooManyReporters);
+        }
+        reporters.push_back(reporter.clone());
+        env.storage().instance().set(&DataKey::Reporters, &reporters);
+        env.storage().persistent().remove(&DataKey::Strikes(reporter));
+    }
+
+    pub fn remove_reporter(env: Env, admin: Address, reporter: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        if !Self::_drop_reporter(&env, &reporter) {
+            panic_with_error!(&env, OracleAggregatorError::ReporterNotFound);
+        }
+    }
+
+    /// Route finalized rounds of `metric` to `function` on `contract`
+    pub fn set_consumer(env: Env, admin: Address, metric: Symbol, contract: Address, function: Symbol) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Consumer(metric), &Consumer { contract, function });
+    }
+
+    pub fn remove_consumer(env: Env, admin: Address, metric: Symbol) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().remove(&DataKey::Consumer(metric));
+    }
+
+    /// Submit a measurement; finalizes the round once the quorum is reached.
+    /// Returns true if this submission finalized the round.
+    pub fn submit(env: Env, reporter: Address, metric: Symbol, subject: u64, round: u64, value: u64) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        reporter.require_auth();
+        if !Self::_reporters(&env).contains(&reporter) {
+            panic_with_error!(&env, OracleAggregatorError::NotAReporter);
+        }
+        if env.storage().persistent().has(&DataKey::Round(metric.clone(), subject, round)) {
+            panic_with_error!(&env, OracleAggregatorError::RoundFinalized);
+        }
+
+        let _ttl_key = DataKey::Submissions(metric.clone(), subject, round);
+        let mut submissions: Vec<Submission> = env
+            .storage()
+            .persistent()
+            .get(&_ttl_key)
+            .unwrap_or(Vec::new(&env));
+        if submissions.iter().any(|s| s.reporter == reporter) {
+            panic_with_error!(&env, OracleAggregatorError::AlreadySubmitted);
+        }
+        submissions.push_back(Submission { reporter: reporter.clone(), value });
+
+        events::publish(&env, EVENT_TAG, ValueSubmitted { metric: metric.clone(), subject, round, reporter, value });
+
+        let config = Self::_config(&env);
+        if submissions.len() < config.quorum {
+            env.storage().persistent().set(&_ttl_key, &submissions);
+            env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+            return false;
+        }
+
+        env.storage().persistent().remove(&_ttl_key);
+        Self::_finalize(&env, &config, metric, subject, round, &submissions);
+        true
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    /// Most recent finalized round for a metric and subject
+    pub fn get_latest(env: Env, metric: Symbol, subject: u64) -> Option<Aggregate> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Latest(metric, subject))
+    }
+
+    pub fn get_round(env: Env, metric: Symbol, subject: u64, round: u64) -> Option<Aggregate> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Round(metric, subject, round))
+    }
+
+    /// Submissions still waiting on the quorum
+    pub fn get_pending(env: Env, metric: Symbol, subject: u64, round: u64) -> Vec<Submission> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::Submissions(metric, subject, round))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_strikes(env: Env, reporter: Address) -> u32 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Strikes(reporter)).unwrap_or(0)
+    }
+
+    pub fn get_reporters(env: Env) -> Vec<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_reporters(&env)
+    }
+
+    pub fn get_config(env: Env) -> AggregatorConfig {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_config(&env)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _require_admin(env: &Env, admin: &Address) {
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *admin != stored_admin {
+            panic_with_error!(&env, OracleAggregatorError::Unauthorized);
+        }
+    }
+
+    fn _validate_config(env: &Env, config: &AggregatorConfig) {
+        if config.quorum == 0 || config.quorum > MAX_REPORTERS {
+            panic_with_error!(&env, OracleAggregatorError::InvalidQuorum);
+        }
+        if config.outlier_bps == 0 {
+            panic_with_error!(&env, OracleAggregatorError::InvalidOutlierBound);
+        }
+    }
+
+    fn _config(env: &Env) -> AggregatorConfig {
+        env.storage().instance().get(&DataKey::Config).unwrap()
+    }
+
+    fn _reporters(env: &Env) -> Vec<Address> {
+        env.storage().instance().get(&DataKey::Reporters).unwrap_or(Vec::new(env))
+    }
+
+    fn _drop_reporter(env: &Env, reporter: &Address) -> bool {
+        let mut reporters = Self::_reporters(env);
+        match reporters.first_index_of(reporter) {
+            Some(index) => {
+                reporters.remove(index);
+                env.storage().instance().set(&DataKey::Reporters, &reporters);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn _finalize(
+        env: &Env,
+        config: &AggregatorConfig,
+        metric: Symbol,
+        subject: u64,
+        round: u64,
+        submissions: &Vec<Submission>,
+    ) {
+        let median = Self::_median(env, submissions);
+        let aggregate = Aggregate {
+            round,
+            median,
+            reporters: submissions.len(),
+            finalized_at: env.ledger().timestamp(),
+        };
+        let _ttl_key = DataKey::Round(metric.clone(), subject, round);
+        env.storage().persistent().set(&_ttl_key, &aggregate);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(env, EVENT_TAG, RoundFinalized { metric: metric.clone(), subject, round, median });
+
+        for s in submissions.iter() {
+            if Self::_is_outlier(config, s.value, median) {
+                Self::_strike(env, config, &s.reporter);
+                events::publish(env, EVENT_TAG, OutlierFlagged { reporter: s.reporter.clone(), metric: metric.clone(), round, value: s.value });
+            }
+        }
+
+        // Late rounds are recorded but never replace or re-push a newer value
+        let latest: Option<Aggregate> = env.storage().persistent().get(&DataKey::Latest(metric.clone(), subject));
+        if latest.map(|l| l.round > round).unwrap_or(false) {
+            return;
+        }
+        let _ttl_key = DataKey::Latest(metric.clone(), subject);
+        env.storage().persistent().set(&_ttl_key, &aggregate);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        Self::_forward(env, metric, subject, round, median);
+    }
+
+    fn _forward(env: &Env, metric: Symbol, subject: u64, round: u64, median: u64) {
+        let consumer: Option<Consumer> = env.storage().instance().get(&DataKey::Consumer(metric.clone()));
+        if let Some(consumer) = consumer {
+            let args = Vec::from_array(
+                env,
+                [
+                    env.current_contract_address().into_val(env),
+                    subject.into_val(env),
+                    median.into_val(env),
+                ],
+            );
+            // A consumer that rejects the value must not block finalization
+            let result = env.try_invoke_contract::<(), soroban_sdk::Error>(&consumer.contract, &consumer.function, args);
+            if !matches!(result, Ok(Ok(()))) {
+                events::publish(env, EVENT_TAG, ForwardFailed { metric, subject, round });
+            }
+        }
+    }
+
+    fn _is_outlier(config: &AggregatorConfig, value: u64, median: u64) -> bool {
+        let distance = value.abs_diff(median) as u128;
+        distance * 10_000 > median as u128 * config.outlier_bps as u128
+    }
+
+    fn _strike(env: &Env, config: &AggregatorConfig, reporter: &Address) {
+        let _ttl_key = DataKey::Strikes(reporter.clone());
+        let strikes: u32 = env.storage().persistent().get(&_ttl_key).unwrap_or(0) + 1;
+        env.storage().persistent().set(&_ttl_key, &strikes);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        if config.max_strikes > 0 && strikes >= config.max_strikes && Self::_drop_reporter(env, reporter) {
+            events::publish(env, EVENT_TAG, ReporterEjected { reporter: reporter.clone(), strikes });
+        }
+    }
+
+    /// Median of the submitted values; the mean of the middle pair for an
+    /// even count
+    fn _median(env: &Env, submissions: &Vec<Submission>) -> u64 {
+        let mut sorted: Vec<u64> = Vec::new(env);
+        for s in submissions.iter() {
+            let mut index = sorted.len();
+            for (i, v) in sorted.iter().enumerate() {
+                if s.value < v {
+                    index = i as u32;
+                    break;
+                }
+            }
+            sorted.insert(index, s.value);
+        }
+        let n = sorted.len();
+        let mid = sorted.get(n / 2).unwrap();
+        if n % 2 == 1 {
+            mid
+        } else {
+            let lower = sorted.get(n / 2 - 1).unwrap();
+            lower + (mid - lower) / 2
+        }
+    }
+}
+
+mod test;
@@ -0,0 +1,128 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, symbol_short, Address, Env, Symbol};
+
+// Stands in for a consumer such as the payment stream's impression feed
+#[contract]
+pub struct MockConsumer;
+
+#[contractimpl]
+impl MockConsumer {
+    pub fn set_oracle(env: Env, oracle: Address) {
+        env.storage().instance().set(&symbol_short!("oracle"), &oracle);
+    }
+
+    pub fn record_impressions(env: Env, oracle: Address, stream_id: u64, count: u64) {
+        oracle.require_auth();
+        let stored: Address = env.storage().instance().get(&symbol_short!("oracle")).unwrap();
+        if oracle != stored {
+            panic!("unauthorized");
+        }
+        if count == 0 {
+            panic!("invalid count");
+        }
+        env.storage().instance().set(&stream_id, &count);
+    }
+
+    pub fn recorded(env: Env, stream_id: u64) -> u64 {
+        env.storage().instance().get(&stream_id).unwrap_or(0)
+    }
+}
+
+fn config() -> AggregatorConfig {
+    AggregatorConfig { quorum: 3, outlier_bps: 1_000, max_strikes: 2 }
+}
+
+fn setup(env: &Env) -> (OracleAggregatorContractClient<'_>, Address, [Address; 4]) {
+    let contract_id = env.register_contract(None, OracleAggregatorContract);
+    let client = OracleAggregatorContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin, &config());
+    let reporters = [0; 4].map(|_| Address::generate(env));
+    for r in reporters.iter() {
+        client.add_reporter(&admin, r);
+    }
+    (client, admin, reporters)
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, reporters) = setup(&env);
+    assert_eq!(client.get_reporters().len(), 4);
+    assert_eq!(client.get_config().quorum, 3);
+    assert_eq!(client.get_strikes(&reporters[0]), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3501)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _reporters) = setup(&env);
+    client.initialize(&admin, &config());
+}
+
+#[test]
+fn test_median_quorum_and_outlier_ejection() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, reporters) = setup(&env);
+    let uptime = Symbol::new(&env, "uptime");
+    let stranger = Address::generate(&env);
+
+    assert!(client.try_submit(&stranger, &uptime, &7, &1, &9_900).is_err());
+    assert!(!client.submit(&reporters[0], &uptime, &7, &1, &9_900));
+    assert!(client.try_submit(&reporters[0], &uptime, &7, &1, &9_900).is_err());
+    assert!(!client.submit(&reporters[1], &uptime, &7, &1, &9_800));
+    assert!(client.get_latest(&uptime, &7).is_none());
+    assert!(client.submit(&reporters[3], &uptime, &7, &1, &5_000));
+
+    let latest = client.get_latest(&uptime, &7).unwrap();
+    assert_eq!(latest.median, 9_800);
+    assert_eq!(latest.reporters, 3);
+    assert_eq!(client.get_strikes(&reporters[3]), 1);
+    assert!(client.try_submit(&reporters[2], &uptime, &7, &1, &9_900).is_err());
+
+    // Second strike drops the reporter
+    client.submit(&reporters[0], &uptime, &7, &2, &9_000);
+    client.submit(&reporters[3], &uptime, &7, &2, &1_000);
+    client.submit(&reporters[1], &uptime, &7, &2, &9_400);
+    assert_eq!(client.get_latest(&uptime, &7).unwrap().median, 9_000);
+    assert_eq!(client.get_reporters().len(), 3);
+    assert!(client.try_submit(&reporters[3], &uptime, &7, &3, &9_000).is_err());
+
+    // Even counts take the mean of the middle pair; a stale round doesn't
+    // overwrite the latest value
+    client.set_config(&admin, &AggregatorConfig { quorum: 2, outlier_bps: 1_000, max_strikes: 0 });
+    client.submit(&reporters[0], &uptime, &7, &0, &100);
+    client.submit(&reporters[1], &uptime, &7, &0, &104);
+    assert_eq!(client.get_round(&uptime, &7, &0).unwrap().median, 102);
+    assert_eq!(client.get_latest(&uptime, &7).unwrap().round, 2);
+}
+
+#[test]
+fn test_finalized_rounds_forwarded_to_consumer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, reporters) = setup(&env);
+    let impressions = Symbol::new(&env, "impressions");
+
+    let consumer_id = env.register_contract(None, MockConsumer);
+    let consumer = MockConsumerClient::new(&env, &consumer_id);
+    consumer.set_oracle(&client.address);
+    client.set_consumer(&admin, &impressions, &consumer_id, &Symbol::new(&env, "record_impressions"));
+
+    client.submit(&reporters[0], &impressions, &42, &1, &1_000);
+    client.submit(&reporters[1], &impressions, &42, &1, &1_010);
+    client.submit(&reporters[2], &impressions, &42, &1, &990);
+    assert_eq!(consumer.recorded(&42), 1_000);
+
+    // A consumer rejecting the value doesn't undo the round
+    client.submit(&reporters[0], &impressions, &42, &2, &0);
+    client.submit(&reporters[1], &impressions, &42, &2, &0);
+    assert!(client.submit(&reporters[2], &impressions, &42, &2, &0));
+    assert_eq!(client.get_latest(&impressions, &42).unwrap().round, 2);
+    assert_eq!(consumer.recorded(&42), 1_000);
+}
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Env, String,
 };
+pub use pulsartrack_errors::OracleIntegrationError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone)]
@@ -28,6 +30,21 @@ pub struct PerformanceData {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct PriceUpdated {
+    pub asset: String,
+    pub price_usd: i128,
+}
+contract_event!(PriceUpdated, "price_updated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PerformanceUpdated {
+    pub campaign_id: u64,
+}
+contract_event!(PerformanceUpdated, "performance_updated");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -44,6 +61,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "oracle";
+
 #[contract]
 pub struct OracleIntegrationContract;
 
@@ -52,7 +71,7 @@ impl OracleIntegrationContract {
     pub fn initialize(env: Env, admin: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, OracleIntegrationError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -64,7 +83,7 @@ impl OracleIntegrationContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, OracleIntegrationError::Unauthorized);
         }
         let _ttl_key = DataKey::AuthorizedOracle(oracle.clone());
         env.storage().persistent().set(&_ttl_key, &true);
@@ -79,7 +98,7 @@ impl OracleIntegrationContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, OracleIntegrationError::Unauthorized);
         }
         env.storage().persistent().remove(&DataKey::AuthorizedOracle(oracle));
     }
@@ -108,10 +127,7 @@ impl OracleIntegrationContract {
         env.storage().persistent().set(&_ttl_key, &feed);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("oracle"), symbol_short!("price")),
-            (asset, price_usd),
-        );
+        events::publish(&env, EVENT_TAG, PriceUpdated { asset, price_usd });
     }
 
     pub fn update_performance(
@@ -140,10 +156,7 @@ impl OracleIntegrationContract {
         env.storage().persistent().set(&_ttl_key, &data);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("oracle"), symbol_short!("perf")),
-            campaign_id,
-        );
+        events::publish(&env, EVENT_TAG, PerformanceUpdated { campaign_id });
     }
 
     pub fn get_price(env: Env, asset: String) -> Option<PriceFeed> {
@@ -168,7 +181,7 @@ impl OracleIntegrationContract {
             .get(&DataKey::AuthorizedOracle(oracle.clone()))
             .unwrap_or(false);
         if !is_auth {
-            panic!("not authorized oracle");
+            panic_with_error!(&env, OracleIntegrationError::NotAuthorizedOracle);
         }
     }
 }
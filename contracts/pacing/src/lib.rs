@@ -0,0 +1,227 @@
+//! PulsarTrack - Budget Pacing (Soroban)
+//! Hourly and daily spend caps for campaigns on Stellar.
+//!
+//! The campaign orchestrator sets each campaign's caps on the advertiser's
+//! behalf and calls `consume` before releasing any spend; a spend that would
+//! push the current hour or day over its cap is refused. Windows are fixed
+//! clock hours and days (UTC, by ledger timestamp). A cap of 0 leaves that
+//! window unlimited, and campaigns without caps are never throttled.
+//!
+//! Events are published as ("pacing", action, version) with a typed payload
+//! (see the Events section):
+//! - "caps_set": `CapsSet`
+//! - "spend_throttled": `SpendThrottled`
+
+
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contracttype, panic_with_error, Address, Env};
+pub use pulsartrack_errors::PacingError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PacingCaps {
+    pub hourly_cap: i128, // 0 = unlimited
+    pub daily_cap: i128,  // 0 = unlimited
+    pub updated_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct WindowSpend {
+    pub hour: u64, // hours since epoch
+    pub hour_spent: i128,
+    pub day: u64, // days since epoch
+    pub day_spent: i128,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct CapsSet {
+    pub campaign_id: u64,
+    pub hourly_cap: i128,
+    pub daily_cap: i128,
+}
+contract_event!(CapsSet, "caps_set");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SpendThrottled {
+    pub campaign_id: u64,
+    pub amount: i128,
+}
+contract_event!(SpendThrottled, "spend_throttled");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Orchestrator,
+    Caps(u64),
+    HourSpend(u64, u64), // campaign_id, hour
+    DaySpend(u64, u64),  // campaign_id, day
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+// Window tallies only need to outlive their window (~1 day of ledgers)
+const WINDOW_LIFETIME_THRESHOLD: u32 = 17_280;
+const WINDOW_BUMP_AMOUNT: u32 = 34_560;
+
+const HOUR_SECS: u64 = 3_600;
+const DAY_SECS: u64 = 86_400;
+
+const EVENT_TAG: &str = "pacing";
+
+#[contract]
+pub struct PacingContract;
+
+#[contractimpl]
+impl PacingContract {
+    pub fn initialize(env: Env, admin: Address, orchestrator: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, PacingError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Orchestrator, &orchestrator);
+    }
+
+    pub fn set_orchestrator(env: Env, admin: Address, orchestrator: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if admin != stored_admin {
+            panic_with_error!(&env, PacingError::Unauthorized);
+        }
+        env.storage().instance().set(&DataKey::Orchestrator, &orchestrator);
+    }
+
+    /// Set a campaign's caps (orchestrator only)
+    pub fn set_caps(env: Env, orchestrator: Address, campaign_id: u64, hourly_cap: i128, daily_cap: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_orchestrator(&env, &orchestrator);
+        if hourly_cap < 0 || daily_cap < 0 {
+            panic_with_error!(&env, PacingError::InvalidCap);
+        }
+        if hourly_cap > 0 && daily_cap > 0 && hourly_cap > daily_cap {
+            panic_with_error!(&env, PacingError::HourlyCapAboveDailyCap);
+        }
+
+        let caps = PacingCaps {
+            hourly_cap,
+            daily_cap,
+            updated_at: env.ledger().timestamp(),
+        };
+        let _ttl_key = DataKey::Caps(campaign_id);
+        env.storage().persistent().set(&_ttl_key, &caps);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, CapsSet { campaign_id, hourly_cap, daily_cap });
+    }
+
+    /// Record `amount` of spend if it fits this hour's and day's caps
+    /// (orchestrator only). Returns false, recording nothing, if it doesn't.
+    pub fn consume(env: Env, orchestrator: Address, campaign_id: u64, amount: i128) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_orchestrator(&env, &orchestrator);
+        if amount <= 0 {
+            panic_with_error!(&env, PacingError::InvalidAmount);
+        }
+        if !Self::_fits(&env, campaign_id, amount) {
+            events::publish(&env, EVENT_TAG, SpendThrottled { campaign_id, amount });
+            return false;
+        }
+
+        let spend = Self::_window_spend(&env, campaign_id);
+        let hour_key = DataKey::HourSpend(campaign_id, spend.hour);
+        env.storage().temporary().set(&hour_key, &(spend.hour_spent + amount));
+        env.storage().temporary().extend_ttl(&hour_key, WINDOW_LIFETIME_THRESHOLD, WINDOW_BUMP_AMOUNT);
+        let day_key = DataKey::DaySpend(campaign_id, spend.day);
+        env.storage().temporary().set(&day_key, &(spend.day_spent + amount));
+        env.storage().temporary().extend_ttl(&day_key, WINDOW_LIFETIME_THRESHOLD, WINDOW_BUMP_AMOUNT);
+        true
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    /// Whether `amount` more could be spent right now without breaching
+    /// the campaign's caps
+    pub fn can_spend(env: Env, campaign_id: u64, amount: i128) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_fits(&env, campaign_id, amount)
+    }
+
+    pub fn get_caps(env: Env, campaign_id: u64) -> Option<PacingCaps> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Caps(campaign_id))
+    }
+
+    /// Spend consumed in the current hour and day
+    pub fn get_window_spend(env: Env, campaign_id: u64) -> WindowSpend {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_window_spend(&env, campaign_id)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _require_orchestrator(env: &Env, orchestrator: &Address) {
+        orchestrator.require_auth();
+        let stored: Address = env.storage().instance().get(&DataKey::Orchestrator).unwrap();
+        if *orchestrator != stored {
+            panic_with_error!(&env, PacingError::Unauthorized);
+        }
+    }
+
+    fn _window_spend(env: &Env, campaign_id: u64) -> WindowSpend {
+        let now = env.ledger().timestamp();
+        let hour = now / HOUR_SECS;
+        let day = now / DAY_SECS;
+        let storage = env.storage().temporary();
+        WindowSpend {
+            hour,
+            hour_spent: storage.get(&DataKey::HourSpend(campaign_id, hour)).unwrap_or(0),
+            day,
+            day_spent: storage.get(&DataKey::DaySpend(campaign_id, day)).unwrap_or(0),
+        }
+    }
+
+    fn _fits(env: &Env, campaign_id: u64, amount: i128) -> bool {
+        let caps: PacingCaps = match env.storage().persistent().get(&DataKey::Caps(campaign_id)) {
+            Some(caps) => caps,
+            None => return true,
+        };
+        let spend = Self::_window_spend(env, campaign_id);
+        if caps.hourly_cap > 0 && spend.hour_spent + amount > caps.hourly_cap {
+            return false;
+        }
+        if caps.daily_cap > 0 && spend.day_spent + amount > caps.daily_cap {
+            return false;
+        }
+        true
+    }
+}
+
+mod test;
@@ -0,0 +1,64 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
+
+fn setup(env: &Env) -> (PacingContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, PacingContract);
+    let client = PacingContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let orchestrator = Address::generate(env);
+    client.initialize(&admin, &orchestrator);
+    (client, admin, orchestrator)
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _orchestrator) = setup(&env);
+    assert!(client.get_caps(&1).is_none());
+    // Uncapped campaigns are never throttled
+    assert!(client.can_spend(&1, &1_000_000));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3701)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, orchestrator) = setup(&env);
+    client.initialize(&admin, &orchestrator);
+}
+
+#[test]
+fn test_hourly_and_daily_caps() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, orchestrator) = setup(&env);
+    let stranger = Address::generate(&env);
+
+    assert!(client.try_set_caps(&stranger, &1, &100, &250).is_err());
+    assert!(client.try_set_caps(&orchestrator, &1, &300, &250).is_err());
+    client.set_caps(&orchestrator, &1, &100, &250);
+    assert!(client.try_consume(&stranger, &1, &10).is_err());
+
+    assert!(client.consume(&orchestrator, &1, &60));
+    assert!(client.can_spend(&1, &40));
+    assert!(!client.can_spend(&1, &41));
+    assert!(!client.consume(&orchestrator, &1, &50));
+    assert!(client.consume(&orchestrator, &1, &40));
+    assert_eq!(client.get_window_spend(&1).hour_spent, 100);
+
+    // A new hour resets the hourly window but not the daily one
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    assert!(client.consume(&orchestrator, &1, &100));
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    assert!(!client.consume(&orchestrator, &1, &60));
+    assert!(client.consume(&orchestrator, &1, &50));
+    assert_eq!(client.get_window_spend(&1).day_spent, 250);
+
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    assert!(client.can_spend(&1, &100));
+    // Other campaigns have their own windows
+    assert!(client.can_spend(&2, &1_000));
+}
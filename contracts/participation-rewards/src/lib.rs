@@ -0,0 +1,387 @@
+//! PulsarTrack - Participation Rewards (Soroban)
+//! PULSAR rewards for voting on DAO proposals, on Stellar.
+//!
+//! Time is split into fixed epochs from initialization. Each epoch has a
+//! PULSAR budget, fixed when its first participation is recorded. Once a
+//! proposal is finalized, each of its voters can have their vote recorded
+//! here (by anyone); the vote is read back from the DAO and earns one point
+//! in the epoch the proposal was created in. After the epoch closes its
+//! budget is shared out in proportion to points, and voters pull their
+//! share with `claim_rewards`.
+//!
+//! Anti-gaming rules:
+//! - only finalized proposals count; cancelled ones never do
+//! - the proposal must have reached its quorum
+//! - the vote's snapshot power must meet `min_stake`
+//! - one point per voter per proposal, whatever the voting power
+//!
+//! Participation in epoch `e` can be recorded until epoch `e + 1` ends,
+//! and its rewards are claimable from epoch `e + 2` on.
+//!
+//! Events are published as ("participation", action, version) with a typed
+//! payload (see the Events section):
+//! - "funded": `Funded`
+//! - "participation_recorded": `ParticipationRecorded`
+//! - "rewards_claimed": `RewardsClaimed`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    token, Address, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec,
+};
+pub use pulsartrack_errors::ParticipationRewardsError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RewardRules {
+    pub epoch_budget: i128, // PULSAR shared out per epoch
+    pub min_stake: i128,    // minimum snapshot voting power for a vote to count
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EpochInfo {
+    pub budget: i128,
+    pub total_points: u32,
+    pub claimed: i128,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Funded {
+    pub funder: Address,
+    pub amount: i128,
+}
+contract_event!(Funded, "funded");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ParticipationRecorded {
+    pub voter: Address,
+    pub proposal_id: u64,
+    pub epoch: u64,
+}
+contract_event!(ParticipationRecorded, "participation_recorded");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RewardsClaimed {
+    pub voter: Address,
+    pub amount: i128,
+}
+contract_event!(RewardsClaimed, "rewards_claimed");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Dao,
+    RewardToken,
+    EpochSecs,
+    Genesis,
+    Rules,
+    Epoch(u64),
+    Points(u64, Address),     // epoch, voter
+    Recorded(u64, Address),   // proposal_id, voter
+    VoterEpochs(Address),     // epochs with unclaimed points
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const FINALIZED: [&str; 6] = ["Passed", "Rejected", "Queued", "Vetoed", "Executed", "Expired"];
+
+const EVENT_TAG: &str = "participation";
+
+#[contract]
+pub struct ParticipationRewardsContract;
+
+#[contractimpl]
+impl ParticipationRewardsContract {
+    pub fn initialize(env: Env, admin: Address, dao: Address, reward_token: Address, epoch_secs: u64, rules: RewardRules) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, ParticipationRewardsError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        if epoch_secs == 0 {
+            panic_with_error!(&env, ParticipationRewardsError::InvalidEpochLength);
+        }
+        Self::_validate_rules(&env, &rules);
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Dao, &dao);
+        env.storage().instance().set(&DataKey::RewardToken, &reward_token);
+        env.storage().instance().set(&DataKey::EpochSecs, &epoch_secs);
+        env.storage().instance().set(&DataKey::Genesis, &env.ledger().timestamp());
+        env.storage().instance().set(&DataKey::Rules, &rules);
+    }
+
+    /// Change the budget and minimum stake. Epochs that already have a
+    /// budget keep it.
+    pub fn set_rules(env: Env, admin: Address, rules: RewardRules) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if admin != stored_admin {
+            panic_with_error!(&env, ParticipationRewardsError::Unauthorized);
+        }
+        Self::_validate_rules(&env, &rules);
+        env.storage().instance().set(&DataKey::Rules, &rules);
+    }
+
+    pub fn fund(env: Env, funder: Address, amount: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        funder.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&env, ParticipationRewardsError::InvalidAmount);
+        }
+        Self::_reward_token(&env).transfer(&funder, &env.current_contract_address(), &amount);
+
+        events::publish(&env, EVENT_TAG, Funded { funder, amount });
+    }
+
+    /// Record a voter's vote on a finalized proposal. Anyone may call
+    /// this. Returns the epoch the point was credited to.
+    pub fn record_participation(env: Env, voter: Address, proposal_id: u64) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let recorded_key = DataKey::Recorded(proposal_id, voter.clone());
+        if env.storage().persistent().has(&recorded_key) {
+            panic_with_error!(&env, ParticipationRewardsError::AlreadyRecorded);
+        }
+
+        let dao: Address = env.storage().instance().get(&DataKey::Dao).unwrap();
+        let proposal = Self::_fetch(&env, &dao, "get_proposal", Vec::from_array(&env, [proposal_id.into_val(&env)]))
+            .unwrap_or_else(|| panic_with_error!(&env, ParticipationRewardsError::ProposalNotFound));
+        let status = Self::_variant(&env, &Self::_field(&env, &proposal, "status"));
+        if !FINALIZED.iter().any(|s| status == Some(Symbol::new(&env, s))) {
+            panic_with_error!(&env, ParticipationRewardsError::ProposalNotFinalized);
+        }
+        let turnout = Self::_i128_field(&env, &proposal, "votes_for")
+            + Self::_i128_field(&env, &proposal, "votes_against")
+            + Self::_i128_field(&env, &proposal, "votes_abstain");
+        if turnout < Self::_i128_field(&env, &proposal, "quorum_required") {
+            panic_with_error!(&env, ParticipationRewardsError::ProposalMissedQuorum);
+        }
+
+        let vote = Self::_fetch(
+            &env,
+            &dao,
+            "get_vote",
+            Vec::from_array(&env, [proposal_id.into_val(&env), voter.clone().into_val(&env)]),
+        )
+        .unwrap_or_else(|| panic_with_error!(&env, ParticipationRewardsError::NoVote));
+        let rules: RewardRules = env.storage().instance().get(&DataKey::Rules).unwrap();
+        if Self::_i128_field(&env, &vote, "raw_power") < rules.min_stake {
+            panic_with_error!(&env, ParticipationRewardsError::StakeBelowMinimum);
+        }
+
+        let created_at = u64::try_from_val(&env, &Self::_field(&env, &proposal, "created_at"))
+            .unwrap_or_else(|_| panic_with_error!(&env, ParticipationRewardsError::InvalidRecord));
+        let genesis: u64 = env.storage().instance().get(&DataKey::Genesis).unwrap();
+        if created_at < genesis {
+            panic_with_error!(&env, ParticipationRewardsError::ProposalPredatesRewards);
+        }
+        let epoch = Self::_epoch_at(&env, created_at);
+        if Self::_current_epoch(&env) > epoch + 1 {
+            panic_with_error!(&env, ParticipationRewardsError::EpochClosed);
+        }
+
+        let mut info = Self::_epoch(&env, epoch).unwrap_or(EpochInfo {
+            budget: rules.epoch_budget,
+            total_points: 0,
+            claimed: 0,
+        });
+        info.total_points += 1;
+        Self::_save_epoch(&env, epoch, &info);
+
+        let points_key = DataKey::Points(epoch, voter.clone());
+        let points: u32 = env.storage().persistent().get(&points_key).unwrap_or(0);
+        env.storage().persistent().set(&points_key, &(points + 1));
+        env.storage().persistent().extend_ttl(&points_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        if points == 0 {
+            let mut epochs = Self::_voter_epochs(&env, &voter);
+            epochs.push_back(epoch);
+            Self::_save_voter_epochs(&env, &voter, &epochs);
+        }
+
+        env.storage().persistent().set(&recorded_key, &true);
+        env.storage().persistent().extend_ttl(&recorded_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, ParticipationRecorded { voter, proposal_id, epoch });
+
+        epoch
+    }
+
+    /// Pay out the voter's share of every closed epoch they earned points
+    /// in. Returns the amount paid.
+    pub fn claim_rewards(env: Env, voter: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        voter.require_auth();
+        let current = Self::_current_epoch(&env);
+        let mut pending: Vec<u64> = Vec::new(&env);
+        let mut total: i128 = 0;
+        for epoch in Self::_voter_epochs(&env, &voter).iter() {
+            if current < epoch + 2 {
+                pending.push_back(epoch);
+                continue;
+            }
+            let points_key = DataKey::Points(epoch, voter.clone());
+            let points: u32 = env.storage().persistent().get(&points_key).unwrap_or(0);
+            env.storage().persistent().remove(&points_key);
+            let mut info = Self::_epoch(&env, epoch).unwrap();
+            let share = info.budget * points as i128 / info.total_points as i128;
+            info.claimed += share;
+            Self::_save_epoch(&env, epoch, &info);
+            total += share;
+        }
+        if total == 0 {
+            panic_with_error!(&env, ParticipationRewardsError::NothingToClaim);
+        }
+        Self::_save_voter_epochs(&env, &voter, &pending);
+        Self::_reward_token(&env).transfer(&env.current_contract_address(), &voter, &total);
+
+        events::publish(&env, EVENT_TAG, RewardsClaimed { voter, amount: total });
+
+        total
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn current_epoch(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_current_epoch(&env)
+    }
+
+    pub fn get_epoch(env: Env, epoch: u64) -> Option<EpochInfo> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_epoch(&env, epoch)
+    }
+
+    pub fn get_points(env: Env, epoch: u64, voter: Address) -> u32 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Points(epoch, voter)).unwrap_or(0)
+    }
+
+    /// Rewards `claim_rewards` would pay right now
+    pub fn pending_rewards(env: Env, voter: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let current = Self::_current_epoch(&env);
+        let mut total: i128 = 0;
+        for epoch in Self::_voter_epochs(&env, &voter).iter() {
+            if current < epoch + 2 {
+                continue;
+            }
+            let points: u32 = env.storage().persistent().get(&DataKey::Points(epoch, voter.clone())).unwrap_or(0);
+            let info = Self::_epoch(&env, epoch).unwrap();
+            total += info.budget * points as i128 / info.total_points as i128;
+        }
+        total
+    }
+
+    pub fn get_rules(env: Env) -> RewardRules {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::Rules).unwrap()
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _validate_rules(env: &Env, rules: &RewardRules) {
+        if rules.epoch_budget < 0 || rules.min_stake < 0 {
+            panic_with_error!(&env, ParticipationRewardsError::InvalidRules);
+        }
+    }
+
+    fn _reward_token(env: &Env) -> token::Client<'_> {
+        let token: Address = env.storage().instance().get(&DataKey::RewardToken).unwrap();
+        token::Client::new(env, &token)
+    }
+
+    fn _epoch_at(env: &Env, timestamp: u64) -> u64 {
+        let genesis: u64 = env.storage().instance().get(&DataKey::Genesis).unwrap();
+        let epoch_secs: u64 = env.storage().instance().get(&DataKey::EpochSecs).unwrap();
+        timestamp.saturating_sub(genesis) / epoch_secs
+    }
+
+    fn _current_epoch(env: &Env) -> u64 {
+        Self::_epoch_at(env, env.ledger().timestamp())
+    }
+
+    fn _epoch(env: &Env, epoch: u64) -> Option<EpochInfo> {
+        env.storage().persistent().get(&DataKey::Epoch(epoch))
+    }
+
+    fn _save_epoch(env: &Env, epoch: u64, info: &EpochInfo) {
+        let _ttl_key = DataKey::Epoch(epoch);
+        env.storage().persistent().set(&_ttl_key, info);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _voter_epochs(env: &Env, voter: &Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VoterEpochs(voter.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn _save_voter_epochs(env: &Env, voter: &Address, epochs: &Vec<u64>) {
+        let _ttl_key = DataKey::VoterEpochs(voter.clone());
+        if epochs.is_empty() {
+            env.storage().persistent().remove(&_ttl_key);
+            return;
+        }
+        env.storage().persistent().set(&_ttl_key, epochs);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    /// Fetch a record from another contract as a field map
+    fn _fetch(env: &Env, contract: &Address, function: &str, args: Vec<Val>) -> Option<Map<Symbol, Val>> {
+        let val: Val = env.invoke_contract(contract, &Symbol::new(env, function), args);
+        if val.is_void() {
+            return None;
+        }
+        Some(Map::<Symbol, Val>::try_from_val(env, &val).unwrap_or_else(|_| panic_with_error!(&env, ParticipationRewardsError::InvalidRecord)))
+    }
+
+    fn _field(env: &Env, record: &Map<Symbol, Val>, name: &str) -> Val {
+        record
+            .get(Symbol::new(env, name))
+            .unwrap_or_else(|| panic_with_error!(&env, ParticipationRewardsError::InvalidRecord))
+    }
+
+    fn _i128_field(env: &Env, record: &Map<Symbol, Val>, name: &str) -> i128 {
+        i128::try_from_val(env, &Self::_field(env, record, name)).unwrap_or_else(|_| panic_with_error!(&env, ParticipationRewardsError::InvalidRecord))
+    }
+
+    /// Variant name of a unit enum value
+    fn _variant(env: &Env, val: &Val) -> Option<Symbol> {
+        Vec::<Val>::try_from_val(env, val)
+            .ok()
+            .and_then(|v| v.get(0))
+            .and_then(|tag| Symbol::try_from_val(env, &tag).ok())
+    }
+}
+
+mod test;
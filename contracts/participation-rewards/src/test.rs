@@ -0,0 +1,180 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{contract, contractimpl, contracttype, testutils::{Address as _, Ledger}, token, Address, Env};
+
+// Stand-in for the governance DAO: same entry points and field names, with
+// proposals and votes set directly by the test
+#[contracttype]
+#[derive(Clone)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Rejected,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct MockProposal {
+    pub status: ProposalStatus,
+    pub votes_for: i128,
+    pub votes_against: i128,
+    pub votes_abstain: i128,
+    pub quorum_required: i128,
+    pub created_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct MockVote {
+    pub raw_power: i128,
+}
+
+#[contract]
+pub struct MockDao;
+
+#[contractimpl]
+impl MockDao {
+    pub fn set_proposal(env: Env, proposal_id: u64, status: ProposalStatus, turnout: i128, created_at: u64) {
+        let proposal = MockProposal {
+            status,
+            votes_for: turnout,
+            votes_against: 0,
+            votes_abstain: 0,
+            quorum_required: 100,
+            created_at,
+        };
+        env.storage().instance().set(&proposal_id, &proposal);
+    }
+
+    pub fn set_vote(env: Env, proposal_id: u64, voter: Address, raw_power: i128) {
+        env.storage().instance().set(&(proposal_id, voter), &MockVote { raw_power });
+    }
+
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Option<MockProposal> {
+        env.storage().instance().get(&proposal_id)
+    }
+
+    pub fn get_vote(env: Env, proposal_id: u64, voter: Address) -> Option<MockVote> {
+        env.storage().instance().get(&(proposal_id, voter))
+    }
+}
+
+const EPOCH: u64 = 1_000;
+
+struct Setup<'a> {
+    client: ParticipationRewardsContractClient<'a>,
+    admin: Address,
+    token: Address,
+    dao: MockDaoClient<'a>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let contract_id = env.register_contract(None, ParticipationRewardsContract);
+    let client = ParticipationRewardsContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    let dao_id = env.register_contract(None, MockDao);
+    client.initialize(&admin, &dao_id, &token, &EPOCH, &RewardRules { epoch_budget: 900, min_stake: 50 });
+    token::StellarAssetClient::new(env, &token).mint(&admin, &10_000);
+    client.fund(&admin, &10_000);
+    Setup { client, admin, token, dao: MockDaoClient::new(env, &dao_id) }
+}
+
+fn pass(env: &Env, secs: u64) {
+    env.ledger().with_mut(|li| li.timestamp += secs);
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    assert_eq!(s.client.current_epoch(), 0);
+    assert_eq!(s.client.get_rules().epoch_budget, 900);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3801)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.initialize(&s.admin, &s.dao.address, &s.token, &EPOCH, &RewardRules { epoch_budget: 900, min_stake: 50 });
+}
+
+#[test]
+fn test_only_qualifying_votes_earn_points() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let voter = Address::generate(&env);
+    let minnow = Address::generate(&env);
+
+    s.dao.set_proposal(&1, &ProposalStatus::Active, &500, &10_100);
+    s.dao.set_proposal(&2, &ProposalStatus::Cancelled, &500, &10_100);
+    s.dao.set_proposal(&3, &ProposalStatus::Passed, &40, &10_100);
+    s.dao.set_proposal(&4, &ProposalStatus::Rejected, &500, &10_100);
+    s.dao.set_proposal(&5, &ProposalStatus::Passed, &500, &9_000);
+    for id in 1..=5u64 {
+        s.dao.set_vote(&id, &voter, &1_000);
+        s.dao.set_vote(&id, &minnow, &10);
+    }
+
+    assert!(s.client.try_record_participation(&voter, &1).is_err()); // still voting
+    assert!(s.client.try_record_participation(&voter, &2).is_err()); // cancelled
+    assert!(s.client.try_record_participation(&voter, &3).is_err()); // missed quorum
+    assert!(s.client.try_record_participation(&voter, &5).is_err()); // before rewards began
+    assert!(s.client.try_record_participation(&minnow, &4).is_err()); // stake too low
+    assert!(s.client.try_record_participation(&Address::generate(&env), &4).is_err()); // didn't vote
+
+    assert_eq!(s.client.record_participation(&voter, &4), 0);
+    assert!(s.client.try_record_participation(&voter, &4).is_err());
+    assert_eq!(s.client.get_points(&0, &voter), 1);
+
+    // Late recording closes once the following epoch is over
+    s.dao.set_proposal(&6, &ProposalStatus::Passed, &500, &10_200);
+    s.dao.set_vote(&6, &voter, &1_000);
+    pass(&env, 2 * EPOCH);
+    assert!(s.client.try_record_participation(&voter, &6).is_err());
+}
+
+#[test]
+fn test_epoch_budget_shared_by_points() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let balance = token::Client::new(&env, &s.token);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    for id in 1..=2u64 {
+        s.dao.set_proposal(&id, &ProposalStatus::Passed, &500, &10_100);
+        s.dao.set_vote(&id, &alice, &1_000);
+    }
+    s.dao.set_vote(&2, &bob, &1_000_000);
+    s.client.record_participation(&alice, &1);
+    s.client.record_participation(&alice, &2);
+    s.client.record_participation(&bob, &2);
+
+    // A later budget change doesn't touch an epoch already under way
+    s.client.set_rules(&s.admin, &RewardRules { epoch_budget: 300, min_stake: 50 });
+    pass(&env, EPOCH);
+    s.dao.set_proposal(&3, &ProposalStatus::Passed, &500, &11_100);
+    s.dao.set_vote(&3, &bob, &1_000);
+    assert_eq!(s.client.record_participation(&bob, &3), 1);
+
+    assert!(s.client.try_claim_rewards(&alice).is_err());
+    pass(&env, EPOCH);
+    assert_eq!(s.client.pending_rewards(&alice), 600);
+    assert_eq!(s.client.claim_rewards(&alice), 600);
+    assert_eq!(s.client.claim_rewards(&bob), 300);
+    assert_eq!(balance.balance(&bob), 300);
+    assert!(s.client.try_claim_rewards(&alice).is_err());
+
+    pass(&env, EPOCH);
+    assert_eq!(s.client.claim_rewards(&bob), 300);
+    assert_eq!(s.client.get_epoch(&0).unwrap().claimed, 900);
+}
@@ -4,9 +4,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     token, Address, Env, String,
 };
+pub use pulsartrack_errors::PaymentProcessorError;
+use pulsartrack_events::{self as events, contract_event};
 
 // ============================================================
 // Data Types
@@ -59,6 +61,20 @@ pub struct RevenueStats {
     pub payment_count: u64,
 }
 
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PaymentProcessed {
+    pub payment_id: u64,
+    pub payer: Address,
+    pub recipient: Address,
+    pub amount: i128,
+}
+contract_event!(PaymentProcessed, "payment_processed");
+
 // ============================================================
 // Storage Keys
 // ============================================================
@@ -85,6 +101,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "payments";
+
 #[contract]
 pub struct PaymentProcessorContract;
 
@@ -94,7 +112,7 @@ impl PaymentProcessorContract {
     pub fn initialize(env: Env, admin: Address, treasury: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, PaymentProcessorError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -121,7 +139,7 @@ impl PaymentProcessorContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, PaymentProcessorError::Unauthorized);
         }
 
         let config = TokenConfig {
@@ -146,7 +164,7 @@ impl PaymentProcessorContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, PaymentProcessorError::Unauthorized);
         }
 
         env.storage()
@@ -166,25 +184,25 @@ impl PaymentProcessorContract {
         payer.require_auth();
 
         if payer == recipient {
-            panic!("cannot pay yourself");
+            panic_with_error!(&env, PaymentProcessorError::CannotPayYourself);
         }
 
         if amount <= 0 {
-            panic!("invalid amount");
+            panic_with_error!(&env, PaymentProcessorError::InvalidAmount);
         }
 
         let config: TokenConfig = env
             .storage()
             .persistent()
             .get(&DataKey::TokenConfig(token.clone()))
-            .expect("token not whitelisted");
+            .unwrap_or_else(|| panic_with_error!(&env, PaymentProcessorError::TokenNotWhitelisted));
 
         if !config.enabled {
-            panic!("token disabled");
+            panic_with_error!(&env, PaymentProcessorError::TokenDisabled);
         }
 
         if amount < config.min_amount {
-            panic!("amount below minimum");
+            panic_with_error!(&env, PaymentProcessorError::AmountBelowMinimum);
         }
 
         // Check daily limit
@@ -193,7 +211,7 @@ impl PaymentProcessorContract {
         let daily_vol: i128 = env.storage().temporary().get(&daily_key).unwrap_or(0);
 
         if daily_vol + amount > config.daily_limit {
-            panic!("daily limit exceeded");
+            panic_with_error!(&env, PaymentProcessorError::DailyLimitExceeded);
         }
 
         // Calculate fee
@@ -259,10 +277,7 @@ impl PaymentProcessorContract {
         // Update revenue stats
         Self::_update_revenue_stats(&env, &token, fee, amount);
 
-        env.events().publish(
-            (symbol_short!("payment"), symbol_short!("processed")),
-            (payment_id, payer, recipient, amount),
-        );
+        events::publish(&env, EVENT_TAG, PaymentProcessed { payment_id, payer, recipient, amount });
 
         payment_id
     }
@@ -273,10 +288,10 @@ impl PaymentProcessorContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, PaymentProcessorError::Unauthorized);
         }
         if fee_bps > 1000 {
-            panic!("fee too high"); // max 10%
+            panic_with_error!(&env, PaymentProcessorError::FeeTooHigh); // max 10%
         }
         env.storage()
             .instance()
@@ -0,0 +1,359 @@
+//! PulsarTrack - Payment Stream (Soroban)
+//! Continuous micro-payouts from campaign funds to publishers on Stellar.
+//!
+//! A stream accrues either linearly over time or per verified impression
+//! reported by the impression oracle, capped at its deposit. The recipient
+//! withdraws whatever has accrued at any time; cancelling splits the deposit
+//! pro rata between recipient and sender.
+//!
+//! Events are published as ("streams", action, version) with a typed payload
+//! (see the Events section):
+//! - "stream_created": `StreamCreated`
+//! - "impressions_recorded": `ImpressionsRecorded`
+//! - "stream_withdrawn": `StreamWithdrawn`
+//! - "stream_cancelled": `StreamCancelled`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    token, Address, Env,
+};
+pub use pulsartrack_errors::PaymentStreamError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+/// How a stream accrues
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum StreamRate {
+    PerSecond(i128),
+    PerImpression(i128),
+}
+
+/// Stream terms supplied when the stream is created
+#[contracttype]
+#[derive(Clone)]
+pub struct StreamTerms {
+    pub rate: StreamRate,
+    pub deposit: i128,
+    pub start_time: u64, // per-second streams accrue from here; clamped to now
+}
+
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum StreamStatus {
+    Active,
+    Cancelled,
+    Depleted, // deposit fully withdrawn
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Stream {
+    pub sender: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub campaign_id: u64,
+    pub rate: StreamRate,
+    pub deposit: i128,
+    pub withdrawn: i128,
+    pub start_time: u64, // per-second streams accrue from here
+    pub impressions: u64, // verified impressions reported so far
+    pub status: StreamStatus,
+    pub created_at: u64,
+    pub cancelled_at: Option<u64>,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct StreamCreated {
+    pub stream_id: u64,
+    pub sender: Address,
+    pub recipient: Address,
+    pub deposit: i128,
+}
+contract_event!(StreamCreated, "stream_created");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ImpressionsRecorded {
+    pub stream_id: u64,
+    pub count: u64,
+}
+contract_event!(ImpressionsRecorded, "impressions_recorded");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct StreamWithdrawn {
+    pub stream_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+}
+contract_event!(StreamWithdrawn, "stream_withdrawn");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct StreamCancelled {
+    pub stream_id: u64,
+    pub recipient_amount: i128,
+    pub sender_amount: i128,
+}
+contract_event!(StreamCancelled, "stream_cancelled");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    ImpressionOracle,
+    StreamCounter,
+    Stream(u64),
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const EVENT_TAG: &str = "streams";
+
+#[contract]
+pub struct PaymentStreamContract;
+
+#[contractimpl]
+impl PaymentStreamContract {
+    pub fn initialize(env: Env, admin: Address, impression_oracle: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, PaymentStreamError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::ImpressionOracle, &impression_oracle);
+        env.storage().instance().set(&DataKey::StreamCounter, &0u64);
+    }
+
+    /// Replace the oracle allowed to report verified impressions (admin only)
+    pub fn set_impression_oracle(env: Env, admin: Address, oracle: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if admin != stored_admin {
+            panic_with_error!(&env, PaymentStreamError::Unauthorized);
+        }
+        env.storage().instance().set(&DataKey::ImpressionOracle, &oracle);
+    }
+
+    /// Lock the terms' deposit from the sender and start streaming it to the
+    /// recipient
+    pub fn create_stream(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token: Address,
+        campaign_id: u64,
+        terms: StreamTerms,
+    ) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        sender.require_auth();
+        let StreamTerms { rate, deposit, start_time } = terms;
+
+        if deposit <= 0 {
+            panic_with_error!(&env, PaymentStreamError::InvalidDeposit);
+        }
+        match rate {
+            StreamRate::PerSecond(r) | StreamRate::PerImpression(r) => {
+                if r <= 0 || r > deposit {
+                    panic_with_error!(&env, PaymentStreamError::InvalidRate);
+                }
+            }
+        }
+        if sender == recipient {
+            panic_with_error!(&env, PaymentStreamError::SenderIsRecipient);
+        }
+
+        token::Client::new(&env, &token).transfer(&sender, &env.current_contract_address(), &deposit);
+
+        let counter: u64 = env.storage().instance().get(&DataKey::StreamCounter).unwrap_or(0);
+        let stream_id = counter + 1;
+        let now = env.ledger().timestamp();
+
+        let stream = Stream {
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+            token,
+            campaign_id,
+            rate,
+            deposit,
+            withdrawn: 0,
+            start_time: start_time.max(now),
+            impressions: 0,
+            status: StreamStatus::Active,
+            created_at: now,
+            cancelled_at: None,
+        };
+        Self::_save_stream(&env, stream_id, &stream);
+        env.storage().instance().set(&DataKey::StreamCounter, &stream_id);
+
+        events::publish(&env, EVENT_TAG, StreamCreated { stream_id, sender, recipient, deposit });
+
+        stream_id
+    }
+
+    /// Report verified impressions for a per-impression stream (oracle only)
+    pub fn record_impressions(env: Env, oracle: Address, stream_id: u64, count: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        oracle.require_auth();
+        let stored_oracle: Address = env.storage().instance().get(&DataKey::ImpressionOracle).unwrap();
+        if oracle != stored_oracle {
+            panic_with_error!(&env, PaymentStreamError::Unauthorized);
+        }
+
+        let mut stream = Self::_load_stream(&env, stream_id);
+        if stream.status != StreamStatus::Active {
+            panic_with_error!(&env, PaymentStreamError::StreamNotActive);
+        }
+        if !matches!(stream.rate, StreamRate::PerImpression(_)) {
+            panic_with_error!(&env, PaymentStreamError::NotAnImpressionStream);
+        }
+
+        stream.impressions = stream.impressions.saturating_add(count);
+        Self::_save_stream(&env, stream_id, &stream);
+
+        events::publish(&env, EVENT_TAG, ImpressionsRecorded { stream_id, count });
+    }
+
+    /// Pay out everything accrued to the recipient so far
+    pub fn withdraw_stream(env: Env, recipient: Address, stream_id: u64) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        recipient.require_auth();
+
+        let mut stream = Self::_load_stream(&env, stream_id);
+        if recipient != stream.recipient {
+            panic_with_error!(&env, PaymentStreamError::Unauthorized);
+        }
+        if stream.status != StreamStatus::Active {
+            panic_with_error!(&env, PaymentStreamError::StreamNotActive);
+        }
+
+        let amount = Self::_accrued(&env, &stream) - stream.withdrawn;
+        if amount <= 0 {
+            panic_with_error!(&env, PaymentStreamError::NothingToWithdraw);
+        }
+
+        stream.withdrawn += amount;
+        if stream.withdrawn == stream.deposit {
+            stream.status = StreamStatus::Depleted;
+        }
+        Self::_save_stream(&env, stream_id, &stream);
+
+        token::Client::new(&env, &stream.token).transfer(&env.current_contract_address(), &recipient, &amount);
+
+        events::publish(&env, EVENT_TAG, StreamWithdrawn { stream_id, recipient, amount });
+
+        amount
+    }
+
+    /// Stop a stream (sender or recipient). The recipient receives what has
+    /// accrued and not yet been withdrawn; the sender gets back the rest.
+    pub fn cancel_stream(env: Env, caller: Address, stream_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        caller.require_auth();
+
+        let mut stream = Self::_load_stream(&env, stream_id);
+        if caller != stream.sender && caller != stream.recipient {
+            panic_with_error!(&env, PaymentStreamError::Unauthorized);
+        }
+        if stream.status != StreamStatus::Active {
+            panic_with_error!(&env, PaymentStreamError::StreamNotActive);
+        }
+
+        let accrued = Self::_accrued(&env, &stream);
+        let recipient_amount = accrued - stream.withdrawn;
+        let sender_amount = stream.deposit - accrued;
+
+        stream.withdrawn = accrued;
+        stream.status = StreamStatus::Cancelled;
+        stream.cancelled_at = Some(env.ledger().timestamp());
+        Self::_save_stream(&env, stream_id, &stream);
+
+        let token_client = token::Client::new(&env, &stream.token);
+        if recipient_amount > 0 {
+            token_client.transfer(&env.current_contract_address(), &stream.recipient, &recipient_amount);
+        }
+        if sender_amount > 0 {
+            token_client.transfer(&env.current_contract_address(), &stream.sender, &sender_amount);
+        }
+
+        events::publish(&env, EVENT_TAG, StreamCancelled { stream_id, recipient_amount, sender_amount });
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn get_stream(env: Env, stream_id: u64) -> Option<Stream> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Stream(stream_id))
+    }
+
+    /// Amount the recipient could withdraw right now
+    pub fn withdrawable(env: Env, stream_id: u64) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let stream = Self::_load_stream(&env, stream_id);
+        if stream.status != StreamStatus::Active {
+            return 0;
+        }
+        Self::_accrued(&env, &stream) - stream.withdrawn
+    }
+
+    pub fn get_stream_count(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::StreamCounter).unwrap_or(0)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _load_stream(env: &Env, stream_id: u64) -> Stream {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Stream(stream_id))
+            .unwrap_or_else(|| panic_with_error!(&env, PaymentStreamError::StreamNotFound))
+    }
+
+    fn _save_stream(env: &Env, stream_id: u64, stream: &Stream) {
+        let _ttl_key = DataKey::Stream(stream_id);
+        env.storage().persistent().set(&_ttl_key, stream);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    /// Total earned by the recipient so far, capped at the deposit
+    fn _accrued(env: &Env, stream: &Stream) -> i128 {
+        let earned = match stream.rate {
+            StreamRate::PerSecond(rate) => {
+                let elapsed = env.ledger().timestamp().saturating_sub(stream.start_time);
+                rate.saturating_mul(elapsed as i128)
+            }
+            StreamRate::PerImpression(rate) => rate.saturating_mul(stream.impressions as i128),
+        };
+        earned.min(stream.deposit)
+    }
+}
+
+mod test;
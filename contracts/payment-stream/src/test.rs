@@ -0,0 +1,113 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn setup(env: &Env) -> (PaymentStreamContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, PaymentStreamContract);
+    let client = PaymentStreamContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let oracle = Address::generate(env);
+    client.initialize(&admin, &oracle);
+
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    (client, oracle, token)
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PaymentStreamContract);
+    let client = PaymentStreamContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    assert_eq!(client.get_stream_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4001)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PaymentStreamContract);
+    let client = PaymentStreamContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    client.initialize(&admin, &oracle);
+}
+
+#[test]
+fn test_per_second_stream_withdraw_and_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _oracle, token) = setup(&env);
+    let balance = token::Client::new(&env, &token);
+
+    let campaign = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&campaign, &1_000);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let id = client.create_stream(
+        &campaign,
+        &publisher,
+        &token,
+        &1,
+        &StreamTerms { rate: StreamRate::PerSecond(2), deposit: 1_000, start_time: 100 },
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 150);
+    assert_eq!(client.withdrawable(&id), 100);
+    assert_eq!(client.withdraw_stream(&publisher, &id), 100);
+    assert!(client.try_withdraw_stream(&publisher, &id).is_err());
+
+    // Cancelling pays the recipient's accrued share and refunds the rest
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.cancel_stream(&campaign, &id);
+    assert_eq!(balance.balance(&publisher), 200);
+    assert_eq!(balance.balance(&campaign), 800);
+    assert!(client.get_stream(&id).unwrap().status == StreamStatus::Cancelled);
+    assert_eq!(client.withdrawable(&id), 0);
+}
+
+#[test]
+fn test_per_impression_stream_capped_at_deposit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, oracle, token) = setup(&env);
+    let balance = token::Client::new(&env, &token);
+
+    let campaign = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&campaign, &500);
+    let id = client.create_stream(
+        &campaign,
+        &publisher,
+        &token,
+        &1,
+        &StreamTerms { rate: StreamRate::PerImpression(3), deposit: 500, start_time: 0 },
+    );
+
+    // Time alone earns nothing on an impression stream
+    env.ledger().with_mut(|li| li.timestamp += 10_000);
+    assert_eq!(client.withdrawable(&id), 0);
+    assert!(client.try_record_impressions(&publisher, &id, &10).is_err());
+
+    client.record_impressions(&oracle, &id, &100);
+    assert_eq!(client.withdraw_stream(&publisher, &id), 300);
+    client.record_impressions(&oracle, &id, &1_000);
+    assert_eq!(client.withdraw_stream(&publisher, &id), 200);
+
+    assert_eq!(balance.balance(&publisher), 500);
+    assert!(client.get_stream(&id).unwrap().status == StreamStatus::Depleted);
+    assert!(client.try_cancel_stream(&campaign, &id).is_err());
+}
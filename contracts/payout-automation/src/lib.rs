@@ -1,15 +1,17 @@
 //! PulsarTrack - Payout Automation (Soroban)
 //! Automated publisher payouts and scheduled payments on Stellar.
 //!
-//! Events:
-//! - ("payout", "schedule"): [payout_id: u64, recipient: Address, amount: i128]
-//! - ("payout", "execute"): [payout_id: u64, amount: i128]
+//! Events are published as ("payouts", action, version) with a typed payload:
+//! - "payout_scheduled": `PayoutScheduled`
+//! - "payout_executed": `PayoutExecuted`
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     token, Address, Env,
 };
+pub use pulsartrack_errors::PayoutAutomationError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -44,6 +46,23 @@ pub struct PublisherEarnings {
     pub last_payout: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct PayoutScheduled {
+    pub payout_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+}
+contract_event!(PayoutScheduled, "payout_scheduled");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PayoutExecuted {
+    pub payout_id: u64,
+    pub amount: i128,
+}
+contract_event!(PayoutExecuted, "payout_executed");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -59,6 +78,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "payouts";
+
 #[contract]
 pub struct PayoutAutomationContract;
 
@@ -67,7 +88,7 @@ impl PayoutAutomationContract {
     pub fn initialize(env: Env, admin: Address, token: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, PayoutAutomationError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -88,7 +109,7 @@ impl PayoutAutomationContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, PayoutAutomationError::Unauthorized);
         }
 
         let counter: u64 = env.storage().instance().get(&DataKey::PayoutCounter).unwrap_or(0);
@@ -113,10 +134,7 @@ impl PayoutAutomationContract {
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
         env.storage().instance().set(&DataKey::PayoutCounter, &payout_id);
 
-        env.events().publish(
-            (symbol_short!("payout"), symbol_short!("schedule")),
-            (payout_id, recipient, amount),
-        );
+        events::publish(&env, EVENT_TAG, PayoutScheduled { payout_id, recipient, amount });
 
         payout_id
     }
@@ -127,14 +145,14 @@ impl PayoutAutomationContract {
             .storage()
             .persistent()
             .get(&DataKey::Payout(payout_id))
-            .expect("payout not found");
+            .unwrap_or_else(|| panic_with_error!(&env, PayoutAutomationError::PayoutNotFound));
 
         if payout.status != PayoutStatus::Scheduled {
-            panic!("payout not scheduled");
+            panic_with_error!(&env, PayoutAutomationError::PayoutNotScheduled);
         }
 
         if env.ledger().timestamp() < payout.execute_after {
-            panic!("too early to execute");
+            panic_with_error!(&env, PayoutAutomationError::TooEarlyToExecute);
         }
 
         let token_client = token::Client::new(&env, &payout.token);
@@ -169,10 +187,7 @@ impl PayoutAutomationContract {
         env.storage().persistent().set(&key, &earnings);
         env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("payout"), symbol_short!("execute")),
-            (payout_id, payout.amount),
-        );
+        events::publish(&env, EVENT_TAG, PayoutExecuted { payout_id, amount: payout.amount });
     }
 
     pub fn add_publisher_earnings(env: Env, admin: Address, publisher: Address, amount: i128) {
@@ -180,7 +195,7 @@ impl PayoutAutomationContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, PayoutAutomationError::Unauthorized);
         }
 
         let key = DataKey::PublisherEarnings(publisher.clone());
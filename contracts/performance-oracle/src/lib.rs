@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, BytesN, Env,
 };
+pub use pulsartrack_errors::PerformanceOracleError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone)]
@@ -34,6 +36,13 @@ pub struct OracleConsensus {
     pub last_updated: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct PerformanceAttested {
+    pub campaign_id: u64,
+}
+contract_event!(PerformanceAttested, "performance_attested");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -50,6 +59,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "performance";
+
 #[contract]
 pub struct PerformanceOracleContract;
 
@@ -58,7 +69,7 @@ impl PerformanceOracleContract {
     pub fn initialize(env: Env, admin: Address, min_attesters: u32) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, PerformanceOracleError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -71,7 +82,7 @@ impl PerformanceOracleContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, PerformanceOracleError::Unauthorized);
         }
         let _ttl_key = DataKey::Attester(attester);
         env.storage().persistent().set(&_ttl_key, &true);
@@ -98,11 +109,11 @@ impl PerformanceOracleContract {
             .unwrap_or(false);
 
         if !is_auth {
-            panic!("not authorized attester");
+            panic_with_error!(&env, PerformanceOracleError::NotAuthorizedAttester);
         }
 
         if env.storage().persistent().has(&DataKey::Attestation(campaign_id, attester.clone())) {
-            panic!("already attested");
+            panic_with_error!(&env, PerformanceOracleError::AlreadyAttested);
         }
 
         let attestation = PerformanceAttestation {
@@ -133,10 +144,7 @@ impl PerformanceOracleContract {
         // Attempt to build consensus
         Self::_try_build_consensus(&env, campaign_id, impressions, clicks, fraud_rate, quality_score, count + 1);
 
-        env.events().publish(
-            (symbol_short!("oracle"), symbol_short!("attested")),
-            campaign_id,
-        );
+        events::publish(&env, EVENT_TAG, PerformanceAttested { campaign_id });
     }
 
     pub fn get_attestation(env: Env, campaign_id: u64, attester: Address) -> Option<PerformanceAttestation> {
@@ -0,0 +1,363 @@
+//! PulsarTrack - Placement NFT (Soroban)
+//! Transferable tokens for exclusive ad placement slots on Stellar.
+//!
+//! Each token is one placement slot: a domain, a position on it and a time
+//! window. The first publisher to mint on a domain owns it, and no two slots
+//! for the same domain and position may overlap in time. The campaign
+//! orchestrator checks `holds_slot` before scheduling a campaign into a
+//! placement.
+//!
+//! Tokens change hands freely with `transfer`, or are sold through the
+//! built-in listing. Every sale pays the minting publisher a royalty, fixed
+//! at mint, out of the price.
+//!
+//! Events are published as ("placements", action, version) with a typed payload
+//! (see the Events section):
+//! - "slot_minted": `SlotMinted`
+//! - "slot_transferred": `SlotTransferred`
+//! - "slot_listed": `SlotListed`
+//! - "slot_unlisted": `SlotUnlisted`
+//! - "slot_sold": `SlotSold`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    token, Address, Env, String, Symbol, Vec,
+};
+pub use pulsartrack_errors::PlacementNftError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PlacementSlot {
+    pub publisher: Address, // original minter, paid royalties
+    pub domain: String,
+    pub position: Symbol, // e.g. `header`, `sidebar`
+    pub starts_at: u64,
+    pub ends_at: u64,
+    pub royalty_bps: u32,
+    pub minted_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Listing {
+    pub seller: Address,
+    pub price: i128,
+    pub listed_at: u64,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SlotMinted {
+    pub token_id: u64,
+    pub publisher: Address,
+    pub starts_at: u64,
+    pub ends_at: u64,
+}
+contract_event!(SlotMinted, "slot_minted");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SlotTransferred {
+    pub token_id: u64,
+    pub from: Address,
+    pub to: Address,
+}
+contract_event!(SlotTransferred, "slot_transferred");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SlotListed {
+    pub token_id: u64,
+    pub seller: Address,
+    pub price: i128,
+}
+contract_event!(SlotListed, "slot_listed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SlotUnlisted {
+    pub token_id: u64,
+}
+contract_event!(SlotUnlisted, "slot_unlisted");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SlotSold {
+    pub token_id: u64,
+    pub seller: Address,
+    pub buyer: Address,
+    pub price: i128,
+    pub royalty: i128,
+}
+contract_event!(SlotSold, "slot_sold");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    PaymentToken,
+    TokenCounter,
+    Slot(u64),
+    Owner(u64),
+    Listing(u64),
+    DomainPublisher(String),
+    PositionSlots(String, Symbol), // domain, position -> Vec<u64>
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const MAX_ROYALTY_BPS: u32 = 2_500;
+const MAX_SLOTS_PER_POSITION: u32 = 200;
+
+const EVENT_TAG: &str = "placements";
+
+#[contract]
+pub struct PlacementNftContract;
+
+#[contractimpl]
+impl PlacementNftContract {
+    pub fn initialize(env: Env, admin: Address, payment_token: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, PlacementNftError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::PaymentToken, &payment_token);
+        env.storage().instance().set(&DataKey::TokenCounter, &0u64);
+    }
+
+    /// Mint a placement slot to the publisher. The domain must be unclaimed
+    /// or already the publisher's, and the window must not overlap another
+    /// slot for the same position.
+    pub fn mint(
+        env: Env,
+        publisher: Address,
+        domain: String,
+        position: Symbol,
+        starts_at: u64,
+        ends_at: u64,
+        royalty_bps: u32,
+    ) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+        if starts_at >= ends_at || ends_at <= env.ledger().timestamp() {
+            panic_with_error!(&env, PlacementNftError::InvalidWindow);
+        }
+        if royalty_bps > MAX_ROYALTY_BPS {
+            panic_with_error!(&env, PlacementNftError::RoyaltyTooHigh);
+        }
+
+        let domain_key = DataKey::DomainPublisher(domain.clone());
+        match env.storage().persistent().get::<DataKey, Address>(&domain_key) {
+            Some(owner) if owner != publisher => panic_with_error!(&env, PlacementNftError::DomainOwnedByAnotherPublisher),
+            Some(_) => {}
+            None => {
+                env.storage().persistent().set(&domain_key, &publisher);
+            }
+        }
+        env.storage().persistent().extend_ttl(&domain_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        let position_key = DataKey::PositionSlots(domain.clone(), position.clone());
+        let mut slots: Vec<u64> = env.storage().persistent().get(&position_key).unwrap_or(Vec::new(&env));
+        if slots.len() >= MAX_SLOTS_PER_POSITION {
+            panic_with_error!(&env, PlacementNftError::TooManySlots);
+        }
+        for id in slots.iter() {
+            let other = Self::_load_slot(&env, id);
+            if starts_at < other.ends_at && other.starts_at < ends_at {
+                panic_with_error!(&env, PlacementNftError::SlotWindowOverlaps);
+            }
+        }
+
+        let counter: u64 = env.storage().instance().get(&DataKey::TokenCounter).unwrap_or(0);
+        let token_id = counter + 1;
+        let slot = PlacementSlot {
+            publisher: publisher.clone(),
+            domain,
+            position,
+            starts_at,
+            ends_at,
+            royalty_bps,
+            minted_at: env.ledger().timestamp(),
+        };
+        let _ttl_key = DataKey::Slot(token_id);
+        env.storage().persistent().set(&_ttl_key, &slot);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_set_owner(&env, token_id, &publisher);
+
+        slots.push_back(token_id);
+        env.storage().persistent().set(&position_key, &slots);
+        env.storage().persistent().extend_ttl(&position_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        env.storage().instance().set(&DataKey::TokenCounter, &token_id);
+
+        events::publish(&env, EVENT_TAG, SlotMinted { token_id, publisher, starts_at, ends_at });
+
+        token_id
+    }
+
+    /// Hand a slot to another address without a sale. Cancels any listing.
+    pub fn transfer(env: Env, from: Address, to: Address, token_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        from.require_auth();
+        Self::_require_owner(&env, &from, token_id);
+        env.storage().persistent().remove(&DataKey::Listing(token_id));
+        Self::_set_owner(&env, token_id, &to);
+
+        events::publish(&env, EVENT_TAG, SlotTransferred { token_id, from, to });
+    }
+
+    pub fn list(env: Env, seller: Address, token_id: u64, price: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        seller.require_auth();
+        Self::_require_owner(&env, &seller, token_id);
+        if price <= 0 {
+            panic_with_error!(&env, PlacementNftError::InvalidPrice);
+        }
+        let listing = Listing {
+            seller: seller.clone(),
+            price,
+            listed_at: env.ledger().timestamp(),
+        };
+        let _ttl_key = DataKey::Listing(token_id);
+        env.storage().persistent().set(&_ttl_key, &listing);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, SlotListed { token_id, seller, price });
+    }
+
+    pub fn unlist(env: Env, seller: Address, token_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        seller.require_auth();
+        Self::_require_owner(&env, &seller, token_id);
+        if !env.storage().persistent().has(&DataKey::Listing(token_id)) {
+            panic_with_error!(&env, PlacementNftError::NotListed);
+        }
+        env.storage().persistent().remove(&DataKey::Listing(token_id));
+
+        events::publish(&env, EVENT_TAG, SlotUnlisted { token_id });
+    }
+
+    /// Buy a listed slot. The minting publisher's royalty comes out of the
+    /// price; a publisher selling their own slot keeps the whole price.
+    pub fn buy(env: Env, buyer: Address, token_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        buyer.require_auth();
+        let listing: Listing = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Listing(token_id))
+            .unwrap_or_else(|| panic_with_error!(&env, PlacementNftError::NotListed));
+        if buyer == listing.seller {
+            panic_with_error!(&env, PlacementNftError::BuyerIsSeller);
+        }
+        let slot = Self::_load_slot(&env, token_id);
+
+        let payment_token: Address = env.storage().instance().get(&DataKey::PaymentToken).unwrap();
+        let token_client = token::Client::new(&env, &payment_token);
+        let royalty = if listing.seller == slot.publisher {
+            0
+        } else {
+            listing.price * slot.royalty_bps as i128 / 10_000
+        };
+        if royalty > 0 {
+            token_client.transfer(&buyer, &slot.publisher, &royalty);
+        }
+        token_client.transfer(&buyer, &listing.seller, &(listing.price - royalty));
+
+        env.storage().persistent().remove(&DataKey::Listing(token_id));
+        Self::_set_owner(&env, token_id, &buyer);
+
+        events::publish(&env, EVENT_TAG, SlotSold { token_id, seller: listing.seller, buyer, price: listing.price, royalty });
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn owner_of(env: Env, token_id: u64) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Owner(token_id))
+    }
+
+    pub fn get_slot(env: Env, token_id: u64) -> Option<PlacementSlot> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Slot(token_id))
+    }
+
+    pub fn get_listing(env: Env, token_id: u64) -> Option<Listing> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Listing(token_id))
+    }
+
+    /// Whether `holder` owns the slot and its window has not ended
+    pub fn holds_slot(env: Env, holder: Address, token_id: u64) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let owner: Option<Address> = env.storage().persistent().get(&DataKey::Owner(token_id));
+        if owner != Some(holder) {
+            return false;
+        }
+        Self::_load_slot(&env, token_id).ends_at > env.ledger().timestamp()
+    }
+
+    pub fn get_domain_publisher(env: Env, domain: String) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::DomainPublisher(domain))
+    }
+
+    pub fn get_token_count(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::TokenCounter).unwrap_or(0)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _load_slot(env: &Env, token_id: u64) -> PlacementSlot {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Slot(token_id))
+            .unwrap_or_else(|| panic_with_error!(&env, PlacementNftError::TokenNotFound))
+    }
+
+    fn _require_owner(env: &Env, who: &Address, token_id: u64) {
+        let owner: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Owner(token_id))
+            .unwrap_or_else(|| panic_with_error!(&env, PlacementNftError::TokenNotFound));
+        if owner != *who {
+            panic_with_error!(&env, PlacementNftError::NotTokenOwner);
+        }
+    }
+
+    fn _set_owner(env: &Env, token_id: u64, owner: &Address) {
+        let _ttl_key = DataKey::Owner(token_id);
+        env.storage().persistent().set(&_ttl_key, owner);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+}
+
+mod test;
@@ -0,0 +1,95 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{testutils::{Address as _, Ledger}, token, Address, Env, String, Symbol};
+
+fn setup(env: &Env) -> (PlacementNftContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, PlacementNftContract);
+    let client = PlacementNftContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    client.initialize(&admin, &token);
+    (client, admin, token)
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _token) = setup(&env);
+    assert_eq!(client.get_token_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4301)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token) = setup(&env);
+    client.initialize(&admin, &token);
+}
+
+#[test]
+fn test_slots_are_exclusive_per_domain_and_position() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _token) = setup(&env);
+    let publisher = Address::generate(&env);
+    let squatter = Address::generate(&env);
+    let domain = String::from_str(&env, "news.example");
+    let header = Symbol::new(&env, "header");
+    let sidebar = Symbol::new(&env, "sidebar");
+
+    let first = client.mint(&publisher, &domain, &header, &1_000, &2_000, &500);
+    assert_eq!(client.owner_of(&first), Some(publisher.clone()));
+    assert_eq!(client.get_domain_publisher(&domain), Some(publisher.clone()));
+
+    assert!(client.try_mint(&publisher, &domain, &header, &1_500, &2_500, &500).is_err());
+    assert!(client.try_mint(&squatter, &domain, &sidebar, &1_000, &2_000, &500).is_err());
+    assert!(client.try_mint(&publisher, &domain, &sidebar, &1_000, &2_000, &5_000).is_err());
+    assert!(client.try_mint(&publisher, &domain, &sidebar, &2_000, &1_000, &500).is_err());
+
+    // Back-to-back windows and other positions are fine
+    client.mint(&publisher, &domain, &header, &2_000, &3_000, &500);
+    client.mint(&publisher, &domain, &sidebar, &1_000, &2_000, &500);
+    assert_eq!(client.get_token_count(), 3);
+}
+
+#[test]
+fn test_secondary_sales_pay_royalty_to_publisher() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, token) = setup(&env);
+    let balance = token::Client::new(&env, &token);
+    let publisher = Address::generate(&env);
+    let first_buyer = Address::generate(&env);
+    let second_buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&first_buyer, &10_000);
+    token::StellarAssetClient::new(&env, &token).mint(&second_buyer, &10_000);
+
+    let slot = client.mint(&publisher, &String::from_str(&env, "blog.example"), &Symbol::new(&env, "footer"), &1_000, &5_000, &1_000);
+    assert!(client.try_list(&first_buyer, &slot, &1_000).is_err());
+
+    // The publisher's own sale carries no royalty
+    client.list(&publisher, &slot, &1_000);
+    client.buy(&first_buyer, &slot);
+    assert_eq!(balance.balance(&publisher), 1_000);
+    assert!(client.holds_slot(&first_buyer, &slot));
+    assert!(!client.holds_slot(&publisher, &slot));
+
+    client.list(&first_buyer, &slot, &4_000);
+    assert!(client.try_buy(&first_buyer, &slot).is_err());
+    client.buy(&second_buyer, &slot);
+    assert_eq!(balance.balance(&publisher), 1_400);
+    assert_eq!(balance.balance(&first_buyer), 9_000 + 3_600);
+    assert!(client.get_listing(&slot).is_none());
+
+    // Plain transfers move the token and drop any listing
+    client.list(&second_buyer, &slot, &9_000);
+    client.transfer(&second_buyer, &first_buyer, &slot);
+    assert!(client.get_listing(&slot).is_none());
+    assert!(client.holds_slot(&first_buyer, &slot));
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    assert!(!client.holds_slot(&first_buyer, &slot));
+}
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Bytes, BytesN, Env, String,
 };
+pub use pulsartrack_errors::PrivacyLayerError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone)]
@@ -42,6 +44,27 @@ pub struct DataRequest {
     pub requested_at: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct ConsentSet {
+    pub user: Address,
+}
+contract_event!(ConsentSet, "consent_set");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ConsentRevoked {
+    pub user: Address,
+}
+contract_event!(ConsentRevoked, "consent_revoked");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ProofSubmitted {
+    pub prover: Address,
+}
+contract_event!(ProofSubmitted, "proof_submitted");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -56,6 +79,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "privacy";
+
 #[contract]
 pub struct PrivacyLayerContract;
 
@@ -64,7 +89,7 @@ impl PrivacyLayerContract {
     pub fn initialize(env: Env, admin: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, PrivacyLayerError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -101,10 +126,7 @@ impl PrivacyLayerContract {
         env.storage().persistent().set(&_ttl_key, &consent);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("privacy"), symbol_short!("consent")),
-            user,
-        );
+        events::publish(&env, EVENT_TAG, ConsentSet { user });
     }
 
     pub fn revoke_consent(env: Env, user: Address) {
@@ -112,10 +134,7 @@ impl PrivacyLayerContract {
         user.require_auth();
         env.storage().persistent().remove(&DataKey::Consent(user.clone()));
 
-        env.events().publish(
-            (symbol_short!("privacy"), symbol_short!("revoked")),
-            user,
-        );
+        events::publish(&env, EVENT_TAG, ConsentRevoked { user });
     }
 
     pub fn submit_zkp(
@@ -143,10 +162,7 @@ impl PrivacyLayerContract {
         env.storage().persistent().set(&_ttl_key, &proof);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("zkp"), symbol_short!("submitted")),
-            prover,
-        );
+        events::publish(&env, EVENT_TAG, ProofSubmitted { prover });
 
         proof_id.into()
     }
@@ -156,14 +172,14 @@ impl PrivacyLayerContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, PrivacyLayerError::Unauthorized);
         }
 
         let mut proof: AnonymousSegmentProof = env
             .storage()
             .persistent()
             .get(&DataKey::Proof(proof_id.clone()))
-            .expect("proof not found");
+            .unwrap_or_else(|| panic_with_error!(&env, PrivacyLayerError::ProofNotFound));
 
         proof.verified = true;
         let _ttl_key = DataKey::Proof(proof_id);
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Env, String, Vec,
 };
+pub use pulsartrack_errors::PublisherNetworkError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone)]
@@ -39,6 +41,13 @@ pub struct NetworkStats {
     pub last_updated: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherJoined {
+    pub publisher: Address,
+}
+contract_event!(PublisherJoined, "publisher_joined");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -52,6 +61,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "network";
+
 #[contract]
 pub struct PublisherNetworkContract;
 
@@ -60,7 +71,7 @@ impl PublisherNetworkContract {
     pub fn initialize(env: Env, admin: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, PublisherNetworkError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -87,7 +98,7 @@ impl PublisherNetworkContract {
         publisher.require_auth();
 
         if env.storage().persistent().has(&DataKey::Node(publisher.clone())) {
-            panic!("already in network");
+            panic_with_error!(&env, PublisherNetworkError::AlreadyInNetwork);
         }
 
         let node = NetworkNode {
@@ -115,10 +126,7 @@ impl PublisherNetworkContract {
         stats.total_capacity += capacity;
         env.storage().instance().set(&DataKey::NetworkStats, &stats);
 
-        env.events().publish(
-            (symbol_short!("network"), symbol_short!("joined")),
-            publisher,
-        );
+        events::publish(&env, EVENT_TAG, PublisherJoined { publisher });
     }
 
     pub fn heartbeat(env: Env, publisher: Address) {
@@ -129,7 +137,7 @@ impl PublisherNetworkContract {
             .storage()
             .persistent()
             .get(&DataKey::Node(publisher.clone()))
-            .expect("not in network");
+            .unwrap_or_else(|| panic_with_error!(&env, PublisherNetworkError::NotInNetwork));
 
         node.last_heartbeat = env.ledger().timestamp();
         let _ttl_key = DataKey::Node(publisher);
@@ -145,7 +153,7 @@ impl PublisherNetworkContract {
             .storage()
             .persistent()
             .get(&DataKey::Node(publisher.clone()))
-            .expect("not in network");
+            .unwrap_or_else(|| panic_with_error!(&env, PublisherNetworkError::NotInNetwork));
 
         node.is_active = false;
         let _ttl_key = DataKey::Node(publisher);
@@ -4,14 +4,21 @@
 #![no_std]
 use soroban_sdk::{
     contract, contractimpl, contracttype, symbol_short,
-    Address, Env,
+    token, Address, BytesN, Env, IntoVal, Symbol, Vec,
 };
+pub use pulsartrack_errors::ReputationError;
+use pulsartrack_events::{self as events, contract_event, RoleGranted, RoleRevoked};
+use pulsartrack_access::{Ownable, RoleBased};
+use pulsartrack_upgrade::{ContractVersion, Upgradeable};
+use pulsartrack_storage::{self as storage, Persistent, TtlPolicy};
 
 #[contracttype]
 #[derive(Clone)]
 pub struct ReputationScore {
     pub publisher: Address,
-    pub score: u32,          // 0-1000
+    pub score: u32,          // 0-1000, composite; see `_composite_score`
+    pub review_score: u32,   // 0-1000, moved only by reviews
+    pub slash_penalty: u32,  // points removed by applied slashes
     pub total_reviews: u64,
     pub positive_reviews: u64,
     pub negative_reviews: u64,
@@ -21,6 +28,15 @@ pub struct ReputationScore {
     pub last_updated: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReviewStatus {
+    Active,
+    Disputed,
+    Upheld,
+    Voided,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct ReviewEntry {
@@ -28,47 +44,380 @@ pub struct ReviewEntry {
     pub campaign_id: u64,
     pub positive: bool,
     pub rating: u32,  // 1-5
+    pub spend: i128,  // campaign spend attested for this review
     pub timestamp: u64,
+    pub score_impact: u32, // points actually added or removed, reversed if voided
+    pub status: ReviewStatus,
+    pub response_hash: Option<BytesN<32>>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SlashStatus {
+    Proposed,
+    Applied,
+    Appealed,
+    Reversed,
+    Upheld,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SlashProposal {
+    pub slash_id: u64,
+    pub publisher: Address,
+    pub penalty: u32,
+    pub evidence_hash: BytesN<32>,
+    pub proposer: Address,
+    pub confirmations: u32,
+    pub status: SlashStatus,
+    pub score_impact: u32, // points actually removed, restored if reversed
+    pub appeal_bond: i128,
+    pub created_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AppealConfig {
+    pub bond_token: Address, // PULSAR
+    pub treasury: Address,   // receives forfeited bonds
+    pub arbiter: Address,
+    pub min_bond: i128,
+}
+
+/// Minimum governance token balance a reviewer must hold
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StakeRequirement {
+    pub token: Address, // PULSAR governance token
+    pub min_balance: i128,
+}
+
+/// Weights applied when updating a publisher's score
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoringConfig {
+    pub positive_weight: u32, // points per rating point of a positive review
+    pub negative_weight: u32, // points per rating point of a negative review
+    pub uptime_weight: u32,   // points lost at 0% uptime
+    pub quality_weight: u32,  // points lost at 0% quality
+    pub slash_floor: u32,     // slashing never takes a score below this
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreCheckpoint {
+    pub ledger_seq: u32,
+    pub score: u32,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeaderboardEntry {
+    pub publisher: Address,
+    pub score: u32,
+}
+
+/// Minimums a publisher must meet to be offered a campaign
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EligibilityCriteria {
+    pub min_score: u32,   // composite score, 0-1000
+    pub min_uptime: u32,  // 0-100
+    pub max_slashes: u32, // applied slashes
+}
+
+/// Merkle root over all publisher scores at an epoch, for off-chain proofs
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RootCommitment {
+    pub epoch: u64,
+    pub root: BytesN<32>,
+    pub committed_by: Address,
+    pub ledger_seq: u32,
+    pub committed_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherInitialized {
+    pub publisher: Address,
+    pub caller: Address,
+}
+contract_event!(PublisherInitialized, "publisher_initialized");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReviewAuthorized {
+    pub advertiser: Address,
+    pub publisher: Address,
+    pub campaign_id: u64,
+    pub spend: i128,
+}
+contract_event!(ReviewAuthorized, "review_authorized");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReviewSubmitted {
+    pub publisher: Address,
+    pub advertiser: Address,
+    pub campaign_id: u64,
+    pub positive: bool,
+    pub rating: u32,
+    pub score_impact: u32,
+}
+contract_event!(ReviewSubmitted, "review_submitted");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReviewResponded {
+    pub publisher: Address,
+    pub review_index: u64,
+    pub response_hash: BytesN<32>,
+}
+contract_event!(ReviewResponded, "review_responded");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReviewDisputed {
+    pub publisher: Address,
+    pub review_index: u64,
+}
+contract_event!(ReviewDisputed, "review_disputed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReviewEscalated {
+    pub publisher: Address,
+    pub review_index: u64,
+    pub case_id: u64,
+}
+contract_event!(ReviewEscalated, "review_escalated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SlashProposed {
+    pub slash_id: u64,
+    pub publisher: Address,
+    pub penalty: u32,
+}
+contract_event!(SlashProposed, "slash_proposed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SlashAppealed {
+    pub slash_id: u64,
+    pub publisher: Address,
+    pub bond_amount: i128,
+}
+contract_event!(SlashAppealed, "slash_appealed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AppealResolved {
+    pub slash_id: u64,
+    pub reversed: bool,
+    pub appeal_bond: i128,
+}
+contract_event!(AppealResolved, "appeal_resolved");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ScoringUpdated {
+    pub config: ScoringConfig,
+}
+contract_event!(ScoringUpdated, "scoring_updated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct UptimeUpdated {
+    pub publisher: Address,
+    pub uptime: u32,
+}
+contract_event!(UptimeUpdated, "uptime_updated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct QualityUpdated {
+    pub publisher: Address,
+    pub quality: u32,
+}
+contract_event!(QualityUpdated, "quality_updated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RootCommitted {
+    pub epoch: u64,
+    pub root: BytesN<32>,
+}
+contract_event!(RootCommitted, "root_committed");
+
+/// Score fell below the alert threshold
+#[contracttype]
+#[derive(Clone)]
+pub struct LowScore {
+    pub publisher: Address,
+    pub old_score: u32,
+    pub new_score: u32,
+}
+contract_event!(LowScore, "low_score");
+
+/// Score climbed back above the alert threshold
+#[contracttype]
+#[derive(Clone)]
+pub struct ScoreRecovered {
+    pub publisher: Address,
+    pub old_score: u32,
+    pub new_score: u32,
+}
+contract_event!(ScoreRecovered, "score_recovered");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SlashConfirmed {
+    pub slash_id: u64,
+    pub oracle: Address,
+    pub confirmations: u32,
+}
+contract_event!(SlashConfirmed, "slash_confirmed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherSlashed {
+    pub publisher: Address,
+    pub penalty: u32,
+}
+contract_event!(PublisherSlashed, "publisher_slashed");
+
+/// `void` is true when the disputed review was struck
+#[contracttype]
+#[derive(Clone)]
+pub struct ReviewResolved {
+    pub publisher: Address,
+    pub review_index: u64,
+    pub void: bool,
+}
+contract_event!(ReviewResolved, "review_resolved");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
-    ReputationOracle,
+    Oracles,          // Vec<Address>
+    OracleThreshold,  // confirmations needed to apply a slash
     Reputation(Address),
     Review(Address, u64),  // publisher, review_index
     ReviewCount(Address),
+    SlashCounter,
+    Slash(u64),
+    SlashConfirmation(u64, Address),
+    AppealConfig,
+    ScoringConfig,
+    Checkpoints(Address), // Vec<ScoreCheckpoint>, oldest first
+    Leaderboard,          // Vec<LeaderboardEntry>, highest score first
+    StakeRequirement,
+    VerificationContract, // may initialize publishers on verification
+    ReputationRoot(u64),  // epoch -> RootCommitment
+    LatestRootEpoch,
+    Attestor(Address),                     // contract allowed to authorize reviews
+    ReviewAuth(Address, Address, u64),     // advertiser, publisher, campaign_id -> attested spend
+    Arbitration,                           // court disputed reviews can be escalated to
+    ReviewCase(Address, u64),              // publisher, review_index -> arbitration case_id
+    UpgradeAuthority,
+    ContractVersion,
 }
 
+// Review impact is scaled by a per-mille weight that grows by one step for
+// every tenfold increase in campaign spend, between the min and max weight.
+const MIN_REVIEW_WEIGHT: u32 = 500;
+const MAX_REVIEW_WEIGHT: u32 = 2_000;
+const REVIEW_WEIGHT_STEP: u32 = 250;
+const REVIEW_SPEND_UNIT: i128 = 10_000_000; // 1 token at 7 decimals
+
+const LEADERBOARD_SIZE: u32 = 50;
+pub const MAX_ELIGIBILITY_BATCH: u32 = 100;
+
+// Oldest checkpoints are dropped once a publisher has this many
+const MAX_CHECKPOINTS: u32 = 100;
+/// Scores below this emit a `low_score` event; climbing back emits `recovered`
+const LOW_SCORE_THRESHOLD: u32 = 200;
+
 const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
 const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+// TTL per kind of persistent entry, bumped on every access
+const REPUTATION_TTL: TtlPolicy = storage::LONG_LIVED;
+
+const EVENT_TAG: &str = "reputation";
+const CONTRACT_VERSION: u32 = 1; // deployments from before versioning count as 1
 
 #[contract]
 pub struct PublisherReputationContract;
 
 #[contractimpl]
 impl PublisherReputationContract {
-    pub fn initialize(env: Env, admin: Address, oracle: Address) {
+    pub fn initialize(env: Env, admin: Address, oracle: Address) -> Result<(), ReputationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
-        }
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage().instance().set(&DataKey::ReputationOracle, &oracle);
+        Ownable::init(&env, &DataKey::Admin, &admin)?;
+        env.storage().instance().set(&DataKey::Oracles, &Vec::from_array(&env, [oracle]));
+        env.storage().instance().set(&DataKey::OracleThreshold, &1u32);
+        Upgradeable::init(&env, &DataKey::ContractVersion, CONTRACT_VERSION);
+        Ok(())
+    }
+
+    /// See `Upgradeable`
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::require_authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)?;
+        Upgradeable::upgrade(&env, EVENT_TAG, &DataKey::ContractVersion, new_wasm_hash);
+        Ok(())
+    }
+
+    pub fn migrate(env: Env, from_version: u32) -> Result<u32, ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::require_authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)?;
+        Ok(Upgradeable::migrate(&env, EVENT_TAG, &DataKey::ContractVersion, from_version, CONTRACT_VERSION, |_| {})?)
+    }
+
+    pub fn set_upgrade_authority(env: Env, caller: Address, authority: Address) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::set_authority(&env, EVENT_TAG, &DataKey::UpgradeAuthority, &DataKey::Admin, &caller, &authority)?;
+        Ok(())
+    }
+
+    pub fn get_upgrade_authority(env: Env) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)
+    }
+
+    pub fn get_version(env: Env) -> ContractVersion {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::version(&env, &DataKey::ContractVersion)
     }
 
-    pub fn init_publisher(env: Env, publisher: Address) {
+    /// Start tracking a publisher at a neutral score. Callable by the admin
+    /// or the registered publisher-verification contract.
+    pub fn init_publisher(
+        env: Env,
+        caller: Address,
+        publisher: Address,
+    ) -> Result<(), ReputationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        caller.require_auth();
+        let verifier: Option<Address> = env.storage().instance().get(&DataKey::VerificationContract);
+        if !Ownable::is_owner(&env, &DataKey::Admin, &caller) && Some(caller.clone()) != verifier {
+            return Err(ReputationError::Unauthorized);
+        }
+
         if env.storage().persistent().has(&DataKey::Reputation(publisher.clone())) {
-            panic!("already initialized");
+            return Err(ReputationError::PublisherAlreadyRegistered);
         }
 
         let score = ReputationScore {
             publisher: publisher.clone(),
             score: 500,
+            review_score: 500,
+            slash_penalty: 0,
             total_reviews: 0,
             positive_reviews: 0,
             negative_reviews: 0,
@@ -78,11 +427,101 @@ impl PublisherReputationContract {
             last_updated: env.ledger().timestamp(),
         };
 
-        let _ttl_key = DataKey::Reputation(publisher);
-        env.storage().persistent().set(&_ttl_key, &score);
+        Self::_save_reputation(&env, score);
+
+        events::publish(&env, EVENT_TAG, PublisherInitialized { publisher, caller });
+        Ok(())
+    }
+
+    /// Let the publisher-verification contract initialize reputation on verify (admin only)
+    pub fn set_verification_contract(
+        env: Env,
+        admin: Address,
+        verification_contract: Address,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::VerificationContract, &verification_contract);
+        Ok(())
+    }
+
+    pub fn get_verification_contract(env: Env) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::VerificationContract)
+    }
+
+    /// Allow a contract (e.g. the campaign orchestrator) to vouch for
+    /// advertiser/publisher relationships (admin only)
+    pub fn register_attestor(
+        env: Env,
+        admin: Address,
+        attestor: Address,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::Attestor(attestor.clone()), &true);
+
+        events::publish(&env, EVENT_TAG, RoleGranted { role: symbol_short!("attestor"), account: attestor });
+        Ok(())
+    }
+
+    pub fn remove_attestor(
+        env: Env,
+        admin: Address,
+        attestor: Address,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+        env.storage().instance().remove(&DataKey::Attestor(attestor.clone()));
+
+        events::publish(&env, EVENT_TAG, RoleRevoked { role: symbol_short!("attestor"), account: attestor });
+        Ok(())
+    }
+
+    pub fn is_attestor(env: Env, attestor: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().has(&DataKey::Attestor(attestor))
+    }
+
+    /// Record that `advertiser` spent `spend` on `campaign_id` with `publisher`,
+    /// entitling them to submit one review for it. Callable by registered attestors.
+    pub fn authorize_review(
+        env: Env,
+        attestor: Address,
+        advertiser: Address,
+        publisher: Address,
+        campaign_id: u64,
+        spend: i128,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        attestor.require_auth();
+        if !env.storage().instance().has(&DataKey::Attestor(attestor)) {
+            return Err(ReputationError::Unauthorized);
+        }
+        if spend < 0 {
+            return Err(ReputationError::InvalidSpend);
+        }
+
+        let _ttl_key = DataKey::ReviewAuth(advertiser.clone(), publisher.clone(), campaign_id);
+        env.storage().persistent().set(&_ttl_key, &spend);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, ReviewAuthorized { advertiser, publisher, campaign_id, spend });
+        Ok(())
     }
 
+    pub fn is_review_authorized(env: Env, advertiser: Address, publisher: Address, campaign_id: u64) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .has(&DataKey::ReviewAuth(advertiser, publisher, campaign_id))
+    }
+
+    /// Submit a review. Requires a prior `authorize_review` for the same
+    /// advertiser, publisher and campaign, which is consumed. The score
+    /// change is weighted by the attested campaign spend.
     pub fn submit_review(
         env: Env,
         advertiser: Address,
@@ -90,26 +529,59 @@ impl PublisherReputationContract {
         campaign_id: u64,
         positive: bool,
         rating: u32,
-    ) {
+    ) -> Result<(), ReputationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         advertiser.require_auth();
 
-        if rating < 1 || rating > 5 {
-            panic!("invalid rating");
+        if !(1..=5).contains(&rating) {
+            return Err(ReputationError::InvalidRating);
         }
 
         let mut rep: ReputationScore = env
             .storage()
             .persistent()
             .get(&DataKey::Reputation(publisher.clone()))
-            .expect("publisher not registered");
+            .ok_or(ReputationError::PublisherNotRegistered)?;
+
+        let stake: Option<StakeRequirement> = env.storage().instance().get(&DataKey::StakeRequirement);
+        if let Some(stake) = stake {
+            if token::Client::new(&env, &stake.token).balance(&advertiser) < stake.min_balance {
+                return Err(ReputationError::InsufficientStake);
+            }
+        }
+
+        let auth_key = DataKey::ReviewAuth(advertiser.clone(), publisher.clone(), campaign_id);
+        let spend: i128 = env
+            .storage()
+            .persistent()
+            .get(&auth_key)
+            .ok_or(ReputationError::ReviewNotAuthorized)?;
+        env.storage().persistent().remove(&auth_key);
 
+        let config = Self::_scoring_config(&env);
+        let weight = Self::_review_weight(spend);
+        let previous_review_score = rep.review_score;
+        rep.total_reviews += 1;
+        if positive {
+            rep.positive_reviews += 1;
+            rep.review_score = (rep.review_score + rating * config.positive_weight * weight / 1000).min(1000);
+        } else {
+            rep.negative_reviews += 1;
+            rep.review_score = rep.review_score.saturating_sub(rating * config.negative_weight * weight / 1000);
+        }
+        rep.last_updated = env.ledger().timestamp();
+
+        let score_impact = previous_review_score.abs_diff(rep.review_score);
         let review = ReviewEntry {
-            reviewer: advertiser,
+            reviewer: advertiser.clone(),
             campaign_id,
             positive,
             rating,
+            spend,
             timestamp: env.ledger().timestamp(),
+            score_impact,
+            status: ReviewStatus::Active,
+            response_hash: None,
         };
 
         let count: u64 = env
@@ -124,83 +596,614 @@ impl PublisherReputationContract {
         env.storage().persistent().set(&_ttl_key, &(count + 1));
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        rep.total_reviews += 1;
-        if positive {
-            rep.positive_reviews += 1;
-            // Increase score (max 1000)
-            rep.score = (rep.score + rating as u32 * 2).min(1000);
+        Self::_save_reputation(&env, rep);
+
+        events::publish(
+            &env,
+            EVENT_TAG,
+            ReviewSubmitted { publisher, advertiser, campaign_id, positive, rating, score_impact },
+        );
+        Ok(())
+    }
+
+    /// Attach the publisher's public response (hash of off-chain content) to a review
+    pub fn respond_to_review(
+        env: Env,
+        publisher: Address,
+        review_index: u64,
+        response_hash: BytesN<32>,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+
+        let mut review = Self::_load_review(&env, &publisher, review_index)?;
+        review.response_hash = Some(response_hash.clone());
+        Self::_save_review(&env, &publisher, review_index, &review);
+
+        events::publish(&env, EVENT_TAG, ReviewResponded { publisher, review_index, response_hash });
+        Ok(())
+    }
+
+    /// Flag a review for oracle adjudication
+    pub fn dispute_review(
+        env: Env,
+        publisher: Address,
+        review_index: u64,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+
+        let mut review = Self::_load_review(&env, &publisher, review_index)?;
+        if review.status != ReviewStatus::Active {
+            return Err(ReputationError::ReviewNotDisputable);
+        }
+        review.status = ReviewStatus::Disputed;
+        Self::_save_review(&env, &publisher, review_index, &review);
+
+        events::publish(&env, EVENT_TAG, ReviewDisputed { publisher, review_index });
+        Ok(())
+    }
+
+    /// Settle a disputed review. Voiding it reverses its score impact.
+    pub fn resolve_dispute(
+        env: Env,
+        oracle: Address,
+        publisher: Address,
+        review_index: u64,
+        void: bool,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_oracle(&env, &oracle)?;
+        if env.storage().persistent().has(&DataKey::ReviewCase(publisher.clone(), review_index)) {
+            return Err(ReputationError::ReviewUnderArbitration);
+        }
+        Self::_settle_review_dispute(&env, publisher, review_index, void)
+    }
+
+    pub fn set_arbitration(env: Env, admin: Address, court: Address) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::Arbitration, &court);
+        Ok(())
+    }
+
+    /// Take a disputed review to the arbitration court instead of the
+    /// oracles. The publisher pays the court's case fee.
+    pub fn escalate_review_dispute(
+        env: Env,
+        publisher: Address,
+        review_index: u64,
+    ) -> Result<u64, ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+        let court: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Arbitration)
+            .ok_or(ReputationError::ArbitrationNotSet)?;
+        let review = Self::_load_review(&env, &publisher, review_index)?;
+        if review.status != ReviewStatus::Disputed {
+            return Err(ReputationError::ReviewNotDisputed);
+        }
+        let case_key = DataKey::ReviewCase(publisher.clone(), review_index);
+        if env.storage().persistent().has(&case_key) {
+            return Err(ReputationError::ReviewUnderArbitration);
+        }
+
+        let case_id: u64 = env.invoke_contract(
+            &court,
+            &Symbol::new(&env, "open_case"),
+            Vec::from_array(
+                &env,
+                [
+                    env.current_contract_address().into_val(&env),
+                    publisher.clone().into_val(&env),
+                    publisher.clone().into_val(&env),
+                    review_index.into_val(&env),
+                ],
+            ),
+        );
+        env.storage().persistent().set(&case_key, &case_id);
+        env.storage().persistent().extend_ttl(&case_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, ReviewEscalated { publisher, review_index, case_id });
+        Ok(case_id)
+    }
+
+    /// Ruling hook called by the arbitration court: a win for the
+    /// publisher voids the review, anything else upholds it
+    pub fn on_ruling(
+        env: Env,
+        court: Address,
+        case_id: u64,
+        publisher: Address,
+        review_index: u64,
+        claimant_wins: bool,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        court.require_auth();
+        let stored_court: Option<Address> = env.storage().instance().get(&DataKey::Arbitration);
+        if stored_court != Some(court) {
+            return Err(ReputationError::Unauthorized);
+        }
+        let case_key = DataKey::ReviewCase(publisher.clone(), review_index);
+        let escalated: Option<u64> = env.storage().persistent().get(&case_key);
+        if escalated != Some(case_id) {
+            return Err(ReputationError::CaseMismatch);
+        }
+        env.storage().persistent().remove(&case_key);
+        Self::_settle_review_dispute(&env, publisher, review_index, claimant_wins)
+    }
+
+    /// Propose a slash backed by off-chain evidence. The proposing oracle's
+    /// confirmation is counted; the slash applies once the oracle threshold is met.
+    pub fn propose_slash(
+        env: Env,
+        oracle: Address,
+        publisher: Address,
+        penalty: u32,
+        evidence_hash: BytesN<32>,
+    ) -> Result<u64, ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_oracle(&env, &oracle)?;
+
+        if !env.storage().persistent().has(&DataKey::Reputation(publisher.clone())) {
+            return Err(ReputationError::PublisherNotRegistered);
+        }
+
+        let slash_id: u64 = env.storage().instance().get(&DataKey::SlashCounter).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::SlashCounter, &slash_id);
+
+        let proposal = SlashProposal {
+            slash_id,
+            publisher: publisher.clone(),
+            penalty,
+            evidence_hash,
+            proposer: oracle.clone(),
+            confirmations: 0,
+            status: SlashStatus::Proposed,
+            score_impact: 0,
+            appeal_bond: 0,
+            created_at: env.ledger().timestamp(),
+        };
+
+        events::publish(&env, EVENT_TAG, SlashProposed { slash_id, publisher, penalty });
+
+        Self::_confirm_slash(&env, oracle, proposal)?;
+        Ok(slash_id)
+    }
+
+    pub fn confirm_slash(env: Env, oracle: Address, slash_id: u64) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_oracle(&env, &oracle)?;
+
+        let proposal = Self::_load_slash(&env, slash_id)?;
+        if proposal.status != SlashStatus::Proposed {
+            return Err(ReputationError::SlashAlreadyApplied);
+        }
+        if env.storage().persistent().has(&DataKey::SlashConfirmation(slash_id, oracle.clone())) {
+            return Err(ReputationError::AlreadyConfirmed);
+        }
+
+        Self::_confirm_slash(&env, oracle, proposal)?;
+        Ok(())
+    }
+
+    /// Configure slash appeals: the bond token, where forfeited bonds go,
+    /// who decides appeals and the minimum bond (admin only)
+    pub fn set_appeal_config(
+        env: Env,
+        admin: Address,
+        bond_token: Address,
+        treasury: Address,
+        arbiter: Address,
+        min_bond: i128,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+        if min_bond <= 0 {
+            return Err(ReputationError::InvalidBond);
+        }
+        env.storage().instance().set(
+            &DataKey::AppealConfig,
+            &AppealConfig { bond_token, treasury, arbiter, min_bond },
+        );
+        Ok(())
+    }
+
+    pub fn get_appeal_config(env: Env) -> Option<AppealConfig> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::AppealConfig)
+    }
+
+    /// Contest an applied slash by locking `bond_amount` of the bond token
+    pub fn appeal_slash(
+        env: Env,
+        publisher: Address,
+        slash_id: u64,
+        bond_amount: i128,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+
+        let config: AppealConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::AppealConfig)
+            .ok_or(ReputationError::AppealsNotConfigured)?;
+        let mut proposal = Self::_load_slash(&env, slash_id)?;
+        if proposal.publisher != publisher {
+            return Err(ReputationError::Unauthorized);
+        }
+        if proposal.status != SlashStatus::Applied {
+            return Err(ReputationError::SlashNotAppealable);
+        }
+        if bond_amount < config.min_bond {
+            return Err(ReputationError::BondTooLow);
+        }
+
+        token::Client::new(&env, &config.bond_token).transfer(
+            &publisher,
+            &env.current_contract_address(),
+            &bond_amount,
+        );
+
+        proposal.status = SlashStatus::Appealed;
+        proposal.appeal_bond = bond_amount;
+        Self::_save_slash(&env, &proposal);
+
+        events::publish(&env, EVENT_TAG, SlashAppealed { slash_id, publisher, bond_amount });
+        Ok(())
+    }
+
+    /// Decide an appeal. Reversing restores the score and returns the bond;
+    /// upholding forfeits the bond to the treasury.
+    pub fn resolve_appeal(
+        env: Env,
+        arbiter: Address,
+        slash_id: u64,
+        reverse: bool,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        arbiter.require_auth();
+
+        let config: AppealConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::AppealConfig)
+            .ok_or(ReputationError::AppealsNotConfigured)?;
+        if arbiter != config.arbiter {
+            return Err(ReputationError::Unauthorized);
+        }
+
+        let mut proposal = Self::_load_slash(&env, slash_id)?;
+        if proposal.status != SlashStatus::Appealed {
+            return Err(ReputationError::SlashNotAppealed);
+        }
+
+        let bond_client = token::Client::new(&env, &config.bond_token);
+        if reverse {
+            let mut rep: ReputationScore = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Reputation(proposal.publisher.clone()))
+                .ok_or(ReputationError::PublisherNotRegistered)?;
+            rep.slashes -= 1;
+            rep.slash_penalty = rep.slash_penalty.saturating_sub(proposal.score_impact);
+            rep.last_updated = env.ledger().timestamp();
+
+            Self::_save_reputation(&env, rep);
+
+            bond_client.transfer(&env.current_contract_address(), &proposal.publisher, &proposal.appeal_bond);
+            proposal.status = SlashStatus::Reversed;
         } else {
-            rep.negative_reviews += 1;
-            // Decrease score (min 0)
-            rep.score = rep.score.saturating_sub(rating as u32 * 3);
+            bond_client.transfer(&env.current_contract_address(), &config.treasury, &proposal.appeal_bond);
+            proposal.status = SlashStatus::Upheld;
         }
-        rep.last_updated = env.ledger().timestamp();
+        Self::_save_slash(&env, &proposal);
 
-        let _ttl_key = DataKey::Reputation(publisher);
-        env.storage().persistent().set(&_ttl_key, &rep);
-        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        events::publish(
+            &env,
+            EVENT_TAG,
+            AppealResolved { slash_id, reversed: reverse, appeal_bond: proposal.appeal_bond },
+        );
+        Ok(())
     }
 
-    pub fn slash_publisher(env: Env, oracle: Address, publisher: Address, penalty: u32) {
+    /// Require reviewers to hold at least `min_balance` of `token`. A zero
+    /// minimum removes the requirement (admin only).
+    pub fn set_reviewer_stake(
+        env: Env,
+        admin: Address,
+        token: Address,
+        min_balance: i128,
+    ) -> Result<(), ReputationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        oracle.require_auth();
-        let stored_oracle: Address = env.storage().instance().get(&DataKey::ReputationOracle).unwrap();
-        if oracle != stored_oracle {
-            panic!("unauthorized");
+        Self::_require_admin(&env, &admin)?;
+        if min_balance < 0 {
+            return Err(ReputationError::InvalidStake);
+        }
+        if min_balance == 0 {
+            env.storage().instance().remove(&DataKey::StakeRequirement);
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKey::StakeRequirement, &StakeRequirement { token, min_balance });
+        }
+        Ok(())
+    }
+
+    pub fn get_reviewer_stake(env: Env) -> Option<StakeRequirement> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::StakeRequirement)
+    }
+
+    /// Replace the weights used for reviews, uptime, quality and slashing (admin only).
+    /// Stored scores pick up new uptime/quality weights on their next update.
+    /// Each weight and the floor are capped at the 1000-point score scale.
+    pub fn set_scoring_config(
+        env: Env,
+        admin: Address,
+        config: ScoringConfig,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+        if config.positive_weight > 1000
+            || config.negative_weight > 1000
+            || config.uptime_weight > 1000
+            || config.quality_weight > 1000
+            || config.slash_floor > 1000
+        {
+            return Err(ReputationError::InvalidScoringConfig);
+        }
+        env.storage().instance().set(&DataKey::ScoringConfig, &config);
+
+        events::publish(&env, EVENT_TAG, ScoringUpdated { config });
+        Ok(())
+    }
+
+    pub fn get_scoring_config(env: Env) -> ScoringConfig {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_scoring_config(&env)
+    }
+
+    pub fn add_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+
+        if !RoleBased::grant_role(&env, &DataKey::Oracles, &oracle) {
+            return Err(ReputationError::AlreadyOracle);
+        }
+
+        events::publish(&env, EVENT_TAG, RoleGranted { role: symbol_short!("oracle"), account: oracle });
+        Ok(())
+    }
+
+    pub fn remove_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+
+        if !RoleBased::has_role(&env, &DataKey::Oracles, &oracle) {
+            return Err(ReputationError::NotOracle);
+        }
+        let threshold: u32 = env.storage().instance().get(&DataKey::OracleThreshold).unwrap_or(1);
+        if RoleBased::members(&env, &DataKey::Oracles).len() - 1 < threshold {
+            return Err(ReputationError::WouldDropBelowThreshold);
+        }
+        RoleBased::revoke_role(&env, &DataKey::Oracles, &oracle);
+
+        events::publish(&env, EVENT_TAG, RoleRevoked { role: symbol_short!("oracle"), account: oracle });
+        Ok(())
+    }
+
+    /// Set how many oracle confirmations a slash needs (admin only)
+    pub fn set_oracle_threshold(
+        env: Env,
+        admin: Address,
+        threshold: u32,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin)?;
+
+        let oracles: Vec<Address> = env.storage().instance().get(&DataKey::Oracles).ok_or(ReputationError::NotInitialized)?;
+        if threshold == 0 || threshold > oracles.len() {
+            return Err(ReputationError::InvalidThreshold);
+        }
+        env.storage().instance().set(&DataKey::OracleThreshold, &threshold);
+        Ok(())
+    }
+
+    pub fn get_oracles(env: Env) -> Vec<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        RoleBased::members(&env, &DataKey::Oracles)
+    }
+
+    pub fn get_oracle_threshold(env: Env) -> u32 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::OracleThreshold).unwrap_or(1)
+    }
+
+    pub fn get_slash(env: Env, slash_id: u64) -> Option<SlashProposal> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Slash(slash_id))
+    }
+
+    pub fn update_uptime(
+        env: Env,
+        oracle: Address,
+        publisher: Address,
+        uptime: u32,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_oracle(&env, &oracle)?;
+
+        if uptime > 100 {
+            return Err(ReputationError::InvalidUptime);
         }
 
         let mut rep: ReputationScore = env
             .storage()
             .persistent()
             .get(&DataKey::Reputation(publisher.clone()))
-            .expect("publisher not registered");
+            .ok_or(ReputationError::PublisherNotRegistered)?;
 
-        rep.slashes += 1;
-        rep.score = rep.score.saturating_sub(penalty);
+        rep.uptime_score = uptime;
         rep.last_updated = env.ledger().timestamp();
 
-        let _ttl_key = DataKey::Reputation(publisher.clone());
-        env.storage().persistent().set(&_ttl_key, &rep);
-        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_save_reputation(&env, rep);
 
-        env.events().publish(
-            (symbol_short!("publisher"), symbol_short!("slashed")),
-            (publisher, penalty),
-        );
+        events::publish(&env, EVENT_TAG, UptimeUpdated { publisher, uptime });
+        Ok(())
     }
 
-    pub fn update_uptime(env: Env, oracle: Address, publisher: Address, uptime: u32) {
+    pub fn update_quality(
+        env: Env,
+        oracle: Address,
+        publisher: Address,
+        quality: u32,
+    ) -> Result<(), ReputationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        oracle.require_auth();
-        let stored_oracle: Address = env.storage().instance().get(&DataKey::ReputationOracle).unwrap();
-        if oracle != stored_oracle {
-            panic!("unauthorized");
-        }
+        Self::_require_oracle(&env, &oracle)?;
 
-        if uptime > 100 {
-            panic!("invalid uptime");
+        if quality > 100 {
+            return Err(ReputationError::InvalidQuality);
         }
 
         let mut rep: ReputationScore = env
             .storage()
             .persistent()
             .get(&DataKey::Reputation(publisher.clone()))
-            .expect("publisher not registered");
+            .ok_or(ReputationError::PublisherNotRegistered)?;
 
-        rep.uptime_score = uptime;
-        // Recalculate score based on uptime
-        let uptime_weight = uptime / 5; // up to 20 points
-        rep.score = (rep.score + uptime_weight).min(1000);
+        rep.quality_score = quality;
         rep.last_updated = env.ledger().timestamp();
 
-        let _ttl_key = DataKey::Reputation(publisher);
-        env.storage().persistent().set(&_ttl_key, &rep);
-        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_save_reputation(&env, rep);
+
+        events::publish(&env, EVENT_TAG, QualityUpdated { publisher, quality });
+        Ok(())
     }
 
     pub fn get_reputation(env: Env, publisher: Address) -> Option<ReputationScore> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        env.storage().persistent().get(&DataKey::Reputation(publisher))
+        Persistent::get(&env, &DataKey::Reputation(publisher), REPUTATION_TTL)
+    }
+
+    /// Whether the publisher meets every threshold; unregistered publishers
+    /// never do
+    pub fn is_eligible(env: Env, publisher: Address, min_score: u32, min_uptime: u32, max_slashes: u32) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_is_eligible(&env, &publisher, &EligibilityCriteria { min_score, min_uptime, max_slashes })
+    }
+
+    /// The candidates that meet `criteria`, in their original order. Lets the
+    /// orchestrator screen up to `MAX_ELIGIBILITY_BATCH` publishers per call.
+    pub fn filter_eligible(
+        env: Env,
+        publishers: Vec<Address>,
+        criteria: EligibilityCriteria,
+    ) -> Result<Vec<Address>, ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if publishers.len() > MAX_ELIGIBILITY_BATCH {
+            return Err(ReputationError::InvalidBatchSize);
+        }
+        let mut eligible = Vec::new(&env);
+        for publisher in publishers.iter() {
+            if Self::_is_eligible(&env, &publisher, &criteria) {
+                eligible.push_back(publisher);
+            }
+        }
+        Ok(eligible)
+    }
+
+    /// Score as of `ledger_seq`. None if the publisher was not registered
+    /// then or the ledger predates the retained checkpoint history.
+    pub fn get_reputation_at(env: Env, publisher: Address, ledger_seq: u32) -> Option<u32> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let checkpoints: Vec<ScoreCheckpoint> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Checkpoints(publisher))
+            .unwrap_or(Vec::new(&env));
+
+        // Binary search for the last checkpoint at or before ledger_seq
+        let mut lo: u32 = 0;
+        let mut hi: u32 = checkpoints.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if checkpoints.get(mid).unwrap().ledger_seq <= ledger_seq {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        if lo == 0 {
+            return None;
+        }
+        Some(checkpoints.get(lo - 1).unwrap().score)
+    }
+
+    pub fn get_checkpoints(env: Env, publisher: Address) -> Vec<ScoreCheckpoint> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::Checkpoints(publisher))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Highest-scoring publishers, best first. A publisher that falls off a
+    /// full board re-enters on its next update once it outscores the last entry.
+    pub fn get_leaderboard(env: Env) -> Vec<LeaderboardEntry> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Leaderboard).unwrap_or(Vec::new(&env))
+    }
+
+    /// Publish the merkle root of all scores for `epoch`. Epochs must
+    /// strictly increase. Callable by the admin or any oracle.
+    pub fn commit_reputation_root(
+        env: Env,
+        caller: Address,
+        root: BytesN<32>,
+        epoch: u64,
+    ) -> Result<(), ReputationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        caller.require_auth();
+        if !Ownable::is_owner(&env, &DataKey::Admin, &caller) && !RoleBased::has_role(&env, &DataKey::Oracles, &caller) {
+            return Err(ReputationError::Unauthorized);
+        }
+
+        let latest: Option<u64> = env.storage().instance().get(&DataKey::LatestRootEpoch);
+        if let Some(latest) = latest {
+            if epoch <= latest {
+                return Err(ReputationError::StaleEpoch);
+            }
+        }
+
+        let commitment = RootCommitment {
+            epoch,
+            root: root.clone(),
+            committed_by: caller,
+            ledger_seq: env.ledger().sequence(),
+            committed_at: env.ledger().timestamp(),
+        };
+        let _ttl_key = DataKey::ReputationRoot(epoch);
+        env.storage().persistent().set(&_ttl_key, &commitment);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        env.storage().instance().set(&DataKey::LatestRootEpoch, &epoch);
+
+        events::publish(&env, EVENT_TAG, RootCommitted { epoch, root });
+        Ok(())
+    }
+
+    pub fn get_reputation_root(env: Env, epoch: u64) -> Option<RootCommitment> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::ReputationRoot(epoch))
+    }
+
+    pub fn get_latest_reputation_root(env: Env) -> Option<RootCommitment> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let latest: Option<u64> = env.storage().instance().get(&DataKey::LatestRootEpoch);
+        latest.and_then(|epoch| env.storage().persistent().get(&DataKey::ReputationRoot(epoch)))
     }
 
     pub fn get_review(env: Env, publisher: Address, index: u64) -> Option<ReviewEntry> {
@@ -208,10 +1211,320 @@ impl PublisherReputationContract {
         env.storage().persistent().get(&DataKey::Review(publisher, index))
     }
 
+    /// Page through a publisher's reviews, oldest or newest first
+    pub fn list_reviews(
+        env: Env,
+        publisher: Address,
+        offset: u64,
+        limit: u32,
+        newest_first: bool,
+    ) -> Vec<ReviewEntry> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let count: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReviewCount(publisher.clone()))
+            .unwrap_or(0);
+
+        let mut result = Vec::new(&env);
+        let mut position = offset;
+        while position < count && result.len() < limit {
+            let index = if newest_first { count - 1 - position } else { position };
+            if let Some(review) = env.storage().persistent().get(&DataKey::Review(publisher.clone(), index)) {
+                result.push_back(review);
+            }
+            position += 1;
+        }
+        result
+    }
+
+    /// All reviews a publisher received for one campaign, oldest first
+    pub fn list_reviews_by_campaign(env: Env, publisher: Address, campaign_id: u64) -> Vec<ReviewEntry> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let count: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReviewCount(publisher.clone()))
+            .unwrap_or(0);
+
+        let mut result = Vec::new(&env);
+        for index in 0..count {
+            let review: Option<ReviewEntry> = env.storage().persistent().get(&DataKey::Review(publisher.clone(), index));
+            if let Some(review) = review {
+                if review.campaign_id == campaign_id {
+                    result.push_back(review);
+                }
+            }
+        }
+        result
+    }
+
     pub fn get_review_count(env: Env, publisher: Address) -> u64 {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         env.storage().persistent().get(&DataKey::ReviewCount(publisher)).unwrap_or(0)
     }
+
+    // ============================================================
+    // TTL Maintenance
+    // ============================================================
+
+    /// Keep a publisher's score, review count and checkpoints from being
+    /// archived. Anyone may call; returns false for an unknown publisher.
+    pub fn extend_ttl_publisher(env: Env, publisher: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Persistent::extend(&env, &DataKey::ReviewCount(publisher.clone()), REPUTATION_TTL);
+        Persistent::extend(&env, &DataKey::Checkpoints(publisher.clone()), REPUTATION_TTL);
+        Persistent::extend(&env, &DataKey::Reputation(publisher), REPUTATION_TTL)
+    }
+
+    // ============================================================
+    // Internal Helpers
+    // ============================================================
+
+    fn _is_eligible(env: &Env, publisher: &Address, criteria: &EligibilityCriteria) -> bool {
+        match Persistent::get::<DataKey, ReputationScore>(env, &DataKey::Reputation(publisher.clone()), REPUTATION_TTL) {
+            Some(rep) => {
+                rep.score >= criteria.min_score
+                    && rep.uptime_score >= criteria.min_uptime
+                    && rep.slashes <= criteria.max_slashes
+            }
+            None => false,
+        }
+    }
+
+    /// Per-mille weight for a review backed by `spend`
+    fn _review_weight(spend: i128) -> u32 {
+        let mut weight = MIN_REVIEW_WEIGHT;
+        let mut threshold = REVIEW_SPEND_UNIT;
+        while spend >= threshold && weight < MAX_REVIEW_WEIGHT {
+            weight += REVIEW_WEIGHT_STEP;
+            threshold = threshold.saturating_mul(10);
+        }
+        weight
+    }
+
+    /// Recompute the composite score, persist it and checkpoint it for `get_reputation_at`
+    fn _save_reputation(env: &Env, mut rep: ReputationScore) {
+        rep.score = Self::_composite_score(&Self::_scoring_config(env), &rep);
+
+        let _ttl_key = DataKey::Reputation(rep.publisher.clone());
+        let previous: Option<ReputationScore> = env.storage().persistent().get(&_ttl_key);
+        if let Some(previous) = previous {
+            if previous.score >= LOW_SCORE_THRESHOLD && rep.score < LOW_SCORE_THRESHOLD {
+                events::publish(
+                    env,
+                    EVENT_TAG,
+                    LowScore { publisher: rep.publisher.clone(), old_score: previous.score, new_score: rep.score },
+                );
+            } else if previous.score < LOW_SCORE_THRESHOLD && rep.score >= LOW_SCORE_THRESHOLD {
+                events::publish(
+                    env,
+                    EVENT_TAG,
+                    ScoreRecovered { publisher: rep.publisher.clone(), old_score: previous.score, new_score: rep.score },
+                );
+            }
+        }
+
+        env.storage().persistent().set(&_ttl_key, &rep);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        let key = DataKey::Checkpoints(rep.publisher.clone());
+        let mut checkpoints: Vec<ScoreCheckpoint> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        let checkpoint = ScoreCheckpoint { ledger_seq: env.ledger().sequence(), score: rep.score };
+        match checkpoints.last() {
+            Some(last) if last.ledger_seq == checkpoint.ledger_seq => {
+                checkpoints.set(checkpoints.len() - 1, checkpoint);
+            }
+            _ => {
+                if checkpoints.len() >= MAX_CHECKPOINTS {
+                    checkpoints.pop_front();
+                }
+                checkpoints.push_back(checkpoint);
+            }
+        }
+        env.storage().persistent().set(&key, &checkpoints);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        Self::_update_leaderboard(env, &rep.publisher, rep.score);
+    }
+
+    fn _update_leaderboard(env: &Env, publisher: &Address, score: u32) {
+        let mut board: Vec<LeaderboardEntry> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Leaderboard)
+            .unwrap_or(Vec::new(env));
+
+        for (idx, entry) in board.iter().enumerate() {
+            if entry.publisher == *publisher {
+                board.remove(idx as u32);
+                break;
+            }
+        }
+
+        // Ties keep the earlier entry ahead
+        let mut position = board.len();
+        for (idx, entry) in board.iter().enumerate() {
+            if score > entry.score {
+                position = idx as u32;
+                break;
+            }
+        }
+        if position < LEADERBOARD_SIZE {
+            board.insert(position, LeaderboardEntry { publisher: publisher.clone(), score });
+            if board.len() > LEADERBOARD_SIZE {
+                board.pop_back();
+            }
+        }
+
+        env.storage().persistent().set(&DataKey::Leaderboard, &board);
+        env.storage().persistent().extend_ttl(&DataKey::Leaderboard, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    /// Overall score: the review score less weighted uptime and quality
+    /// shortfalls and accumulated slash penalties, clamped to 0-1000.
+    ///
+    /// score = review_score
+    ///       - uptime_weight  * (100 - uptime_score)  / 100
+    ///       - quality_weight * (100 - quality_score) / 100
+    ///       - slash_penalty
+    fn _composite_score(config: &ScoringConfig, rep: &ReputationScore) -> u32 {
+        let uptime_shortfall = config.uptime_weight * (100 - rep.uptime_score) / 100;
+        let quality_shortfall = config.quality_weight * (100 - rep.quality_score) / 100;
+        rep.review_score
+            .saturating_sub(uptime_shortfall)
+            .saturating_sub(quality_shortfall)
+            .saturating_sub(rep.slash_penalty)
+            .min(1000)
+    }
+
+    fn _scoring_config(env: &Env) -> ScoringConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::ScoringConfig)
+            .unwrap_or(ScoringConfig {
+                positive_weight: 2,
+                negative_weight: 3,
+                uptime_weight: 100,
+                quality_weight: 100,
+                slash_floor: 0,
+            })
+    }
+
+    fn _require_oracle(env: &Env, oracle: &Address) -> Result<(), ReputationError> {
+        Ok(RoleBased::require_role(env, &DataKey::Oracles, oracle)?)
+    }
+
+    /// Record `oracle`'s confirmation and apply the slash once the threshold is met
+    fn _confirm_slash(env: &Env, oracle: Address, mut proposal: SlashProposal) -> Result<(), ReputationError> {
+        let slash_id = proposal.slash_id;
+        let _ttl_key = DataKey::SlashConfirmation(slash_id, oracle.clone());
+        env.storage().persistent().set(&_ttl_key, &true);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        proposal.confirmations += 1;
+
+        events::publish(
+            env,
+            EVENT_TAG,
+            SlashConfirmed { slash_id, oracle, confirmations: proposal.confirmations },
+        );
+
+        let threshold: u32 = env.storage().instance().get(&DataKey::OracleThreshold).unwrap_or(1);
+        if proposal.confirmations >= threshold {
+            let mut rep: ReputationScore = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Reputation(proposal.publisher.clone()))
+                .ok_or(ReputationError::PublisherNotRegistered)?;
+
+            // Only the part of the penalty above the floor is applied
+            let floor = Self::_scoring_config(env).slash_floor.min(rep.score);
+            let impact = proposal.penalty.min(rep.score - floor);
+            rep.slashes += 1;
+            rep.slash_penalty += impact;
+            rep.last_updated = env.ledger().timestamp();
+
+            Self::_save_reputation(env, rep);
+            proposal.status = SlashStatus::Applied;
+            proposal.score_impact = impact;
+
+            events::publish(
+                env,
+                EVENT_TAG,
+                PublisherSlashed { publisher: proposal.publisher.clone(), penalty: proposal.penalty },
+            );
+        }
+
+        Self::_save_slash(env, &proposal);
+        Ok(())
+    }
+
+    fn _load_slash(env: &Env, slash_id: u64) -> Result<SlashProposal, ReputationError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Slash(slash_id))
+            .ok_or(ReputationError::SlashNotFound)
+    }
+
+    fn _save_slash(env: &Env, proposal: &SlashProposal) {
+        let _ttl_key = DataKey::Slash(proposal.slash_id);
+        env.storage().persistent().set(&_ttl_key, proposal);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _settle_review_dispute(
+        env: &Env,
+        publisher: Address,
+        review_index: u64,
+        void: bool,
+    ) -> Result<(), ReputationError> {
+        let mut review = Self::_load_review(env, &publisher, review_index)?;
+        if review.status != ReviewStatus::Disputed {
+            return Err(ReputationError::ReviewNotDisputed);
+        }
+
+        if void {
+            let mut rep: ReputationScore = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Reputation(publisher.clone()))
+                .ok_or(ReputationError::PublisherNotRegistered)?;
+            if review.positive {
+                rep.positive_reviews -= 1;
+                rep.review_score = rep.review_score.saturating_sub(review.score_impact);
+            } else {
+                rep.negative_reviews -= 1;
+                rep.review_score = (rep.review_score + review.score_impact).min(1000);
+            }
+            rep.last_updated = env.ledger().timestamp();
+
+            Self::_save_reputation(env, rep);
+            review.status = ReviewStatus::Voided;
+        } else {
+            review.status = ReviewStatus::Upheld;
+        }
+        Self::_save_review(env, &publisher, review_index, &review);
+
+        events::publish(env, EVENT_TAG, ReviewResolved { publisher, review_index, void });
+        Ok(())
+    }
+
+    fn _load_review(env: &Env, publisher: &Address, index: u64) -> Result<ReviewEntry, ReputationError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Review(publisher.clone(), index))
+            .ok_or(ReputationError::ReviewNotFound)
+    }
+
+    fn _save_review(env: &Env, publisher: &Address, index: u64, review: &ReviewEntry) {
+        let _ttl_key = DataKey::Review(publisher.clone(), index);
+        env.storage().persistent().set(&_ttl_key, review);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _require_admin(env: &Env, admin: &Address) -> Result<(), ReputationError> {
+        Ok(Ownable::require_owner(env, &DataKey::Admin, admin)?)
+    }
 }
 
 mod test;
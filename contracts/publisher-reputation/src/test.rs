@@ -1,6 +1,48 @@
 #![cfg(test)]
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, vec, String};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{storage::Persistent as _, Address as _, Events, Ledger},
+    token, Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+// Stand-in for the arbitration court: opens cases and hands down whatever
+// ruling the test asks for
+#[contract]
+pub struct MockCourt;
+
+#[contractimpl]
+impl MockCourt {
+    pub fn open_case(env: Env, escalator: Address, payer: Address, _subject: Address, _ref_id: u64) -> u64 {
+        escalator.require_auth();
+        payer.require_auth();
+        let case_id: u64 = env.storage().instance().get(&0u64).unwrap_or(0) + 1;
+        env.storage().instance().set(&0u64, &case_id);
+        case_id
+    }
+
+    pub fn rule(env: Env, escalator: Address, case_id: u64, subject: Address, ref_id: u64, claimant_wins: bool) {
+        env.invoke_contract::<soroban_sdk::Val>(
+            &escalator,
+            &Symbol::new(&env, "on_ruling"),
+            Vec::from_array(
+                &env,
+                [
+                    env.current_contract_address().into_val(&env),
+                    case_id.into_val(&env),
+                    subject.into_val(&env),
+                    ref_id.into_val(&env),
+                    claimant_wins.into_val(&env),
+                ],
+            ),
+        );
+    }
+}
+
+
+fn event_topics(env: &Env, action: &str) -> soroban_sdk::Vec<soroban_sdk::Val> {
+    (Symbol::new(env, "reputation"), Symbol::new(env, action), 1u32).into_val(env)
+}
 
 #[test]
 fn test_initialize() {
@@ -17,7 +59,7 @@ fn test_initialize() {
 }
 
 #[test]
-#[should_panic(expected = "already initialized")]
+#[should_panic(expected = "Error(Contract, #501)")]
 fn test_initialize_twice() {
     let env = Env::default();
     env.mock_all_auths();
@@ -46,3 +88,673 @@ fn test_initialize_non_admin_fails() {
     // This should panic because admin didn't authorize it and we haven't mocked it
     client.initialize(&admin, &oracle);
 }
+
+#[test]
+fn test_review_requires_attested_campaign() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let advertiser = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    client.init_publisher(&admin, &publisher);
+
+    assert_eq!(
+        client.try_submit_review(&advertiser, &publisher, &1, &false, &5),
+        Err(Ok(ReputationError::ReviewNotAuthorized))
+    );
+    assert_eq!(
+        client.try_authorize_review(&orchestrator, &advertiser, &publisher, &1, &0),
+        Err(Ok(ReputationError::Unauthorized))
+    );
+
+    client.register_attestor(&admin, &orchestrator);
+    client.authorize_review(&orchestrator, &advertiser, &publisher, &1, &100_000_000);
+    assert!(client.is_review_authorized(&advertiser, &publisher, &1));
+
+    client.submit_review(&advertiser, &publisher, &1, &true, &5);
+    assert_eq!(client.get_reputation(&publisher).unwrap().score, 510);
+    assert!(!client.is_review_authorized(&advertiser, &publisher, &1));
+
+    // The authorization is single-use
+    assert!(client.try_submit_review(&advertiser, &publisher, &1, &true, &5).is_err());
+}
+
+#[test]
+fn test_review_impact_scales_with_spend() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let small = Address::generate(&env);
+    let large = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    client.register_attestor(&admin, &orchestrator);
+    client.init_publisher(&admin, &publisher);
+
+    // 10 tokens of spend: weight 1.0x, -15 for a 5-star negative review
+    client.authorize_review(&orchestrator, &small, &publisher, &1, &100_000_000);
+    client.submit_review(&small, &publisher, &1, &false, &5);
+    assert_eq!(client.get_reputation(&publisher).unwrap().score, 485);
+
+    // 100k tokens of spend: capped at 2.0x, -30
+    client.authorize_review(&orchestrator, &large, &publisher, &2, &1_000_000_000_000);
+    client.submit_review(&large, &publisher, &2, &false, &5);
+    assert_eq!(client.get_reputation(&publisher).unwrap().score, 455);
+
+    let review = client.get_review(&publisher, &1).unwrap();
+    assert_eq!(review.spend, 1_000_000_000_000);
+}
+
+#[test]
+fn test_dispute_voids_review_impact() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let advertiser = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    client.register_attestor(&admin, &orchestrator);
+    client.init_publisher(&admin, &publisher);
+
+    client.authorize_review(&orchestrator, &advertiser, &publisher, &1, &100_000_000);
+    client.submit_review(&advertiser, &publisher, &1, &false, &4);
+    client.authorize_review(&orchestrator, &advertiser, &publisher, &2, &100_000_000);
+    client.submit_review(&advertiser, &publisher, &2, &false, &2);
+    assert_eq!(client.get_reputation(&publisher).unwrap().score, 482);
+
+    let response = BytesN::from_array(&env, &[7u8; 32]);
+    client.respond_to_review(&publisher, &0, &response);
+    client.dispute_review(&publisher, &0);
+    client.dispute_review(&publisher, &1);
+    assert!(client.try_dispute_review(&publisher, &0).is_err());
+
+    client.resolve_dispute(&oracle, &publisher, &0, &true);
+    client.resolve_dispute(&oracle, &publisher, &1, &false);
+
+    let rep = client.get_reputation(&publisher).unwrap();
+    assert_eq!(rep.score, 494);
+    assert_eq!(rep.negative_reviews, 1);
+
+    let voided = client.get_review(&publisher, &0).unwrap();
+    assert_eq!(voided.status, ReviewStatus::Voided);
+    assert_eq!(voided.response_hash, Some(response));
+    assert_eq!(client.get_review(&publisher, &1).unwrap().status, ReviewStatus::Upheld);
+}
+
+#[test]
+fn test_escalated_review_dispute_ruled_by_court() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+    let court_id = env.register_contract(None, MockCourt);
+    let court = MockCourtClient::new(&env, &court_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let advertiser = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    client.register_attestor(&admin, &orchestrator);
+    client.init_publisher(&admin, &publisher);
+
+    client.authorize_review(&orchestrator, &advertiser, &publisher, &1, &100_000_000);
+    client.submit_review(&advertiser, &publisher, &1, &false, &4);
+    let before = client.get_reputation(&publisher).unwrap().score;
+
+    assert_eq!(client.try_escalate_review_dispute(&publisher, &0), Err(Ok(ReputationError::ArbitrationNotSet)));
+    client.set_arbitration(&admin, &court_id);
+    assert_eq!(client.try_escalate_review_dispute(&publisher, &0), Err(Ok(ReputationError::ReviewNotDisputed)));
+
+    client.dispute_review(&publisher, &0);
+    let case_id = client.escalate_review_dispute(&publisher, &0);
+    assert_eq!(
+        client.try_resolve_dispute(&oracle, &publisher, &0, &false),
+        Err(Ok(ReputationError::ReviewUnderArbitration))
+    );
+    assert!(court.try_rule(&contract_id, &(case_id + 1), &publisher, &0, &true).is_err());
+
+    // The publisher winning the case voids the review
+    court.rule(&contract_id, &case_id, &publisher, &0, &true);
+    assert_eq!(client.get_review(&publisher, &0).unwrap().status, ReviewStatus::Voided);
+    assert!(client.get_reputation(&publisher).unwrap().score > before);
+}
+
+#[test]
+fn test_slash_requires_oracle_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let o1 = Address::generate(&env);
+    let o2 = Address::generate(&env);
+    let o3 = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &o1);
+    client.add_oracle(&admin, &o2);
+    client.add_oracle(&admin, &o3);
+    client.set_oracle_threshold(&admin, &2);
+    client.init_publisher(&admin, &publisher);
+
+    let evidence = BytesN::from_array(&env, &[1u8; 32]);
+    let slash_id = client.propose_slash(&o1, &publisher, &100, &evidence);
+    assert_eq!(client.get_reputation(&publisher).unwrap().score, 500);
+    assert!(client.try_confirm_slash(&o1, &slash_id).is_err());
+
+    client.confirm_slash(&o2, &slash_id);
+    let rep = client.get_reputation(&publisher).unwrap();
+    assert_eq!(rep.score, 400);
+    assert_eq!(rep.slashes, 1);
+    assert_eq!(client.get_slash(&slash_id).unwrap().status, SlashStatus::Applied);
+    assert!(client.try_confirm_slash(&o3, &slash_id).is_err());
+
+    // Removing an oracle may not leave fewer oracles than the threshold
+    client.remove_oracle(&admin, &o3);
+    assert!(client.try_remove_oracle(&admin, &o2).is_err());
+    assert_eq!(client.get_oracles().len(), 2);
+}
+
+#[test]
+fn test_slash_appeal_reverses_or_forfeits_bond() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    client.init_publisher(&admin, &publisher);
+
+    let pulsar = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    token::StellarAssetClient::new(&env, &pulsar).mint(&publisher, &1_000);
+    let pulsar_client = token::Client::new(&env, &pulsar);
+    client.set_appeal_config(&admin, &pulsar, &treasury, &arbiter, &100);
+
+    let evidence = BytesN::from_array(&env, &[2u8; 32]);
+    let first = client.propose_slash(&oracle, &publisher, &150, &evidence);
+    let second = client.propose_slash(&oracle, &publisher, &50, &evidence);
+    assert_eq!(client.get_reputation(&publisher).unwrap().score, 300);
+
+    assert!(client.try_appeal_slash(&publisher, &first, &99).is_err());
+    client.appeal_slash(&publisher, &first, &200);
+    client.appeal_slash(&publisher, &second, &100);
+    assert_eq!(pulsar_client.balance(&publisher), 700);
+
+    client.resolve_appeal(&arbiter, &first, &true);
+    let rep = client.get_reputation(&publisher).unwrap();
+    assert_eq!(rep.score, 450);
+    assert_eq!(rep.slashes, 1);
+    assert_eq!(pulsar_client.balance(&publisher), 900);
+    assert_eq!(client.get_slash(&first).unwrap().status, SlashStatus::Reversed);
+
+    client.resolve_appeal(&arbiter, &second, &false);
+    assert_eq!(client.get_reputation(&publisher).unwrap().score, 450);
+    assert_eq!(pulsar_client.balance(&treasury), 100);
+    assert_eq!(client.get_slash(&second).unwrap().status, SlashStatus::Upheld);
+    assert!(client.try_appeal_slash(&publisher, &second, &100).is_err());
+}
+
+#[test]
+fn test_scoring_config_applies_to_updates() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let advertiser = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    client.register_attestor(&admin, &orchestrator);
+    client.init_publisher(&admin, &publisher);
+    assert_eq!(client.get_scoring_config().negative_weight, 3);
+
+    let config = ScoringConfig {
+        positive_weight: 4,
+        negative_weight: 10,
+        uptime_weight: 50,
+        quality_weight: 0,
+        slash_floor: 300,
+    };
+    client.set_scoring_config(&admin, &config);
+    assert_eq!(client.get_scoring_config(), config);
+
+    client.authorize_review(&orchestrator, &advertiser, &publisher, &1, &100_000_000);
+    client.submit_review(&advertiser, &publisher, &1, &false, &5);
+    assert_eq!(client.get_reputation(&publisher).unwrap().score, 450);
+
+    client.update_uptime(&oracle, &publisher, &80);
+    assert_eq!(client.get_reputation(&publisher).unwrap().score, 440);
+
+    let evidence = BytesN::from_array(&env, &[3u8; 32]);
+    client.propose_slash(&oracle, &publisher, &400, &evidence);
+    assert_eq!(client.get_reputation(&publisher).unwrap().score, 300);
+
+    // Review weights past the score scale are rejected; at the top of it the
+    // heaviest review still fits the arithmetic
+    let mut too_heavy = config.clone();
+    too_heavy.positive_weight = 1001;
+    assert_eq!(
+        client.try_set_scoring_config(&admin, &too_heavy),
+        Err(Ok(ReputationError::InvalidScoringConfig))
+    );
+    too_heavy.positive_weight = 4;
+    too_heavy.negative_weight = u32::MAX;
+    assert_eq!(
+        client.try_set_scoring_config(&admin, &too_heavy),
+        Err(Ok(ReputationError::InvalidScoringConfig))
+    );
+    assert_eq!(client.get_scoring_config(), config);
+
+    let mut heaviest = config.clone();
+    heaviest.positive_weight = 1000;
+    client.set_scoring_config(&admin, &heaviest);
+    client.authorize_review(&orchestrator, &advertiser, &publisher, &2, &i128::MAX);
+    client.submit_review(&advertiser, &publisher, &2, &true, &5);
+    assert_eq!(client.get_reputation(&publisher).unwrap().review_score, 1000);
+}
+
+#[test]
+fn test_reputation_checkpoints_by_ledger() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    client.init_publisher(&admin, &publisher);
+
+    env.ledger().with_mut(|li| li.sequence_number = 200);
+    client.update_uptime(&oracle, &publisher, &100);
+    client.update_uptime(&oracle, &publisher, &50);
+
+    env.ledger().with_mut(|li| li.sequence_number = 300);
+    let evidence = BytesN::from_array(&env, &[4u8; 32]);
+    client.propose_slash(&oracle, &publisher, &100, &evidence);
+
+    // Updates within one ledger collapse into a single checkpoint
+    assert_eq!(client.get_checkpoints(&publisher).len(), 3);
+    assert_eq!(client.get_reputation_at(&publisher, &99), None);
+    assert_eq!(client.get_reputation_at(&publisher, &100), Some(500));
+    assert_eq!(client.get_reputation_at(&publisher, &250), Some(450));
+    assert_eq!(client.get_reputation_at(&publisher, &1_000), Some(350));
+}
+
+#[test]
+fn test_list_reviews_paginated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    client.register_attestor(&admin, &orchestrator);
+    client.init_publisher(&admin, &publisher);
+
+    for campaign_id in 1..=4u64 {
+        let advertiser = Address::generate(&env);
+        client.authorize_review(&orchestrator, &advertiser, &publisher, &(campaign_id % 2), &0);
+        client.submit_review(&advertiser, &publisher, &(campaign_id % 2), &true, &(campaign_id as u32));
+    }
+
+    let oldest = client.list_reviews(&publisher, &0, &3, &false);
+    assert_eq!(oldest.len(), 3);
+    assert_eq!(oldest.get(0).unwrap().rating, 1);
+
+    let newest = client.list_reviews(&publisher, &1, &10, &true);
+    assert_eq!(newest.len(), 3);
+    assert_eq!(newest.get(0).unwrap().rating, 3);
+    assert_eq!(newest.get(2).unwrap().rating, 1);
+    assert_eq!(client.list_reviews(&publisher, &4, &10, &true).len(), 0);
+
+    let campaign = client.list_reviews_by_campaign(&publisher, &0);
+    assert_eq!(campaign.len(), 2);
+    assert_eq!(campaign.get(1).unwrap().rating, 4);
+}
+
+#[test]
+fn test_composite_score_components() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let advertiser = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    client.register_attestor(&admin, &orchestrator);
+    client.init_publisher(&admin, &publisher);
+
+    client.authorize_review(&orchestrator, &advertiser, &publisher, &1, &100_000_000);
+    client.submit_review(&advertiser, &publisher, &1, &true, &5);
+    client.update_uptime(&oracle, &publisher, &90);
+    client.update_quality(&oracle, &publisher, &70);
+    let evidence = BytesN::from_array(&env, &[5u8; 32]);
+    client.propose_slash(&oracle, &publisher, &25, &evidence);
+
+    // 510 review score - 10 uptime - 30 quality - 25 slash
+    let rep = client.get_reputation(&publisher).unwrap();
+    assert_eq!(rep.review_score, 510);
+    assert_eq!(rep.slash_penalty, 25);
+    assert_eq!(rep.score, 445);
+
+    // Restoring uptime recovers its share without touching the others
+    client.update_uptime(&oracle, &publisher, &100);
+    assert_eq!(client.get_reputation(&publisher).unwrap().score, 455);
+    assert!(client.try_update_quality(&oracle, &publisher, &101).is_err());
+}
+
+#[test]
+fn test_leaderboard_tracks_top_scores() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+    let p3 = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    client.init_publisher(&admin, &p1);
+    client.init_publisher(&admin, &p2);
+    client.init_publisher(&admin, &p3);
+
+    client.update_uptime(&oracle, &p1, &50);
+    client.update_quality(&oracle, &p3, &90);
+
+    let board = client.get_leaderboard();
+    assert_eq!(board.len(), 3);
+    assert_eq!(board.get(0).unwrap(), LeaderboardEntry { publisher: p2.clone(), score: 500 });
+    assert_eq!(board.get(1).unwrap(), LeaderboardEntry { publisher: p3.clone(), score: 490 });
+    assert_eq!(board.get(2).unwrap(), LeaderboardEntry { publisher: p1.clone(), score: 450 });
+
+    client.update_uptime(&oracle, &p1, &100);
+    let board = client.get_leaderboard();
+    assert_eq!(board.len(), 3);
+    assert_eq!(board.get(1).unwrap().publisher, p1);
+}
+
+#[test]
+fn test_reviewer_stake_requirement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let advertiser = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    client.register_attestor(&admin, &orchestrator);
+    client.init_publisher(&admin, &publisher);
+
+    let pulsar = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    token::StellarAssetClient::new(&env, &pulsar).mint(&advertiser, &400);
+    client.set_reviewer_stake(&admin, &pulsar, &500);
+
+    client.authorize_review(&orchestrator, &advertiser, &publisher, &1, &0);
+    assert!(client.try_submit_review(&advertiser, &publisher, &1, &false, &5).is_err());
+
+    token::StellarAssetClient::new(&env, &pulsar).mint(&advertiser, &100);
+    client.submit_review(&advertiser, &publisher, &1, &false, &5);
+    assert_eq!(client.get_review_count(&publisher), 1);
+
+    client.set_reviewer_stake(&admin, &pulsar, &0);
+    assert_eq!(client.get_reviewer_stake(), None);
+}
+
+#[test]
+fn test_init_publisher_restricted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let squatter = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+
+    assert_eq!(client.try_init_publisher(&squatter, &publisher), Err(Ok(ReputationError::Unauthorized)));
+    assert!(client.try_init_publisher(&verifier, &publisher).is_err());
+
+    client.set_verification_contract(&admin, &verifier);
+    client.init_publisher(&verifier, &publisher);
+    assert_eq!(client.get_reputation(&publisher).unwrap().score, 500);
+    assert_eq!(
+        client.try_init_publisher(&admin, &publisher),
+        Err(Ok(ReputationError::PublisherAlreadyRegistered))
+    );
+}
+
+#[test]
+fn test_commit_reputation_roots() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    assert_eq!(client.get_latest_reputation_root(), None);
+
+    let first = BytesN::from_array(&env, &[8u8; 32]);
+    let second = BytesN::from_array(&env, &[9u8; 32]);
+    assert!(client.try_commit_reputation_root(&outsider, &first, &1).is_err());
+
+    client.commit_reputation_root(&oracle, &first, &1);
+    client.commit_reputation_root(&admin, &second, &2);
+    assert_eq!(client.try_commit_reputation_root(&oracle, &first, &2), Err(Ok(ReputationError::StaleEpoch)));
+
+    assert_eq!(client.get_reputation_root(&1).unwrap().root, first);
+    let latest = client.get_latest_reputation_root().unwrap();
+    assert_eq!(latest.epoch, 2);
+    assert_eq!(latest.root, second);
+    assert_eq!(latest.committed_by, admin);
+}
+
+#[test]
+fn test_events_and_low_score_alerts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let advertiser = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+
+    client.init_publisher(&admin, &publisher);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, event_topics(&env, "publisher_initialized"));
+    let ev: PublisherInitialized = data.into_val(&env);
+    assert_eq!((ev.publisher, ev.caller), (publisher.clone(), admin.clone()));
+
+    client.register_attestor(&admin, &orchestrator);
+    client.authorize_review(&orchestrator, &advertiser, &publisher, &1, &0);
+    client.submit_review(&advertiser, &publisher, &1, &true, &5);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, event_topics(&env, "review_submitted"));
+    let ev: ReviewSubmitted = data.into_val(&env);
+    assert_eq!((ev.advertiser, ev.campaign_id, ev.positive, ev.rating, ev.score_impact), (advertiser, 1, true, 5, 5));
+
+    // 505 -> 405 -> 305
+    client.update_uptime(&oracle, &publisher, &0);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, event_topics(&env, "uptime_updated"));
+    let ev: UptimeUpdated = data.into_val(&env);
+    assert_eq!(ev.uptime, 0);
+    client.update_quality(&oracle, &publisher, &0);
+
+    let low_score = event_topics(&env, "low_score");
+    let recovered = event_topics(&env, "score_recovered");
+    assert!(!env.events().all().iter().any(|(_, topics, _)| topics == low_score));
+
+    // 305 -> 155 crosses below the alert threshold
+    client.propose_slash(&oracle, &publisher, &150, &BytesN::from_array(&env, &[1u8; 32]));
+    let alert = env.events().all().iter().find(|(_, topics, _)| *topics == low_score).unwrap();
+    let ev: LowScore = alert.2.into_val(&env);
+    assert_eq!((ev.old_score, ev.new_score), (305, 155));
+
+    // 155 -> 255 climbs back above it
+    client.update_uptime(&oracle, &publisher, &100);
+    let recovery = env.events().all().iter().find(|(_, topics, _)| *topics == recovered).unwrap();
+    let ev: ScoreRecovered = recovery.2.into_val(&env);
+    assert_eq!((ev.old_score, ev.new_score), (155, 255));
+
+    assert_eq!(
+        client.try_update_uptime(&oracle, &publisher, &101),
+        Err(Ok(ReputationError::InvalidUptime))
+    );
+}
+
+#[test]
+fn test_upgrade_authority() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let dao = Address::generate(&env);
+    client.initialize(&admin, &Address::generate(&env));
+
+    assert_eq!(client.get_version().version, 1);
+    assert_eq!(client.try_migrate(&1), Err(Ok(ReputationError::AlreadyMigrated)));
+
+    client.set_upgrade_authority(&admin, &dao);
+    assert_eq!(client.get_upgrade_authority(), Some(dao));
+    assert_eq!(client.try_set_upgrade_authority(&admin, &admin), Err(Ok(ReputationError::Unauthorized)));
+}
+
+#[test]
+fn test_extend_ttl_publisher() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    client.init_publisher(&admin, &publisher);
+
+    let ttl = || env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&DataKey::Reputation(publisher.clone())));
+    assert_eq!(ttl(), REPUTATION_TTL.extend_to);
+
+    for _ in 0..12 {
+        env.ledger().with_mut(|li| li.sequence_number += 80_000);
+        client.get_version();
+    }
+    assert!(ttl() < REPUTATION_TTL.threshold);
+
+    assert!(client.extend_ttl_publisher(&publisher));
+    assert_eq!(ttl(), REPUTATION_TTL.extend_to);
+    assert!(!client.extend_ttl_publisher(&Address::generate(&env)));
+}
+
+#[test]
+fn test_eligibility_screening() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherReputationContract);
+    let client = PublisherReputationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let good = Address::generate(&env);
+    let slashed = Address::generate(&env);
+    let flaky = Address::generate(&env);
+    let unknown = Address::generate(&env);
+    client.initialize(&admin, &oracle);
+    for publisher in [&good, &slashed, &flaky] {
+        client.init_publisher(&admin, publisher);
+    }
+    client.propose_slash(&oracle, &slashed, &50, &BytesN::from_array(&env, &[3u8; 32]));
+    client.update_uptime(&oracle, &flaky, &40);
+
+    assert!(client.is_eligible(&good, &400, &90, &0));
+    assert!(!client.is_eligible(&good, &600, &90, &0));
+    assert!(!client.is_eligible(&slashed, &0, &0, &0));
+    assert!(client.is_eligible(&slashed, &0, &0, &1));
+    assert!(!client.is_eligible(&flaky, &0, &90, &0));
+    assert!(!client.is_eligible(&unknown, &0, &0, &10));
+
+    let criteria = EligibilityCriteria { min_score: 0, min_uptime: 90, max_slashes: 0 };
+    let candidates = Vec::from_array(&env, [unknown.clone(), flaky.clone(), good.clone(), slashed.clone()]);
+    assert_eq!(client.filter_eligible(&candidates, &criteria), Vec::from_array(&env, [good.clone()]));
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..=MAX_ELIGIBILITY_BATCH {
+        too_many.push_back(good.clone());
+    }
+    assert_eq!(
+        client.try_filter_eligible(&too_many, &criteria),
+        Err(Ok(ReputationError::InvalidBatchSize))
+    );
+}
@@ -5,8 +5,13 @@
 #![no_std]
 use soroban_sdk::{
     contract, contractimpl, contracttype, symbol_short,
-    Address, Env, String,
+    token, Address, BytesN, Env, IntoVal, Map, String, Symbol, Val, Vec,
 };
+pub use pulsartrack_errors::VerificationError;
+use pulsartrack_events::{self as events, contract_event, RoleGranted, RoleRevoked};
+use pulsartrack_access::{Ownable, RoleBased};
+use pulsartrack_upgrade::{ContractVersion, Upgradeable};
+use pulsartrack_storage::{self as storage, Persistent, TtlPolicy};
 
 // ============================================================
 // Data Types
@@ -20,10 +25,11 @@ pub enum VerificationStatus {
     Rejected,
     Suspended,
     Revoked,
+    Inactive,
 }
 
 #[contracttype]
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub enum PublisherTier {
     Bronze,
     Silver,
@@ -37,25 +43,374 @@ pub struct Publisher {
     pub owner: Address,
     pub status: VerificationStatus,
     pub tier: PublisherTier,
-    pub domain: String,
+    pub domains: Vec<String>,
     pub reputation_score: u32,
     pub total_earnings: i128,
     pub total_impressions: u64,
     pub join_ledger: u32,
     pub verified_at: Option<u64>,
+    pub reviewed_by: Option<Address>, // verifier behind the latest status decision
+    pub suspended_until: Option<u64>, // None while suspended means indefinitely
+    pub deactivated_at: Option<u64>,
     pub last_active: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RejectionReason {
+    InvalidDocuments,
+    IdentityMismatch,
+    SanctionsMatch,
+    FraudSuspected,
+    IncompleteSubmission,
+    Other,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Rejection {
+    pub reason: RejectionReason,
+    pub verifier: Address,
+    pub rejected_at: u64,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct KycRecord {
     pub publisher: Address,
-    pub kyc_hash: String,     // hash of KYC documents stored off-chain
-    pub kyc_provider: String, // name of KYC provider
+    pub version: u32, // 1 for the first submission, bumped on each re-submission
+    pub kyc_hash: String,      // hash of KYC documents stored off-chain
+    pub kyc_provider: Address, // registered KYC provider that checked the documents
+    pub attested_at: Option<u64>, // set once the provider signs off on `kyc_hash`
     pub verified: bool,
     pub submitted_at: u64,
     pub verified_at: Option<u64>,
+    pub expires_at: Option<u64>, // set on verification; re-verify before it passes
+    pub reviewed_by: Option<Address>,
+}
+
+/// Bond every new registration must lock; slashed bonds go to `treasury`
+#[contracttype]
+#[derive(Clone)]
+pub struct BondConfig {
+    pub token: Address,
+    pub amount: i128,
+    pub treasury: Address,
+}
+
+/// Token publisher earnings settle in; withdrawal fees go to `fee_recipient`
+#[contracttype]
+#[derive(Clone)]
+pub struct EarningsConfig {
+    pub token: Address,
+    pub fee_recipient: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherBond {
+    pub token: Address,
+    pub amount: i128,
+    pub treasury: Address, // recipient if the bond is slashed
+    pub locked_at: u64,
+}
+
+/// A referrer earns `reward` once a referred publisher is verified and has
+/// earned at least `earnings_threshold`
+#[contracttype]
+#[derive(Clone)]
+pub struct ReferralConfig {
+    pub token: Address,
+    pub reward: i128,
+    pub earnings_threshold: i128,
+}
+
+/// Targeting and payout details, kept apart from the core `Publisher` record
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherProfile {
+    pub categories: Vec<Symbol>,
+    pub regions: Vec<Symbol>,
+    pub contact_hash: BytesN<32>, // hash of off-chain contact details
+    pub payout_address: Address,  // where settlement contracts send earnings
+    pub updated_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReinstatementRequest {
+    pub publisher: Address,
+    pub appeal_hash: BytesN<32>, // hash of the off-chain appeal
+    pub requested_at: u64,
+}
+
+/// Minimums a publisher must meet on every metric to hold a tier
+#[contracttype]
+#[derive(Clone)]
+pub struct TierThreshold {
+    pub min_earnings: i128,
+    pub min_impressions: u64,
+    pub min_reputation: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TierConfig {
+    pub silver: TierThreshold,
+    pub gold: TierThreshold,
+    pub platinum: TierThreshold,
+}
+
+/// Per-tier commercial parameters read by the fee manager and orchestrator
+#[contracttype]
+#[derive(Clone)]
+pub struct TierBenefits {
+    pub platform_fee_bps: u32,
+    pub max_concurrent_campaigns: u32,
+    pub payout_frequency_secs: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct DomainChallenge {
+    pub publisher: Address,
+    pub domain: String,
+    pub nonce: u64, // published by the publisher in a DNS TXT record or well-known file
+    pub requested_at: u64,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherRegistered {
+    pub publisher: Address,
+}
+contract_event!(PublisherRegistered, "publisher_registered");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct DomainConfirmed {
+    pub publisher: Address,
+    pub domain: String,
+    pub proof_hash: BytesN<32>,
+}
+contract_event!(DomainConfirmed, "domain_confirmed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct DomainRemoved {
+    pub publisher: Address,
+    pub domain: String,
+}
+contract_event!(DomainRemoved, "domain_removed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct KycSubmitted {
+    pub publisher: Address,
+    pub version: u32,
+}
+contract_event!(KycSubmitted, "kyc_submitted");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct KycAttested {
+    pub publisher: Address,
+    pub provider: Address,
+}
+contract_event!(KycAttested, "kyc_attested");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct KycProviderAdded {
+    pub provider: Address,
+    pub name: String,
+}
+contract_event!(KycProviderAdded, "kyc_provider_added");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherVerified {
+    pub publisher: Address,
+    pub verifier: Address,
+}
+contract_event!(PublisherVerified, "publisher_verified");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct KycRejected {
+    pub publisher: Address,
+    pub verifier: Address,
+    pub reason: RejectionReason,
+}
+contract_event!(KycRejected, "kyc_rejected");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct KycExpired {
+    pub publisher: Address,
+}
+contract_event!(KycExpired, "kyc_expired");
+
+/// `suspended_until` is None for an indefinite suspension
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherSuspended {
+    pub publisher: Address,
+    pub verifier: Address,
+    pub suspended_until: Option<u64>,
+}
+contract_event!(PublisherSuspended, "publisher_suspended");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReinstatementRequested {
+    pub publisher: Address,
+    pub appeal_hash: BytesN<32>,
+}
+contract_event!(ReinstatementRequested, "reinstatement_requested");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherReinstated {
+    pub publisher: Address,
+    pub verifier: Address,
+}
+contract_event!(PublisherReinstated, "publisher_reinstated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherDeactivated {
+    pub publisher: Address,
+}
+contract_event!(PublisherDeactivated, "publisher_deactivated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherReactivated {
+    pub publisher: Address,
+    pub status: VerificationStatus,
 }
+contract_event!(PublisherReactivated, "publisher_reactivated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct DomainsReleased {
+    pub publisher: Address,
+    pub count: u32,
+}
+contract_event!(DomainsReleased, "domains_released");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ProfileUpdated {
+    pub publisher: Address,
+}
+contract_event!(ProfileUpdated, "profile_updated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PublisherRevoked {
+    pub publisher: Address,
+    pub slashed: i128,
+}
+contract_event!(PublisherRevoked, "publisher_revoked");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReferralClaimed {
+    pub referrer: Address,
+    pub amount: i128,
+}
+contract_event!(ReferralClaimed, "referral_claimed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EarningsSettled {
+    pub caller: Address,
+    pub publisher: Address,
+    pub amount: i128,
+}
+contract_event!(EarningsSettled, "earnings_settled");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EarningsWithdrawn {
+    pub publisher: Address,
+    pub payout: Address,
+    pub amount: i128, // net of the fee
+    pub fee: i128,
+}
+contract_event!(EarningsWithdrawn, "earnings_withdrawn");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TierBenefitsSet {
+    pub tier: PublisherTier,
+    pub platform_fee_bps: u32,
+    pub max_concurrent_campaigns: u32,
+    pub payout_frequency_secs: u64,
+}
+contract_event!(TierBenefitsSet, "tier_benefits_set");
+
+/// One batch of impressions; `publishers` is how many distinct publishers it credited
+#[contracttype]
+#[derive(Clone)]
+pub struct ImpressionsRecorded {
+    pub caller: Address,
+    pub publishers: u32,
+    pub impressions: u64,
+    pub earnings: i128,
+}
+contract_event!(ImpressionsRecorded, "impressions_recorded");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReferralAccrued {
+    pub referrer: Address,
+    pub publisher: Address,
+    pub reward: i128,
+}
+contract_event!(ReferralAccrued, "referral_accrued");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct StatusChanged {
+    pub publisher: Address,
+    pub old: VerificationStatus,
+    pub new: VerificationStatus,
+}
+contract_event!(StatusChanged, "status_changed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct DomainRequested {
+    pub publisher: Address,
+    pub domain: String,
+    pub nonce: u64,
+}
+contract_event!(DomainRequested, "domain_requested");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct DomainTransferred {
+    pub from: Address,
+    pub to: Address,
+    pub domain: String,
+}
+contract_event!(DomainTransferred, "domain_transferred");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TierChanged {
+    pub publisher: Address,
+    pub old: PublisherTier,
+    pub new: PublisherTier,
+}
+contract_event!(TierChanged, "tier_changed");
 
 // ============================================================
 // Storage Keys
@@ -66,18 +421,62 @@ pub enum DataKey {
     Admin,
     PublisherCount,
     Publisher(Address),
-    KycRecord(Address),
+    KycRecord(Address, u32),
+    KycVersion(Address),
     DomainOwner(String),
+    ReputationContract,
+    KycValidity,
+    DomainOracles,
+    DomainChallenge(Address, String),
+    DomainProof(String),
+    DomainTransferredAt(String),
+    Verifiers,
+    KycProvider(Address),
+    AuthorizedCaller(Address),
+    TierConfig,
+    Reinstatement(Address),
+    Profile(Address),
+    PublisherIndex(u64),
+    BondConfig,
+    Bond(Address),
+    Rejection(Address),
+    ReapplyCooldown,
+    ReferralConfig,
+    ReferralPool,
+    Referrer(Address),
+    ReferralRewarded(Address),
+    ReferralRewards(Address),
+    StatusSubscribers,
+    Version, // bare u32 from v2; superseded by ContractVersion
+    TierBenefits(PublisherTier),
+    UpgradeAuthority,
+    ContractVersion,
+    EarningsConfig,
+    Accrued(Address), // settled earnings not yet withdrawn
+    AccruedTotal, // sum of all Accrued balances
 }
 
 // ============================================================
 // Contract
 // ============================================================
 
+const CONTRACT_VERSION: u32 = 2; // deployments from before versioning count as 1
+const DEFAULT_KYC_VALIDITY: u64 = 31_536_000; // 365 days
+const DOMAIN_TRANSFER_COOLDOWN: u64 = 2_592_000; // 30 days between transfers of a domain
+const MAX_IMPRESSION_BATCH: u32 = 100;
+const DEFAULT_REAPPLY_COOLDOWN: u64 = 604_800; // 7 days after a rejection
+const MAX_PROFILE_CATEGORIES: u32 = 10;
+const MAX_PROFILE_REGIONS: u32 = 20;
+const DEACTIVATION_GRACE_PERIOD: u64 = 2_592_000; // 30 days before an inactive publisher's domains are freed
+
 const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
 const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+// TTL per kind of persistent entry, bumped on every access
+const PUBLISHER_TTL: TtlPolicy = storage::LONG_LIVED;
+
+const EVENT_TAG: &str = "verification";
 
 #[contract]
 pub struct PublisherVerificationContract;
@@ -85,20 +484,68 @@ pub struct PublisherVerificationContract;
 #[contractimpl]
 impl PublisherVerificationContract {
     /// Initialize the contract
-    pub fn initialize(env: Env, admin: Address) {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), VerificationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
-        }
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &admin);
+        Ownable::init(&env, &DataKey::Admin, &admin)?;
         env.storage()
             .instance()
             .set(&DataKey::PublisherCount, &0u64);
+        Upgradeable::init(&env, &DataKey::ContractVersion, CONTRACT_VERSION);
+        Ok(())
+    }
+
+    /// See `Upgradeable`
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::require_authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)?;
+        Upgradeable::upgrade(&env, EVENT_TAG, &DataKey::ContractVersion, new_wasm_hash);
+        Ok(())
+    }
+
+    /// Also carries over the bare version number v2 stored under `Version`
+    pub fn migrate(env: Env, from_version: u32) -> Result<u32, VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::require_authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)?;
+
+        // v2 kept a bare u32 under `Version`; carry it over before checking
+        if let Some(legacy) = env.storage().instance().get::<DataKey, u32>(&DataKey::Version) {
+            Upgradeable::init(&env, &DataKey::ContractVersion, legacy);
+            env.storage().instance().remove(&DataKey::Version);
+        }
+
+        Ok(Upgradeable::migrate(&env, EVENT_TAG, &DataKey::ContractVersion, from_version, CONTRACT_VERSION, |from| {
+            // v1 -> v2: the publisher count predates the index, so make sure it exists
+            if from < 2 && !env.storage().instance().has(&DataKey::PublisherCount) {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::PublisherCount, &0u64);
+            }
+        })?)
+    }
+
+    pub fn set_upgrade_authority(env: Env, caller: Address, authority: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::set_authority(&env, EVENT_TAG, &DataKey::UpgradeAuthority, &DataKey::Admin, &caller, &authority)?;
+        Ok(())
+    }
+
+    pub fn get_upgrade_authority(env: Env) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)
+    }
+
+    pub fn get_version(env: Env) -> ContractVersion {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        match env.storage().instance().get::<DataKey, u32>(&DataKey::Version) {
+            Some(legacy) => ContractVersion { version: legacy, wasm_hash: None, upgraded_at: None, migrated_at: None },
+            None => Upgradeable::version(&env, &DataKey::ContractVersion),
+        }
     }
 
-    /// Register as a publisher (self-registration)
-    pub fn register_publisher(env: Env, publisher: Address, domain: String) {
+    /// Register as a publisher (self-registration), optionally crediting a
+    /// referrer. The domain is not bound until ownership is proven; returns
+    /// the challenge nonce for it.
+    pub fn register_publisher(env: Env, publisher: Address, domain: String, referrer: Option<Address>) -> Result<u64, VerificationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         publisher.require_auth();
 
@@ -107,28 +554,42 @@ impl PublisherVerificationContract {
             .persistent()
             .has(&DataKey::Publisher(publisher.clone()))
         {
-            panic!("already registered");
+            return Err(VerificationError::AlreadyRegistered);
         }
 
-        // Check domain not taken
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::DomainOwner(domain.clone()))
-        {
-            panic!("domain already registered");
+        if let Some(referrer) = referrer {
+            if referrer == publisher
+                || !env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::Publisher(referrer.clone()))
+            {
+                return Err(VerificationError::InvalidReferrer);
+            }
+            let _ttl_key = DataKey::Referrer(publisher.clone());
+            env.storage()
+                .persistent()
+                .set(&_ttl_key, &referrer);
+            env.storage()
+                .persistent()
+                .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
         }
 
+        Self::_lock_bond(&env, &publisher);
+
         let pub_data = Publisher {
             owner: publisher.clone(),
             status: VerificationStatus::Pending,
             tier: PublisherTier::Bronze,
-            domain: domain.clone(),
+            domains: Vec::new(&env),
             reputation_score: 0,
             total_earnings: 0,
             total_impressions: 0,
             join_ledger: env.ledger().sequence(),
             verified_at: None,
+            reviewed_by: None,
+            suspended_until: None,
+            deactivated_at: None,
             last_active: env.ledger().timestamp(),
         };
 
@@ -139,13 +600,6 @@ impl PublisherVerificationContract {
         env.storage()
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
-        let _ttl_key = DataKey::DomainOwner(domain);
-        env.storage()
-            .persistent()
-            .set(&_ttl_key, &publisher);
-        env.storage()
-            .persistent()
-            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
         let count: u64 = env
             .storage()
@@ -155,78 +609,203 @@ impl PublisherVerificationContract {
         env.storage()
             .instance()
             .set(&DataKey::PublisherCount, &(count + 1));
+        let _ttl_key = DataKey::PublisherIndex(count);
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &publisher);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("publisher"), symbol_short!("register")),
-            publisher,
-        );
+        events::publish(&env, EVENT_TAG, PublisherRegistered { publisher: publisher.clone() });
+
+        Self::_open_challenge(&env, &pub_data, domain)
     }
 
-    /// Submit KYC documents (publisher)
-    pub fn submit_kyc(
-        env: Env,
-        publisher: Address,
-        kyc_hash: String,
-        kyc_provider: String,
-    ) {
+    /// Start a claim on `domain`. Returns the nonce the publisher must
+    /// publish on the domain before an oracle confirms it.
+    pub fn request_domain(env: Env, publisher: Address, domain: String) -> Result<u64, VerificationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         publisher.require_auth();
 
-        if !env
+        let pub_data: Publisher = env
             .storage()
             .persistent()
-            .has(&DataKey::Publisher(publisher.clone()))
-        {
-            panic!("not registered");
-        }
+            .get(&DataKey::Publisher(publisher.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
 
-        let kyc = KycRecord {
-            publisher: publisher.clone(),
-            kyc_hash,
-            kyc_provider,
-            verified: false,
-            submitted_at: env.ledger().timestamp(),
-            verified_at: None,
-        };
+        Self::_open_challenge(&env, &pub_data, domain)
+    }
+
+    /// Bind a challenged domain to the publisher once its proof (hash of the
+    /// DNS TXT record or well-known file) has been checked off-chain.
+    /// Callable by the admin, a verifier or a domain oracle.
+    pub fn confirm_domain(
+        env: Env,
+        verifier: Address,
+        publisher: Address,
+        domain: String,
+        proof_hash: BytesN<32>,
+    ) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        verifier.require_auth();
+        let oracles: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::DomainOracles)
+            .unwrap_or(Vec::new(&env));
+        if !oracles.contains(&verifier) && !Self::_is_verifier(&env, &verifier) {
+            return Err(VerificationError::Unauthorized);
+        }
+
+        let challenge_key = DataKey::DomainChallenge(publisher.clone(), domain.clone());
+        if !env.storage().persistent().has(&challenge_key) {
+            return Err(VerificationError::NoPendingChallenge);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::DomainOwner(domain.clone()))
+        {
+            return Err(VerificationError::DomainAlreadyRegistered);
+        }
+
+        let mut pub_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+        if pub_data.domains.len() >= Self::_domain_cap(&pub_data.tier) {
+            return Err(VerificationError::DomainLimitReached);
+        }
+        pub_data.domains.push_back(domain.clone());
 
-        let _ttl_key = DataKey::KycRecord(publisher.clone());
+        env.storage().persistent().remove(&challenge_key);
+        let _ttl_key = DataKey::Publisher(publisher.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &pub_data);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        let _ttl_key = DataKey::DomainOwner(domain.clone());
         env.storage()
             .persistent()
-            .set(&_ttl_key, &kyc);
+            .set(&_ttl_key, &publisher);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        let _ttl_key = DataKey::DomainProof(domain.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &proof_hash);
         env.storage()
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("kyc"), symbol_short!("submitted")),
-            publisher,
-        );
+        events::publish(&env, EVENT_TAG, DomainConfirmed { publisher, domain, proof_hash });
+        Ok(())
     }
 
-    /// Verify a publisher (admin only)
-    pub fn verify_publisher(
-        env: Env,
-        admin: Address,
-        publisher: Address,
-        initial_tier: PublisherTier,
-    ) {
+    /// Allow an oracle to confirm domain ownership proofs (admin only)
+    pub fn add_domain_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+
+        let mut oracles: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::DomainOracles)
+            .unwrap_or(Vec::new(&env));
+        if oracles.contains(&oracle) {
+            return Err(VerificationError::AlreadyOracle);
+        }
+        oracles.push_back(oracle);
+        env.storage()
+            .instance()
+            .set(&DataKey::DomainOracles, &oracles);
+        Ok(())
+    }
+
+    pub fn remove_domain_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+
+        let mut oracles: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::DomainOracles)
+            .unwrap_or(Vec::new(&env));
+        let idx = oracles.first_index_of(&oracle).ok_or(VerificationError::NotOracle)?;
+        oracles.remove(idx);
+        env.storage()
+            .instance()
+            .set(&DataKey::DomainOracles, &oracles);
+        Ok(())
+    }
+
+    /// Register a contract to be told about publisher status transitions (admin only).
+    /// Subscribers must expose `on_publisher_status(publisher, old, new)`.
+    pub fn add_status_subscriber(env: Env, admin: Address, subscriber: Address) -> Result<(), VerificationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        admin.require_auth();
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if admin != stored_admin {
-            panic!("unauthorized");
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+
+        let mut subscribers: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::StatusSubscribers)
+            .unwrap_or(Vec::new(&env));
+        if subscribers.contains(&subscriber) {
+            return Err(VerificationError::AlreadySubscribed);
         }
+        subscribers.push_back(subscriber);
+        env.storage()
+            .instance()
+            .set(&DataKey::StatusSubscribers, &subscribers);
+        Ok(())
+    }
+
+    pub fn remove_status_subscriber(env: Env, admin: Address, subscriber: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+
+        let mut subscribers: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::StatusSubscribers)
+            .unwrap_or(Vec::new(&env));
+        let idx = subscribers.first_index_of(&subscriber).ok_or(VerificationError::NotSubscribed)?;
+        subscribers.remove(idx);
+        env.storage()
+            .instance()
+            .set(&DataKey::StatusSubscribers, &subscribers);
+        Ok(())
+    }
+
+    pub fn get_status_subscribers(env: Env) -> Vec<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .instance()
+            .get(&DataKey::StatusSubscribers)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Release one of the publisher's domains
+    pub fn remove_domain(env: Env, publisher: Address, domain: String) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
 
         let mut pub_data: Publisher = env
             .storage()
             .persistent()
             .get(&DataKey::Publisher(publisher.clone()))
-            .expect("publisher not found");
+            .ok_or(VerificationError::PublisherNotFound)?;
 
-        pub_data.status = VerificationStatus::Verified;
-        pub_data.tier = initial_tier;
-        pub_data.verified_at = Some(env.ledger().timestamp());
-        pub_data.reputation_score = 100;
+        let idx = pub_data
+            .domains
+            .first_index_of(&domain)
+            .ok_or(VerificationError::DomainNotOwned)?;
+        pub_data.domains.remove(idx);
 
         let _ttl_key = DataKey::Publisher(publisher.clone());
         env.storage()
@@ -235,164 +814,1827 @@ impl PublisherVerificationContract {
         env.storage()
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::DomainOwner(domain.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::DomainProof(domain.clone()));
 
-        // Mark KYC as verified
-        if let Some(mut kyc) = env
+        events::publish(&env, EVENT_TAG, DomainRemoved { publisher, domain });
+        Ok(())
+    }
+
+    /// Hand a domain to another publisher. Both parties must authorize and
+    /// the domain must not have changed hands within the cooldown.
+    pub fn transfer_domain(env: Env, current_owner: Address, new_owner: Address, domain: String) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        current_owner.require_auth();
+        new_owner.require_auth();
+
+        let last_transfer: Option<u64> = env
             .storage()
             .persistent()
-            .get::<DataKey, KycRecord>(&DataKey::KycRecord(publisher.clone()))
-        {
-            kyc.verified = true;
-            kyc.verified_at = Some(env.ledger().timestamp());
-            let _ttl_key = DataKey::KycRecord(publisher.clone());
-            env.storage()
-                .persistent()
-                .set(&_ttl_key, &kyc);
-            env.storage()
-                .persistent()
-                .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+            .get(&DataKey::DomainTransferredAt(domain.clone()));
+        if let Some(at) = last_transfer {
+            if env.ledger().timestamp() < at + DOMAIN_TRANSFER_COOLDOWN {
+                return Err(VerificationError::TransferCooldownActive);
+            }
         }
 
-        env.events().publish(
-            (symbol_short!("publisher"), symbol_short!("verified")),
-            publisher,
-        );
+        Self::_move_domain(&env, &current_owner, &new_owner, domain)?;
+        Ok(())
     }
 
-    /// Suspend a publisher (admin only)
-    pub fn suspend_publisher(env: Env, admin: Address, publisher: Address) {
+    /// Reassign a disputed domain, bypassing the cooldown (admin only)
+    pub fn force_transfer_domain(env: Env, admin: Address, domain: String, new_owner: Address) -> Result<(), VerificationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        admin.require_auth();
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if admin != stored_admin {
-            panic!("unauthorized");
-        }
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
 
-        let mut pub_data: Publisher = env
+        let current_owner: Address = env
             .storage()
             .persistent()
-            .get(&DataKey::Publisher(publisher.clone()))
-            .expect("publisher not found");
+            .get(&DataKey::DomainOwner(domain.clone()))
+            .ok_or(VerificationError::DomainNotOwned)?;
+        Self::_move_domain(&env, &current_owner, &new_owner, domain)?;
+        Ok(())
+    }
 
-        pub_data.status = VerificationStatus::Suspended;
+    /// Submit KYC documents (publisher)
+    pub fn submit_kyc(
+        env: Env,
+        publisher: Address,
+        kyc_hash: String,
+        kyc_provider: Address,
+    ) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
 
-        let _ttl_key = DataKey::Publisher(publisher);
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Publisher(publisher.clone()))
+        {
+            return Err(VerificationError::PublisherNotFound);
+        }
+        if !env
+            .storage()
+            .instance()
+            .has(&DataKey::KycProvider(kyc_provider.clone()))
+        {
+            return Err(VerificationError::UnknownKycProvider);
+        }
+        if let Some(rejection) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Rejection>(&DataKey::Rejection(publisher.clone()))
+        {
+            let cooldown = Self::get_reapply_cooldown(env.clone());
+            if env.ledger().timestamp() < rejection.rejected_at + cooldown {
+                return Err(VerificationError::ReapplyCooldownActive);
+            }
+        }
+
+        let version: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::KycVersion(publisher.clone()))
+            .unwrap_or(0)
+            + 1;
+        let kyc = KycRecord {
+            publisher: publisher.clone(),
+            version,
+            kyc_hash,
+            kyc_provider,
+            attested_at: None,
+            verified: false,
+            submitted_at: env.ledger().timestamp(),
+            verified_at: None,
+            expires_at: None,
+            reviewed_by: None,
+        };
+
+        Self::_save_kyc(&env, &kyc);
+        let _ttl_key = DataKey::KycVersion(publisher.clone());
         env.storage()
             .persistent()
-            .set(&_ttl_key, &pub_data);
+            .set(&_ttl_key, &version);
         env.storage()
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, KycSubmitted { publisher, version });
+        Ok(())
     }
 
-    /// Update publisher reputation score (admin only)
-    pub fn update_reputation(env: Env, admin: Address, publisher: Address, score: u32) {
+    /// Provider sign-off that `kyc_hash` matches the documents it checked.
+    /// A submitted KYC record must be attested before it can be verified.
+    pub fn attest_kyc(env: Env, provider: Address, publisher: Address, kyc_hash: String) -> Result<(), VerificationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        admin.require_auth();
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if admin != stored_admin {
-            panic!("unauthorized");
+        provider.require_auth();
+        if !env
+            .storage()
+            .instance()
+            .has(&DataKey::KycProvider(provider.clone()))
+        {
+            return Err(VerificationError::UnknownKycProvider);
         }
 
-        if score > 1000 {
-            panic!("invalid score");
+        let mut kyc: KycRecord = Self::_latest_kyc(&env, &publisher)
+            .ok_or(VerificationError::KycNotFound)?;
+        if kyc.kyc_provider != provider {
+            return Err(VerificationError::Unauthorized);
+        }
+        if kyc.kyc_hash != kyc_hash {
+            return Err(VerificationError::KycHashMismatch);
         }
 
+        kyc.attested_at = Some(env.ledger().timestamp());
+        Self::_save_kyc(&env, &kyc);
+
+        events::publish(&env, EVENT_TAG, KycAttested { publisher, provider });
+        Ok(())
+    }
+
+    /// Approve a KYC provider; `name` is for display only (admin only)
+    pub fn add_kyc_provider(env: Env, admin: Address, provider: Address, name: String) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::KycProvider(provider.clone()), &name);
+
+        events::publish(&env, EVENT_TAG, KycProviderAdded { provider, name });
+        Ok(())
+    }
+
+    pub fn remove_kyc_provider(env: Env, admin: Address, provider: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+        env.storage()
+            .instance()
+            .remove(&DataKey::KycProvider(provider.clone()));
+
+        events::publish(&env, EVENT_TAG, RoleRevoked { role: symbol_short!("kyc_prov"), account: provider });
+        Ok(())
+    }
+
+    /// Display name of an approved KYC provider
+    pub fn get_kyc_provider(env: Env, provider: Address) -> Option<String> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .instance()
+            .get(&DataKey::KycProvider(provider))
+    }
+
+    /// Verify a publisher (admin or verifier)
+    pub fn verify_publisher(
+        env: Env,
+        verifier: Address,
+        publisher: Address,
+        initial_tier: PublisherTier,
+    ) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_verifier(&env, &verifier)?;
+
         let mut pub_data: Publisher = env
             .storage()
             .persistent()
             .get(&DataKey::Publisher(publisher.clone()))
-            .expect("publisher not found");
+            .ok_or(VerificationError::PublisherNotFound)?;
 
-        pub_data.reputation_score = score;
-        pub_data.tier = Self::_score_to_tier(score);
+        let previous_status = pub_data.status.clone();
+        pub_data.status = VerificationStatus::Verified;
+        pub_data.tier = initial_tier;
+        pub_data.verified_at = Some(env.ledger().timestamp());
+        pub_data.reviewed_by = Some(verifier.clone());
+        pub_data.reputation_score = 100;
 
-        let _ttl_key = DataKey::Publisher(publisher);
+        let _ttl_key = DataKey::Publisher(publisher.clone());
         env.storage()
             .persistent()
             .set(&_ttl_key, &pub_data);
         env.storage()
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_notify_status(&env, &publisher, previous_status, pub_data.status.clone());
+
+        // Mark KYC as verified
+        if let Some(mut kyc) = Self::_latest_kyc(&env, &publisher) {
+            if kyc.attested_at.is_none() {
+                return Err(VerificationError::KycNotAttested);
+            }
+            kyc.verified = true;
+            kyc.verified_at = Some(env.ledger().timestamp());
+            kyc.expires_at = Some(env.ledger().timestamp() + Self::_kyc_validity(&env));
+            kyc.reviewed_by = Some(verifier.clone());
+            Self::_save_kyc(&env, &kyc);
+        }
+
+        // Start tracking reputation the first time a publisher is verified
+        if let Some(reputation) = env
+            .storage()
+            .instance()
+            .get::<DataKey, Address>(&DataKey::ReputationContract)
+        {
+            let existing: Val = env.invoke_contract(
+                &reputation,
+                &Symbol::new(&env, "get_reputation"),
+                (publisher.clone(),).into_val(&env),
+            );
+            if existing.is_void() {
+                env.invoke_contract::<()>(
+                    &reputation,
+                    &Symbol::new(&env, "init_publisher"),
+                    (env.current_contract_address(), publisher.clone()).into_val(&env),
+                );
+            }
+        }
+
+        events::publish(&env, EVENT_TAG, PublisherVerified { publisher, verifier });
+        Ok(())
     }
 
-    /// Record impression (called by campaign orchestrator)
-    pub fn record_impression(env: Env, caller: Address, publisher: Address, earning: i128) {
+    /// Reject a publisher's KYC submission with a reason. The publisher may
+    /// not resubmit KYC until the re-application cooldown passes (admin or verifier).
+    pub fn reject_publisher(env: Env, verifier: Address, publisher: Address, reason: RejectionReason) -> Result<(), VerificationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        // In production, restrict to campaign orchestrator contract only
+        Self::_require_verifier(&env, &verifier)?;
+
         let mut pub_data: Publisher = env
             .storage()
             .persistent()
             .get(&DataKey::Publisher(publisher.clone()))
-            .expect("publisher not found");
-
-        match pub_data.status {
-            VerificationStatus::Verified => {}
-            _ => panic!("publisher not verified"),
-        }
+            .ok_or(VerificationError::PublisherNotFound)?;
 
-        pub_data.total_earnings += earning;
-        pub_data.total_impressions += 1;
-        pub_data.last_active = env.ledger().timestamp();
+        let previous_status = pub_data.status.clone();
+        pub_data.status = VerificationStatus::Rejected;
+        pub_data.reviewed_by = Some(verifier.clone());
 
-        let _ttl_key = DataKey::Publisher(publisher);
+        let _ttl_key = DataKey::Publisher(publisher.clone());
         env.storage()
             .persistent()
             .set(&_ttl_key, &pub_data);
         env.storage()
             .persistent()
             .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
-    }
+        Self::_notify_status(&env, &publisher, previous_status, pub_data.status.clone());
 
-    // ============================================================
-    // Read-Only Functions
-    // ============================================================
+        if let Some(mut kyc) = Self::_latest_kyc(&env, &publisher) {
+            kyc.verified = false;
+            kyc.reviewed_by = Some(verifier.clone());
+            Self::_save_kyc(&env, &kyc);
+        }
 
-    pub fn get_publisher(env: Env, publisher: Address) -> Option<Publisher> {
-        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let rejection = Rejection {
+            reason: reason.clone(),
+            verifier: verifier.clone(),
+            rejected_at: env.ledger().timestamp(),
+        };
+        let _ttl_key = DataKey::Rejection(publisher.clone());
         env.storage()
             .persistent()
-            .get(&DataKey::Publisher(publisher))
+            .set(&_ttl_key, &rejection);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, KycRejected { publisher, verifier, reason });
+        Ok(())
     }
 
-    pub fn get_kyc(env: Env, publisher: Address) -> Option<KycRecord> {
+    /// Most recent rejection of the publisher, if any
+    pub fn get_rejection(env: Env, publisher: Address) -> Option<Rejection> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         env.storage()
             .persistent()
-            .get(&DataKey::KycRecord(publisher))
+            .get(&DataKey::Rejection(publisher))
     }
 
-    pub fn is_verified(env: Env, publisher: Address) -> bool {
+    /// Set how long a rejected publisher waits before resubmitting KYC (admin only)
+    pub fn set_reapply_cooldown(env: Env, admin: Address, cooldown_secs: u64) -> Result<(), VerificationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        if let Some(pub_data) = env
-            .storage()
-            .persistent()
-            .get::<DataKey, Publisher>(&DataKey::Publisher(publisher))
-        {
-            matches!(pub_data.status, VerificationStatus::Verified)
-        } else {
-            false
-        }
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+        env.storage().instance().set(&DataKey::ReapplyCooldown, &cooldown_secs);
+        Ok(())
     }
 
-    pub fn get_domain_owner(env: Env, domain: String) -> Option<Address> {
+    pub fn get_reapply_cooldown(env: Env) -> u64 {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         env.storage()
-            .persistent()
-            .get(&DataKey::DomainOwner(domain))
+            .instance()
+            .get(&DataKey::ReapplyCooldown)
+            .unwrap_or(DEFAULT_REAPPLY_COOLDOWN)
     }
 
-    pub fn get_publisher_count(env: Env) -> u64 {
+    /// Delegate KYC review and publisher status decisions (admin only)
+    pub fn add_verifier(env: Env, admin: Address, verifier: Address) -> Result<(), VerificationError> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        env.storage()
-            .instance()
-            .get(&DataKey::PublisherCount)
-            .unwrap_or(0)
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+
+        if !RoleBased::grant_role(&env, &DataKey::Verifiers, &verifier) {
+            return Err(VerificationError::AlreadyVerifier);
+        }
+
+        events::publish(&env, EVENT_TAG, RoleGranted { role: symbol_short!("verifier"), account: verifier });
+        Ok(())
     }
 
-    // ============================================================
-    // Internal Helpers
-    // ============================================================
+    pub fn remove_verifier(env: Env, admin: Address, verifier: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+
+        if !RoleBased::revoke_role(&env, &DataKey::Verifiers, &verifier) {
+            return Err(VerificationError::NotVerifier);
+        }
+
+        events::publish(&env, EVENT_TAG, RoleRevoked { role: symbol_short!("verifier"), account: verifier });
+        Ok(())
+    }
+
+    pub fn get_verifiers(env: Env) -> Vec<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        RoleBased::members(&env, &DataKey::Verifiers)
+    }
+
+    /// Set how long a KYC verification stays valid, in seconds (admin only).
+    /// Applies to verifications made after the change.
+    pub fn set_kyc_validity(env: Env, admin: Address, validity_secs: u64) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+        if validity_secs == 0 {
+            return Err(VerificationError::InvalidValidity);
+        }
+        env.storage().instance().set(&DataKey::KycValidity, &validity_secs);
+        Ok(())
+    }
+
+    pub fn get_kyc_validity(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_kyc_validity(&env)
+    }
+
+    /// Downgrade a verified publisher whose KYC has lapsed back to Pending.
+    /// Permissionless so keepers can sweep expired records.
+    pub fn expire_kyc(env: Env, publisher: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if !Self::_kyc_lapsed(&env, &publisher) {
+            return Err(VerificationError::KycNotExpired);
+        }
+
+        let mut kyc: KycRecord = Self::_latest_kyc(&env, &publisher)
+            .ok_or(VerificationError::KycNotFound)?;
+        kyc.verified = false;
+        Self::_save_kyc(&env, &kyc);
+
+        let mut pub_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+        if pub_data.status == VerificationStatus::Verified {
+            let previous_status = pub_data.status.clone();
+            pub_data.status = VerificationStatus::Pending;
+            let _ttl_key = DataKey::Publisher(publisher.clone());
+            env.storage()
+                .persistent()
+                .set(&_ttl_key, &pub_data);
+            env.storage()
+                .persistent()
+                .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+            Self::_notify_status(&env, &publisher, previous_status, pub_data.status.clone());
+        }
+
+        events::publish(&env, EVENT_TAG, KycExpired { publisher });
+        Ok(())
+    }
+
+    /// Set the publisher-reputation contract initialized on verification (admin only)
+    pub fn set_reputation_contract(env: Env, admin: Address, reputation_contract: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::ReputationContract, &reputation_contract);
+        Ok(())
+    }
+
+    pub fn get_reputation_contract(env: Env) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::ReputationContract)
+    }
+
+    /// Suspend a publisher for `duration_secs`, or indefinitely if zero
+    /// (admin or verifier)
+    pub fn suspend_publisher(env: Env, verifier: Address, publisher: Address, duration_secs: u64) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_verifier(&env, &verifier)?;
+
+        let mut pub_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+
+        let previous_status = pub_data.status.clone();
+        pub_data.status = VerificationStatus::Suspended;
+        pub_data.reviewed_by = Some(verifier.clone());
+        pub_data.suspended_until = if duration_secs == 0 {
+            None
+        } else {
+            Some(env.ledger().timestamp() + duration_secs)
+        };
+
+        let _ttl_key = DataKey::Publisher(publisher.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &pub_data);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_notify_status(&env, &publisher, previous_status, pub_data.status.clone());
+
+        events::publish(
+            &env,
+            EVENT_TAG,
+            PublisherSuspended { publisher, verifier, suspended_until: pub_data.suspended_until },
+        );
+        Ok(())
+    }
+
+    /// Ask the verifiers to lift a suspension, referencing an off-chain appeal
+    pub fn request_reinstatement(env: Env, publisher: Address, appeal_hash: BytesN<32>) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+
+        if !Self::is_suspended(env.clone(), publisher.clone()) {
+            return Err(VerificationError::NotSuspended);
+        }
+
+        let request = ReinstatementRequest {
+            publisher: publisher.clone(),
+            appeal_hash: appeal_hash.clone(),
+            requested_at: env.ledger().timestamp(),
+        };
+        let _ttl_key = DataKey::Reinstatement(publisher.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &request);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, ReinstatementRequested { publisher, appeal_hash });
+        Ok(())
+    }
+
+    /// Lift a suspension early. The publisher returns to Verified if it had
+    /// been verified before, otherwise to Pending (admin or verifier).
+    pub fn reinstate_publisher(env: Env, verifier: Address, publisher: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_verifier(&env, &verifier)?;
+
+        let mut pub_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+        if pub_data.status != VerificationStatus::Suspended {
+            return Err(VerificationError::NotSuspended);
+        }
+
+        let previous_status = pub_data.status.clone();
+        pub_data.status = Self::_status_after_suspension(&pub_data);
+        pub_data.suspended_until = None;
+        pub_data.reviewed_by = Some(verifier.clone());
+
+        let _ttl_key = DataKey::Publisher(publisher.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &pub_data);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_notify_status(&env, &publisher, previous_status, pub_data.status.clone());
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Reinstatement(publisher.clone()));
+
+        events::publish(&env, EVENT_TAG, PublisherReinstated { publisher, verifier });
+        Ok(())
+    }
+
+    pub fn get_reinstatement_request(env: Env, publisher: Address) -> Option<ReinstatementRequest> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::Reinstatement(publisher))
+    }
+
+    /// Whether the publisher is under a suspension that has not yet run out
+    pub fn is_suspended(env: Env, publisher: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        match env
+            .storage()
+            .persistent()
+            .get::<DataKey, Publisher>(&DataKey::Publisher(publisher))
+        {
+            Some(pub_data) => Self::_suspension_active(&env, &pub_data),
+            None => false,
+        }
+    }
+
+    /// Leave the network. The account stops counting as verified and its
+    /// domains can be released once the grace period has passed.
+    pub fn deactivate(env: Env, publisher: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+
+        let mut pub_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+        match pub_data.status {
+            VerificationStatus::Verified | VerificationStatus::Pending => {}
+            _ => return Err(VerificationError::CannotDeactivate),
+        }
+
+        let previous_status = pub_data.status.clone();
+        pub_data.status = VerificationStatus::Inactive;
+        pub_data.deactivated_at = Some(env.ledger().timestamp());
+
+        // Leaving in good standing returns the registration bond
+        if let Some(bond) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, PublisherBond>(&DataKey::Bond(publisher.clone()))
+        {
+            token::Client::new(&env, &bond.token).transfer(
+                &env.current_contract_address(),
+                &publisher,
+                &bond.amount,
+            );
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Bond(publisher.clone()));
+        }
+
+        let _ttl_key = DataKey::Publisher(publisher.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &pub_data);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_notify_status(&env, &publisher, previous_status, pub_data.status.clone());
+
+        events::publish(&env, EVENT_TAG, PublisherDeactivated { publisher });
+        Ok(())
+    }
+
+    /// Come back after deactivating. Returns to Verified only if the
+    /// publisher was verified and its KYC has not lapsed, otherwise Pending.
+    pub fn reactivate(env: Env, publisher: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+
+        let mut pub_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+        if pub_data.status != VerificationStatus::Inactive {
+            return Err(VerificationError::NotInactive);
+        }
+
+        let previous_status = pub_data.status.clone();
+        pub_data.status = if pub_data.verified_at.is_some() && !Self::_kyc_lapsed(&env, &publisher) {
+            VerificationStatus::Verified
+        } else {
+            VerificationStatus::Pending
+        };
+        pub_data.deactivated_at = None;
+        pub_data.last_active = env.ledger().timestamp();
+        Self::_lock_bond(&env, &publisher);
+
+        let _ttl_key = DataKey::Publisher(publisher.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &pub_data);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_notify_status(&env, &publisher, previous_status, pub_data.status.clone());
+
+        events::publish(&env, EVENT_TAG, PublisherReactivated { publisher, status: pub_data.status });
+        Ok(())
+    }
+
+    /// Free the domains of a publisher that has been inactive for the grace
+    /// period. Permissionless.
+    pub fn release_inactive_domains(env: Env, publisher: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let mut pub_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+        let deactivated_at = match (&pub_data.status, pub_data.deactivated_at) {
+            (VerificationStatus::Inactive, Some(at)) => at,
+            _ => return Err(VerificationError::NotInactive),
+        };
+        if env.ledger().timestamp() < deactivated_at + DEACTIVATION_GRACE_PERIOD {
+            return Err(VerificationError::GracePeriodActive);
+        }
+
+        for domain in pub_data.domains.iter() {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::DomainOwner(domain.clone()));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::DomainProof(domain));
+        }
+        let released = pub_data.domains.len();
+        pub_data.domains = Vec::new(&env);
+
+        let _ttl_key = DataKey::Publisher(publisher.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &pub_data);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, DomainsReleased { publisher, count: released });
+        Ok(())
+    }
+
+    /// Set the publisher's targeting profile and payout address
+    pub fn update_profile(
+        env: Env,
+        publisher: Address,
+        categories: Vec<Symbol>,
+        regions: Vec<Symbol>,
+        contact_hash: BytesN<32>,
+        payout_address: Address,
+    ) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Publisher(publisher.clone()))
+        {
+            return Err(VerificationError::PublisherNotFound);
+        }
+        if categories.len() > MAX_PROFILE_CATEGORIES {
+            return Err(VerificationError::TooManyCategories);
+        }
+        if regions.len() > MAX_PROFILE_REGIONS {
+            return Err(VerificationError::TooManyRegions);
+        }
+
+        let profile = PublisherProfile {
+            categories,
+            regions,
+            contact_hash,
+            payout_address,
+            updated_at: env.ledger().timestamp(),
+        };
+        let _ttl_key = DataKey::Profile(publisher.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &profile);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, ProfileUpdated { publisher });
+        Ok(())
+    }
+
+    /// Revoke a publisher for fraud, forfeiting its bond to the treasury (admin only)
+    pub fn revoke_publisher(env: Env, admin: Address, publisher: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+
+        let mut pub_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+        if pub_data.status == VerificationStatus::Revoked {
+            return Err(VerificationError::AlreadyRevoked);
+        }
+        let previous_status = pub_data.status.clone();
+        pub_data.status = VerificationStatus::Revoked;
+        pub_data.reviewed_by = Some(admin);
+
+        let _ttl_key = DataKey::Publisher(publisher.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &pub_data);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_notify_status(&env, &publisher, previous_status, pub_data.status.clone());
+
+        let mut slashed: i128 = 0;
+        if let Some(bond) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, PublisherBond>(&DataKey::Bond(publisher.clone()))
+        {
+            token::Client::new(&env, &bond.token).transfer(
+                &env.current_contract_address(),
+                &bond.treasury,
+                &bond.amount,
+            );
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Bond(publisher.clone()));
+            slashed = bond.amount;
+        }
+
+        events::publish(&env, EVENT_TAG, PublisherRevoked { publisher, slashed });
+        Ok(())
+    }
+
+    /// Require new registrations to lock `amount` of `token`. A zero amount
+    /// removes the requirement; existing bonds are unaffected (admin only).
+    pub fn set_registration_bond(env: Env, admin: Address, token: Address, amount: i128, treasury: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+        if amount < 0 {
+            return Err(VerificationError::InvalidBond);
+        }
+        if amount == 0 {
+            env.storage().instance().remove(&DataKey::BondConfig);
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKey::BondConfig, &BondConfig { token, amount, treasury });
+        }
+        Ok(())
+    }
+
+    pub fn get_registration_bond(env: Env) -> Option<BondConfig> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::BondConfig)
+    }
+
+    pub fn get_bond(env: Env, publisher: Address) -> Option<PublisherBond> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::Bond(publisher))
+    }
+
+    /// Configure referral rewards (admin only)
+    pub fn set_referral_config(env: Env, admin: Address, config: ReferralConfig) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+        if config.reward <= 0 || config.earnings_threshold < 0 {
+            return Err(VerificationError::InvalidReferralConfig);
+        }
+        let current: Option<ReferralConfig> = env.storage().instance().get(&DataKey::ReferralConfig);
+        let pool: i128 = env.storage().instance().get(&DataKey::ReferralPool).unwrap_or(0);
+        if let Some(current) = current {
+            if current.token != config.token && pool > 0 {
+                return Err(VerificationError::PoolNotEmpty);
+            }
+        }
+        env.storage().instance().set(&DataKey::ReferralConfig, &config);
+        Ok(())
+    }
+
+    pub fn get_referral_config(env: Env) -> Option<ReferralConfig> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::ReferralConfig)
+    }
+
+    /// Top up the pool referral rewards are paid from
+    pub fn fund_referral_pool(env: Env, funder: Address, amount: i128) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        funder.require_auth();
+        if amount <= 0 {
+            return Err(VerificationError::InvalidAmount);
+        }
+        let config: ReferralConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::ReferralConfig)
+            .ok_or(VerificationError::ReferralsNotConfigured)?;
+
+        token::Client::new(&env, &config.token).transfer(&funder, &env.current_contract_address(), &amount);
+        let pool: i128 = env.storage().instance().get(&DataKey::ReferralPool).unwrap_or(0);
+        env.storage().instance().set(&DataKey::ReferralPool, &(pool + amount));
+        Ok(())
+    }
+
+    pub fn get_referral_pool(env: Env) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::ReferralPool).unwrap_or(0)
+    }
+
+    pub fn get_referrer(env: Env, publisher: Address) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::Referrer(publisher))
+    }
+
+    pub fn get_referral_rewards(env: Env, referrer: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReferralRewards(referrer))
+            .unwrap_or(0)
+    }
+
+    /// Pay out accrued referral rewards; returns the amount paid
+    pub fn claim_referral_rewards(env: Env, referrer: Address) -> Result<i128, VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        referrer.require_auth();
+
+        let amount: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReferralRewards(referrer.clone()))
+            .unwrap_or(0);
+        if amount == 0 {
+            return Err(VerificationError::NothingToClaim);
+        }
+        let config: ReferralConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::ReferralConfig)
+            .ok_or(VerificationError::ReferralsNotConfigured)?;
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReferralRewards(referrer.clone()));
+        token::Client::new(&env, &config.token).transfer(&env.current_contract_address(), &referrer, &amount);
+
+        events::publish(&env, EVENT_TAG, ReferralClaimed { referrer, amount });
+        Ok(amount)
+    }
+
+    /// Update publisher reputation score (admin only)
+    pub fn update_reputation(env: Env, admin: Address, publisher: Address, score: u32) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+
+        if score > 1000 {
+            return Err(VerificationError::InvalidScore);
+        }
+
+        let mut pub_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+
+        pub_data.reputation_score = score;
+        let new_tier = Self::_score_to_tier(score);
+        Self::_set_tier(&env, &mut pub_data, new_tier);
+
+        let _ttl_key = DataKey::Publisher(publisher);
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &pub_data);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Ok(())
+    }
+
+    /// Re-derive a publisher's tier from earnings, impressions and reputation
+    /// against the tier config. Permissionless; returns the resulting tier.
+    pub fn recalculate_tier(env: Env, publisher: Address) -> Result<PublisherTier, VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let mut pub_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+
+        let config = Self::_tier_config(&env);
+        let new_tier = if Self::_meets(&pub_data, &config.platinum) {
+            PublisherTier::Platinum
+        } else if Self::_meets(&pub_data, &config.gold) {
+            PublisherTier::Gold
+        } else if Self::_meets(&pub_data, &config.silver) {
+            PublisherTier::Silver
+        } else {
+            PublisherTier::Bronze
+        };
+
+        if Self::_set_tier(&env, &mut pub_data, new_tier.clone()) {
+            let _ttl_key = DataKey::Publisher(publisher);
+            env.storage()
+                .persistent()
+                .set(&_ttl_key, &pub_data);
+            env.storage()
+                .persistent()
+                .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+        Ok(new_tier)
+    }
+
+    /// Replace the tier thresholds used by `recalculate_tier` (admin only)
+    pub fn set_tier_config(env: Env, admin: Address, config: TierConfig) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+        if config.silver.min_earnings < 0 || config.silver.min_reputation > 1000 {
+            return Err(VerificationError::InvalidTierConfig);
+        }
+        if config.gold.min_earnings < config.silver.min_earnings
+            || config.gold.min_impressions < config.silver.min_impressions
+            || config.gold.min_reputation < config.silver.min_reputation
+            || config.platinum.min_earnings < config.gold.min_earnings
+            || config.platinum.min_impressions < config.gold.min_impressions
+            || config.platinum.min_reputation < config.gold.min_reputation
+        {
+            return Err(VerificationError::InvalidTierConfig);
+        }
+        env.storage().instance().set(&DataKey::TierConfig, &config);
+        Ok(())
+    }
+
+    pub fn get_tier_config(env: Env) -> TierConfig {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_tier_config(&env)
+    }
+
+    /// Set the fee, campaign and payout parameters for a tier (admin only)
+    pub fn set_tier_benefits(
+        env: Env,
+        admin: Address,
+        tier: PublisherTier,
+        benefits: TierBenefits,
+    ) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+        if benefits.platform_fee_bps > 10_000
+            || benefits.max_concurrent_campaigns == 0
+            || benefits.payout_frequency_secs == 0
+        {
+            return Err(VerificationError::InvalidTierBenefits);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TierBenefits(tier.clone()), &benefits);
+
+        events::publish(
+            &env,
+            EVENT_TAG,
+            TierBenefitsSet { tier, platform_fee_bps: benefits.platform_fee_bps, max_concurrent_campaigns: benefits.max_concurrent_campaigns, payout_frequency_secs: benefits.payout_frequency_secs },
+        );
+        Ok(())
+    }
+
+    pub fn get_tier_benefits(env: Env, tier: PublisherTier) -> TierBenefits {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_tier_benefits(&env, &tier)
+    }
+
+    /// Benefits of the publisher's current tier
+    pub fn get_publisher_benefits(env: Env, publisher: Address) -> Result<TierBenefits, VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let pub_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher))
+            .ok_or(VerificationError::PublisherNotFound)?;
+        Ok(Self::_tier_benefits(&env, &pub_data.tier))
+    }
+
+    /// Allow a contract (e.g. the campaign orchestrator) to record impressions (admin only)
+    pub fn authorize_caller(env: Env, admin: Address, caller: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::AuthorizedCaller(caller.clone()), &true);
+
+        events::publish(&env, EVENT_TAG, RoleGranted { role: symbol_short!("caller"), account: caller });
+        Ok(())
+    }
+
+    pub fn revoke_caller(env: Env, admin: Address, caller: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+        env.storage()
+            .instance()
+            .remove(&DataKey::AuthorizedCaller(caller.clone()));
+
+        events::publish(&env, EVENT_TAG, RoleRevoked { role: symbol_short!("caller"), account: caller });
+        Ok(())
+    }
+
+    pub fn is_authorized_caller(env: Env, caller: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .instance()
+            .has(&DataKey::AuthorizedCaller(caller))
+    }
+
+    /// Record impression (authorized orchestrator contracts only)
+    pub fn record_impression(env: Env, caller: Address, publisher: Address, earning: i128) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_authorized_caller(&env, &caller)?;
+        if earning < 0 {
+            return Err(VerificationError::InvalidEarning);
+        }
+
+        Self::_apply_impressions(&env, publisher, 1, earning)?;
+        Ok(())
+    }
+
+    /// Record `(publisher, impressions, earnings)` entries in one call.
+    /// Repeated publishers are summed and each publisher is written once.
+    pub fn record_impressions_batch(env: Env, caller: Address, entries: Vec<(Address, u64, i128)>) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_authorized_caller(&env, &caller)?;
+        if entries.is_empty() || entries.len() > MAX_IMPRESSION_BATCH {
+            return Err(VerificationError::InvalidBatchSize);
+        }
+
+        let mut totals: Map<Address, (u64, i128)> = Map::new(&env);
+        let mut total_impressions: u64 = 0;
+        let mut total_earnings: i128 = 0;
+        for (publisher, count, earnings) in entries.iter() {
+            if count == 0 || earnings < 0 {
+                return Err(VerificationError::InvalidEntry);
+            }
+            let (prev_count, prev_earnings) = totals.get(publisher.clone()).unwrap_or((0, 0));
+            totals.set(publisher, (prev_count + count, prev_earnings + earnings));
+            total_impressions += count;
+            total_earnings += earnings;
+        }
+
+        for (publisher, (count, earnings)) in totals.iter() {
+            Self::_apply_impressions(&env, publisher, count, earnings)?;
+        }
+
+        events::publish(
+            &env,
+            EVENT_TAG,
+            ImpressionsRecorded { caller, publishers: totals.len(), impressions: total_impressions, earnings: total_earnings },
+        );
+        Ok(())
+    }
+
+    // ============================================================
+    // Earnings
+    // ============================================================
+
+    /// Set the token earnings settle in and where withdrawal fees go. The
+    /// token can't change while any earnings are unwithdrawn (admin only).
+    pub fn set_earnings_config(env: Env, admin: Address, token: Address, fee_recipient: Address) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Ownable::require_owner(&env, &DataKey::Admin, &admin)?;
+        let current: Option<EarningsConfig> = env.storage().instance().get(&DataKey::EarningsConfig);
+        let outstanding: i128 = env.storage().instance().get(&DataKey::AccruedTotal).unwrap_or(0);
+        if let Some(current) = current {
+            if current.token != token && outstanding > 0 {
+                return Err(VerificationError::EarningsOutstanding);
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::EarningsConfig, &EarningsConfig { token, fee_recipient });
+        Ok(())
+    }
+
+    pub fn get_earnings_config(env: Env) -> Option<EarningsConfig> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::EarningsConfig)
+    }
+
+    /// Pay `amount` of the earnings token in from `caller` and credit it to
+    /// the publisher (authorized orchestrator contracts only)
+    pub fn settle_earnings(env: Env, caller: Address, publisher: Address, amount: i128) -> Result<(), VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_authorized_caller(&env, &caller)?;
+        if amount <= 0 {
+            return Err(VerificationError::InvalidAmount);
+        }
+        let config = Self::_earnings_config(&env)?;
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Publisher(publisher.clone()))
+        {
+            return Err(VerificationError::PublisherNotFound);
+        }
+
+        token::Client::new(&env, &config.token).transfer(&caller, &env.current_contract_address(), &amount);
+        Self::_credit_accrued(&env, &publisher, amount);
+
+        events::publish(&env, EVENT_TAG, EarningsSettled { caller, publisher, amount });
+        Ok(())
+    }
+
+    /// Withdraw `amount` of settled earnings to the publisher's payout
+    /// address, less the platform fee of their current tier. Returns the
+    /// amount paid out.
+    pub fn withdraw_earnings(env: Env, publisher: Address, amount: i128) -> Result<i128, VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+        if amount <= 0 {
+            return Err(VerificationError::InvalidAmount);
+        }
+        let config = Self::_earnings_config(&env)?;
+        let pub_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+
+        let accrued = Self::_accrued(&env, &publisher);
+        if amount > accrued {
+            return Err(VerificationError::InsufficientEarnings);
+        }
+        Self::_credit_accrued(&env, &publisher, -amount);
+
+        let fee = amount * Self::_tier_benefits(&env, &pub_data.tier).platform_fee_bps as i128 / 10_000;
+        let net = amount - fee;
+        let payout = Self::_payout_address(&env, &publisher);
+        let token_client = token::Client::new(&env, &config.token);
+        token_client.transfer(&env.current_contract_address(), &payout, &net);
+        if fee > 0 {
+            token_client.transfer(&env.current_contract_address(), &config.fee_recipient, &fee);
+        }
+
+        events::publish(&env, EVENT_TAG, EarningsWithdrawn { publisher, payout, amount: net, fee });
+        Ok(net)
+    }
+
+    /// Settled earnings the publisher hasn't withdrawn yet
+    pub fn get_accrued_earnings(env: Env, publisher: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_accrued(&env, &publisher)
+    }
+
+    // ============================================================
+    // Read-Only Functions
+    // ============================================================
+
+    pub fn get_publisher(env: Env, publisher: Address) -> Option<Publisher> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Persistent::get(&env, &DataKey::Publisher(publisher), PUBLISHER_TTL)
+    }
+
+    pub fn get_kyc(env: Env, publisher: Address) -> Option<KycRecord> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_latest_kyc(&env, &publisher)
+    }
+
+    /// Past and current KYC submissions, oldest first, for compliance audits
+    pub fn get_kyc_history(env: Env, publisher: Address, offset: u32, limit: u32) -> Vec<KycRecord> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let latest: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::KycVersion(publisher.clone()))
+            .unwrap_or(0);
+
+        let mut result = Vec::new(&env);
+        let mut version = offset + 1;
+        while version <= latest && result.len() < limit {
+            if let Some(kyc) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::KycRecord(publisher.clone(), version))
+            {
+                result.push_back(kyc);
+            }
+            version += 1;
+        }
+        result
+    }
+
+    /// True if the publisher holds a verified KYC record that has not expired
+    pub fn is_kyc_current(env: Env, publisher: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        match Self::_latest_kyc(&env, &publisher) {
+            Some(kyc) => kyc.verified && kyc.expires_at.is_some_and(|at| env.ledger().timestamp() < at),
+            None => false,
+        }
+    }
+
+    /// Verified status, treating a lapsed KYC as unverified even before
+    /// `expire_kyc` has been called
+    pub fn is_verified(env: Env, publisher: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if let Some(mut pub_data) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Publisher>(&DataKey::Publisher(publisher.clone()))
+        {
+            Self::_lift_expired_suspension(&env, &mut pub_data);
+            matches!(pub_data.status, VerificationStatus::Verified) && !Self::_kyc_lapsed(&env, &publisher)
+        } else {
+            false
+        }
+    }
+
+    pub fn get_profile(env: Env, publisher: Address) -> Option<PublisherProfile> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::Profile(publisher))
+    }
+
+    /// Where to send a publisher's earnings: the profile payout address,
+    /// falling back to the owner key
+    pub fn get_payout_address(env: Env, publisher: Address) -> Result<Address, VerificationError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Publisher(publisher.clone()))
+        {
+            return Err(VerificationError::PublisherNotFound);
+        }
+        Ok(Self::_payout_address(&env, &publisher))
+    }
+
+    pub fn list_domains(env: Env, publisher: Address) -> Vec<String> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get::<DataKey, Publisher>(&DataKey::Publisher(publisher))
+            .map(|pub_data| pub_data.domains)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_domain_challenge(env: Env, publisher: Address, domain: String) -> Option<DomainChallenge> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::DomainChallenge(publisher, domain))
+    }
+
+    pub fn get_domain_proof(env: Env, domain: String) -> Option<BytesN<32>> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::DomainProof(domain))
+    }
+
+    pub fn get_domain_oracles(env: Env) -> Vec<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .instance()
+            .get(&DataKey::DomainOracles)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_domain_owner(env: Env, domain: String) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::DomainOwner(domain))
+    }
+
+    /// Page through publishers in registration order
+    pub fn list_publishers(env: Env, offset: u64, limit: u32) -> Vec<Publisher> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let count: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PublisherCount)
+            .unwrap_or(0);
+
+        let mut result = Vec::new(&env);
+        let mut id = offset;
+        while id < count && result.len() < limit {
+            if let Some(pub_data) = Self::_publisher_at(&env, id) {
+                result.push_back(pub_data);
+            }
+            id += 1;
+        }
+        result
+    }
+
+    /// Page through publishers with `status`, in registration order.
+    /// `offset` counts matching publishers only.
+    pub fn list_by_status(env: Env, status: VerificationStatus, offset: u64, limit: u32) -> Vec<Publisher> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let count: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PublisherCount)
+            .unwrap_or(0);
+
+        let mut result = Vec::new(&env);
+        let mut skipped: u64 = 0;
+        let mut id: u64 = 0;
+        while id < count && result.len() < limit {
+            if let Some(pub_data) = Self::_publisher_at(&env, id) {
+                if pub_data.status == status {
+                    if skipped < offset {
+                        skipped += 1;
+                    } else {
+                        result.push_back(pub_data);
+                    }
+                }
+            }
+            id += 1;
+        }
+        result
+    }
+
+    pub fn get_publisher_count(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .instance()
+            .get(&DataKey::PublisherCount)
+            .unwrap_or(0)
+    }
+
+    // ============================================================
+    // TTL Maintenance
+    // ============================================================
+
+    /// Keep a publisher's record, profile, latest KYC and bond from being
+    /// archived. Anyone may call; returns false for an unknown publisher.
+    pub fn extend_ttl_publisher(env: Env, publisher: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if let Some(version) = Persistent::get::<DataKey, u32>(&env, &DataKey::KycVersion(publisher.clone()), PUBLISHER_TTL) {
+            Persistent::extend(&env, &DataKey::KycRecord(publisher.clone(), version), PUBLISHER_TTL);
+        }
+        Persistent::extend(&env, &DataKey::Profile(publisher.clone()), PUBLISHER_TTL);
+        Persistent::extend(&env, &DataKey::Bond(publisher.clone()), PUBLISHER_TTL);
+        Persistent::extend(&env, &DataKey::Accrued(publisher.clone()), PUBLISHER_TTL);
+        Persistent::extend(&env, &DataKey::Publisher(publisher), PUBLISHER_TTL)
+    }
+
+    // ============================================================
+    // Internal Helpers
+    // ============================================================
+
+    fn _require_authorized_caller(env: &Env, caller: &Address) -> Result<(), VerificationError> {
+        caller.require_auth();
+        if !env
+            .storage()
+            .instance()
+            .has(&DataKey::AuthorizedCaller(caller.clone()))
+        {
+            return Err(VerificationError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn _payout_address(env: &Env, publisher: &Address) -> Address {
+        env.storage()
+            .persistent()
+            .get::<DataKey, PublisherProfile>(&DataKey::Profile(publisher.clone()))
+            .map(|profile| profile.payout_address)
+            .unwrap_or(publisher.clone())
+    }
+
+    fn _earnings_config(env: &Env) -> Result<EarningsConfig, VerificationError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::EarningsConfig)
+            .ok_or(VerificationError::EarningsNotConfigured)
+    }
+
+    fn _accrued(env: &Env, publisher: &Address) -> i128 {
+        Persistent::get(env, &DataKey::Accrued(publisher.clone()), PUBLISHER_TTL).unwrap_or(0)
+    }
+
+    /// Add `delta` (negative on withdrawal) to the publisher's accrued
+    /// balance and the running total
+    fn _credit_accrued(env: &Env, publisher: &Address, delta: i128) {
+        let key = DataKey::Accrued(publisher.clone());
+        let balance = Self::_accrued(env, publisher) + delta;
+        if balance == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            Persistent::set(env, &key, &balance, PUBLISHER_TTL);
+        }
+        let total: i128 = env.storage().instance().get(&DataKey::AccruedTotal).unwrap_or(0);
+        env.storage().instance().set(&DataKey::AccruedTotal, &(total + delta));
+    }
+
+    /// Add impressions and earnings to a verified publisher's totals
+    fn _apply_impressions(env: &Env, publisher: Address, count: u64, earnings: i128) -> Result<(), VerificationError> {
+        let mut pub_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+
+        let previous_status = pub_data.status.clone();
+        Self::_lift_expired_suspension(env, &mut pub_data);
+        match pub_data.status {
+            VerificationStatus::Verified => {}
+            _ => return Err(VerificationError::NotVerified),
+        }
+
+        pub_data.total_earnings += earnings;
+        pub_data.total_impressions += count;
+        pub_data.last_active = env.ledger().timestamp();
+
+        let _ttl_key = DataKey::Publisher(publisher.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &pub_data);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_notify_status(env, &publisher, previous_status, pub_data.status.clone());
+
+        Self::_accrue_referral(env, &publisher, pub_data.total_earnings);
+        Ok(())
+    }
+
+    /// Credit the referrer once the referred publisher crosses the earnings
+    /// threshold. Skipped, and retried on later impressions, while the pool is short.
+    fn _accrue_referral(env: &Env, publisher: &Address, total_earnings: i128) {
+        let config: Option<ReferralConfig> = env.storage().instance().get(&DataKey::ReferralConfig);
+        let config = match config {
+            Some(config) if total_earnings >= config.earnings_threshold => config,
+            _ => return,
+        };
+        let referrer: Address = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::Referrer(publisher.clone()))
+        {
+            Some(referrer) => referrer,
+            None => return,
+        };
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::ReferralRewarded(publisher.clone()))
+        {
+            return;
+        }
+        let pool: i128 = env.storage().instance().get(&DataKey::ReferralPool).unwrap_or(0);
+        if pool < config.reward {
+            return;
+        }
+
+        env.storage().instance().set(&DataKey::ReferralPool, &(pool - config.reward));
+        let _ttl_key = DataKey::ReferralRewarded(publisher.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        let accrued: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReferralRewards(referrer.clone()))
+            .unwrap_or(0);
+        let _ttl_key = DataKey::ReferralRewards(referrer.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &(accrued + config.reward));
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(
+            env,
+            EVENT_TAG,
+            ReferralAccrued { referrer, publisher: publisher.clone(), reward: config.reward },
+        );
+    }
+
+    /// Pull the configured registration bond from `publisher`, if any
+    fn _lock_bond(env: &Env, publisher: &Address) {
+        let config: Option<BondConfig> = env.storage().instance().get(&DataKey::BondConfig);
+        if let Some(config) = config {
+            token::Client::new(env, &config.token).transfer(
+                publisher,
+                &env.current_contract_address(),
+                &config.amount,
+            );
+            let bond = PublisherBond {
+                token: config.token,
+                amount: config.amount,
+                treasury: config.treasury,
+                locked_at: env.ledger().timestamp(),
+            };
+            let _ttl_key = DataKey::Bond(publisher.clone());
+            env.storage()
+                .persistent()
+                .set(&_ttl_key, &bond);
+            env.storage()
+                .persistent()
+                .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+    }
+
+    fn _publisher_at(env: &Env, id: u64) -> Option<Publisher> {
+        let publisher: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PublisherIndex(id))?;
+        env.storage()
+            .persistent()
+            .get(&DataKey::Publisher(publisher))
+    }
+
+    fn _suspension_active(env: &Env, pub_data: &Publisher) -> bool {
+        pub_data.status == VerificationStatus::Suspended
+            && pub_data
+                .suspended_until
+                .is_none_or(|until| env.ledger().timestamp() < until)
+    }
+
+    fn _status_after_suspension(pub_data: &Publisher) -> VerificationStatus {
+        if pub_data.verified_at.is_some() {
+            VerificationStatus::Verified
+        } else {
+            VerificationStatus::Pending
+        }
+    }
+
+    /// Restore the pre-suspension status once a timed suspension has run out
+    fn _lift_expired_suspension(env: &Env, pub_data: &mut Publisher) {
+        if pub_data.status == VerificationStatus::Suspended && !Self::_suspension_active(env, pub_data) {
+            pub_data.status = Self::_status_after_suspension(pub_data);
+            pub_data.suspended_until = None;
+        }
+    }
+
+    /// Emit a status-change event and push the transition to every subscriber.
+    /// A failing subscriber is skipped so it cannot block moderation here.
+    fn _notify_status(
+        env: &Env,
+        publisher: &Address,
+        old: VerificationStatus,
+        new: VerificationStatus,
+    ) {
+        if old == new {
+            return;
+        }
+
+        events::publish(
+            env,
+            EVENT_TAG,
+            StatusChanged { publisher: publisher.clone(), old: old.clone(), new: new.clone() },
+        );
+
+        let subscribers: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::StatusSubscribers)
+            .unwrap_or(Vec::new(env));
+        for subscriber in subscribers.iter() {
+            let args: Vec<Val> = Vec::from_array(
+                env,
+                [
+                    publisher.into_val(env),
+                    old.clone().into_val(env),
+                    new.clone().into_val(env),
+                ],
+            );
+            let _ = env.try_invoke_contract::<(), soroban_sdk::Error>(
+                &subscriber,
+                &Symbol::new(env, "on_publisher_status"),
+                args,
+            );
+        }
+    }
+
+    /// The admin or a registered verifier
+    fn _is_verifier(env: &Env, caller: &Address) -> bool {
+        Ownable::is_owner(env, &DataKey::Admin, caller) || RoleBased::has_role(env, &DataKey::Verifiers, caller)
+    }
+
+    fn _require_verifier(env: &Env, caller: &Address) -> Result<(), VerificationError> {
+        caller.require_auth();
+        if !Self::_is_verifier(env, caller) {
+            return Err(VerificationError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn _kyc_validity(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::KycValidity)
+            .unwrap_or(DEFAULT_KYC_VALIDITY)
+    }
+
+    fn _latest_kyc(env: &Env, publisher: &Address) -> Option<KycRecord> {
+        let version: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::KycVersion(publisher.clone()))?;
+        env.storage()
+            .persistent()
+            .get(&DataKey::KycRecord(publisher.clone(), version))
+    }
+
+    fn _save_kyc(env: &Env, kyc: &KycRecord) {
+        let _ttl_key = DataKey::KycRecord(kyc.publisher.clone(), kyc.version);
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, kyc);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    /// A verified KYC record whose expiry has passed
+    fn _kyc_lapsed(env: &Env, publisher: &Address) -> bool {
+        match Self::_latest_kyc(env, publisher) {
+            Some(kyc) => kyc.verified && kyc.expires_at.is_some_and(|at| env.ledger().timestamp() >= at),
+            None => false,
+        }
+    }
+
+    /// Record a fresh ownership challenge for `domain` and return its nonce
+    fn _open_challenge(env: &Env, pub_data: &Publisher, domain: String) -> Result<u64, VerificationError> {
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::DomainOwner(domain.clone()))
+        {
+            return Err(VerificationError::DomainAlreadyRegistered);
+        }
+        if pub_data.domains.len() >= Self::_domain_cap(&pub_data.tier) {
+            return Err(VerificationError::DomainLimitReached);
+        }
+
+        let challenge = DomainChallenge {
+            publisher: pub_data.owner.clone(),
+            domain: domain.clone(),
+            nonce: env.prng().gen(),
+            requested_at: env.ledger().timestamp(),
+        };
+        let _ttl_key = DataKey::DomainChallenge(pub_data.owner.clone(), domain.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &challenge);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(
+            env,
+            EVENT_TAG,
+            DomainRequested { publisher: pub_data.owner.clone(), domain, nonce: challenge.nonce },
+        );
+        Ok(challenge.nonce)
+    }
+
+    /// Move `domain` from one publisher's set to another's and repoint `DomainOwner`
+    fn _move_domain(env: &Env, from: &Address, to: &Address, domain: String) -> Result<(), VerificationError> {
+        if from == to {
+            return Err(VerificationError::SameOwner);
+        }
+        let owner: Option<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DomainOwner(domain.clone()));
+        if owner.as_ref() != Some(from) {
+            return Err(VerificationError::DomainNotOwned);
+        }
+
+        let mut from_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(from.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+        let mut to_data: Publisher = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Publisher(to.clone()))
+            .ok_or(VerificationError::PublisherNotFound)?;
+        if to_data.domains.len() >= Self::_domain_cap(&to_data.tier) {
+            return Err(VerificationError::DomainLimitReached);
+        }
+
+        let idx = from_data
+            .domains
+            .first_index_of(&domain)
+            .ok_or(VerificationError::DomainNotOwned)?;
+        from_data.domains.remove(idx);
+        to_data.domains.push_back(domain.clone());
+
+        let _ttl_key = DataKey::Publisher(from.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &from_data);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        let _ttl_key = DataKey::Publisher(to.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &to_data);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        let _ttl_key = DataKey::DomainOwner(domain.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, to);
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        let _ttl_key = DataKey::DomainTransferredAt(domain.clone());
+        env.storage()
+            .persistent()
+            .set(&_ttl_key, &env.ledger().timestamp());
+        env.storage()
+            .persistent()
+            .extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(env, EVENT_TAG, DomainTransferred { from: from.clone(), to: to.clone(), domain });
+        Ok(())
+    }
+
+    fn _tier_config(env: &Env) -> TierConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::TierConfig)
+            .unwrap_or(TierConfig {
+                silver: TierThreshold { min_earnings: 1_000_000_000, min_impressions: 10_000, min_reputation: 200 },
+                gold: TierThreshold { min_earnings: 10_000_000_000, min_impressions: 100_000, min_reputation: 500 },
+                platinum: TierThreshold {
+                    min_earnings: 100_000_000_000,
+                    min_impressions: 1_000_000,
+                    min_reputation: 800,
+                },
+            })
+    }
+
+    fn _tier_benefits(env: &Env, tier: &PublisherTier) -> TierBenefits {
+        env.storage()
+            .instance()
+            .get(&DataKey::TierBenefits(tier.clone()))
+            .unwrap_or(match tier {
+                PublisherTier::Bronze => TierBenefits {
+                    platform_fee_bps: 500,
+                    max_concurrent_campaigns: 5,
+                    payout_frequency_secs: 2_592_000,
+                },
+                PublisherTier::Silver => TierBenefits {
+                    platform_fee_bps: 400,
+                    max_concurrent_campaigns: 10,
+                    payout_frequency_secs: 1_209_600,
+                },
+                PublisherTier::Gold => TierBenefits {
+                    platform_fee_bps: 300,
+                    max_concurrent_campaigns: 25,
+                    payout_frequency_secs: 604_800,
+                },
+                PublisherTier::Platinum => TierBenefits {
+                    platform_fee_bps: 200,
+                    max_concurrent_campaigns: 50,
+                    payout_frequency_secs: 86_400,
+                },
+            })
+    }
+
+    fn _meets(pub_data: &Publisher, threshold: &TierThreshold) -> bool {
+        pub_data.total_earnings >= threshold.min_earnings
+            && pub_data.total_impressions >= threshold.min_impressions
+            && pub_data.reputation_score >= threshold.min_reputation
+    }
+
+    /// Update the tier in place, emitting an event if it changed
+    fn _set_tier(env: &Env, pub_data: &mut Publisher, tier: PublisherTier) -> bool {
+        if pub_data.tier == tier {
+            return false;
+        }
+        events::publish(
+            env,
+            EVENT_TAG,
+            TierChanged { publisher: pub_data.owner.clone(), old: pub_data.tier.clone(), new: tier.clone() },
+        );
+        pub_data.tier = tier;
+        true
+    }
+
+    /// Maximum number of domains a publisher of `tier` may hold
+    fn _domain_cap(tier: &PublisherTier) -> u32 {
+        match tier {
+            PublisherTier::Bronze => 1,
+            PublisherTier::Silver => 3,
+            PublisherTier::Gold => 5,
+            PublisherTier::Platinum => 10,
+        }
+    }
 
     fn _score_to_tier(score: u32) -> PublisherTier {
         if score >= 800 {
@@ -1,6 +1,10 @@
 #![cfg(test)]
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, vec, String};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    token, Address, BytesN, Env, String,
+};
 
 #[test]
 fn test_initialize() {
@@ -16,7 +20,7 @@ fn test_initialize() {
 }
 
 #[test]
-#[should_panic(expected = "already initialized")]
+#[should_panic(expected = "Error(Contract, #401)")]
 fn test_initialize_twice() {
     let env = Env::default();
     env.mock_all_auths();
@@ -43,3 +47,964 @@ fn test_initialize_non_admin_fails() {
     // This should panic because admin didn't authorize it and we haven't mocked it
     client.initialize(&admin);
 }
+
+#[contract]
+pub struct MockReputation;
+
+#[contractimpl]
+impl MockReputation {
+    pub fn init_publisher(env: Env, caller: Address, publisher: Address) {
+        caller.require_auth();
+        if env.storage().persistent().has(&publisher) {
+            panic!("already initialized");
+        }
+        env.storage().persistent().set(&publisher, &500u32);
+    }
+
+    pub fn get_reputation(env: Env, publisher: Address) -> Option<u32> {
+        env.storage().persistent().get(&publisher)
+    }
+}
+
+#[test]
+fn test_verify_initializes_reputation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+    let reputation_id = env.register_contract(None, MockReputation);
+    let reputation = MockReputationClient::new(&env, &reputation_id);
+
+    let admin = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_reputation_contract(&admin, &reputation_id);
+
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Bronze);
+    assert_eq!(reputation.get_reputation(&publisher), Some(500));
+
+    // Re-verifying does not try to initialize again
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Silver);
+    assert!(client.is_verified(&publisher));
+}
+
+#[test]
+fn test_kyc_expiry_and_reverification() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    let provider = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_kyc_validity(&admin, &1_000);
+    client.add_kyc_provider(&admin, &provider, &String::from_str(&env, "provider"));
+
+    let doc_hash = String::from_str(&env, "doc-hash");
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+    client.submit_kyc(&publisher, &doc_hash, &provider);
+    client.attest_kyc(&provider, &publisher, &doc_hash);
+    assert!(!client.is_kyc_current(&publisher));
+
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Bronze);
+    assert!(client.is_kyc_current(&publisher));
+    assert_eq!(client.get_kyc(&publisher).unwrap().expires_at, Some(env.ledger().timestamp() + 1_000));
+    assert!(client.try_expire_kyc(&publisher).is_err());
+
+    // Lapsed KYC stops counting as verified before anyone sweeps it
+    env.ledger().with_mut(|li| li.timestamp += 1_000);
+    assert!(!client.is_kyc_current(&publisher));
+    assert!(!client.is_verified(&publisher));
+
+    client.expire_kyc(&publisher);
+    assert!(client.get_publisher(&publisher).unwrap().status == VerificationStatus::Pending);
+    assert!(!client.get_kyc(&publisher).unwrap().verified);
+    assert!(client.try_expire_kyc(&publisher).is_err());
+
+    let doc_hash = String::from_str(&env, "doc-hash-2");
+    client.submit_kyc(&publisher, &doc_hash, &provider);
+    client.attest_kyc(&provider, &publisher, &doc_hash);
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Bronze);
+    assert!(client.is_kyc_current(&publisher));
+    assert!(client.is_verified(&publisher));
+}
+
+#[test]
+fn test_multi_domain_registration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    let other = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.initialize(&admin);
+
+    let main = String::from_str(&env, "news.example");
+    let blog = String::from_str(&env, "blog.example");
+    let shop = String::from_str(&env, "shop.example");
+    client.register_publisher(&publisher, &main, &None);
+    client.register_publisher(&other, &shop, &None);
+    client.confirm_domain(&admin, &publisher, &main, &proof);
+    client.confirm_domain(&admin, &other, &shop, &proof);
+
+    // Bronze publishers hold a single domain
+    assert!(client.try_request_domain(&publisher, &blog).is_err());
+
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Silver);
+    client.request_domain(&publisher, &blog);
+    client.confirm_domain(&admin, &publisher, &blog, &proof);
+    assert!(client.try_request_domain(&publisher, &shop).is_err());
+    assert_eq!(client.list_domains(&publisher), soroban_sdk::vec![&env, main.clone(), blog.clone()]);
+    assert_eq!(client.get_domain_owner(&blog), Some(publisher.clone()));
+
+    client.remove_domain(&publisher, &main);
+    assert_eq!(client.get_domain_owner(&main), None);
+    assert!(client.try_remove_domain(&publisher, &shop).is_err());
+
+    // A released domain can be claimed by someone else
+    client.verify_publisher(&admin, &other, &PublisherTier::Silver);
+    client.request_domain(&other, &main);
+    client.confirm_domain(&admin, &other, &main, &proof);
+    assert_eq!(client.list_domains(&publisher), soroban_sdk::vec![&env, blog]);
+}
+
+#[test]
+fn test_domain_challenge_flow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    let squatter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[7u8; 32]);
+    client.initialize(&admin);
+    client.add_domain_oracle(&admin, &oracle);
+
+    let domain = String::from_str(&env, "news.example");
+    let nonce = client.register_publisher(&publisher, &domain, &None);
+    assert_eq!(client.get_domain_challenge(&publisher, &domain).unwrap().nonce, nonce);
+
+    // Registering does not bind the domain, so others may also challenge it
+    assert_eq!(client.get_domain_owner(&domain), None);
+    assert!(client.list_domains(&publisher).is_empty());
+    client.register_publisher(&squatter, &domain, &None);
+
+    assert!(client.try_confirm_domain(&squatter, &publisher, &domain, &proof).is_err());
+    client.confirm_domain(&oracle, &publisher, &domain, &proof);
+    assert_eq!(client.get_domain_owner(&domain), Some(publisher.clone()));
+    assert_eq!(client.get_domain_proof(&domain), Some(proof.clone()));
+    assert!(client.get_domain_challenge(&publisher, &domain).is_none());
+
+    // The losing challenge can no longer be confirmed
+    assert!(client.try_confirm_domain(&oracle, &squatter, &domain, &proof).is_err());
+    assert!(client.try_confirm_domain(&oracle, &publisher, &domain, &proof).is_err());
+
+    client.remove_domain_oracle(&admin, &oracle);
+    assert!(client.get_domain_oracles().is_empty());
+}
+
+#[test]
+fn test_domain_transfer_with_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[3u8; 32]);
+    client.initialize(&admin);
+
+    let domain = String::from_str(&env, "news.example");
+    client.register_publisher(&seller, &domain, &None);
+    client.confirm_domain(&admin, &seller, &domain, &proof);
+    client.register_publisher(&buyer, &String::from_str(&env, "buyer.example"), &None);
+
+    assert!(client.try_transfer_domain(&buyer, &seller, &domain).is_err());
+    client.transfer_domain(&seller, &buyer, &domain);
+    assert_eq!(client.get_domain_owner(&domain), Some(buyer.clone()));
+    assert!(client.list_domains(&seller).is_empty());
+    assert_eq!(client.list_domains(&buyer), soroban_sdk::vec![&env, domain.clone()]);
+
+    // Flipping the domain straight back is blocked by the cooldown
+    assert!(client.try_transfer_domain(&buyer, &seller, &domain).is_err());
+
+    // Admin can settle disputes regardless of the cooldown
+    client.force_transfer_domain(&admin, &domain, &seller);
+    assert_eq!(client.get_domain_owner(&domain), Some(seller.clone()));
+
+    env.ledger().with_mut(|li| li.timestamp += DOMAIN_TRANSFER_COOLDOWN);
+    client.transfer_domain(&seller, &buyer, &domain);
+    assert_eq!(client.get_domain_owner(&domain), Some(buyer));
+}
+
+#[test]
+fn test_verifier_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let compliance = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    let applicant = Address::generate(&env);
+    let provider = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_kyc_provider(&admin, &provider, &String::from_str(&env, "provider"));
+
+    let doc_hash = String::from_str(&env, "doc-hash");
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+    client.register_publisher(&applicant, &String::from_str(&env, "spam.example"), &None);
+    client.submit_kyc(&publisher, &doc_hash, &provider);
+    client.attest_kyc(&provider, &publisher, &doc_hash);
+    assert!(client.try_verify_publisher(&compliance, &publisher, &PublisherTier::Bronze).is_err());
+
+    client.add_verifier(&admin, &compliance);
+    assert!(client.try_add_verifier(&admin, &compliance).is_err());
+    client.verify_publisher(&compliance, &publisher, &PublisherTier::Bronze);
+    assert_eq!(client.get_publisher(&publisher).unwrap().reviewed_by, Some(compliance.clone()));
+    assert_eq!(client.get_kyc(&publisher).unwrap().reviewed_by, Some(compliance.clone()));
+
+    client.reject_publisher(&compliance, &applicant, &RejectionReason::InvalidDocuments);
+    assert!(client.get_publisher(&applicant).unwrap().status == VerificationStatus::Rejected);
+
+    client.suspend_publisher(&compliance, &publisher, &0);
+    assert!(!client.is_verified(&publisher));
+
+    client.remove_verifier(&admin, &compliance);
+    assert!(client.get_verifiers().is_empty());
+    assert!(client.try_verify_publisher(&compliance, &publisher, &PublisherTier::Bronze).is_err());
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Bronze);
+    assert_eq!(client.get_publisher(&publisher).unwrap().reviewed_by, Some(admin));
+}
+
+#[test]
+fn test_kyc_provider_attestation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+    let rogue = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin);
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+
+    let doc_hash = String::from_str(&env, "doc-hash");
+    assert!(client.try_submit_kyc(&publisher, &doc_hash, &provider).is_err());
+
+    client.add_kyc_provider(&admin, &provider, &String::from_str(&env, "Acme KYC"));
+    assert_eq!(client.get_kyc_provider(&provider), Some(String::from_str(&env, "Acme KYC")));
+    client.submit_kyc(&publisher, &doc_hash, &provider);
+
+    // Verification waits for the provider's sign-off
+    assert!(client.try_verify_publisher(&admin, &publisher, &PublisherTier::Bronze).is_err());
+
+    client.add_kyc_provider(&admin, &rogue, &String::from_str(&env, "Rogue KYC"));
+    assert!(client.try_attest_kyc(&rogue, &publisher, &doc_hash).is_err());
+    assert!(client.try_attest_kyc(&provider, &publisher, &String::from_str(&env, "other-hash")).is_err());
+
+    client.attest_kyc(&provider, &publisher, &doc_hash);
+    assert!(client.get_kyc(&publisher).unwrap().attested_at.is_some());
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Bronze);
+    assert!(client.is_kyc_current(&publisher));
+
+    client.remove_kyc_provider(&admin, &rogue);
+    assert_eq!(client.get_kyc_provider(&rogue), None);
+}
+
+#[test]
+fn test_record_impression_requires_authorized_caller() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin);
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Bronze);
+
+    assert!(client.try_record_impression(&orchestrator, &publisher, &100).is_err());
+
+    client.authorize_caller(&admin, &orchestrator);
+    assert!(client.is_authorized_caller(&orchestrator));
+    client.record_impression(&orchestrator, &publisher, &100);
+    assert!(client.try_record_impression(&orchestrator, &publisher, &-1).is_err());
+    let pub_data = client.get_publisher(&publisher).unwrap();
+    assert_eq!((pub_data.total_impressions, pub_data.total_earnings), (1, 100));
+
+    client.revoke_caller(&admin, &orchestrator);
+    assert!(client.try_record_impression(&orchestrator, &publisher, &100).is_err());
+}
+
+#[test]
+fn test_record_impressions_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let pending = Address::generate(&env);
+    client.initialize(&admin);
+    client.authorize_caller(&admin, &orchestrator);
+    client.register_publisher(&first, &String::from_str(&env, "a.example"), &None);
+    client.register_publisher(&second, &String::from_str(&env, "b.example"), &None);
+    client.register_publisher(&pending, &String::from_str(&env, "c.example"), &None);
+    client.verify_publisher(&admin, &first, &PublisherTier::Bronze);
+    client.verify_publisher(&admin, &second, &PublisherTier::Bronze);
+
+    client.record_impressions_batch(
+        &orchestrator,
+        &soroban_sdk::vec![
+            &env,
+            (first.clone(), 10u64, 1_000i128),
+            (second.clone(), 5u64, 400i128),
+            (first.clone(), 2u64, 300i128),
+        ],
+    );
+    let first_data = client.get_publisher(&first).unwrap();
+    assert_eq!((first_data.total_impressions, first_data.total_earnings), (12, 1_300));
+    let second_data = client.get_publisher(&second).unwrap();
+    assert_eq!((second_data.total_impressions, second_data.total_earnings), (5, 400));
+
+    // One unverified publisher fails the whole batch
+    assert!(client
+        .try_record_impressions_batch(
+            &orchestrator,
+            &soroban_sdk::vec![&env, (first.clone(), 1u64, 10i128), (pending, 1u64, 10i128)],
+        )
+        .is_err());
+    assert_eq!(client.get_publisher(&first).unwrap().total_impressions, 12);
+
+    assert!(client
+        .try_record_impressions_batch(&orchestrator, &soroban_sdk::vec![&env, (first, 0u64, 10i128)])
+        .is_err());
+}
+
+#[test]
+fn test_recalculate_tier_from_activity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin);
+    client.authorize_caller(&admin, &orchestrator);
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Bronze);
+
+    let threshold = |earnings: i128, impressions: u64, reputation: u32| TierThreshold {
+        min_earnings: earnings,
+        min_impressions: impressions,
+        min_reputation: reputation,
+    };
+    let config = TierConfig {
+        silver: threshold(100, 10, 100),
+        gold: threshold(1_000, 100, 100),
+        platinum: threshold(10_000, 1_000, 800),
+    };
+    client.set_tier_config(&admin, &config);
+
+    // Verification starts reputation at 100, but there is no activity yet
+    assert!(client.recalculate_tier(&publisher) == PublisherTier::Bronze);
+
+    client.record_impressions_batch(&orchestrator, &soroban_sdk::vec![&env, (publisher.clone(), 150u64, 5_000i128)]);
+    assert!(client.recalculate_tier(&publisher) == PublisherTier::Gold);
+    assert!(client.get_publisher(&publisher).unwrap().tier == PublisherTier::Gold);
+
+    // Reputation is one of the gates for the top tier
+    client.record_impressions_batch(&orchestrator, &soroban_sdk::vec![&env, (publisher.clone(), 1_000u64, 10_000i128)]);
+    assert!(client.recalculate_tier(&publisher) == PublisherTier::Gold);
+
+    let bad = TierConfig {
+        silver: threshold(100, 10, 100),
+        gold: threshold(50, 100, 100),
+        platinum: threshold(10_000, 1_000, 800),
+    };
+    assert!(client.try_set_tier_config(&admin, &bad).is_err());
+}
+
+#[test]
+fn test_timed_suspension_and_reinstatement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    let appeal = BytesN::from_array(&env, &[5u8; 32]);
+    client.initialize(&admin);
+    client.authorize_caller(&admin, &orchestrator);
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Bronze);
+
+    client.suspend_publisher(&admin, &publisher, &3_600);
+    assert!(client.is_suspended(&publisher));
+    assert!(!client.is_verified(&publisher));
+    assert!(client.try_record_impression(&orchestrator, &publisher, &10).is_err());
+
+    // Timed suspensions lapse without anyone acting
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    assert!(!client.is_suspended(&publisher));
+    assert!(client.is_verified(&publisher));
+    client.record_impression(&orchestrator, &publisher, &10);
+    assert!(client.get_publisher(&publisher).unwrap().status == VerificationStatus::Verified);
+
+    // Indefinite suspensions need a verifier to reinstate
+    assert!(client.try_request_reinstatement(&publisher, &appeal).is_err());
+    client.suspend_publisher(&admin, &publisher, &0);
+    env.ledger().with_mut(|li| li.timestamp += 1_000_000);
+    assert!(client.is_suspended(&publisher));
+
+    client.request_reinstatement(&publisher, &appeal);
+    assert_eq!(client.get_reinstatement_request(&publisher).unwrap().appeal_hash, appeal);
+    client.reinstate_publisher(&admin, &publisher);
+    assert!(client.is_verified(&publisher));
+    assert!(client.get_reinstatement_request(&publisher).is_none());
+    assert!(client.try_reinstate_publisher(&admin, &publisher).is_err());
+}
+
+#[test]
+fn test_deactivate_and_reactivate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    let provider = Address::generate(&env);
+    let newcomer = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_kyc_validity(&admin, &5_000_000);
+    client.add_kyc_provider(&admin, &provider, &String::from_str(&env, "provider"));
+
+    let domain = String::from_str(&env, "news.example");
+    let doc_hash = String::from_str(&env, "doc-hash");
+    client.register_publisher(&publisher, &domain, &None);
+    client.confirm_domain(&admin, &publisher, &domain, &BytesN::from_array(&env, &[1u8; 32]));
+    client.submit_kyc(&publisher, &doc_hash, &provider);
+    client.attest_kyc(&provider, &publisher, &doc_hash);
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Bronze);
+
+    client.deactivate(&publisher);
+    assert!(!client.is_verified(&publisher));
+    assert!(client.try_deactivate(&publisher).is_err());
+
+    // Coming back inside the grace period and KYC window restores verification
+    client.reactivate(&publisher);
+    assert!(client.is_verified(&publisher));
+    assert_eq!(client.get_domain_owner(&domain), Some(publisher.clone()));
+
+    client.deactivate(&publisher);
+    assert!(client.try_release_inactive_domains(&publisher).is_err());
+    env.ledger().with_mut(|li| li.timestamp += DEACTIVATION_GRACE_PERIOD);
+    client.release_inactive_domains(&publisher);
+    assert_eq!(client.get_domain_owner(&domain), None);
+    assert!(client.list_domains(&publisher).is_empty());
+    client.register_publisher(&newcomer, &domain, &None);
+
+    // Lapsed KYC sends the publisher back through verification
+    env.ledger().with_mut(|li| li.timestamp += 5_000_000);
+    client.reactivate(&publisher);
+    assert!(client.get_publisher(&publisher).unwrap().status == VerificationStatus::Pending);
+}
+
+#[test]
+fn test_publisher_profile_and_payout_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    let payout = Address::generate(&env);
+    let contact = BytesN::from_array(&env, &[4u8; 32]);
+    client.initialize(&admin);
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+    assert_eq!(client.get_payout_address(&publisher), publisher);
+
+    let categories = soroban_sdk::vec![&env, Symbol::new(&env, "news"), Symbol::new(&env, "tech")];
+    let regions = soroban_sdk::vec![&env, Symbol::new(&env, "EU")];
+    client.update_profile(&publisher, &categories, &regions, &contact, &payout);
+
+    let profile = client.get_profile(&publisher).unwrap();
+    assert_eq!(profile.categories, categories);
+    assert_eq!(profile.regions, regions);
+    assert_eq!(client.get_payout_address(&publisher), payout);
+
+    let mut too_many = soroban_sdk::Vec::new(&env);
+    for _ in 0..=MAX_PROFILE_CATEGORIES {
+        too_many.push_back(Symbol::new(&env, "news"));
+    }
+    assert!(client
+        .try_update_profile(&publisher, &too_many, &regions, &contact, &payout)
+        .is_err());
+}
+
+#[test]
+fn test_list_publishers_paginated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let domains = ["a.example", "b.example", "c.example", "d.example", "e.example"];
+    let mut publishers = soroban_sdk::Vec::new(&env);
+    for (i, domain) in domains.iter().enumerate() {
+        let publisher = Address::generate(&env);
+        client.register_publisher(&publisher, &String::from_str(&env, domain), &None);
+        if i % 2 == 0 {
+            client.verify_publisher(&admin, &publisher, &PublisherTier::Bronze);
+        }
+        publishers.push_back(publisher);
+    }
+
+    let page = client.list_publishers(&1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().owner, publishers.get(1).unwrap());
+    assert_eq!(page.get(1).unwrap().owner, publishers.get(2).unwrap());
+    assert_eq!(client.list_publishers(&4, &10).len(), 1);
+    assert!(client.list_publishers(&5, &10).is_empty());
+
+    // Verified publishers are 0, 2 and 4
+    let verified = client.list_by_status(&VerificationStatus::Verified, &1, &10);
+    assert_eq!(verified.len(), 2);
+    assert_eq!(verified.get(0).unwrap().owner, publishers.get(2).unwrap());
+    assert_eq!(verified.get(1).unwrap().owner, publishers.get(4).unwrap());
+    assert_eq!(client.list_by_status(&VerificationStatus::Pending, &0, &10).len(), 2);
+}
+
+#[test]
+fn test_registration_bond() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let honest = Address::generate(&env);
+    let fraud = Address::generate(&env);
+    client.initialize(&admin);
+
+    let pulsar = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let pulsar_admin = token::StellarAssetClient::new(&env, &pulsar);
+    let pulsar_client = token::Client::new(&env, &pulsar);
+    pulsar_admin.mint(&honest, &1_000);
+    pulsar_admin.mint(&fraud, &1_000);
+    client.set_registration_bond(&admin, &pulsar, &400, &treasury);
+
+    client.register_publisher(&honest, &String::from_str(&env, "honest.example"), &None);
+    client.register_publisher(&fraud, &String::from_str(&env, "fraud.example"), &None);
+    assert_eq!(client.get_bond(&honest).unwrap().amount, 400);
+    assert_eq!(pulsar_client.balance(&contract_id), 800);
+
+    // Clean exit returns the bond
+    client.deactivate(&honest);
+    assert_eq!(pulsar_client.balance(&honest), 1_000);
+    assert!(client.get_bond(&honest).is_none());
+
+    // Fraud forfeits it
+    client.revoke_publisher(&admin, &fraud);
+    assert_eq!(pulsar_client.balance(&treasury), 400);
+    assert!(client.get_bond(&fraud).is_none());
+    assert!(client.try_deactivate(&fraud).is_err());
+
+    // Without funds for the bond, registration fails
+    let broke = Address::generate(&env);
+    assert!(client
+        .try_register_publisher(&broke, &String::from_str(&env, "broke.example"), &None)
+        .is_err());
+}
+
+#[test]
+fn test_kyc_history_is_versioned() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_kyc_provider(&admin, &provider, &String::from_str(&env, "provider"));
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+
+    let first = String::from_str(&env, "doc-hash-1");
+    let second = String::from_str(&env, "doc-hash-2");
+    client.submit_kyc(&publisher, &first, &provider);
+    client.attest_kyc(&provider, &publisher, &first);
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Bronze);
+    client.submit_kyc(&publisher, &second, &provider);
+
+    let latest = client.get_kyc(&publisher).unwrap();
+    assert_eq!((latest.version, latest.kyc_hash), (2, second.clone()));
+    assert!(!latest.verified);
+
+    // The verified first submission survives the re-submission
+    let history = client.get_kyc_history(&publisher, &0, &10);
+    assert_eq!(history.len(), 2);
+    let original = history.get(0).unwrap();
+    assert_eq!((original.version, original.kyc_hash), (1, first));
+    assert!(original.verified);
+    assert_eq!(client.get_kyc_history(&publisher, &1, &10).get(0).unwrap().kyc_hash, second);
+    assert_eq!(client.get_kyc_history(&publisher, &0, &1).len(), 1);
+}
+
+#[test]
+fn test_rejection_reason_and_reapply_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let provider = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_kyc_provider(&admin, &provider, &String::from_str(&env, "provider"));
+    client.set_reapply_cooldown(&admin, &86_400);
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+    client.submit_kyc(&publisher, &String::from_str(&env, "blurry-scan"), &provider);
+
+    client.reject_publisher(&admin, &publisher, &RejectionReason::IdentityMismatch);
+    let rejection = client.get_rejection(&publisher).unwrap();
+    assert_eq!(rejection.reason, RejectionReason::IdentityMismatch);
+    assert_eq!(rejection.verifier, admin);
+    assert!(client.get_publisher(&publisher).unwrap().status == VerificationStatus::Rejected);
+
+    let retry = String::from_str(&env, "clear-scan");
+    assert!(client.try_submit_kyc(&publisher, &retry, &provider).is_err());
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    client.submit_kyc(&publisher, &retry, &provider);
+    assert_eq!(client.get_kyc(&publisher).unwrap().version, 2);
+}
+
+#[test]
+fn test_referral_rewards() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let referred = Address::generate(&env);
+    client.initialize(&admin);
+    client.authorize_caller(&admin, &orchestrator);
+
+    let pulsar = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    token::StellarAssetClient::new(&env, &pulsar).mint(&admin, &1_000);
+    client.set_referral_config(&admin, &ReferralConfig { token: pulsar.clone(), reward: 300, earnings_threshold: 500 });
+    client.fund_referral_pool(&admin, &1_000);
+
+    client.register_publisher(&referrer, &String::from_str(&env, "ref.example"), &None);
+    assert!(client
+        .try_register_publisher(&referred, &String::from_str(&env, "new.example"), &Some(referred.clone()))
+        .is_err());
+    client.register_publisher(&referred, &String::from_str(&env, "new.example"), &Some(referrer.clone()));
+    assert_eq!(client.get_referrer(&referred), Some(referrer.clone()));
+    client.verify_publisher(&admin, &referred, &PublisherTier::Bronze);
+
+    client.record_impression(&orchestrator, &referred, &400);
+    assert_eq!(client.get_referral_rewards(&referrer), 0);
+
+    // Crossing the threshold pays once, no matter how much more is earned
+    client.record_impression(&orchestrator, &referred, &200);
+    client.record_impression(&orchestrator, &referred, &200);
+    assert_eq!(client.get_referral_rewards(&referrer), 300);
+    assert_eq!(client.get_referral_pool(), 700);
+
+    assert_eq!(client.claim_referral_rewards(&referrer), 300);
+    assert_eq!(token::Client::new(&env, &pulsar).balance(&referrer), 300);
+    assert!(client.try_claim_referral_rewards(&referrer).is_err());
+}
+
+#[contract]
+pub struct MockSubscriber;
+
+#[contractimpl]
+impl MockSubscriber {
+    pub fn on_publisher_status(
+        env: Env,
+        publisher: Address,
+        _old: VerificationStatus,
+        new: VerificationStatus,
+    ) {
+        if env.storage().instance().has(&symbol_short!("broken")) {
+            panic!("subscriber failure");
+        }
+        env.storage().persistent().set(&publisher, &new);
+    }
+
+    pub fn set_broken(env: Env) {
+        env.storage().instance().set(&symbol_short!("broken"), &true);
+    }
+
+    pub fn last_status(env: Env, publisher: Address) -> Option<VerificationStatus> {
+        env.storage().persistent().get(&publisher)
+    }
+}
+
+#[test]
+fn test_status_changes_notify_subscribers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+    let subscriber_id = env.register_contract(None, MockSubscriber);
+    let subscriber = MockSubscriberClient::new(&env, &subscriber_id);
+    let broken_id = env.register_contract(None, MockSubscriber);
+    MockSubscriberClient::new(&env, &broken_id).set_broken();
+
+    let admin = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_status_subscriber(&admin, &broken_id);
+    client.add_status_subscriber(&admin, &subscriber_id);
+    assert_eq!(client.get_status_subscribers().len(), 2);
+
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Bronze);
+    assert!(subscriber.last_status(&publisher) == Some(VerificationStatus::Verified));
+
+    client.suspend_publisher(&admin, &publisher, &0);
+    assert!(subscriber.last_status(&publisher) == Some(VerificationStatus::Suspended));
+
+    client.remove_status_subscriber(&admin, &broken_id);
+    client.revoke_publisher(&admin, &publisher);
+    assert!(subscriber.last_status(&publisher) == Some(VerificationStatus::Revoked));
+    assert_eq!(client.get_status_subscribers().len(), 1);
+}
+
+#[test]
+fn test_typed_errors_and_migration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.initialize(&admin);
+
+    let domain = String::from_str(&env, "news.example");
+    client.register_publisher(&publisher, &domain, &None);
+    assert_eq!(
+        client.try_register_publisher(&publisher, &domain, &None),
+        Err(Ok(VerificationError::AlreadyRegistered))
+    );
+    assert_eq!(
+        client.try_verify_publisher(&stranger, &publisher, &PublisherTier::Bronze),
+        Err(Ok(VerificationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_upgrade_authority(&stranger, &stranger),
+        Err(Ok(VerificationError::Unauthorized))
+    );
+
+    // Fresh deployments start on the current version
+    assert_eq!(client.get_version().version, 2);
+    assert_eq!(client.try_migrate(&2), Err(Ok(VerificationError::AlreadyMigrated)));
+
+    // A deployment from before versioning migrates once and keeps its publishers
+    env.as_contract(&contract_id, || {
+        env.storage().instance().remove(&DataKey::ContractVersion);
+    });
+    assert_eq!(client.get_version().version, 1);
+    assert_eq!(client.try_migrate(&2), Err(Ok(VerificationError::VersionMismatch)));
+    assert_eq!(client.migrate(&1), 2);
+    assert_eq!(client.try_migrate(&1), Err(Ok(VerificationError::AlreadyMigrated)));
+
+    // So does a v2 deployment that stored its version as a bare u32
+    env.as_contract(&contract_id, || {
+        env.storage().instance().remove(&DataKey::ContractVersion);
+        env.storage().instance().set(&DataKey::Version, &2u32);
+    });
+    assert_eq!(client.get_version().version, 2);
+    assert_eq!(client.try_migrate(&2), Err(Ok(VerificationError::AlreadyMigrated)));
+    assert_eq!(client.get_version().version, 2);
+    assert_eq!(client.get_publisher_count(), 1);
+    assert!(client.get_publisher(&publisher).is_some());
+}
+
+#[test]
+fn test_tier_benefits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin);
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+    client.verify_publisher(&admin, &publisher, &PublisherTier::Gold);
+
+    // Defaults apply until the admin overrides them
+    assert_eq!(client.get_tier_benefits(&PublisherTier::Bronze).platform_fee_bps, 500);
+    assert_eq!(client.get_publisher_benefits(&publisher).max_concurrent_campaigns, 25);
+
+    let gold = TierBenefits {
+        platform_fee_bps: 250,
+        max_concurrent_campaigns: 30,
+        payout_frequency_secs: 259_200,
+    };
+    client.set_tier_benefits(&admin, &PublisherTier::Gold, &gold);
+    let benefits = client.get_publisher_benefits(&publisher);
+    assert_eq!(benefits.platform_fee_bps, 250);
+    assert_eq!(benefits.payout_frequency_secs, 259_200);
+
+    let invalid = TierBenefits {
+        platform_fee_bps: 10_001,
+        max_concurrent_campaigns: 30,
+        payout_frequency_secs: 259_200,
+    };
+    assert_eq!(
+        client.try_set_tier_benefits(&admin, &PublisherTier::Gold, &invalid),
+        Err(Ok(VerificationError::InvalidTierBenefits))
+    );
+    assert!(client.try_get_publisher_benefits(&Address::generate(&env)).is_err());
+}
+
+#[test]
+fn test_extend_ttl_publisher() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    client.initialize(&admin);
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+
+    let ttl = || env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&DataKey::Publisher(publisher.clone())));
+    assert_eq!(ttl(), PUBLISHER_TTL.extend_to);
+
+    for _ in 0..12 {
+        env.ledger().with_mut(|li| li.sequence_number += 80_000);
+        client.get_version();
+    }
+    assert!(ttl() < PUBLISHER_TTL.threshold);
+
+    assert!(client.extend_ttl_publisher(&publisher));
+    assert_eq!(ttl(), PUBLISHER_TTL.extend_to);
+    assert!(!client.extend_ttl_publisher(&Address::generate(&env)));
+}
+
+#[test]
+fn test_earnings_settle_and_withdraw() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, PublisherVerificationContract);
+    let client = PublisherVerificationContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let orchestrator = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let publisher = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.initialize(&admin);
+    client.register_publisher(&publisher, &String::from_str(&env, "news.example"), &None);
+
+    let pulsar = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let pulsar_client = token::Client::new(&env, &pulsar);
+    token::StellarAssetClient::new(&env, &pulsar).mint(&orchestrator, &10_000);
+
+    assert_eq!(
+        client.try_settle_earnings(&orchestrator, &publisher, &1_000),
+        Err(Ok(VerificationError::Unauthorized))
+    );
+    client.authorize_caller(&admin, &orchestrator);
+    assert_eq!(
+        client.try_settle_earnings(&orchestrator, &publisher, &1_000),
+        Err(Ok(VerificationError::EarningsNotConfigured))
+    );
+    client.set_earnings_config(&admin, &pulsar, &fee_recipient);
+
+    client.settle_earnings(&orchestrator, &publisher, &1_000);
+    client.settle_earnings(&orchestrator, &publisher, &1_000);
+    assert_eq!(client.get_accrued_earnings(&publisher), 2_000);
+    assert_eq!(pulsar_client.balance(&contract_id), 2_000);
+
+    // Payouts go to the profile's payout address, less the tier's fee
+    let contact = BytesN::from_array(&env, &[7; 32]);
+    client.update_profile(&publisher, &Vec::new(&env), &Vec::new(&env), &contact, &payout);
+    let fee_bps = client.get_publisher_benefits(&publisher).platform_fee_bps as i128;
+    assert_eq!(
+        client.try_withdraw_earnings(&publisher, &2_001),
+        Err(Ok(VerificationError::InsufficientEarnings))
+    );
+    let net = client.withdraw_earnings(&publisher, &1_500);
+    let fee = 1_500 * fee_bps / 10_000;
+    assert_eq!(net, 1_500 - fee);
+    assert_eq!(pulsar_client.balance(&payout), net);
+    assert_eq!(pulsar_client.balance(&fee_recipient), fee);
+    assert_eq!(client.get_accrued_earnings(&publisher), 500);
+
+    // The settlement token is locked while earnings are outstanding
+    let other = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    assert_eq!(
+        client.try_set_earnings_config(&admin, &other, &fee_recipient),
+        Err(Ok(VerificationError::EarningsOutstanding))
+    );
+    client.withdraw_earnings(&publisher, &500);
+    assert_eq!(client.get_accrued_earnings(&publisher), 0);
+    client.set_earnings_config(&admin, &other, &fee_recipient);
+}
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     token, Address, Env,
 };
+pub use pulsartrack_errors::RecurringPaymentError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -33,6 +35,14 @@ pub struct RecurringPayment {
     pub next_payment: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct RecurringPaid {
+    pub payment_id: u64,
+    pub amount: i128,
+}
+contract_event!(RecurringPaid, "recurring_paid");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -45,6 +55,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "recurring";
+
 #[contract]
 pub struct RecurringPaymentContract;
 
@@ -53,7 +65,7 @@ impl RecurringPaymentContract {
     pub fn initialize(env: Env, admin: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, RecurringPaymentError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -73,10 +85,10 @@ impl RecurringPaymentContract {
         payer.require_auth();
 
         if amount <= 0 {
-            panic!("invalid amount");
+            panic_with_error!(&env, RecurringPaymentError::InvalidAmount);
         }
         if interval_secs == 0 {
-            panic!("invalid interval");
+            panic_with_error!(&env, RecurringPaymentError::InvalidInterval);
         }
 
         let counter: u64 = env.storage().instance().get(&DataKey::PaymentCounter).unwrap_or(0);
@@ -112,15 +124,15 @@ impl RecurringPaymentContract {
             .storage()
             .persistent()
             .get(&DataKey::Payment(payment_id))
-            .expect("payment not found");
+            .unwrap_or_else(|| panic_with_error!(&env, RecurringPaymentError::PaymentNotFound));
 
         if recurring.status != RecurringStatus::Active {
-            panic!("payment not active");
+            panic_with_error!(&env, RecurringPaymentError::PaymentNotActive);
         }
 
         let now = env.ledger().timestamp();
         if now < recurring.next_payment {
-            panic!("too early");
+            panic_with_error!(&env, RecurringPaymentError::TooEarly);
         }
 
         if let Some(max) = recurring.max_payments {
@@ -129,7 +141,7 @@ impl RecurringPaymentContract {
                 let _ttl_key = DataKey::Payment(payment_id);
                 env.storage().persistent().set(&_ttl_key, &recurring);
                 env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
-                panic!("max payments reached");
+                panic_with_error!(&env, RecurringPaymentError::MaxPaymentsReached);
             }
         }
 
@@ -144,10 +156,7 @@ impl RecurringPaymentContract {
         env.storage().persistent().set(&_ttl_key, &recurring);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("recurring"), symbol_short!("paid")),
-            (payment_id, recurring.amount),
-        );
+        events::publish(&env, EVENT_TAG, RecurringPaid { payment_id, amount: recurring.amount });
     }
 
     pub fn pause_payment(env: Env, payer: Address, payment_id: u64) {
@@ -158,10 +167,10 @@ impl RecurringPaymentContract {
             .storage()
             .persistent()
             .get(&DataKey::Payment(payment_id))
-            .expect("payment not found");
+            .unwrap_or_else(|| panic_with_error!(&env, RecurringPaymentError::PaymentNotFound));
 
         if recurring.payer != payer {
-            panic!("unauthorized");
+            panic_with_error!(&env, RecurringPaymentError::Unauthorized);
         }
 
         recurring.status = RecurringStatus::Paused;
@@ -178,10 +187,10 @@ impl RecurringPaymentContract {
             .storage()
             .persistent()
             .get(&DataKey::Payment(payment_id))
-            .expect("payment not found");
+            .unwrap_or_else(|| panic_with_error!(&env, RecurringPaymentError::PaymentNotFound));
 
         if recurring.payer != payer {
-            panic!("unauthorized");
+            panic_with_error!(&env, RecurringPaymentError::Unauthorized);
         }
 
         recurring.status = RecurringStatus::Active;
@@ -199,10 +208,10 @@ impl RecurringPaymentContract {
             .storage()
             .persistent()
             .get(&DataKey::Payment(payment_id))
-            .expect("payment not found");
+            .unwrap_or_else(|| panic_with_error!(&env, RecurringPaymentError::PaymentNotFound));
 
         if recurring.payer != payer {
-            panic!("unauthorized");
+            panic_with_error!(&env, RecurringPaymentError::Unauthorized);
         }
 
         recurring.status = RecurringStatus::Cancelled;
@@ -1,11 +1,24 @@
 //! PulsarTrack - Refund Processor (Soroban)
 //! Campaign refund processing and dispute resolution on Stellar.
+//!
+//! Approvals are checked against the campaign orchestrator: the campaign must
+//! exist, the requester must be the advertiser who funded it, and refunds
+//! approved on a campaign can't add up to more than the budget it has left.
+//!
+//! Contracts the admin authorizes as filers (escrow) may file on an
+//! advertiser's behalf. Those refunds are checked against the advertiser and
+//! paid out by the filer from the funds it holds, not by this contract.
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
-    token, Address, Env, String,
+    contract, contractimpl, contracttype, panic_with_error, symbol_short,
+    token, Address, BytesN, Env, IntoVal, String, Symbol, Vec,
 };
+pub use pulsartrack_errors::RefundError;
+use pulsartrack_events::{self as events, contract_event};
+use pulsartrack_access::Ownable;
+use pulsartrack_upgrade::{ContractVersion, Upgradeable};
+use pulsartrack_storage::{self as storage, Persistent, TtlPolicy};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -22,6 +35,7 @@ pub enum RefundStatus {
 pub struct RefundRequest {
     pub refund_id: u64,
     pub requester: Address,
+    pub on_behalf_of: Option<Address>, // advertiser an authorized filer acts for
     pub campaign_id: u64,
     pub token: Address,
     pub amount_requested: i128,
@@ -32,6 +46,63 @@ pub struct RefundRequest {
     pub resolved_at: Option<u64>,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct RefundProcessed {
+    pub refund_id: u64,
+    pub amount: i128,
+}
+contract_event!(RefundProcessed, "refund_processed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RefundEscalated {
+    pub refund_id: u64,
+    pub case_id: u64,
+}
+contract_event!(RefundEscalated, "refund_escalated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RefundRuled {
+    pub refund_id: u64,
+    pub case_id: u64,
+    pub claimant_wins: bool,
+}
+contract_event!(RefundRuled, "refund_ruled");
+
+// campaign-orchestrator's `CampaignStatus` and `Campaign`, as returned by its
+// `get_campaign`. Variant and field names must match for the value to decode.
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum CampaignStatus {
+    Active,
+    Paused,
+    Completed,
+    Cancelled,
+    Expired,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Campaign {
+    pub advertiser: Address,
+    pub campaign_type: u32,
+    pub budget: i128,
+    pub remaining_budget: i128,
+    pub cost_per_view: i128,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+    pub status: CampaignStatus,
+    pub target_views: u64,
+    pub current_views: u64,
+    pub daily_view_limit: u64,
+    pub refundable: bool,
+    pub platform_fee: i128,
+    pub created_at: u64,
+    pub last_updated: u64,
+}
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -39,12 +110,25 @@ pub enum DataKey {
     RefundCounter,
     AutoRefundPeriod,
     Refund(u64),
+    Arbitration,
+    ArbitrationCase(u64), // refund_id -> case_id
+    Orchestrator,
+    CampaignRefunded(u64), // campaign_id -> total approved across its refunds
+    Filer(Address),        // contracts allowed to file on an advertiser's behalf
+    Registry,
+    UpgradeAuthority,
+    ContractVersion,
 }
 
 const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
 const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+// TTL per kind of persistent entry, bumped on every access
+const REFUND_TTL: TtlPolicy = storage::LONG_LIVED;
+
+const EVENT_TAG: &str = "refunds";
+const CONTRACT_VERSION: u32 = 1; // deployments from before versioning count as 1
 
 #[contract]
 pub struct RefundProcessorContract;
@@ -53,14 +137,43 @@ pub struct RefundProcessorContract;
 impl RefundProcessorContract {
     pub fn initialize(env: Env, admin: Address, token: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+        if Ownable::init(&env, &DataKey::Admin, &admin).is_err() {
+            panic_with_error!(&env, RefundError::AlreadyInitialized);
         }
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::TokenAddress, &token);
         env.storage().instance().set(&DataKey::RefundCounter, &0u64);
         env.storage().instance().set(&DataKey::AutoRefundPeriod, &604_800u64); // 7 days
+        Upgradeable::init(&env, &DataKey::ContractVersion, CONTRACT_VERSION);
+    }
+
+    /// See `Upgradeable`
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), RefundError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::require_authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)?;
+        Upgradeable::upgrade(&env, EVENT_TAG, &DataKey::ContractVersion, new_wasm_hash);
+        Ok(())
+    }
+
+    pub fn migrate(env: Env, from_version: u32) -> Result<u32, RefundError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::require_authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)?;
+        Ok(Upgradeable::migrate(&env, EVENT_TAG, &DataKey::ContractVersion, from_version, CONTRACT_VERSION, |_| {})?)
+    }
+
+    pub fn set_upgrade_authority(env: Env, caller: Address, authority: Address) -> Result<(), RefundError> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::set_authority(&env, EVENT_TAG, &DataKey::UpgradeAuthority, &DataKey::Admin, &caller, &authority)?;
+        Ok(())
+    }
+
+    pub fn get_upgrade_authority(env: Env) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::authority(&env, &DataKey::UpgradeAuthority, &DataKey::Admin)
+    }
+
+    pub fn get_version(env: Env) -> ContractVersion {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Upgradeable::version(&env, &DataKey::ContractVersion)
     }
 
     pub fn request_refund(
@@ -72,77 +185,81 @@ impl RefundProcessorContract {
     ) -> u64 {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         requester.require_auth();
+        Self::_file_refund(&env, requester, None, campaign_id, amount, reason)
+    }
 
-        if amount <= 0 {
-            panic!("invalid amount");
+    /// File a refund for `advertiser` from an authorized filer. Approval is
+    /// checked against the advertiser; the filer settles the outcome itself.
+    pub fn request_refund_for(
+        env: Env,
+        filer: Address,
+        advertiser: Address,
+        campaign_id: u64,
+        amount: i128,
+        reason: String,
+    ) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        filer.require_auth();
+        if !env
+            .storage()
+            .instance()
+            .get(&DataKey::Filer(filer.clone()))
+            .unwrap_or(false)
+        {
+            panic_with_error!(&env, RefundError::NotFiler);
         }
-
-        let counter: u64 = env.storage().instance().get(&DataKey::RefundCounter).unwrap_or(0);
-        let refund_id = counter + 1;
-
-        let token_addr: Address = env.storage().instance().get(&DataKey::TokenAddress).unwrap();
-
-        let refund = RefundRequest {
-            refund_id,
-            requester: requester.clone(),
-            campaign_id,
-            token: token_addr,
-            amount_requested: amount,
-            amount_approved: 0,
-            reason,
-            status: RefundStatus::Requested,
-            submitted_at: env.ledger().timestamp(),
-            resolved_at: None,
-        };
-
-        let _ttl_key = DataKey::Refund(refund_id);
-        env.storage().persistent().set(&_ttl_key, &refund);
-        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
-        env.storage().instance().set(&DataKey::RefundCounter, &refund_id);
-
-        refund_id
+        Self::_file_refund(&env, filer, Some(advertiser), campaign_id, amount, reason)
     }
 
     pub fn approve_refund(env: Env, admin: Address, refund_id: u64, approved_amount: i128) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        admin.require_auth();
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if admin != stored_admin {
-            panic!("unauthorized");
-        }
+        Self::_require_admin(&env, &admin);
 
         let mut refund: RefundRequest = env
             .storage()
             .persistent()
             .get(&DataKey::Refund(refund_id))
-            .expect("refund not found");
+            .unwrap_or_else(|| panic_with_error!(&env, RefundError::RefundNotFound));
+        if env.storage().persistent().has(&DataKey::ArbitrationCase(refund_id)) {
+            panic_with_error!(&env, RefundError::RefundUnderArbitration);
+        }
 
         if refund.status != RefundStatus::Requested && refund.status != RefundStatus::UnderReview {
-            panic!("invalid status");
+            panic_with_error!(&env, RefundError::InvalidStatus);
+        }
+
+        if approved_amount <= 0 {
+            panic_with_error!(&env, RefundError::InvalidAmount);
         }
+        let approved = approved_amount.min(refund.amount_requested);
+        Self::_check_contribution(&env, &refund, approved);
 
-        refund.amount_approved = approved_amount.min(refund.amount_requested);
+        refund.amount_approved = approved;
         refund.status = RefundStatus::Approved;
         refund.resolved_at = Some(env.ledger().timestamp());
 
         let _ttl_key = DataKey::Refund(refund_id);
         env.storage().persistent().set(&_ttl_key, &refund);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::_add_refunded(&env, refund.campaign_id, approved);
     }
 
     pub fn reject_refund(env: Env, admin: Address, refund_id: u64) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        admin.require_auth();
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if admin != stored_admin {
-            panic!("unauthorized");
-        }
+        Self::_require_admin(&env, &admin);
 
         let mut refund: RefundRequest = env
             .storage()
             .persistent()
             .get(&DataKey::Refund(refund_id))
-            .expect("refund not found");
+            .unwrap_or_else(|| panic_with_error!(&env, RefundError::RefundNotFound));
+        if env.storage().persistent().has(&DataKey::ArbitrationCase(refund_id)) {
+            panic_with_error!(&env, RefundError::RefundUnderArbitration);
+        }
+        // An approval already counts against the campaign and may be paid out
+        if refund.status != RefundStatus::Requested && refund.status != RefundStatus::UnderReview {
+            panic_with_error!(&env, RefundError::InvalidStatus);
+        }
 
         refund.status = RefundStatus::Rejected;
         refund.resolved_at = Some(env.ledger().timestamp());
@@ -158,10 +275,13 @@ impl RefundProcessorContract {
             .storage()
             .persistent()
             .get(&DataKey::Refund(refund_id))
-            .expect("refund not found");
+            .unwrap_or_else(|| panic_with_error!(&env, RefundError::RefundNotFound));
 
         if refund.status != RefundStatus::Approved {
-            panic!("refund not approved");
+            panic_with_error!(&env, RefundError::RefundNotApproved);
+        }
+        if refund.on_behalf_of.is_some() {
+            panic_with_error!(&env, RefundError::SettledByFiler);
         }
 
         let token_client = token::Client::new(&env, &refund.token);
@@ -176,15 +296,267 @@ impl RefundProcessorContract {
         env.storage().persistent().set(&_ttl_key, &refund);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("refund"), symbol_short!("processed")),
-            (refund_id, refund.amount_approved),
+        events::publish(&env, EVENT_TAG, RefundProcessed { refund_id, amount: refund.amount_approved });
+    }
+
+    /// Set the campaign orchestrator approvals are checked against (admin only)
+    pub fn set_orchestrator(env: Env, admin: Address, orchestrator: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Orchestrator, &orchestrator);
+    }
+
+    /// Allow or stop `filer` filing refunds on an advertiser's behalf (admin only)
+    pub fn set_filer(env: Env, admin: Address, filer: Address, allowed: bool) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        if allowed {
+            env.storage().instance().set(&DataKey::Filer(filer), &true);
+        } else {
+            env.storage().instance().remove(&DataKey::Filer(filer));
+        }
+    }
+
+    pub fn is_filer(env: Env, filer: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::Filer(filer)).unwrap_or(false)
+    }
+
+    /// Set the arbitration court refund disputes can be escalated to (admin only)
+    pub fn set_arbitration(env: Env, admin: Address, court: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Arbitration, &court);
+    }
+
+    /// Resolve the orchestrator and arbitration court through the contract
+    /// registry's "orchestr" and "arbitr" keys, falling back to
+    /// `set_orchestrator` / `set_arbitration` (admin only)
+    pub fn set_registry(env: Env, admin: Address, registry: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Registry, &registry);
+    }
+
+    /// Send an undecided refund to the arbitration court instead of the
+    /// admin. The requester pays the court's case fee.
+    pub fn escalate_refund(env: Env, requester: Address, refund_id: u64) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        requester.require_auth();
+        let court = Self::_arbitration(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, RefundError::ArbitrationNotSet));
+
+        let mut refund: RefundRequest = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Refund(refund_id))
+            .unwrap_or_else(|| panic_with_error!(&env, RefundError::RefundNotFound));
+        if refund.requester != requester {
+            panic_with_error!(&env, RefundError::Unauthorized);
+        }
+        if refund.status != RefundStatus::Requested {
+            panic_with_error!(&env, RefundError::InvalidStatus);
+        }
+
+        let case_id: u64 = env.invoke_contract(
+            &court,
+            &Symbol::new(&env, "open_case"),
+            Vec::from_array(
+                &env,
+                [
+                    env.current_contract_address().into_val(&env),
+                    requester.clone().into_val(&env),
+                    requester.into_val(&env),
+                    refund_id.into_val(&env),
+                ],
+            ),
         );
+
+        refund.status = RefundStatus::UnderReview;
+        let _ttl_key = DataKey::Refund(refund_id);
+        env.storage().persistent().set(&_ttl_key, &refund);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        let _ttl_key = DataKey::ArbitrationCase(refund_id);
+        env.storage().persistent().set(&_ttl_key, &case_id);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, RefundEscalated { refund_id, case_id });
+
+        case_id
+    }
+
+    /// Ruling hook called by the arbitration court: a win for the requester
+    /// approves the full amount, subject to the same campaign checks as an
+    /// admin approval; anything else rejects the refund
+    pub fn on_ruling(env: Env, court: Address, case_id: u64, _subject: Address, refund_id: u64, claimant_wins: bool) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        court.require_auth();
+        if Self::_arbitration(&env) != Some(court) {
+            panic_with_error!(&env, RefundError::Unauthorized);
+        }
+        let escalated: Option<u64> = env.storage().persistent().get(&DataKey::ArbitrationCase(refund_id));
+        if escalated != Some(case_id) {
+            panic_with_error!(&env, RefundError::CaseMismatch);
+        }
+
+        let mut refund: RefundRequest = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Refund(refund_id))
+            .unwrap_or_else(|| panic_with_error!(&env, RefundError::RefundNotFound));
+        if refund.status != RefundStatus::UnderReview {
+            panic_with_error!(&env, RefundError::InvalidStatus);
+        }
+        if claimant_wins {
+            Self::_check_contribution(&env, &refund, refund.amount_requested);
+            refund.amount_approved = refund.amount_requested;
+            refund.status = RefundStatus::Approved;
+            Self::_add_refunded(&env, refund.campaign_id, refund.amount_approved);
+        } else {
+            refund.status = RefundStatus::Rejected;
+        }
+        refund.resolved_at = Some(env.ledger().timestamp());
+
+        let _ttl_key = DataKey::Refund(refund_id);
+        env.storage().persistent().set(&_ttl_key, &refund);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, RefundRuled { refund_id, case_id, claimant_wins });
     }
 
     pub fn get_refund(env: Env, refund_id: u64) -> Option<RefundRequest> {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        env.storage().persistent().get(&DataKey::Refund(refund_id))
+        Persistent::get(&env, &DataKey::Refund(refund_id), REFUND_TTL)
+    }
+
+    pub fn get_orchestrator(env: Env) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_orchestrator(&env)
+    }
+
+    /// Total approved so far across a campaign's refunds
+    pub fn get_campaign_refunded(env: Env, campaign_id: u64) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_refunded(&env, campaign_id)
+    }
+
+    // ============================================================
+    // TTL Maintenance
+    // ============================================================
+
+    /// Keep a refund record from being archived. Anyone may call; returns
+    /// false if there is no such refund.
+    pub fn extend_ttl_refund(env: Env, refund_id: u64) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Persistent::extend(&env, &DataKey::ArbitrationCase(refund_id), REFUND_TTL);
+        Persistent::extend(&env, &DataKey::Refund(refund_id), REFUND_TTL)
+    }
+
+    // ============================================================
+    // Internal Helpers
+    // ============================================================
+
+    fn _require_admin(env: &Env, admin: &Address) {
+        if let Err(e) = Ownable::require_owner(env, &DataKey::Admin, admin) {
+            panic_with_error!(env, RefundError::from(e));
+        }
+    }
+
+    fn _file_refund(
+        env: &Env,
+        requester: Address,
+        on_behalf_of: Option<Address>,
+        campaign_id: u64,
+        amount: i128,
+        reason: String,
+    ) -> u64 {
+        if amount <= 0 {
+            panic_with_error!(env, RefundError::InvalidAmount);
+        }
+
+        let counter: u64 = env.storage().instance().get(&DataKey::RefundCounter).unwrap_or(0);
+        let refund_id = counter + 1;
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::TokenAddress).unwrap();
+
+        let refund = RefundRequest {
+            refund_id,
+            requester,
+            on_behalf_of,
+            campaign_id,
+            token: token_addr,
+            amount_requested: amount,
+            amount_approved: 0,
+            reason,
+            status: RefundStatus::Requested,
+            submitted_at: env.ledger().timestamp(),
+            resolved_at: None,
+        };
+
+        let _ttl_key = DataKey::Refund(refund_id);
+        env.storage().persistent().set(&_ttl_key, &refund);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        env.storage().instance().set(&DataKey::RefundCounter, &refund_id);
+
+        refund_id
+    }
+
+    fn _orchestrator(env: &Env) -> Option<Address> {
+        Self::_resolve(env, symbol_short!("orchestr"), &DataKey::Orchestrator)
+    }
+
+    fn _arbitration(env: &Env) -> Option<Address> {
+        Self::_resolve(env, symbol_short!("arbitr"), &DataKey::Arbitration)
+    }
+
+    /// The registry's entry for `key` if there is one, else the address
+    /// stored under `fallback`
+    fn _resolve(env: &Env, key: Symbol, fallback: &DataKey) -> Option<Address> {
+        if let Some(registry) = env.storage().instance().get::<DataKey, Address>(&DataKey::Registry) {
+            let resolved: Option<Address> = env.invoke_contract(
+                &registry,
+                &Symbol::new(env, "lookup"),
+                (key,).into_val(env),
+            );
+            if resolved.is_some() {
+                return resolved;
+            }
+        }
+        env.storage().instance().get(fallback)
+    }
+
+    /// Ask the orchestrator whether `refund`'s campaign exists, was funded by
+    /// the requester (or the advertiser a filer acts for) and has `amount` of
+    /// its remaining budget left to refund. Refunds approved here never come
+    /// off the orchestrator's remaining budget, paid out or not, so all of
+    /// them are netted against it.
+    fn _check_contribution(env: &Env, refund: &RefundRequest, amount: i128) {
+        let orchestrator = Self::_orchestrator(env)
+            .unwrap_or_else(|| panic_with_error!(env, RefundError::OrchestratorNotSet));
+
+        let campaign: Option<Campaign> = env.invoke_contract(
+            &orchestrator,
+            &Symbol::new(env, "get_campaign"),
+            Vec::from_array(env, [refund.campaign_id.into_val(env)]),
+        );
+        let campaign = campaign.unwrap_or_else(|| panic_with_error!(env, RefundError::CampaignNotFound));
+
+        let contributor = refund.on_behalf_of.as_ref().unwrap_or(&refund.requester);
+        if campaign.advertiser != *contributor {
+            panic_with_error!(env, RefundError::NotContributor);
+        }
+        if amount > campaign.remaining_budget - Self::_refunded(env, refund.campaign_id) {
+            panic_with_error!(env, RefundError::ExceedsContribution);
+        }
+    }
+
+    fn _refunded(env: &Env, campaign_id: u64) -> i128 {
+        Persistent::get(env, &DataKey::CampaignRefunded(campaign_id), REFUND_TTL).unwrap_or(0)
+    }
+
+    fn _add_refunded(env: &Env, campaign_id: u64, amount: i128) {
+        let total = Self::_refunded(env, campaign_id) + amount;
+        Persistent::set(env, &DataKey::CampaignRefunded(campaign_id), &total, REFUND_TTL);
     }
 }
 
@@ -1,6 +1,93 @@
 #![cfg(test)]
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, vec, String};
+use soroban_sdk::{contract, contractimpl, testutils::{storage::Persistent as _, Address as _, Ledger}, Address, Env, IntoVal, String, Symbol, Vec};
+
+// Stand-in for the arbitration court: opens cases and hands down whatever
+// ruling the test asks for
+#[contract]
+pub struct MockCourt;
+
+#[contractimpl]
+impl MockCourt {
+    pub fn open_case(env: Env, escalator: Address, payer: Address, _subject: Address, _ref_id: u64) -> u64 {
+        escalator.require_auth();
+        payer.require_auth();
+        let case_id: u64 = env.storage().instance().get(&0u64).unwrap_or(0) + 1;
+        env.storage().instance().set(&0u64, &case_id);
+        case_id
+    }
+
+    pub fn rule(env: Env, escalator: Address, case_id: u64, subject: Address, ref_id: u64, claimant_wins: bool) {
+        env.invoke_contract::<soroban_sdk::Val>(
+            &escalator,
+            &Symbol::new(&env, "on_ruling"),
+            Vec::from_array(
+                &env,
+                [
+                    env.current_contract_address().into_val(&env),
+                    case_id.into_val(&env),
+                    subject.into_val(&env),
+                    ref_id.into_val(&env),
+                    claimant_wins.into_val(&env),
+                ],
+            ),
+        );
+    }
+}
+
+// Stand-in for the campaign orchestrator: serves whatever campaigns the test
+// stores
+#[contract]
+pub struct MockOrchestrator;
+
+#[contractimpl]
+impl MockOrchestrator {
+    pub fn set_campaign(env: Env, campaign_id: u64, advertiser: Address, budget: i128) {
+        let campaign = Campaign {
+            advertiser,
+            campaign_type: 0,
+            budget,
+            remaining_budget: budget,
+            cost_per_view: 10,
+            start_ledger: 0,
+            end_ledger: 1_000,
+            status: CampaignStatus::Active,
+            target_views: 0,
+            current_views: 0,
+            daily_view_limit: 0,
+            refundable: true,
+            platform_fee: 0,
+            created_at: 0,
+            last_updated: 0,
+        };
+        env.storage().instance().set(&campaign_id, &campaign);
+    }
+
+    /// Take `amount` off a campaign's remaining budget, as served views do
+    pub fn spend(env: Env, campaign_id: u64, amount: i128) {
+        let mut campaign: Campaign = env.storage().instance().get(&campaign_id).unwrap();
+        campaign.remaining_budget -= amount;
+        env.storage().instance().set(&campaign_id, &campaign);
+    }
+
+    pub fn get_campaign(env: Env, campaign_id: u64) -> Option<Campaign> {
+        env.storage().instance().get(&campaign_id)
+    }
+}
+
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_address(env: Env, key: Symbol, address: Address) {
+        env.storage().instance().set(&key, &address);
+    }
+
+    pub fn lookup(env: Env, key: Symbol) -> Option<Address> {
+        env.storage().instance().get(&key)
+    }
+}
 
 #[test]
 fn test_initialize() {
@@ -17,7 +104,7 @@ fn test_initialize() {
 }
 
 #[test]
-#[should_panic(expected = "already initialized")]
+#[should_panic(expected = "Error(Contract, #301)")]
 fn test_initialize_twice() {
     let env = Env::default();
     env.mock_all_auths();
@@ -46,3 +133,248 @@ fn test_initialize_non_admin_fails() {
     // This should panic because admin didn't authorize it and we haven't mocked it
     client.initialize(&admin, &token);
 }
+
+#[test]
+fn test_escalated_refund_follows_court_ruling() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, RefundProcessorContract);
+    let client = RefundProcessorContractClient::new(&env, &contract_id);
+    let court_id = env.register_contract(None, MockCourt);
+    let court = MockCourtClient::new(&env, &court_id);
+
+    let orchestrator_id = env.register_contract(None, MockOrchestrator);
+    let orchestrator = MockOrchestratorClient::new(&env, &orchestrator_id);
+
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    let requester = Address::generate(&env);
+    client.initialize(&admin, &token);
+    client.set_orchestrator(&admin, &orchestrator_id);
+    orchestrator.set_campaign(&1, &requester, &1_000);
+
+    let won = client.request_refund(&requester, &1, &500, &String::from_str(&env, "undelivered"));
+    let lost = client.request_refund(&requester, &2, &300, &String::from_str(&env, "undelivered"));
+    assert!(client.try_escalate_refund(&requester, &won).is_err());
+
+    client.set_arbitration(&admin, &court_id);
+    let stranger = Address::generate(&env);
+    assert!(client.try_escalate_refund(&stranger, &won).is_err());
+    let won_case = client.escalate_refund(&requester, &won);
+    let lost_case = client.escalate_refund(&requester, &lost);
+    assert!(client.get_refund(&won).unwrap().status == RefundStatus::UnderReview);
+
+    // The admin can no longer decide an escalated refund
+    assert!(client.try_approve_refund(&admin, &won, &500).is_err());
+    assert!(client.try_reject_refund(&admin, &lost).is_err());
+    // Rulings are only accepted from the configured court for the right case
+    assert!(client.try_on_ruling(&stranger, &won_case, &requester, &won, &true).is_err());
+    assert!(court.try_rule(&contract_id, &lost_case, &requester, &won, &true).is_err());
+
+    court.rule(&contract_id, &won_case, &requester, &won, &true);
+    court.rule(&contract_id, &lost_case, &requester, &lost, &false);
+
+    let refund = client.get_refund(&won).unwrap();
+    assert!(refund.status == RefundStatus::Approved);
+    assert_eq!(refund.amount_approved, 500);
+    assert_eq!(client.get_campaign_refunded(&1), 500);
+    assert!(client.get_refund(&lost).unwrap().status == RefundStatus::Rejected);
+
+    // Winning in court doesn't get around the campaign checks
+    let outsider = client.request_refund(&stranger, &1, &100, &String::from_str(&env, "undelivered"));
+    let outsider_case = client.escalate_refund(&stranger, &outsider);
+    assert!(court.try_rule(&contract_id, &outsider_case, &stranger, &outsider, &true).is_err());
+    assert!(client.get_refund(&outsider).unwrap().status == RefundStatus::UnderReview);
+}
+
+#[test]
+fn test_upgrade_authority() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, RefundProcessorContract);
+    let client = RefundProcessorContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let timelock = Address::generate(&env);
+    client.initialize(&admin, &Address::generate(&env));
+
+    assert_eq!(client.get_version().version, 1);
+    client.set_upgrade_authority(&admin, &timelock);
+    assert_eq!(client.get_upgrade_authority(), Some(timelock));
+    assert_eq!(client.try_set_upgrade_authority(&admin, &admin), Err(Ok(RefundError::Unauthorized)));
+    assert_eq!(client.try_migrate(&1), Err(Ok(RefundError::AlreadyMigrated)));
+}
+
+#[test]
+fn test_refund_ttl_bumped_on_access() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, RefundProcessorContract);
+    let client = RefundProcessorContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    let requester = Address::generate(&env);
+    client.initialize(&admin, &token);
+
+    let refund_id = client.request_refund(&requester, &1, &500, &String::from_str(&env, "undelivered"));
+    let ttl = || env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&DataKey::Refund(refund_id)));
+    let age = || {
+        for _ in 0..12 {
+            env.ledger().with_mut(|li| li.sequence_number += 80_000);
+            client.get_version();
+        }
+    };
+    assert_eq!(ttl(), REFUND_TTL.extend_to);
+
+    // Reading a refund keeps it live
+    age();
+    assert!(ttl() < REFUND_TTL.threshold);
+    client.get_refund(&refund_id);
+    assert_eq!(ttl(), REFUND_TTL.extend_to);
+
+    // So does the permissionless maintenance entry point
+    age();
+    assert!(client.extend_ttl_refund(&refund_id));
+    assert_eq!(ttl(), REFUND_TTL.extend_to);
+    assert!(!client.extend_ttl_refund(&(refund_id + 1)));
+}
+
+#[test]
+fn test_approve_refund_checks_campaign_contribution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, RefundProcessorContract);
+    let client = RefundProcessorContractClient::new(&env, &contract_id);
+    let orchestrator_id = env.register_contract(None, MockOrchestrator);
+    let orchestrator = MockOrchestratorClient::new(&env, &orchestrator_id);
+
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    let advertiser = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.initialize(&admin, &token);
+    orchestrator.set_campaign(&1, &advertiser, &1_000);
+
+    let reason = String::from_str(&env, "undelivered");
+    let first = client.request_refund(&advertiser, &1, &600, &reason);
+    assert_eq!(
+        client.try_approve_refund(&admin, &first, &600),
+        Err(Ok(soroban_sdk::Error::from_contract_error(RefundError::OrchestratorNotSet as u32)))
+    );
+    client.set_orchestrator(&admin, &orchestrator_id);
+    assert_eq!(client.get_orchestrator(), Some(orchestrator_id));
+
+    // Unknown campaigns and requesters who didn't fund the campaign
+    let unknown = client.request_refund(&advertiser, &2, &100, &reason);
+    assert_eq!(
+        client.try_approve_refund(&admin, &unknown, &100),
+        Err(Ok(soroban_sdk::Error::from_contract_error(RefundError::CampaignNotFound as u32)))
+    );
+    let outsider = client.request_refund(&stranger, &1, &100, &reason);
+    assert_eq!(
+        client.try_approve_refund(&admin, &outsider, &100),
+        Err(Ok(soroban_sdk::Error::from_contract_error(RefundError::NotContributor as u32)))
+    );
+
+    // Approvals on a campaign can't add up to more than the budget it has
+    // left: 1_000 less 200 already spent on views
+    orchestrator.spend(&1, &200);
+    client.approve_refund(&admin, &first, &600);
+    assert_eq!(client.get_campaign_refunded(&1), 600);
+    let second = client.request_refund(&advertiser, &1, &300, &reason);
+    assert_eq!(
+        client.try_approve_refund(&admin, &second, &300),
+        Err(Ok(soroban_sdk::Error::from_contract_error(RefundError::ExceedsContribution as u32)))
+    );
+    client.approve_refund(&admin, &second, &200);
+    assert_eq!(client.get_refund(&second).unwrap().amount_approved, 200);
+    assert_eq!(client.get_campaign_refunded(&1), 800);
+
+    // An approved refund can no longer be rejected
+    assert_eq!(
+        client.try_reject_refund(&admin, &second),
+        Err(Ok(soroban_sdk::Error::from_contract_error(RefundError::InvalidStatus as u32)))
+    );
+    assert!(client.get_refund(&second).unwrap().status == RefundStatus::Approved);
+}
+
+#[test]
+fn test_authorized_filer_files_for_advertiser() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, RefundProcessorContract);
+    let client = RefundProcessorContractClient::new(&env, &contract_id);
+    let orchestrator_id = env.register_contract(None, MockOrchestrator);
+    let orchestrator = MockOrchestratorClient::new(&env, &orchestrator_id);
+
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    let advertiser = Address::generate(&env);
+    let escrow = Address::generate(&env);
+    client.initialize(&admin, &token);
+    client.set_orchestrator(&admin, &orchestrator_id);
+    orchestrator.set_campaign(&1, &advertiser, &1_000);
+
+    let reason = String::from_str(&env, "impressions were bots");
+    assert_eq!(
+        client.try_request_refund_for(&escrow, &advertiser, &1, &500, &reason),
+        Err(Ok(soroban_sdk::Error::from_contract_error(RefundError::NotFiler as u32)))
+    );
+    client.set_filer(&admin, &escrow, &true);
+    assert!(client.is_filer(&escrow));
+
+    // Checked against the advertiser the filer acts for, not the filer
+    let refund_id = client.request_refund_for(&escrow, &advertiser, &1, &500, &reason);
+    let refund = client.get_refund(&refund_id).unwrap();
+    assert_eq!(refund.requester, escrow);
+    assert_eq!(refund.on_behalf_of, Some(advertiser.clone()));
+    client.approve_refund(&admin, &refund_id, &200);
+    assert_eq!(client.get_refund(&refund_id).unwrap().amount_approved, 200);
+
+    // The filer pays out from the funds it holds
+    assert_eq!(
+        client.try_process_refund(&refund_id),
+        Err(Ok(soroban_sdk::Error::from_contract_error(RefundError::SettledByFiler as u32)))
+    );
+
+    client.set_filer(&admin, &escrow, &false);
+    assert!(client.try_request_refund_for(&escrow, &advertiser, &1, &100, &reason).is_err());
+}
+
+#[test]
+fn test_dependencies_resolved_through_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, RefundProcessorContract);
+    let client = RefundProcessorContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let requester = Address::generate(&env);
+    client.initialize(&admin, &Address::generate(&env));
+
+    let stale = Address::generate(&env);
+    client.set_orchestrator(&admin, &stale);
+    let registry_id = env.register_contract(None, MockRegistry);
+    let registry = MockRegistryClient::new(&env, &registry_id);
+    client.set_registry(&admin, &registry_id);
+    assert_eq!(client.get_orchestrator(), Some(stale));
+
+    // Registry entries win over the addresses set by the admin
+    let orchestrator_id = env.register_contract(None, MockOrchestrator);
+    MockOrchestratorClient::new(&env, &orchestrator_id).set_campaign(&1, &requester, &1_000);
+    registry.set_address(&symbol_short!("orchestr"), &orchestrator_id);
+    assert_eq!(client.get_orchestrator(), Some(orchestrator_id));
+
+    let refund_id = client.request_refund(&requester, &1, &400, &String::from_str(&env, "undelivered"));
+    assert!(client.try_escalate_refund(&requester, &refund_id).is_err());
+    let court_id = env.register_contract(None, MockCourt);
+    registry.set_address(&symbol_short!("arbitr"), &court_id);
+    let case_id = client.escalate_refund(&requester, &refund_id);
+
+    MockCourtClient::new(&env, &court_id).rule(&contract_id, &case_id, &requester, &refund_id, &true);
+    assert!(client.get_refund(&refund_id).unwrap().status == RefundStatus::Approved);
+}
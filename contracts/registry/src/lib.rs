@@ -0,0 +1,208 @@
+//! PulsarTrack - Contract Registry (Soroban)
+//! Service locator mapping well-known keys to PulsarTrack contract addresses.
+//!
+//! Contracts look up their dependencies here at call time instead of baking
+//! addresses in at initialization, so an upgraded contract only needs to be
+//! re-pointed in one place. Well-known keys: "refund", "orchestr", "arbitr",
+//! "token", "dao", "pacing", "analytics", "verifier" and "placement".
+//! Entries are managed by the admin or the DAO.
+//!
+//! Events are published as ("registry", action, version) with a typed payload
+//! (see the Events section):
+//! - "address_set": `AddressSet`
+//! - "address_removed": `AddressRemoved`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    Address, Env, Symbol, Vec,
+};
+pub use pulsartrack_errors::RegistryError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RegistryEntry {
+    pub address: Address,
+    pub version: u32, // bumped each time the key is re-pointed
+    pub updated_at: u64,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AddressSet {
+    pub key: Symbol,
+    pub address: Address,
+    pub version: u32,
+}
+contract_event!(AddressSet, "address_set");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AddressRemoved {
+    pub key: Symbol,
+}
+contract_event!(AddressRemoved, "address_removed");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Dao,
+    Keys,
+    Entry(Symbol),
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const MAX_KEYS: u32 = 100;
+
+const EVENT_TAG: &str = "registry";
+
+#[contract]
+pub struct RegistryContract;
+
+#[contractimpl]
+impl RegistryContract {
+    pub fn initialize(env: Env, admin: Address, dao: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, RegistryError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Dao, &dao);
+        env.storage().instance().set(&DataKey::Keys, &Vec::<Symbol>::new(&env));
+    }
+
+    /// Hand the DAO role to a new address (admin or DAO)
+    pub fn set_dao(env: Env, caller: Address, dao: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_governor(&env, &caller);
+        env.storage().instance().set(&DataKey::Dao, &dao);
+    }
+
+    /// Point `key` at `address` (admin or DAO)
+    pub fn set_address(env: Env, caller: Address, key: Symbol, address: Address) -> u32 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_governor(&env, &caller);
+
+        let _ttl_key = DataKey::Entry(key.clone());
+        let previous: Option<RegistryEntry> = env.storage().persistent().get(&_ttl_key);
+        let version = match previous {
+            Some(entry) => entry.version + 1,
+            None => {
+                let mut keys = Self::_keys(&env);
+                if keys.len() >= MAX_KEYS {
+                    panic_with_error!(&env, RegistryError::TooManyKeys);
+                }
+                keys.push_back(key.clone());
+                env.storage().instance().set(&DataKey::Keys, &keys);
+                1
+            }
+        };
+
+        let entry = RegistryEntry {
+            address: address.clone(),
+            version,
+            updated_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&_ttl_key, &entry);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, AddressSet { key, address, version });
+
+        version
+    }
+
+    pub fn remove_address(env: Env, caller: Address, key: Symbol) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_governor(&env, &caller);
+        let _ttl_key = DataKey::Entry(key.clone());
+        if !env.storage().persistent().has(&_ttl_key) {
+            panic_with_error!(&env, RegistryError::KeyNotRegistered);
+        }
+        env.storage().persistent().remove(&_ttl_key);
+
+        let mut keys = Self::_keys(&env);
+        if let Some(index) = keys.first_index_of(&key) {
+            keys.remove(index);
+            env.storage().instance().set(&DataKey::Keys, &keys);
+        }
+
+        events::publish(&env, EVENT_TAG, AddressRemoved { key });
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    /// Address registered under `key`; panics if there is none
+    pub fn get_address(env: Env, key: Symbol) -> Address {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_entry(&env, &key).unwrap_or_else(|| panic_with_error!(&env, RegistryError::KeyNotRegistered)).address
+    }
+
+    /// Address registered under `key`, if any
+    pub fn lookup(env: Env, key: Symbol) -> Option<Address> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_entry(&env, &key).map(|entry| entry.address)
+    }
+
+    pub fn get_entry(env: Env, key: Symbol) -> Option<RegistryEntry> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_entry(&env, &key)
+    }
+
+    pub fn get_keys(env: Env) -> Vec<Symbol> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_keys(&env)
+    }
+
+    pub fn get_dao(env: Env) -> Address {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::Dao).unwrap()
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _require_governor(env: &Env, caller: &Address) {
+        caller.require_auth();
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        let dao: Address = env.storage().instance().get(&DataKey::Dao).unwrap();
+        if *caller != admin && *caller != dao {
+            panic_with_error!(&env, RegistryError::Unauthorized);
+        }
+    }
+
+    fn _keys(env: &Env) -> Vec<Symbol> {
+        env.storage().instance().get(&DataKey::Keys).unwrap_or(Vec::new(env))
+    }
+
+    fn _entry(env: &Env, key: &Symbol) -> Option<RegistryEntry> {
+        env.storage().persistent().get(&DataKey::Entry(key.clone()))
+    }
+}
+
+mod test;
@@ -0,0 +1,56 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+
+fn setup(env: &Env) -> (RegistryContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, RegistryContract);
+    let client = RegistryContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let dao = Address::generate(env);
+    client.initialize(&admin, &dao);
+    (client, admin, dao)
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, dao) = setup(&env);
+    assert_eq!(client.get_dao(), dao);
+    assert_eq!(client.get_keys().len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4701)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, dao) = setup(&env);
+    client.initialize(&admin, &dao);
+}
+
+#[test]
+fn test_set_lookup_and_repoint() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, dao) = setup(&env);
+    let refund = symbol_short!("refund");
+    let v1 = Address::generate(&env);
+    let v2 = Address::generate(&env);
+
+    assert!(client.lookup(&refund).is_none());
+    assert!(client.try_get_address(&refund).is_err());
+    assert!(client.try_set_address(&v1, &refund, &v1).is_err());
+
+    assert_eq!(client.set_address(&admin, &refund, &v1), 1);
+    assert_eq!(client.get_address(&refund), v1);
+    assert_eq!(client.set_address(&dao, &refund, &v2), 2);
+    assert_eq!(client.lookup(&refund), Some(v2));
+    assert_eq!(client.get_keys().len(), 1);
+
+    client.set_address(&dao, &symbol_short!("token"), &v1);
+    client.remove_address(&dao, &refund);
+    assert!(client.lookup(&refund).is_none());
+    assert!(client.try_remove_address(&dao, &refund).is_err());
+    assert_eq!(client.get_keys(), soroban_sdk::vec![&env, symbol_short!("token")]);
+}
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     token, Address, Env,
 };
+pub use pulsartrack_errors::RevenueSettlementError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone)]
@@ -33,6 +35,14 @@ pub struct SettlementRecord {
     pub settled_at: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct RevenueClaimed {
+    pub publisher: Address,
+    pub amount: i128,
+}
+contract_event!(RevenueClaimed, "revenue_claimed");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -50,6 +60,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "revenue";
+
 #[contract]
 pub struct RevenueSettlementContract;
 
@@ -64,7 +76,7 @@ impl RevenueSettlementContract {
     ) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, RevenueSettlementError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -91,7 +103,7 @@ impl RevenueSettlementContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, RevenueSettlementError::Unauthorized);
         }
 
         let mut pool: RevenuePool = env.storage().instance().get(&DataKey::RevenuePool).unwrap();
@@ -141,7 +153,7 @@ impl RevenueSettlementContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, RevenueSettlementError::Unauthorized);
         }
 
         let mut pool: RevenuePool = env.storage().instance().get(&DataKey::RevenuePool).unwrap();
@@ -172,7 +184,7 @@ impl RevenueSettlementContract {
         let balance: i128 = env.storage().persistent().get(&pub_key).unwrap_or(0);
 
         if balance <= 0 {
-            panic!("no balance to claim");
+            panic_with_error!(&env, RevenueSettlementError::NoBalanceToClaim);
         }
 
         let token_addr: Address = env.storage().instance().get(&DataKey::TokenAddress).unwrap();
@@ -182,15 +194,12 @@ impl RevenueSettlementContract {
         env.storage().persistent().set(&pub_key, &0i128);
         env.storage().persistent().extend_ttl(&pub_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("revenue"), symbol_short!("claimed")),
-            (publisher, balance),
-        );
+        events::publish(&env, EVENT_TAG, RevenueClaimed { publisher, amount: balance });
     }
 
     pub fn get_revenue_pool(env: Env) -> RevenuePool {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        env.storage().instance().get(&DataKey::RevenuePool).expect("not initialized")
+        env.storage().instance().get(&DataKey::RevenuePool).unwrap_or_else(|| panic_with_error!(&env, RevenueSettlementError::NotInitialized))
     }
 
     pub fn get_publisher_balance(env: Env, publisher: Address) -> i128 {
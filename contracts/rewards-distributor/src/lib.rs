@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     token, Address, Env,
 };
+pub use pulsartrack_errors::RewardsDistributorError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone)]
@@ -32,6 +34,22 @@ pub struct UserRewards {
     pub last_earned: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct RewardsEarned {
+    pub recipient: Address,
+    pub amount: i128,
+}
+contract_event!(RewardsEarned, "rewards_earned");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RewardsClaimed {
+    pub user: Address,
+    pub amount: i128,
+}
+contract_event!(RewardsClaimed, "rewards_claimed");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -46,6 +64,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "rewards";
+
 #[contract]
 pub struct RewardsDistributorContract;
 
@@ -54,7 +74,7 @@ impl RewardsDistributorContract {
     pub fn initialize(env: Env, admin: Address, reward_token: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, RewardsDistributorError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -74,7 +94,7 @@ impl RewardsDistributorContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, RewardsDistributorError::Unauthorized);
         }
 
         let counter: u32 = env.storage().instance().get(&DataKey::ProgramCounter).unwrap_or(0);
@@ -105,25 +125,25 @@ impl RewardsDistributorContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, RewardsDistributorError::Unauthorized);
         }
 
         let mut program: RewardProgram = env
             .storage()
             .persistent()
             .get(&DataKey::Program(program_id))
-            .expect("program not found");
+            .unwrap_or_else(|| panic_with_error!(&env, RewardsDistributorError::ProgramNotFound));
 
         if !program.is_active {
-            panic!("program not active");
+            panic_with_error!(&env, RewardsDistributorError::ProgramNotActive);
         }
 
         if program.distributed + amount > program.total_budget {
-            panic!("exceeds budget");
+            panic_with_error!(&env, RewardsDistributorError::ExceedsBudget);
         }
 
         if env.ledger().sequence() > program.end_ledger {
-            panic!("program ended");
+            panic_with_error!(&env, RewardsDistributorError::ProgramEnded);
         }
 
         program.distributed += amount;
@@ -151,10 +171,7 @@ impl RewardsDistributorContract {
         env.storage().persistent().set(&key, &rewards);
         env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("rewards"), symbol_short!("earned")),
-            (recipient, amount),
-        );
+        events::publish(&env, EVENT_TAG, RewardsEarned { recipient, amount });
     }
 
     pub fn claim_rewards(env: Env, user: Address) -> i128 {
@@ -166,11 +183,11 @@ impl RewardsDistributorContract {
             .storage()
             .persistent()
             .get(&key)
-            .expect("no rewards");
+            .unwrap_or_else(|| panic_with_error!(&env, RewardsDistributorError::NoRewards));
 
         let pending = rewards.pending;
         if pending <= 0 {
-            panic!("no pending rewards");
+            panic_with_error!(&env, RewardsDistributorError::NoPendingRewards);
         }
 
         let token: Address = env.storage().instance().get(&DataKey::RewardToken).unwrap();
@@ -182,10 +199,7 @@ impl RewardsDistributorContract {
         env.storage().persistent().set(&key, &rewards);
         env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("rewards"), symbol_short!("claimed")),
-            (user, pending),
-        );
+        events::publish(&env, EVENT_TAG, RewardsClaimed { user, amount: pending });
 
         pending
     }
@@ -0,0 +1,321 @@
+//! PulsarTrack - Revenue Splitter (Soroban)
+//! Basis-point revenue splits for publisher payouts on Stellar.
+//!
+//! A split names its recipients and their shares (summing to 10_000 bps).
+//! Payouts deposited into a split are allocated to the recipients at once
+//! and each recipient claims their balance per token whenever they like.
+//! Mutable splits can be re-shared by their owner, which only affects later
+//! deposits; immutable splits are fixed from creation, and a mutable split
+//! can be locked for good.
+//!
+//! Events are published as ("splitter", action, version) with a typed payload
+//! (see the Events section):
+//! - "split_created": `SplitCreated`
+//! - "split_updated": `SplitUpdated`
+//! - "split_locked": `SplitLocked`
+//! - "split_deposited": `SplitDeposited`
+//! - "split_claimed": `SplitClaimed`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    token, Address, Env, Vec,
+};
+pub use pulsartrack_errors::SplitterError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Split {
+    pub owner: Address,
+    pub recipients: Vec<Address>,
+    pub shares_bps: Vec<u32>, // parallel to `recipients`
+    pub mutable: bool,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SplitCreated {
+    pub split_id: u64,
+    pub owner: Address,
+    pub mutable: bool,
+}
+contract_event!(SplitCreated, "split_created");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SplitUpdated {
+    pub split_id: u64,
+    pub recipients: u32,
+}
+contract_event!(SplitUpdated, "split_updated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SplitLocked {
+    pub split_id: u64,
+}
+contract_event!(SplitLocked, "split_locked");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SplitDeposited {
+    pub split_id: u64,
+    pub token: Address,
+    pub amount: i128,
+}
+contract_event!(SplitDeposited, "split_deposited");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SplitClaimed {
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+contract_event!(SplitClaimed, "split_claimed");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    SplitCounter,
+    Split(u64),
+    Distributed(u64, Address),  // split_id, token
+    Claimable(Address, Address), // recipient, token
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const TOTAL_BPS: u32 = 10_000;
+const MAX_RECIPIENTS: u32 = 20;
+
+const EVENT_TAG: &str = "splitter";
+
+#[contract]
+pub struct SplitterContract;
+
+#[contractimpl]
+impl SplitterContract {
+    pub fn create_split(
+        env: Env,
+        owner: Address,
+        recipients: Vec<Address>,
+        shares_bps: Vec<u32>,
+        mutable: bool,
+    ) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        owner.require_auth();
+        Self::_validate_shares(&recipients, &shares_bps);
+
+        let counter: u64 = env.storage().instance().get(&DataKey::SplitCounter).unwrap_or(0);
+        let split_id = counter + 1;
+        let now = env.ledger().timestamp();
+        let split = Split {
+            owner: owner.clone(),
+            recipients,
+            shares_bps,
+            mutable,
+            created_at: now,
+            updated_at: now,
+        };
+        Self::_save_split(&env, split_id, &split);
+        env.storage().instance().set(&DataKey::SplitCounter, &split_id);
+
+        events::publish(&env, EVENT_TAG, SplitCreated { split_id, owner, mutable });
+
+        split_id
+    }
+
+    /// Replace the recipients and shares of a mutable split (owner only).
+    /// Balances already allocated are unaffected.
+    pub fn update_split(env: Env, owner: Address, split_id: u64, recipients: Vec<Address>, shares_bps: Vec<u32>) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let mut split = Self::_load_owned(&env, &owner, split_id);
+        if !split.mutable {
+            panic_with_error!(&env, SplitterError::SplitIsImmutable);
+        }
+        Self::_validate_shares(&recipients, &shares_bps);
+
+        let count = recipients.len();
+        split.recipients = recipients;
+        split.shares_bps = shares_bps;
+        split.updated_at = env.ledger().timestamp();
+        Self::_save_split(&env, split_id, &split);
+
+        events::publish(&env, EVENT_TAG, SplitUpdated { split_id, recipients: count });
+    }
+
+    /// Permanently fix a mutable split's shares (owner only)
+    pub fn lock_split(env: Env, owner: Address, split_id: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let mut split = Self::_load_owned(&env, &owner, split_id);
+        if !split.mutable {
+            panic_with_error!(&env, SplitterError::SplitIsImmutable);
+        }
+        split.mutable = false;
+        split.updated_at = env.ledger().timestamp();
+        Self::_save_split(&env, split_id, &split);
+
+        events::publish(&env, EVENT_TAG, SplitLocked { split_id });
+    }
+
+    /// Pay `amount` of `token` into a split and allocate it to the
+    /// recipients. Rounding dust goes to the first recipient.
+    pub fn deposit(env: Env, from: Address, split_id: u64, token: Address, amount: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        from.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&env, SplitterError::InvalidAmount);
+        }
+        let split = Self::_load_split(&env, split_id);
+        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+
+        // Everyone after the first gets their exact share; the first
+        // recipient takes what's left, dust included
+        let mut remaining = amount;
+        for i in 1..split.recipients.len() {
+            let share = amount * split.shares_bps.get(i).unwrap() as i128 / TOTAL_BPS as i128;
+            Self::_credit(&env, &split.recipients.get(i).unwrap(), &token, share);
+            remaining -= share;
+        }
+        Self::_credit(&env, &split.recipients.get(0).unwrap(), &token, remaining);
+
+        let _ttl_key = DataKey::Distributed(split_id, token.clone());
+        let distributed: i128 = env.storage().persistent().get(&_ttl_key).unwrap_or(0);
+        env.storage().persistent().set(&_ttl_key, &(distributed + amount));
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        events::publish(&env, EVENT_TAG, SplitDeposited { split_id, token, amount });
+    }
+
+    /// Withdraw everything allocated to `recipient` in `token`, across all
+    /// splits. Returns the amount paid.
+    pub fn claim(env: Env, recipient: Address, token: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        recipient.require_auth();
+        let key = DataKey::Claimable(recipient.clone(), token.clone());
+        let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if amount <= 0 {
+            panic_with_error!(&env, SplitterError::NothingToClaim);
+        }
+        env.storage().persistent().remove(&key);
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &recipient, &amount);
+
+        events::publish(&env, EVENT_TAG, SplitClaimed { recipient, token, amount });
+
+        amount
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn get_split(env: Env, split_id: u64) -> Option<Split> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Split(split_id))
+    }
+
+    pub fn get_claimable(env: Env, recipient: Address, token: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::Claimable(recipient, token))
+            .unwrap_or(0)
+    }
+
+    /// Total ever deposited into a split in `token`
+    pub fn get_distributed(env: Env, split_id: u64, token: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::Distributed(split_id, token))
+            .unwrap_or(0)
+    }
+
+    pub fn get_split_count(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::SplitCounter).unwrap_or(0)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _validate_shares(recipients: &Vec<Address>, shares_bps: &Vec<u32>) {
+        if recipients.is_empty() || recipients.len() > MAX_RECIPIENTS {
+            panic_with_error!(recipients.env(), SplitterError::InvalidRecipients);
+        }
+        if recipients.len() != shares_bps.len() {
+            panic_with_error!(recipients.env(), SplitterError::LengthMismatch);
+        }
+        let mut total: u32 = 0;
+        for (i, recipient) in recipients.iter().enumerate() {
+            let bps = shares_bps.get(i as u32).unwrap();
+            if bps == 0 {
+                panic_with_error!(recipients.env(), SplitterError::ZeroShare);
+            }
+            if recipients.first_index_of(&recipient) != Some(i as u32) {
+                panic_with_error!(recipients.env(), SplitterError::DuplicateRecipient);
+            }
+            total += bps;
+        }
+        if total != TOTAL_BPS {
+            panic_with_error!(recipients.env(), SplitterError::SharesMustTotal10000Bps);
+        }
+    }
+
+    fn _load_split(env: &Env, split_id: u64) -> Split {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Split(split_id))
+            .unwrap_or_else(|| panic_with_error!(&env, SplitterError::SplitNotFound))
+    }
+
+    fn _load_owned(env: &Env, owner: &Address, split_id: u64) -> Split {
+        owner.require_auth();
+        let split = Self::_load_split(env, split_id);
+        if split.owner != *owner {
+            panic_with_error!(&env, SplitterError::Unauthorized);
+        }
+        split
+    }
+
+    fn _save_split(env: &Env, split_id: u64, split: &Split) {
+        let _ttl_key = DataKey::Split(split_id);
+        env.storage().persistent().set(&_ttl_key, split);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _credit(env: &Env, recipient: &Address, token: &Address, amount: i128) {
+        if amount <= 0 {
+            return;
+        }
+        let _ttl_key = DataKey::Claimable(recipient.clone(), token.clone());
+        let balance: i128 = env.storage().persistent().get(&_ttl_key).unwrap_or(0);
+        env.storage().persistent().set(&_ttl_key, &(balance + amount));
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+}
+
+mod test;
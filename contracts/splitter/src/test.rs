@@ -0,0 +1,96 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+fn setup(env: &Env) -> (SplitterContractClient<'_>, Address) {
+    let contract_id = env.register_contract(None, SplitterContract);
+    let client = SplitterContractClient::new(env, &contract_id);
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    (client, token)
+}
+
+#[test]
+fn test_create_split_validates_shares() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token) = setup(&env);
+    let owner = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+
+    assert!(client.try_create_split(&owner, &vec![&env, a.clone(), b.clone()], &vec![&env, 5_000, 4_000], &true).is_err());
+    assert!(client.try_create_split(&owner, &vec![&env, a.clone(), b.clone()], &vec![&env, 10_000], &true).is_err());
+    assert!(client.try_create_split(&owner, &vec![&env, a.clone(), a.clone()], &vec![&env, 5_000, 5_000], &true).is_err());
+    assert!(client.try_create_split(&owner, &vec![&env, a.clone(), b.clone()], &vec![&env, 10_000, 0], &true).is_err());
+
+    let split_id = client.create_split(&owner, &vec![&env, a, b], &vec![&env, 7_000, 3_000], &false);
+    assert_eq!(split_id, 1);
+    assert_eq!(client.get_split_count(), 1);
+    assert!(!client.get_split(&split_id).unwrap().mutable);
+}
+
+#[test]
+fn test_deposit_allocates_and_recipients_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, token) = setup(&env);
+    let balance = token::Client::new(&env, &token);
+    let owner = Address::generate(&env);
+    let site = Address::generate(&env);
+    let network = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &10_000);
+
+    let split_id = client.create_split(
+        &owner,
+        &vec![&env, site.clone(), network.clone(), referrer.clone()],
+        &vec![&env, 6_000, 3_333, 667],
+        &false,
+    );
+    client.deposit(&payer, &split_id, &token, &1_001);
+
+    // 333 and 66 after rounding; the site owner takes the rest
+    assert_eq!(client.get_claimable(&network, &token), 333);
+    assert_eq!(client.get_claimable(&referrer, &token), 66);
+    assert_eq!(client.get_claimable(&site, &token), 602);
+    assert_eq!(client.get_distributed(&split_id, &token), 1_001);
+
+    assert_eq!(client.claim(&network, &token), 333);
+    assert_eq!(balance.balance(&network), 333);
+    assert!(client.try_claim(&network, &token).is_err());
+
+    client.deposit(&payer, &split_id, &token, &3_000);
+    assert_eq!(client.claim(&site, &token), 602 + 1_801);
+    assert_eq!(balance.balance(&client.address), 66 + 999 + 200);
+}
+
+#[test]
+fn test_mutable_split_updates_only_future_deposits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, token) = setup(&env);
+    let owner = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &10_000);
+
+    let fixed = client.create_split(&owner, &vec![&env, a.clone()], &vec![&env, 10_000], &false);
+    assert!(client.try_update_split(&owner, &fixed, &vec![&env, b.clone()], &vec![&env, 10_000]).is_err());
+
+    let split_id = client.create_split(&owner, &vec![&env, a.clone()], &vec![&env, 10_000], &true);
+    client.deposit(&payer, &split_id, &token, &1_000);
+
+    let stranger = Address::generate(&env);
+    assert!(client.try_update_split(&stranger, &split_id, &vec![&env, b.clone()], &vec![&env, 10_000]).is_err());
+    client.update_split(&owner, &split_id, &vec![&env, a.clone(), b.clone()], &vec![&env, 5_000, 5_000]);
+    client.deposit(&payer, &split_id, &token, &1_000);
+    assert_eq!(client.get_claimable(&a, &token), 1_500);
+    assert_eq!(client.get_claimable(&b, &token), 500);
+
+    client.lock_split(&owner, &split_id);
+    assert!(client.try_update_split(&owner, &split_id, &vec![&env, b.clone()], &vec![&env, 10_000]).is_err());
+    assert!(client.try_lock_split(&owner, &split_id).is_err());
+}
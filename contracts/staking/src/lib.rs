@@ -0,0 +1,443 @@
+//! PulsarTrack - Publisher Staking (Soroban)
+//! PULSAR quality bonds that unlock publisher tiers and campaign volume on Stellar.
+//!
+//! Publishers stake PULSAR to reach a tier, each tier capping the campaign
+//! volume they may take on. Stake leaves through an unbonding period during
+//! which it can still be slashed by the reputation oracle for confirmed
+//! fraud. Stakers share rewards paid into the pool pro rata to active stake.
+//!
+//! Events are published as ("staking", action, version) with a typed payload
+//! (see the Events section):
+//! - "staked": `Staked`
+//! - "unbond_requested": `UnbondRequested`
+//! - "unbonded_withdrawn": `UnbondedWithdrawn`
+//! - "stake_slashed": `StakeSlashed`
+//! - "rewards_funded": `RewardsFunded`
+//! - "rewards_claimed": `RewardsClaimed`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    token, Address, BytesN, Env, Vec,
+};
+pub use pulsartrack_errors::StakingError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+/// Stake needed for a tier and the campaign volume it unlocks
+#[contracttype]
+#[derive(Clone)]
+pub struct StakeTier {
+    pub min_stake: i128,
+    pub max_campaign_volume: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Default)]
+pub struct StakeInfo {
+    pub staked: i128,
+    pub unbonding: i128,
+    pub release_at: u64,     // when the unbonding amount can be withdrawn
+    pub reward_debt: i128,   // accumulator value already accounted for
+    pub pending_rewards: i128,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Staked {
+    pub publisher: Address,
+    pub amount: i128,
+}
+contract_event!(Staked, "staked");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct UnbondRequested {
+    pub publisher: Address,
+    pub amount: i128,
+    pub release_at: u64,
+}
+contract_event!(UnbondRequested, "unbond_requested");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct UnbondedWithdrawn {
+    pub publisher: Address,
+    pub amount: i128,
+}
+contract_event!(UnbondedWithdrawn, "unbonded_withdrawn");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct StakeSlashed {
+    pub publisher: Address,
+    pub amount: i128,
+    pub evidence_hash: BytesN<32>,
+}
+contract_event!(StakeSlashed, "stake_slashed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RewardsFunded {
+    pub funder: Address,
+    pub amount: i128,
+}
+contract_event!(RewardsFunded, "rewards_funded");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RewardsClaimed {
+    pub publisher: Address,
+    pub amount: i128,
+}
+contract_event!(RewardsClaimed, "rewards_claimed");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    StakeToken,
+    Slasher,
+    SlashRecipient,
+    UnbondingPeriod,
+    Tiers,
+    TotalStaked,
+    RewardPerShare,
+    Stake(Address),
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const REWARD_PRECISION: i128 = 1_000_000_000;
+const MAX_TIERS: u32 = 10;
+
+const EVENT_TAG: &str = "staking";
+
+#[contract]
+pub struct StakingContract;
+
+#[contractimpl]
+impl StakingContract {
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        stake_token: Address,
+        slasher: Address,        // reputation oracle
+        slash_recipient: Address,
+        unbonding_secs: u64,
+    ) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, StakingError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::StakeToken, &stake_token);
+        env.storage().instance().set(&DataKey::Slasher, &slasher);
+        env.storage().instance().set(&DataKey::SlashRecipient, &slash_recipient);
+        env.storage().instance().set(&DataKey::UnbondingPeriod, &unbonding_secs);
+        env.storage().instance().set(&DataKey::TotalStaked, &0i128);
+        env.storage().instance().set(&DataKey::RewardPerShare, &0i128);
+    }
+
+    /// Replace the tier table; tiers must be in ascending order of stake
+    /// (admin only)
+    pub fn set_tiers(env: Env, admin: Address, tiers: Vec<StakeTier>) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        if tiers.len() > MAX_TIERS {
+            panic_with_error!(&env, StakingError::TooManyTiers);
+        }
+        let mut previous: Option<StakeTier> = None;
+        for tier in tiers.iter() {
+            if tier.min_stake <= 0 || tier.max_campaign_volume < 0 {
+                panic_with_error!(&env, StakingError::InvalidTier);
+            }
+            if let Some(prev) = previous {
+                if tier.min_stake <= prev.min_stake || tier.max_campaign_volume < prev.max_campaign_volume {
+                    panic_with_error!(&env, StakingError::TiersNotAscending);
+                }
+            }
+            previous = Some(tier);
+        }
+        env.storage().instance().set(&DataKey::Tiers, &tiers);
+    }
+
+    pub fn set_unbonding_period(env: Env, admin: Address, unbonding_secs: u64) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::UnbondingPeriod, &unbonding_secs);
+    }
+
+    pub fn set_slasher(env: Env, admin: Address, slasher: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Slasher, &slasher);
+    }
+
+    pub fn stake(env: Env, publisher: Address, amount: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&env, StakingError::InvalidAmount);
+        }
+
+        let stake_token: Address = env.storage().instance().get(&DataKey::StakeToken).unwrap();
+        token::Client::new(&env, &stake_token).transfer(&publisher, &env.current_contract_address(), &amount);
+
+        let mut info = Self::_accrue(&env, &publisher);
+        info.staked += amount;
+        Self::_save_stake(&env, &publisher, &info);
+        Self::_adjust_total(&env, amount);
+
+        events::publish(&env, EVENT_TAG, Staked { publisher, amount });
+    }
+
+    /// Start unbonding part of the stake. It stops earning rewards and
+    /// counting toward the tier immediately, but stays slashable until
+    /// withdrawn. A new request restarts the unbonding clock.
+    pub fn request_unbond(env: Env, publisher: Address, amount: i128) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+
+        let mut info = Self::_accrue(&env, &publisher);
+        if amount <= 0 || amount > info.staked {
+            panic_with_error!(&env, StakingError::InvalidAmount);
+        }
+        let period: u64 = env.storage().instance().get(&DataKey::UnbondingPeriod).unwrap_or(0);
+        let release_at = env.ledger().timestamp() + period;
+
+        info.staked -= amount;
+        info.unbonding += amount;
+        info.release_at = release_at;
+        Self::_save_stake(&env, &publisher, &info);
+        Self::_adjust_total(&env, -amount);
+
+        events::publish(&env, EVENT_TAG, UnbondRequested { publisher, amount, release_at });
+
+        release_at
+    }
+
+    /// Withdraw stake whose unbonding period has elapsed
+    pub fn withdraw_unbonded(env: Env, publisher: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+
+        let mut info = Self::_accrue(&env, &publisher);
+        if info.unbonding <= 0 {
+            panic_with_error!(&env, StakingError::NothingUnbonding);
+        }
+        if env.ledger().timestamp() < info.release_at {
+            panic_with_error!(&env, StakingError::StillUnbonding);
+        }
+
+        let amount = info.unbonding;
+        info.unbonding = 0;
+        Self::_save_stake(&env, &publisher, &info);
+
+        let stake_token: Address = env.storage().instance().get(&DataKey::StakeToken).unwrap();
+        token::Client::new(&env, &stake_token).transfer(&env.current_contract_address(), &publisher, &amount);
+
+        events::publish(&env, EVENT_TAG, UnbondedWithdrawn { publisher, amount });
+
+        amount
+    }
+
+    /// Slash up to `amount` for confirmed fraud, taking active stake first
+    /// and then unbonding stake (slasher only). Returns the amount slashed.
+    pub fn slash(env: Env, slasher: Address, publisher: Address, amount: i128, evidence_hash: BytesN<32>) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        slasher.require_auth();
+        let stored_slasher: Address = env.storage().instance().get(&DataKey::Slasher).unwrap();
+        if slasher != stored_slasher {
+            panic_with_error!(&env, StakingError::Unauthorized);
+        }
+        if amount <= 0 {
+            panic_with_error!(&env, StakingError::InvalidAmount);
+        }
+
+        let mut info = Self::_accrue(&env, &publisher);
+        let from_active = amount.min(info.staked);
+        let from_unbonding = (amount - from_active).min(info.unbonding);
+        let slashed = from_active + from_unbonding;
+        if slashed == 0 {
+            panic_with_error!(&env, StakingError::NothingToSlash);
+        }
+
+        info.staked -= from_active;
+        info.unbonding -= from_unbonding;
+        Self::_save_stake(&env, &publisher, &info);
+        Self::_adjust_total(&env, -from_active);
+
+        let stake_token: Address = env.storage().instance().get(&DataKey::StakeToken).unwrap();
+        let recipient: Address = env.storage().instance().get(&DataKey::SlashRecipient).unwrap();
+        token::Client::new(&env, &stake_token).transfer(&env.current_contract_address(), &recipient, &slashed);
+
+        events::publish(&env, EVENT_TAG, StakeSlashed { publisher, amount: slashed, evidence_hash });
+
+        slashed
+    }
+
+    /// Pay rewards into the pool, shared among current stakers pro rata
+    pub fn fund_rewards(env: Env, funder: Address, amount: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        funder.require_auth();
+        if amount <= 0 {
+            panic_with_error!(&env, StakingError::InvalidAmount);
+        }
+        let total: i128 = env.storage().instance().get(&DataKey::TotalStaked).unwrap_or(0);
+        if total == 0 {
+            panic_with_error!(&env, StakingError::NoStakers);
+        }
+
+        let stake_token: Address = env.storage().instance().get(&DataKey::StakeToken).unwrap();
+        token::Client::new(&env, &stake_token).transfer(&funder, &env.current_contract_address(), &amount);
+
+        let per_share: i128 = env.storage().instance().get(&DataKey::RewardPerShare).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::RewardPerShare, &(per_share + amount * REWARD_PRECISION / total));
+
+        events::publish(&env, EVENT_TAG, RewardsFunded { funder, amount });
+    }
+
+    pub fn claim_rewards(env: Env, publisher: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        publisher.require_auth();
+
+        let mut info = Self::_accrue(&env, &publisher);
+        let amount = info.pending_rewards;
+        if amount <= 0 {
+            panic_with_error!(&env, StakingError::NothingToClaim);
+        }
+        info.pending_rewards = 0;
+        Self::_save_stake(&env, &publisher, &info);
+
+        let stake_token: Address = env.storage().instance().get(&DataKey::StakeToken).unwrap();
+        token::Client::new(&env, &stake_token).transfer(&env.current_contract_address(), &publisher, &amount);
+
+        events::publish(&env, EVENT_TAG, RewardsClaimed { publisher, amount });
+
+        amount
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn get_stake(env: Env, publisher: Address) -> StakeInfo {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_load_stake(&env, &publisher)
+    }
+
+    pub fn pending_rewards(env: Env, publisher: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let info = Self::_load_stake(&env, &publisher);
+        info.pending_rewards + Self::_earned(&env, &info)
+    }
+
+    /// Highest tier the active stake qualifies for, 1-based; 0 means none
+    pub fn get_tier(env: Env, publisher: Address) -> u32 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let staked = Self::_load_stake(&env, &publisher).staked;
+        let mut level = 0;
+        for (index, tier) in Self::get_tiers(env.clone()).iter().enumerate() {
+            if staked >= tier.min_stake {
+                level = index as u32 + 1;
+            }
+        }
+        level
+    }
+
+    /// Campaign volume the publisher's tier allows; 0 below the first tier
+    pub fn get_campaign_volume_cap(env: Env, publisher: Address) -> i128 {
+        let level = Self::get_tier(env.clone(), publisher);
+        if level == 0 {
+            return 0;
+        }
+        Self::get_tiers(env).get(level - 1).unwrap().max_campaign_volume
+    }
+
+    pub fn get_tiers(env: Env) -> Vec<StakeTier> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::Tiers).unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_total_staked(env: Env) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::TotalStaked).unwrap_or(0)
+    }
+
+    pub fn get_unbonding_period(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::UnbondingPeriod).unwrap_or(0)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _require_admin(env: &Env, admin: &Address) {
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *admin != stored_admin {
+            panic_with_error!(&env, StakingError::Unauthorized);
+        }
+    }
+
+    fn _load_stake(env: &Env, publisher: &Address) -> StakeInfo {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Stake(publisher.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Store a stake accrued with `_accrue`, re-basing its reward debt on
+    /// the (possibly changed) active stake
+    fn _save_stake(env: &Env, publisher: &Address, info: &StakeInfo) {
+        let per_share: i128 = env.storage().instance().get(&DataKey::RewardPerShare).unwrap_or(0);
+        let mut info = info.clone();
+        info.reward_debt = info.staked * per_share / REWARD_PRECISION;
+        let _ttl_key = DataKey::Stake(publisher.clone());
+        env.storage().persistent().set(&_ttl_key, &info);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _adjust_total(env: &Env, delta: i128) {
+        let total: i128 = env.storage().instance().get(&DataKey::TotalStaked).unwrap_or(0);
+        env.storage().instance().set(&DataKey::TotalStaked, &(total + delta));
+    }
+
+    /// Rewards earned by the active stake since the last accrual
+    fn _earned(env: &Env, info: &StakeInfo) -> i128 {
+        let per_share: i128 = env.storage().instance().get(&DataKey::RewardPerShare).unwrap_or(0);
+        info.staked * per_share / REWARD_PRECISION - info.reward_debt
+    }
+
+    /// Move earned rewards into `pending_rewards` before the stake changes
+    fn _accrue(env: &Env, publisher: &Address) -> StakeInfo {
+        let mut info = Self::_load_stake(env, publisher);
+        info.pending_rewards += Self::_earned(env, &info);
+        info
+    }
+}
+
+mod test;
@@ -0,0 +1,117 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env,
+};
+
+fn setup(env: &Env) -> (StakingContractClient<'_>, Address, Address, Address, Address) {
+    let contract_id = env.register_contract(None, StakingContract);
+    let client = StakingContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let slasher = Address::generate(env);
+    let treasury = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    client.initialize(&admin, &token, &slasher, &treasury, &604_800);
+    (client, admin, token, slasher, treasury)
+}
+
+fn funded(env: &Env, token: &Address, amount: i128) -> Address {
+    let account = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&account, &amount);
+    account
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _token, _slasher, _treasury) = setup(&env);
+    assert_eq!(client.get_total_staked(), 0);
+    assert_eq!(client.get_unbonding_period(), 604_800);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5101)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token, slasher, treasury) = setup(&env);
+    client.initialize(&admin, &token, &slasher, &treasury, &0);
+}
+
+#[test]
+fn test_tiers_unbonding_and_slashing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, token, slasher, treasury) = setup(&env);
+    let balance = token::Client::new(&env, &token);
+
+    let tiers = vec![
+        &env,
+        StakeTier { min_stake: 1_000, max_campaign_volume: 10_000 },
+        StakeTier { min_stake: 5_000, max_campaign_volume: 100_000 },
+    ];
+    client.set_tiers(&admin, &tiers);
+    let unordered = vec![
+        &env,
+        StakeTier { min_stake: 5_000, max_campaign_volume: 100_000 },
+        StakeTier { min_stake: 1_000, max_campaign_volume: 10_000 },
+    ];
+    assert!(client.try_set_tiers(&admin, &unordered).is_err());
+
+    let publisher = funded(&env, &token, 10_000);
+    client.stake(&publisher, &6_000);
+    assert_eq!(client.get_tier(&publisher), 2);
+    assert_eq!(client.get_campaign_volume_cap(&publisher), 100_000);
+
+    // Unbonding stake stops counting toward the tier right away
+    client.request_unbond(&publisher, &2_000);
+    assert_eq!(client.get_tier(&publisher), 1);
+    assert_eq!(client.get_total_staked(), 4_000);
+    assert!(client.try_withdraw_unbonded(&publisher).is_err());
+
+    // ...but is still on the hook for fraud confirmed before it leaves
+    let evidence = BytesN::from_array(&env, &[3u8; 32]);
+    assert!(client.try_slash(&publisher, &publisher, &100, &evidence).is_err());
+    assert_eq!(client.slash(&slasher, &publisher, &5_000, &evidence), 5_000);
+    let info = client.get_stake(&publisher);
+    assert_eq!(info.staked, 0);
+    assert_eq!(info.unbonding, 1_000);
+    assert_eq!(balance.balance(&treasury), 5_000);
+    assert_eq!(client.get_tier(&publisher), 0);
+
+    env.ledger().with_mut(|li| li.timestamp += 604_800);
+    assert_eq!(client.withdraw_unbonded(&publisher), 1_000);
+    assert_eq!(balance.balance(&publisher), 5_000);
+}
+
+#[test]
+fn test_rewards_shared_pro_rata() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, token, _slasher, _treasury) = setup(&env);
+
+    let alice = funded(&env, &token, 10_000);
+    let bob = funded(&env, &token, 10_000);
+    let funder = funded(&env, &token, 10_000);
+    assert!(client.try_fund_rewards(&funder, &100).is_err());
+
+    client.stake(&alice, &1_000);
+    client.stake(&bob, &3_000);
+    client.fund_rewards(&funder, &400);
+    assert_eq!(client.pending_rewards(&alice), 100);
+    assert_eq!(client.pending_rewards(&bob), 300);
+
+    // Alice's unbonding stake earns nothing from the next round
+    client.request_unbond(&alice, &1_000);
+    client.fund_rewards(&funder, &300);
+    assert_eq!(client.pending_rewards(&alice), 100);
+    assert_eq!(client.pending_rewards(&bob), 600);
+
+    assert_eq!(client.claim_rewards(&alice), 100);
+    assert_eq!(client.claim_rewards(&bob), 600);
+    assert!(client.try_claim_rewards(&bob).is_err());
+    assert_eq!(token::Client::new(&env, &token).balance(&bob), 7_600);
+}
@@ -3,9 +3,10 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error, symbol_short,
     Address, Env, String,
 };
+pub use pulsartrack_errors::SubscriptionBenefitsError;
 
 #[contracttype]
 #[derive(Clone)]
@@ -49,7 +50,7 @@ impl SubscriptionBenefitsContract {
     pub fn initialize(env: Env, admin: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, SubscriptionBenefitsError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -67,7 +68,7 @@ impl SubscriptionBenefitsContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, SubscriptionBenefitsError::Unauthorized);
         }
 
         let counter: u32 = env.storage().instance().get(&DataKey::BenefitCounter).unwrap_or(0);
@@ -103,7 +104,7 @@ impl SubscriptionBenefitsContract {
         subscriber.require_auth();
 
         if !Self::check_benefit_access(env.clone(), subscriber.clone(), benefit_id, subscriber_tier) {
-            panic!("access denied");
+            panic_with_error!(&env, SubscriptionBenefitsError::AccessDenied);
         }
 
         let now = env.ledger().timestamp();
@@ -125,7 +126,7 @@ impl SubscriptionBenefitsContract {
         }
 
         if usage.uses_this_period >= usage.max_uses_per_period {
-            panic!("usage limit reached");
+            panic_with_error!(&env, SubscriptionBenefitsError::UsageLimitReached);
         }
 
         usage.uses_this_period += 1;
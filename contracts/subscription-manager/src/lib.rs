@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     token, Address, Env, String,
 };
+pub use pulsartrack_errors::SubscriptionManagerError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -44,6 +46,14 @@ pub struct Subscription {
     pub impressions_used: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct Subscribed {
+    pub subscriber: Address,
+    pub amount: i128,
+}
+contract_event!(Subscribed, "subscribed");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -58,6 +68,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "subscriptions";
+
 #[contract]
 pub struct SubscriptionManagerContract;
 
@@ -66,7 +78,7 @@ impl SubscriptionManagerContract {
     pub fn initialize(env: Env, admin: Address, token: Address, treasury: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, SubscriptionManagerError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -91,7 +103,7 @@ impl SubscriptionManagerContract {
             .storage()
             .persistent()
             .get(&DataKey::Plan(tier.clone()))
-            .expect("plan not found");
+            .unwrap_or_else(|| panic_with_error!(&env, SubscriptionManagerError::PlanNotFound));
 
         let amount = if is_annual {
             plan.price_annual
@@ -127,10 +139,7 @@ impl SubscriptionManagerContract {
         env.storage().persistent().set(&_ttl_key, &sub);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("sub"), symbol_short!("subbed")),
-            (subscriber, amount),
-        );
+        events::publish(&env, EVENT_TAG, Subscribed { subscriber, amount });
     }
 
     pub fn cancel_subscription(env: Env, subscriber: Address) {
@@ -141,7 +150,7 @@ impl SubscriptionManagerContract {
             .storage()
             .persistent()
             .get(&DataKey::Subscription(subscriber.clone()))
-            .expect("subscription not found");
+            .unwrap_or_else(|| panic_with_error!(&env, SubscriptionManagerError::SubscriptionNotFound));
 
         sub.auto_renew = false;
         let _ttl_key = DataKey::Subscription(subscriber);
@@ -0,0 +1,433 @@
+//! PulsarTrack - Subscription Billing (Soroban)
+//! Recurring advertiser plan billing in USDC on Stellar.
+//!
+//! Advertisers subscribe to a monthly or quarterly plan and approve this
+//! contract to pull from their USDC balance, capped at a `max_price` they
+//! choose. Any keeper may call `charge` once a period is due. A pull that
+//! fails (no allowance, no balance, or a plan price above the cap) is a
+//! missed payment: the subscription drops to `PastDue` and is retried after
+//! a grace period, and is `Suspended` after `MAX_MISSED_PAYMENTS` misses in
+//! a row. A later successful charge restores `Active` service.
+//!
+//! Plan revenue is forwarded whole to the treasury through the fee manager's
+//! `collect_revenue`, under the `subscr` operation.
+//!
+//! Events are published as ("subscription", action, version) with a typed
+//! payload (see the Events section):
+//! - "plan_created": `PlanCreated`
+//! - "plan_updated": `PlanUpdated`
+//! - "subscription_started": `SubscriptionStarted`
+//! - "payment_missed": `PaymentMissed`
+//! - "subscription_cancelled": `SubscriptionCancelled`
+//! - "subscription_charged": `SubscriptionCharged`
+
+
+#![no_std]
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractimpl, contracttype, panic_with_error, symbol_short,
+    token, vec, Address, Env, IntoVal, String, Symbol, Vec,
+};
+pub use pulsartrack_errors::SubscriptionError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BillingPeriod {
+    Monthly,
+    Quarterly,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ServiceStatus {
+    Active,
+    PastDue,   // missed a payment, still within grace
+    Suspended, // too many missed payments in a row
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Plan {
+    pub name: String,
+    pub price: i128,
+    pub period: BillingPeriod,
+    pub active: bool, // closed plans take no new subscribers
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Subscription {
+    pub advertiser: Address,
+    pub plan_id: u64,
+    pub max_price: i128, // most the advertiser lets a single pull take
+    pub status: ServiceStatus,
+    pub missed_payments: u32,
+    pub next_charge_at: u64,
+    pub last_paid_at: u64,
+    pub total_paid: i128,
+    pub started_at: u64,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PlanCreated {
+    pub plan_id: u64,
+    pub price: i128,
+    pub period: BillingPeriod,
+}
+contract_event!(PlanCreated, "plan_created");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PlanUpdated {
+    pub plan_id: u64,
+    pub price: i128,
+    pub active: bool,
+}
+contract_event!(PlanUpdated, "plan_updated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SubscriptionStarted {
+    pub advertiser: Address,
+    pub plan_id: u64,
+}
+contract_event!(SubscriptionStarted, "subscription_started");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PaymentMissed {
+    pub advertiser: Address,
+    pub missed_payments: u32,
+    pub status: ServiceStatus,
+}
+contract_event!(PaymentMissed, "payment_missed");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SubscriptionCancelled {
+    pub advertiser: Address,
+}
+contract_event!(SubscriptionCancelled, "subscription_cancelled");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SubscriptionCharged {
+    pub advertiser: Address,
+    pub plan_id: u64,
+    pub amount: i128,
+}
+contract_event!(SubscriptionCharged, "subscription_charged");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    TokenAddress,
+    FeeManager,
+    PlanCounter,
+    Plan(u64),
+    Subscription(Address),
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const MONTH_SECS: u64 = 2_592_000; // 30 days
+const QUARTER_SECS: u64 = 7_776_000; // 90 days
+const RETRY_SECS: u64 = 259_200; // 3 days between attempts on a missed payment
+const MAX_MISSED_PAYMENTS: u32 = 3;
+
+const EVENT_TAG: &str = "subscription";
+
+#[contract]
+pub struct SubscriptionContract;
+
+#[contractimpl]
+impl SubscriptionContract {
+    pub fn initialize(env: Env, admin: Address, token: Address, fee_manager: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, SubscriptionError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::TokenAddress, &token);
+        env.storage().instance().set(&DataKey::FeeManager, &fee_manager);
+        env.storage().instance().set(&DataKey::PlanCounter, &0u64);
+    }
+
+    pub fn set_fee_manager(env: Env, admin: Address, fee_manager: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::FeeManager, &fee_manager);
+    }
+
+    pub fn create_plan(env: Env, admin: Address, name: String, price: i128, period: BillingPeriod) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        if price <= 0 {
+            panic_with_error!(&env, SubscriptionError::InvalidPrice);
+        }
+
+        let counter: u64 = env.storage().instance().get(&DataKey::PlanCounter).unwrap_or(0);
+        let plan_id = counter + 1;
+        let plan = Plan { name, price, period, active: true };
+        Self::_save_plan(&env, plan_id, &plan);
+        env.storage().instance().set(&DataKey::PlanCounter, &plan_id);
+
+        events::publish(&env, EVENT_TAG, PlanCreated { plan_id, price, period });
+
+        plan_id
+    }
+
+    /// Reprice or close a plan. Subscribers whose `max_price` is below a new
+    /// price miss their next payment until they raise it.
+    pub fn update_plan(env: Env, admin: Address, plan_id: u64, price: i128, active: bool) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        if price <= 0 {
+            panic_with_error!(&env, SubscriptionError::InvalidPrice);
+        }
+        let mut plan = Self::_load_plan(&env, plan_id);
+        plan.price = price;
+        plan.active = active;
+        Self::_save_plan(&env, plan_id, &plan);
+
+        events::publish(&env, EVENT_TAG, PlanUpdated { plan_id, price, active });
+    }
+
+    /// Subscribe to a plan and pay the first period. The advertiser must have
+    /// approved this contract to pull at least the plan price.
+    pub fn subscribe(env: Env, advertiser: Address, plan_id: u64, max_price: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        advertiser.require_auth();
+        if let Some(existing) = Self::_subscription(&env, &advertiser) {
+            if existing.status != ServiceStatus::Cancelled {
+                panic_with_error!(&env, SubscriptionError::AlreadySubscribed);
+            }
+        }
+        let plan = Self::_load_plan(&env, plan_id);
+        if !plan.active {
+            panic_with_error!(&env, SubscriptionError::PlanNotActive);
+        }
+        if plan.price > max_price {
+            panic_with_error!(&env, SubscriptionError::PriceAboveMax);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut sub = Subscription {
+            advertiser: advertiser.clone(),
+            plan_id,
+            max_price,
+            status: ServiceStatus::Active,
+            missed_payments: 0,
+            next_charge_at: now,
+            last_paid_at: 0,
+            total_paid: 0,
+            started_at: now,
+        };
+        if !Self::_pull(&env, &advertiser, plan.price) {
+            panic_with_error!(&env, SubscriptionError::PaymentFailed);
+        }
+        Self::_record_payment(&env, &mut sub, &plan);
+        Self::_save_subscription(&env, &sub);
+
+        events::publish(&env, EVENT_TAG, SubscriptionStarted { advertiser, plan_id });
+    }
+
+    /// Bill a due subscription. Anyone may call this; returns whether the
+    /// payment went through.
+    pub fn charge(env: Env, advertiser: Address) -> bool {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let mut sub = Self::_subscription(&env, &advertiser).unwrap_or_else(|| panic_with_error!(&env, SubscriptionError::SubscriptionNotFound));
+        if sub.status == ServiceStatus::Cancelled {
+            panic_with_error!(&env, SubscriptionError::SubscriptionCancelled);
+        }
+        let now = env.ledger().timestamp();
+        if now < sub.next_charge_at {
+            panic_with_error!(&env, SubscriptionError::NotDue);
+        }
+
+        let plan = Self::_load_plan(&env, sub.plan_id);
+        let paid = plan.price <= sub.max_price && Self::_pull(&env, &advertiser, plan.price);
+        if paid {
+            Self::_record_payment(&env, &mut sub, &plan);
+        } else {
+            sub.missed_payments += 1;
+            sub.status = if sub.missed_payments >= MAX_MISSED_PAYMENTS {
+                ServiceStatus::Suspended
+            } else {
+                ServiceStatus::PastDue
+            };
+            sub.next_charge_at = now + RETRY_SECS;
+
+            events::publish(&env, EVENT_TAG, PaymentMissed { advertiser, missed_payments: sub.missed_payments, status: sub.status });
+        }
+        Self::_save_subscription(&env, &sub);
+
+        paid
+    }
+
+    /// Change the most a single pull may take
+    pub fn set_max_price(env: Env, advertiser: Address, max_price: i128) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        advertiser.require_auth();
+        let mut sub = Self::_subscription(&env, &advertiser).unwrap_or_else(|| panic_with_error!(&env, SubscriptionError::SubscriptionNotFound));
+        if max_price <= 0 {
+            panic_with_error!(&env, SubscriptionError::InvalidPrice);
+        }
+        sub.max_price = max_price;
+        Self::_save_subscription(&env, &sub);
+    }
+
+    pub fn cancel(env: Env, advertiser: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        advertiser.require_auth();
+        let mut sub = Self::_subscription(&env, &advertiser).unwrap_or_else(|| panic_with_error!(&env, SubscriptionError::SubscriptionNotFound));
+        if sub.status == ServiceStatus::Cancelled {
+            panic_with_error!(&env, SubscriptionError::SubscriptionCancelled);
+        }
+        sub.status = ServiceStatus::Cancelled;
+        Self::_save_subscription(&env, &sub);
+
+        events::publish(&env, EVENT_TAG, SubscriptionCancelled { advertiser });
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn get_plan(env: Env, plan_id: u64) -> Option<Plan> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Plan(plan_id))
+    }
+
+    pub fn get_subscription(env: Env, advertiser: Address) -> Option<Subscription> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_subscription(&env, &advertiser)
+    }
+
+    /// Service status, with `Cancelled` for advertisers who never subscribed
+    pub fn get_status(env: Env, advertiser: Address) -> ServiceStatus {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_subscription(&env, &advertiser)
+            .map(|s| s.status)
+            .unwrap_or(ServiceStatus::Cancelled)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _require_admin(env: &Env, admin: &Address) {
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *admin != stored_admin {
+            panic_with_error!(&env, SubscriptionError::Unauthorized);
+        }
+    }
+
+    fn _period_secs(period: BillingPeriod) -> u64 {
+        match period {
+            BillingPeriod::Monthly => MONTH_SECS,
+            BillingPeriod::Quarterly => QUARTER_SECS,
+        }
+    }
+
+    /// Pull `amount` under the advertiser's allowance and forward it to the
+    /// treasury through the fee manager. False if the pull is refused.
+    fn _pull(env: &Env, advertiser: &Address, amount: i128) -> bool {
+        let token: Address = env.storage().instance().get(&DataKey::TokenAddress).unwrap();
+        let this = env.current_contract_address();
+        let pulled = token::Client::new(env, &token).try_transfer_from(&this, advertiser, &this, &amount);
+        if !matches!(pulled, Ok(Ok(()))) {
+            return false;
+        }
+
+        // The fee manager moves the funds on to the treasury out of this
+        // contract's balance, so that nested transfer is pre-authorized
+        let fee_manager: Address = env.storage().instance().get(&DataKey::FeeManager).unwrap();
+        let treasury: Address = env.invoke_contract(&fee_manager, &Symbol::new(env, "get_treasury"), Vec::new(env));
+        env.authorize_as_current_contract(vec![
+            env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token.clone(),
+                    fn_name: Symbol::new(env, "transfer"),
+                    args: (this.clone(), treasury, amount).into_val(env),
+                },
+                sub_invocations: Vec::new(env),
+            }),
+        ]);
+        env.invoke_contract::<()>(
+            &fee_manager,
+            &Symbol::new(env, "collect_revenue"),
+            Vec::from_array(
+                env,
+                [
+                    this.into_val(env),
+                    token.into_val(env),
+                    symbol_short!("subscr").into_val(env),
+                    amount.into_val(env),
+                ],
+            ),
+        );
+        true
+    }
+
+    fn _record_payment(env: &Env, sub: &mut Subscription, plan: &Plan) {
+        let now = env.ledger().timestamp();
+        sub.status = ServiceStatus::Active;
+        sub.missed_payments = 0;
+        sub.last_paid_at = now;
+        sub.next_charge_at = now + Self::_period_secs(plan.period);
+        sub.total_paid += plan.price;
+
+        events::publish(env, EVENT_TAG, SubscriptionCharged { advertiser: sub.advertiser.clone(), plan_id: sub.plan_id, amount: plan.price });
+    }
+
+    fn _load_plan(env: &Env, plan_id: u64) -> Plan {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Plan(plan_id))
+            .unwrap_or_else(|| panic_with_error!(&env, SubscriptionError::PlanNotFound))
+    }
+
+    fn _save_plan(env: &Env, plan_id: u64, plan: &Plan) {
+        let _ttl_key = DataKey::Plan(plan_id);
+        env.storage().persistent().set(&_ttl_key, plan);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _subscription(env: &Env, advertiser: &Address) -> Option<Subscription> {
+        env.storage().persistent().get(&DataKey::Subscription(advertiser.clone()))
+    }
+
+    fn _save_subscription(env: &Env, sub: &Subscription) {
+        let _ttl_key = DataKey::Subscription(sub.advertiser.clone());
+        env.storage().persistent().set(&_ttl_key, sub);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+}
+
+mod test;
@@ -0,0 +1,150 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl, testutils::{Address as _, Ledger}, token, Address, Env, String, Symbol,
+};
+
+// Stand-in for the fee manager: forwards revenue to a fixed treasury and
+// tallies it per operation
+#[contract]
+pub struct MockFeeManager;
+
+#[contractimpl]
+impl MockFeeManager {
+    pub fn set_treasury(env: Env, treasury: Address) {
+        env.storage().instance().set(&symbol_short!("treasury"), &treasury);
+    }
+
+    pub fn collect_revenue(env: Env, payer: Address, token: Address, op: Symbol, amount: i128) {
+        payer.require_auth();
+        let treasury: Address = env.storage().instance().get(&symbol_short!("treasury")).unwrap();
+        token::Client::new(&env, &token).transfer(&payer, &treasury, &amount);
+        let collected: i128 = env.storage().instance().get(&op).unwrap_or(0);
+        env.storage().instance().set(&op, &(collected + amount));
+    }
+
+    pub fn get_treasury(env: Env) -> Address {
+        env.storage().instance().get(&symbol_short!("treasury")).unwrap()
+    }
+
+    pub fn get_collected(env: Env, op: Symbol) -> i128 {
+        env.storage().instance().get(&op).unwrap_or(0)
+    }
+}
+
+struct Setup<'a> {
+    client: SubscriptionContractClient<'a>,
+    admin: Address,
+    token: Address,
+    fees: MockFeeManagerClient<'a>,
+    treasury: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let contract_id = env.register_contract(None, SubscriptionContract);
+    let client = SubscriptionContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    let fees_id = env.register_contract(None, MockFeeManager);
+    let fees = MockFeeManagerClient::new(env, &fees_id);
+    let treasury = Address::generate(env);
+    fees.set_treasury(&treasury);
+    client.initialize(&admin, &token, &fees_id);
+    Setup { client, admin, token, fees, treasury }
+}
+
+fn advertiser(env: &Env, s: &Setup, balance: i128, allowance: i128) -> Address {
+    let advertiser = Address::generate(env);
+    token::StellarAssetClient::new(env, &s.token).mint(&advertiser, &balance);
+    token::Client::new(env, &s.token).approve(&advertiser, &s.client.address, &allowance, &100_000);
+    advertiser
+}
+
+fn pass(env: &Env, secs: u64) {
+    env.ledger().with_mut(|li| li.timestamp += secs);
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    assert!(s.client.get_plan(&1).is_none());
+    assert_eq!(s.client.get_status(&Address::generate(&env)), ServiceStatus::Cancelled);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5401)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.initialize(&s.admin, &s.token, &s.fees.address);
+}
+
+#[test]
+fn test_recurring_pulls_route_revenue_to_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let balance = token::Client::new(&env, &s.token);
+    let plan_id = s.client.create_plan(&s.admin, &String::from_str(&env, "Growth"), &300, &BillingPeriod::Quarterly);
+    let adv = advertiser(&env, &s, 1_000, 1_000);
+
+    assert!(s.client.try_subscribe(&adv, &plan_id, &200).is_err());
+    s.client.subscribe(&adv, &plan_id, &300);
+    assert!(s.client.try_subscribe(&adv, &plan_id, &300).is_err());
+    assert_eq!(balance.balance(&s.treasury), 300);
+    assert!(s.client.try_charge(&adv).is_err());
+
+    pass(&env, 7_776_000);
+    assert!(s.client.charge(&adv));
+    let sub = s.client.get_subscription(&adv).unwrap();
+    assert_eq!(sub.status, ServiceStatus::Active);
+    assert_eq!(sub.total_paid, 600);
+    assert_eq!(balance.balance(&s.treasury), 600);
+    assert_eq!(s.fees.get_collected(&symbol_short!("subscr")), 600);
+    assert_eq!(balance.balance(&s.client.address), 0);
+
+    s.client.cancel(&adv);
+    pass(&env, 7_776_000);
+    assert!(s.client.try_charge(&adv).is_err());
+}
+
+#[test]
+fn test_missed_payments_downgrade_then_recover() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let plan_id = s.client.create_plan(&s.admin, &String::from_str(&env, "Starter"), &100, &BillingPeriod::Monthly);
+    // Allowance covers only the first month
+    let adv = advertiser(&env, &s, 1_000, 100);
+    s.client.subscribe(&adv, &plan_id, &100);
+
+    pass(&env, 2_592_000);
+    assert!(!s.client.charge(&adv));
+    assert_eq!(s.client.get_status(&adv), ServiceStatus::PastDue);
+    // Retries wait out the grace period
+    assert!(s.client.try_charge(&adv).is_err());
+    pass(&env, 259_200);
+    assert!(!s.client.charge(&adv));
+    pass(&env, 259_200);
+    assert!(!s.client.charge(&adv));
+    assert_eq!(s.client.get_status(&adv), ServiceStatus::Suspended);
+
+    // A price rise above the advertiser's cap is also a missed payment
+    token::Client::new(&env, &s.token).approve(&adv, &s.client.address, &1_000, &100_000);
+    s.client.update_plan(&s.admin, &plan_id, &150, &true);
+    pass(&env, 259_200);
+    assert!(!s.client.charge(&adv));
+    assert_eq!(s.client.get_subscription(&adv).unwrap().missed_payments, 4);
+
+    s.client.set_max_price(&adv, &150);
+    pass(&env, 259_200);
+    assert!(s.client.charge(&adv));
+    let sub = s.client.get_subscription(&adv).unwrap();
+    assert_eq!(sub.status, ServiceStatus::Active);
+    assert_eq!(sub.missed_payments, 0);
+    assert_eq!(sub.total_paid, 250);
+}
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Env, String,
 };
+pub use pulsartrack_errors::TargetingEngineError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone)]
@@ -54,6 +56,14 @@ pub struct TargetingScore {
     pub computed_at: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct TargetingSet {
+    pub campaign_id: u64,
+    pub advertiser: Address,
+}
+contract_event!(TargetingSet, "targeting_set");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -66,6 +76,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "targeting";
+
 #[contract]
 pub struct TargetingEngineContract;
 
@@ -74,7 +86,7 @@ impl TargetingEngineContract {
     pub fn initialize(env: Env, admin: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, TargetingEngineError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -90,7 +102,7 @@ impl TargetingEngineContract {
         advertiser.require_auth();
 
         if params.min_age > params.max_age {
-            panic!("invalid age range");
+            panic_with_error!(&env, TargetingEngineError::InvalidAgeRange);
         }
 
         let config = TargetingConfig {
@@ -116,10 +128,7 @@ impl TargetingEngineContract {
         env.storage().persistent().set(&_ttl_key, &config);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("targeting"), symbol_short!("set")),
-            (campaign_id, advertiser),
-        );
+        events::publish(&env, EVENT_TAG, TargetingSet { campaign_id, advertiser });
     }
 
     pub fn compute_score(
@@ -135,7 +144,7 @@ impl TargetingEngineContract {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         // In production, use authorized oracle list
         if oracle != admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, TargetingEngineError::Unauthorized);
         }
 
         let targeting_score = TargetingScore {
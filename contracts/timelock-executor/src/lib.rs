@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     Address, Env, String,
 };
+pub use pulsartrack_errors::TimelockExecutorError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -31,6 +33,21 @@ pub struct TimelockEntry {
     pub executed_at: Option<u64>,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct EntryQueued {
+    pub entry_id: u64,
+    pub proposer: Address,
+}
+contract_event!(EntryQueued, "entry_queued");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EntryExecuted {
+    pub entry_id: u64,
+}
+contract_event!(EntryExecuted, "entry_executed");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -47,6 +64,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "timelock";
+
 #[contract]
 pub struct TimelockExecutorContract;
 
@@ -61,7 +80,7 @@ impl TimelockExecutorContract {
     ) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, TimelockExecutorError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -85,14 +104,14 @@ impl TimelockExecutorContract {
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if proposer != admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, TimelockExecutorError::Unauthorized);
         }
 
         let min_delay: u64 = env.storage().instance().get(&DataKey::MinDelay).unwrap_or(86_400);
         let max_delay: u64 = env.storage().instance().get(&DataKey::MaxDelay).unwrap_or(2_592_000);
 
         if delay_secs < min_delay || delay_secs > max_delay {
-            panic!("invalid delay");
+            panic_with_error!(&env, TimelockExecutorError::InvalidDelay);
         }
 
         let counter: u64 = env.storage().instance().get(&DataKey::EntryCounter).unwrap_or(0);
@@ -119,10 +138,7 @@ impl TimelockExecutorContract {
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
         env.storage().instance().set(&DataKey::EntryCounter, &entry_id);
 
-        env.events().publish(
-            (symbol_short!("timelock"), symbol_short!("queued")),
-            (entry_id, proposer),
-        );
+        events::publish(&env, EVENT_TAG, EntryQueued { entry_id, proposer });
 
         entry_id
     }
@@ -133,23 +149,23 @@ impl TimelockExecutorContract {
 
         let stored_executor: Address = env.storage().instance().get(&DataKey::ExecutorAddress).unwrap();
         if executor != stored_executor {
-            panic!("unauthorized executor");
+            panic_with_error!(&env, TimelockExecutorError::UnauthorizedExecutor);
         }
 
         let mut entry: TimelockEntry = env
             .storage()
             .persistent()
             .get(&DataKey::Entry(entry_id))
-            .expect("entry not found");
+            .unwrap_or_else(|| panic_with_error!(&env, TimelockExecutorError::EntryNotFound));
 
         if entry.status != TimelockStatus::Queued {
-            panic!("entry not queued");
+            panic_with_error!(&env, TimelockExecutorError::EntryNotQueued);
         }
 
         let now = env.ledger().timestamp();
 
         if now < entry.eta {
-            panic!("timelock not expired");
+            panic_with_error!(&env, TimelockExecutorError::TimelockNotExpired);
         }
 
         if now > entry.eta + entry.grace_period {
@@ -157,7 +173,7 @@ impl TimelockExecutorContract {
             let _ttl_key = DataKey::Entry(entry_id);
             env.storage().persistent().set(&_ttl_key, &entry);
             env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
-            panic!("grace period expired");
+            panic_with_error!(&env, TimelockExecutorError::GracePeriodExpired);
         }
 
         entry.status = TimelockStatus::Executed;
@@ -166,10 +182,7 @@ impl TimelockExecutorContract {
         env.storage().persistent().set(&_ttl_key, &entry);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("timelock"), symbol_short!("executed")),
-            entry_id,
-        );
+        events::publish(&env, EVENT_TAG, EntryExecuted { entry_id });
     }
 
     pub fn cancel(env: Env, admin: Address, entry_id: u64) {
@@ -177,17 +190,17 @@ impl TimelockExecutorContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, TimelockExecutorError::Unauthorized);
         }
 
         let mut entry: TimelockEntry = env
             .storage()
             .persistent()
             .get(&DataKey::Entry(entry_id))
-            .expect("entry not found");
+            .unwrap_or_else(|| panic_with_error!(&env, TimelockExecutorError::EntryNotFound));
 
         if entry.status != TimelockStatus::Queued {
-            panic!("entry not queued");
+            panic_with_error!(&env, TimelockExecutorError::EntryNotQueued);
         }
 
         entry.status = TimelockStatus::Cancelled;
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     token, Address, Bytes, BytesN, Env, String,
 };
+pub use pulsartrack_errors::TokenBridgeError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -33,6 +35,22 @@ pub struct BridgeDeposit {
     pub tx_hash: Option<BytesN<32>>,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct BridgeDeposited {
+    pub deposit_id: u64,
+    pub sender: Address,
+    pub amount: i128,
+}
+contract_event!(BridgeDeposited, "bridge_deposited");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BridgeConfirmed {
+    pub deposit_id: u64,
+}
+contract_event!(BridgeConfirmed, "bridge_confirmed");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -48,6 +66,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "bridge";
+
 #[contract]
 pub struct TokenBridgeContract;
 
@@ -56,7 +76,7 @@ impl TokenBridgeContract {
     pub fn initialize(env: Env, admin: Address, relayer: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, TokenBridgeError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -70,7 +90,7 @@ impl TokenBridgeContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, TokenBridgeError::Unauthorized);
         }
         let _ttl_key = DataKey::SupportedChain(chain);
         env.storage().persistent().set(&_ttl_key, &max_daily_limit);
@@ -90,11 +110,11 @@ impl TokenBridgeContract {
 
         // Verify chain is supported
         if !env.storage().persistent().has(&DataKey::SupportedChain(recipient_chain.clone())) {
-            panic!("chain not supported");
+            panic_with_error!(&env, TokenBridgeError::ChainNotSupported);
         }
 
         if amount <= 0 {
-            panic!("invalid amount");
+            panic_with_error!(&env, TokenBridgeError::InvalidAmount);
         }
 
         let fee_bps: u32 = env.storage().instance().get(&DataKey::BridgeFeesBps).unwrap_or(50);
@@ -127,10 +147,7 @@ impl TokenBridgeContract {
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
         env.storage().instance().set(&DataKey::DepositCounter, &deposit_id);
 
-        env.events().publish(
-            (symbol_short!("bridge"), symbol_short!("deposit")),
-            (deposit_id, sender, net_amount),
-        );
+        events::publish(&env, EVENT_TAG, BridgeDeposited { deposit_id, sender, amount: net_amount });
 
         deposit_id
     }
@@ -145,17 +162,17 @@ impl TokenBridgeContract {
         relayer.require_auth();
         let stored_relayer: Address = env.storage().instance().get(&DataKey::RelayerAddress).unwrap();
         if relayer != stored_relayer {
-            panic!("unauthorized relayer");
+            panic_with_error!(&env, TokenBridgeError::UnauthorizedRelayer);
         }
 
         let mut deposit: BridgeDeposit = env
             .storage()
             .persistent()
             .get(&DataKey::Deposit(deposit_id))
-            .expect("deposit not found");
+            .unwrap_or_else(|| panic_with_error!(&env, TokenBridgeError::DepositNotFound));
 
         if deposit.status != BridgeStatus::Pending {
-            panic!("not pending");
+            panic_with_error!(&env, TokenBridgeError::NotPending);
         }
 
         deposit.status = BridgeStatus::Completed;
@@ -166,10 +183,7 @@ impl TokenBridgeContract {
         env.storage().persistent().set(&_ttl_key, &deposit);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("bridge"), symbol_short!("confirmed")),
-            deposit_id,
-        );
+        events::publish(&env, EVENT_TAG, BridgeConfirmed { deposit_id });
     }
 
     pub fn refund_deposit(env: Env, admin: Address, deposit_id: u64) {
@@ -177,17 +191,17 @@ impl TokenBridgeContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, TokenBridgeError::Unauthorized);
         }
 
         let mut deposit: BridgeDeposit = env
             .storage()
             .persistent()
             .get(&DataKey::Deposit(deposit_id))
-            .expect("deposit not found");
+            .unwrap_or_else(|| panic_with_error!(&env, TokenBridgeError::DepositNotFound));
 
         if deposit.status != BridgeStatus::Pending && deposit.status != BridgeStatus::Failed {
-            panic!("cannot refund");
+            panic_with_error!(&env, TokenBridgeError::CannotRefund);
         }
 
         let total_refund = deposit.amount + deposit.bridge_fee;
@@ -0,0 +1,553 @@
+//! PulsarTrack - Vesting (Soroban)
+//! Team and investor allocations of the PULSAR governance token on Stellar.
+//!
+//! Governance mints the allocation to this contract and then opens grants
+//! against the unallocated balance. A beneficiary can hold any number of
+//! grants, each with its own cliff, linear or step schedule. Revocable
+//! grants can be cut short by governance: what has vested stays claimable
+//! and the rest goes back to governance.
+//!
+//! Tokens still locked in a grant can be delegated for voting. The contract
+//! tallies the locked amount behind each delegate and hands each delegate
+//! that share of its balance through partial delegations on the governance
+//! token, so the DAO counts the votes like any other delegated tokens.
+//!
+//! Events are published as ("vesting", action, version) with a typed payload
+//! (see the Events section):
+//! - "grant_created": `GrantCreated`
+//! - "grant_revoked": `GrantRevoked`
+//! - "votes_delegated": `VotesDelegated`
+//! - "grant_claimed": `GrantClaimed`
+
+
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error,
+    token, Address, Env, IntoVal, Map, Symbol, Vec,
+};
+pub use pulsartrack_errors::VestingError;
+use pulsartrack_events::{self as events, contract_event};
+
+// ============================================================
+// Data Types
+// ============================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VestingKind {
+    Cliff,  // everything unlocks at the cliff
+    Linear, // unlocks continuously from the cliff to the end
+    Step,   // unlocks in equal slices every `step_secs`
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct VestingSchedule {
+    pub kind: VestingKind,
+    pub start: u64,
+    pub cliff_secs: u64,
+    pub duration_secs: u64,
+    pub step_secs: u64, // Step only
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Grant {
+    pub beneficiary: Address,
+    pub schedule: VestingSchedule,
+    pub total: i128,
+    pub claimed: i128,
+    pub revocable: bool,
+    pub revoked: bool,
+    pub delegate: Option<Address>,
+    pub created_at: u64,
+}
+
+// ============================================================
+// Events
+// ============================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub struct GrantCreated {
+    pub grant_id: u64,
+    pub beneficiary: Address,
+    pub total: i128,
+}
+contract_event!(GrantCreated, "grant_created");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct GrantRevoked {
+    pub grant_id: u64,
+    pub returned: i128,
+}
+contract_event!(GrantRevoked, "grant_revoked");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct VotesDelegated {
+    pub grant_id: u64,
+    pub delegate: Address,
+}
+contract_event!(VotesDelegated, "votes_delegated");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct GrantClaimed {
+    pub grant_id: u64,
+    pub beneficiary: Address,
+    pub amount: i128,
+}
+contract_event!(GrantClaimed, "grant_claimed");
+
+// ============================================================
+// Storage Keys
+// ============================================================
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Token,
+    Governance,
+    GrantCounter,
+    Grant(u64),
+    BeneficiaryGrants(Address),
+    Outstanding,
+    DelegatedPower(Address),
+    VoteShares, // Map<Address, u32>: bps of the balance delegated on the token
+}
+
+// ============================================================
+// Contract
+// ============================================================
+
+const INSTANCE_LIFETIME_THRESHOLD: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
+
+const MAX_GRANTS_PER_BENEFICIARY: u32 = 20;
+// The governance token's cap on partial delegates per delegator
+const MAX_DELEGATES: u32 = 10;
+const MAX_BPS: i128 = 10_000;
+
+const EVENT_TAG: &str = "vesting";
+
+#[contract]
+pub struct VestingContract;
+
+#[contractimpl]
+impl VestingContract {
+    pub fn initialize(env: Env, admin: Address, token: Address, governance: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, VestingError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Token, &token);
+        env.storage().instance().set(&DataKey::Governance, &governance);
+        env.storage().instance().set(&DataKey::GrantCounter, &0u64);
+        env.storage().instance().set(&DataKey::Outstanding, &0i128);
+    }
+
+    pub fn set_governance(env: Env, admin: Address, governance: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Governance, &governance);
+    }
+
+    /// Open a grant backed by tokens already minted to this contract
+    /// (governance only)
+    pub fn create_grant(
+        env: Env,
+        governance: Address,
+        beneficiary: Address,
+        schedule: VestingSchedule,
+        total: i128,
+        revocable: bool,
+    ) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_governance(&env, &governance);
+        if total <= 0 {
+            panic_with_error!(&env, VestingError::InvalidTotal);
+        }
+        if schedule.duration_secs == 0 || schedule.cliff_secs > schedule.duration_secs {
+            panic_with_error!(&env, VestingError::InvalidSchedule);
+        }
+        if schedule.kind == VestingKind::Step
+            && (schedule.step_secs == 0 || !schedule.duration_secs.is_multiple_of(schedule.step_secs))
+        {
+            panic_with_error!(&env, VestingError::InvalidStep);
+        }
+
+        let outstanding = Self::_outstanding(&env);
+        if Self::unallocated(env.clone()) < total {
+            panic_with_error!(&env, VestingError::InsufficientFunding);
+        }
+
+        let mut grant_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryGrants(beneficiary.clone()))
+            .unwrap_or(Vec::new(&env));
+        if grant_ids.len() >= MAX_GRANTS_PER_BENEFICIARY {
+            panic_with_error!(&env, VestingError::TooManyGrants);
+        }
+
+        let counter: u64 = env.storage().instance().get(&DataKey::GrantCounter).unwrap_or(0);
+        let grant_id = counter + 1;
+        let grant = Grant {
+            beneficiary: beneficiary.clone(),
+            schedule,
+            total,
+            claimed: 0,
+            revocable,
+            revoked: false,
+            delegate: None,
+            created_at: env.ledger().timestamp(),
+        };
+        Self::_save_grant(&env, grant_id, &grant);
+        env.storage().instance().set(&DataKey::GrantCounter, &grant_id);
+        env.storage().instance().set(&DataKey::Outstanding, &(outstanding + total));
+
+        grant_ids.push_back(grant_id);
+        let _ttl_key = DataKey::BeneficiaryGrants(beneficiary.clone());
+        env.storage().persistent().set(&_ttl_key, &grant_ids);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        // Tokens minted in for the grant widened every delegate's share
+        Self::_sync_votes(&env);
+
+        events::publish(&env, EVENT_TAG, GrantCreated { grant_id, beneficiary, total });
+
+        grant_id
+    }
+
+    /// Claim everything vested and not yet claimed on one grant
+    pub fn claim(env: Env, beneficiary: Address, grant_id: u64) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        beneficiary.require_auth();
+        let mut grant = Self::_load_grant(&env, grant_id);
+        if grant.beneficiary != beneficiary {
+            panic_with_error!(&env, VestingError::Unauthorized);
+        }
+        let amount = Self::_vested(&env, &grant) - grant.claimed;
+        if amount <= 0 {
+            panic_with_error!(&env, VestingError::NothingToClaim);
+        }
+        Self::_release(&env, grant_id, &mut grant, amount);
+        Self::_sync_votes(&env);
+        amount
+    }
+
+    /// Claim across every grant the beneficiary holds
+    pub fn claim_all(env: Env, beneficiary: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        beneficiary.require_auth();
+        let grant_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryGrants(beneficiary))
+            .unwrap_or(Vec::new(&env));
+        let mut total = 0i128;
+        for grant_id in grant_ids.iter() {
+            let mut grant = Self::_load_grant(&env, grant_id);
+            let amount = Self::_vested(&env, &grant) - grant.claimed;
+            if amount > 0 {
+                Self::_release(&env, grant_id, &mut grant, amount);
+                total += amount;
+            }
+        }
+        if total == 0 {
+            panic_with_error!(&env, VestingError::NothingToClaim);
+        }
+        Self::_sync_votes(&env);
+        total
+    }
+
+    /// Stop a revocable grant. What has vested stays claimable; the unvested
+    /// remainder goes back to governance (governance only).
+    pub fn revoke_grant(env: Env, governance: Address, grant_id: u64) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_require_governance(&env, &governance);
+        let mut grant = Self::_load_grant(&env, grant_id);
+        if !grant.revocable {
+            panic_with_error!(&env, VestingError::GrantNotRevocable);
+        }
+        if grant.revoked {
+            panic_with_error!(&env, VestingError::AlreadyRevoked);
+        }
+
+        let vested = Self::_vested(&env, &grant);
+        let returned = grant.total - vested;
+        if let Some(delegate) = grant.delegate.clone() {
+            Self::_adjust_power(&env, &delegate, -returned);
+        }
+        grant.total = vested;
+        grant.revoked = true;
+        Self::_save_grant(&env, grant_id, &grant);
+        let outstanding = Self::_outstanding(&env);
+        env.storage().instance().set(&DataKey::Outstanding, &(outstanding - returned));
+
+        if returned > 0 {
+            Self::_token(&env).transfer(&env.current_contract_address(), &governance, &returned);
+        }
+        Self::_sync_votes(&env);
+
+        events::publish(&env, EVENT_TAG, GrantRevoked { grant_id, returned });
+
+        returned
+    }
+
+    /// Point the voting weight of a grant's locked tokens at `delegate`. At
+    /// most `MAX_DELEGATES` delegates can hold votes at once.
+    pub fn delegate_votes(env: Env, beneficiary: Address, grant_id: u64, delegate: Address) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        beneficiary.require_auth();
+        let mut grant = Self::_load_grant(&env, grant_id);
+        if grant.beneficiary != beneficiary {
+            panic_with_error!(&env, VestingError::Unauthorized);
+        }
+
+        let locked = grant.total - grant.claimed;
+        if let Some(previous) = grant.delegate.clone() {
+            Self::_adjust_power(&env, &previous, -locked);
+        }
+        Self::_adjust_power(&env, &delegate, locked);
+        grant.delegate = Some(delegate.clone());
+        Self::_save_grant(&env, grant_id, &grant);
+        Self::_sync_votes(&env);
+
+        events::publish(&env, EVENT_TAG, VotesDelegated { grant_id, delegate });
+    }
+
+    /// Bring the delegates' shares on the governance token back in line
+    /// with their locked tokens after tokens were minted to this contract.
+    /// Anyone may call.
+    pub fn sync_votes(env: Env) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_sync_votes(&env);
+    }
+
+    // ========================================================
+    // Views
+    // ========================================================
+
+    pub fn get_grant(env: Env, grant_id: u64) -> Option<Grant> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().persistent().get(&DataKey::Grant(grant_id))
+    }
+
+    pub fn get_beneficiary_grants(env: Env, beneficiary: Address) -> Vec<u64> {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryGrants(beneficiary))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn vested_amount(env: Env, grant_id: u64) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let grant = Self::_load_grant(&env, grant_id);
+        Self::_vested(&env, &grant)
+    }
+
+    pub fn claimable(env: Env, grant_id: u64) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let grant = Self::_load_grant(&env, grant_id);
+        Self::_vested(&env, &grant) - grant.claimed
+    }
+
+    /// Locked grant tokens currently delegated to `delegate`
+    pub fn get_delegated_power(env: Env, delegate: Address) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .get(&DataKey::DelegatedPower(delegate))
+            .unwrap_or(0)
+    }
+
+    /// Minted tokens not yet committed to any grant
+    pub fn unallocated(env: Env) -> i128 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        Self::_token(&env).balance(&env.current_contract_address()) - Self::_outstanding(&env)
+    }
+
+    pub fn get_grant_count(env: Env) -> u64 {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        env.storage().instance().get(&DataKey::GrantCounter).unwrap_or(0)
+    }
+
+    // ========================================================
+    // Internal helpers
+    // ========================================================
+
+    fn _require_admin(env: &Env, admin: &Address) {
+        admin.require_auth();
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *admin != stored_admin {
+            panic_with_error!(&env, VestingError::Unauthorized);
+        }
+    }
+
+    fn _require_governance(env: &Env, governance: &Address) {
+        governance.require_auth();
+        let stored: Address = env.storage().instance().get(&DataKey::Governance).unwrap();
+        if *governance != stored {
+            panic_with_error!(&env, VestingError::Unauthorized);
+        }
+    }
+
+    fn _token(env: &Env) -> token::Client<'_> {
+        let token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        token::Client::new(env, &token)
+    }
+
+    fn _outstanding(env: &Env) -> i128 {
+        env.storage().instance().get(&DataKey::Outstanding).unwrap_or(0)
+    }
+
+    fn _load_grant(env: &Env, grant_id: u64) -> Grant {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Grant(grant_id))
+            .unwrap_or_else(|| panic_with_error!(&env, VestingError::GrantNotFound))
+    }
+
+    fn _save_grant(env: &Env, grant_id: u64, grant: &Grant) {
+        let _ttl_key = DataKey::Grant(grant_id);
+        env.storage().persistent().set(&_ttl_key, grant);
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn _adjust_power(env: &Env, delegate: &Address, delta: i128) {
+        let _ttl_key = DataKey::DelegatedPower(delegate.clone());
+        let power: i128 = env.storage().persistent().get(&_ttl_key).unwrap_or(0);
+        env.storage().persistent().set(&_ttl_key, &(power + delta));
+        env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        // Track the delegate so `_sync_votes` hands it its share
+        let mut shares: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::VoteShares)
+            .unwrap_or(Map::new(env));
+        if !shares.contains_key(delegate.clone()) {
+            shares.set(delegate.clone(), 0);
+            env.storage().instance().set(&DataKey::VoteShares, &shares);
+        }
+    }
+
+    /// Re-point this contract's partial delegations on the governance token
+    /// so each delegate holds its locked tokens' share of the balance, to the
+    /// basis point. Shrinking shares go first so the total never passes 100%.
+    fn _sync_votes(env: &Env) {
+        let mut shares: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::VoteShares)
+            .unwrap_or(Map::new(env));
+        if shares.is_empty() {
+            return;
+        }
+        let this = env.current_contract_address();
+        let token = Self::_token(env);
+        let balance = token.balance(&this);
+
+        let mut targets: Map<Address, u32> = Map::new(env);
+        let mut delegates: u32 = 0;
+        for delegate in shares.keys().iter() {
+            let power: i128 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::DelegatedPower(delegate.clone()))
+                .unwrap_or(0);
+            let target = if balance > 0 { (power * MAX_BPS / balance) as u32 } else { 0 };
+            if target > 0 {
+                delegates += 1;
+            }
+            targets.set(delegate, target);
+        }
+        if delegates > MAX_DELEGATES {
+            panic_with_error!(env, VestingError::TooManyDelegates);
+        }
+
+        for shrinking in [true, false] {
+            for (delegate, target) in targets.iter() {
+                let current = shares.get(delegate.clone()).unwrap_or(0);
+                if (target < current) != shrinking || target == current {
+                    continue;
+                }
+                if target == 0 {
+                    env.invoke_contract::<()>(
+                        &token.address,
+                        &Symbol::new(env, "revoke_partial_delegation"),
+                        (this.clone(), delegate.clone()).into_val(env),
+                    );
+                } else {
+                    env.invoke_contract::<()>(
+                        &token.address,
+                        &Symbol::new(env, "delegate_partial"),
+                        (this.clone(), delegate.clone(), target).into_val(env),
+                    );
+                }
+            }
+        }
+
+        for (delegate, target) in targets.iter() {
+            let power: i128 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::DelegatedPower(delegate.clone()))
+                .unwrap_or(0);
+            if target == 0 && power == 0 {
+                shares.remove(delegate);
+            } else {
+                shares.set(delegate, target);
+            }
+        }
+        env.storage().instance().set(&DataKey::VoteShares, &shares);
+    }
+
+    fn _release(env: &Env, grant_id: u64, grant: &mut Grant, amount: i128) {
+        grant.claimed += amount;
+        Self::_save_grant(env, grant_id, grant);
+        if let Some(delegate) = grant.delegate.clone() {
+            Self::_adjust_power(env, &delegate, -amount);
+        }
+        let outstanding = Self::_outstanding(env);
+        env.storage().instance().set(&DataKey::Outstanding, &(outstanding - amount));
+
+        Self::_token(env).transfer(&env.current_contract_address(), &grant.beneficiary, &amount);
+
+        events::publish(env, EVENT_TAG, GrantClaimed { grant_id, beneficiary: grant.beneficiary.clone(), amount });
+    }
+
+    fn _vested(env: &Env, grant: &Grant) -> i128 {
+        if grant.revoked {
+            return grant.total;
+        }
+        let s = &grant.schedule;
+        let now = env.ledger().timestamp();
+        if now < s.start.saturating_add(s.cliff_secs) {
+            return 0;
+        }
+        let elapsed = now - s.start;
+        if grant.schedule.kind == VestingKind::Cliff || elapsed >= s.duration_secs {
+            return grant.total;
+        }
+        match s.kind {
+            VestingKind::Linear => grant.total * elapsed as i128 / s.duration_secs as i128,
+            VestingKind::Step => {
+                let steps = (elapsed / s.step_secs) as i128;
+                let total_steps = (s.duration_secs / s.step_secs) as i128;
+                grant.total * steps / total_steps
+            }
+            VestingKind::Cliff => grant.total,
+        }
+    }
+}
+
+mod test;
@@ -0,0 +1,172 @@
+#![cfg(test)]
+use super::*;
+use pulsar_governance_token::{GovernanceTokenContract, GovernanceTokenContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+// Grants are paid in the PULSAR governance token, whose admin is `admin`
+fn setup(env: &Env) -> (VestingContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, VestingContract);
+    let client = VestingContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let governance = Address::generate(env);
+    let token = env.register_contract(None, GovernanceTokenContract);
+    GovernanceTokenContractClient::new(env, &token).initialize(&admin);
+    client.initialize(&admin, &token, &governance);
+    (client, admin, governance, token)
+}
+
+fn mint_to_vesting(env: &Env, client: &VestingContractClient, token: &Address, admin: &Address, amount: i128) {
+    GovernanceTokenContractClient::new(env, token).mint(admin, &client.address, &amount);
+}
+
+fn schedule(kind: VestingKind, cliff_secs: u64, duration_secs: u64, step_secs: u64) -> VestingSchedule {
+    VestingSchedule { kind, start: 1_000, cliff_secs, duration_secs, step_secs }
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _governance, _token) = setup(&env);
+    assert_eq!(client.get_grant_count(), 0);
+    assert_eq!(client.unallocated(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5801)")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, governance, token) = setup(&env);
+    client.initialize(&admin, &token, &governance);
+}
+
+#[test]
+fn test_cliff_linear_and_step_schedules() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, governance, token) = setup(&env);
+    let beneficiary = Address::generate(&env);
+
+    let linear = schedule(VestingKind::Linear, 100, 1_000, 0);
+    assert!(client.try_create_grant(&governance, &beneficiary, &linear, &1_000, &false).is_err());
+    mint_to_vesting(&env, &client, &token, &admin, 3_000);
+
+    let g_linear = client.create_grant(&governance, &beneficiary, &linear, &1_000, &false);
+    let g_cliff = client.create_grant(&governance, &beneficiary, &schedule(VestingKind::Cliff, 500, 500, 0), &1_000, &false);
+    let g_step = client.create_grant(&governance, &beneficiary, &schedule(VestingKind::Step, 0, 1_000, 250), &1_000, &false);
+    assert!(client
+        .try_create_grant(&governance, &beneficiary, &schedule(VestingKind::Step, 0, 1_000, 300), &1, &false)
+        .is_err());
+    assert_eq!(client.unallocated(), 0);
+    assert_eq!(client.get_beneficiary_grants(&beneficiary).len(), 3);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_050);
+    assert_eq!(client.vested_amount(&g_linear), 0);
+    assert!(client.try_claim(&beneficiary, &g_linear).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp = 1_400);
+    assert_eq!(client.vested_amount(&g_linear), 400);
+    assert_eq!(client.vested_amount(&g_cliff), 0);
+    assert_eq!(client.vested_amount(&g_step), 250);
+    assert_eq!(client.claim_all(&beneficiary), 650);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_600);
+    assert_eq!(client.claimable(&g_linear), 200);
+    assert_eq!(client.claimable(&g_cliff), 1_000);
+    assert_eq!(client.claimable(&g_step), 250);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(client.claim_all(&beneficiary), 2_350);
+    assert_eq!(token::Client::new(&env, &token).balance(&beneficiary), 3_000);
+    assert!(client.try_claim_all(&beneficiary).is_err());
+}
+
+#[test]
+fn test_revoke_returns_unvested_and_updates_delegated_power() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, governance, token) = setup(&env);
+    let balance = token::Client::new(&env, &token);
+    let votes = GovernanceTokenContractClient::new(&env, &token);
+    let beneficiary = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    mint_to_vesting(&env, &client, &token, &admin, 2_000);
+
+    let linear = schedule(VestingKind::Linear, 0, 1_000, 0);
+    let revocable = client.create_grant(&governance, &beneficiary, &linear, &1_000, &true);
+    let fixed = client.create_grant(&governance, &beneficiary, &linear, &1_000, &false);
+
+    assert!(client.try_delegate_votes(&delegate, &revocable, &delegate).is_err());
+    client.delegate_votes(&beneficiary, &revocable, &delegate);
+    client.delegate_votes(&beneficiary, &fixed, &delegate);
+    assert_eq!(client.get_delegated_power(&delegate), 2_000);
+    assert_eq!(votes.voting_power(&delegate), 2_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_300);
+    client.claim(&beneficiary, &revocable);
+    assert_eq!(client.get_delegated_power(&delegate), 1_700);
+    assert_eq!(votes.voting_power(&delegate), 1_700);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_600);
+    assert!(client.try_revoke_grant(&beneficiary, &revocable).is_err());
+    assert!(client.try_revoke_grant(&governance, &fixed).is_err());
+    assert_eq!(client.revoke_grant(&governance, &revocable), 400);
+    assert_eq!(balance.balance(&governance), 400);
+    assert_eq!(client.get_delegated_power(&delegate), 1_300);
+    assert_eq!(votes.voting_power(&delegate), 1_300);
+
+    // Vested-but-unclaimed tokens survive the revocation and stop growing
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(client.claim(&beneficiary, &revocable), 300);
+    assert!(client.try_revoke_grant(&governance, &revocable).is_err());
+    assert_eq!(client.get_delegated_power(&delegate), 1_000);
+    assert_eq!(client.unallocated(), 0);
+}
+
+#[test]
+fn test_locked_votes_delegated_on_the_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, governance, token) = setup(&env);
+    let votes = GovernanceTokenContractClient::new(&env, &token);
+    let beneficiary = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    mint_to_vesting(&env, &client, &token, &admin, 4_000);
+
+    let linear = schedule(VestingKind::Linear, 0, 1_000, 0);
+    let g1 = client.create_grant(&governance, &beneficiary, &linear, &1_000, &false);
+    let g2 = client.create_grant(&governance, &beneficiary, &linear, &1_000, &false);
+    client.delegate_votes(&beneficiary, &g1, &first);
+    client.delegate_votes(&beneficiary, &g2, &second);
+    assert_eq!(votes.voting_power(&first), 1_000);
+    assert_eq!(votes.voting_power(&second), 1_000);
+    assert_eq!(votes.get_delegated_bps(&client.address), 5_000);
+
+    // A mint widens the shares until they are synced back
+    mint_to_vesting(&env, &client, &token, &admin, 4_000);
+    assert_eq!(votes.voting_power(&first), 2_000);
+    client.sync_votes();
+    assert_eq!(votes.voting_power(&first), 1_000);
+
+    // Moving a grant to another delegate moves its votes
+    client.delegate_votes(&beneficiary, &g1, &second);
+    assert_eq!(votes.voting_power(&first), 0);
+    assert_eq!(votes.voting_power(&second), 2_000);
+    assert_eq!(votes.get_partial_delegations(&client.address).len(), 1);
+
+    // The token caps how many delegates the contract can split between
+    for _ in 0..9 {
+        let grant = client.create_grant(&governance, &beneficiary, &linear, &100, &false);
+        client.delegate_votes(&beneficiary, &grant, &Address::generate(&env));
+    }
+    let grant = client.create_grant(&governance, &beneficiary, &linear, &100, &false);
+    assert_eq!(
+        client.try_delegate_votes(&beneficiary, &grant, &Address::generate(&env)),
+        Err(Ok(soroban_sdk::Error::from_contract_error(VestingError::TooManyDelegates as u32)))
+    );
+}
@@ -3,9 +3,11 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, panic_with_error,
     token, Address, Env, String,
 };
+pub use pulsartrack_errors::WrappedTokenError;
+use pulsartrack_events::{self as events, contract_event};
 
 #[contracttype]
 #[derive(Clone)]
@@ -31,6 +33,24 @@ pub struct WrapRecord {
     pub wrapped_at: u64,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct WrappedMinted {
+    pub record_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+}
+contract_event!(WrappedMinted, "wrapped_minted");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct WrappedBurned {
+    pub user: Address,
+    pub amount: i128,
+    pub target_address: String,
+}
+contract_event!(WrappedBurned, "wrapped_burned");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -46,6 +66,8 @@ const INSTANCE_BUMP_AMOUNT: u32 = 86_400;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
 const PERSISTENT_BUMP_AMOUNT: u32 = 1_051_200;
 
+const EVENT_TAG: &str = "wrapped";
+
 #[contract]
 pub struct WrappedTokenContract;
 
@@ -54,7 +76,7 @@ impl WrappedTokenContract {
     pub fn initialize(env: Env, admin: Address, relayer: Address) {
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+            panic_with_error!(&env, WrappedTokenError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -76,7 +98,7 @@ impl WrappedTokenContract {
         admin.require_auth();
         let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if admin != stored_admin {
-            panic!("unauthorized");
+            panic_with_error!(&env, WrappedTokenError::Unauthorized);
         }
 
         let wrapped = WrappedToken {
@@ -107,17 +129,17 @@ impl WrappedTokenContract {
         relayer.require_auth();
         let stored_relayer: Address = env.storage().instance().get(&DataKey::RelayerAddress).unwrap();
         if relayer != stored_relayer {
-            panic!("unauthorized relayer");
+            panic_with_error!(&env, WrappedTokenError::UnauthorizedRelayer);
         }
 
         let mut wrapped: WrappedToken = env
             .storage()
             .persistent()
             .get(&DataKey::WrappedToken(symbol.clone()))
-            .expect("token not registered");
+            .unwrap_or_else(|| panic_with_error!(&env, WrappedTokenError::TokenNotRegistered));
 
         if !wrapped.is_active {
-            panic!("token not active");
+            panic_with_error!(&env, WrappedTokenError::TokenNotActive);
         }
 
         // Mint stellar-side tokens
@@ -150,10 +172,7 @@ impl WrappedTokenContract {
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
         env.storage().instance().set(&DataKey::WrapRecordCounter, &record_id);
 
-        env.events().publish(
-            (symbol_short!("wrapped"), symbol_short!("minted")),
-            (record_id, recipient, amount),
-        );
+        events::publish(&env, EVENT_TAG, WrappedMinted { record_id, recipient, amount });
 
         record_id
     }
@@ -166,7 +185,7 @@ impl WrappedTokenContract {
         let current: i128 = env.storage().persistent().get(&key).unwrap_or(0);
 
         if current < amount {
-            panic!("insufficient balance");
+            panic_with_error!(&env, WrappedTokenError::InsufficientBalance);
         }
 
         env.storage().persistent().set(&key, &(current - amount));
@@ -176,17 +195,14 @@ impl WrappedTokenContract {
             .storage()
             .persistent()
             .get(&DataKey::WrappedToken(symbol.clone()))
-            .expect("token not registered");
+            .unwrap_or_else(|| panic_with_error!(&env, WrappedTokenError::TokenNotRegistered));
 
         wrapped.total_wrapped -= amount;
         let _ttl_key = DataKey::WrappedToken(symbol);
         env.storage().persistent().set(&_ttl_key, &wrapped);
         env.storage().persistent().extend_ttl(&_ttl_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
 
-        env.events().publish(
-            (symbol_short!("wrapped"), symbol_short!("burned")),
-            (user, amount, target_address),
-        );
+        events::publish(&env, EVENT_TAG, WrappedBurned { user, amount, target_address });
     }
 
     pub fn get_wrapped_token(env: Env, symbol: String) -> Option<WrappedToken> {
@@ -0,0 +1,186 @@
+//! PulsarTrack - Access Control (Soroban)
+//! Shared ownership, role and pause checks for PulsarTrack contracts.
+//!
+//! The helpers keep no keys of their own: each call takes the storage key the
+//! calling contract already uses (`DataKey::Admin`, `DataKey::Verifiers`, ...),
+//! so adopting them changes no on-chain layout. Everything lives in instance
+//! storage, which the contracts already bump on every entry point.
+//!
+//! - `Ownable`: a single owner address stored under one key.
+//! - `RoleBased`: role members stored as a `Vec<Address>` under one key per role.
+//! - `Pausable`: a `bool` flag stored under one key.
+//!
+//! Checks return `AccessError`; contracts map it into their own error enum
+//! with a `From` impl, or panic on it.
+
+
+#![no_std]
+use soroban_sdk::{Address, Env, IntoVal, Val, Vec};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessError {
+    NotInitialized,
+    AlreadyInitialized,
+    Unauthorized,
+    MissingRole,
+    Paused,
+}
+
+// ============================================================
+// Ownable
+// ============================================================
+
+pub struct Ownable;
+
+impl Ownable {
+    /// Store the first owner; fails if one is already set
+    pub fn init<K>(env: &Env, key: &K, owner: &Address) -> Result<(), AccessError>
+    where
+        K: IntoVal<Env, Val>,
+    {
+        if env.storage().instance().has(key) {
+            return Err(AccessError::AlreadyInitialized);
+        }
+        owner.require_auth();
+        env.storage().instance().set(key, owner);
+        Ok(())
+    }
+
+    pub fn owner<K>(env: &Env, key: &K) -> Option<Address>
+    where
+        K: IntoVal<Env, Val>,
+    {
+        env.storage().instance().get(key)
+    }
+
+    pub fn is_owner<K>(env: &Env, key: &K, account: &Address) -> bool
+    where
+        K: IntoVal<Env, Val>,
+    {
+        Self::owner(env, key).as_ref() == Some(account)
+    }
+
+    /// Require `caller`'s auth and that it is the stored owner
+    pub fn require_owner<K>(env: &Env, key: &K, caller: &Address) -> Result<(), AccessError>
+    where
+        K: IntoVal<Env, Val>,
+    {
+        caller.require_auth();
+        let owner = Self::owner(env, key).ok_or(AccessError::NotInitialized)?;
+        if *caller != owner {
+            return Err(AccessError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    pub fn transfer_ownership<K>(env: &Env, key: &K, owner: &Address, new_owner: &Address) -> Result<(), AccessError>
+    where
+        K: IntoVal<Env, Val>,
+    {
+        Self::require_owner(env, key, owner)?;
+        env.storage().instance().set(key, new_owner);
+        Ok(())
+    }
+}
+
+// ============================================================
+// RoleBased
+// ============================================================
+
+pub struct RoleBased;
+
+impl RoleBased {
+    pub fn members<K>(env: &Env, role: &K) -> Vec<Address>
+    where
+        K: IntoVal<Env, Val>,
+    {
+        env.storage().instance().get(role).unwrap_or(Vec::new(env))
+    }
+
+    pub fn has_role<K>(env: &Env, role: &K, account: &Address) -> bool
+    where
+        K: IntoVal<Env, Val>,
+    {
+        Self::members(env, role).contains(account)
+    }
+
+    /// Add `account` to the role; returns false if it already held it
+    pub fn grant_role<K>(env: &Env, role: &K, account: &Address) -> bool
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let mut members = Self::members(env, role);
+        if members.contains(account) {
+            return false;
+        }
+        members.push_back(account.clone());
+        env.storage().instance().set(role, &members);
+        true
+    }
+
+    /// Remove `account` from the role; returns false if it didn't hold it
+    pub fn revoke_role<K>(env: &Env, role: &K, account: &Address) -> bool
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let mut members = Self::members(env, role);
+        match members.first_index_of(account) {
+            Some(idx) => {
+                members.remove(idx);
+                env.storage().instance().set(role, &members);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Require `account`'s auth and that it holds the role
+    pub fn require_role<K>(env: &Env, role: &K, account: &Address) -> Result<(), AccessError>
+    where
+        K: IntoVal<Env, Val>,
+    {
+        account.require_auth();
+        if !Self::has_role(env, role, account) {
+            return Err(AccessError::MissingRole);
+        }
+        Ok(())
+    }
+}
+
+// ============================================================
+// Pausable
+// ============================================================
+
+pub struct Pausable;
+
+impl Pausable {
+    pub fn is_paused<K>(env: &Env, key: &K) -> bool
+    where
+        K: IntoVal<Env, Val>,
+    {
+        env.storage().instance().get(key).unwrap_or(false)
+    }
+
+    pub fn set_paused<K>(env: &Env, key: &K, paused: bool)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        if paused {
+            env.storage().instance().set(key, &true);
+        } else {
+            env.storage().instance().remove(key);
+        }
+    }
+
+    pub fn require_not_paused<K>(env: &Env, key: &K) -> Result<(), AccessError>
+    where
+        K: IntoVal<Env, Val>,
+    {
+        if Self::is_paused(env, key) {
+            return Err(AccessError::Paused);
+        }
+        Ok(())
+    }
+}
+
+mod test;
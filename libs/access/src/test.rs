@@ -0,0 +1,77 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{contract, contracttype, testutils::Address as _, Address, Env};
+
+// Stand-in contract giving the helpers a storage context
+#[contract]
+pub struct Host;
+
+#[contracttype]
+pub enum Key {
+    Owner,
+    Verifiers,
+    Paused,
+}
+
+#[test]
+fn test_ownable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let host = env.register(Host, ());
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    // Each auth check needs a fresh frame, so each step runs in its own call
+    let run = |f: &dyn Fn()| env.as_contract(&host, f);
+    run(&|| assert_eq!(Ownable::require_owner(&env, &Key::Owner, &owner), Err(AccessError::NotInitialized)));
+    run(&|| Ownable::init(&env, &Key::Owner, &owner).unwrap());
+    run(&|| assert_eq!(Ownable::init(&env, &Key::Owner, &other), Err(AccessError::AlreadyInitialized)));
+    run(&|| assert!(Ownable::require_owner(&env, &Key::Owner, &owner).is_ok()));
+    run(&|| assert_eq!(Ownable::require_owner(&env, &Key::Owner, &other), Err(AccessError::Unauthorized)));
+    run(&|| assert_eq!(Ownable::transfer_ownership(&env, &Key::Owner, &other, &other), Err(AccessError::Unauthorized)));
+    run(&|| Ownable::transfer_ownership(&env, &Key::Owner, &owner, &other).unwrap());
+    run(&|| {
+        assert!(Ownable::is_owner(&env, &Key::Owner, &other));
+        assert!(!Ownable::is_owner(&env, &Key::Owner, &owner));
+    });
+}
+
+#[test]
+fn test_roles() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let host = env.register(Host, ());
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let run = |f: &dyn Fn()| env.as_contract(&host, f);
+    run(&|| {
+        assert!(RoleBased::grant_role(&env, &Key::Verifiers, &alice));
+        assert!(!RoleBased::grant_role(&env, &Key::Verifiers, &alice));
+    });
+    run(&|| assert!(RoleBased::require_role(&env, &Key::Verifiers, &alice).is_ok()));
+    run(&|| assert_eq!(RoleBased::require_role(&env, &Key::Verifiers, &bob), Err(AccessError::MissingRole)));
+    run(&|| {
+        assert!(RoleBased::grant_role(&env, &Key::Verifiers, &bob));
+        assert_eq!(RoleBased::members(&env, &Key::Verifiers).len(), 2);
+        assert!(RoleBased::revoke_role(&env, &Key::Verifiers, &alice));
+        assert!(!RoleBased::revoke_role(&env, &Key::Verifiers, &alice));
+        assert!(!RoleBased::has_role(&env, &Key::Verifiers, &alice));
+        assert!(RoleBased::has_role(&env, &Key::Verifiers, &bob));
+    });
+}
+
+#[test]
+fn test_pausable() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+
+    env.as_contract(&host, || {
+        assert!(Pausable::require_not_paused(&env, &Key::Paused).is_ok());
+        Pausable::set_paused(&env, &Key::Paused, true);
+        assert!(Pausable::is_paused(&env, &Key::Paused));
+        assert_eq!(Pausable::require_not_paused(&env, &Key::Paused), Err(AccessError::Paused));
+        Pausable::set_paused(&env, &Key::Paused, false);
+        assert!(!Pausable::is_paused(&env, &Key::Paused));
+    });
+}
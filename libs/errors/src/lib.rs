@@ -0,0 +1,2678 @@
+//! PulsarTrack - Error Codes (Soroban)
+//! Stable contract error codes shared across PulsarTrack contracts.
+//!
+//! Each contract owns a block of one hundred codes, so any code seen on-chain
+//! identifies both the contract that raised it and the failure. The core
+//! contracts hold 1xx-6xx; the rest follow in crate-name order from 7xx, and
+//! contracts added later take the next block after the last one:
+//!
+//! - 1xx `TokenError` (governance-token)
+//! - 2xx `TreasuryError` (multisig-treasury)
+//! - 3xx `RefundError` (refund-processor)
+//! - 4xx `VerificationError` (publisher-verification)
+//! - 5xx `ReputationError` (publisher-reputation)
+//! - 6xx `DaoError` (governance-dao)
+//! - 7xx `AdAuctionError` (ad-auction)
+//! - 8xx `AdRegistryError` (ad-registry)
+//! - 9xx `AnalyticsAggregatorError` (analytics-aggregator)
+//! - 10xx `AnalyticsError` (analytics)
+//! - 11xx `AnomalyDetectorError` (anomaly-detector)
+//! - 12xx `ArbitrationError` (arbitration)
+//! - 13xx `AttributionError` (attribution)
+//! - 14xx `AuctionEngineError` (auction-engine)
+//! - 15xx `AudienceSegmentsError` (audience-segments)
+//! - 16xx `BudgetOptimizerError` (budget-optimizer)
+//! - 17xx `CampaignAnalyticsError` (campaign-analytics)
+//! - 18xx `CampaignLifecycleError` (campaign-lifecycle)
+//! - 19xx `CampaignOrchestratorError` (campaign-orchestrator)
+//! - 20xx `CreativeMarketplaceError` (creative-marketplace)
+//! - 21xx `DisputeResolutionError` (dispute-resolution)
+//! - 22xx `EscrowVaultError` (escrow-vault)
+//! - 23xx `EscrowError` (escrow)
+//! - 24xx `FeeManagerError` (fee-manager)
+//! - 25xx `FraudPreventionError` (fraud-prevention)
+//! - 26xx `FraudRegistryError` (fraud-registry)
+//! - 27xx `GovernanceCoreError` (governance-core)
+//! - 28xx `IdentityRegistryError` (identity-registry)
+//! - 29xx `InsurancePoolError` (insurance-pool)
+//! - 30xx `KycRegistryError` (kyc-registry)
+//! - 31xx `LiquidityPoolError` (liquidity-pool)
+//! - 32xx `MerkleDistributorError` (merkle-distributor)
+//! - 33xx `MilestoneTrackerError` (milestone-tracker)
+//! - 34xx `MulticallError` (multicall)
+//! - 35xx `OracleAggregatorError` (oracle-aggregator)
+//! - 36xx `OracleIntegrationError` (oracle-integration)
+//! - 37xx `PacingError` (pacing)
+//! - 38xx `ParticipationRewardsError` (participation-rewards)
+//! - 39xx `PaymentProcessorError` (payment-processor)
+//! - 40xx `PaymentStreamError` (payment-stream)
+//! - 41xx `PayoutAutomationError` (payout-automation)
+//! - 42xx `PerformanceOracleError` (performance-oracle)
+//! - 43xx `PlacementNftError` (placement-nft)
+//! - 44xx `PrivacyLayerError` (privacy-layer)
+//! - 45xx `PublisherNetworkError` (publisher-network)
+//! - 46xx `RecurringPaymentError` (recurring-payment)
+//! - 47xx `RegistryError` (registry)
+//! - 48xx `RevenueSettlementError` (revenue-settlement)
+//! - 49xx `RewardsDistributorError` (rewards-distributor)
+//! - 50xx `SplitterError` (splitter)
+//! - 51xx `StakingError` (staking)
+//! - 52xx `SubscriptionBenefitsError` (subscription-benefits)
+//! - 53xx `SubscriptionManagerError` (subscription-manager)
+//! - 54xx `SubscriptionError` (subscription)
+//! - 55xx `TargetingEngineError` (targeting-engine)
+//! - 56xx `TimelockExecutorError` (timelock-executor)
+//! - 57xx `TokenBridgeError` (token-bridge)
+//! - 58xx `VestingError` (vesting)
+//! - 59xx `WrappedTokenError` (wrapped-token)
+//!
+//! Within a block, x01-x03 are always already-initialized, not-initialized
+//! and unauthorized, whether or not the contract raises them. Codes are never
+//! renumbered or reused; new failures take the next free code in their block. `describe` maps any code back to its
+//! contract and message for client SDKs.
+
+
+#![no_std]
+use pulsartrack_access::AccessError;
+use pulsartrack_upgrade::UpgradeError;
+use soroban_sdk::{contracterror, Error};
+
+// ============================================================
+// 1xx - governance-token
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TokenError {
+    AlreadyInitialized = 101,
+    NotInitialized = 102,
+    Unauthorized = 103,
+    InvalidAmount = 104,
+    InsufficientBalance = 105,
+    InsufficientAllowance = 106,
+    ExceedsMaxSupply = 107,
+    Overflow = 108,
+    AccountFrozen = 109,
+    AccountNotFrozen = 110,
+    InvalidBps = 111,
+    AlreadyDelegated = 112,
+    PartialDelegationsActive = 113,
+    DelegationExceedsLimit = 114,
+    TooManyDelegates = 115,
+    DelegationNotFound = 116,
+    InvalidMetadata = 117,
+    AlreadyMigrated = 118,
+    VersionMismatch = 119,
+    SelfDelegation = 120,
+    DelegationCycle = 121,
+    DelegationChainTooLong = 122,
+}
+
+impl TokenError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            TokenError::AlreadyInitialized => "already initialized",
+            TokenError::NotInitialized => "not initialized",
+            TokenError::Unauthorized => "unauthorized",
+            TokenError::InvalidAmount => "invalid amount",
+            TokenError::InsufficientBalance => "insufficient balance",
+            TokenError::InsufficientAllowance => "insufficient allowance",
+            TokenError::ExceedsMaxSupply => "exceeds max supply",
+            TokenError::Overflow => "overflow",
+            TokenError::AccountFrozen => "account frozen",
+            TokenError::AccountNotFrozen => "account not frozen",
+            TokenError::InvalidBps => "invalid bps",
+            TokenError::AlreadyDelegated => "already delegated",
+            TokenError::PartialDelegationsActive => "partial delegations active",
+            TokenError::DelegationExceedsLimit => "delegation exceeds limit",
+            TokenError::TooManyDelegates => "too many delegates",
+            TokenError::DelegationNotFound => "delegation not found",
+            TokenError::InvalidMetadata => "invalid metadata",
+            TokenError::AlreadyMigrated => "already migrated",
+            TokenError::VersionMismatch => "version mismatch",
+            TokenError::SelfDelegation => "self delegation",
+            TokenError::DelegationCycle => "delegation cycle",
+            TokenError::DelegationChainTooLong => "delegation chain too long",
+        }
+    }
+}
+
+impl From<AccessError> for TokenError {
+    fn from(e: AccessError) -> Self {
+        match e {
+            AccessError::NotInitialized => TokenError::NotInitialized,
+            AccessError::AlreadyInitialized => TokenError::AlreadyInitialized,
+            _ => TokenError::Unauthorized,
+        }
+    }
+}
+
+impl From<UpgradeError> for TokenError {
+    fn from(e: UpgradeError) -> Self {
+        match e {
+            UpgradeError::AlreadyMigrated => TokenError::AlreadyMigrated,
+            UpgradeError::VersionMismatch => TokenError::VersionMismatch,
+        }
+    }
+}
+
+// ============================================================
+// 2xx - multisig-treasury
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TreasuryError {
+    AlreadyInitialized = 201,
+    NotInitialized = 202,
+    Unauthorized = 203,
+    NotSigner = 204,
+    InvalidAmount = 205,
+    InvalidRequiredSigners = 206,
+    AlreadyVoted = 207,
+    TxNotFound = 208,
+    TxNotPending = 209,
+    TxExpired = 210,
+    NotApproved = 211,
+    TxNotOpen = 212,
+    TxNotApproved = 213,
+    TimelockActive = 214,
+    CannotCallSelf = 215,
+    InvalidBatchSize = 216,
+    NotSignerChange = 217,
+    AlreadySigner = 218,
+    WouldDropBelowThreshold = 219,
+    InvalidWeight = 220,
+    TreasuryFrozen = 221,
+    InsufficientApprovals = 222,
+    NotProposer = 223,
+    InvalidLimit = 224,
+    SpendingLimitExceeded = 225,
+    DaoNotConfigured = 226,
+    UntrustedDao = 227,
+    ProposalAlreadyExecuted = 228,
+    ProposalNotFound = 229,
+    InvalidProposal = 230,
+    ProposalNotPassed = 231,
+    ProposalWrongTarget = 232,
+    AlreadyMigrated = 233,
+    VersionMismatch = 234,
+    InsufficientTreasuryBalance = 235,
+    LimitedTokenCall = 236,
+}
+
+impl TreasuryError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            TreasuryError::AlreadyInitialized => "already initialized",
+            TreasuryError::NotInitialized => "not initialized",
+            TreasuryError::Unauthorized => "unauthorized",
+            TreasuryError::NotSigner => "not signer",
+            TreasuryError::InvalidAmount => "invalid amount",
+            TreasuryError::InvalidRequiredSigners => "invalid required signers",
+            TreasuryError::AlreadyVoted => "already voted",
+            TreasuryError::TxNotFound => "transaction not found",
+            TreasuryError::TxNotPending => "transaction not pending",
+            TreasuryError::TxExpired => "transaction expired",
+            TreasuryError::NotApproved => "not approved",
+            TreasuryError::TxNotOpen => "transaction not open",
+            TreasuryError::TxNotApproved => "transaction not approved",
+            TreasuryError::TimelockActive => "timelock active",
+            TreasuryError::CannotCallSelf => "cannot call self",
+            TreasuryError::InvalidBatchSize => "invalid batch size",
+            TreasuryError::NotSignerChange => "not signer change",
+            TreasuryError::AlreadySigner => "already signer",
+            TreasuryError::WouldDropBelowThreshold => "would drop below threshold",
+            TreasuryError::InvalidWeight => "invalid weight",
+            TreasuryError::TreasuryFrozen => "treasury frozen",
+            TreasuryError::InsufficientApprovals => "insufficient approvals",
+            TreasuryError::NotProposer => "not proposer",
+            TreasuryError::InvalidLimit => "invalid limit",
+            TreasuryError::SpendingLimitExceeded => "spending limit exceeded",
+            TreasuryError::DaoNotConfigured => "dao not configured",
+            TreasuryError::UntrustedDao => "untrusted dao",
+            TreasuryError::ProposalAlreadyExecuted => "proposal already executed",
+            TreasuryError::ProposalNotFound => "proposal not found",
+            TreasuryError::InvalidProposal => "invalid proposal",
+            TreasuryError::ProposalNotPassed => "proposal not passed",
+            TreasuryError::ProposalWrongTarget => "proposal wrong target",
+            TreasuryError::AlreadyMigrated => "already migrated",
+            TreasuryError::VersionMismatch => "version mismatch",
+            TreasuryError::InsufficientTreasuryBalance => "insufficient treasury balance",
+            TreasuryError::LimitedTokenCall => "call targets a spending-limited token",
+        }
+    }
+}
+
+impl From<AccessError> for TreasuryError {
+    fn from(e: AccessError) -> Self {
+        match e {
+            AccessError::NotInitialized => TreasuryError::NotInitialized,
+            AccessError::AlreadyInitialized => TreasuryError::AlreadyInitialized,
+            _ => TreasuryError::Unauthorized,
+        }
+    }
+}
+
+impl From<UpgradeError> for TreasuryError {
+    fn from(e: UpgradeError) -> Self {
+        match e {
+            UpgradeError::AlreadyMigrated => TreasuryError::AlreadyMigrated,
+            UpgradeError::VersionMismatch => TreasuryError::VersionMismatch,
+        }
+    }
+}
+
+// ============================================================
+// 3xx - refund-processor
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RefundError {
+    AlreadyInitialized = 301,
+    NotInitialized = 302,
+    Unauthorized = 303,
+    InvalidAmount = 304,
+    RefundNotFound = 305,
+    RefundUnderArbitration = 306,
+    InvalidStatus = 307,
+    RefundNotApproved = 308,
+    ArbitrationNotSet = 309,
+    CaseMismatch = 310,
+    AlreadyMigrated = 311,
+    VersionMismatch = 312,
+    OrchestratorNotSet = 313,
+    CampaignNotFound = 314,
+    NotContributor = 315,
+    ExceedsContribution = 316,
+    NotFiler = 317,
+    SettledByFiler = 318,
+}
+
+impl RefundError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            RefundError::AlreadyInitialized => "already initialized",
+            RefundError::NotInitialized => "not initialized",
+            RefundError::Unauthorized => "unauthorized",
+            RefundError::InvalidAmount => "invalid amount",
+            RefundError::RefundNotFound => "refund not found",
+            RefundError::RefundUnderArbitration => "refund under arbitration",
+            RefundError::InvalidStatus => "invalid status",
+            RefundError::RefundNotApproved => "refund not approved",
+            RefundError::ArbitrationNotSet => "arbitration not set",
+            RefundError::CaseMismatch => "case mismatch",
+            RefundError::AlreadyMigrated => "already migrated",
+            RefundError::VersionMismatch => "version mismatch",
+            RefundError::OrchestratorNotSet => "orchestrator not set",
+            RefundError::CampaignNotFound => "campaign not found",
+            RefundError::NotContributor => "not a contributor",
+            RefundError::ExceedsContribution => "exceeds contribution",
+            RefundError::NotFiler => "not an authorized filer",
+            RefundError::SettledByFiler => "settled by filer",
+        }
+    }
+}
+
+impl From<AccessError> for RefundError {
+    fn from(e: AccessError) -> Self {
+        match e {
+            AccessError::NotInitialized => RefundError::NotInitialized,
+            AccessError::AlreadyInitialized => RefundError::AlreadyInitialized,
+            _ => RefundError::Unauthorized,
+        }
+    }
+}
+
+impl From<UpgradeError> for RefundError {
+    fn from(e: UpgradeError) -> Self {
+        match e {
+            UpgradeError::AlreadyMigrated => RefundError::AlreadyMigrated,
+            UpgradeError::VersionMismatch => RefundError::VersionMismatch,
+        }
+    }
+}
+
+// ============================================================
+// 4xx - publisher-verification
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VerificationError {
+    AlreadyInitialized = 401,
+    NotInitialized = 402,
+    Unauthorized = 403,
+    PublisherNotFound = 404,
+    AlreadyRegistered = 405,
+    DomainAlreadyRegistered = 406,
+    DomainLimitReached = 407,
+    DomainNotOwned = 408,
+    NoPendingChallenge = 409,
+    TransferCooldownActive = 410,
+    SameOwner = 411,
+    AlreadyVerifier = 412,
+    NotVerifier = 413,
+    AlreadyOracle = 414,
+    NotOracle = 415,
+    AlreadySubscribed = 416,
+    NotSubscribed = 417,
+    UnknownKycProvider = 418,
+    KycNotFound = 419,
+    KycHashMismatch = 420,
+    KycNotAttested = 421,
+    KycNotExpired = 422,
+    InvalidValidity = 423,
+    ReapplyCooldownActive = 424,
+    NotVerified = 425,
+    NotSuspended = 426,
+    CannotDeactivate = 427,
+    NotInactive = 428,
+    GracePeriodActive = 429,
+    AlreadyRevoked = 430,
+    InvalidScore = 431,
+    InvalidTierConfig = 432,
+    InvalidBatchSize = 433,
+    InvalidEntry = 434,
+    InvalidEarning = 435,
+    InvalidBond = 436,
+    InvalidAmount = 437,
+    InvalidReferralConfig = 438,
+    InvalidReferrer = 439,
+    ReferralsNotConfigured = 440,
+    PoolNotEmpty = 441,
+    NothingToClaim = 442,
+    TooManyCategories = 443,
+    TooManyRegions = 444,
+    AlreadyMigrated = 445,
+    InvalidTierBenefits = 446,
+    VersionMismatch = 447,
+    EarningsNotConfigured = 448,
+    InsufficientEarnings = 449,
+    EarningsOutstanding = 450,
+}
+
+impl VerificationError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            VerificationError::AlreadyInitialized => "already initialized",
+            VerificationError::NotInitialized => "not initialized",
+            VerificationError::Unauthorized => "unauthorized",
+            VerificationError::PublisherNotFound => "publisher not found",
+            VerificationError::AlreadyRegistered => "already registered",
+            VerificationError::DomainAlreadyRegistered => "domain already registered",
+            VerificationError::DomainLimitReached => "domain limit reached",
+            VerificationError::DomainNotOwned => "domain not owned",
+            VerificationError::NoPendingChallenge => "no pending challenge",
+            VerificationError::TransferCooldownActive => "transfer cooldown active",
+            VerificationError::SameOwner => "same owner",
+            VerificationError::AlreadyVerifier => "already verifier",
+            VerificationError::NotVerifier => "not verifier",
+            VerificationError::AlreadyOracle => "already oracle",
+            VerificationError::NotOracle => "not oracle",
+            VerificationError::AlreadySubscribed => "already subscribed",
+            VerificationError::NotSubscribed => "not subscribed",
+            VerificationError::UnknownKycProvider => "unknown kyc provider",
+            VerificationError::KycNotFound => "kyc not found",
+            VerificationError::KycHashMismatch => "kyc hash mismatch",
+            VerificationError::KycNotAttested => "kyc not attested",
+            VerificationError::KycNotExpired => "kyc not expired",
+            VerificationError::InvalidValidity => "invalid validity",
+            VerificationError::ReapplyCooldownActive => "reapply cooldown active",
+            VerificationError::NotVerified => "not verified",
+            VerificationError::NotSuspended => "not suspended",
+            VerificationError::CannotDeactivate => "cannot deactivate",
+            VerificationError::NotInactive => "not inactive",
+            VerificationError::GracePeriodActive => "grace period active",
+            VerificationError::AlreadyRevoked => "already revoked",
+            VerificationError::InvalidScore => "invalid score",
+            VerificationError::InvalidTierConfig => "invalid tier config",
+            VerificationError::InvalidBatchSize => "invalid batch size",
+            VerificationError::InvalidEntry => "invalid entry",
+            VerificationError::InvalidEarning => "invalid earning",
+            VerificationError::InvalidBond => "invalid bond",
+            VerificationError::InvalidAmount => "invalid amount",
+            VerificationError::InvalidReferralConfig => "invalid referral config",
+            VerificationError::InvalidReferrer => "invalid referrer",
+            VerificationError::ReferralsNotConfigured => "referrals not configured",
+            VerificationError::PoolNotEmpty => "pool not empty",
+            VerificationError::NothingToClaim => "nothing to claim",
+            VerificationError::TooManyCategories => "too many categories",
+            VerificationError::TooManyRegions => "too many regions",
+            VerificationError::AlreadyMigrated => "already migrated",
+            VerificationError::InvalidTierBenefits => "invalid tier benefits",
+            VerificationError::VersionMismatch => "version mismatch",
+            VerificationError::EarningsNotConfigured => "earnings not configured",
+            VerificationError::InsufficientEarnings => "insufficient earnings",
+            VerificationError::EarningsOutstanding => "earnings outstanding",
+        }
+    }
+}
+
+impl From<AccessError> for VerificationError {
+    fn from(e: AccessError) -> Self {
+        match e {
+            AccessError::NotInitialized => VerificationError::NotInitialized,
+            AccessError::AlreadyInitialized => VerificationError::AlreadyInitialized,
+            _ => VerificationError::Unauthorized,
+        }
+    }
+}
+
+impl From<UpgradeError> for VerificationError {
+    fn from(e: UpgradeError) -> Self {
+        match e {
+            UpgradeError::AlreadyMigrated => VerificationError::AlreadyMigrated,
+            UpgradeError::VersionMismatch => VerificationError::VersionMismatch,
+        }
+    }
+}
+
+// ============================================================
+// 5xx - publisher-reputation
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReputationError {
+    AlreadyInitialized = 501,
+    NotInitialized = 502,
+    Unauthorized = 503,
+    PublisherNotRegistered = 504,
+    PublisherAlreadyRegistered = 505,
+    InvalidSpend = 506,
+    InvalidRating = 507,
+    InsufficientStake = 508,
+    ReviewNotAuthorized = 509,
+    ReviewNotFound = 510,
+    ReviewNotDisputable = 511,
+    ReviewNotDisputed = 512,
+    SlashNotFound = 513,
+    SlashAlreadyApplied = 514,
+    AlreadyConfirmed = 515,
+    InvalidBond = 516,
+    AppealsNotConfigured = 517,
+    SlashNotAppealable = 518,
+    BondTooLow = 519,
+    SlashNotAppealed = 520,
+    InvalidStake = 521,
+    InvalidScoringConfig = 522,
+    AlreadyOracle = 523,
+    NotOracle = 524,
+    WouldDropBelowThreshold = 525,
+    InvalidThreshold = 526,
+    InvalidUptime = 527,
+    InvalidQuality = 528,
+    StaleEpoch = 529,
+    ArbitrationNotSet = 530,
+    ReviewUnderArbitration = 531,
+    CaseMismatch = 532,
+    AlreadyMigrated = 533,
+    VersionMismatch = 534,
+    InvalidBatchSize = 535,
+}
+
+impl ReputationError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            ReputationError::AlreadyInitialized => "already initialized",
+            ReputationError::NotInitialized => "not initialized",
+            ReputationError::Unauthorized => "unauthorized",
+            ReputationError::PublisherNotRegistered => "publisher not registered",
+            ReputationError::PublisherAlreadyRegistered => "publisher already registered",
+            ReputationError::InvalidSpend => "invalid spend",
+            ReputationError::InvalidRating => "invalid rating",
+            ReputationError::InsufficientStake => "insufficient stake",
+            ReputationError::ReviewNotAuthorized => "review not authorized",
+            ReputationError::ReviewNotFound => "review not found",
+            ReputationError::ReviewNotDisputable => "review not disputable",
+            ReputationError::ReviewNotDisputed => "review not disputed",
+            ReputationError::SlashNotFound => "slash not found",
+            ReputationError::SlashAlreadyApplied => "slash already applied",
+            ReputationError::AlreadyConfirmed => "already confirmed",
+            ReputationError::InvalidBond => "invalid bond",
+            ReputationError::AppealsNotConfigured => "appeals not configured",
+            ReputationError::SlashNotAppealable => "slash not appealable",
+            ReputationError::BondTooLow => "bond too low",
+            ReputationError::SlashNotAppealed => "slash not appealed",
+            ReputationError::InvalidStake => "invalid stake",
+            ReputationError::InvalidScoringConfig => "invalid scoring config",
+            ReputationError::AlreadyOracle => "already oracle",
+            ReputationError::NotOracle => "not oracle",
+            ReputationError::WouldDropBelowThreshold => "would drop below threshold",
+            ReputationError::InvalidThreshold => "invalid threshold",
+            ReputationError::InvalidUptime => "invalid uptime",
+            ReputationError::InvalidQuality => "invalid quality",
+            ReputationError::StaleEpoch => "stale epoch",
+            ReputationError::ArbitrationNotSet => "arbitration not set",
+            ReputationError::ReviewUnderArbitration => "review under arbitration",
+            ReputationError::CaseMismatch => "case mismatch",
+            ReputationError::AlreadyMigrated => "already migrated",
+            ReputationError::VersionMismatch => "version mismatch",
+            ReputationError::InvalidBatchSize => "invalid batch size",
+        }
+    }
+}
+
+impl From<AccessError> for ReputationError {
+    fn from(e: AccessError) -> Self {
+        match e {
+            AccessError::NotInitialized => ReputationError::NotInitialized,
+            AccessError::AlreadyInitialized => ReputationError::AlreadyInitialized,
+            _ => ReputationError::Unauthorized,
+        }
+    }
+}
+
+impl From<UpgradeError> for ReputationError {
+    fn from(e: UpgradeError) -> Self {
+        match e {
+            UpgradeError::AlreadyMigrated => ReputationError::AlreadyMigrated,
+            UpgradeError::VersionMismatch => ReputationError::VersionMismatch,
+        }
+    }
+}
+
+// ============================================================
+// 6xx - governance-dao
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DaoError {
+    AlreadyInitialized = 601,
+    NotInitialized = 602,
+    Unauthorized = 603,
+    ReservedKind = 604,
+    EmergencyProposalsDisabled = 605,
+    InsufficientStake = 606,
+    TooManyEmergencyProposals = 607,
+    SignatureExpired = 608,
+    NoVoteKey = 609,
+    MetadataFrozen = 610,
+    TooManyMetadataEntries = 611,
+    NotVoted = 612,
+    ProposalNotFound = 613,
+    ProposalNotActive = 614,
+    VotingPeriodNotEnded = 615,
+    InvalidBounty = 616,
+    InvalidAmount = 617,
+    BountyNotConfigured = 618,
+    NoGuardian = 619,
+    ProposalNotQueued = 620,
+    CannotVetoOverride = 621,
+    TimelockElapsed = 622,
+    ProposalNotVetoed = 623,
+    InvalidEmergencyConfig = 624,
+    UnknownParam = 625,
+    InvalidDeposit = 626,
+    ProposalNotPassed = 627,
+    TimelockNotExpired = 628,
+    NoVotingPower = 629,
+    VotingPeriodEnded = 630,
+    InvalidVotingMode = 631,
+    InvalidKindConfig = 632,
+    ParamOutOfBounds = 633,
+    InvalidActionArgs = 634,
+    UnsupportedSelfCall = 635,
+    SnapshotUnavailable = 636,
+    AlreadyMigrated = 637,
+    VersionMismatch = 638,
+}
+
+impl DaoError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            DaoError::AlreadyInitialized => "already initialized",
+            DaoError::NotInitialized => "not initialized",
+            DaoError::Unauthorized => "unauthorized",
+            DaoError::ReservedKind => "reserved kind",
+            DaoError::EmergencyProposalsDisabled => "emergency proposals disabled",
+            DaoError::InsufficientStake => "insufficient stake",
+            DaoError::TooManyEmergencyProposals => "too many emergency proposals",
+            DaoError::SignatureExpired => "signature expired",
+            DaoError::NoVoteKey => "no vote key",
+            DaoError::MetadataFrozen => "metadata frozen",
+            DaoError::TooManyMetadataEntries => "too many metadata entries",
+            DaoError::NotVoted => "not voted",
+            DaoError::ProposalNotFound => "proposal not found",
+            DaoError::ProposalNotActive => "proposal not active",
+            DaoError::VotingPeriodNotEnded => "voting period not ended",
+            DaoError::InvalidBounty => "invalid bounty",
+            DaoError::InvalidAmount => "invalid amount",
+            DaoError::BountyNotConfigured => "bounty not configured",
+            DaoError::NoGuardian => "no guardian",
+            DaoError::ProposalNotQueued => "proposal not queued",
+            DaoError::CannotVetoOverride => "cannot veto an override",
+            DaoError::TimelockElapsed => "timelock elapsed",
+            DaoError::ProposalNotVetoed => "proposal not vetoed",
+            DaoError::InvalidEmergencyConfig => "invalid emergency config",
+            DaoError::UnknownParam => "unknown param",
+            DaoError::InvalidDeposit => "invalid deposit",
+            DaoError::ProposalNotPassed => "proposal not passed",
+            DaoError::TimelockNotExpired => "timelock not expired",
+            DaoError::NoVotingPower => "no voting power",
+            DaoError::VotingPeriodEnded => "voting period ended",
+            DaoError::InvalidVotingMode => "invalid voting mode",
+            DaoError::InvalidKindConfig => "invalid kind config",
+            DaoError::ParamOutOfBounds => "param out of bounds",
+            DaoError::InvalidActionArgs => "invalid action args",
+            DaoError::UnsupportedSelfCall => "unsupported self call",
+            DaoError::SnapshotUnavailable => "snapshot unavailable",
+            DaoError::AlreadyMigrated => "already migrated",
+            DaoError::VersionMismatch => "version mismatch",
+        }
+    }
+}
+
+impl From<AccessError> for DaoError {
+    fn from(e: AccessError) -> Self {
+        match e {
+            AccessError::NotInitialized => DaoError::NotInitialized,
+            AccessError::AlreadyInitialized => DaoError::AlreadyInitialized,
+            _ => DaoError::Unauthorized,
+        }
+    }
+}
+
+impl From<UpgradeError> for DaoError {
+    fn from(e: UpgradeError) -> Self {
+        match e {
+            UpgradeError::AlreadyMigrated => DaoError::AlreadyMigrated,
+            UpgradeError::VersionMismatch => DaoError::VersionMismatch,
+        }
+    }
+}
+
+// ============================================================
+// 7xx - ad-auction
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AdAuctionError {
+    AlreadyInitialized = 701,
+    NotInitialized = 702,
+    Unauthorized = 703,
+    TokenNotAccepted = 704,
+    InvalidFloorPrice = 705,
+    InvalidBiddingWindow = 706,
+    FixedPriceSlotsTakeOpenBids = 707,
+    InvalidRevealWindow = 708,
+    SlotNotOpen = 709,
+    SealedAuction = 710,
+    BiddingClosed = 711,
+    PublisherCannotBid = 712,
+    BidBelowFloorPrice = 713,
+    BidMustMatchFixedPrice = 714,
+    BidNotRaised = 715,
+    NotASealedAuction = 716,
+    DepositBelowFloorPrice = 717,
+    AlreadyCommitted = 718,
+    NotInRevealWindow = 719,
+    BidNotFound = 720,
+    AlreadyRevealed = 721,
+    CommitmentMismatch = 722,
+    InvalidBidAmount = 723,
+    AuctionStillRunning = 724,
+    SlotNotSettled = 725,
+    SlotNotFound = 726,
+}
+
+impl AdAuctionError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            AdAuctionError::AlreadyInitialized => "already initialized",
+            AdAuctionError::NotInitialized => "not initialized",
+            AdAuctionError::Unauthorized => "unauthorized",
+            AdAuctionError::TokenNotAccepted => "token not accepted",
+            AdAuctionError::InvalidFloorPrice => "invalid floor price",
+            AdAuctionError::InvalidBiddingWindow => "invalid bidding window",
+            AdAuctionError::FixedPriceSlotsTakeOpenBids => "fixed price slots take open bids",
+            AdAuctionError::InvalidRevealWindow => "invalid reveal window",
+            AdAuctionError::SlotNotOpen => "slot not open",
+            AdAuctionError::SealedAuction => "sealed auction",
+            AdAuctionError::BiddingClosed => "bidding closed",
+            AdAuctionError::PublisherCannotBid => "publisher cannot bid",
+            AdAuctionError::BidBelowFloorPrice => "bid below floor price",
+            AdAuctionError::BidMustMatchFixedPrice => "bid must match fixed price",
+            AdAuctionError::BidNotRaised => "bid not raised",
+            AdAuctionError::NotASealedAuction => "not a sealed auction",
+            AdAuctionError::DepositBelowFloorPrice => "deposit below floor price",
+            AdAuctionError::AlreadyCommitted => "already committed",
+            AdAuctionError::NotInRevealWindow => "not in reveal window",
+            AdAuctionError::BidNotFound => "bid not found",
+            AdAuctionError::AlreadyRevealed => "already revealed",
+            AdAuctionError::CommitmentMismatch => "commitment mismatch",
+            AdAuctionError::InvalidBidAmount => "invalid bid amount",
+            AdAuctionError::AuctionStillRunning => "auction still running",
+            AdAuctionError::SlotNotSettled => "slot not settled",
+            AdAuctionError::SlotNotFound => "slot not found",
+        }
+    }
+}
+
+// ============================================================
+// 8xx - ad-registry
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AdRegistryError {
+    AlreadyInitialized = 801,
+    NotInitialized = 802,
+    Unauthorized = 803,
+    InvalidContentSize = 804,
+    ContentNotFound = 805,
+    CannotFlagOwnContent = 806,
+    ContentNotApproved = 807,
+    PerformanceNotFound = 808,
+}
+
+impl AdRegistryError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            AdRegistryError::AlreadyInitialized => "already initialized",
+            AdRegistryError::NotInitialized => "not initialized",
+            AdRegistryError::Unauthorized => "unauthorized",
+            AdRegistryError::InvalidContentSize => "invalid content size",
+            AdRegistryError::ContentNotFound => "content not found",
+            AdRegistryError::CannotFlagOwnContent => "cannot flag own content",
+            AdRegistryError::ContentNotApproved => "content not approved",
+            AdRegistryError::PerformanceNotFound => "performance not found",
+        }
+    }
+}
+
+// ============================================================
+// 9xx - analytics-aggregator
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AnalyticsAggregatorError {
+    AlreadyInitialized = 901,
+    NotInitialized = 902,
+    Unauthorized = 903,
+    AnalyticsNotFound = 904,
+}
+
+impl AnalyticsAggregatorError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            AnalyticsAggregatorError::AlreadyInitialized => "already initialized",
+            AnalyticsAggregatorError::NotInitialized => "not initialized",
+            AnalyticsAggregatorError::Unauthorized => "unauthorized",
+            AnalyticsAggregatorError::AnalyticsNotFound => "analytics not found",
+        }
+    }
+}
+
+// ============================================================
+// 10xx - analytics
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AnalyticsError {
+    AlreadyInitialized = 1001,
+    NotInitialized = 1002,
+    Unauthorized = 1003,
+    InvalidEpochLength = 1004,
+    InvalidAmount = 1005,
+    InvalidRange = 1006,
+}
+
+impl AnalyticsError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            AnalyticsError::AlreadyInitialized => "already initialized",
+            AnalyticsError::NotInitialized => "not initialized",
+            AnalyticsError::Unauthorized => "unauthorized",
+            AnalyticsError::InvalidEpochLength => "invalid epoch length",
+            AnalyticsError::InvalidAmount => "invalid amount",
+            AnalyticsError::InvalidRange => "invalid range",
+        }
+    }
+}
+
+// ============================================================
+// 11xx - anomaly-detector
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AnomalyDetectorError {
+    AlreadyInitialized = 1101,
+    NotInitialized = 1102,
+    Unauthorized = 1103,
+    ReportNotFound = 1104,
+}
+
+impl AnomalyDetectorError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            AnomalyDetectorError::AlreadyInitialized => "already initialized",
+            AnomalyDetectorError::NotInitialized => "not initialized",
+            AnomalyDetectorError::Unauthorized => "unauthorized",
+            AnomalyDetectorError::ReportNotFound => "report not found",
+        }
+    }
+}
+
+// ============================================================
+// 12xx - arbitration
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ArbitrationError {
+    AlreadyInitialized = 1201,
+    NotInitialized = 1202,
+    Unauthorized = 1203,
+    InvalidAmount = 1204,
+    StakeBelowMinimum = 1205,
+    JurorPoolFull = 1206,
+    JurorNotFound = 1207,
+    JurorHasActiveCases = 1208,
+    NotEnoughJurors = 1209,
+    NotOnPanel = 1210,
+    CommitPeriodEnded = 1211,
+    NotInRevealPeriod = 1212,
+    AlreadyRevealed = 1213,
+    NoCommitment = 1214,
+    CommitmentMismatch = 1215,
+    CaseAlreadyRuled = 1216,
+    RevealPeriodActive = 1217,
+    InvalidConfig = 1218,
+    InvalidPanelSize = 1219,
+    CaseNotFound = 1220,
+    CaseNotRuled = 1221,
+    RulingAlreadyDelivered = 1222,
+}
+
+impl ArbitrationError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            ArbitrationError::AlreadyInitialized => "already initialized",
+            ArbitrationError::NotInitialized => "not initialized",
+            ArbitrationError::Unauthorized => "unauthorized",
+            ArbitrationError::InvalidAmount => "invalid amount",
+            ArbitrationError::StakeBelowMinimum => "stake below minimum",
+            ArbitrationError::JurorPoolFull => "juror pool full",
+            ArbitrationError::JurorNotFound => "juror not found",
+            ArbitrationError::JurorHasActiveCases => "juror has active cases",
+            ArbitrationError::NotEnoughJurors => "not enough jurors",
+            ArbitrationError::NotOnPanel => "not on panel",
+            ArbitrationError::CommitPeriodEnded => "commit period ended",
+            ArbitrationError::NotInRevealPeriod => "not in reveal period",
+            ArbitrationError::AlreadyRevealed => "already revealed",
+            ArbitrationError::NoCommitment => "no commitment",
+            ArbitrationError::CommitmentMismatch => "commitment mismatch",
+            ArbitrationError::CaseAlreadyRuled => "case already ruled",
+            ArbitrationError::RevealPeriodActive => "reveal period active",
+            ArbitrationError::InvalidConfig => "invalid config",
+            ArbitrationError::InvalidPanelSize => "invalid panel size",
+            ArbitrationError::CaseNotFound => "case not found",
+            ArbitrationError::CaseNotRuled => "case not ruled",
+            ArbitrationError::RulingAlreadyDelivered => "ruling already delivered",
+        }
+    }
+}
+
+// ============================================================
+// 13xx - attribution
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AttributionError {
+    AlreadyInitialized = 1301,
+    NotInitialized = 1302,
+    Unauthorized = 1303,
+    InvalidThreshold = 1304,
+    AlreadyAnOracle = 1305,
+    TooManyOracles = 1306,
+    NotAnOracle = 1307,
+    AlreadyRecorded = 1308,
+    InvalidClick = 1309,
+    InvalidValue = 1310,
+    ClickNotFound = 1311,
+    ClickMismatch = 1312,
+    UnknownOracle = 1313,
+    DuplicateSigner = 1314,
+    NotEnoughSignatures = 1315,
+}
+
+impl AttributionError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            AttributionError::AlreadyInitialized => "already initialized",
+            AttributionError::NotInitialized => "not initialized",
+            AttributionError::Unauthorized => "unauthorized",
+            AttributionError::InvalidThreshold => "invalid threshold",
+            AttributionError::AlreadyAnOracle => "already an oracle",
+            AttributionError::TooManyOracles => "too many oracles",
+            AttributionError::NotAnOracle => "not an oracle",
+            AttributionError::AlreadyRecorded => "already recorded",
+            AttributionError::InvalidClick => "invalid click",
+            AttributionError::InvalidValue => "invalid value",
+            AttributionError::ClickNotFound => "click not found",
+            AttributionError::ClickMismatch => "click mismatch",
+            AttributionError::UnknownOracle => "unknown oracle",
+            AttributionError::DuplicateSigner => "duplicate signer",
+            AttributionError::NotEnoughSignatures => "not enough signatures",
+        }
+    }
+}
+
+// ============================================================
+// 14xx - auction-engine
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AuctionEngineError {
+    AlreadyInitialized = 1401,
+    NotInitialized = 1402,
+    Unauthorized = 1403,
+    AuctionNotFound = 1404,
+    AuctionNotOpen = 1405,
+    AuctionEnded = 1406,
+    BidBelowFloorPrice = 1407,
+    BidTooLow = 1408,
+    AuctionStillRunning = 1409,
+}
+
+impl AuctionEngineError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            AuctionEngineError::AlreadyInitialized => "already initialized",
+            AuctionEngineError::NotInitialized => "not initialized",
+            AuctionEngineError::Unauthorized => "unauthorized",
+            AuctionEngineError::AuctionNotFound => "auction not found",
+            AuctionEngineError::AuctionNotOpen => "auction not open",
+            AuctionEngineError::AuctionEnded => "auction ended",
+            AuctionEngineError::BidBelowFloorPrice => "bid below floor price",
+            AuctionEngineError::BidTooLow => "bid too low",
+            AuctionEngineError::AuctionStillRunning => "auction still running",
+        }
+    }
+}
+
+// ============================================================
+// 15xx - audience-segments
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AudienceSegmentsError {
+    AlreadyInitialized = 1501,
+    NotInitialized = 1502,
+    Unauthorized = 1503,
+    SegmentNotFound = 1504,
+    AlreadyAMember = 1505,
+}
+
+impl AudienceSegmentsError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            AudienceSegmentsError::AlreadyInitialized => "already initialized",
+            AudienceSegmentsError::NotInitialized => "not initialized",
+            AudienceSegmentsError::Unauthorized => "unauthorized",
+            AudienceSegmentsError::SegmentNotFound => "segment not found",
+            AudienceSegmentsError::AlreadyAMember => "already a member",
+        }
+    }
+}
+
+// ============================================================
+// 16xx - budget-optimizer
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BudgetOptimizerError {
+    AlreadyInitialized = 1601,
+    NotInitialized = 1602,
+    Unauthorized = 1603,
+    DailyBudgetExceedsTotal = 1604,
+    AllocationNotFound = 1605,
+}
+
+impl BudgetOptimizerError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            BudgetOptimizerError::AlreadyInitialized => "already initialized",
+            BudgetOptimizerError::NotInitialized => "not initialized",
+            BudgetOptimizerError::Unauthorized => "unauthorized",
+            BudgetOptimizerError::DailyBudgetExceedsTotal => "daily budget exceeds total",
+            BudgetOptimizerError::AllocationNotFound => "allocation not found",
+        }
+    }
+}
+
+// ============================================================
+// 17xx - campaign-analytics
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CampaignAnalyticsError {
+    AlreadyInitialized = 1701,
+    NotInitialized = 1702,
+    Unauthorized = 1703,
+}
+
+impl CampaignAnalyticsError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            CampaignAnalyticsError::AlreadyInitialized => "already initialized",
+            CampaignAnalyticsError::NotInitialized => "not initialized",
+            CampaignAnalyticsError::Unauthorized => "unauthorized",
+        }
+    }
+}
+
+// ============================================================
+// 18xx - campaign-lifecycle
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CampaignLifecycleError {
+    AlreadyInitialized = 1801,
+    NotInitialized = 1802,
+    Unauthorized = 1803,
+    LifecycleNotFound = 1804,
+    InvalidStateTransition = 1805,
+}
+
+impl CampaignLifecycleError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            CampaignLifecycleError::AlreadyInitialized => "already initialized",
+            CampaignLifecycleError::NotInitialized => "not initialized",
+            CampaignLifecycleError::Unauthorized => "unauthorized",
+            CampaignLifecycleError::LifecycleNotFound => "lifecycle not found",
+            CampaignLifecycleError::InvalidStateTransition => "invalid state transition",
+        }
+    }
+}
+
+// ============================================================
+// 19xx - campaign-orchestrator
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CampaignOrchestratorError {
+    AlreadyInitialized = 1901,
+    NotInitialized = 1902,
+    Unauthorized = 1903,
+    CampaignTypeNotFound = 1904,
+    BudgetTooLow = 1905,
+    InvalidDuration = 1906,
+    CampaignNotFound = 1907,
+    PublisherNotVerified = 1908,
+    CampaignNotActive = 1909,
+    CampaignExpired = 1910,
+    InsufficientBudget = 1911,
+    DailyViewLimitReached = 1912,
+    PacingLimitReached = 1913,
+    CampaignNotRefundable = 1914,
+    FeeTooHigh = 1915,
+    PacingNotSet = 1916,
+    PlacementNftNotSet = 1917,
+    PlacementSlotNotHeld = 1918,
+}
+
+impl CampaignOrchestratorError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            CampaignOrchestratorError::AlreadyInitialized => "already initialized",
+            CampaignOrchestratorError::NotInitialized => "not initialized",
+            CampaignOrchestratorError::Unauthorized => "unauthorized",
+            CampaignOrchestratorError::CampaignTypeNotFound => "campaign type not found",
+            CampaignOrchestratorError::BudgetTooLow => "budget too low",
+            CampaignOrchestratorError::InvalidDuration => "invalid duration",
+            CampaignOrchestratorError::CampaignNotFound => "campaign not found",
+            CampaignOrchestratorError::PublisherNotVerified => "publisher not verified",
+            CampaignOrchestratorError::CampaignNotActive => "campaign not active",
+            CampaignOrchestratorError::CampaignExpired => "campaign expired",
+            CampaignOrchestratorError::InsufficientBudget => "insufficient budget",
+            CampaignOrchestratorError::DailyViewLimitReached => "daily view limit reached",
+            CampaignOrchestratorError::PacingLimitReached => "pacing limit reached",
+            CampaignOrchestratorError::CampaignNotRefundable => "campaign not refundable",
+            CampaignOrchestratorError::FeeTooHigh => "fee too high",
+            CampaignOrchestratorError::PacingNotSet => "pacing not set",
+            CampaignOrchestratorError::PlacementNftNotSet => "placement nft not set",
+            CampaignOrchestratorError::PlacementSlotNotHeld => "placement slot not held",
+        }
+    }
+}
+
+// ============================================================
+// 20xx - creative-marketplace
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CreativeMarketplaceError {
+    AlreadyInitialized = 2001,
+    NotInitialized = 2002,
+    Unauthorized = 2003,
+    InvalidPrice = 2004,
+    ListingNotFound = 2005,
+    ListingNotActive = 2006,
+    AlreadyLicensed = 2007,
+}
+
+impl CreativeMarketplaceError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            CreativeMarketplaceError::AlreadyInitialized => "already initialized",
+            CreativeMarketplaceError::NotInitialized => "not initialized",
+            CreativeMarketplaceError::Unauthorized => "unauthorized",
+            CreativeMarketplaceError::InvalidPrice => "invalid price",
+            CreativeMarketplaceError::ListingNotFound => "listing not found",
+            CreativeMarketplaceError::ListingNotActive => "listing not active",
+            CreativeMarketplaceError::AlreadyLicensed => "already licensed",
+        }
+    }
+}
+
+// ============================================================
+// 21xx - dispute-resolution
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DisputeResolutionError {
+    AlreadyInitialized = 2101,
+    NotInitialized = 2102,
+    Unauthorized = 2103,
+    ArbitratorNotAuthorized = 2104,
+    DisputeNotFound = 2105,
+    NotAssignedArbitrator = 2106,
+    InvalidOutcome = 2107,
+}
+
+impl DisputeResolutionError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            DisputeResolutionError::AlreadyInitialized => "already initialized",
+            DisputeResolutionError::NotInitialized => "not initialized",
+            DisputeResolutionError::Unauthorized => "unauthorized",
+            DisputeResolutionError::ArbitratorNotAuthorized => "arbitrator not authorized",
+            DisputeResolutionError::DisputeNotFound => "dispute not found",
+            DisputeResolutionError::NotAssignedArbitrator => "not assigned arbitrator",
+            DisputeResolutionError::InvalidOutcome => "invalid outcome",
+        }
+    }
+}
+
+// ============================================================
+// 22xx - escrow-vault
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum EscrowVaultError {
+    AlreadyInitialized = 2201,
+    NotInitialized = 2202,
+    Unauthorized = 2203,
+    InvalidAmount = 2204,
+    InvalidPerformanceThreshold = 2205,
+    NotARequiredApprover = 2206,
+    EscrowNotFound = 2207,
+    AlreadyReleased = 2208,
+    NothingToRelease = 2209,
+    EscrowNotYetExpired = 2210,
+    NothingToRefund = 2211,
+    InvalidPerformance = 2212,
+    TimeLockActive = 2213,
+    ApprovalRequired = 2214,
+    PerformanceThresholdNotMet = 2215,
+}
+
+impl EscrowVaultError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            EscrowVaultError::AlreadyInitialized => "already initialized",
+            EscrowVaultError::NotInitialized => "not initialized",
+            EscrowVaultError::Unauthorized => "unauthorized",
+            EscrowVaultError::InvalidAmount => "invalid amount",
+            EscrowVaultError::InvalidPerformanceThreshold => "invalid performance threshold",
+            EscrowVaultError::NotARequiredApprover => "not a required approver",
+            EscrowVaultError::EscrowNotFound => "escrow not found",
+            EscrowVaultError::AlreadyReleased => "already released",
+            EscrowVaultError::NothingToRelease => "nothing to release",
+            EscrowVaultError::EscrowNotYetExpired => "escrow not yet expired",
+            EscrowVaultError::NothingToRefund => "nothing to refund",
+            EscrowVaultError::InvalidPerformance => "invalid performance",
+            EscrowVaultError::TimeLockActive => "time lock active",
+            EscrowVaultError::ApprovalRequired => "approval required",
+            EscrowVaultError::PerformanceThresholdNotMet => "performance threshold not met",
+        }
+    }
+}
+
+// ============================================================
+// 23xx - escrow
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum EscrowError {
+    AlreadyInitialized = 2301,
+    NotInitialized = 2302,
+    Unauthorized = 2303,
+    InvalidMilestoneCount = 2304,
+    DepositorIsBeneficiary = 2305,
+    InvalidAmount = 2306,
+    InvalidDeadline = 2307,
+    AmountOverflow = 2308,
+    MilestoneNotPending = 2309,
+    DeadlineNotReached = 2310,
+    MilestoneNotDisputed = 2311,
+    NoRefundRequest = 2312,
+    RefundProcessorNotSet = 2313,
+    RefundNotFound = 2314,
+    InvalidRefund = 2315,
+    DisputePending = 2316,
+    EscrowNotFound = 2317,
+    MilestoneNotFound = 2318,
+    MilestoneAlreadySettled = 2319,
+}
+
+impl EscrowError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            EscrowError::AlreadyInitialized => "already initialized",
+            EscrowError::NotInitialized => "not initialized",
+            EscrowError::Unauthorized => "unauthorized",
+            EscrowError::InvalidMilestoneCount => "invalid milestone count",
+            EscrowError::DepositorIsBeneficiary => "depositor is beneficiary",
+            EscrowError::InvalidAmount => "invalid amount",
+            EscrowError::InvalidDeadline => "invalid deadline",
+            EscrowError::AmountOverflow => "amount overflow",
+            EscrowError::MilestoneNotPending => "milestone not pending",
+            EscrowError::DeadlineNotReached => "deadline not reached",
+            EscrowError::MilestoneNotDisputed => "milestone not disputed",
+            EscrowError::NoRefundRequest => "no refund request",
+            EscrowError::RefundProcessorNotSet => "refund processor not set",
+            EscrowError::RefundNotFound => "refund not found",
+            EscrowError::InvalidRefund => "invalid refund",
+            EscrowError::DisputePending => "dispute pending",
+            EscrowError::EscrowNotFound => "escrow not found",
+            EscrowError::MilestoneNotFound => "milestone not found",
+            EscrowError::MilestoneAlreadySettled => "milestone already settled",
+        }
+    }
+}
+
+// ============================================================
+// 24xx - fee-manager
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FeeManagerError {
+    AlreadyInitialized = 2401,
+    NotInitialized = 2402,
+    Unauthorized = 2403,
+    InvalidShare = 2404,
+    FeeTooHigh = 2405,
+    InvalidFee = 2406,
+    InvalidFeeBounds = 2407,
+    InvalidAmount = 2408,
+}
+
+impl FeeManagerError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            FeeManagerError::AlreadyInitialized => "already initialized",
+            FeeManagerError::NotInitialized => "not initialized",
+            FeeManagerError::Unauthorized => "unauthorized",
+            FeeManagerError::InvalidShare => "invalid share",
+            FeeManagerError::FeeTooHigh => "fee too high",
+            FeeManagerError::InvalidFee => "invalid fee",
+            FeeManagerError::InvalidFeeBounds => "invalid fee bounds",
+            FeeManagerError::InvalidAmount => "invalid amount",
+        }
+    }
+}
+
+// ============================================================
+// 25xx - fraud-prevention
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FraudPreventionError {
+    AlreadyInitialized = 2501,
+    NotInitialized = 2502,
+    Unauthorized = 2503,
+    RateLimitExceeded = 2504,
+    DuplicateView = 2505,
+    VerificationFailed = 2506,
+    InvalidThreshold = 2507,
+}
+
+impl FraudPreventionError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            FraudPreventionError::AlreadyInitialized => "already initialized",
+            FraudPreventionError::NotInitialized => "not initialized",
+            FraudPreventionError::Unauthorized => "unauthorized",
+            FraudPreventionError::RateLimitExceeded => "rate limit exceeded",
+            FraudPreventionError::DuplicateView => "duplicate view",
+            FraudPreventionError::VerificationFailed => "verification failed",
+            FraudPreventionError::InvalidThreshold => "invalid threshold",
+        }
+    }
+}
+
+// ============================================================
+// 26xx - fraud-registry
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FraudRegistryError {
+    AlreadyInitialized = 2601,
+    NotInitialized = 2602,
+    Unauthorized = 2603,
+    AlreadyAnAdjudicator = 2604,
+    NotAnAdjudicator = 2605,
+    CannotReportSelf = 2606,
+    BondBelowMinimum = 2607,
+    ReportNotOpen = 2608,
+    InvalidConfig = 2609,
+    ReportNotFound = 2610,
+    ReportAlreadyResolved = 2611,
+}
+
+impl FraudRegistryError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            FraudRegistryError::AlreadyInitialized => "already initialized",
+            FraudRegistryError::NotInitialized => "not initialized",
+            FraudRegistryError::Unauthorized => "unauthorized",
+            FraudRegistryError::AlreadyAnAdjudicator => "already an adjudicator",
+            FraudRegistryError::NotAnAdjudicator => "not an adjudicator",
+            FraudRegistryError::CannotReportSelf => "cannot report self",
+            FraudRegistryError::BondBelowMinimum => "bond below minimum",
+            FraudRegistryError::ReportNotOpen => "report not open",
+            FraudRegistryError::InvalidConfig => "invalid config",
+            FraudRegistryError::ReportNotFound => "report not found",
+            FraudRegistryError::ReportAlreadyResolved => "report already resolved",
+        }
+    }
+}
+
+// ============================================================
+// 27xx - governance-core
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum GovernanceCoreError {
+    AlreadyInitialized = 2701,
+    NotInitialized = 2702,
+    Unauthorized = 2703,
+}
+
+impl GovernanceCoreError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            GovernanceCoreError::AlreadyInitialized => "already initialized",
+            GovernanceCoreError::NotInitialized => "not initialized",
+            GovernanceCoreError::Unauthorized => "unauthorized",
+        }
+    }
+}
+
+// ============================================================
+// 28xx - identity-registry
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum IdentityRegistryError {
+    AlreadyInitialized = 2801,
+    NotInitialized = 2802,
+    Unauthorized = 2803,
+    AlreadyRegistered = 2804,
+    NameTaken = 2805,
+    IdentityNotFound = 2806,
+}
+
+impl IdentityRegistryError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            IdentityRegistryError::AlreadyInitialized => "already initialized",
+            IdentityRegistryError::NotInitialized => "not initialized",
+            IdentityRegistryError::Unauthorized => "unauthorized",
+            IdentityRegistryError::AlreadyRegistered => "already registered",
+            IdentityRegistryError::NameTaken => "name taken",
+            IdentityRegistryError::IdentityNotFound => "identity not found",
+        }
+    }
+}
+
+// ============================================================
+// 29xx - insurance-pool
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum InsurancePoolError {
+    AlreadyInitialized = 2901,
+    NotInitialized = 2902,
+    Unauthorized = 2903,
+    InvalidCap = 2904,
+    InvalidAmount = 2905,
+    AlreadyClaimed = 2906,
+    ExceedsCampaignCap = 2907,
+    ClaimNotPending = 2908,
+    DisputePending = 2909,
+    InsufficientPoolFunds = 2910,
+    ClaimNotFound = 2911,
+    InvalidRecord = 2912,
+    ReportNotFound = 2913,
+    FraudNotConfirmed = 2914,
+    NotAPublisherReport = 2915,
+    DisputeNotFound = 2916,
+}
+
+impl InsurancePoolError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            InsurancePoolError::AlreadyInitialized => "already initialized",
+            InsurancePoolError::NotInitialized => "not initialized",
+            InsurancePoolError::Unauthorized => "unauthorized",
+            InsurancePoolError::InvalidCap => "invalid cap",
+            InsurancePoolError::InvalidAmount => "invalid amount",
+            InsurancePoolError::AlreadyClaimed => "already claimed",
+            InsurancePoolError::ExceedsCampaignCap => "exceeds campaign cap",
+            InsurancePoolError::ClaimNotPending => "claim not pending",
+            InsurancePoolError::DisputePending => "dispute pending",
+            InsurancePoolError::InsufficientPoolFunds => "insufficient pool funds",
+            InsurancePoolError::ClaimNotFound => "claim not found",
+            InsurancePoolError::InvalidRecord => "invalid record",
+            InsurancePoolError::ReportNotFound => "report not found",
+            InsurancePoolError::FraudNotConfirmed => "fraud not confirmed",
+            InsurancePoolError::NotAPublisherReport => "not a publisher report",
+            InsurancePoolError::DisputeNotFound => "dispute not found",
+        }
+    }
+}
+
+// ============================================================
+// 30xx - kyc-registry
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum KycRegistryError {
+    AlreadyInitialized = 3001,
+    NotInitialized = 3002,
+    Unauthorized = 3003,
+    ProviderNotRegistered = 3004,
+    ProviderNotActive = 3005,
+    ProviderNotFound = 3006,
+    KycNotSubmitted = 3007,
+    KycNotFound = 3008,
+}
+
+impl KycRegistryError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            KycRegistryError::AlreadyInitialized => "already initialized",
+            KycRegistryError::NotInitialized => "not initialized",
+            KycRegistryError::Unauthorized => "unauthorized",
+            KycRegistryError::ProviderNotRegistered => "provider not registered",
+            KycRegistryError::ProviderNotActive => "provider not active",
+            KycRegistryError::ProviderNotFound => "provider not found",
+            KycRegistryError::KycNotSubmitted => "kyc not submitted",
+            KycRegistryError::KycNotFound => "kyc not found",
+        }
+    }
+}
+
+// ============================================================
+// 31xx - liquidity-pool
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum LiquidityPoolError {
+    AlreadyInitialized = 3101,
+    NotInitialized = 3102,
+    Unauthorized = 3103,
+    InvalidAmount = 3104,
+    NoPosition = 3105,
+    InsufficientShares = 3106,
+    InsufficientLiquidity = 3107,
+    AlreadyHasBorrow = 3108,
+    BorrowNotFound = 3109,
+}
+
+impl LiquidityPoolError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            LiquidityPoolError::AlreadyInitialized => "already initialized",
+            LiquidityPoolError::NotInitialized => "not initialized",
+            LiquidityPoolError::Unauthorized => "unauthorized",
+            LiquidityPoolError::InvalidAmount => "invalid amount",
+            LiquidityPoolError::NoPosition => "no position",
+            LiquidityPoolError::InsufficientShares => "insufficient shares",
+            LiquidityPoolError::InsufficientLiquidity => "insufficient liquidity",
+            LiquidityPoolError::AlreadyHasBorrow => "already has borrow",
+            LiquidityPoolError::BorrowNotFound => "borrow not found",
+        }
+    }
+}
+
+// ============================================================
+// 32xx - merkle-distributor
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MerkleDistributorError {
+    AlreadyInitialized = 3201,
+    NotInitialized = 3202,
+    Unauthorized = 3203,
+    InvalidTotal = 3204,
+    InvalidDeadline = 3205,
+    ClaimPeriodEnded = 3206,
+    AlreadyClaimed = 3207,
+    ProofTooLong = 3208,
+    InvalidProof = 3209,
+    InvalidAmount = 3210,
+    ClaimPeriodActive = 3211,
+    AlreadySwept = 3212,
+    DistributionNotFound = 3213,
+}
+
+impl MerkleDistributorError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            MerkleDistributorError::AlreadyInitialized => "already initialized",
+            MerkleDistributorError::NotInitialized => "not initialized",
+            MerkleDistributorError::Unauthorized => "unauthorized",
+            MerkleDistributorError::InvalidTotal => "invalid total",
+            MerkleDistributorError::InvalidDeadline => "invalid deadline",
+            MerkleDistributorError::ClaimPeriodEnded => "claim period ended",
+            MerkleDistributorError::AlreadyClaimed => "already claimed",
+            MerkleDistributorError::ProofTooLong => "proof too long",
+            MerkleDistributorError::InvalidProof => "invalid proof",
+            MerkleDistributorError::InvalidAmount => "invalid amount",
+            MerkleDistributorError::ClaimPeriodActive => "claim period active",
+            MerkleDistributorError::AlreadySwept => "already swept",
+            MerkleDistributorError::DistributionNotFound => "distribution not found",
+        }
+    }
+}
+
+// ============================================================
+// 33xx - milestone-tracker
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MilestoneTrackerError {
+    AlreadyInitialized = 3301,
+    NotInitialized = 3302,
+    Unauthorized = 3303,
+    MilestoneNotFound = 3304,
+}
+
+impl MilestoneTrackerError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            MilestoneTrackerError::AlreadyInitialized => "already initialized",
+            MilestoneTrackerError::NotInitialized => "not initialized",
+            MilestoneTrackerError::Unauthorized => "unauthorized",
+            MilestoneTrackerError::MilestoneNotFound => "milestone not found",
+        }
+    }
+}
+
+// ============================================================
+// 34xx - multicall
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MulticallError {
+    AlreadyInitialized = 3401,
+    NotInitialized = 3402,
+    Unauthorized = 3403,
+    NoCalls = 3404,
+    TooManyCalls = 3405,
+}
+
+impl MulticallError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            MulticallError::AlreadyInitialized => "already initialized",
+            MulticallError::NotInitialized => "not initialized",
+            MulticallError::Unauthorized => "unauthorized",
+            MulticallError::NoCalls => "no calls",
+            MulticallError::TooManyCalls => "too many calls",
+        }
+    }
+}
+
+// ============================================================
+// 35xx - oracle-aggregator
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum OracleAggregatorError {
+    AlreadyInitialized = 3501,
+    NotInitialized = 3502,
+    Unauthorized = 3503,
+    ReporterExists = 3504,
+    TooManyReporters = 3505,
+    ReporterNotFound = 3506,
+    NotAReporter = 3507,
+    RoundFinalized = 3508,
+    AlreadySubmitted = 3509,
+    InvalidQuorum = 3510,
+    InvalidOutlierBound = 3511,
+}
+
+impl OracleAggregatorError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            OracleAggregatorError::AlreadyInitialized => "already initialized",
+            OracleAggregatorError::NotInitialized => "not initialized",
+            OracleAggregatorError::Unauthorized => "unauthorized",
+            OracleAggregatorError::ReporterExists => "reporter exists",
+            OracleAggregatorError::TooManyReporters => "too many reporters",
+            OracleAggregatorError::ReporterNotFound => "reporter not found",
+            OracleAggregatorError::NotAReporter => "not a reporter",
+            OracleAggregatorError::RoundFinalized => "round finalized",
+            OracleAggregatorError::AlreadySubmitted => "already submitted",
+            OracleAggregatorError::InvalidQuorum => "invalid quorum",
+            OracleAggregatorError::InvalidOutlierBound => "invalid outlier bound",
+        }
+    }
+}
+
+// ============================================================
+// 36xx - oracle-integration
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum OracleIntegrationError {
+    AlreadyInitialized = 3601,
+    NotInitialized = 3602,
+    Unauthorized = 3603,
+    NotAuthorizedOracle = 3604,
+}
+
+impl OracleIntegrationError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            OracleIntegrationError::AlreadyInitialized => "already initialized",
+            OracleIntegrationError::NotInitialized => "not initialized",
+            OracleIntegrationError::Unauthorized => "unauthorized",
+            OracleIntegrationError::NotAuthorizedOracle => "not authorized oracle",
+        }
+    }
+}
+
+// ============================================================
+// 37xx - pacing
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PacingError {
+    AlreadyInitialized = 3701,
+    NotInitialized = 3702,
+    Unauthorized = 3703,
+    InvalidCap = 3704,
+    HourlyCapAboveDailyCap = 3705,
+    InvalidAmount = 3706,
+}
+
+impl PacingError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            PacingError::AlreadyInitialized => "already initialized",
+            PacingError::NotInitialized => "not initialized",
+            PacingError::Unauthorized => "unauthorized",
+            PacingError::InvalidCap => "invalid cap",
+            PacingError::HourlyCapAboveDailyCap => "hourly cap above daily cap",
+            PacingError::InvalidAmount => "invalid amount",
+        }
+    }
+}
+
+// ============================================================
+// 38xx - participation-rewards
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ParticipationRewardsError {
+    AlreadyInitialized = 3801,
+    NotInitialized = 3802,
+    Unauthorized = 3803,
+    InvalidEpochLength = 3804,
+    InvalidAmount = 3805,
+    AlreadyRecorded = 3806,
+    ProposalNotFound = 3807,
+    ProposalNotFinalized = 3808,
+    ProposalMissedQuorum = 3809,
+    NoVote = 3810,
+    StakeBelowMinimum = 3811,
+    InvalidRecord = 3812,
+    ProposalPredatesRewards = 3813,
+    EpochClosed = 3814,
+    NothingToClaim = 3815,
+    InvalidRules = 3816,
+}
+
+impl ParticipationRewardsError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            ParticipationRewardsError::AlreadyInitialized => "already initialized",
+            ParticipationRewardsError::NotInitialized => "not initialized",
+            ParticipationRewardsError::Unauthorized => "unauthorized",
+            ParticipationRewardsError::InvalidEpochLength => "invalid epoch length",
+            ParticipationRewardsError::InvalidAmount => "invalid amount",
+            ParticipationRewardsError::AlreadyRecorded => "already recorded",
+            ParticipationRewardsError::ProposalNotFound => "proposal not found",
+            ParticipationRewardsError::ProposalNotFinalized => "proposal not finalized",
+            ParticipationRewardsError::ProposalMissedQuorum => "proposal missed quorum",
+            ParticipationRewardsError::NoVote => "no vote",
+            ParticipationRewardsError::StakeBelowMinimum => "stake below minimum",
+            ParticipationRewardsError::InvalidRecord => "invalid record",
+            ParticipationRewardsError::ProposalPredatesRewards => "proposal predates rewards",
+            ParticipationRewardsError::EpochClosed => "epoch closed",
+            ParticipationRewardsError::NothingToClaim => "nothing to claim",
+            ParticipationRewardsError::InvalidRules => "invalid rules",
+        }
+    }
+}
+
+// ============================================================
+// 39xx - payment-processor
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PaymentProcessorError {
+    AlreadyInitialized = 3901,
+    NotInitialized = 3902,
+    Unauthorized = 3903,
+    CannotPayYourself = 3904,
+    InvalidAmount = 3905,
+    TokenNotWhitelisted = 3906,
+    TokenDisabled = 3907,
+    AmountBelowMinimum = 3908,
+    DailyLimitExceeded = 3909,
+    FeeTooHigh = 3910,
+}
+
+impl PaymentProcessorError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            PaymentProcessorError::AlreadyInitialized => "already initialized",
+            PaymentProcessorError::NotInitialized => "not initialized",
+            PaymentProcessorError::Unauthorized => "unauthorized",
+            PaymentProcessorError::CannotPayYourself => "cannot pay yourself",
+            PaymentProcessorError::InvalidAmount => "invalid amount",
+            PaymentProcessorError::TokenNotWhitelisted => "token not whitelisted",
+            PaymentProcessorError::TokenDisabled => "token disabled",
+            PaymentProcessorError::AmountBelowMinimum => "amount below minimum",
+            PaymentProcessorError::DailyLimitExceeded => "daily limit exceeded",
+            PaymentProcessorError::FeeTooHigh => "fee too high",
+        }
+    }
+}
+
+// ============================================================
+// 40xx - payment-stream
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PaymentStreamError {
+    AlreadyInitialized = 4001,
+    NotInitialized = 4002,
+    Unauthorized = 4003,
+    InvalidDeposit = 4004,
+    InvalidRate = 4005,
+    SenderIsRecipient = 4006,
+    StreamNotActive = 4007,
+    NotAnImpressionStream = 4008,
+    NothingToWithdraw = 4009,
+    StreamNotFound = 4010,
+}
+
+impl PaymentStreamError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            PaymentStreamError::AlreadyInitialized => "already initialized",
+            PaymentStreamError::NotInitialized => "not initialized",
+            PaymentStreamError::Unauthorized => "unauthorized",
+            PaymentStreamError::InvalidDeposit => "invalid deposit",
+            PaymentStreamError::InvalidRate => "invalid rate",
+            PaymentStreamError::SenderIsRecipient => "sender is recipient",
+            PaymentStreamError::StreamNotActive => "stream not active",
+            PaymentStreamError::NotAnImpressionStream => "not an impression stream",
+            PaymentStreamError::NothingToWithdraw => "nothing to withdraw",
+            PaymentStreamError::StreamNotFound => "stream not found",
+        }
+    }
+}
+
+// ============================================================
+// 41xx - payout-automation
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PayoutAutomationError {
+    AlreadyInitialized = 4101,
+    NotInitialized = 4102,
+    Unauthorized = 4103,
+    PayoutNotFound = 4104,
+    PayoutNotScheduled = 4105,
+    TooEarlyToExecute = 4106,
+}
+
+impl PayoutAutomationError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            PayoutAutomationError::AlreadyInitialized => "already initialized",
+            PayoutAutomationError::NotInitialized => "not initialized",
+            PayoutAutomationError::Unauthorized => "unauthorized",
+            PayoutAutomationError::PayoutNotFound => "payout not found",
+            PayoutAutomationError::PayoutNotScheduled => "payout not scheduled",
+            PayoutAutomationError::TooEarlyToExecute => "too early to execute",
+        }
+    }
+}
+
+// ============================================================
+// 42xx - performance-oracle
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PerformanceOracleError {
+    AlreadyInitialized = 4201,
+    NotInitialized = 4202,
+    Unauthorized = 4203,
+    NotAuthorizedAttester = 4204,
+    AlreadyAttested = 4205,
+}
+
+impl PerformanceOracleError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            PerformanceOracleError::AlreadyInitialized => "already initialized",
+            PerformanceOracleError::NotInitialized => "not initialized",
+            PerformanceOracleError::Unauthorized => "unauthorized",
+            PerformanceOracleError::NotAuthorizedAttester => "not authorized attester",
+            PerformanceOracleError::AlreadyAttested => "already attested",
+        }
+    }
+}
+
+// ============================================================
+// 43xx - placement-nft
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PlacementNftError {
+    AlreadyInitialized = 4301,
+    NotInitialized = 4302,
+    Unauthorized = 4303,
+    InvalidWindow = 4304,
+    RoyaltyTooHigh = 4305,
+    DomainOwnedByAnotherPublisher = 4306,
+    TooManySlots = 4307,
+    SlotWindowOverlaps = 4308,
+    InvalidPrice = 4309,
+    NotListed = 4310,
+    BuyerIsSeller = 4311,
+    TokenNotFound = 4312,
+    NotTokenOwner = 4313,
+}
+
+impl PlacementNftError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            PlacementNftError::AlreadyInitialized => "already initialized",
+            PlacementNftError::NotInitialized => "not initialized",
+            PlacementNftError::Unauthorized => "unauthorized",
+            PlacementNftError::InvalidWindow => "invalid window",
+            PlacementNftError::RoyaltyTooHigh => "royalty too high",
+            PlacementNftError::DomainOwnedByAnotherPublisher => "domain owned by another publisher",
+            PlacementNftError::TooManySlots => "too many slots",
+            PlacementNftError::SlotWindowOverlaps => "slot window overlaps",
+            PlacementNftError::InvalidPrice => "invalid price",
+            PlacementNftError::NotListed => "not listed",
+            PlacementNftError::BuyerIsSeller => "buyer is seller",
+            PlacementNftError::TokenNotFound => "token not found",
+            PlacementNftError::NotTokenOwner => "not token owner",
+        }
+    }
+}
+
+// ============================================================
+// 44xx - privacy-layer
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PrivacyLayerError {
+    AlreadyInitialized = 4401,
+    NotInitialized = 4402,
+    Unauthorized = 4403,
+    ProofNotFound = 4404,
+}
+
+impl PrivacyLayerError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            PrivacyLayerError::AlreadyInitialized => "already initialized",
+            PrivacyLayerError::NotInitialized => "not initialized",
+            PrivacyLayerError::Unauthorized => "unauthorized",
+            PrivacyLayerError::ProofNotFound => "proof not found",
+        }
+    }
+}
+
+// ============================================================
+// 45xx - publisher-network
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PublisherNetworkError {
+    AlreadyInitialized = 4501,
+    NotInitialized = 4502,
+    Unauthorized = 4503,
+    AlreadyInNetwork = 4504,
+    NotInNetwork = 4505,
+}
+
+impl PublisherNetworkError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            PublisherNetworkError::AlreadyInitialized => "already initialized",
+            PublisherNetworkError::NotInitialized => "not initialized",
+            PublisherNetworkError::Unauthorized => "unauthorized",
+            PublisherNetworkError::AlreadyInNetwork => "already in network",
+            PublisherNetworkError::NotInNetwork => "not in network",
+        }
+    }
+}
+
+// ============================================================
+// 46xx - recurring-payment
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RecurringPaymentError {
+    AlreadyInitialized = 4601,
+    NotInitialized = 4602,
+    Unauthorized = 4603,
+    InvalidAmount = 4604,
+    InvalidInterval = 4605,
+    PaymentNotFound = 4606,
+    PaymentNotActive = 4607,
+    TooEarly = 4608,
+    MaxPaymentsReached = 4609,
+}
+
+impl RecurringPaymentError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            RecurringPaymentError::AlreadyInitialized => "already initialized",
+            RecurringPaymentError::NotInitialized => "not initialized",
+            RecurringPaymentError::Unauthorized => "unauthorized",
+            RecurringPaymentError::InvalidAmount => "invalid amount",
+            RecurringPaymentError::InvalidInterval => "invalid interval",
+            RecurringPaymentError::PaymentNotFound => "payment not found",
+            RecurringPaymentError::PaymentNotActive => "payment not active",
+            RecurringPaymentError::TooEarly => "too early",
+            RecurringPaymentError::MaxPaymentsReached => "max payments reached",
+        }
+    }
+}
+
+// ============================================================
+// 47xx - registry
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RegistryError {
+    AlreadyInitialized = 4701,
+    NotInitialized = 4702,
+    Unauthorized = 4703,
+    TooManyKeys = 4704,
+    KeyNotRegistered = 4705,
+}
+
+impl RegistryError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            RegistryError::AlreadyInitialized => "already initialized",
+            RegistryError::NotInitialized => "not initialized",
+            RegistryError::Unauthorized => "unauthorized",
+            RegistryError::TooManyKeys => "too many keys",
+            RegistryError::KeyNotRegistered => "key not registered",
+        }
+    }
+}
+
+// ============================================================
+// 48xx - revenue-settlement
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RevenueSettlementError {
+    AlreadyInitialized = 4801,
+    NotInitialized = 4802,
+    Unauthorized = 4803,
+    NoBalanceToClaim = 4804,
+}
+
+impl RevenueSettlementError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            RevenueSettlementError::AlreadyInitialized => "already initialized",
+            RevenueSettlementError::NotInitialized => "not initialized",
+            RevenueSettlementError::Unauthorized => "unauthorized",
+            RevenueSettlementError::NoBalanceToClaim => "no balance to claim",
+        }
+    }
+}
+
+// ============================================================
+// 49xx - rewards-distributor
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RewardsDistributorError {
+    AlreadyInitialized = 4901,
+    NotInitialized = 4902,
+    Unauthorized = 4903,
+    ProgramNotFound = 4904,
+    ProgramNotActive = 4905,
+    ExceedsBudget = 4906,
+    ProgramEnded = 4907,
+    NoRewards = 4908,
+    NoPendingRewards = 4909,
+}
+
+impl RewardsDistributorError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            RewardsDistributorError::AlreadyInitialized => "already initialized",
+            RewardsDistributorError::NotInitialized => "not initialized",
+            RewardsDistributorError::Unauthorized => "unauthorized",
+            RewardsDistributorError::ProgramNotFound => "program not found",
+            RewardsDistributorError::ProgramNotActive => "program not active",
+            RewardsDistributorError::ExceedsBudget => "exceeds budget",
+            RewardsDistributorError::ProgramEnded => "program ended",
+            RewardsDistributorError::NoRewards => "no rewards",
+            RewardsDistributorError::NoPendingRewards => "no pending rewards",
+        }
+    }
+}
+
+// ============================================================
+// 50xx - splitter
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SplitterError {
+    AlreadyInitialized = 5001,
+    NotInitialized = 5002,
+    Unauthorized = 5003,
+    SplitIsImmutable = 5004,
+    InvalidAmount = 5005,
+    NothingToClaim = 5006,
+    InvalidRecipients = 5007,
+    LengthMismatch = 5008,
+    ZeroShare = 5009,
+    DuplicateRecipient = 5010,
+    SharesMustTotal10000Bps = 5011,
+    SplitNotFound = 5012,
+}
+
+impl SplitterError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            SplitterError::AlreadyInitialized => "already initialized",
+            SplitterError::NotInitialized => "not initialized",
+            SplitterError::Unauthorized => "unauthorized",
+            SplitterError::SplitIsImmutable => "split is immutable",
+            SplitterError::InvalidAmount => "invalid amount",
+            SplitterError::NothingToClaim => "nothing to claim",
+            SplitterError::InvalidRecipients => "invalid recipients",
+            SplitterError::LengthMismatch => "length mismatch",
+            SplitterError::ZeroShare => "zero share",
+            SplitterError::DuplicateRecipient => "duplicate recipient",
+            SplitterError::SharesMustTotal10000Bps => "shares must total 10000 bps",
+            SplitterError::SplitNotFound => "split not found",
+        }
+    }
+}
+
+// ============================================================
+// 51xx - staking
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum StakingError {
+    AlreadyInitialized = 5101,
+    NotInitialized = 5102,
+    Unauthorized = 5103,
+    TooManyTiers = 5104,
+    InvalidTier = 5105,
+    TiersNotAscending = 5106,
+    InvalidAmount = 5107,
+    NothingUnbonding = 5108,
+    StillUnbonding = 5109,
+    NothingToSlash = 5110,
+    NoStakers = 5111,
+    NothingToClaim = 5112,
+}
+
+impl StakingError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            StakingError::AlreadyInitialized => "already initialized",
+            StakingError::NotInitialized => "not initialized",
+            StakingError::Unauthorized => "unauthorized",
+            StakingError::TooManyTiers => "too many tiers",
+            StakingError::InvalidTier => "invalid tier",
+            StakingError::TiersNotAscending => "tiers not ascending",
+            StakingError::InvalidAmount => "invalid amount",
+            StakingError::NothingUnbonding => "nothing unbonding",
+            StakingError::StillUnbonding => "still unbonding",
+            StakingError::NothingToSlash => "nothing to slash",
+            StakingError::NoStakers => "no stakers",
+            StakingError::NothingToClaim => "nothing to claim",
+        }
+    }
+}
+
+// ============================================================
+// 52xx - subscription-benefits
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SubscriptionBenefitsError {
+    AlreadyInitialized = 5201,
+    NotInitialized = 5202,
+    Unauthorized = 5203,
+    AccessDenied = 5204,
+    UsageLimitReached = 5205,
+}
+
+impl SubscriptionBenefitsError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            SubscriptionBenefitsError::AlreadyInitialized => "already initialized",
+            SubscriptionBenefitsError::NotInitialized => "not initialized",
+            SubscriptionBenefitsError::Unauthorized => "unauthorized",
+            SubscriptionBenefitsError::AccessDenied => "access denied",
+            SubscriptionBenefitsError::UsageLimitReached => "usage limit reached",
+        }
+    }
+}
+
+// ============================================================
+// 53xx - subscription-manager
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SubscriptionManagerError {
+    AlreadyInitialized = 5301,
+    NotInitialized = 5302,
+    Unauthorized = 5303,
+    PlanNotFound = 5304,
+    SubscriptionNotFound = 5305,
+}
+
+impl SubscriptionManagerError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            SubscriptionManagerError::AlreadyInitialized => "already initialized",
+            SubscriptionManagerError::NotInitialized => "not initialized",
+            SubscriptionManagerError::Unauthorized => "unauthorized",
+            SubscriptionManagerError::PlanNotFound => "plan not found",
+            SubscriptionManagerError::SubscriptionNotFound => "subscription not found",
+        }
+    }
+}
+
+// ============================================================
+// 54xx - subscription
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SubscriptionError {
+    AlreadyInitialized = 5401,
+    NotInitialized = 5402,
+    Unauthorized = 5403,
+    InvalidPrice = 5404,
+    AlreadySubscribed = 5405,
+    PlanNotActive = 5406,
+    PriceAboveMax = 5407,
+    PaymentFailed = 5408,
+    SubscriptionNotFound = 5409,
+    SubscriptionCancelled = 5410,
+    NotDue = 5411,
+    PlanNotFound = 5412,
+}
+
+impl SubscriptionError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            SubscriptionError::AlreadyInitialized => "already initialized",
+            SubscriptionError::NotInitialized => "not initialized",
+            SubscriptionError::Unauthorized => "unauthorized",
+            SubscriptionError::InvalidPrice => "invalid price",
+            SubscriptionError::AlreadySubscribed => "already subscribed",
+            SubscriptionError::PlanNotActive => "plan not active",
+            SubscriptionError::PriceAboveMax => "price above max",
+            SubscriptionError::PaymentFailed => "payment failed",
+            SubscriptionError::SubscriptionNotFound => "subscription not found",
+            SubscriptionError::SubscriptionCancelled => "subscription cancelled",
+            SubscriptionError::NotDue => "not due",
+            SubscriptionError::PlanNotFound => "plan not found",
+        }
+    }
+}
+
+// ============================================================
+// 55xx - targeting-engine
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TargetingEngineError {
+    AlreadyInitialized = 5501,
+    NotInitialized = 5502,
+    Unauthorized = 5503,
+    InvalidAgeRange = 5504,
+}
+
+impl TargetingEngineError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            TargetingEngineError::AlreadyInitialized => "already initialized",
+            TargetingEngineError::NotInitialized => "not initialized",
+            TargetingEngineError::Unauthorized => "unauthorized",
+            TargetingEngineError::InvalidAgeRange => "invalid age range",
+        }
+    }
+}
+
+// ============================================================
+// 56xx - timelock-executor
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TimelockExecutorError {
+    AlreadyInitialized = 5601,
+    NotInitialized = 5602,
+    Unauthorized = 5603,
+    InvalidDelay = 5604,
+    UnauthorizedExecutor = 5605,
+    EntryNotFound = 5606,
+    EntryNotQueued = 5607,
+    TimelockNotExpired = 5608,
+    GracePeriodExpired = 5609,
+}
+
+impl TimelockExecutorError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            TimelockExecutorError::AlreadyInitialized => "already initialized",
+            TimelockExecutorError::NotInitialized => "not initialized",
+            TimelockExecutorError::Unauthorized => "unauthorized",
+            TimelockExecutorError::InvalidDelay => "invalid delay",
+            TimelockExecutorError::UnauthorizedExecutor => "unauthorized executor",
+            TimelockExecutorError::EntryNotFound => "entry not found",
+            TimelockExecutorError::EntryNotQueued => "entry not queued",
+            TimelockExecutorError::TimelockNotExpired => "timelock not expired",
+            TimelockExecutorError::GracePeriodExpired => "grace period expired",
+        }
+    }
+}
+
+// ============================================================
+// 57xx - token-bridge
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TokenBridgeError {
+    AlreadyInitialized = 5701,
+    NotInitialized = 5702,
+    Unauthorized = 5703,
+    ChainNotSupported = 5704,
+    InvalidAmount = 5705,
+    UnauthorizedRelayer = 5706,
+    DepositNotFound = 5707,
+    NotPending = 5708,
+    CannotRefund = 5709,
+}
+
+impl TokenBridgeError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            TokenBridgeError::AlreadyInitialized => "already initialized",
+            TokenBridgeError::NotInitialized => "not initialized",
+            TokenBridgeError::Unauthorized => "unauthorized",
+            TokenBridgeError::ChainNotSupported => "chain not supported",
+            TokenBridgeError::InvalidAmount => "invalid amount",
+            TokenBridgeError::UnauthorizedRelayer => "unauthorized relayer",
+            TokenBridgeError::DepositNotFound => "deposit not found",
+            TokenBridgeError::NotPending => "not pending",
+            TokenBridgeError::CannotRefund => "cannot refund",
+        }
+    }
+}
+
+// ============================================================
+// 58xx - vesting
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VestingError {
+    AlreadyInitialized = 5801,
+    NotInitialized = 5802,
+    Unauthorized = 5803,
+    InvalidTotal = 5804,
+    InvalidSchedule = 5805,
+    InvalidStep = 5806,
+    InsufficientFunding = 5807,
+    TooManyGrants = 5808,
+    NothingToClaim = 5809,
+    GrantNotRevocable = 5810,
+    AlreadyRevoked = 5811,
+    GrantNotFound = 5812,
+    TooManyDelegates = 5813,
+}
+
+impl VestingError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            VestingError::AlreadyInitialized => "already initialized",
+            VestingError::NotInitialized => "not initialized",
+            VestingError::Unauthorized => "unauthorized",
+            VestingError::InvalidTotal => "invalid total",
+            VestingError::InvalidSchedule => "invalid schedule",
+            VestingError::InvalidStep => "invalid step",
+            VestingError::InsufficientFunding => "insufficient funding",
+            VestingError::TooManyGrants => "too many grants",
+            VestingError::NothingToClaim => "nothing to claim",
+            VestingError::GrantNotRevocable => "grant not revocable",
+            VestingError::AlreadyRevoked => "already revoked",
+            VestingError::GrantNotFound => "grant not found",
+            VestingError::TooManyDelegates => "too many delegates",
+        }
+    }
+}
+
+// ============================================================
+// 59xx - wrapped-token
+// ============================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum WrappedTokenError {
+    AlreadyInitialized = 5901,
+    NotInitialized = 5902,
+    Unauthorized = 5903,
+    UnauthorizedRelayer = 5904,
+    TokenNotRegistered = 5905,
+    TokenNotActive = 5906,
+    InsufficientBalance = 5907,
+}
+
+impl WrappedTokenError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            WrappedTokenError::AlreadyInitialized => "already initialized",
+            WrappedTokenError::NotInitialized => "not initialized",
+            WrappedTokenError::Unauthorized => "unauthorized",
+            WrappedTokenError::UnauthorizedRelayer => "unauthorized relayer",
+            WrappedTokenError::TokenNotRegistered => "token not registered",
+            WrappedTokenError::TokenNotActive => "token not active",
+            WrappedTokenError::InsufficientBalance => "insufficient balance",
+        }
+    }
+}
+
+// ============================================================
+// Lookup
+// ============================================================
+
+/// The contract that owns `code`'s block, if any
+pub fn contract_for(code: u32) -> Option<&'static str> {
+    match code / 100 {
+        1 => Some("governance-token"),
+        2 => Some("multisig-treasury"),
+        3 => Some("refund-processor"),
+        4 => Some("publisher-verification"),
+        5 => Some("publisher-reputation"),
+        6 => Some("governance-dao"),
+        7 => Some("ad-auction"),
+        8 => Some("ad-registry"),
+        9 => Some("analytics-aggregator"),
+        10 => Some("analytics"),
+        11 => Some("anomaly-detector"),
+        12 => Some("arbitration"),
+        13 => Some("attribution"),
+        14 => Some("auction-engine"),
+        15 => Some("audience-segments"),
+        16 => Some("budget-optimizer"),
+        17 => Some("campaign-analytics"),
+        18 => Some("campaign-lifecycle"),
+        19 => Some("campaign-orchestrator"),
+        20 => Some("creative-marketplace"),
+        21 => Some("dispute-resolution"),
+        22 => Some("escrow-vault"),
+        23 => Some("escrow"),
+        24 => Some("fee-manager"),
+        25 => Some("fraud-prevention"),
+        26 => Some("fraud-registry"),
+        27 => Some("governance-core"),
+        28 => Some("identity-registry"),
+        29 => Some("insurance-pool"),
+        30 => Some("kyc-registry"),
+        31 => Some("liquidity-pool"),
+        32 => Some("merkle-distributor"),
+        33 => Some("milestone-tracker"),
+        34 => Some("multicall"),
+        35 => Some("oracle-aggregator"),
+        36 => Some("oracle-integration"),
+        37 => Some("pacing"),
+        38 => Some("participation-rewards"),
+        39 => Some("payment-processor"),
+        40 => Some("payment-stream"),
+        41 => Some("payout-automation"),
+        42 => Some("performance-oracle"),
+        43 => Some("placement-nft"),
+        44 => Some("privacy-layer"),
+        45 => Some("publisher-network"),
+        46 => Some("recurring-payment"),
+        47 => Some("registry"),
+        48 => Some("revenue-settlement"),
+        49 => Some("rewards-distributor"),
+        50 => Some("splitter"),
+        51 => Some("staking"),
+        52 => Some("subscription-benefits"),
+        53 => Some("subscription-manager"),
+        54 => Some("subscription"),
+        55 => Some("targeting-engine"),
+        56 => Some("timelock-executor"),
+        57 => Some("token-bridge"),
+        58 => Some("vesting"),
+        59 => Some("wrapped-token"),
+        _ => None,
+    }
+}
+
+/// The message for any PulsarTrack contract error code
+pub fn describe(code: u32) -> Option<&'static str> {
+    let error = Error::from_contract_error(code);
+    match code / 100 {
+        1 => TokenError::try_from(error).ok().map(|e| e.message()),
+        2 => TreasuryError::try_from(error).ok().map(|e| e.message()),
+        3 => RefundError::try_from(error).ok().map(|e| e.message()),
+        4 => VerificationError::try_from(error).ok().map(|e| e.message()),
+        5 => ReputationError::try_from(error).ok().map(|e| e.message()),
+        6 => DaoError::try_from(error).ok().map(|e| e.message()),
+        7 => AdAuctionError::try_from(error).ok().map(|e| e.message()),
+        8 => AdRegistryError::try_from(error).ok().map(|e| e.message()),
+        9 => AnalyticsAggregatorError::try_from(error).ok().map(|e| e.message()),
+        10 => AnalyticsError::try_from(error).ok().map(|e| e.message()),
+        11 => AnomalyDetectorError::try_from(error).ok().map(|e| e.message()),
+        12 => ArbitrationError::try_from(error).ok().map(|e| e.message()),
+        13 => AttributionError::try_from(error).ok().map(|e| e.message()),
+        14 => AuctionEngineError::try_from(error).ok().map(|e| e.message()),
+        15 => AudienceSegmentsError::try_from(error).ok().map(|e| e.message()),
+        16 => BudgetOptimizerError::try_from(error).ok().map(|e| e.message()),
+        17 => CampaignAnalyticsError::try_from(error).ok().map(|e| e.message()),
+        18 => CampaignLifecycleError::try_from(error).ok().map(|e| e.message()),
+        19 => CampaignOrchestratorError::try_from(error).ok().map(|e| e.message()),
+        20 => CreativeMarketplaceError::try_from(error).ok().map(|e| e.message()),
+        21 => DisputeResolutionError::try_from(error).ok().map(|e| e.message()),
+        22 => EscrowVaultError::try_from(error).ok().map(|e| e.message()),
+        23 => EscrowError::try_from(error).ok().map(|e| e.message()),
+        24 => FeeManagerError::try_from(error).ok().map(|e| e.message()),
+        25 => FraudPreventionError::try_from(error).ok().map(|e| e.message()),
+        26 => FraudRegistryError::try_from(error).ok().map(|e| e.message()),
+        27 => GovernanceCoreError::try_from(error).ok().map(|e| e.message()),
+        28 => IdentityRegistryError::try_from(error).ok().map(|e| e.message()),
+        29 => InsurancePoolError::try_from(error).ok().map(|e| e.message()),
+        30 => KycRegistryError::try_from(error).ok().map(|e| e.message()),
+        31 => LiquidityPoolError::try_from(error).ok().map(|e| e.message()),
+        32 => MerkleDistributorError::try_from(error).ok().map(|e| e.message()),
+        33 => MilestoneTrackerError::try_from(error).ok().map(|e| e.message()),
+        34 => MulticallError::try_from(error).ok().map(|e| e.message()),
+        35 => OracleAggregatorError::try_from(error).ok().map(|e| e.message()),
+        36 => OracleIntegrationError::try_from(error).ok().map(|e| e.message()),
+        37 => PacingError::try_from(error).ok().map(|e| e.message()),
+        38 => ParticipationRewardsError::try_from(error).ok().map(|e| e.message()),
+        39 => PaymentProcessorError::try_from(error).ok().map(|e| e.message()),
+        40 => PaymentStreamError::try_from(error).ok().map(|e| e.message()),
+        41 => PayoutAutomationError::try_from(error).ok().map(|e| e.message()),
+        42 => PerformanceOracleError::try_from(error).ok().map(|e| e.message()),
+        43 => PlacementNftError::try_from(error).ok().map(|e| e.message()),
+        44 => PrivacyLayerError::try_from(error).ok().map(|e| e.message()),
+        45 => PublisherNetworkError::try_from(error).ok().map(|e| e.message()),
+        46 => RecurringPaymentError::try_from(error).ok().map(|e| e.message()),
+        47 => RegistryError::try_from(error).ok().map(|e| e.message()),
+        48 => RevenueSettlementError::try_from(error).ok().map(|e| e.message()),
+        49 => RewardsDistributorError::try_from(error).ok().map(|e| e.message()),
+        50 => SplitterError::try_from(error).ok().map(|e| e.message()),
+        51 => StakingError::try_from(error).ok().map(|e| e.message()),
+        52 => SubscriptionBenefitsError::try_from(error).ok().map(|e| e.message()),
+        53 => SubscriptionManagerError::try_from(error).ok().map(|e| e.message()),
+        54 => SubscriptionError::try_from(error).ok().map(|e| e.message()),
+        55 => TargetingEngineError::try_from(error).ok().map(|e| e.message()),
+        56 => TimelockExecutorError::try_from(error).ok().map(|e| e.message()),
+        57 => TokenBridgeError::try_from(error).ok().map(|e| e.message()),
+        58 => VestingError::try_from(error).ok().map(|e| e.message()),
+        59 => WrappedTokenError::try_from(error).ok().map(|e| e.message()),
+        _ => None,
+    }
+}
+
+mod test;
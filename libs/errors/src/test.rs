@@ -0,0 +1,48 @@
+#![cfg(test)]
+use super::*;
+
+#[test]
+fn test_codes_stay_in_their_blocks() {
+    assert_eq!(TokenError::AlreadyInitialized as u32, 101);
+    assert_eq!(TreasuryError::Unauthorized as u32, 203);
+    assert_eq!(RefundError::AlreadyInitialized as u32, 301);
+    assert_eq!(VerificationError::NotInitialized as u32, 402);
+    assert_eq!(ReputationError::Unauthorized as u32, 503);
+    assert_eq!(DaoError::AlreadyInitialized as u32, 601);
+    assert_eq!(AdAuctionError::AlreadyInitialized as u32, 701);
+    assert_eq!(CampaignOrchestratorError::Unauthorized as u32, 1903);
+    assert_eq!(EscrowError::NotInitialized as u32, 2302);
+    assert_eq!(WrappedTokenError::AlreadyInitialized as u32, 5901);
+}
+
+#[test]
+fn test_describe() {
+    assert_eq!(describe(103), Some("unauthorized"));
+    assert_eq!(describe(TreasuryError::TxNotFound as u32), Some("transaction not found"));
+    assert_eq!(describe(RefundError::RefundUnderArbitration as u32), Some("refund under arbitration"));
+    assert_eq!(describe(DaoError::ParamOutOfBounds as u32), Some("param out of bounds"));
+    assert_eq!(contract_for(DaoError::ParamOutOfBounds as u32), Some("governance-dao"));
+    assert_eq!(describe(StakingError::StillUnbonding as u32), Some("still unbonding"));
+    assert_eq!(contract_for(PaymentStreamError::StreamNotFound as u32), Some("payment-stream"));
+    assert_eq!(describe(SplitterError::Unauthorized as u32), Some("unauthorized"));
+
+    // Unused codes inside a block and codes outside every block
+    assert_eq!(describe(199), None);
+    assert_eq!(describe(42), None);
+    assert_eq!(contract_for(9_001), None);
+    assert_eq!(describe(6_001), None);
+}
+
+#[test]
+fn test_access_errors_map_to_standard_codes() {
+    assert_eq!(VerificationError::from(AccessError::MissingRole), VerificationError::Unauthorized);
+    assert_eq!(DaoError::from(AccessError::AlreadyInitialized), DaoError::AlreadyInitialized);
+    assert_eq!(RefundError::from(AccessError::NotInitialized), RefundError::NotInitialized);
+}
+
+#[test]
+fn test_upgrade_errors_map_per_contract() {
+    assert_eq!(TokenError::from(UpgradeError::VersionMismatch), TokenError::VersionMismatch);
+    assert_eq!(VerificationError::from(UpgradeError::AlreadyMigrated), VerificationError::AlreadyMigrated);
+    assert_eq!(describe(DaoError::VersionMismatch as u32), Some("version mismatch"));
+}
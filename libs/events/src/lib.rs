@@ -0,0 +1,155 @@
+//! PulsarTrack - Event Schema (Soroban)
+//! Typed, versioned events shared across PulsarTrack contracts.
+//!
+//! PulsarTrack events are published with the same three topics:
+//!
+//! - contract: `Symbol`, the emitting contract's tag (`"token"`, `"dao"`, ...)
+//! - action: `Symbol`, what happened (`"transfer"`, `"proposal_queued"`, ...)
+//! - version: `u32`, the payload schema version
+//!
+//! The data is always one `#[contracttype]` struct, so indexers route on
+//! contract and action, branch on version and decode fields by name. A
+//! payload is never changed in place: altering its fields means a new struct
+//! with a bumped `VERSION`, published under the same action.
+//!
+//! Payloads shared by several contracts (role changes, upgrades) live here;
+//! contracts declare their own and tag them with `contract_event!`.
+//!
+//! The one exception is the SEP-41 token events (transfer, approve, mint,
+//! burn, clawback): tokens publish those through `token` in the standard
+//! layout so wallets and explorers can read them.
+
+
+#![no_std]
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, Symbol, Val};
+
+/// A typed event payload
+pub trait ContractEvent {
+    const ACTION: &'static str;
+    const VERSION: u32 = 1;
+}
+
+/// Publish `event` under the standard `(contract, action, version)` topics
+pub fn publish<E>(env: &Env, contract: &str, event: E)
+where
+    E: ContractEvent + IntoVal<Env, Val>,
+{
+    env.events().publish(
+        (Symbol::new(env, contract), Symbol::new(env, E::ACTION), E::VERSION),
+        event,
+    );
+}
+
+/// Implement `ContractEvent` for a contract's own `#[contracttype]` payload:
+///
+/// ```ignore
+/// contract_event!(ProposalQueued, "proposal_queued");
+/// contract_event!(Voted, "voted", 2);
+/// ```
+#[macro_export]
+macro_rules! contract_event {
+    ($name:ty, $action:literal) => {
+        impl $crate::ContractEvent for $name {
+            const ACTION: &'static str = $action;
+        }
+    };
+    ($name:ty, $action:literal, $version:literal) => {
+        impl $crate::ContractEvent for $name {
+            const ACTION: &'static str = $action;
+            const VERSION: u32 = $version;
+        }
+    };
+}
+
+// ============================================================
+// SEP-41 token events
+// ============================================================
+
+/// Token movements in the SEP-41 layout: `(action, addresses...)` topics
+/// with the amount as data. Wallets and explorers decode these by position,
+/// so PulsarTrack tokens publish them as-is rather than under the versioned
+/// topics; anything token-specific beyond them uses `publish`.
+pub mod token {
+    use soroban_sdk::{symbol_short, Address, Env};
+
+    pub fn transfer(env: &Env, from: Address, to: Address, amount: i128) {
+        env.events().publish((symbol_short!("transfer"), from, to), amount);
+    }
+
+    pub fn approve(env: &Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        env.events().publish((symbol_short!("approve"), from, spender), (amount, expiration_ledger));
+    }
+
+    pub fn mint(env: &Env, admin: Address, to: Address, amount: i128) {
+        env.events().publish((symbol_short!("mint"), admin, to), amount);
+    }
+
+    pub fn burn(env: &Env, from: Address, amount: i128) {
+        env.events().publish((symbol_short!("burn"), from), amount);
+    }
+
+    pub fn clawback(env: &Env, admin: Address, from: Address, amount: i128) {
+        env.events().publish((symbol_short!("clawback"), admin, from), amount);
+    }
+}
+
+// ============================================================
+// Shared payloads
+// ============================================================
+
+/// `account` was given `role` (verifier, oracle, signer, ...)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGranted {
+    pub role: Symbol,
+    pub account: Address,
+}
+
+impl ContractEvent for RoleGranted {
+    const ACTION: &'static str = "role_granted";
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleRevoked {
+    pub role: Symbol,
+    pub account: Address,
+}
+
+impl ContractEvent for RoleRevoked {
+    const ACTION: &'static str = "role_revoked";
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Upgraded {
+    pub new_wasm_hash: BytesN<32>,
+}
+
+impl ContractEvent for Upgraded {
+    const ACTION: &'static str = "upgraded";
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Migrated {
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+impl ContractEvent for Migrated {
+    const ACTION: &'static str = "migrated";
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeAuthoritySet {
+    pub previous: Address,
+    pub authority: Address,
+}
+
+impl ContractEvent for UpgradeAuthoritySet {
+    const ACTION: &'static str = "upgrade_authority";
+}
+
+mod test;
@@ -0,0 +1,77 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{contract, contracttype, testutils::{Address as _, Events}, Address, Env, IntoVal};
+
+// Stand-in contract to publish from
+#[contract]
+pub struct Host;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Pinged {
+    pub count: u32,
+}
+contract_event!(Pinged, "pinged");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Ponged {
+    pub from: Address,
+    pub count: u32,
+}
+contract_event!(Ponged, "ponged", 2);
+
+#[test]
+fn test_topics_follow_schema() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+    let account = Address::generate(&env);
+    let role = Symbol::new(&env, "signer");
+
+    env.as_contract(&host, || publish(&env, "treasury", RoleGranted { role: role.clone(), account: account.clone() }));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "treasury"), Symbol::new(&env, "role_granted"), 1u32).into_val(&env));
+    let event: RoleGranted = data.into_val(&env);
+    assert_eq!(event, RoleGranted { role, account });
+}
+
+#[test]
+fn test_token_events_keep_sep41_layout() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    env.as_contract(&host, || token::transfer(&env, from.clone(), to.clone(), 5));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "transfer"), from.clone(), to.clone()).into_val(&env));
+    let amount: i128 = data.into_val(&env);
+    assert_eq!(amount, 5);
+
+    env.as_contract(&host, || token::approve(&env, from.clone(), to.clone(), 7, 100));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "approve"), from.clone(), to).into_val(&env));
+    let (amount, expiration): (i128, u32) = data.into_val(&env);
+    assert_eq!((amount, expiration), (7, 100));
+
+    env.as_contract(&host, || token::burn(&env, from.clone(), 3));
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "burn"), from).into_val(&env));
+}
+
+#[test]
+fn test_declared_events_carry_version() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+    let from = Address::generate(&env);
+
+    env.as_contract(&host, || publish(&env, "test", Pinged { count: 1 }));
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "test"), Symbol::new(&env, "pinged"), 1u32).into_val(&env));
+
+    env.as_contract(&host, || publish(&env, "test", Ponged { from: from.clone(), count: 2 }));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&env, "test"), Symbol::new(&env, "ponged"), 2u32).into_val(&env));
+    let event: Ponged = data.into_val(&env);
+    assert_eq!((event.from, event.count), (from, 2));
+}
@@ -0,0 +1,98 @@
+//! PulsarTrack - Storage TTL (Soroban)
+//! TTL-aware storage access shared across PulsarTrack contracts.
+//!
+//! Persistent entries that go unread and unwritten for long enough are
+//! archived and must be restored before any transaction can touch them. The
+//! wrappers here bump an entry's TTL every time it is read or written, using
+//! a `TtlPolicy` chosen per kind of entry, so anything in regular use stays
+//! live on its own.
+//!
+//! Entries that sit idle (a dormant holder's balance, a settled refund) are
+//! kept alive by bots calling each contract's permissionless `extend_ttl_*`
+//! entry points, which go through `Persistent::extend`. Any call also bumps
+//! the contract instance.
+//!
+//! Ledger counts assume ~5s ledgers (17_280 per day).
+
+
+#![no_std]
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val};
+
+/// When an entry's remaining TTL drops below `threshold` ledgers, extend it
+/// to `extend_to` ledgers
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TtlPolicy {
+    pub threshold: u32,
+    pub extend_to: u32,
+}
+
+impl TtlPolicy {
+    pub const fn new(threshold: u32, extend_to: u32) -> Self {
+        TtlPolicy { threshold, extend_to }
+    }
+}
+
+/// Contract instance: config and counters (1 day / 5 days)
+pub const INSTANCE: TtlPolicy = TtlPolicy::new(17_280, 86_400);
+/// Entries touched often while in use: balances, proposals (2 days / 15 days)
+pub const STANDARD: TtlPolicy = TtlPolicy::new(34_560, 259_200);
+/// Records that must outlive long idle stretches: publishers, refunds,
+/// reputation, treasury transactions (7 days / ~61 days)
+pub const LONG_LIVED: TtlPolicy = TtlPolicy::new(120_960, 1_051_200);
+
+// ============================================================
+// Instance
+// ============================================================
+
+pub struct Instance;
+
+impl Instance {
+    pub fn bump(env: &Env, ttl: TtlPolicy) {
+        env.storage().instance().extend_ttl(ttl.threshold, ttl.extend_to);
+    }
+}
+
+// ============================================================
+// Persistent
+// ============================================================
+
+pub struct Persistent;
+
+impl Persistent {
+    /// Read `key`, extending its TTL if it exists
+    pub fn get<K, V>(env: &Env, key: &K, ttl: TtlPolicy) -> Option<V>
+    where
+        K: IntoVal<Env, Val>,
+        V: TryFromVal<Env, Val>,
+    {
+        let value = env.storage().persistent().get(key);
+        if value.is_some() {
+            env.storage().persistent().extend_ttl(key, ttl.threshold, ttl.extend_to);
+        }
+        value
+    }
+
+    /// Write `key` and extend its TTL
+    pub fn set<K, V>(env: &Env, key: &K, value: &V, ttl: TtlPolicy)
+    where
+        K: IntoVal<Env, Val>,
+        V: IntoVal<Env, Val>,
+    {
+        env.storage().persistent().set(key, value);
+        env.storage().persistent().extend_ttl(key, ttl.threshold, ttl.extend_to);
+    }
+
+    /// Extend `key`'s TTL without reading it; false if there is no such entry
+    pub fn extend<K>(env: &Env, key: &K, ttl: TtlPolicy) -> bool
+    where
+        K: IntoVal<Env, Val>,
+    {
+        if !env.storage().persistent().has(key) {
+            return false;
+        }
+        env.storage().persistent().extend_ttl(key, ttl.threshold, ttl.extend_to);
+        true
+    }
+}
+
+mod test;
@@ -0,0 +1,46 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{contract, contracttype, testutils::storage::Persistent as _, Env};
+
+// Stand-in contract giving the helpers a storage context
+#[contract]
+pub struct Host;
+
+#[contracttype]
+pub enum Key {
+    Record(u32),
+}
+
+#[test]
+fn test_reads_and_writes_extend_ttl() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+
+    env.as_contract(&host, || {
+        Persistent::set(&env, &Key::Record(1), &7u32, LONG_LIVED);
+        assert_eq!(env.storage().persistent().get_ttl(&Key::Record(1)), LONG_LIVED.extend_to);
+
+        // Below the threshold a read pushes it back out
+        env.storage().persistent().set(&Key::Record(2), &9u32);
+        let before = env.storage().persistent().get_ttl(&Key::Record(2));
+        assert!(before < STANDARD.threshold);
+        assert_eq!(Persistent::get::<_, u32>(&env, &Key::Record(2), STANDARD), Some(9));
+        assert_eq!(env.storage().persistent().get_ttl(&Key::Record(2)), STANDARD.extend_to);
+
+        assert_eq!(Persistent::get::<_, u32>(&env, &Key::Record(3), STANDARD), None);
+    });
+}
+
+#[test]
+fn test_extend_reports_missing_entries() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+
+    env.as_contract(&host, || {
+        assert!(!Persistent::extend(&env, &Key::Record(1), LONG_LIVED));
+
+        env.storage().persistent().set(&Key::Record(1), &1u32);
+        assert!(Persistent::extend(&env, &Key::Record(1), LONG_LIVED));
+        assert_eq!(env.storage().persistent().get_ttl(&Key::Record(1)), LONG_LIVED.extend_to);
+    });
+}
@@ -0,0 +1,245 @@
+//! PulsarTrack - Test Utilities (Soroban)
+//! Cross-contract fixtures for integration tests.
+//!
+//! `Suite::new()` registers the core contracts in one host environment,
+//! initializes them under a shared admin and wires the links between them:
+//!
+//! - publisher-verification → publisher-reputation (reputation starts on verify)
+//! - governance-dao → governance-token (votes weighted by PULSAR checkpoints)
+//! - multisig-treasury → governance-dao (the DAO may drive treasury actions)
+//! - campaign-orchestrator and refund-processor settle in one Stellar asset
+//! - campaign-orchestrator → publisher-verification (view spend accrues as
+//!   publisher earnings, withdrawn net of the tier fee to the admin)
+//! - refund-processor → campaign-orchestrator (approvals checked per campaign)
+//!
+//! All auths are mocked, so tests call entry points directly and only assert
+//! on outcomes. Clients are public fields; reach for them whenever a helper
+//! does not cover a flow.
+//!
+//! Time travel goes through `advance_time` / `advance_ledgers`, which move
+//! the ledger timestamp and sequence together at the network's ~5s close time.
+//!
+//! Everything here needs the host test environment, so the crate is empty
+//! unless built for tests or with the `testutils` feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! pulsartrack-testutils = { workspace = true, features = ["testutils"] }
+//! ```
+
+
+#![cfg(any(test, feature = "testutils"))]
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+pub use pulsar_campaign_orchestrator::{CampaignOrchestratorContract, CampaignOrchestratorContractClient};
+pub use pulsar_governance_dao::{GovernanceDaoContract, GovernanceDaoContractClient};
+pub use pulsar_governance_token::{GovernanceTokenContract, GovernanceTokenContractClient};
+pub use pulsar_multisig_treasury::{MultisigTreasuryContract, MultisigTreasuryContractClient};
+pub use pulsar_publisher_reputation::{PublisherReputationContract, PublisherReputationContractClient};
+pub use pulsar_publisher_verification::{
+    PublisherTier, PublisherVerificationContract, PublisherVerificationContractClient,
+};
+pub use pulsar_refund_processor::{RefundProcessorContract, RefundProcessorContractClient};
+
+/// Seconds between ledger closes used by the time-travel helpers
+pub const LEDGER_CLOSE_SECS: u64 = 5;
+
+/// DAO parameters the suite initializes with: voting period (ledgers),
+/// quorum, pass threshold (%) and proposer minimum
+pub const DAO_VOTING_PERIOD: u32 = 100;
+pub const DAO_QUORUM: i128 = 1_000;
+pub const DAO_PASS_THRESHOLD: u32 = 51;
+pub const DAO_PROPOSER_MIN: i128 = 0;
+
+/// Initial score the orchestrator records for publishers onboarded through
+/// `Suite::onboard_publisher`
+pub const DEFAULT_PUBLISHER_SCORE: u32 = 100;
+
+pub struct Suite {
+    pub env: Env,
+    pub admin: Address,
+    pub oracle: Address,
+
+    /// Stellar asset campaigns and refunds settle in
+    pub token: token::Client<'static>,
+    pub token_admin: token::StellarAssetClient<'static>,
+
+    pub governance_token: GovernanceTokenContractClient<'static>,
+    pub dao: GovernanceDaoContractClient<'static>,
+    pub treasury: MultisigTreasuryContractClient<'static>,
+    pub verification: PublisherVerificationContractClient<'static>,
+    pub reputation: PublisherReputationContractClient<'static>,
+    pub orchestrator: CampaignOrchestratorContractClient<'static>,
+    pub refunds: RefundProcessorContractClient<'static>,
+}
+
+impl Suite {
+    /// Fresh environment with all auths mocked and the full suite deployed
+    pub fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        Self::with_env(&env)
+    }
+
+    /// Deploy the suite into an existing environment. The caller decides how
+    /// auths are handled.
+    pub fn with_env(env: &Env) -> Self {
+        let admin = Address::generate(env);
+        let oracle = Address::generate(env);
+
+        let token_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let token = token::Client::new(env, &token_address);
+        let token_admin = token::StellarAssetClient::new(env, &token_address);
+
+        let governance_token = GovernanceTokenContractClient::new(
+            env,
+            &env.register(GovernanceTokenContract, ()),
+        );
+        governance_token.initialize(&admin);
+
+        let dao = GovernanceDaoContractClient::new(env, &env.register(GovernanceDaoContract, ()));
+        dao.initialize(
+            &admin,
+            &governance_token.address,
+            &DAO_VOTING_PERIOD,
+            &DAO_QUORUM,
+            &DAO_PASS_THRESHOLD,
+            &DAO_PROPOSER_MIN,
+        );
+
+        let treasury = MultisigTreasuryContractClient::new(
+            env,
+            &env.register(MultisigTreasuryContract, ()),
+        );
+        treasury.initialize(&admin, &vec![env, admin.clone()], &1);
+        treasury.set_dao_contract(&admin, &dao.address);
+
+        let reputation = PublisherReputationContractClient::new(
+            env,
+            &env.register(PublisherReputationContract, ()),
+        );
+        reputation.initialize(&admin, &oracle);
+
+        let verification = PublisherVerificationContractClient::new(
+            env,
+            &env.register(PublisherVerificationContract, ()),
+        );
+        verification.initialize(&admin);
+        verification.set_reputation_contract(&admin, &reputation.address);
+        reputation.set_verification_contract(&admin, &verification.address);
+
+        let orchestrator = CampaignOrchestratorContractClient::new(
+            env,
+            &env.register(CampaignOrchestratorContract, ()),
+        );
+        orchestrator.initialize(&admin, &token_address);
+        verification.set_earnings_config(&admin, &token_address, &admin);
+        verification.authorize_caller(&admin, &orchestrator.address);
+        orchestrator.set_verification(&admin, &verification.address);
+
+        let refunds = RefundProcessorContractClient::new(
+            env,
+            &env.register(RefundProcessorContract, ()),
+        );
+        refunds.initialize(&admin, &token_address);
+        refunds.set_orchestrator(&admin, &orchestrator.address);
+
+        Suite {
+            env: env.clone(),
+            admin,
+            oracle,
+            token,
+            token_admin,
+            governance_token,
+            dao,
+            treasury,
+            verification,
+            reputation,
+            orchestrator,
+            refunds,
+        }
+    }
+
+    // ============================================================
+    // Accounts
+    // ============================================================
+
+    /// Mint `amount` of the settlement asset to `who`
+    pub fn fund(&self, who: &Address, amount: i128) {
+        self.token_admin.mint(who, &amount);
+    }
+
+    /// New account holding `amount` of the settlement asset
+    pub fn funded_account(&self, amount: i128) -> Address {
+        let account = Address::generate(&self.env);
+        if amount > 0 {
+            self.fund(&account, amount);
+        }
+        account
+    }
+
+    /// Mint `amount` PULSAR governance tokens to `who`
+    pub fn fund_pulsar(&self, who: &Address, amount: i128) {
+        self.governance_token.mint(&self.admin, who, &amount);
+    }
+
+    pub fn balance(&self, who: &Address) -> i128 {
+        self.token.balance(who)
+    }
+
+    /// Register `domain` for a new publisher, verify it with the admin as
+    /// verifier and mark it verified in the orchestrator so it can serve views
+    pub fn onboard_publisher(&self, domain: &str) -> Address {
+        let publisher = Address::generate(&self.env);
+        self.verification
+            .register_publisher(&publisher, &String::from_str(&self.env, domain), &None);
+        self.verification
+            .verify_publisher(&self.admin, &publisher, &PublisherTier::Bronze);
+        self.orchestrator
+            .verify_publisher(&self.admin, &publisher, &DEFAULT_PUBLISHER_SCORE);
+        publisher
+    }
+
+    // ============================================================
+    // Time travel
+    // ============================================================
+
+    /// Move the clock forward by `secs`, closing ledgers to match
+    pub fn advance_time(&self, secs: u64) {
+        let ledgers = (secs / LEDGER_CLOSE_SECS) as u32;
+        self.env.ledger().with_mut(|li| {
+            li.timestamp += secs;
+            li.sequence_number += ledgers;
+        });
+    }
+
+    /// Close `ledgers` ledgers, moving the clock forward to match
+    pub fn advance_ledgers(&self, ledgers: u32) {
+        self.env.ledger().with_mut(|li| {
+            li.sequence_number += ledgers;
+            li.timestamp += ledgers as u64 * LEDGER_CLOSE_SECS;
+        });
+    }
+
+    pub fn now(&self) -> u64 {
+        self.env.ledger().timestamp()
+    }
+
+    pub fn ledger(&self) -> u32 {
+        self.env.ledger().sequence()
+    }
+}
+
+impl Default for Suite {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,261 @@
+#![cfg(test)]
+use super::*;
+use pulsar_governance_dao::{ProposalKind, ProposalStatus, VoteChoice};
+use pulsar_refund_processor::RefundStatus;
+use soroban_sdk::{
+    testutils::{MockAuth, MockAuthInvoke},
+    vec, IntoVal, Symbol,
+};
+
+// Campaign used across the scenarios: 0.2 XLM budget at 0.0001 XLM per view
+const BUDGET: i128 = 2_000_000;
+const COST_PER_VIEW: i128 = 1_000;
+const DURATION: u32 = 1_000;
+
+fn start_campaign(suite: &Suite, advertiser: &Address) -> u64 {
+    suite.orchestrator.create_campaign(
+        advertiser,
+        &1,
+        &BUDGET,
+        &COST_PER_VIEW,
+        &DURATION,
+        &500,
+        &100,
+        &true,
+    )
+}
+
+#[test]
+fn test_suite_wires_contracts() {
+    let suite = Suite::new();
+
+    assert_eq!(
+        suite.verification.get_reputation_contract(),
+        Some(suite.reputation.address.clone())
+    );
+    assert_eq!(
+        suite.reputation.get_verification_contract(),
+        Some(suite.verification.address.clone())
+    );
+    assert_eq!(suite.reputation.get_oracles(), vec![&suite.env, suite.oracle.clone()]);
+
+    let advertiser = suite.funded_account(5_000_000);
+    assert_eq!(suite.balance(&advertiser), 5_000_000);
+
+    suite.fund_pulsar(&advertiser, 750);
+    assert_eq!(suite.governance_token.balance(&advertiser), 750);
+}
+
+#[test]
+fn test_register_verify_run_campaign_refund() {
+    let suite = Suite::new();
+
+    // Publisher goes through verification; reputation tracking starts on verify
+    let publisher = suite.onboard_publisher("news.example");
+    assert!(suite.verification.is_verified(&publisher));
+    assert_eq!(suite.reputation.get_reputation(&publisher).unwrap().score, 500);
+
+    // Advertiser funds a campaign and the publisher serves views against it
+    let advertiser = suite.funded_account(5_000_000);
+    let campaign_id = start_campaign(&suite, &advertiser);
+    let fee = BUDGET * 2 / 100;
+    assert_eq!(suite.balance(&advertiser), 5_000_000 - BUDGET - fee);
+
+    for _ in 0..3 {
+        suite.orchestrator.record_view(&campaign_id, &publisher);
+    }
+    assert_eq!(suite.verification.get_accrued_earnings(&publisher), 3 * COST_PER_VIEW);
+
+    // Advertiser disputes part of the delivered spend through the refund processor
+    suite.fund(&suite.refunds.address, 10_000);
+    let refund_id = suite.refunds.request_refund(
+        &advertiser,
+        &campaign_id,
+        &(2 * COST_PER_VIEW),
+        &String::from_str(&suite.env, "invalid traffic"),
+    );
+    suite.refunds.approve_refund(&suite.admin, &refund_id, &COST_PER_VIEW);
+    suite.refunds.process_refund(&refund_id);
+
+    let refund = suite.refunds.get_refund(&refund_id).unwrap();
+    assert!(refund.status == RefundStatus::Processed);
+    assert_eq!(refund.amount_approved, COST_PER_VIEW);
+
+    // Cancelling returns whatever budget is left
+    suite.orchestrator.cancel_campaign(&advertiser, &campaign_id);
+    assert_eq!(
+        suite.balance(&advertiser),
+        5_000_000 - fee - 3 * COST_PER_VIEW + COST_PER_VIEW
+    );
+}
+
+#[test]
+fn test_time_travel_expires_campaign() {
+    let suite = Suite::new();
+    let publisher = suite.onboard_publisher("blog.example");
+    let advertiser = suite.funded_account(5_000_000);
+    let campaign_id = start_campaign(&suite, &advertiser);
+
+    let (ledger, now) = (suite.ledger(), suite.now());
+    suite.advance_time(50);
+    assert_eq!((suite.ledger(), suite.now()), (ledger + 10, now + 50));
+
+    suite.orchestrator.record_view(&campaign_id, &publisher);
+
+    suite.advance_ledgers(DURATION);
+    assert!(suite.orchestrator.try_record_view(&campaign_id, &publisher).is_err());
+    assert_eq!(suite.verification.get_accrued_earnings(&publisher), COST_PER_VIEW);
+}
+
+#[test]
+fn test_view_spend_settles_into_publisher_earnings() {
+    let suite = Suite::new();
+    let publisher = suite.onboard_publisher("daily.example");
+    let advertiser = suite.funded_account(5_000_000);
+    let campaign_id = start_campaign(&suite, &advertiser);
+    let escrowed = suite.balance(&suite.orchestrator.address);
+
+    // Only the publisher signs the view; the orchestrator authorizes the
+    // transfer out of campaign escrow itself
+    suite.env.mock_auths(&[MockAuth {
+        address: &publisher,
+        invoke: &MockAuthInvoke {
+            contract: &suite.orchestrator.address,
+            fn_name: "record_view",
+            args: (campaign_id, publisher.clone()).into_val(&suite.env),
+            sub_invokes: &[],
+        },
+    }]);
+    suite.orchestrator.record_view(&campaign_id, &publisher);
+    suite.env.mock_all_auths();
+    suite.orchestrator.record_view(&campaign_id, &publisher);
+
+    assert_eq!(suite.balance(&suite.orchestrator.address), escrowed - 2 * COST_PER_VIEW);
+    assert_eq!(suite.balance(&suite.verification.address), 2 * COST_PER_VIEW);
+    assert_eq!(suite.balance(&publisher), 0);
+    assert_eq!(suite.verification.get_accrued_earnings(&publisher), 2 * COST_PER_VIEW);
+
+    // Bronze publishers pay a 5% platform fee on withdrawal
+    let admin_before = suite.balance(&suite.admin);
+    let net = suite.verification.withdraw_earnings(&publisher, &(2 * COST_PER_VIEW));
+    assert_eq!(net, 2 * COST_PER_VIEW * 95 / 100);
+    assert_eq!(suite.balance(&publisher), net);
+    assert_eq!(suite.balance(&suite.admin) - admin_before, 2 * COST_PER_VIEW - net);
+    assert_eq!(suite.verification.get_accrued_earnings(&publisher), 0);
+}
+
+#[test]
+fn test_dao_votes_with_pulsar_snapshots() {
+    let suite = Suite::new();
+    let yes = Address::generate(&suite.env);
+    let no = Address::generate(&suite.env);
+    suite.fund_pulsar(&yes, 1_500);
+    suite.fund_pulsar(&no, 400);
+    suite.advance_ledgers(1);
+
+    let proposal_id = suite.dao.create_proposal(
+        &yes,
+        &String::from_str(&suite.env, "Lower fees"),
+        &String::from_str(&suite.env, "Cut the platform fee"),
+        &ProposalKind::General,
+        &None,
+    );
+
+    // PULSAR bought after the proposal opened carries no weight on it
+    suite.advance_ledgers(1);
+    suite.fund_pulsar(&no, 5_000);
+
+    suite.dao.cast_vote(&yes, &proposal_id, &VoteChoice::For);
+    suite.dao.cast_vote(&no, &proposal_id, &VoteChoice::Against);
+    let proposal = suite.dao.get_proposal(&proposal_id).unwrap();
+    assert_eq!((proposal.votes_for, proposal.votes_against), (1_500, 400));
+
+    suite.advance_ledgers(DAO_VOTING_PERIOD);
+    suite.dao.finalize_proposal(&suite.admin, &proposal_id);
+    assert!(suite.dao.get_proposal(&proposal_id).unwrap().status == ProposalStatus::Passed);
+}
+
+#[test]
+fn test_pulsar_moved_in_the_creation_ledger_counts_once() {
+    let suite = Suite::new();
+    let [alice, bob] = [0; 2].map(|_| Address::generate(&suite.env));
+    suite.fund_pulsar(&alice, 100);
+    suite.advance_ledgers(1);
+
+    let proposal_id = suite.dao.create_proposal(
+        &alice,
+        &String::from_str(&suite.env, "Lower fees"),
+        &String::from_str(&suite.env, "Cut the platform fee"),
+        &ProposalKind::General,
+        &None,
+    );
+
+    // Alice votes, hands her tokens to Bob and Bob tries again, all in the
+    // ledger the proposal was created in
+    suite.dao.cast_vote(&alice, &proposal_id, &VoteChoice::For);
+    suite.governance_token.transfer(&alice, &bob, &100);
+    assert!(suite.dao.try_cast_vote(&bob, &proposal_id, &VoteChoice::For).is_err());
+    assert_eq!(suite.dao.get_proposal(&proposal_id).unwrap().votes_for, 100);
+
+    // Tokens received in the current ledger don't meet `proposer_min` either
+    suite.dao.set_param(&suite.admin, &Symbol::new(&suite.env, "proposer_min"), &50);
+    assert!(suite
+        .dao
+        .try_create_proposal(
+            &bob,
+            &String::from_str(&suite.env, "Raise fees"),
+            &String::from_str(&suite.env, "Borrowed weight"),
+            &ProposalKind::General,
+            &None,
+        )
+        .is_err());
+}
+
+#[test]
+fn test_dao_follows_partial_and_chained_delegations() {
+    let suite = Suite::new();
+    let [alice, bob, carol, dave] = [0; 4].map(|_| Address::generate(&suite.env));
+    suite.fund_pulsar(&alice, 1_000);
+    suite.fund_pulsar(&bob, 100);
+    suite.fund_pulsar(&carol, 300);
+    suite.fund_pulsar(&dave, 200);
+
+    // Alice lends Bob 40% of her weight; Carol -> Dave -> Bob passes the rest on
+    suite.governance_token.delegate_partial(&alice, &bob, &4_000);
+    suite.governance_token.delegate(&carol, &dave);
+    suite.governance_token.delegate(&dave, &bob);
+    assert_eq!(suite.governance_token.voting_power(&bob), 1_000);
+    suite.advance_ledgers(1);
+
+    let proposal_id = suite.dao.create_proposal(
+        &bob,
+        &String::from_str(&suite.env, "Lower fees"),
+        &String::from_str(&suite.env, "Cut the platform fee"),
+        &ProposalKind::General,
+        &None,
+    );
+    let tally = || {
+        let proposal = suite.dao.get_proposal(&proposal_id).unwrap();
+        (proposal.votes_for, proposal.votes_against)
+    };
+
+    suite.dao.cast_vote(&bob, &proposal_id, &VoteChoice::For);
+    assert_eq!(tally(), (1_000, 0));
+
+    // Carol takes her weight back from the end of the chain, then Dave votes
+    // with what he still represents
+    suite.dao.cast_vote(&carol, &proposal_id, &VoteChoice::Against);
+    assert_eq!(tally(), (700, 300));
+    suite.dao.cast_vote(&dave, &proposal_id, &VoteChoice::Against);
+    assert_eq!(tally(), (500, 500));
+    assert_eq!(suite.dao.get_vote(&proposal_id, &dave).unwrap().raw_power, 200);
+
+    // Alice's partial share stays with Bob; she votes only what she kept
+    suite.dao.cast_vote(&alice, &proposal_id, &VoteChoice::Against);
+    assert_eq!(tally(), (500, 1_100));
+
+    // Withdrawing hands Carol's weight back to her own delegate
+    suite.dao.withdraw_vote(&carol, &proposal_id);
+    assert_eq!(tally(), (500, 1_100));
+    assert_eq!(suite.dao.get_vote(&proposal_id, &dave).unwrap().raw_power, 500);
+}
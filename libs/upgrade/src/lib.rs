@@ -0,0 +1,180 @@
+//! PulsarTrack - Upgrades (Soroban)
+//! Code upgrades and versioned storage migrations for PulsarTrack contracts.
+//! The entry points every core contract builds on these helpers are
+//! documented on [`Upgradeable`].
+//!
+//! As with `pulsartrack-access`, keys are supplied by the calling contract
+//! and everything lives in instance storage. Deployments from before
+//! versioning have no stored `ContractVersion` and count as version 1.
+
+
+#![no_std]
+use pulsartrack_access::{AccessError, Ownable};
+use pulsartrack_events::{self as events, Migrated, UpgradeAuthoritySet, Upgraded};
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, Val};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UpgradeError {
+    /// The stored version has already reached the running code's version
+    AlreadyMigrated,
+    /// `from_version` doesn't match the stored version
+    VersionMismatch,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractVersion {
+    pub version: u32,                    // storage layout version
+    pub wasm_hash: Option<BytesN<32>>, // code installed by the last upgrade
+    pub upgraded_at: Option<u64>,
+    pub migrated_at: Option<u64>,
+}
+
+/// Every core contract exposes the same entry points on top of these helpers,
+/// and all of them return `Result` with the contract's own error enum, which
+/// converts from `AccessError` and `UpgradeError`:
+///
+/// - `upgrade(new_wasm_hash) -> Result<(), E>` swaps in new code; storage is
+///   kept. Call `migrate` afterwards if the new code bumps `CONTRACT_VERSION`.
+/// - `migrate(from_version) -> Result<u32, E>` brings stored data up to the
+///   running code's `CONTRACT_VERSION` and returns it. It only runs from the
+///   version actually stored, so a migration can't be replayed or applied to
+///   the wrong layout. Contracts still on their first versioned layout have
+///   no steps yet.
+/// - `set_upgrade_authority(caller, authority) -> Result<(), E>` hands
+///   upgrade rights on, e.g. to the DAO or timelock.
+/// - `get_upgrade_authority()` / `get_version()`.
+///
+/// Both `upgrade` and `migrate` need the upgrade authority's auth. Until one
+/// is set the contract admin acts as authority; once handed to the DAO or
+/// timelock only that authority can move it again, so the admin can't take
+/// it back. A DAO proposal whose action calls `upgrade` on the target
+/// contract passes the check as the direct invoker.
+pub struct Upgradeable;
+
+impl Upgradeable {
+    /// Record a fresh deployment as already on `version`
+    pub fn init<K>(env: &Env, key: &K, version: u32)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        env.storage().instance().set(
+            key,
+            &ContractVersion {
+                version,
+                wasm_hash: None,
+                upgraded_at: None,
+                migrated_at: None,
+            },
+        );
+    }
+
+    pub fn version<K>(env: &Env, key: &K) -> ContractVersion
+    where
+        K: IntoVal<Env, Val>,
+    {
+        env.storage().instance().get(key).unwrap_or(ContractVersion {
+            version: 1,
+            wasm_hash: None,
+            upgraded_at: None,
+            migrated_at: None,
+        })
+    }
+
+    /// The configured authority, falling back to the owner stored under `owner_key`
+    pub fn authority<A, O>(env: &Env, authority_key: &A, owner_key: &O) -> Option<Address>
+    where
+        A: IntoVal<Env, Val>,
+        O: IntoVal<Env, Val>,
+    {
+        env.storage()
+            .instance()
+            .get(authority_key)
+            .or_else(|| Ownable::owner(env, owner_key))
+    }
+
+    /// Require the current authority's auth; returns it
+    pub fn require_authority<A, O>(env: &Env, authority_key: &A, owner_key: &O) -> Result<Address, AccessError>
+    where
+        A: IntoVal<Env, Val>,
+        O: IntoVal<Env, Val>,
+    {
+        let authority = Self::authority(env, authority_key, owner_key).ok_or(AccessError::NotInitialized)?;
+        authority.require_auth();
+        Ok(authority)
+    }
+
+    /// Hand the upgrade authority to `authority`; `caller` must be the current one
+    pub fn set_authority<A, O>(
+        env: &Env,
+        contract: &str,
+        authority_key: &A,
+        owner_key: &O,
+        caller: &Address,
+        authority: &Address,
+    ) -> Result<(), AccessError>
+    where
+        A: IntoVal<Env, Val>,
+        O: IntoVal<Env, Val>,
+    {
+        caller.require_auth();
+        let previous = Self::authority(env, authority_key, owner_key).ok_or(AccessError::NotInitialized)?;
+        if *caller != previous {
+            return Err(AccessError::Unauthorized);
+        }
+        env.storage().instance().set(authority_key, authority);
+        events::publish(env, contract, UpgradeAuthoritySet { previous, authority: authority.clone() });
+        Ok(())
+    }
+
+    /// Install `new_wasm_hash` as the contract's code. The caller checks the
+    /// authority first.
+    pub fn upgrade<K>(env: &Env, contract: &str, key: &K, new_wasm_hash: BytesN<32>)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let mut current = Self::version(env, key);
+        current.wasm_hash = Some(new_wasm_hash.clone());
+        current.upgraded_at = Some(env.ledger().timestamp());
+        env.storage().instance().set(key, &current);
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+        events::publish(env, contract, Upgraded { new_wasm_hash });
+    }
+
+    /// Run `steps` to take stored data from `from_version` to `to_version`,
+    /// then record the new version. `steps` gets the version being migrated
+    /// from and should apply every step from there up. The caller checks the
+    /// authority first.
+    pub fn migrate<K, F>(
+        env: &Env,
+        contract: &str,
+        key: &K,
+        from_version: u32,
+        to_version: u32,
+        steps: F,
+    ) -> Result<u32, UpgradeError>
+    where
+        K: IntoVal<Env, Val>,
+        F: FnOnce(u32),
+    {
+        let mut current = Self::version(env, key);
+        if current.version >= to_version {
+            return Err(UpgradeError::AlreadyMigrated);
+        }
+        if current.version != from_version {
+            return Err(UpgradeError::VersionMismatch);
+        }
+
+        steps(from_version);
+
+        current.version = to_version;
+        current.migrated_at = Some(env.ledger().timestamp());
+        env.storage().instance().set(key, &current);
+
+        events::publish(env, contract, Migrated { from_version, to_version });
+        Ok(to_version)
+    }
+}
+
+mod test;
@@ -0,0 +1,86 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{contract, contracttype, testutils::Address as _, Address, Env};
+
+// Stand-in contract giving the helpers a storage context
+#[contract]
+pub struct Host;
+
+#[contracttype]
+pub enum Key {
+    Admin,
+    UpgradeAuthority,
+    Version,
+}
+
+#[test]
+fn test_legacy_deployments_count_as_v1() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+
+    env.as_contract(&host, || {
+        assert_eq!(Upgradeable::version(&env, &Key::Version).version, 1);
+        Upgradeable::init(&env, &Key::Version, 3);
+        assert_eq!(Upgradeable::version(&env, &Key::Version).version, 3);
+    });
+}
+
+#[test]
+fn test_migrate_runs_once_from_stored_version() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+
+    env.as_contract(&host, || {
+        Upgradeable::init(&env, &Key::Version, 1);
+
+        assert_eq!(
+            Upgradeable::migrate(&env, "host", &Key::Version, 2, 3, |_| panic!("ran from wrong version")),
+            Err(UpgradeError::VersionMismatch)
+        );
+
+        let mut ran_from = 0;
+        assert_eq!(Upgradeable::migrate(&env, "host", &Key::Version, 1, 3, |from| ran_from = from), Ok(3));
+        assert_eq!(ran_from, 1);
+
+        let stored = Upgradeable::version(&env, &Key::Version);
+        assert_eq!(stored.version, 3);
+        assert!(stored.migrated_at.is_some());
+
+        assert_eq!(
+            Upgradeable::migrate(&env, "host", &Key::Version, 1, 3, |_| {}),
+            Err(UpgradeError::AlreadyMigrated)
+        );
+    });
+}
+
+#[test]
+fn test_authority_hand_off_is_one_way() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let host = env.register(Host, ());
+    let admin = Address::generate(&env);
+    let dao = Address::generate(&env);
+
+    env.as_contract(&host, || {
+        assert_eq!(
+            Upgradeable::require_authority(&env, &Key::UpgradeAuthority, &Key::Admin),
+            Err(AccessError::NotInitialized)
+        );
+        Ownable::init(&env, &Key::Admin, &admin).unwrap();
+        assert_eq!(Upgradeable::authority(&env, &Key::UpgradeAuthority, &Key::Admin), Some(admin.clone()));
+    });
+    env.as_contract(&host, || {
+        Upgradeable::set_authority(&env, "host", &Key::UpgradeAuthority, &Key::Admin, &admin, &dao).unwrap();
+        assert_eq!(Upgradeable::authority(&env, &Key::UpgradeAuthority, &Key::Admin), Some(dao.clone()));
+    });
+    env.as_contract(&host, || {
+        // The admin no longer holds it and can't take it back
+        assert_eq!(
+            Upgradeable::set_authority(&env, "host", &Key::UpgradeAuthority, &Key::Admin, &admin, &admin),
+            Err(AccessError::Unauthorized)
+        );
+    });
+    env.as_contract(&host, || {
+        assert_eq!(Upgradeable::require_authority(&env, &Key::UpgradeAuthority, &Key::Admin), Ok(dao.clone()));
+    });
+}
@@ -132,7 +132,9 @@ with open('$DEPLOY_FILE', 'w') as f:
 deploy_contract "ad_registry"           "pulsar_ad_registry"
 deploy_contract "campaign_orchestrator" "pulsar_campaign_orchestrator"
 deploy_contract "escrow_vault"          "pulsar_escrow_vault"
+deploy_contract "escrow"                "pulsar_escrow"
 deploy_contract "fraud_prevention"      "pulsar_fraud_prevention"
+deploy_contract "fraud_registry"        "pulsar_fraud_registry"
 deploy_contract "payment_processor"     "pulsar_payment_processor"
 
 # Governance
@@ -140,16 +142,22 @@ deploy_contract "governance_token"      "pulsar_governance_token"
 deploy_contract "governance_dao"        "pulsar_governance_dao"
 deploy_contract "governance_core"       "pulsar_governance_core"
 deploy_contract "timelock_executor"     "pulsar_timelock_executor"
+deploy_contract "merkle_distributor"    "pulsar_merkle_distributor"
+deploy_contract "vesting"               "pulsar_vesting"
+deploy_contract "participation_rewards" "pulsar_participation_rewards"
 
 # Publisher
 deploy_contract "publisher_verification" "pulsar_publisher_verification"
 deploy_contract "publisher_network"      "pulsar_publisher_network"
 deploy_contract "publisher_reputation"   "pulsar_publisher_reputation"
+deploy_contract "staking"                "pulsar_staking"
 
 # Analytics
 deploy_contract "analytics_aggregator"  "pulsar_analytics_aggregator"
+deploy_contract "analytics"             "pulsar_analytics"
 deploy_contract "campaign_analytics"    "pulsar_campaign_analytics"
 deploy_contract "campaign_lifecycle"    "pulsar_campaign_lifecycle"
+deploy_contract "attribution"           "pulsar_attribution"
 
 # Privacy & Targeting
 deploy_contract "privacy_layer"         "pulsar_privacy_layer"
@@ -160,28 +168,40 @@ deploy_contract "kyc_registry"          "pulsar_kyc_registry"
 
 # Marketplace
 deploy_contract "auction_engine"        "pulsar_auction_engine"
+deploy_contract "ad_auction"            "pulsar_ad_auction"
 deploy_contract "creative_marketplace"  "pulsar_creative_marketplace"
+deploy_contract "placement_nft"         "pulsar_placement_nft"
 
 # Financial
 deploy_contract "subscription_manager"  "pulsar_subscription_manager"
 deploy_contract "subscription_benefits" "pulsar_subscription_benefits"
+deploy_contract "subscription"          "pulsar_subscription"
+deploy_contract "fee_manager"           "pulsar_fee_manager"
+deploy_contract "insurance_pool"        "pulsar_insurance_pool"
 deploy_contract "liquidity_pool"        "pulsar_liquidity_pool"
 deploy_contract "milestone_tracker"     "pulsar_milestone_tracker"
 deploy_contract "multisig_treasury"     "pulsar_multisig_treasury"
+deploy_contract "oracle_aggregator"     "pulsar_oracle_aggregator"
 deploy_contract "oracle_integration"    "pulsar_oracle_integration"
+deploy_contract "payment_stream"        "pulsar_payment_stream"
 deploy_contract "payout_automation"     "pulsar_payout_automation"
 deploy_contract "performance_oracle"    "pulsar_performance_oracle"
 deploy_contract "recurring_payment"     "pulsar_recurring_payment"
 deploy_contract "refund_processor"      "pulsar_refund_processor"
 deploy_contract "revenue_settlement"    "pulsar_revenue_settlement"
 deploy_contract "rewards_distributor"   "pulsar_rewards_distributor"
+deploy_contract "splitter"              "pulsar_splitter"
 
 # Bridge & Utility
 deploy_contract "token_bridge"          "pulsar_token_bridge"
 deploy_contract "wrapped_token"         "pulsar_wrapped_token"
 deploy_contract "dispute_resolution"    "pulsar_dispute_resolution"
+deploy_contract "arbitration"           "pulsar_arbitration"
 deploy_contract "budget_optimizer"      "pulsar_budget_optimizer"
+deploy_contract "pacing"                "pulsar_pacing"
 deploy_contract "anomaly_detector"      "pulsar_anomaly_detector"
+deploy_contract "registry"              "pulsar_registry"
+deploy_contract "multicall"             "pulsar_multicall"
 
 echo ""
 echo "=============================================="